@@ -0,0 +1,394 @@
+use std::path::{Path, PathBuf};
+
+use age::secrecy::SecretString;
+
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, ExternalPaths, FocusHandle,
+    Focusable, InteractiveElement, ParentElement, PathPromptOptions, Render, SharedString, Styled,
+    Window, div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use crate::{Tool, ToolError, editor_font};
+
+struct LoadedFile {
+    path: PathBuf,
+    bytes: Vec<u8>,
+}
+
+pub struct AgeEncryptionTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    passphrase: Entity<InputState>,
+    output: Entity<InputState>,
+    file: Option<LoadedFile>,
+    output_bytes: Vec<u8>,
+    output_name: String,
+    busy: bool,
+    error: Option<ToolError>,
+}
+
+impl AgeEncryptionTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text or Armored Ciphertext")
+        });
+        let passphrase = cx.new(|cx| {
+            InputState::new(window, cx)
+                .masked(true)
+                .placeholder("Passphrase")
+        });
+        let output = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Output")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            passphrase,
+            output,
+            file: None,
+            output_bytes: Vec::new(),
+            output_name: String::new(),
+            busy: false,
+            error: None,
+        }
+    }
+
+    fn input_bytes(&self, cx: &App) -> Vec<u8> {
+        match &self.file {
+            Some(file) => file.bytes.clone(),
+            None => self.editor.read(cx).value().as_bytes().to_vec(),
+        }
+    }
+
+    fn input_name(&self) -> Option<String> {
+        self.file
+            .as_ref()
+            .and_then(|file| file.path.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+    }
+
+    fn run<F>(&mut self, output_name: String, f: F, window: &mut Window, cx: &mut Context<Self>)
+    where
+        F: FnOnce() -> Result<Vec<u8>, ToolError> + Send + 'static,
+    {
+        // scrypt is deliberately slow, so the work happens off the UI thread.
+        self.busy = true;
+        cx.notify();
+        cx.spawn_in(window, async move |this, cx| {
+            let result = cx.background_executor().spawn(async move { f() }).await;
+            this.update_in(cx, |this, window, cx| {
+                this.busy = false;
+                let output_value = match result {
+                    Ok(bytes) => {
+                        this.error = None;
+                        let text = match std::str::from_utf8(&bytes) {
+                            Ok(text) => text.to_string(),
+                            Err(_) => format!("Binary output ({} bytes)", bytes.len()),
+                        };
+                        this.output_bytes = bytes;
+                        this.output_name = output_name;
+                        text
+                    }
+                    Err(err) => {
+                        this.output_bytes = Vec::new();
+                        this.error = Some(err);
+                        String::new()
+                    }
+                };
+                this.output.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(output_value), window, cx);
+                });
+                cx.notify();
+            })
+        })
+        .detach();
+    }
+
+    fn on_encrypt_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let plaintext = self.input_bytes(cx);
+        let passphrase = self.passphrase.read(cx).value().to_string();
+        let output_name = match self.input_name() {
+            Some(name) => format!("{}.age", name),
+            None => "encrypted.age".to_string(),
+        };
+        self.run(
+            output_name,
+            move || encrypt(&plaintext, passphrase),
+            window,
+            cx,
+        );
+    }
+
+    fn on_decrypt_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let ciphertext = self.input_bytes(cx);
+        let passphrase = self.passphrase.read(cx).value().to_string();
+        let output_name = match self.input_name() {
+            Some(name) => name.trim_end_matches(".age").to_string(),
+            None => "decrypted".to_string(),
+        };
+        self.run(
+            output_name,
+            move || decrypt(&ciphertext, passphrase),
+            window,
+            cx,
+        );
+    }
+
+    fn on_open_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        cx.spawn_in(window, async move |this, cx| {
+            if let Ok(Ok(Some(paths))) = paths.await
+                && let Some(path) = paths.into_iter().next()
+            {
+                this.update(cx, |this, cx| this.load_file(path, cx)).ok();
+            }
+        })
+        .detach();
+    }
+
+    fn on_drop(&mut self, paths: &ExternalPaths, _: &mut Window, cx: &mut Context<Self>) {
+        if let Some(path) = paths.paths().first() {
+            self.load_file(path.clone(), cx);
+        }
+    }
+
+    fn load_file(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        if let Ok(bytes) = std::fs::read(&path) {
+            self.file = Some(LoadedFile { path, bytes });
+            cx.notify();
+        }
+    }
+
+    fn on_clear_file_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.file = None;
+        cx.notify();
+    }
+
+    fn on_save_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let directory = self
+            .file
+            .as_ref()
+            .and_then(|file| file.path.parent().map(Path::to_path_buf))
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        let path = cx.prompt_for_new_path(&directory, Some(&self.output_name));
+        let bytes = self.output_bytes.clone();
+        cx.spawn_in(window, async move |_, _| {
+            if let Ok(Ok(Some(path))) = path.await {
+                std::fs::write(path, bytes).ok();
+            }
+        })
+        .detach();
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.file = None;
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_output_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.output.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+}
+
+fn encrypt(plaintext: &[u8], passphrase: String) -> Result<Vec<u8>, ToolError> {
+    let recipient = age::scrypt::Recipient::new(SecretString::from(passphrase));
+    age::encrypt_and_armor(&recipient, plaintext)
+        .map(String::into_bytes)
+        .map_err(|err| ToolError::new(err.to_string()).context("Encryption failed"))
+}
+
+fn decrypt(ciphertext: &[u8], passphrase: String) -> Result<Vec<u8>, ToolError> {
+    let identity = age::scrypt::Identity::new(SecretString::from(passphrase));
+    age::decrypt(&identity, ciphertext)
+        .map_err(|err| ToolError::new(err.to_string()).context("Decryption failed"))
+}
+
+impl Tool for AgeEncryptionTool {
+    fn title() -> &'static str {
+        "Age Encryption"
+    }
+
+    fn short_title() -> &'static str {
+        "Age Encryption"
+    }
+
+    fn description() -> &'static str {
+        "Encrypts and decrypts text or files with a passphrase using the age format."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value();
+        let passphrase = self.passphrase.read(cx).value();
+        if self.busy || (value.is_empty() && self.file.is_none()) || passphrase.is_empty() {
+            return;
+        }
+        self.on_encrypt_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_output_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+}
+
+impl Focusable for AgeEncryptionTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for AgeEncryptionTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let passphrase = self.passphrase.read(cx).value();
+        let disabled = (value.is_empty() && self.file.is_none()) || passphrase.is_empty();
+
+        div()
+            .on_drop(cx.listener(Self::on_drop))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("encrypt-button")
+                            .label("Encrypt")
+                            .primary()
+                            .loading(self.busy)
+                            .disabled(disabled || self.busy)
+                            .on_click(cx.listener(Self::on_encrypt_click)),
+                    )
+                    .child(
+                        Button::new("decrypt-button")
+                            .label("Decrypt")
+                            .loading(self.busy)
+                            .disabled(disabled || self.busy)
+                            .on_click(cx.listener(Self::on_decrypt_click)),
+                    )
+                    .child(
+                        Button::new("open-button")
+                            .label("Open File…")
+                            .on_click(cx.listener(Self::on_open_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(TextInput::new(&self.passphrase).focus_bordered(false))
+            .when_some(self.file.as_ref(), |this, file| {
+                this.child(
+                    h_flex()
+                        .gap_2()
+                        .child(Label::new(format!(
+                            "{} ({} bytes)",
+                            file.path.display(),
+                            file.bytes.len()
+                        )))
+                        .child(
+                            Button::new("clear-file-button")
+                                .label("Clear")
+                                .on_click(cx.listener(Self::on_clear_file_click))
+                                .ml_auto(),
+                        ),
+                )
+            })
+            .when(self.file.is_none(), |this| {
+                this.child(
+                    TextInput::new(&self.editor)
+                        .h_full()
+                        .font_family(editor_font(cx))
+                        .text_size(px(15.))
+                        .focus_bordered(false),
+                )
+                .child(
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child("Drop a file here to encrypt or decrypt it instead."),
+                )
+            })
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("save-button")
+                            .label("Save…")
+                            .disabled(self.output_bytes.is_empty())
+                            .on_click(cx.listener(Self::on_save_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("copy-output-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_output_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.output)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,272 @@
+use chrono::Utc;
+
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::{oauth, saml},
+    editor_font,
+};
+
+pub struct AuthFlowInspectorTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    decoded: Entity<InputState>,
+    summary: Entity<InputState>,
+    error: Option<ToolError>,
+}
+
+impl AuthFlowInspectorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("SAMLRequest, SAMLResponse, or OAuth URL")
+        });
+        let decoded = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Html.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Decoded XML or Parameters")
+        });
+        let summary = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Summary")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            decoded,
+            summary,
+            error: None,
+        }
+    }
+
+    fn on_decode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let inspection = (!value.contains("SAMLRequest=") && !value.contains("SAMLResponse="))
+            .then(|| oauth::inspect(&value))
+            .flatten();
+        self.error = None;
+        let (decoded_value, summary_value, language) = match inspection {
+            Some(inspection) => (inspection.table(), inspection.summary(), Language::Plain),
+            None => {
+                let (decoded_value, summary_value) = match saml::decode(&value) {
+                    Ok(xml) => match saml::inspect(&xml, Utc::now()) {
+                        Ok(inspection) => (inspection.formatted, inspection.summary),
+                        Err(err) => {
+                            // The decoded text is still shown so the fault can be found.
+                            self.error = Some(err);
+                            (xml, String::new())
+                        }
+                    },
+                    Err(err) => {
+                        self.error = Some(err);
+                        (String::new(), String::new())
+                    }
+                };
+                (decoded_value, summary_value, Language::Html)
+            }
+        };
+        self.decoded.update(cx, |state, cx| {
+            state.set_highlighter(language.name().to_string(), cx);
+            state.set_value(SharedString::from(decoded_value), window, cx);
+        });
+        self.summary.update(cx, |state, cx| {
+            state.set_value(SharedString::from(summary_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_decoded_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.decoded.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+}
+
+impl Tool for AuthFlowInspectorTool {
+    fn title() -> &'static str {
+        "Auth Flow Inspector"
+    }
+
+    fn short_title() -> &'static str {
+        "Auth Flows"
+    }
+
+    fn description() -> &'static str {
+        "Decodes SAML requests and responses and summarizes their assertions, or breaks OAuth authorization and callback URLs into their parameters."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_decode_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_decoded_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.decoded.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.editor.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
+}
+
+impl Focusable for AuthFlowInspectorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for AuthFlowInspectorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("decode-button")
+                            .label("Decode")
+                            .primary()
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_decode_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_1_3()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-decoded-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_decoded_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .h_full()
+                    .gap_2()
+                    .child(
+                        div().w_full().h_full().child(
+                            TextInput::new(&self.decoded)
+                                .h_full()
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        div().w_full().h_full().child(
+                            TextInput::new(&self.summary)
+                                .h_full()
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    ),
+            )
+    }
+}
@@ -1,26 +1,67 @@
-use base64::{Engine as _, engine::general_purpose};
+use std::sync::Arc;
 
 use gpui::{
-    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
-    ParentElement, Render, SharedString, Styled, Window, div, px,
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable, Image,
+    ObjectFit, ParentElement, Render, SharedString, Styled, StyledImage, Subscription, Window, div,
+    img, prelude::FluentBuilder, px,
 };
 
 use gpui_component::{
-    Disableable, StyledExt,
+    ActiveTheme, Disableable, StyledExt,
     button::{Button, ButtonVariants},
     h_flex,
     highlighter::Language,
+    input::InputEvent,
     input::InputState,
     input::TabSize,
     input::TextInput,
+    v_flex,
 };
 
-use crate::Tool;
+use serde_json::Value;
+
+use crate::{
+    LiveUpdate, Tool, ToolError,
+    core::base64::{self, ImageType},
+    editor_font,
+    image_preview::preview_image,
+};
+
+/// Decoded bytes that are not shown as text, being an image or not UTF-8.
+struct Binary {
+    bytes: Vec<u8>,
+    image: Option<(ImageType, Arc<Image>)>,
+}
+
+impl Binary {
+    fn new(bytes: Vec<u8>, image_type: Option<ImageType>) -> Self {
+        let image =
+            image_type.map(|image_type| (image_type, preview_image(image_type, bytes.clone())));
+        Self { bytes, image }
+    }
+
+    fn description(&self) -> String {
+        match &self.image {
+            Some((image_type, _)) => {
+                format!("{} image, {} bytes", image_type.name(), self.bytes.len())
+            }
+            None => format!(
+                "Binary data, {} bytes, that is not UTF-8 text",
+                self.bytes.len()
+            ),
+        }
+    }
+}
 
 pub struct Base64DecoderTool {
     focus_handle: FocusHandle,
     editor: Entity<InputState>,
     decoded: Entity<InputState>,
+    binary: Option<Binary>,
+    error: Option<ToolError>,
+    live: bool,
+    live_update: LiveUpdate,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl Base64DecoderTool {
@@ -51,27 +92,63 @@ impl Base64DecoderTool {
                 .default_value("")
                 .placeholder("Decoded Text")
         });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
 
         Self {
             focus_handle: cx.focus_handle(),
             editor,
             decoded,
+            binary: None,
+            error: None,
+            live: false,
+            live_update: LiveUpdate::default(),
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e
+            && self.live
+        {
+            self.live_update.schedule(Self::update_decoded, window, cx);
         }
     }
 
     fn on_decode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_decoded(window, cx);
+    }
+
+    fn update_decoded(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         let value = self.editor.read(cx).value().clone();
-        self.decoded.update(cx, |state, cx| {
-            match general_purpose::STANDARD.decode(value.to_string()) {
-                Ok(decoded_bytes) => match String::from_utf8(decoded_bytes) {
-                    Ok(decoded_value) => {
-                        state.set_value(SharedString::from(decoded_value), window, cx);
+        match base64::decode_bytes(&value) {
+            Ok(bytes) => {
+                self.error = None;
+                // Images are previewed, and other bytes that are not text
+                // can be saved to a file, rather than shown garbled.
+                let decoded_value = match (ImageType::detect(&bytes), String::from_utf8(bytes)) {
+                    (None, Ok(text)) => {
+                        self.binary = None;
+                        text
                     }
-                    Err(_) => {}
-                },
-                Err(_) => {}
+                    (image_type, result) => {
+                        let bytes = result.map_or_else(|err| err.into_bytes(), String::into_bytes);
+                        self.binary = Some(Binary::new(bytes, image_type));
+                        String::new()
+                    }
+                };
+                self.decoded.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(decoded_value), window, cx);
+                });
             }
-        })
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
     }
 
     fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
@@ -94,6 +171,14 @@ impl Base64DecoderTool {
         _window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        if let Some(Binary {
+            image: Some((_, image)),
+            ..
+        }) = &self.binary
+        {
+            cx.write_to_clipboard(ClipboardItem::new_image(image));
+            return;
+        }
         let value = self.decoded.read(cx).value().clone();
         cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
     }
@@ -106,11 +191,37 @@ impl Base64DecoderTool {
     ) {
         if let Some(clipboard) = cx.read_from_clipboard() {
             let value = clipboard.text().unwrap_or_default();
+            self.binary = None;
             self.decoded.update(cx, |state, cx| {
                 state.set_value(value, window, cx);
             })
         }
     }
+
+    fn on_save_decoded_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(binary) = &self.binary else {
+            return;
+        };
+        let directory = std::env::current_dir().unwrap_or_default();
+        let extension = binary
+            .image
+            .as_ref()
+            .map_or("bin", |(image_type, _)| image_type.extension());
+        let name = format!("decoded.{}", extension);
+        let path = cx.prompt_for_new_path(&directory, Some(&name));
+        let bytes = binary.bytes.clone();
+        cx.spawn_in(window, async move |_, _| {
+            if let Ok(Ok(Some(path))) = path.await {
+                std::fs::write(path, bytes).ok();
+            }
+        })
+        .detach();
+    }
 }
 
 impl Tool for Base64DecoderTool {
@@ -129,6 +240,49 @@ impl Tool for Base64DecoderTool {
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_decode_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_encoded_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.decoded.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn supports_live() -> bool {
+        true
+    }
+
+    fn set_live(&mut self, live: bool, window: &mut Window, cx: &mut Context<Self>) {
+        self.live = live;
+        if live {
+            self.update_decoded(window, cx);
+        }
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.editor.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
 }
 
 impl Focusable for Base64DecoderTool {
@@ -174,13 +328,20 @@ impl Render for Base64DecoderTool {
             .child(
                 TextInput::new(&self.editor)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
             .child(
                 h_flex()
                     .gap_2()
+                    .when(self.binary.is_some(), |this| {
+                        this.child(
+                            Button::new("save-decoded-button")
+                                .label("Save to File…")
+                                .on_click(cx.listener(Self::on_save_decoded_click)),
+                        )
+                    })
                     .child(
                         Button::new("copy-encoded-button")
                             .label("Copy")
@@ -193,12 +354,42 @@ impl Render for Base64DecoderTool {
                             .on_click(cx.listener(Self::on_paste_encoded_click)),
                     ),
             )
-            .child(
-                TextInput::new(&self.decoded)
-                    .h_full()
-                    .font_family("Space Mono")
-                    .text_size(px(15.))
-                    .focus_bordered(false),
-            )
+            .when(self.binary.is_none(), |this| {
+                this.child(
+                    TextInput::new(&self.decoded)
+                        .h_full()
+                        .font_family(editor_font(cx))
+                        .text_size(px(15.))
+                        .focus_bordered(false),
+                )
+            })
+            .when_some(self.binary.as_ref(), |this, binary| {
+                this.child(
+                    v_flex()
+                        .h_full()
+                        .min_h_0()
+                        .p_2()
+                        .gap_2()
+                        .items_center()
+                        .justify_center()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .rounded(cx.theme().radius)
+                        .when_some(binary.image.clone(), |this, (_, image)| {
+                            this.child(
+                                img(image)
+                                    .flex_1()
+                                    .min_h_0()
+                                    .w_full()
+                                    .object_fit(ObjectFit::Contain),
+                            )
+                        })
+                        .child(
+                            div()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(binary.description()),
+                        ),
+                )
+            })
     }
 }
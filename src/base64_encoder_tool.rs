@@ -1,8 +1,6 @@
-use base64::{Engine as _, engine::general_purpose};
-
 use gpui::{
     App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
-    ParentElement, Render, SharedString, Styled, Window, div, px,
+    ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
 };
 
 use gpui_component::{
@@ -10,15 +8,20 @@ use gpui_component::{
     button::{Button, ButtonVariants},
     h_flex,
     highlighter::Language,
-    input::{InputState, TabSize, TextInput},
+    input::{InputEvent, InputState, TabSize, TextInput},
 };
 
-use crate::Tool;
+use serde_json::Value;
+
+use crate::{LiveUpdate, Tool, core::base64, editor_font};
 
 pub struct Base64EncoderTool {
     focus_handle: FocusHandle,
     editor: Entity<InputState>,
     encoded: Entity<InputState>,
+    live: bool,
+    live_update: LiveUpdate,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl Base64EncoderTool {
@@ -49,19 +52,40 @@ impl Base64EncoderTool {
                 .default_value("")
                 .placeholder("Encoded Text")
         });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
 
         Self {
             focus_handle: cx.focus_handle(),
             editor,
             encoded,
+            live: false,
+            live_update: LiveUpdate::default(),
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e
+            && self.live
+        {
+            self.live_update.schedule(Self::update_encoded, window, cx);
         }
     }
 
     fn on_encode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_encoded(window, cx);
+    }
+
+    fn update_encoded(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         let value = self.editor.read(cx).value().clone();
         self.encoded.update(cx, |state, cx| {
-            let encoded_value = general_purpose::STANDARD.encode(value.to_string());
-            state.set_value(SharedString::from(encoded_value), window, cx);
+            state.set_value(SharedString::from(base64::encode(&value)), window, cx);
         })
     }
 
@@ -120,6 +144,45 @@ impl Tool for Base64EncoderTool {
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_encode_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_encoded_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.encoded.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn supports_live() -> bool {
+        true
+    }
+
+    fn set_live(&mut self, live: bool, window: &mut Window, cx: &mut Context<Self>) {
+        self.live = live;
+        if live {
+            self.update_encoded(window, cx);
+        }
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.editor.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
 }
 
 impl Focusable for Base64EncoderTool {
@@ -165,7 +228,7 @@ impl Render for Base64EncoderTool {
             .child(
                 TextInput::new(&self.editor)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
@@ -187,7 +250,7 @@ impl Render for Base64EncoderTool {
             .child(
                 TextInput::new(&self.encoded)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
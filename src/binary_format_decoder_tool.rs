@@ -0,0 +1,429 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::{
+        base64,
+        binary_format::{self, Format},
+        encoding::{self, InputEncoding},
+        json,
+    },
+    default_indentation, editor_font,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Direction {
+    Decode,
+    Encode,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = binary_tools, no_json)]
+pub struct SetBinaryDirection(Direction);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = binary_tools, no_json)]
+pub struct SetBinaryFormat(Option<Format>);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = binary_tools, no_json)]
+pub struct SetBytesEncoding(InputEncoding);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    direction: Direction,
+    format: Option<Format>,
+    encoding: InputEncoding,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct BinaryFormatDecoderTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    converted: Entity<InputState>,
+    direction: Direction,
+    /// The format to read or write, or `None` to detect it when decoding.
+    /// Encoding without a format writes MessagePack.
+    format: Option<Format>,
+    /// How the bytes are written. Detecting reads hex or Base64 and writes
+    /// Base64.
+    encoding: InputEncoding,
+    /// The format the last input was decoded as.
+    detected: Option<Format>,
+    error: Option<ToolError>,
+}
+
+impl BinaryFormatDecoderTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Base64 or Hex Bytes")
+        });
+        let converted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("JSON")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            converted,
+            direction: Direction::Decode,
+            format: None,
+            encoding: InputEncoding::Auto,
+            detected: None,
+            error: None,
+        }
+    }
+
+    fn convert(&mut self, value: &str, cx: &App) -> Result<String, ToolError> {
+        match self.direction {
+            Direction::Decode => {
+                let bytes = encoding::parse_bytes(value, self.encoding)?;
+                let (format, decoded) = binary_format::decode(&bytes, self.format)?;
+                self.detected = Some(format);
+                json::format(&decoded.to_string(), default_indentation(cx))
+            }
+            Direction::Encode => {
+                let bytes = binary_format::encode(value, self.format.unwrap_or_default())?;
+                Ok(match self.encoding {
+                    InputEncoding::Hex => encoding::to_hex(&bytes),
+                    InputEncoding::Auto | InputEncoding::Base64 => base64::encode_bytes(&bytes),
+                })
+            }
+        }
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        self.detected = None;
+        match self.convert(&value, cx) {
+            Ok(converted) => {
+                self.error = None;
+                self.converted.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(converted), window, cx);
+                });
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_converted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.converted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_binary_direction(
+        &mut self,
+        action: &SetBinaryDirection,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.direction == action.0 {
+            return;
+        }
+        self.direction = action.0;
+        self.detected = None;
+        let (source, converted) = match self.direction {
+            Direction::Decode => (
+                ("Base64 or Hex Bytes", Language::Plain),
+                ("JSON", Language::Json),
+            ),
+            Direction::Encode => (
+                ("JSON Source", Language::Json),
+                ("Encoded Bytes", Language::Plain),
+            ),
+        };
+        self.editor.update(cx, |state, cx| {
+            state.set_highlighter(source.1.name().to_string(), cx);
+            state.set_placeholder(source.0, window, cx);
+        });
+        self.converted.update(cx, |state, cx| {
+            state.set_highlighter(converted.1.name().to_string(), cx);
+            state.set_placeholder(converted.0, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_action_set_binary_format(
+        &mut self,
+        action: &SetBinaryFormat,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.format = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_bytes_encoding(
+        &mut self,
+        action: &SetBytesEncoding,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.encoding = action.0;
+        cx.notify();
+    }
+}
+
+impl Tool for BinaryFormatDecoderTool {
+    fn title() -> &'static str {
+        "MessagePack/CBOR Decoder"
+    }
+
+    fn short_title() -> &'static str {
+        "MessagePack/CBOR"
+    }
+
+    fn description() -> &'static str {
+        "Decodes Base64 or hex MessagePack and CBOR payloads into JSON, and encodes JSON back into either format."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_converted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.converted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            direction: self.direction,
+            format: self.format,
+            encoding: self.encoding,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_binary_direction(&SetBinaryDirection(preset.direction), window, cx);
+        self.format = preset.format;
+        self.encoding = preset.encoding;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for BinaryFormatDecoderTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for BinaryFormatDecoderTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let direction = self.direction;
+        let format = self.format;
+        let encoding = self.encoding;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_binary_direction))
+            .on_action(cx.listener(Self::on_action_set_binary_format))
+            .on_action(cx.listener(Self::on_action_set_bytes_encoding))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("convert-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("convert-button")
+                                    .label(match direction {
+                                        Direction::Decode => "Decode to JSON".to_string(),
+                                        Direction::Encode => format!(
+                                            "Encode as {}",
+                                            format.unwrap_or_default().name()
+                                        ),
+                                    })
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                let this = this
+                                    .label("Direction")
+                                    .menu_with_check(
+                                        "Decode to JSON",
+                                        direction == Direction::Decode,
+                                        Box::new(SetBinaryDirection(Direction::Decode)),
+                                    )
+                                    .menu_with_check(
+                                        "Encode JSON",
+                                        direction == Direction::Encode,
+                                        Box::new(SetBinaryDirection(Direction::Encode)),
+                                    )
+                                    .label("Format");
+                                let this = match direction {
+                                    Direction::Decode => this.menu_with_check(
+                                        "Detect Automatically",
+                                        format.is_none(),
+                                        Box::new(SetBinaryFormat(None)),
+                                    ),
+                                    Direction::Encode => this,
+                                };
+                                let this = Format::ALL.into_iter().fold(this, |this, option| {
+                                    let checked = match direction {
+                                        Direction::Decode => format == Some(option),
+                                        Direction::Encode => format.unwrap_or_default() == option,
+                                    };
+                                    this.menu_with_check(
+                                        option.name(),
+                                        checked,
+                                        Box::new(SetBinaryFormat(Some(option))),
+                                    )
+                                });
+                                match direction {
+                                    Direction::Decode => InputEncoding::ALL.into_iter().fold(
+                                        this.label("Bytes"),
+                                        |this, option| {
+                                            this.menu_with_check(
+                                                option.name(),
+                                                encoding == option,
+                                                Box::new(SetBytesEncoding(option)),
+                                            )
+                                        },
+                                    ),
+                                    Direction::Encode => this
+                                        .label("Bytes")
+                                        .menu_with_check(
+                                            "Base64",
+                                            encoding != InputEncoding::Hex,
+                                            Box::new(SetBytesEncoding(InputEncoding::Base64)),
+                                        )
+                                        .menu_with_check(
+                                            "Hex",
+                                            encoding == InputEncoding::Hex,
+                                            Box::new(SetBytesEncoding(InputEncoding::Hex)),
+                                        ),
+                                }
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .when_some(self.detected, |this, detected| {
+                        this.child(Label::new(format!("Decoded as {}", detected.name())))
+                    })
+                    .child(
+                        Button::new("copy-converted-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_converted_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.converted)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
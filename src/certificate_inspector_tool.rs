@@ -0,0 +1,258 @@
+use chrono::Utc;
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, InteractiveElement,
+    ParentElement, Render, SharedString, StatefulInteractiveElement, Styled, Subscription, Window,
+    div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants},
+    clipboard::Clipboard,
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, core::certificate, editor_font};
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+/// The fields of one certificate in the pasted chain.
+struct Section {
+    title: String,
+    fields: Vec<(&'static str, Entity<InputState>)>,
+}
+
+pub struct CertificateInspectorTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    sections: Vec<Section>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl CertificateInspectorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("PEM Certificate or Certificate Request")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            sections: Vec::new(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_sections(window, cx);
+        }
+    }
+
+    fn update_sections(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        self.error = None;
+        self.sections.clear();
+        if value.trim().is_empty() {
+            cx.notify();
+            return;
+        }
+        match certificate::parse_pem(&value) {
+            Ok(certificates) => {
+                let now = Utc::now();
+                for (i, certificate) in certificates.iter().enumerate() {
+                    let mut fields = certificate::inspect(certificate, now);
+                    if let Some(status) = certificate::chain_status(&certificates, i) {
+                        fields.insert(1, ("Chain", status));
+                    }
+                    let title = format!(
+                        "{} {} of {}",
+                        certificate.kind.name(),
+                        i + 1,
+                        certificates.len()
+                    );
+                    let fields = fields
+                        .into_iter()
+                        .map(|(name, value)| {
+                            (
+                                name,
+                                cx.new(|cx| InputState::new(window, cx).default_value(value)),
+                            )
+                        })
+                        .collect();
+                    self.sections.push(Section { title, fields });
+                }
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_inspect_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_sections(window, cx);
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            });
+            self.update_sections(window, cx);
+        }
+    }
+}
+
+impl Tool for CertificateInspectorTool {
+    fn title() -> &'static str {
+        "Certificate Inspector"
+    }
+
+    fn short_title() -> &'static str {
+        "Certificate"
+    }
+
+    fn description() -> &'static str {
+        "Decodes PEM certificates, chains, and certificate requests, showing names, validity, keys, and fingerprints."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_inspect_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+            self.update_sections(window, cx);
+        }
+    }
+}
+
+impl Focusable for CertificateInspectorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CertificateInspectorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("inspect-button")
+                            .label("Inspect")
+                            .primary()
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_inspect_click)),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h(px(200.))
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                div()
+                    .id("sections")
+                    .v_flex()
+                    .flex_1()
+                    .gap_2()
+                    .mt_4()
+                    .overflow_y_scroll()
+                    .children(self.sections.iter().enumerate().map(|(i, section)| {
+                        div()
+                            .v_flex()
+                            .gap_2()
+                            .mb_4()
+                            .child(Label::new(section.title.clone()).font_semibold())
+                            .children(section.fields.iter().enumerate().map(
+                                |(j, (name, state))| {
+                                    let value = state.read(cx).value().clone();
+                                    h_flex().gap_2().child(Label::new(*name).w(px(160.))).child(
+                                        TextInput::new(state)
+                                            .text_size(px(15.))
+                                            .focus_bordered(false)
+                                            .suffix(
+                                                Clipboard::new(SharedString::from(format!(
+                                                    "field-clipboard-{}-{}",
+                                                    i, j
+                                                )))
+                                                .value(value),
+                                            ),
+                                    )
+                                },
+                            ))
+                    })),
+            )
+    }
+}
@@ -0,0 +1,361 @@
+use std::path::PathBuf;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, Context, Entity, ExternalPaths, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, PathPromptOptions, Render, SharedString, Styled,
+    Subscription, Window, div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    clipboard::Clipboard,
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool,
+    core::checksum::{self, Algorithm, Encoding},
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = checksum_tools, no_json)]
+pub struct SetEncoding(Encoding);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    encoding: Encoding,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+struct LoadedFile {
+    path: PathBuf,
+    bytes: Vec<u8>,
+}
+
+pub struct ChecksumTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    key: Entity<InputState>,
+    file: Option<LoadedFile>,
+    encoding: Encoding,
+    results: Vec<(Algorithm, Entity<InputState>)>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ChecksumTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text")
+        });
+        let key = cx.new(|cx| {
+            InputState::new(window, cx)
+                .masked(true)
+                .placeholder("Secret Key for HMAC")
+        });
+        let results = Algorithm::ALL
+            .into_iter()
+            .map(|algorithm| (algorithm, cx.new(|cx| InputState::new(window, cx))))
+            .collect();
+        let _subscriptions = vec![
+            cx.subscribe_in(&editor, window, Self::on_input_event),
+            cx.subscribe_in(&key, window, Self::on_input_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            key,
+            file: None,
+            encoding: Encoding::Hex,
+            results,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_results(window, cx);
+        }
+    }
+
+    fn update_results(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let data = match &self.file {
+            Some(file) => file.bytes.as_slice(),
+            None => value.as_bytes(),
+        };
+        let key = self.key.read(cx).value().clone();
+        let empty = self.file.is_none() && value.is_empty();
+        for (algorithm, state) in &self.results {
+            // An HMAC without a key is rarely what was meant, so it is left
+            // blank until one is entered.
+            let result = if empty || (algorithm.is_keyed() && key.is_empty()) {
+                String::new()
+            } else {
+                checksum::encode(
+                    &checksum::digest(*algorithm, data, key.as_bytes()),
+                    self.encoding,
+                )
+            };
+            state.update(cx, |state, cx| {
+                state.set_value(result, window, cx);
+            });
+        }
+        cx.notify();
+    }
+
+    fn on_calculate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_results(window, cx);
+    }
+
+    fn on_open_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        cx.spawn_in(window, async move |this, cx| {
+            if let Ok(Ok(Some(paths))) = paths.await
+                && let Some(path) = paths.into_iter().next()
+            {
+                this.update_in(cx, |this, window, cx| this.load_file(path, window, cx))
+                    .ok();
+            }
+        })
+        .detach();
+    }
+
+    fn on_drop(&mut self, paths: &ExternalPaths, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(path) = paths.paths().first() {
+            self.load_file(path.clone(), window, cx);
+        }
+    }
+
+    fn load_file(&mut self, path: PathBuf, window: &mut Window, cx: &mut Context<Self>) {
+        if let Ok(bytes) = std::fs::read(&path) {
+            self.file = Some(LoadedFile { path, bytes });
+            self.update_results(window, cx);
+        }
+    }
+
+    fn on_clear_file_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.file = None;
+        self.update_results(window, cx);
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.file = None;
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            });
+            self.update_results(window, cx);
+        }
+    }
+
+    fn on_action_set_encoding(
+        &mut self,
+        action: &SetEncoding,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.encoding = action.0;
+        self.update_results(window, cx);
+    }
+}
+
+impl Tool for ChecksumTool {
+    fn title() -> &'static str {
+        "Checksum Calculator"
+    }
+
+    fn short_title() -> &'static str {
+        "Checksum"
+    }
+
+    fn description() -> &'static str {
+        "Calculates CRC32, Adler-32, and xxHash checksums and HMAC-SHA256 and HMAC-SHA1 of text or files."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_calculate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            encoding: self.encoding,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        if let Some(input) = preset.input {
+            self.file = None;
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.on_action_set_encoding(&SetEncoding(preset.encoding), window, cx);
+    }
+}
+
+impl Focusable for ChecksumTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ChecksumTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let encoding = self.encoding;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_encoding))
+            .on_drop(cx.listener(Self::on_drop))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("calculate-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("calculate-button")
+                                    .label("Calculate")
+                                    .primary()
+                                    .disabled(value.is_empty() && self.file.is_none())
+                                    .on_click(cx.listener(Self::on_calculate_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Output")
+                                    .menu_with_check(
+                                        "Hex",
+                                        encoding == Encoding::Hex,
+                                        Box::new(SetEncoding(Encoding::Hex)),
+                                    )
+                                    .menu_with_check(
+                                        "Base64",
+                                        encoding == Encoding::Base64,
+                                        Box::new(SetEncoding(Encoding::Base64)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("open-button")
+                            .label("Open File…")
+                            .on_click(cx.listener(Self::on_open_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .when_some(self.file.as_ref(), |this, file| {
+                this.child(
+                    h_flex()
+                        .gap_2()
+                        .child(Label::new(format!(
+                            "{} ({} bytes)",
+                            file.path.display(),
+                            file.bytes.len()
+                        )))
+                        .child(
+                            Button::new("clear-file-button")
+                                .label("Clear")
+                                .on_click(cx.listener(Self::on_clear_file_click))
+                                .ml_auto(),
+                        ),
+                )
+            })
+            .when(self.file.is_none(), |this| {
+                this.child(
+                    TextInput::new(&self.editor)
+                        .h_full()
+                        .font_family(editor_font(cx))
+                        .text_size(px(15.))
+                        .focus_bordered(false),
+                )
+                .child(
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child("Drop a file here to calculate its checksums instead."),
+                )
+            })
+            .child(TextInput::new(&self.key).focus_bordered(false))
+            .child(
+                div()
+                    .v_flex()
+                    .gap_2()
+                    .mt_4()
+                    .children(self.results.iter().map(|(algorithm, state)| {
+                        let value = state.read(cx).value().clone();
+                        h_flex()
+                            .gap_2()
+                            .child(Label::new(algorithm.name()).w(px(160.)))
+                            .child(
+                                TextInput::new(state)
+                                    .font_family(editor_font(cx))
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new(SharedString::from(format!(
+                                            "{}-clipboard",
+                                            algorithm.name().to_lowercase()
+                                        )))
+                                        .value(value),
+                                    ),
+                            )
+                    })),
+            )
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    fs,
+    io::{self, Read},
+    process::ExitCode,
+};
+
+use crate::{
+    Settings, ToolError,
+    core::{base64, html, json, sql, url, xml},
+};
+
+/// The options given after a subcommand's name.
+struct Options {
+    indent: usize,
+    compact: bool,
+}
+
+/// A conversion that can be run from the command line without opening a
+/// window.
+struct Command {
+    name: &'static str,
+    description: &'static str,
+    /// The options the command accepts, besides an input file.
+    options: &'static [&'static str],
+    convert: fn(&str, &Options) -> Result<String, ToolError>,
+}
+
+const COMMANDS: &[Command] = &[
+    Command {
+        name: "base64-encode",
+        description: "Encodes text as Base64.",
+        options: &[],
+        convert: |input, _| Ok(base64::encode(input)),
+    },
+    Command {
+        name: "base64-decode",
+        description: "Decodes Base64 into text.",
+        options: &[],
+        convert: |input, _| base64::decode(input.trim()),
+    },
+    Command {
+        name: "url-encode",
+        description: "Percent-encodes text for use in a URL.",
+        options: &[],
+        convert: |input, _| Ok(url::encode(input)),
+    },
+    Command {
+        name: "url-decode",
+        description: "Decodes percent-encoded text.",
+        options: &[],
+        convert: |input, _| url::decode(input.trim()),
+    },
+    Command {
+        name: "json-format",
+        description: "Pretty-prints or compacts JSON.",
+        options: &["--indent", "--compact"],
+        convert: |input, options| {
+            if options.compact {
+                json::compact(input)
+            } else {
+                json::format(input, options.indent)
+            }
+        },
+    },
+    Command {
+        name: "json-encode",
+        description: "Encodes text as a JSON string.",
+        options: &[],
+        convert: |input, _| Ok(json::encode_string(input)),
+    },
+    Command {
+        name: "json-decode",
+        description: "Decodes a JSON string into text.",
+        options: &[],
+        convert: |input, _| json::decode_string(input.trim()),
+    },
+    Command {
+        name: "xml-format",
+        description: "Pretty-prints or compacts XML.",
+        options: &["--indent", "--compact"],
+        convert: |input, options| {
+            if options.compact {
+                xml::compact(input)
+            } else {
+                xml::format(input, options.indent)
+            }
+        },
+    },
+    Command {
+        name: "html-format",
+        description: "Pretty-prints or minifies HTML.",
+        options: &["--indent", "--compact"],
+        convert: |input, options| {
+            if options.compact {
+                html::minify(input)
+            } else {
+                html::format(input, options.indent)
+            }
+        },
+    },
+    Command {
+        name: "sql-format",
+        description: "Pretty-prints or compacts SQL, with keywords in upper case.",
+        options: &["--indent", "--compact"],
+        convert: |input, options| {
+            sql::format(
+                input,
+                options.indent,
+                sql::KeywordCase::Upper,
+                options.compact,
+            )
+        },
+    },
+];
+
+/// Runs the subcommand named by the first argument, reading its input from a
+/// file or stdin and printing the result to stdout. Returns `None` if the
+/// arguments do not name a subcommand, so that the app can start as usual.
+pub fn run_cli(args: &[String]) -> Option<ExitCode> {
+    let name = args.first()?;
+    if matches!(name.as_str(), "help" | "--help" | "-h") {
+        print_usage();
+        return Some(ExitCode::SUCCESS);
+    }
+    let command = COMMANDS.iter().find(|command| command.name == name)?;
+
+    let result = parse_options(command, &args[1..]).and_then(|(options, path)| {
+        let input = read_input(path)?;
+        (command.convert)(&input, &options).map_err(|err| err.to_string())
+    });
+    match result {
+        Ok(output) => {
+            println!("{}", output.trim_end_matches('\n'));
+            Some(ExitCode::SUCCESS)
+        }
+        Err(err) => {
+            eprintln!("utools {}: {}", command.name, err);
+            Some(ExitCode::FAILURE)
+        }
+    }
+}
+
+/// Parses the arguments after the subcommand's name into its options and an
+/// optional input path. Indentation defaults to the one in the settings.
+fn parse_options<'a>(
+    command: &Command,
+    args: &'a [String],
+) -> Result<(Options, Option<&'a str>), String> {
+    let mut options = Options {
+        indent: Settings::load().indentation,
+        compact: false,
+    };
+    let mut path = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            if path.replace(arg.as_str()).is_some() {
+                return Err("expected at most one input file".to_string());
+            }
+            continue;
+        }
+        if !command.options.contains(&arg.as_str()) {
+            return Err(format!("unknown option {}", arg));
+        }
+        match arg.as_str() {
+            "--indent" => {
+                options.indent = args
+                    .next()
+                    .and_then(|value| value.parse().ok())
+                    .ok_or("--indent expects a number of spaces")?;
+            }
+            "--compact" => options.compact = true,
+            _ => unreachable!(),
+        }
+    }
+    Ok((options, path))
+}
+
+/// Reads the input from the file at `path`, or from stdin if there is none
+/// or it is `-`.
+fn read_input(path: Option<&str>) -> Result<String, String> {
+    match path {
+        Some(path) if path != "-" => {
+            fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))
+        }
+        _ => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| err.to_string())?;
+            Ok(input)
+        }
+    }
+}
+
+fn print_usage() {
+    println!("Usage: utools [TOOL]");
+    println!("       utools <COMMAND> [OPTIONS] [FILE]");
+    println!();
+    println!("Opens the app, at TOOL if given, or runs COMMAND on FILE or stdin and");
+    println!("prints the result.");
+    println!();
+    println!("Commands:");
+    for command in COMMANDS {
+        println!("  {:<15} {}", command.name, command.description);
+    }
+    println!();
+    println!("Options:");
+    println!("  --indent <N>    Indents formatted output by N spaces");
+    println!("  --compact       Compacts instead of pretty-printing");
+}
@@ -0,0 +1,307 @@
+use gpui::{
+    App, AppContext, Context, Entity, FocusHandle, Focusable, ParentElement, Render, SharedString,
+    Styled, Subscription, Window, div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, StyledExt,
+    clipboard::Clipboard,
+    color_picker::{ColorPicker, ColorPickerEvent, ColorPickerState},
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    color_preview::{from_hsla, to_hsla},
+    core::color::{self, Color},
+    editor_font,
+};
+
+/// How far each lighten or darken step moves the lightness, in percent.
+const STEP: f32 = 10.;
+
+pub struct ColorConverterTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    picker: Entity<ColorPickerState>,
+    hex: Entity<InputState>,
+    rgb: Entity<InputState>,
+    hsl: Entity<InputState>,
+    name: Entity<InputState>,
+    color: Option<Color>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ColorConverterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Color, e.g. #ff8800, rgb(255 136 0), or tomato")
+        });
+        let picker = cx.new(|cx| ColorPickerState::new(window, cx));
+        let hex = cx.new(|cx| InputState::new(window, cx));
+        let rgb = cx.new(|cx| InputState::new(window, cx));
+        let hsl = cx.new(|cx| InputState::new(window, cx));
+        let name = cx.new(|cx| InputState::new(window, cx));
+        let _subscriptions = vec![
+            cx.subscribe_in(&input, window, Self::on_input_event),
+            cx.subscribe_in(&picker, window, Self::on_picker_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            picker,
+            hex,
+            rgb,
+            hsl,
+            name,
+            color: None,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            let value = self.input.read(cx).value().clone();
+            let color = if value.trim().is_empty() {
+                self.error = None;
+                None
+            } else {
+                match color::parse(&value) {
+                    Ok(color) => {
+                        self.error = None;
+                        Some(color)
+                    }
+                    Err(err) => {
+                        self.error = Some(err.context("Invalid color"));
+                        None
+                    }
+                }
+            };
+            self.color = color;
+            if let Some(color) = color {
+                self.picker.update(cx, |state, cx| {
+                    state.set_value(to_hsla(color), window, cx);
+                });
+            }
+            let fields = [
+                (&self.hex, color.map(Color::to_hex)),
+                (&self.rgb, color.map(Color::to_rgb_string)),
+                (&self.hsl, color.map(Color::to_hsl_string)),
+                (&self.name, color.and_then(Color::name).map(str::to_string)),
+            ];
+            for (state, value) in fields {
+                state.update(cx, |state, cx| {
+                    state.set_value(value.unwrap_or_default(), window, cx);
+                });
+            }
+            cx.notify();
+        }
+    }
+
+    fn on_picker_event(
+        &mut self,
+        _: &Entity<ColorPickerState>,
+        e: &ColorPickerEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let ColorPickerEvent::Change(Some(color)) = e else {
+            return;
+        };
+        let hex = from_hsla(*color).to_hex();
+        self.input.update(cx, |state, cx| {
+            state.set_value(hex, window, cx);
+        });
+    }
+}
+
+impl Tool for ColorConverterTool {
+    fn title() -> &'static str {
+        "Color Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "Color"
+    }
+
+    fn description() -> &'static str {
+        "Converts colors between hex, rgb(), hsl(), and CSS names, with lighter and darker shades."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.input.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
+}
+
+impl Focusable for ColorConverterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ColorConverterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let border = cx.theme().border;
+        let muted = cx.theme().muted_foreground;
+        let hex = self.hex.read(cx).value().clone();
+        let rgb = self.rgb.read(cx).value().clone();
+        let hsl = self.hsl.read(cx).value().clone();
+        let name = self.name.read(cx).value().clone();
+
+        let output = |label: &'static str, state: &Entity<InputState>, value: SharedString| {
+            div()
+                .v_flex()
+                .w_full()
+                .gap_2()
+                .child(Label::new(label))
+                .child(
+                    TextInput::new(state)
+                        .font_family(editor_font(cx))
+                        .text_size(px(15.))
+                        .focus_bordered(false)
+                        .suffix(
+                            Clipboard::new(SharedString::from(format!(
+                                "{}-clipboard",
+                                label.to_lowercase()
+                            )))
+                            .value_fn(move |_, _| value.clone()),
+                        ),
+                )
+        };
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.input)
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(ColorPicker::new(&self.picker).label("Pick")),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_4()
+                    .items_start()
+                    .child(
+                        div()
+                            .size(px(144.))
+                            .flex_none()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(border)
+                            .when_some(self.color, |this, color| this.bg(to_hsla(color))),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(output("Hex", &self.hex, hex))
+                            .child(output("RGB", &self.rgb, rgb)),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(output("HSL", &self.hsl, hsl))
+                            .child(output("Name", &self.name, name)),
+                    ),
+            )
+            .child(Label::new("Shades"))
+            .child(
+                h_flex().w_full().gap_2().children(
+                    self.color
+                        .map(|color| {
+                            (-4..=4).map(move |step| {
+                                let shade = color.adjust_lightness(step as f32 * STEP);
+                                let hex = shade.to_hex();
+                                div()
+                                    .v_flex()
+                                    .flex_1()
+                                    .gap_1()
+                                    .items_center()
+                                    .child(
+                                        div()
+                                            .w_full()
+                                            .h(px(48.))
+                                            .rounded_md()
+                                            .border_1()
+                                            .border_color(border)
+                                            .bg(to_hsla(shade)),
+                                    )
+                                    .child(div().text_color(muted).child(match step {
+                                        0 => "Base".to_string(),
+                                        step if step < 0 => {
+                                            format!("Darken {}%", -step * STEP as i32)
+                                        }
+                                        step => format!("Lighten {}%", step * STEP as i32),
+                                    }))
+                                    .child(
+                                        h_flex()
+                                            .gap_1()
+                                            .font_family(editor_font(cx))
+                                            .child(hex.clone())
+                                            .child(
+                                                Clipboard::new(SharedString::from(format!(
+                                                    "shade-clipboard-{}",
+                                                    step
+                                                )))
+                                                .value(hex),
+                                            ),
+                                    )
+                            })
+                        })
+                        .into_iter()
+                        .flatten(),
+                ),
+            )
+    }
+}
@@ -0,0 +1,26 @@
+use gpui::{Hsla, Rgba};
+
+use crate::core::color::Color;
+
+/// Converts a parsed color into one that elements can be painted with.
+pub fn to_hsla(color: Color) -> Hsla {
+    Rgba {
+        r: color.r as f32 / 255.,
+        g: color.g as f32 / 255.,
+        b: color.b as f32 / 255.,
+        a: color.a,
+    }
+    .into()
+}
+
+/// Converts a color from a picker back into 8-bit channels.
+pub fn from_hsla(color: Hsla) -> Color {
+    let rgba = color.to_rgb();
+    let channel = |c: f32| (c.clamp(0., 1.) * 255.).round() as u8;
+    Color {
+        r: channel(rgba.r),
+        g: channel(rgba.g),
+        b: channel(rgba.b),
+        a: rgba.a,
+    }
+}
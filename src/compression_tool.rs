@@ -0,0 +1,465 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::{
+        base64,
+        compression::{self, Format, Level},
+        encoding::{self, InputEncoding},
+    },
+    editor_font,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Direction {
+    Compress,
+    Decompress,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = compression_tools, no_json)]
+pub struct SetCompressionDirection(Direction);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = compression_tools, no_json)]
+pub struct SetCompressionFormat(Option<Format>);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = compression_tools, no_json)]
+pub struct SetCompressionLevel(Level);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = compression_tools, no_json)]
+pub struct SetCompressedEncoding(InputEncoding);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    direction: Direction,
+    format: Option<Format>,
+    level: Level,
+    encoding: InputEncoding,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct CompressionTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    converted: Entity<InputState>,
+    direction: Direction,
+    /// The format to write or read, or `None` to detect it when
+    /// decompressing. Compressing without a format writes gzip.
+    format: Option<Format>,
+    level: Level,
+    /// How the compressed bytes are written. Detecting reads hex or Base64
+    /// and writes Base64.
+    encoding: InputEncoding,
+    /// The sizes before and after the last conversion.
+    summary: Option<String>,
+    error: Option<ToolError>,
+}
+
+impl CompressionTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text to Compress")
+        });
+        let converted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Compressed Bytes")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            converted,
+            direction: Direction::Compress,
+            format: None,
+            level: Level::Default,
+            encoding: InputEncoding::Auto,
+            summary: None,
+            error: None,
+        }
+    }
+
+    fn convert(&mut self, value: &str) -> Result<String, ToolError> {
+        match self.direction {
+            Direction::Compress => {
+                let format = self.format.unwrap_or_default();
+                let bytes = compression::compress(value.as_bytes(), format, self.level);
+                self.summary = Some(format!(
+                    "{}: {}",
+                    format.name(),
+                    compression::summary(value.len(), bytes.len())
+                ));
+                Ok(match self.encoding {
+                    InputEncoding::Hex => encoding::to_hex(&bytes),
+                    InputEncoding::Auto | InputEncoding::Base64 => base64::encode_bytes(&bytes),
+                })
+            }
+            Direction::Decompress => {
+                let bytes = encoding::parse_bytes(value, self.encoding)?;
+                let (format, decompressed) = compression::decompress(&bytes, self.format)?;
+                let summary = compression::summary(bytes.len(), decompressed.len());
+                Ok(match String::from_utf8(decompressed) {
+                    Ok(text) => {
+                        self.summary = Some(format!("{}: {}", format.name(), summary));
+                        text
+                    }
+                    Err(err) => {
+                        self.summary = Some(format!(
+                            "{}: {}, not UTF-8 so shown as hex",
+                            format.name(),
+                            summary
+                        ));
+                        encoding::to_hex(err.as_bytes())
+                    }
+                })
+            }
+        }
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        self.summary = None;
+        match self.convert(&value) {
+            Ok(converted) => {
+                self.error = None;
+                self.converted.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(converted), window, cx);
+                });
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_converted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.converted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_compression_direction(
+        &mut self,
+        action: &SetCompressionDirection,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.direction == action.0 {
+            return;
+        }
+        self.direction = action.0;
+        self.summary = None;
+        let (source, converted) = match self.direction {
+            Direction::Compress => ("Text to Compress", "Compressed Bytes"),
+            Direction::Decompress => ("Base64 or Hex Compressed Bytes", "Decompressed Text"),
+        };
+        self.editor.update(cx, |state, cx| {
+            state.set_placeholder(source, window, cx);
+        });
+        self.converted.update(cx, |state, cx| {
+            state.set_placeholder(converted, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_action_set_compression_format(
+        &mut self,
+        action: &SetCompressionFormat,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.format = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_compression_level(
+        &mut self,
+        action: &SetCompressionLevel,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.level = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_compressed_encoding(
+        &mut self,
+        action: &SetCompressedEncoding,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.encoding = action.0;
+        cx.notify();
+    }
+}
+
+impl Tool for CompressionTool {
+    fn title() -> &'static str {
+        "GZIP/Deflate Compression"
+    }
+
+    fn short_title() -> &'static str {
+        "Compression"
+    }
+
+    fn description() -> &'static str {
+        "Compresses text with gzip, zlib, raw deflate, or Brotli, and decompresses Base64 or hex payloads, showing the sizes and compression ratio."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_converted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.converted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            direction: self.direction,
+            format: self.format,
+            level: self.level,
+            encoding: self.encoding,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_compression_direction(
+            &SetCompressionDirection(preset.direction),
+            window,
+            cx,
+        );
+        self.format = preset.format;
+        self.level = preset.level;
+        self.encoding = preset.encoding;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for CompressionTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CompressionTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let direction = self.direction;
+        let format = self.format;
+        let level = self.level;
+        let encoding = self.encoding;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_compression_direction))
+            .on_action(cx.listener(Self::on_action_set_compression_format))
+            .on_action(cx.listener(Self::on_action_set_compression_level))
+            .on_action(cx.listener(Self::on_action_set_compressed_encoding))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("convert-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("convert-button")
+                                    .label(match direction {
+                                        Direction::Compress => "Compress",
+                                        Direction::Decompress => "Decompress",
+                                    })
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                let this = this
+                                    .label("Direction")
+                                    .menu_with_check(
+                                        "Compress",
+                                        direction == Direction::Compress,
+                                        Box::new(SetCompressionDirection(Direction::Compress)),
+                                    )
+                                    .menu_with_check(
+                                        "Decompress",
+                                        direction == Direction::Decompress,
+                                        Box::new(SetCompressionDirection(Direction::Decompress)),
+                                    )
+                                    .label("Format");
+                                let this = match direction {
+                                    Direction::Compress => this,
+                                    Direction::Decompress => this.menu_with_check(
+                                        "Detect Automatically",
+                                        format.is_none(),
+                                        Box::new(SetCompressionFormat(None)),
+                                    ),
+                                };
+                                let this = Format::ALL.into_iter().fold(this, |this, option| {
+                                    let checked = match direction {
+                                        Direction::Compress => format.unwrap_or_default() == option,
+                                        Direction::Decompress => format == Some(option),
+                                    };
+                                    this.menu_with_check(
+                                        option.name(),
+                                        checked,
+                                        Box::new(SetCompressionFormat(Some(option))),
+                                    )
+                                });
+                                match direction {
+                                    Direction::Compress => Level::ALL
+                                        .into_iter()
+                                        .fold(this.label("Level"), |this, option| {
+                                            this.menu_with_check(
+                                                option.name(),
+                                                level == option,
+                                                Box::new(SetCompressionLevel(option)),
+                                            )
+                                        })
+                                        .label("Output")
+                                        .menu_with_check(
+                                            "Base64",
+                                            encoding != InputEncoding::Hex,
+                                            Box::new(SetCompressedEncoding(InputEncoding::Base64)),
+                                        )
+                                        .menu_with_check(
+                                            "Hex",
+                                            encoding == InputEncoding::Hex,
+                                            Box::new(SetCompressedEncoding(InputEncoding::Hex)),
+                                        ),
+                                    Direction::Decompress => InputEncoding::ALL.into_iter().fold(
+                                        this.label("Input"),
+                                        |this, option| {
+                                            this.menu_with_check(
+                                                option.name(),
+                                                encoding == option,
+                                                Box::new(SetCompressedEncoding(option)),
+                                            )
+                                        },
+                                    ),
+                                }
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .when_some(self.summary.clone(), |this, summary| {
+                        this.child(Label::new(summary))
+                    })
+                    .child(
+                        Button::new("copy-converted-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_converted_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.converted)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,28 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{Result, anyhow};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+/// The app's config directory, where settings, presets, state, and history
+/// are kept.
+pub fn dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("utools"))
+}
+
+/// Reads `name` from the config directory, falling back to the default if
+/// there is no such file or it cannot be read.
+pub fn load<T: Default + DeserializeOwned>(name: &str) -> T {
+    dir()
+        .and_then(|dir| fs::read_to_string(dir.join(name)).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Writes `value` to `name` in the config directory as pretty-printed JSON.
+pub fn save<T: Serialize>(name: &str, value: &T) -> Result<()> {
+    let dir = dir().ok_or_else(|| anyhow!("could not find the config directory"))?;
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join(name), serde_json::to_string_pretty(value)?)?;
+    Ok(())
+}
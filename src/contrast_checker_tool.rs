@@ -0,0 +1,448 @@
+use gpui::{
+    AnyElement, App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, IntoElement, ParentElement, Render, SharedString,
+    StatefulInteractiveElement, Styled, Subscription, Window, div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Sizable, StyledExt,
+    button::Button,
+    clipboard::Clipboard,
+    color_picker::{ColorPicker, ColorPickerEvent, ColorPickerState},
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+    tag::Tag,
+    v_flex,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    color_preview::{from_hsla, to_hsla},
+    core::color::{self, CONTRAST_LEVELS, Color, contrast_ratio, nearest_shade},
+    editor_font,
+};
+
+/// The distinct ratios that the WCAG levels need, lowest first.
+const RATIOS: [f64; 3] = [3., 4.5, 7.];
+
+const SAMPLE: &str = "The quick brown fox jumps over the lazy dog.";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Foreground,
+    Background,
+}
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    foreground: String,
+    background: String,
+}
+
+pub struct ContrastCheckerTool {
+    focus_handle: FocusHandle,
+    foreground: Entity<InputState>,
+    background: Entity<InputState>,
+    foreground_picker: Entity<ColorPickerState>,
+    background_picker: Entity<ColorPickerState>,
+    colors: Option<(Color, Color)>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ContrastCheckerTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let foreground = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("Text color, e.g. #777777 or dimgray")
+        });
+        let background = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("Background color, e.g. #ffffff or white")
+        });
+        let foreground_picker = cx.new(|cx| ColorPickerState::new(window, cx));
+        let background_picker = cx.new(|cx| ColorPickerState::new(window, cx));
+        let _subscriptions = vec![
+            cx.subscribe_in(&foreground, window, Self::on_input_event),
+            cx.subscribe_in(&background, window, Self::on_input_event),
+            cx.subscribe_in(&foreground_picker, window, |this, _, e, window, cx| {
+                this.on_picker_event(Side::Foreground, e, window, cx)
+            }),
+            cx.subscribe_in(&background_picker, window, |this, _, e, window, cx| {
+                this.on_picker_event(Side::Background, e, window, cx)
+            }),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            foreground,
+            background,
+            foreground_picker,
+            background_picker,
+            colors: None,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn input(&self, side: Side) -> &Entity<InputState> {
+        match side {
+            Side::Foreground => &self.foreground,
+            Side::Background => &self.background,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_colors(window, cx);
+        }
+    }
+
+    fn on_picker_event(
+        &mut self,
+        side: Side,
+        e: &ColorPickerEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let ColorPickerEvent::Change(Some(color)) = e else {
+            return;
+        };
+        self.set_color(side, from_hsla(*color).to_hex(), window, cx);
+    }
+
+    fn set_color(
+        &mut self,
+        side: Side,
+        value: String,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.input(side).update(cx, |state, cx| {
+            state.set_value(value, window, cx);
+        });
+    }
+
+    fn update_colors(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let parse = |state: &Entity<InputState>, what: &str| {
+            let value = state.read(cx).value().clone();
+            match value.trim() {
+                "" => Ok(None),
+                value => color::parse(value)
+                    .map(Some)
+                    .map_err(|err| err.context(&format!("Invalid {} color", what))),
+            }
+        };
+        let colors = parse(&self.foreground, "text")
+            .and_then(|foreground| Ok((foreground, parse(&self.background, "background")?)));
+        self.colors = None;
+        self.error = None;
+        match colors {
+            Ok((foreground, background)) => {
+                let pickers = [
+                    (&self.foreground_picker, foreground),
+                    (&self.background_picker, background),
+                ];
+                for (picker, color) in pickers {
+                    if let Some(color) = color {
+                        picker.update(cx, |state, cx| {
+                            state.set_value(to_hsla(color), window, cx);
+                        });
+                    }
+                }
+                self.colors = foreground.zip(background);
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_swap_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let foreground = self.foreground.read(cx).value().to_string();
+        let background = self.background.read(cx).value().to_string();
+        self.set_color(Side::Foreground, background, window, cx);
+        self.set_color(Side::Background, foreground, window, cx);
+    }
+
+    /// Lists, for each ratio the colors fall short of, the nearest text and
+    /// background shades that reach it.
+    fn render_suggestions(
+        &self,
+        foreground: Color,
+        background: Color,
+        cx: &mut Context<Self>,
+    ) -> Vec<AnyElement> {
+        let border = cx.theme().border;
+        let muted = cx.theme().muted_foreground;
+        let ratio = contrast_ratio(foreground, background);
+
+        RATIOS
+            .into_iter()
+            .enumerate()
+            .filter(|(_, target)| ratio < *target)
+            .map(|(ix, target)| {
+                let levels = CONTRAST_LEVELS
+                    .iter()
+                    .filter(|(_, level)| *level == target)
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let shades = [
+                    (
+                        Side::Foreground,
+                        nearest_shade(foreground, target, |shade| {
+                            contrast_ratio(shade, background)
+                        }),
+                    ),
+                    (
+                        Side::Background,
+                        nearest_shade(background, target, |shade| {
+                            contrast_ratio(foreground, shade)
+                        }),
+                    ),
+                ];
+
+                h_flex()
+                    .gap_4()
+                    .py_2()
+                    .border_b_1()
+                    .border_color(border)
+                    .child(
+                        v_flex()
+                            .w(px(200.))
+                            .flex_none()
+                            .child(format!("{}:1", target))
+                            .child(div().text_color(muted).child(levels)),
+                    )
+                    .children(shades.into_iter().map(|(side, shade)| {
+                        let name = match side {
+                            Side::Foreground => "Text",
+                            Side::Background => "Background",
+                        };
+                        let Some(shade) = shade else {
+                            return div()
+                                .flex_1()
+                                .text_color(muted)
+                                .child(format!("No {} shade reaches it", name.to_lowercase()))
+                                .into_any_element();
+                        };
+                        let hex = shade.to_hex();
+                        h_flex()
+                            .flex_1()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .size(px(24.))
+                                    .flex_none()
+                                    .rounded_md()
+                                    .border_1()
+                                    .border_color(border)
+                                    .bg(to_hsla(shade)),
+                            )
+                            .child(
+                                div()
+                                    .font_family(editor_font(cx))
+                                    .child(format!("{} {}", name, hex)),
+                            )
+                            .child(
+                                Clipboard::new(SharedString::from(format!(
+                                    "{}-clipboard-{}",
+                                    name.to_lowercase(),
+                                    ix
+                                )))
+                                .value(hex.clone()),
+                            )
+                            .child(
+                                Button::new(SharedString::from(format!(
+                                    "use-{}-button-{}",
+                                    name.to_lowercase(),
+                                    ix
+                                )))
+                                .label("Use")
+                                .small()
+                                .on_click(cx.listener(
+                                    move |this, _, window, cx| {
+                                        this.set_color(side, hex.clone(), window, cx);
+                                    },
+                                )),
+                            )
+                            .into_any_element()
+                    }))
+                    .into_any_element()
+            })
+            .collect()
+    }
+
+    fn render_results(
+        &self,
+        foreground: Color,
+        background: Color,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let border = cx.theme().border;
+        let muted = cx.theme().muted_foreground;
+        let ratio = contrast_ratio(foreground, background);
+        let suggestions = self.render_suggestions(foreground, background, cx);
+
+        v_flex()
+            .gap_2()
+            .child(
+                v_flex()
+                    .p_4()
+                    .gap_2()
+                    .rounded_md()
+                    .border_1()
+                    .border_color(border)
+                    .bg(to_hsla(background))
+                    .text_color(to_hsla(foreground))
+                    .child(div().text_size(px(14.)).child(SAMPLE))
+                    .child(div().text_size(px(24.)).child(SAMPLE)),
+            )
+            .child(
+                h_flex()
+                    .gap_4()
+                    .mt_2()
+                    .child(
+                        div()
+                            .text_size(px(32.))
+                            .font_semibold()
+                            // Rounded down, so that a ratio just short of a
+                            // level is not shown as reaching it.
+                            .child(format!("{:.2}:1", (ratio * 100.).floor() / 100.)),
+                    )
+                    .child(div().text_color(muted).child("Contrast Ratio")),
+            )
+            .children(CONTRAST_LEVELS.iter().map(|(name, level)| {
+                h_flex()
+                    .h(px(36.))
+                    .gap_4()
+                    .px_2()
+                    .border_b_1()
+                    .border_color(border)
+                    .child(div().w(px(200.)).flex_none().child(*name))
+                    .child(
+                        div()
+                            .w(px(80.))
+                            .flex_none()
+                            .text_color(muted)
+                            .child(format!("{}:1", level)),
+                    )
+                    .child(match ratio >= *level {
+                        true => Tag::success().child("Pass"),
+                        false => Tag::danger().child("Fail"),
+                    })
+            }))
+            .when(!suggestions.is_empty(), |this| {
+                this.child(Label::new("Nearest Passing Shades").mt_4())
+                    .children(suggestions)
+            })
+            .into_any_element()
+    }
+}
+
+impl Tool for ContrastCheckerTool {
+    fn title() -> &'static str {
+        "Contrast Checker"
+    }
+
+    fn short_title() -> &'static str {
+        "Contrast"
+    }
+
+    fn description() -> &'static str {
+        "Checks the contrast between text and background colors against WCAG AA and AAA, and suggests the nearest shades that pass."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        let state = State {
+            foreground: self.foreground.read(cx).value().to_string(),
+            background: self.background.read(cx).value().to_string(),
+        };
+        serde_json::to_value(state).ok()
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(state) = State::deserialize(state) else {
+            return;
+        };
+        self.set_color(Side::Foreground, state.foreground, window, cx);
+        self.set_color(Side::Background, state.background, window, cx);
+    }
+}
+
+impl Focusable for ContrastCheckerTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ContrastCheckerTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let results = self
+            .colors
+            .map(|(foreground, background)| self.render_results(foreground, background, cx));
+        let font = editor_font(cx);
+        let input = |state: &Entity<InputState>, picker: &Entity<ColorPickerState>| {
+            h_flex()
+                .flex_1()
+                .gap_2()
+                .child(
+                    div().flex_1().child(
+                        TextInput::new(state)
+                            .font_family(font.clone())
+                            .text_size(px(15.))
+                            .focus_bordered(false),
+                    ),
+                )
+                .child(ColorPicker::new(picker).label("Pick"))
+        };
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(input(&self.foreground, &self.foreground_picker))
+                    .child(
+                        Button::new("swap-button")
+                            .label("Swap")
+                            .on_click(cx.listener(Self::on_swap_click)),
+                    )
+                    .child(input(&self.background, &self.background_picker)),
+            )
+            .child(
+                div()
+                    .id("results")
+                    .flex_1()
+                    .mt_2()
+                    .overflow_y_scroll()
+                    .children(results),
+            )
+    }
+}
@@ -0,0 +1,43 @@
+//! The conversions behind the tools, as plain functions that need no window
+//! and can be tested and used from the command line.
+
+pub mod base64;
+pub mod binary_format;
+pub mod certificate;
+pub mod checksum;
+pub mod cipher;
+pub mod codegen;
+pub mod color;
+pub mod compression;
+pub mod css_unit;
+pub mod csv;
+pub mod data_url;
+pub mod diff;
+pub mod duration;
+pub mod emoji;
+pub mod encoding;
+pub mod escape;
+pub mod figlet;
+pub mod gradient;
+pub mod html;
+pub mod http;
+pub mod id;
+pub mod json;
+pub mod lines;
+pub mod mac;
+pub mod markdown;
+pub mod numeral;
+pub mod oauth;
+pub mod password;
+pub mod protobuf;
+pub mod replace;
+pub mod saml;
+pub mod shadow;
+pub mod similarity;
+pub mod slug;
+pub mod sql;
+pub mod timestamp;
+pub mod toml;
+pub mod unicode;
+pub mod url;
+pub mod xml;
@@ -0,0 +1,154 @@
+use ::base64::{Engine as _, engine::general_purpose};
+
+use crate::ToolError;
+
+pub fn encode(value: &str) -> String {
+    general_purpose::STANDARD.encode(value)
+}
+
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    general_purpose::STANDARD.encode(bytes)
+}
+
+pub fn decode(value: &str) -> Result<String, ToolError> {
+    Ok(String::from_utf8(decode_bytes(value)?)?)
+}
+
+pub fn decode_bytes(value: &str) -> Result<Vec<u8>, ToolError> {
+    general_purpose::STANDARD
+        .decode(value)
+        .map_err(|err| ToolError::from(err).context("Invalid Base64"))
+}
+
+/// The image formats that decoded bytes are recognised as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageType {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+    Bmp,
+    Svg,
+}
+
+impl ImageType {
+    /// Recognises an image by the signature its bytes start with.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n', ..] => Some(Self::Png),
+            [0xff, 0xd8, 0xff, ..] => Some(Self::Jpeg),
+            [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(Self::Gif),
+            [
+                b'R',
+                b'I',
+                b'F',
+                b'F',
+                _,
+                _,
+                _,
+                _,
+                b'W',
+                b'E',
+                b'B',
+                b'P',
+                ..,
+            ] => Some(Self::Webp),
+            [b'B', b'M', _, _, _, _, 0, 0, 0, 0, ..] => Some(Self::Bmp),
+            _ if is_svg(bytes) => Some(Self::Svg),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Png => "PNG",
+            Self::Jpeg => "JPEG",
+            Self::Gif => "GIF",
+            Self::Webp => "WebP",
+            Self::Bmp => "BMP",
+            Self::Svg => "SVG",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Gif => "gif",
+            Self::Webp => "webp",
+            Self::Bmp => "bmp",
+            Self::Svg => "svg",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Gif => "image/gif",
+            Self::Webp => "image/webp",
+            Self::Bmp => "image/bmp",
+            Self::Svg => "image/svg+xml",
+        }
+    }
+}
+
+/// Recognises SVG, which has no signature, by an `<svg` root element at the
+/// start of the text, after any XML declaration, comments or doctype.
+fn is_svg(bytes: &[u8]) -> bool {
+    let bytes = bytes.strip_prefix(b"\xef\xbb\xbf").unwrap_or(bytes);
+    let text = String::from_utf8_lossy(&bytes[..bytes.len().min(1024)]);
+    let text = text.trim_start();
+    text.starts_with("<svg")
+        || ((text.starts_with("<?xml") || text.starts_with("<!")) && text.contains("<svg"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_text() {
+        assert_eq!(encode("hello, world"), "aGVsbG8sIHdvcmxk");
+        assert_eq!(decode("aGVsbG8sIHdvcmxk").unwrap(), "hello, world");
+    }
+
+    #[test]
+    fn rejects_invalid_base64() {
+        let err = decode("not base64!").unwrap_err();
+        assert!(err.to_string().starts_with("Invalid Base64"));
+    }
+
+    #[test]
+    fn rejects_bytes_that_are_not_utf8() {
+        assert!(decode("/w==").is_err());
+        assert_eq!(decode_bytes("/w==").unwrap(), [0xff]);
+    }
+
+    #[test]
+    fn detects_images_by_their_signature() {
+        let png = decode_bytes("iVBORw0KGgo=").unwrap();
+        assert_eq!(ImageType::detect(&png), Some(ImageType::Png));
+        assert_eq!(ImageType::detect(b"GIF89a..."), Some(ImageType::Gif));
+        assert_eq!(
+            ImageType::detect(&[0xff, 0xd8, 0xff, 0xe0]),
+            Some(ImageType::Jpeg)
+        );
+        assert_eq!(
+            ImageType::detect(b"RIFF\0\0\0\0WEBPVP8 "),
+            Some(ImageType::Webp)
+        );
+        assert_eq!(ImageType::detect(b"RIFF\0\0\0\0WAVEfmt "), None);
+        assert_eq!(
+            ImageType::detect(b"BM6\0\0\0\0\0\0\0"),
+            Some(ImageType::Bmp)
+        );
+        assert_eq!(
+            ImageType::detect(b"<?xml version=\"1.0\"?>\n<svg xmlns=\"...\"/>"),
+            Some(ImageType::Svg)
+        );
+        assert_eq!(ImageType::detect(b"<html><svg></svg></html>"), None);
+        assert_eq!(ImageType::detect(b"BMW"), None);
+        assert_eq!(ImageType::detect(b"hello"), None);
+    }
+}
@@ -0,0 +1,582 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+
+use crate::ToolError;
+
+/// How deeply arrays and maps are nested before decoding gives up.
+const MAX_DEPTH: usize = 256;
+
+/// The binary formats that can be converted to and from JSON.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Format {
+    #[default]
+    MessagePack,
+    CBOR,
+}
+
+impl Format {
+    pub const ALL: [Self; 2] = [Self::MessagePack, Self::CBOR];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessagePack => "MessagePack",
+            Self::CBOR => "CBOR",
+        }
+    }
+}
+
+fn big_endian(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |n, &byte| n << 8 | u64::from(byte))
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    depth: usize,
+}
+
+impl Reader<'_> {
+    fn error(&self, message: impl Into<String>) -> ToolError {
+        ToolError::new(message).at_offset(self.offset)
+    }
+
+    fn take(&mut self, length: u64) -> Result<&[u8], ToolError> {
+        let left = self.bytes.len() - self.offset;
+        if (left as u64) < length {
+            return Err(self.error(format!(
+                "value needs {} bytes but only {} are left",
+                length, left
+            )));
+        }
+        let bytes = &self.bytes[self.offset..self.offset + length as usize];
+        self.offset += length as usize;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, ToolError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn uint(&mut self, size: usize) -> Result<u64, ToolError> {
+        Ok(big_endian(self.take(size as u64)?))
+    }
+
+    fn text(&mut self, length: u64) -> Result<String, ToolError> {
+        let start = self.offset;
+        let bytes = self.take(length)?.to_vec();
+        String::from_utf8(bytes).map_err(|err| {
+            ToolError::new("string is not valid UTF-8")
+                .at_offset(start + err.utf8_error().valid_up_to())
+        })
+    }
+
+    fn nest(&mut self) -> Result<(), ToolError> {
+        self.depth += 1;
+        match self.depth > MAX_DEPTH {
+            true => Err(self.error("values are nested too deeply")),
+            false => Ok(()),
+        }
+    }
+
+    fn msgpack_array(&mut self, length: u64) -> Result<Value, ToolError> {
+        self.nest()?;
+        let items = (0..length)
+            .map(|_| self.msgpack())
+            .collect::<Result<_, _>>()?;
+        self.depth -= 1;
+        Ok(Value::Array(items))
+    }
+
+    fn msgpack_map(&mut self, length: u64) -> Result<Value, ToolError> {
+        self.nest()?;
+        let mut map = Map::new();
+        for _ in 0..length {
+            let key = key_string(self.msgpack()?);
+            map.insert(key, self.msgpack()?);
+        }
+        self.depth -= 1;
+        Ok(Value::Object(map))
+    }
+
+    fn msgpack_ext(&mut self, length: u64) -> Result<Value, ToolError> {
+        let start = self.offset;
+        let ext_type = self.byte()? as i8;
+        let data = self.take(length)?;
+        if ext_type != -1 {
+            return Ok(ext_value(i64::from(ext_type), data));
+        }
+        // The timestamp extension holds seconds and nanoseconds in one of
+        // three layouts, told apart by their length.
+        let (seconds, nanoseconds) = match data.len() {
+            4 => (big_endian(data) as i64, 0),
+            8 => {
+                let n = big_endian(data);
+                ((n & 0x3_ffff_ffff) as i64, (n >> 34) as u32)
+            }
+            12 => (big_endian(&data[4..]) as i64, big_endian(&data[..4]) as u32),
+            _ => return Ok(ext_value(-1, data)),
+        };
+        DateTime::from_timestamp(seconds, nanoseconds)
+            .map(|datetime| Value::String(datetime.to_rfc3339()))
+            .ok_or_else(|| ToolError::new("timestamp is out of range").at_offset(start))
+    }
+
+    fn msgpack(&mut self) -> Result<Value, ToolError> {
+        let start = self.offset;
+        let marker = self.byte()?;
+        Ok(match marker {
+            0x00..=0x7f => Value::from(marker),
+            0x80..=0x8f => self.msgpack_map(u64::from(marker & 0x0f))?,
+            0x90..=0x9f => self.msgpack_array(u64::from(marker & 0x0f))?,
+            0xa0..=0xbf => Value::String(self.text(u64::from(marker & 0x1f))?),
+            0xc0 => Value::Null,
+            0xc2 => Value::Bool(false),
+            0xc3 => Value::Bool(true),
+            0xc4..=0xc6 => {
+                let length = self.uint(1 << (marker - 0xc4))?;
+                bytes_value(self.take(length)?)
+            }
+            0xc7..=0xc9 => {
+                let length = self.uint(1 << (marker - 0xc7))?;
+                self.msgpack_ext(length)?
+            }
+            0xca => float(f64::from(f32::from_bits(self.uint(4)? as u32)), start)?,
+            0xcb => float(f64::from_bits(self.uint(8)?), start)?,
+            0xcc..=0xcf => Value::from(self.uint(1 << (marker - 0xcc))?),
+            0xd0..=0xd3 => {
+                let size = 1 << (marker - 0xd0);
+                let n = self.uint(size)?;
+                // Sign-extends from the value's own width.
+                let shift = 64 - 8 * size;
+                Value::from(((n << shift) as i64) >> shift)
+            }
+            0xd4..=0xd8 => self.msgpack_ext(1 << (marker - 0xd4))?,
+            0xd9..=0xdb => {
+                let length = self.uint(1 << (marker - 0xd9))?;
+                Value::String(self.text(length)?)
+            }
+            0xdc | 0xdd => {
+                let length = self.uint(2 << (marker - 0xdc))?;
+                self.msgpack_array(length)?
+            }
+            0xde | 0xdf => {
+                let length = self.uint(2 << (marker - 0xde))?;
+                self.msgpack_map(length)?
+            }
+            0xe0..=0xff => Value::from(marker as i8),
+            0xc1 => {
+                return Err(ToolError::new("0xc1 is never used").at_offset(start));
+            }
+        })
+    }
+
+    /// Reads a CBOR head: the major type and its argument, or `None` as the
+    /// argument for an indefinite length.
+    fn cbor_head(&mut self) -> Result<(u8, Option<u64>), ToolError> {
+        let start = self.offset;
+        let initial = self.byte()?;
+        let argument = match initial & 0x1f {
+            n @ 0..=23 => Some(u64::from(n)),
+            n @ 24..=27 => Some(self.uint(1 << (n - 24))?),
+            31 if matches!(initial >> 5, 2..=5 | 7) => None,
+            n => {
+                return Err(
+                    ToolError::new(format!("invalid additional information {}", n))
+                        .at_offset(start),
+                );
+            }
+        };
+        Ok((initial >> 5, argument))
+    }
+
+    fn is_break(&self) -> bool {
+        self.bytes.get(self.offset) == Some(&0xff)
+    }
+
+    /// Reads the chunks of an indefinite-length byte or text string, each a
+    /// definite-length string of the same major type.
+    fn cbor_chunks(&mut self, major: u8) -> Result<Vec<u8>, ToolError> {
+        let mut bytes = Vec::new();
+        while !self.is_break() {
+            let start = self.offset;
+            match self.cbor_head()? {
+                (chunk_major, Some(length)) if chunk_major == major => {
+                    bytes.extend_from_slice(self.take(length)?)
+                }
+                _ => {
+                    return Err(
+                        ToolError::new("invalid chunk in an indefinite-length string")
+                            .at_offset(start),
+                    );
+                }
+            }
+        }
+        self.offset += 1;
+        Ok(bytes)
+    }
+
+    fn cbor(&mut self) -> Result<Value, ToolError> {
+        let start = self.offset;
+        let (major, argument) = self.cbor_head()?;
+        Ok(match (major, argument) {
+            (0, Some(n)) => Value::from(n),
+            (1, Some(n)) => match i64::try_from(n) {
+                Ok(n) => Value::from(-1 - n),
+                Err(_) => float(-1.0 - n as f64, start)?,
+            },
+            (2, Some(length)) => bytes_value(self.take(length)?),
+            (2, None) => bytes_value(&self.cbor_chunks(2)?),
+            (3, Some(length)) => Value::String(self.text(length)?),
+            (3, None) => {
+                let bytes = self.cbor_chunks(3)?;
+                Value::String(
+                    String::from_utf8(bytes).map_err(|_| {
+                        ToolError::new("string is not valid UTF-8").at_offset(start)
+                    })?,
+                )
+            }
+            (4, length) => {
+                self.nest()?;
+                let mut items = Vec::new();
+                match length {
+                    Some(length) => {
+                        for _ in 0..length {
+                            items.push(self.cbor()?);
+                        }
+                    }
+                    None => {
+                        while !self.is_break() {
+                            items.push(self.cbor()?);
+                        }
+                        self.offset += 1;
+                    }
+                }
+                self.depth -= 1;
+                Value::Array(items)
+            }
+            (5, length) => {
+                self.nest()?;
+                let mut map = Map::new();
+                let mut remaining = length;
+                while remaining.map_or(!self.is_break(), |n| n > 0) {
+                    let key = key_string(self.cbor()?);
+                    map.insert(key, self.cbor()?);
+                    remaining = remaining.map(|n| n - 1);
+                }
+                if remaining.is_none() {
+                    self.offset += 1;
+                }
+                self.depth -= 1;
+                Value::Object(map)
+            }
+            // JSON has nowhere to put tags, so only the tagged value is kept.
+            // Tags can wrap tags, so they count towards the depth too.
+            (6, Some(_)) => {
+                self.nest()?;
+                let value = self.cbor()?;
+                self.depth -= 1;
+                value
+            }
+            (7, Some(20)) => Value::Bool(false),
+            (7, Some(21)) => Value::Bool(true),
+            (7, Some(22 | 23)) => Value::Null,
+            (7, Some(n)) if self.bytes[start] & 0x1f == 25 => float(f16_to_f64(n as u16), start)?,
+            (7, Some(n)) if self.bytes[start] & 0x1f == 26 => {
+                float(f64::from(f32::from_bits(n as u32)), start)?
+            }
+            (7, Some(n)) if self.bytes[start] & 0x1f == 27 => float(f64::from_bits(n), start)?,
+            (7, None) => return Err(ToolError::new("unexpected break").at_offset(start)),
+            (7, Some(n)) => {
+                return Err(
+                    ToolError::new(format!("simple value {} has no JSON equivalent", n))
+                        .at_offset(start),
+                );
+            }
+            _ => return Err(ToolError::new("invalid head").at_offset(start)),
+        })
+    }
+}
+
+fn f16_to_f64(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from(bits >> 10 & 0x1f);
+    let fraction = f64::from(bits & 0x3ff);
+    sign * match exponent {
+        0 => fraction * 2f64.powi(-24),
+        31 if fraction == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (1.0 + fraction / 1024.0) * 2f64.powi(exponent - 15),
+    }
+}
+
+fn float(n: f64, offset: usize) -> Result<Value, ToolError> {
+    Number::from_f64(n)
+        .map(Value::Number)
+        .ok_or_else(|| ToolError::new(format!("{} has no JSON equivalent", n)).at_offset(offset))
+}
+
+/// JSON has no binary strings, so bytes become Base64 text.
+fn bytes_value(bytes: &[u8]) -> Value {
+    Value::String(super::base64::encode_bytes(bytes))
+}
+
+fn ext_value(ext_type: i64, data: &[u8]) -> Value {
+    let mut map = Map::new();
+    map.insert("type".to_string(), Value::from(ext_type));
+    map.insert("data".to_string(), bytes_value(data));
+    Value::Object(map)
+}
+
+/// Turns a map key into an object key. JSON only allows string keys, so
+/// other keys are written as JSON.
+fn key_string(key: Value) -> String {
+    match key {
+        Value::String(key) => key,
+        key => key.to_string(),
+    }
+}
+
+fn read(bytes: &[u8], format: Format) -> Result<Value, ToolError> {
+    let mut reader = Reader {
+        bytes,
+        offset: 0,
+        depth: 0,
+    };
+    let value = match format {
+        Format::MessagePack => reader.msgpack()?,
+        Format::CBOR => reader.cbor()?,
+    };
+    if reader.offset < bytes.len() {
+        return Err(reader.error(format!(
+            "{} more bytes after the value",
+            bytes.len() - reader.offset
+        )));
+    }
+    Ok(value)
+}
+
+/// Decodes a MessagePack or CBOR value to JSON. Without a format, both are
+/// tried, and the format that reads all of the bytes is returned with the
+/// value. Binary strings become Base64 text, and CBOR tags are dropped.
+pub fn decode(bytes: &[u8], format: Option<Format>) -> Result<(Format, Value), ToolError> {
+    let context = |format: Format| format!("Invalid {}", format.name());
+    if let Some(format) = format {
+        return read(bytes, format)
+            .map(|value| (format, value))
+            .map_err(|err| err.context(&context(format)));
+    }
+    match (read(bytes, Format::MessagePack), read(bytes, Format::CBOR)) {
+        // A map or array is a much likelier message than a lone string or
+        // number that happens to span the same bytes.
+        (Ok(msgpack), Ok(cbor)) if !msgpack.is_object() && !msgpack.is_array() => {
+            match cbor.is_object() || cbor.is_array() {
+                true => Ok((Format::CBOR, cbor)),
+                false => Ok((Format::MessagePack, msgpack)),
+            }
+        }
+        (Ok(msgpack), _) => Ok((Format::MessagePack, msgpack)),
+        (Err(_), Ok(cbor)) => Ok((Format::CBOR, cbor)),
+        (Err(err), Err(_)) => Err(err.context("Invalid MessagePack or CBOR")),
+    }
+}
+
+fn write_msgpack(value: &Value, out: &mut Vec<u8>) {
+    match value {
+        Value::Null => out.push(0xc0),
+        Value::Bool(b) => out.push(if *b { 0xc3 } else { 0xc2 }),
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                match n {
+                    0..=0x7f => out.push(n as u8),
+                    0x80..=0xff => out.extend([0xcc, n as u8]),
+                    0x100..=0xffff => out.extend(head(0xcd, n, 2)),
+                    0x1_0000..=0xffff_ffff => out.extend(head(0xce, n, 4)),
+                    _ => out.extend(head(0xcf, n, 8)),
+                }
+            } else if let Some(n) = n.as_i64() {
+                match n {
+                    -32..=-1 => out.push(n as u8),
+                    -0x80..=-33 => out.extend([0xd0, n as u8]),
+                    -0x8000..=-0x81 => out.extend(head(0xd1, n as u64, 2)),
+                    -0x8000_0000..=-0x8001 => out.extend(head(0xd2, n as u64, 4)),
+                    _ => out.extend(head(0xd3, n as u64, 8)),
+                }
+            } else {
+                out.extend(head(0xcb, n.as_f64().unwrap_or_default().to_bits(), 8));
+            }
+        }
+        Value::String(s) => {
+            let length = s.len() as u64;
+            match length {
+                0..=31 => out.push(0xa0 | length as u8),
+                32..=0xff => out.extend([0xd9, length as u8]),
+                0x100..=0xffff => out.extend(head(0xda, length, 2)),
+                _ => out.extend(head(0xdb, length, 4)),
+            }
+            out.extend(s.as_bytes());
+        }
+        Value::Array(items) => {
+            let length = items.len() as u64;
+            match length {
+                0..=15 => out.push(0x90 | length as u8),
+                16..=0xffff => out.extend(head(0xdc, length, 2)),
+                _ => out.extend(head(0xdd, length, 4)),
+            }
+            items.iter().for_each(|item| write_msgpack(item, out));
+        }
+        Value::Object(map) => {
+            let length = map.len() as u64;
+            match length {
+                0..=15 => out.push(0x80 | length as u8),
+                16..=0xffff => out.extend(head(0xde, length, 2)),
+                _ => out.extend(head(0xdf, length, 4)),
+            }
+            for (key, value) in map {
+                write_msgpack(&Value::String(key.clone()), out);
+                write_msgpack(value, out);
+            }
+        }
+    }
+}
+
+/// A marker byte followed by `n` as a big-endian number of `size` bytes.
+fn head(marker: u8, n: u64, size: usize) -> Vec<u8> {
+    let mut bytes = vec![marker];
+    bytes.extend(&n.to_be_bytes()[8 - size..]);
+    bytes
+}
+
+/// Writes a CBOR head in its shortest form, as deterministic encoding
+/// requires.
+fn cbor_head(major: u8, n: u64, out: &mut Vec<u8>) {
+    let major = major << 5;
+    match n {
+        0..=23 => out.push(major | n as u8),
+        24..=0xff => out.extend([major | 24, n as u8]),
+        0x100..=0xffff => out.extend(head(major | 25, n, 2)),
+        0x1_0000..=0xffff_ffff => out.extend(head(major | 26, n, 4)),
+        _ => out.extend(head(major | 27, n, 8)),
+    }
+}
+
+fn write_cbor(value: &Value, out: &mut Vec<u8>) {
+    match value {
+        Value::Null => out.push(0xf6),
+        Value::Bool(b) => out.push(if *b { 0xf5 } else { 0xf4 }),
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                cbor_head(0, n, out);
+            } else if let Some(n) = n.as_i64() {
+                cbor_head(1, (-1 - n) as u64, out);
+            } else {
+                out.extend(head(0xfb, n.as_f64().unwrap_or_default().to_bits(), 8));
+            }
+        }
+        Value::String(s) => {
+            cbor_head(3, s.len() as u64, out);
+            out.extend(s.as_bytes());
+        }
+        Value::Array(items) => {
+            cbor_head(4, items.len() as u64, out);
+            items.iter().for_each(|item| write_cbor(item, out));
+        }
+        Value::Object(map) => {
+            cbor_head(5, map.len() as u64, out);
+            for (key, value) in map {
+                cbor_head(3, key.len() as u64, out);
+                out.extend(key.as_bytes());
+                write_cbor(value, out);
+            }
+        }
+    }
+}
+
+/// Encodes JSON as MessagePack or CBOR, using the smallest form of each
+/// integer, string, and container length. Other numbers are written as
+/// 64-bit floats.
+pub fn encode(source: &str, format: Format) -> Result<Vec<u8>, ToolError> {
+    let value = serde_json::from_str::<Value>(source)
+        .map_err(|err| ToolError::from(err).context("Invalid JSON"))?;
+    let mut bytes = Vec::new();
+    match format {
+        Format::MessagePack => write_msgpack(&value, &mut bytes),
+        Format::CBOR => write_cbor(&value, &mut bytes),
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::encoding::{parse_hex, to_hex};
+
+    const JSON: &str = r#"{"id":300,"name":"Ada","tags":[true,null,-5,-200],"score":1.5}"#;
+
+    #[test]
+    fn encodes_messagepack() {
+        let bytes = encode(JSON, Format::MessagePack).unwrap();
+        assert_eq!(
+            to_hex(&bytes),
+            "84 a2 69 64 cd 01 2c a4 6e 61 6d 65 a3 41 64 61 a4 74 61 67 73 94 c3 c0 fb d1 ff 38 \
+             a5 73 63 6f 72 65 cb 3f f8 00 00 00 00 00 00"
+        );
+        let (format, value) = decode(&bytes, None).unwrap();
+        assert_eq!(format, Format::MessagePack);
+        assert_eq!(value, serde_json::from_str::<Value>(JSON).unwrap());
+    }
+
+    #[test]
+    fn encodes_cbor() {
+        let bytes = encode(JSON, Format::CBOR).unwrap();
+        assert_eq!(
+            to_hex(&bytes),
+            "a4 62 69 64 19 01 2c 64 6e 61 6d 65 63 41 64 61 64 74 61 67 73 84 f5 f6 24 38 c7 \
+             65 73 63 6f 72 65 fb 3f f8 00 00 00 00 00 00"
+        );
+        let (format, value) = decode(&bytes, None).unwrap();
+        assert_eq!(format, Format::CBOR);
+        assert_eq!(value, serde_json::from_str::<Value>(JSON).unwrap());
+    }
+
+    #[test]
+    fn decodes_what_json_lacks() {
+        // MessagePack: bin8, a 32-bit timestamp, and an integer map key.
+        let bytes = parse_hex("93 c4 02 01 02 d6 ff 00 00 00 3c 81 01 a1 61").unwrap();
+        assert_eq!(
+            decode(&bytes, Some(Format::MessagePack)).unwrap().1,
+            serde_json::json!(["AQI=", "1970-01-01T00:01:00+00:00", {"1": "a"}])
+        );
+        // CBOR: a tagged date string, indefinite-length text, and a
+        // half-precision float.
+        let bytes = parse_hex("83 c0 61 78 7f 61 61 62 62 63 ff f9 3e 00").unwrap();
+        assert_eq!(
+            decode(&bytes, Some(Format::CBOR)).unwrap().1,
+            serde_json::json!(["x", "abc", 1.5])
+        );
+    }
+
+    #[test]
+    fn reports_where_decoding_failed() {
+        assert_eq!(
+            decode(&[0x92, 0x01], Some(Format::MessagePack))
+                .unwrap_err()
+                .to_string(),
+            "Invalid MessagePack: value needs 1 bytes but only 0 are left (at character 3)"
+        );
+        assert_eq!(
+            decode(&[0x01, 0x02], Some(Format::CBOR))
+                .unwrap_err()
+                .to_string(),
+            "Invalid CBOR: 1 more bytes after the value (at character 2)"
+        );
+    }
+
+    #[test]
+    fn limits_nested_tags() {
+        let mut bytes = vec![0xc6; 10_000];
+        bytes.push(0x01);
+        assert!(decode(&bytes, Some(Format::CBOR)).is_err());
+        assert!(decode(&bytes, None).is_err());
+    }
+}
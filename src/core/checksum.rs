@@ -0,0 +1,141 @@
+use ::base64::{Engine as _, engine::general_purpose};
+use ring::hmac;
+use serde::{Deserialize, Serialize};
+use xxhash_rust::{xxh32::xxh32, xxh64::xxh64};
+
+/// The checksums and keyed hashes that can be calculated.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    CRC32,
+    Adler32,
+    XXH32,
+    XXH64,
+    HMACSHA256,
+    HMACSHA1,
+}
+
+impl Algorithm {
+    pub const ALL: [Self; 6] = [
+        Self::CRC32,
+        Self::Adler32,
+        Self::XXH32,
+        Self::XXH64,
+        Self::HMACSHA256,
+        Self::HMACSHA1,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CRC32 => "CRC32",
+            Self::Adler32 => "Adler-32",
+            Self::XXH32 => "xxHash32",
+            Self::XXH64 => "xxHash64",
+            Self::HMACSHA256 => "HMAC-SHA256",
+            Self::HMACSHA1 => "HMAC-SHA1",
+        }
+    }
+
+    /// Whether the algorithm takes a secret key.
+    pub fn is_keyed(&self) -> bool {
+        matches!(self, Self::HMACSHA256 | Self::HMACSHA1)
+    }
+}
+
+/// How calculated digests are written out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Encoding {
+    #[default]
+    Hex,
+    Base64,
+}
+
+/// Calculates the digest of `data`, with checksums as big-endian bytes. Only
+/// the HMACs use `key`.
+pub fn digest(algorithm: Algorithm, data: &[u8], key: &[u8]) -> Vec<u8> {
+    match algorithm {
+        Algorithm::CRC32 => crc32fast::hash(data).to_be_bytes().to_vec(),
+        Algorithm::Adler32 => {
+            let mut adler = adler2::Adler32::new();
+            adler.write_slice(data);
+            adler.checksum().to_be_bytes().to_vec()
+        }
+        Algorithm::XXH32 => xxh32(data, 0).to_be_bytes().to_vec(),
+        Algorithm::XXH64 => xxh64(data, 0).to_be_bytes().to_vec(),
+        Algorithm::HMACSHA256 => sign(hmac::HMAC_SHA256, key, data),
+        Algorithm::HMACSHA1 => sign(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, key, data),
+    }
+}
+
+pub fn encode(digest: &[u8], encoding: Encoding) -> String {
+    match encoding {
+        Encoding::Hex => digest.iter().map(|byte| format!("{:02x}", byte)).collect(),
+        Encoding::Base64 => general_purpose::STANDARD.encode(digest),
+    }
+}
+
+fn sign(algorithm: hmac::Algorithm, key: &[u8], data: &[u8]) -> Vec<u8> {
+    hmac::sign(&hmac::Key::new(algorithm, key), data)
+        .as_ref()
+        .to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(algorithm: Algorithm, data: &[u8], key: &[u8]) -> String {
+        encode(&digest(algorithm, data, key), Encoding::Hex)
+    }
+
+    #[test]
+    fn calculates_checksums() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        assert_eq!(hex(Algorithm::CRC32, data, b""), "414fa339");
+        assert_eq!(hex(Algorithm::Adler32, data, b""), "5bdc0fda");
+        assert_eq!(hex(Algorithm::Adler32, b"", b""), "00000001");
+    }
+
+    #[test]
+    fn calculates_xxhash() {
+        assert_eq!(hex(Algorithm::XXH32, b"", b""), "02cc5d05");
+        assert_eq!(hex(Algorithm::XXH32, b"abc", b""), "32d153ff");
+        assert_eq!(hex(Algorithm::XXH64, b"", b""), "ef46db3751d8e999");
+        assert_eq!(hex(Algorithm::XXH64, b"abc", b""), "44bc2cf5ad770999");
+        // Long enough to go through the striped loops.
+        let data = b"Nobody inspects the spammish repetition";
+        assert_eq!(hex(Algorithm::XXH32, data, b""), "e2293b2f");
+        assert_eq!(hex(Algorithm::XXH64, data, b""), "fbcea83c8a378bf1");
+    }
+
+    #[test]
+    fn calculates_hmacs() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        assert_eq!(
+            hex(Algorithm::HMACSHA256, data, b"key"),
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+        assert_eq!(
+            hex(Algorithm::HMACSHA1, data, b"key"),
+            "de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9"
+        );
+        // Keys longer than a block are hashed first (RFC 4231, test case 6).
+        assert_eq!(
+            hex(
+                Algorithm::HMACSHA256,
+                b"Test Using Larger Than Block-Size Key - Hash Key First",
+                &[0xaa; 131]
+            ),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn encodes_as_base64() {
+        let digest = digest(Algorithm::HMACSHA256, b"", b"");
+        assert_eq!(
+            encode(&digest, Encoding::Base64),
+            "thNnmggU2ex3L5XXeMNfxf8Wl8STcVZTxscSFEKSxa0="
+        );
+    }
+}
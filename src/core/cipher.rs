@@ -0,0 +1,269 @@
+use std::num::NonZeroU32;
+
+use ::base64::{Engine as _, engine::general_purpose};
+use argon2::{Argon2, Params, Version};
+use rand::Rng;
+use ring::{aead, pbkdf2};
+use serde::{Deserialize, Serialize};
+
+use crate::{ToolError, core::password::Cost};
+
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = aead::NONCE_LEN;
+const KEY_LENGTH: usize = 32;
+
+/// How hard key derivation works.
+struct Work {
+    pbkdf2_iterations: NonZeroU32,
+    argon2: Cost,
+}
+
+/// The OWASP recommendations for PBKDF2 with HMAC-SHA256 and Argon2id.
+const WORK: Work = Work {
+    pbkdf2_iterations: NonZeroU32::new(600_000).unwrap(),
+    argon2: Cost::RECOMMENDED,
+};
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Cipher {
+    #[default]
+    AES256GCM,
+    ChaCha20Poly1305,
+}
+
+impl Cipher {
+    pub const ALL: [Self; 2] = [Self::AES256GCM, Self::ChaCha20Poly1305];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AES256GCM => "AES-256-GCM",
+            Self::ChaCha20Poly1305 => "ChaCha20-Poly1305",
+        }
+    }
+
+    fn algorithm(&self) -> &'static aead::Algorithm {
+        match self {
+            Self::AES256GCM => &aead::AES_256_GCM,
+            Self::ChaCha20Poly1305 => &aead::CHACHA20_POLY1305,
+        }
+    }
+}
+
+/// How the key is derived from the passphrase.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum KeyDerivation {
+    #[default]
+    PBKDF2,
+    Argon2id,
+}
+
+impl KeyDerivation {
+    pub const ALL: [Self; 2] = [Self::PBKDF2, Self::Argon2id];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::PBKDF2 => "PBKDF2-SHA256",
+            Self::Argon2id => "Argon2id",
+        }
+    }
+}
+
+/// Encrypts with a key derived from the passphrase and a random salt. The
+/// result is the Base64 of the salt, the nonce, and the ciphertext with its
+/// authentication tag, in that order.
+pub fn encrypt(
+    plaintext: &[u8],
+    passphrase: &str,
+    cipher: Cipher,
+    derivation: KeyDerivation,
+    rng: &mut impl Rng,
+) -> Result<String, ToolError> {
+    encrypt_with(plaintext, passphrase, cipher, derivation, &WORK, rng)
+}
+
+pub fn decrypt(
+    encoded: &str,
+    passphrase: &str,
+    cipher: Cipher,
+    derivation: KeyDerivation,
+) -> Result<Vec<u8>, ToolError> {
+    decrypt_with(encoded, passphrase, cipher, derivation, &WORK)
+}
+
+fn encrypt_with(
+    plaintext: &[u8],
+    passphrase: &str,
+    cipher: Cipher,
+    derivation: KeyDerivation,
+    work: &Work,
+    rng: &mut impl Rng,
+) -> Result<String, ToolError> {
+    let salt: [u8; SALT_LENGTH] = rng.random();
+    let nonce: [u8; NONCE_LENGTH] = rng.random();
+    let key = key(cipher, derivation, work, passphrase, &salt)?;
+    let mut sealed = plaintext.to_vec();
+    key.seal_in_place_append_tag(
+        aead::Nonce::assume_unique_for_key(nonce),
+        aead::Aad::empty(),
+        &mut sealed,
+    )
+    .map_err(|_| ToolError::new("Encryption failed"))?;
+
+    let mut output = Vec::with_capacity(SALT_LENGTH + NONCE_LENGTH + sealed.len());
+    output.extend_from_slice(&salt);
+    output.extend_from_slice(&nonce);
+    output.extend_from_slice(&sealed);
+    Ok(general_purpose::STANDARD.encode(output))
+}
+
+fn decrypt_with(
+    encoded: &str,
+    passphrase: &str,
+    cipher: Cipher,
+    derivation: KeyDerivation,
+    work: &Work,
+) -> Result<Vec<u8>, ToolError> {
+    let bytes = general_purpose::STANDARD
+        .decode(encoded.split_whitespace().collect::<String>())
+        .map_err(|err| ToolError::new(err.to_string()).context("Invalid Base64"))?;
+    let tag_length = cipher.algorithm().tag_len();
+    if bytes.len() < SALT_LENGTH + NONCE_LENGTH + tag_length {
+        return Err(ToolError::new(format!(
+            "Expected at least {} bytes, found {}",
+            SALT_LENGTH + NONCE_LENGTH + tag_length,
+            bytes.len()
+        ))
+        .context("Invalid ciphertext"));
+    }
+    let (salt, rest) = bytes.split_at(SALT_LENGTH);
+    let (nonce, sealed) = rest.split_at(NONCE_LENGTH);
+    let nonce = aead::Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| ToolError::new("Invalid nonce"))?;
+    let key = key(cipher, derivation, work, passphrase, salt)?;
+    let mut sealed = sealed.to_vec();
+    let plaintext = key
+        .open_in_place(nonce, aead::Aad::empty(), &mut sealed)
+        .map_err(|_| {
+            ToolError::new("Wrong passphrase, cipher, or key derivation, or the data was altered")
+                .context("Decryption failed")
+        })?;
+    Ok(plaintext.to_vec())
+}
+
+fn key(
+    cipher: Cipher,
+    derivation: KeyDerivation,
+    work: &Work,
+    passphrase: &str,
+    salt: &[u8],
+) -> Result<aead::LessSafeKey, ToolError> {
+    let mut bytes = match derivation {
+        KeyDerivation::PBKDF2 => {
+            let mut bytes = vec![0; KEY_LENGTH];
+            pbkdf2::derive(
+                pbkdf2::PBKDF2_HMAC_SHA256,
+                work.pbkdf2_iterations,
+                salt,
+                passphrase.as_bytes(),
+                &mut bytes,
+            );
+            bytes
+        }
+        KeyDerivation::Argon2id => {
+            let cost = &work.argon2;
+            let params = Params::new(
+                cost.memory,
+                cost.iterations,
+                cost.parallelism,
+                Some(KEY_LENGTH),
+            )
+            .map_err(|err| ToolError::new(err.to_string()).context("Invalid Argon2 parameters"))?;
+            let mut bytes = vec![0; KEY_LENGTH];
+            Argon2::new(argon2::Algorithm::Argon2id, Version::V0x13, params)
+                .hash_password_into(passphrase.as_bytes(), salt, &mut bytes)
+                .map_err(|err| ToolError::new(err.to_string()).context("Key derivation failed"))?;
+            bytes
+        }
+    };
+    let key = aead::UnboundKey::new(cipher.algorithm(), &bytes)
+        .map_err(|_| ToolError::new("Invalid key"))?;
+    bytes.fill(0);
+    Ok(aead::LessSafeKey::new(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    /// Keeps the tests quick; the format is the same at any work factor.
+    const LIGHT: Work = Work {
+        pbkdf2_iterations: NonZeroU32::new(1000).unwrap(),
+        argon2: Cost {
+            bcrypt_cost: 4,
+            memory: 64,
+            iterations: 1,
+            parallelism: 1,
+        },
+    };
+
+    #[test]
+    fn decrypts_known_ciphertexts() {
+        // Produced with Python's cryptography package from the same layout.
+        for (cipher, encoded) in [
+            (
+                Cipher::AES256GCM,
+                "AAECAwQFBgcICQoLDA0OD2RlZmdoaWprbG1ub/c1n122/At8EhaelVov4hengVHsFFkQoMwBvIk95g==",
+            ),
+            (
+                Cipher::ChaCha20Poly1305,
+                "AAECAwQFBgcICQoLDA0OD2RlZmdoaWprbG1ub6cj/XxAYsOcNgzAOpQUM90Pc8AcjH6vE89Ox+AxhQ==",
+            ),
+        ] {
+            let plaintext = decrypt_with(
+                encoded,
+                "correct horse",
+                cipher,
+                KeyDerivation::PBKDF2,
+                &LIGHT,
+            )
+            .unwrap();
+            assert_eq!(plaintext, "Hello, wörld!".as_bytes());
+        }
+    }
+
+    #[test]
+    fn round_trips_every_combination() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for cipher in Cipher::ALL {
+            for derivation in KeyDerivation::ALL {
+                let encoded =
+                    encrypt_with(b"secret", "hunter2", cipher, derivation, &LIGHT, &mut rng)
+                        .unwrap();
+                let decrypted = decrypt_with(&encoded, "hunter2", cipher, derivation, &LIGHT);
+                assert_eq!(decrypted.unwrap(), b"secret");
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_passphrase_and_tampering() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let (cipher, derivation) = (Cipher::AES256GCM, KeyDerivation::PBKDF2);
+        let encoded =
+            encrypt_with(b"secret", "hunter2", cipher, derivation, &LIGHT, &mut rng).unwrap();
+        assert!(decrypt_with(&encoded, "hunter3", cipher, derivation, &LIGHT).is_err());
+        let other = Cipher::ChaCha20Poly1305;
+        assert!(decrypt_with(&encoded, "hunter2", other, derivation, &LIGHT).is_err());
+
+        let mut bytes = general_purpose::STANDARD.decode(&encoded).unwrap();
+        *bytes.last_mut().unwrap() ^= 1;
+        let tampered = general_purpose::STANDARD.encode(bytes);
+        assert!(decrypt_with(&tampered, "hunter2", cipher, derivation, &LIGHT).is_err());
+
+        assert!(decrypt_with("AAAA", "hunter2", cipher, derivation, &LIGHT).is_err());
+    }
+}
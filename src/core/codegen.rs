@@ -0,0 +1,760 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::ToolError;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Target {
+    #[default]
+    Rust,
+    TypeScript,
+    Go,
+}
+
+impl Target {
+    pub const ALL: [Self; 3] = [Self::Rust, Self::TypeScript, Self::Go];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Rust => "Rust",
+            Self::TypeScript => "TypeScript",
+            Self::Go => "Go",
+        }
+    }
+}
+
+/// How field names are derived from JSON keys.
+///
+/// TypeScript interfaces always use the keys as they are, since there is no
+/// way to map a property to a differently named key.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Naming {
+    /// The target language's convention, with the key kept in an attribute
+    /// or tag where it differs.
+    #[default]
+    Idiomatic,
+    /// The keys themselves, wherever they are valid identifiers.
+    Original,
+}
+
+impl Naming {
+    pub const ALL: [Self; 2] = [Self::Idiomatic, Self::Original];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Idiomatic => "Idiomatic Names",
+            Self::Original => "JSON Key Names",
+        }
+    }
+}
+
+pub struct Options<'a> {
+    pub target: Target,
+    pub naming: Naming,
+    /// Whether every field is optional, rather than only those missing from
+    /// some of the sampled objects.
+    pub all_optional: bool,
+    pub root_name: &'a str,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Kind {
+    /// Only nulls or empty arrays were seen.
+    Unknown,
+    Bool,
+    Integer,
+    Float,
+    String,
+    Array(Box<Type>),
+    Object(Vec<Field>),
+    /// Values of incompatible kinds were seen.
+    Mixed,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Type {
+    kind: Kind,
+    nullable: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Field {
+    key: String,
+    ty: Type,
+    optional: bool,
+}
+
+fn infer(value: &Value) -> Type {
+    let kind = match value {
+        Value::Null => Kind::Unknown,
+        Value::Bool(_) => Kind::Bool,
+        Value::Number(n) if n.is_f64() => Kind::Float,
+        Value::Number(_) => Kind::Integer,
+        Value::String(_) => Kind::String,
+        Value::Array(items) => Kind::Array(Box::new(
+            items.iter().map(infer).reduce(merge).unwrap_or(Type {
+                kind: Kind::Unknown,
+                nullable: false,
+            }),
+        )),
+        Value::Object(map) => Kind::Object(
+            map.iter()
+                .map(|(key, value)| Field {
+                    key: key.clone(),
+                    ty: infer(value),
+                    optional: false,
+                })
+                .collect(),
+        ),
+    };
+    Type {
+        kind,
+        nullable: value.is_null(),
+    }
+}
+
+/// Combines the types of two values seen in the same place.
+fn merge(a: Type, b: Type) -> Type {
+    let kind = match (a.kind, b.kind) {
+        (Kind::Unknown, kind) | (kind, Kind::Unknown) => kind,
+        (Kind::Integer, Kind::Float) | (Kind::Float, Kind::Integer) => Kind::Float,
+        (Kind::Array(a), Kind::Array(b)) => Kind::Array(Box::new(merge(*a, *b))),
+        (Kind::Object(a), Kind::Object(b)) => Kind::Object(merge_fields(a, b)),
+        (a, b) if a == b => a,
+        _ => Kind::Mixed,
+    };
+    Type {
+        kind,
+        nullable: a.nullable || b.nullable,
+    }
+}
+
+/// Combines the fields of two objects, making those missing from either
+/// optional.
+fn merge_fields(a: Vec<Field>, mut b: Vec<Field>) -> Vec<Field> {
+    let mut fields = Vec::with_capacity(a.len().max(b.len()));
+    for field in a {
+        match b.iter().position(|other| other.key == field.key) {
+            Some(i) => {
+                let other = b.remove(i);
+                fields.push(Field {
+                    key: field.key,
+                    ty: merge(field.ty, other.ty),
+                    optional: field.optional || other.optional,
+                });
+            }
+            None => fields.push(Field {
+                optional: true,
+                ..field
+            }),
+        }
+    }
+    fields.extend(b.into_iter().map(|field| Field {
+        optional: true,
+        ..field
+    }));
+    fields
+}
+
+/// Splits a key into words at punctuation and case changes, so that
+/// `userID`, `user_id`, and `user-id` all become `user` and `id`.
+fn words(key: &str) -> Vec<String> {
+    let chars = key.chars().collect::<Vec<_>>();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if let Some(prev) = word.chars().last()
+            && c.is_ascii_uppercase()
+        {
+            let next_lower = chars.get(i + 1).is_some_and(|c| c.is_ascii_lowercase());
+            if !prev.is_ascii_uppercase() || next_lower {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// The initialisms Go keeps in upper case, from the Go code review comments.
+const INITIALISMS: [&str; 38] = [
+    "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS", "ID", "IP",
+    "JSON", "LHS", "QPS", "RAM", "RHS", "RPC", "SLA", "SMTP", "SQL", "SSH", "TCP", "TLS", "TTL",
+    "UDP", "UI", "UID", "UUID", "URI", "URL", "UTF8", "VM", "XML", "XMPP", "XSRF", "XSS",
+];
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + &chars.as_str().to_lowercase(),
+        None => String::new(),
+    }
+}
+
+fn pascal_case(key: &str, initialisms: bool) -> String {
+    words(key)
+        .iter()
+        .map(|word| {
+            let upper = word.to_ascii_uppercase();
+            match initialisms && INITIALISMS.contains(&upper.as_str()) {
+                true => upper,
+                false => capitalize(word),
+            }
+        })
+        .collect()
+}
+
+fn snake_case(key: &str) -> String {
+    words(key)
+        .iter()
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Guesses the singular of an English plural, for naming array items.
+fn singular(key: &str) -> String {
+    let lower = key.to_ascii_lowercase();
+    if lower.len() > 3 && lower.ends_with("ies") {
+        format!("{}y", &key[..key.len() - 3])
+    } else if ["sses", "uses", "xes", "ches", "shes"]
+        .iter()
+        .any(|suffix| lower.ends_with(suffix))
+    {
+        key[..key.len() - 2].to_string()
+    } else if lower.len() > 1 && lower.ends_with('s') && !lower.ends_with("ss") {
+        key[..key.len() - 1].to_string()
+    } else {
+        key.to_string()
+    }
+}
+
+/// The type name for the items of an array found under `key`.
+fn item_name(key: &str) -> String {
+    match singular(key) {
+        item if item == key => format!("{}Item", key),
+        item => item,
+    }
+}
+
+const RUST_KEYWORDS: [&str; 51] = [
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while",
+];
+
+fn is_identifier(name: &str, extra: char) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == extra)
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == extra)
+        && name != "_"
+}
+
+fn rust_identifier(name: String) -> String {
+    match name.as_str() {
+        "crate" | "self" | "Self" | "super" => format!("{}_", name),
+        keyword if RUST_KEYWORDS.contains(&keyword) => format!("r#{}", name),
+        _ => name,
+    }
+}
+
+/// Adds a number to `name` until it is not in `taken`.
+fn unique(name: String, taken: &mut HashSet<String>) -> String {
+    let mut candidate = name.clone();
+    let mut n = 2;
+    while !taken.insert(candidate.clone()) {
+        candidate = format!("{}{}", name, n);
+        n += 1;
+    }
+    candidate
+}
+
+struct Member {
+    key: String,
+    ty: String,
+    optional: bool,
+}
+
+struct Definition {
+    name: String,
+    members: Vec<Member>,
+}
+
+struct Generator<'a> {
+    options: &'a Options<'a>,
+    type_names: HashSet<String>,
+    definitions: Vec<Definition>,
+    uses_value: bool,
+}
+
+impl Generator<'_> {
+    fn type_name(&mut self, hint: &str) -> String {
+        let name = pascal_case(hint, self.options.target == Target::Go);
+        let name = match name.chars().next() {
+            None => "Type".to_string(),
+            Some(c) if c.is_ascii_digit() => format!("Type{}", name),
+            Some(_) => name,
+        };
+        unique(name, &mut self.type_names)
+    }
+
+    /// The type of a value, without regard to whether it may be null.
+    fn base(&mut self, ty: &Type, hint: &str) -> String {
+        let target = self.options.target;
+        match &ty.kind {
+            Kind::Bool => match target {
+                Target::TypeScript => "boolean",
+                Target::Rust | Target::Go => "bool",
+            }
+            .to_string(),
+            Kind::Integer => match target {
+                Target::Rust => "i64",
+                Target::TypeScript => "number",
+                Target::Go => "int64",
+            }
+            .to_string(),
+            Kind::Float => match target {
+                Target::Rust => "f64",
+                Target::TypeScript => "number",
+                Target::Go => "float64",
+            }
+            .to_string(),
+            Kind::String => match target {
+                Target::Rust => "String",
+                Target::TypeScript | Target::Go => "string",
+            }
+            .to_string(),
+            Kind::Array(item) => {
+                let item = self.nullable(item, &item_name(hint));
+                match target {
+                    Target::Rust => format!("Vec<{}>", item),
+                    Target::TypeScript if item.contains(' ') => format!("({})[]", item),
+                    Target::TypeScript => format!("{}[]", item),
+                    Target::Go => format!("[]{}", item),
+                }
+            }
+            Kind::Object(fields) => self.define(fields, hint),
+            Kind::Unknown | Kind::Mixed => match target {
+                Target::Rust => {
+                    self.uses_value = true;
+                    "Value".to_string()
+                }
+                Target::TypeScript => "unknown".to_string(),
+                Target::Go => "any".to_string(),
+            },
+        }
+    }
+
+    /// The type of a value that may be null if `nullable` is set.
+    fn nullable(&mut self, ty: &Type, hint: &str) -> String {
+        self.wrap(ty, hint, ty.nullable)
+    }
+
+    fn wrap(&mut self, ty: &Type, hint: &str, nullable: bool) -> String {
+        let base = self.base(ty, hint);
+        // Catch-all types already hold null, and Go slices can be nil.
+        let holds_null = matches!(ty.kind, Kind::Unknown | Kind::Mixed)
+            || (self.options.target == Target::Go && matches!(ty.kind, Kind::Array(_)));
+        match self.options.target {
+            _ if !nullable || holds_null => base,
+            Target::Rust => format!("Option<{}>", base),
+            Target::TypeScript => format!("{} | null", base),
+            Target::Go => format!("*{}", base),
+        }
+    }
+
+    fn define(&mut self, fields: &[Field], hint: &str) -> String {
+        let name = self.type_name(hint);
+        // Reserving the slot first puts each type before the types it uses.
+        let index = self.definitions.len();
+        self.definitions.push(Definition {
+            name: name.clone(),
+            members: Vec::new(),
+        });
+        let members = fields
+            .iter()
+            .map(|field| {
+                let optional = field.optional || self.options.all_optional;
+                let ty = match self.options.target {
+                    // TypeScript marks optional properties on the name.
+                    Target::TypeScript => self.nullable(&field.ty, &field.key),
+                    Target::Rust | Target::Go => {
+                        self.wrap(&field.ty, &field.key, field.ty.nullable || optional)
+                    }
+                };
+                Member {
+                    key: field.key.clone(),
+                    ty,
+                    optional,
+                }
+            })
+            .collect();
+        self.definitions[index].members = members;
+        name
+    }
+
+    fn render(self, root: Option<(String, String)>) -> String {
+        let mut blocks = Vec::new();
+        if self.options.target == Target::Rust {
+            let mut imports = "use serde::{Deserialize, Serialize};".to_string();
+            if self.uses_value {
+                imports.push_str("\nuse serde_json::Value;");
+            }
+            blocks.push(imports);
+        }
+        if let Some((name, ty)) = root {
+            blocks.push(match self.options.target {
+                Target::Rust => format!("pub type {} = {};", name, ty),
+                Target::TypeScript => format!("export type {} = {};", name, ty),
+                Target::Go => format!("type {} {}", name, ty),
+            });
+        }
+        for definition in &self.definitions {
+            blocks.push(match self.options.target {
+                Target::Rust => self.render_rust(definition),
+                Target::TypeScript => self.render_typescript(definition),
+                Target::Go => self.render_go(definition),
+            });
+        }
+        blocks.join("\n\n") + "\n"
+    }
+
+    fn render_rust(&self, definition: &Definition) -> String {
+        let mut taken = HashSet::new();
+        let mut lines = Vec::new();
+        let mut allow_case = false;
+        for member in &definition.members {
+            let original = self.options.naming == Naming::Original
+                && is_identifier(&member.key, '_')
+                && !member.key.starts_with("r#");
+            let name = match original {
+                true => member.key.clone(),
+                false => match snake_case(&member.key) {
+                    name if name.is_empty() => "field".to_string(),
+                    name if name.starts_with(|c: char| c.is_ascii_digit()) => format!("_{}", name),
+                    name => name,
+                },
+            };
+            let name = rust_identifier(unique(name, &mut taken));
+            allow_case |= original && member.key != snake_case(&member.key);
+            let mut attributes = Vec::new();
+            if name.trim_start_matches("r#") != member.key {
+                attributes.push(format!(
+                    "rename = {}",
+                    serde_json::to_string(&member.key).unwrap_or_default()
+                ));
+            }
+            if member.optional {
+                attributes.push("default, skip_serializing_if = \"Option::is_none\"".to_string());
+            }
+            if !attributes.is_empty() {
+                lines.push(format!("    #[serde({})]", attributes.join(", ")));
+            }
+            lines.push(format!("    pub {}: {},", name, member.ty));
+        }
+        let mut block = "#[derive(Debug, Clone, Serialize, Deserialize)]\n".to_string();
+        if allow_case {
+            block.push_str("#[allow(non_snake_case)]\n");
+        }
+        match lines.is_empty() {
+            true => block.push_str(&format!("pub struct {} {{}}", definition.name)),
+            false => block.push_str(&format!(
+                "pub struct {} {{\n{}\n}}",
+                definition.name,
+                lines.join("\n")
+            )),
+        }
+        block
+    }
+
+    fn render_typescript(&self, definition: &Definition) -> String {
+        let lines = definition
+            .members
+            .iter()
+            .map(|member| {
+                let name = match is_identifier(&member.key, '$') {
+                    true => member.key.clone(),
+                    false => serde_json::to_string(&member.key).unwrap_or_default(),
+                };
+                let optional = if member.optional { "?" } else { "" };
+                format!("  {}{}: {};", name, optional, member.ty)
+            })
+            .collect::<Vec<_>>();
+        match lines.is_empty() {
+            true => format!("export interface {} {{}}", definition.name),
+            false => format!(
+                "export interface {} {{\n{}\n}}",
+                definition.name,
+                lines.join("\n")
+            ),
+        }
+    }
+
+    fn render_go(&self, definition: &Definition) -> String {
+        let mut taken = HashSet::new();
+        let rows = definition
+            .members
+            .iter()
+            .map(|member| {
+                let original = self.options.naming == Naming::Original
+                    && is_identifier(&member.key, '_')
+                    && !member.key.starts_with('_');
+                let name = match original {
+                    true => capitalize(&member.key[..1]) + &member.key[1..],
+                    false => match pascal_case(&member.key, true) {
+                        name if name.is_empty() => "Field".to_string(),
+                        name if name.starts_with(|c: char| c.is_ascii_digit()) => {
+                            format!("Field{}", name)
+                        }
+                        name => name,
+                    },
+                };
+                let omit = if member.optional { ",omitempty" } else { "" };
+                let key = member.key.replace('\\', "\\\\").replace('"', "\\\"");
+                (
+                    unique(name, &mut taken),
+                    member.ty.clone(),
+                    format!("`json:\"{}{}\"`", key, omit),
+                )
+            })
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            return format!("type {} struct{{}}", definition.name);
+        }
+        // Aligned in columns the way gofmt would.
+        let name_width = rows
+            .iter()
+            .map(|(name, _, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        let type_width = rows.iter().map(|(_, ty, _)| ty.len()).max().unwrap_or(0);
+        let lines = rows
+            .iter()
+            .map(|(name, ty, tag)| {
+                format!(
+                    "\t{:name_width$} {:type_width$} {}",
+                    name,
+                    ty,
+                    tag,
+                    name_width = name_width,
+                    type_width = type_width
+                )
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "type {} struct {{\n{}\n}}",
+            definition.name,
+            lines.join("\n")
+        )
+    }
+}
+
+/// Infers types from a sample JSON document and renders them as code.
+///
+/// Objects become structs or interfaces named after their keys. The items of
+/// arrays are merged, so fields missing from some items become optional.
+pub fn generate(source: &str, options: &Options) -> Result<String, ToolError> {
+    let value = serde_json::from_str::<Value>(source)
+        .map_err(|err| ToolError::from(err).context("Invalid JSON"))?;
+    let ty = infer(&value);
+    let root_name = match options.root_name.trim() {
+        "" => "Root",
+        name => name,
+    };
+    let mut generator = Generator {
+        options,
+        type_names: HashSet::new(),
+        definitions: Vec::new(),
+        uses_value: false,
+    };
+    if options.target == Target::Rust {
+        for name in [
+            "Deserialize",
+            "Option",
+            "Serialize",
+            "String",
+            "Value",
+            "Vec",
+        ] {
+            generator.type_names.insert(name.to_string());
+        }
+    }
+    // Anything but an object at the top level gets an alias with the root name.
+    let root = match ty.kind {
+        Kind::Object(_) => {
+            generator.nullable(&ty, root_name);
+            None
+        }
+        _ => {
+            let name = generator.type_name(root_name);
+            Some((name.clone(), generator.nullable(&ty, &name)))
+        }
+    };
+    Ok(generator.render(root))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(target: Target) -> Options<'static> {
+        Options {
+            target,
+            naming: Naming::Idiomatic,
+            all_optional: false,
+            root_name: "User",
+        }
+    }
+
+    const SAMPLE: &str = r#"{
+        "userId": 1,
+        "name": "Ada",
+        "score": 9.5,
+        "homePage": null,
+        "tags": ["a"],
+        "addresses": [
+            {"city": "London", "zip": "N1"},
+            {"city": "Paris"}
+        ]
+    }"#;
+
+    #[test]
+    fn generates_rust() {
+        assert_eq!(
+            generate(SAMPLE, &options(Target::Rust)).unwrap(),
+            r#"use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+    pub name: String,
+    pub score: f64,
+    #[serde(rename = "homePage")]
+    pub home_page: Value,
+    pub tags: Vec<String>,
+    pub addresses: Vec<Address>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Address {
+    pub city: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+}
+"#
+        );
+    }
+
+    #[test]
+    fn generates_typescript() {
+        assert_eq!(
+            generate(
+                r#"[{"id": 1, "first-name": "Ada", "ids": [1, null]}, {"id": 2.5}]"#,
+                &options(Target::TypeScript)
+            )
+            .unwrap(),
+            r#"export type User = UserItem[];
+
+export interface UserItem {
+  id: number;
+  "first-name"?: string;
+  ids?: (number | null)[];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn generates_aligned_go() {
+        assert_eq!(
+            generate(SAMPLE, &options(Target::Go)).unwrap(),
+            "type User struct {
+\tUserID    int64     `json:\"userId\"`
+\tName      string    `json:\"name\"`
+\tScore     float64   `json:\"score\"`
+\tHomePage  any       `json:\"homePage\"`
+\tTags      []string  `json:\"tags\"`
+\tAddresses []Address `json:\"addresses\"`
+}
+
+type Address struct {
+\tCity string  `json:\"city\"`
+\tZip  *string `json:\"zip,omitempty\"`
+}
+"
+        );
+    }
+
+    #[test]
+    fn keeps_original_names_and_makes_everything_optional() {
+        let options = Options {
+            naming: Naming::Original,
+            all_optional: true,
+            ..options(Target::Rust)
+        };
+        assert_eq!(
+            generate(r#"{"userId": true, "type": "a", "x-y": 1}"#, &options).unwrap(),
+            r#"use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct User {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub userId: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "x-y", default, skip_serializing_if = "Option::is_none")]
+    pub x_y: Option<i64>,
+}
+"#
+        );
+    }
+
+    #[test]
+    fn names_types_uniquely() {
+        let code = generate(
+            r#"{"item": {"a": 1}, "items": [{"b": 2}], "value": {}}"#,
+            &options(Target::Rust),
+        )
+        .unwrap();
+        assert!(code.contains("pub item: Item,"));
+        assert!(code.contains("pub items: Vec<Item2>,"));
+        assert!(code.contains("pub value: Value2,"));
+        assert!(code.contains("pub struct Value2 {}"));
+    }
+
+    #[test]
+    fn splits_words_and_singularizes() {
+        assert_eq!(
+            words("HTTPServer_url2-id"),
+            ["HTTP", "Server", "url2", "id"]
+        );
+        assert_eq!(pascal_case("userId", true), "UserID");
+        assert_eq!(pascal_case("userId", false), "UserId");
+        assert_eq!(item_name("categories"), "category");
+        assert_eq!(item_name("boxes"), "box");
+        assert_eq!(item_name("data"), "dataItem");
+    }
+
+    #[test]
+    fn reports_invalid_json() {
+        assert!(generate("{", &options(Target::Go)).is_err());
+    }
+}
@@ -0,0 +1,504 @@
+use crate::ToolError;
+
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+/// A color with 8-bit channels, as the formats below write it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: f32,
+}
+
+impl Color {
+    pub fn from_rgb(rgb: u32) -> Self {
+        let [_, r, g, b] = rgb.to_be_bytes();
+        Self { r, g, b, a: 1. }
+    }
+
+    /// Builds a color from hue in degrees and saturation and lightness in
+    /// percent.
+    pub fn from_hsl(h: f32, s: f32, l: f32, a: f32) -> Self {
+        let (s, l) = (s.clamp(0., 100.) / 100., l.clamp(0., 100.) / 100.);
+        let k = |n: f32| (n + h.rem_euclid(360.) / 30.) % 12.;
+        let f = |n: f32| {
+            let c = l - s * l.min(1. - l) * (k(n) - 3.).min(9. - k(n)).clamp(-1., 1.);
+            (c * 255.).round() as u8
+        };
+        Self {
+            r: f(0.),
+            g: f(8.),
+            b: f(4.),
+            a: a.clamp(0., 1.),
+        }
+    }
+
+    /// Returns hue in degrees and saturation and lightness in percent.
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (r, g, b) = (
+            self.r as f32 / 255.,
+            self.g as f32 / 255.,
+            self.b as f32 / 255.,
+        );
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.;
+        let d = max - min;
+        if d == 0. {
+            return (0., 0., l * 100.);
+        }
+        let s = d / (1. - (2. * l - 1.).abs());
+        let h = if max == r {
+            ((g - b) / d).rem_euclid(6.)
+        } else if max == g {
+            (b - r) / d + 2.
+        } else {
+            (r - g) / d + 4.
+        };
+        (h * 60., s * 100., l * 100.)
+    }
+
+    /// Moves the lightness by `amount` percentage points, keeping the hue.
+    pub fn adjust_lightness(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, l + amount, self.a)
+    }
+
+    pub fn to_hex(self) -> String {
+        let hex = format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b);
+        if self.a < 1. {
+            format!("{}{:02x}", hex, (self.a * 255.).round() as u8)
+        } else {
+            hex
+        }
+    }
+
+    pub fn to_rgb_string(self) -> String {
+        if self.a < 1. {
+            format!(
+                "rgba({}, {}, {}, {})",
+                self.r,
+                self.g,
+                self.b,
+                format_number(self.a)
+            )
+        } else {
+            format!("rgb({}, {}, {})", self.r, self.g, self.b)
+        }
+    }
+
+    pub fn to_hsl_string(self) -> String {
+        let (h, s, l) = self.to_hsl();
+        let (h, s, l) = (h.round() % 360., s.round(), l.round());
+        if self.a < 1. {
+            format!("hsla({}, {}%, {}%, {})", h, s, l, format_number(self.a))
+        } else {
+            format!("hsl({}, {}%, {}%)", h, s, l)
+        }
+    }
+
+    /// Relative luminance as WCAG 2 defines it, from 0 for black to 1 for
+    /// white.
+    pub fn luminance(self) -> f64 {
+        let linear = |c: u8| {
+            let c = c as f64 / 255.;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// Blends a translucent color over an opaque background, as it is seen.
+    pub fn over(self, background: Color) -> Color {
+        let blend = |c: u8, b: u8| (c as f32 * self.a + b as f32 * (1. - self.a)).round() as u8;
+        Self {
+            r: blend(self.r, background.r),
+            g: blend(self.g, background.g),
+            b: blend(self.b, background.b),
+            a: 1.,
+        }
+    }
+
+    pub fn name(self) -> Option<&'static str> {
+        let rgb = u32::from_be_bytes([0, self.r, self.g, self.b]);
+        if self.a < 1. {
+            return None;
+        }
+        NAMED_COLORS
+            .iter()
+            .find(|(_, value)| *value == rgb)
+            .map(|(name, _)| *name)
+    }
+}
+
+/// The WCAG 2 levels of contrast between text and its background, with the
+/// ratio each needs. Large text is at least 18pt, or 14pt bold.
+pub const CONTRAST_LEVELS: [(&str, f64); 4] = [
+    ("AA Normal Text", 4.5),
+    ("AA Large Text", 3.),
+    ("AAA Normal Text", 7.),
+    ("AAA Large Text", 4.5),
+];
+
+/// The WCAG 2 contrast ratio between text and its background, from 1 to 21.
+/// Translucent text is blended over the background, which is taken as
+/// opaque.
+pub fn contrast_ratio(foreground: Color, background: Color) -> f64 {
+    let background = Color {
+        a: 1.,
+        ..background
+    };
+    let foreground = foreground.over(background).luminance();
+    let background = background.luminance();
+    (foreground.max(background) + 0.05) / (foreground.min(background) + 0.05)
+}
+
+/// Finds the shade of `color` closest in lightness, keeping its hue and
+/// saturation, whose `contrast` reaches `ratio`.
+pub fn nearest_shade(color: Color, ratio: f64, contrast: impl Fn(Color) -> f64) -> Option<Color> {
+    let (h, s, l) = color.to_hsl();
+    (0..=200)
+        .flat_map(|step| [l - step as f32 / 2., l + step as f32 / 2.])
+        .filter(|l| (0. ..=100.).contains(l))
+        .map(|l| Color::from_hsl(h, s, l, color.a))
+        .find(|shade| contrast(*shade) >= ratio)
+}
+
+fn format_number(value: f32) -> String {
+    let value = (value * 100.).round() / 100.;
+    value.to_string()
+}
+
+/// Parses hex (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`), `rgb()`, `rgba()`,
+/// `hsl()`, `hsla()`, and CSS named colors. Both the comma-separated and
+/// the space-separated forms of the functions are accepted.
+pub fn parse(input: &str) -> Result<Color, ToolError> {
+    let input = input.trim().to_lowercase();
+
+    if let Some((_, rgb)) = NAMED_COLORS.iter().find(|(name, _)| *name == input) {
+        return Ok(Color::from_rgb(*rgb));
+    }
+    if input == "transparent" {
+        return Ok(Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 0.,
+        });
+    }
+    if let Some(args) = function_args(&input, &["rgb", "rgba"]) {
+        let (channels, alpha) = split_args(args)?;
+        let channels = channels
+            .iter()
+            .map(|c| parse_component(c, 255.).map(|c| c.clamp(0., 255.).round() as u8))
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(Color {
+            r: channels[0],
+            g: channels[1],
+            b: channels[2],
+            a: alpha,
+        });
+    }
+    if let Some(args) = function_args(&input, &["hsl", "hsla"]) {
+        let (channels, alpha) = split_args(args)?;
+        let h = channels[0]
+            .trim_end_matches("deg")
+            .parse::<f32>()
+            .map_err(|_| ToolError::new(format!("{:?} is not a valid hue", channels[0])))?;
+        let s = parse_component(channels[1], 100.)?;
+        let l = parse_component(channels[2], 100.)?;
+        return Ok(Color::from_hsl(h, s, l, alpha));
+    }
+
+    let hex = input.strip_prefix('#').unwrap_or(&input);
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ToolError::new(
+            "expected a hex, rgb(), hsl(), or named color",
+        ));
+    }
+    let digits: Vec<u8> = match hex.len() {
+        3 | 4 => hex
+            .chars()
+            .map(|c| c.to_digit(16).unwrap_or_default() as u8 * 17)
+            .collect(),
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or_default())
+            .collect(),
+        n => {
+            return Err(ToolError::new(format!(
+                "hex colors have 3, 4, 6, or 8 digits but found {}",
+                n
+            )));
+        }
+    };
+    Ok(Color {
+        r: digits[0],
+        g: digits[1],
+        b: digits[2],
+        a: digits.get(3).map_or(1., |a| *a as f32 / 255.),
+    })
+}
+
+fn function_args<'a>(input: &'a str, names: &[&str]) -> Option<&'a str> {
+    let (name, rest) = input.split_once('(')?;
+    names
+        .contains(&name.trim())
+        .then(|| rest.strip_suffix(')'))
+        .flatten()
+}
+
+/// Splits function arguments into three channels and an alpha, which
+/// defaults to 1.
+fn split_args(args: &str) -> Result<(Vec<&str>, f32), ToolError> {
+    let (channels, alpha) = match args.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha.trim())),
+        None => (args, None),
+    };
+    let mut channels: Vec<&str> = channels
+        .split([',', ' '])
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .collect();
+    let alpha = match alpha {
+        Some(alpha) => Some(alpha),
+        None if channels.len() == 4 => channels.pop(),
+        None => None,
+    };
+    if channels.len() != 3 {
+        return Err(ToolError::new(format!(
+            "expected 3 channels but found {}",
+            channels.len()
+        )));
+    }
+    let alpha = match alpha {
+        Some(alpha) => parse_component(alpha, 1.)?.clamp(0., 1.),
+        None => 1.,
+    };
+    Ok((channels, alpha))
+}
+
+/// Parses a number, or a percentage of `full`.
+fn parse_component(value: &str, full: f32) -> Result<f32, ToolError> {
+    let (number, scale) = match value.strip_suffix('%') {
+        Some(number) => (number, full / 100.),
+        None => (value, 1.),
+    };
+    number
+        .parse::<f32>()
+        .map(|number| number * scale)
+        .map_err(|_| ToolError::new(format!("{:?} is not a valid number", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_css_colors() {
+        let orange = Color::from_rgb(0xff8800);
+        for value in [
+            "#ff8800",
+            "#F80",
+            "rgb(255, 136, 0)",
+            "rgb(255 136 0 / 100%)",
+        ] {
+            assert_eq!(parse(value).unwrap(), orange, "{}", value);
+        }
+        assert_eq!(parse("hsl(32, 100%, 50%)").unwrap().to_hex(), "#ff8800");
+        assert_eq!(parse("Tomato").unwrap().name(), Some("tomato"));
+        assert_eq!(
+            parse("#ff880080").unwrap().to_rgb_string(),
+            "rgba(255, 136, 0, 0.5)"
+        );
+        assert!(parse("#ff88").is_ok());
+        assert!(parse("#ff8").is_ok());
+        assert!(parse("#ff88000").is_err());
+        assert!(parse("rgb(1, 2)").is_err());
+    }
+
+    #[test]
+    fn measures_contrast() {
+        let black = Color::from_rgb(0x000000);
+        let white = Color::from_rgb(0xffffff);
+        assert_eq!(contrast_ratio(black, white), 21.);
+        assert_eq!(contrast_ratio(white, white), 1.);
+        let gray = contrast_ratio(Color::from_rgb(0x777777), white);
+        assert!((4.47..4.49).contains(&gray), "{}", gray);
+        let faded = Color { a: 0.5, ..black };
+        assert_eq!(
+            contrast_ratio(faded, white),
+            contrast_ratio(Color::from_rgb(0x808080), white)
+        );
+    }
+
+    #[test]
+    fn finds_the_nearest_passing_shade() {
+        let white = Color::from_rgb(0xffffff);
+        let gray = Color::from_rgb(0x777777);
+        let shade = nearest_shade(gray, 4.5, |shade| contrast_ratio(shade, white)).unwrap();
+        assert!(contrast_ratio(shade, white) >= 4.5);
+        assert!(shade.to_hsl().2 < gray.to_hsl().2);
+        assert_eq!(
+            nearest_shade(white, 1., |shade| contrast_ratio(shade, white)),
+            Some(white)
+        );
+        assert_eq!(
+            nearest_shade(gray, 22., |shade| contrast_ratio(shade, white)),
+            None
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use std::io::{Read, Write};
+
+use flate2::{
+    Compression,
+    read::{DeflateDecoder, MultiGzDecoder, ZlibDecoder},
+    write::{DeflateEncoder, GzEncoder, ZlibEncoder},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+const BROTLI_BUFFER_SIZE: usize = 4096;
+/// The window Brotli encoders use by default, 4 MiB.
+const BROTLI_WINDOW_BITS: u32 = 22;
+
+/// The compressed formats, mostly containers for deflate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Format {
+    /// Gzip, as in `.gz` files and `Content-Encoding: gzip`.
+    #[default]
+    Gzip,
+    /// Zlib, as in `Content-Encoding: deflate` and PNG.
+    Zlib,
+    /// Deflate without a header, as in SAML redirect bindings and ZIP.
+    Deflate,
+    /// Brotli, as in `Content-Encoding: br` and WOFF2.
+    Brotli,
+}
+
+impl Format {
+    pub const ALL: [Self; 4] = [Self::Gzip, Self::Zlib, Self::Deflate, Self::Brotli];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Gzip => "GZIP",
+            Self::Zlib => "zlib",
+            Self::Deflate => "Raw Deflate",
+            Self::Brotli => "Brotli",
+        }
+    }
+
+    /// Recognises gzip by its magic bytes and zlib by its header checksum.
+    /// Anything else is taken to be raw deflate, which has no header. Brotli
+    /// has none either, and raw deflate often reads it without error, so it
+    /// is never detected.
+    pub fn detect(bytes: &[u8]) -> Self {
+        match bytes {
+            [0x1f, 0x8b, ..] => Self::Gzip,
+            [cmf, flg, ..]
+                if cmf & 0x0f == 8 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0 =>
+            {
+                Self::Zlib
+            }
+            _ => Self::Deflate,
+        }
+    }
+}
+
+/// How hard to try for smaller output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Level {
+    Fastest,
+    #[default]
+    Default,
+    Smallest,
+}
+
+impl Level {
+    pub const ALL: [Self; 3] = [Self::Fastest, Self::Default, Self::Smallest];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Fastest => "Fastest",
+            Self::Default => "Default",
+            Self::Smallest => "Smallest",
+        }
+    }
+
+    /// The Brotli quality, from 0 to 11.
+    fn quality(&self) -> u32 {
+        match self {
+            Self::Fastest => 1,
+            Self::Default => 6,
+            Self::Smallest => 11,
+        }
+    }
+
+    fn compression(&self) -> Compression {
+        match self {
+            Self::Fastest => Compression::fast(),
+            Self::Default => Compression::default(),
+            Self::Smallest => Compression::best(),
+        }
+    }
+}
+
+pub fn compress(bytes: &[u8], format: Format, level: Level) -> Vec<u8> {
+    let quality = level.quality();
+    let level = level.compression();
+    // Writing to a Vec cannot fail.
+    match format {
+        Format::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), level);
+            encoder.write_all(bytes).ok();
+            encoder.finish().unwrap_or_default()
+        }
+        Format::Zlib => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), level);
+            encoder.write_all(bytes).ok();
+            encoder.finish().unwrap_or_default()
+        }
+        Format::Deflate => {
+            let mut encoder = DeflateEncoder::new(Vec::new(), level);
+            encoder.write_all(bytes).ok();
+            encoder.finish().unwrap_or_default()
+        }
+        Format::Brotli => {
+            let mut encoder = brotli::CompressorWriter::new(
+                Vec::new(),
+                BROTLI_BUFFER_SIZE,
+                quality,
+                BROTLI_WINDOW_BITS,
+            );
+            encoder.write_all(bytes).ok();
+            encoder.into_inner()
+        }
+    }
+}
+
+/// Decompresses `bytes`, detecting their format if none is given. Gzip
+/// members written one after another are decompressed as one stream.
+pub fn decompress(bytes: &[u8], format: Option<Format>) -> Result<(Format, Vec<u8>), ToolError> {
+    let format = format.unwrap_or_else(|| Format::detect(bytes));
+    let mut decompressed = Vec::new();
+    match format {
+        Format::Gzip => MultiGzDecoder::new(bytes).read_to_end(&mut decompressed),
+        Format::Zlib => ZlibDecoder::new(bytes).read_to_end(&mut decompressed),
+        Format::Deflate => DeflateDecoder::new(bytes).read_to_end(&mut decompressed),
+        Format::Brotli => {
+            brotli::Decompressor::new(bytes, BROTLI_BUFFER_SIZE).read_to_end(&mut decompressed)
+        }
+    }
+    .map_err(|err| {
+        ToolError::new(err.to_string()).context(&format!("Invalid {}", format.name()))
+    })?;
+    Ok((format, decompressed))
+}
+
+/// Describes how much smaller `after` is than `before`, e.g.
+/// `1,000 → 250 bytes (25.0%)`.
+pub fn summary(before: usize, after: usize) -> String {
+    let ratio = match before {
+        0 => String::new(),
+        before => format!(" ({:.1}%)", after as f64 / before as f64 * 100.),
+    };
+    format!("{} → {} bytes{}", group(before), group(after), ratio)
+}
+
+fn group(n: usize) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_format() {
+        let text = "to be or not to be, ".repeat(50);
+        // Brotli is never detected, so it has a test of its own.
+        for format in Format::ALL.into_iter().filter(|f| *f != Format::Brotli) {
+            for level in Level::ALL {
+                let compressed = compress(text.as_bytes(), format, level);
+                assert!(compressed.len() < text.len());
+                assert_eq!(Format::detect(&compressed), format);
+                let (detected, decompressed) = decompress(&compressed, None).unwrap();
+                assert_eq!(detected, format);
+                assert_eq!(decompressed, text.as_bytes());
+            }
+        }
+    }
+
+    #[test]
+    fn round_trips_brotli() {
+        let text = "to be or not to be, ".repeat(50);
+        for level in Level::ALL {
+            let compressed = compress(text.as_bytes(), Format::Brotli, level);
+            assert!(compressed.len() < text.len());
+            let (format, decompressed) = decompress(&compressed, Some(Format::Brotli)).unwrap();
+            assert_eq!(format, Format::Brotli);
+            assert_eq!(decompressed, text.as_bytes());
+        }
+        // "hello" as the reference encoder stores it, uncompressed.
+        let hello = [0x0b, 0x02, 0x80, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x03];
+        let (_, decompressed) = decompress(&hello, Some(Format::Brotli)).unwrap();
+        assert_eq!(decompressed, b"hello");
+        assert!(decompress(b"plain text", Some(Format::Brotli)).is_err());
+    }
+
+    #[test]
+    fn reports_invalid_data() {
+        let err = decompress(&[0x1f, 0x8b, 0x08, 0x00], None).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid GZIP: "));
+        assert!(decompress(b"plain text", Some(Format::Zlib)).is_err());
+    }
+
+    #[test]
+    fn summarises_sizes() {
+        assert_eq!(summary(1000, 250), "1,000 → 250 bytes (25.0%)");
+        assert_eq!(summary(0, 20), "0 → 20 bytes");
+    }
+}
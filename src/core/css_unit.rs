@@ -0,0 +1,222 @@
+use crate::ToolError;
+
+/// Points per CSS pixel, as CSS fixes an inch at 96px and 72pt.
+const PT_PER_PX: f64 = 0.75;
+
+/// The CSS units a length is converted between.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unit {
+    Px,
+    Rem,
+    Em,
+    Pt,
+    Percent,
+}
+
+impl Unit {
+    pub const ALL: [Self; 5] = [Self::Px, Self::Rem, Self::Em, Self::Pt, Self::Percent];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Px => "Pixels",
+            Self::Rem => "Root Ems",
+            Self::Em => "Ems",
+            Self::Pt => "Points",
+            Self::Percent => "Percent",
+        }
+    }
+
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Self::Px => "px",
+            Self::Rem => "rem",
+            Self::Em => "em",
+            Self::Pt => "pt",
+            Self::Percent => "%",
+        }
+    }
+}
+
+/// The font sizes, in pixels, that relative units are measured against: the
+/// root element's for rem, and the parent element's for em and percentages.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bases {
+    pub root: f64,
+    pub parent: f64,
+}
+
+impl Default for Bases {
+    fn default() -> Self {
+        Self {
+            root: 16.,
+            parent: 16.,
+        }
+    }
+}
+
+impl Bases {
+    fn pixels_per(&self, unit: Unit) -> f64 {
+        match unit {
+            Unit::Px => 1.,
+            Unit::Rem => self.root,
+            Unit::Em => self.parent,
+            Unit::Pt => 1. / PT_PER_PX,
+            Unit::Percent => self.parent / 100.,
+        }
+    }
+}
+
+/// A length with its unit, as in 1.5rem.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Length {
+    pub amount: f64,
+    pub unit: Unit,
+}
+
+impl Length {
+    pub fn to_px(self, bases: &Bases) -> f64 {
+        self.amount * bases.pixels_per(self.unit)
+    }
+
+    pub fn from_px(px: f64, unit: Unit, bases: &Bases) -> Self {
+        Self {
+            amount: px / bases.pixels_per(unit),
+            unit,
+        }
+    }
+}
+
+impl std::fmt::Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", format_amount(self.amount), self.unit.suffix())
+    }
+}
+
+/// Writes an amount with at most four decimals, as CSS needs no more.
+pub fn format_amount(amount: f64) -> String {
+    let amount = format!("{:.4}", amount);
+    let amount = amount.trim_end_matches('0').trim_end_matches('.');
+    match amount {
+        "-0" => "0".to_string(),
+        amount => amount.to_string(),
+    }
+}
+
+/// Reads a length such as 24px, 1.5rem or 75%. A bare number is taken as
+/// pixels.
+pub fn parse(value: &str) -> Result<Length, ToolError> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+        .unwrap_or(value.len());
+    let (amount, suffix) = value.split_at(split);
+    let unit = match suffix.trim().to_ascii_lowercase().as_str() {
+        "" | "px" => Unit::Px,
+        "rem" => Unit::Rem,
+        "em" => Unit::Em,
+        "pt" => Unit::Pt,
+        "%" => Unit::Percent,
+        suffix => {
+            return Err(ToolError::new(format!(
+                "{:?} is not one of px, rem, em, pt, or %",
+                suffix
+            )));
+        }
+    };
+    match amount.parse::<f64>() {
+        Ok(amount) if amount.is_finite() => Ok(Length { amount, unit }),
+        _ => Err(ToolError::new(format!(
+            "{:?} is not a length such as 24px or 1.5rem",
+            value
+        ))),
+    }
+}
+
+/// Writes a length in each unit.
+pub fn convert(length: Length, bases: &Bases) -> Vec<Length> {
+    let px = length.to_px(bases);
+    Unit::ALL
+        .into_iter()
+        .map(|unit| Length::from_px(px, unit, bases))
+        .collect()
+}
+
+/// Builds a `clamp()` that grows a size linearly from `min_size` at the
+/// `min_viewport` width to `max_size` at the `max_viewport` width, all in
+/// pixels, written in rem so that it follows the user's font size.
+pub fn fluid_clamp(
+    min_size: f64,
+    max_size: f64,
+    min_viewport: f64,
+    max_viewport: f64,
+    root: f64,
+) -> Result<String, ToolError> {
+    if min_viewport >= max_viewport {
+        return Err(ToolError::new(
+            "the minimum viewport width must be less than the maximum",
+        ));
+    }
+    let slope = (max_size - min_size) / (max_viewport - min_viewport);
+    let intercept = min_size - slope * min_viewport;
+    let rem = |px: f64| Length::from_px(px, Unit::Rem, &Bases { root, parent: root });
+    let preferred = match intercept < 0. {
+        true => format!("{}vw - {}", format_amount(slope * 100.), rem(-intercept)),
+        false => format!("{} + {}vw", rem(intercept), format_amount(slope * 100.)),
+    };
+    Ok(format!(
+        "clamp({}, {}, {})",
+        rem(min_size.min(max_size)),
+        preferred,
+        rem(min_size.max(max_size))
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_lengths() {
+        assert_eq!(
+            parse(" 1.5rem ").unwrap(),
+            Length {
+                amount: 1.5,
+                unit: Unit::Rem
+            }
+        );
+        assert_eq!(parse("24").unwrap().unit, Unit::Px);
+        assert_eq!(parse("-2 PX").unwrap().amount, -2.);
+        assert_eq!(parse("75%").unwrap().unit, Unit::Percent);
+        assert_eq!(parse("1em").unwrap().unit, Unit::Em);
+        assert!(parse("12vw").is_err());
+        assert!(parse("rem").is_err());
+    }
+
+    #[test]
+    fn converts_between_units() {
+        let bases = Bases {
+            root: 16.,
+            parent: 20.,
+        };
+        let lengths = convert(parse("24px").unwrap(), &bases)
+            .into_iter()
+            .map(|length| length.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, ["24px", "1.5rem", "1.2em", "18pt", "120%"]);
+        assert_eq!(parse("12pt").unwrap().to_px(&bases), 16.);
+        assert_eq!(format_amount(1. / 3.), "0.3333");
+    }
+
+    #[test]
+    fn generates_fluid_clamps() {
+        assert_eq!(
+            fluid_clamp(16., 32., 320., 1280., 16.).unwrap(),
+            "clamp(1rem, 0.6667rem + 1.6667vw, 2rem)"
+        );
+        assert_eq!(
+            fluid_clamp(16., 48., 400., 800., 16.).unwrap(),
+            "clamp(1rem, 8vw - 1rem, 3rem)"
+        );
+        assert!(fluid_clamp(16., 32., 1280., 320., 16.).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+/// How fields are quoted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Quoting {
+    /// Fields are quoted only when they hold a delimiter, quote, or line
+    /// break.
+    #[default]
+    Minimal,
+    /// Every field is quoted when writing CSV.
+    Always,
+    /// Quotes are ordinary characters, and fields are never quoted.
+    Never,
+}
+
+/// Splits CSV into rows of fields. Quoted fields may hold delimiters, line
+/// breaks, and doubled quotes, unless quoting is turned off.
+pub fn parse(
+    source: &str,
+    delimiter: char,
+    quoting: Quoting,
+) -> Result<Vec<Vec<String>>, ToolError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut chars = source.char_indices().peekable();
+    // Whether anything has been read since the last row ended, so that a
+    // trailing line break does not add an empty row.
+    let mut pending = false;
+
+    while let Some((offset, c)) = chars.next() {
+        pending = true;
+        match c {
+            '"' if quoting != Quoting::Never && field.is_empty() => loop {
+                match chars.next() {
+                    Some((_, '"')) if chars.peek().is_some_and(|(_, c)| *c == '"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some((_, '"')) => break,
+                    Some((_, c)) => field.push(c),
+                    None => {
+                        return Err(
+                            ToolError::new("quoted field is never closed").at_offset(offset)
+                        );
+                    }
+                }
+            },
+            c if c == delimiter => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek().is_some_and(|(_, c)| *c == '\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+                pending = false;
+            }
+            c => field.push(c),
+        }
+    }
+    if pending {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+pub fn write(rows: &[Vec<String>], delimiter: char, quoting: Quoting) -> String {
+    let escape = |field: &String| match quoting {
+        Quoting::Always => format!("\"{}\"", field.replace('"', "\"\"")),
+        Quoting::Minimal if field.contains(delimiter) || field.contains(['"', '\n', '\r']) => {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        }
+        _ => field.clone(),
+    };
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(escape)
+                .collect::<Vec<_>>()
+                .join(&delimiter.to_string())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quoted_fields() {
+        let rows = parse("a,\"b,\"\"c\"\"\"\r\n\"d\ne\",f\n", ',', Quoting::Minimal).unwrap();
+        assert_eq!(rows, [vec!["a", "b,\"c\""], vec!["d\ne", "f"]]);
+        assert_eq!(
+            parse("\"a\",b", ',', Quoting::Never).unwrap(),
+            [vec!["\"a\"", "b"]]
+        );
+        assert_eq!(
+            parse("a,\"b", ',', Quoting::Minimal)
+                .unwrap_err()
+                .to_string(),
+            "quoted field is never closed (at character 3)"
+        );
+    }
+
+    #[test]
+    fn writes_quoted_fields() {
+        let rows = [vec!["a".to_string(), "b;c".to_string()]];
+        assert_eq!(write(&rows, ';', Quoting::Minimal), "a;\"b;c\"");
+        assert_eq!(write(&rows, ',', Quoting::Always), "\"a\",\"b;c\"");
+    }
+}
@@ -0,0 +1,186 @@
+use ::base64::{Engine as _, engine::general_purpose};
+use percent_encoding::{AsciiSet, CONTROLS, percent_decode_str, utf8_percent_encode};
+
+use crate::ToolError;
+
+const ENCODE_SET: &AsciiSet = &CONTROLS.add(b',').add(b'%').add(b'#');
+
+/// The parts of a parsed `data:` URL.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DataURL {
+    pub mime_type: String,
+    pub charset: Option<String>,
+    pub base64: bool,
+    pub data: Vec<u8>,
+}
+
+/// Builds a `data:` URL for `value`, marking plain text as UTF-8.
+pub fn generate(value: &str, mime_type: &str, base64: bool) -> String {
+    let charset = if mime_type == "text/plain" {
+        ";charset=utf-8"
+    } else {
+        ""
+    };
+    let data = if base64 {
+        format!(";base64,{}", general_purpose::URL_SAFE.encode(value))
+    } else {
+        format!(",{}", utf8_percent_encode(value, ENCODE_SET))
+    };
+    format!("data:{}{}{}", mime_type, charset, data)
+}
+
+/// Builds a Base64 `data:` URL for binary data such as an image, using the
+/// standard alphabet that browsers expect in CSS and HTML.
+pub fn generate_bytes(bytes: &[u8], mime_type: &str) -> String {
+    format!(
+        "data:{};base64,{}",
+        mime_type,
+        general_purpose::STANDARD.encode(bytes)
+    )
+}
+
+/// Finds the first `data:` URL in a piece of text, such as a CSS `url()` or
+/// an HTML `src` attribute, ending it at a quote, bracket or whitespace.
+pub fn find(text: &str) -> Option<&str> {
+    let start = text.to_ascii_lowercase().find("data:")?;
+    let rest = &text[start..];
+    let end = rest
+        .find(|c: char| matches!(c, '"' | '\'' | ')' | '<' | '>') || c.is_whitespace())
+        .unwrap_or(rest.len());
+    Some(&rest[..end])
+}
+
+/// Parses a `data:` URL and decodes its payload. A missing media type means
+/// `text/plain;charset=US-ASCII`, as RFC 2397 has it. Base64 payloads may use
+/// either the standard or the URL-safe alphabet, with or without padding.
+pub fn parse(value: &str) -> Result<DataURL, ToolError> {
+    let value = value.trim();
+    let rest = value
+        .get(..5)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("data:"))
+        .map(|_| &value[5..])
+        .ok_or_else(|| ToolError::new("expected a URL starting with \"data:\""))?;
+    let (header, payload) = rest
+        .split_once(',')
+        .ok_or_else(|| ToolError::new("expected a comma before the data").at_offset(value.len()))?;
+
+    let mut params = header.split(';');
+    let mime_type = params.next().unwrap_or_default().trim();
+    let mut charset = None;
+    let mut base64 = false;
+    for param in params.map(str::trim) {
+        if param.eq_ignore_ascii_case("base64") {
+            base64 = true;
+        } else if let Some((name, value)) = param.split_once('=')
+            && name.trim().eq_ignore_ascii_case("charset")
+        {
+            charset = Some(value.trim().to_string());
+        }
+    }
+    let mime_type = if mime_type.is_empty() {
+        charset.get_or_insert_with(|| "US-ASCII".to_string());
+        "text/plain".to_string()
+    } else {
+        mime_type.to_ascii_lowercase()
+    };
+
+    let payload: Vec<u8> = percent_decode_str(payload).collect();
+    let data = if base64 {
+        let normalized: Vec<u8> = payload
+            .into_iter()
+            .filter(|byte| !byte.is_ascii_whitespace())
+            .map(|byte| match byte {
+                b'-' => b'+',
+                b'_' => b'/',
+                byte => byte,
+            })
+            .collect();
+        let padding = normalized
+            .iter()
+            .rev()
+            .take_while(|&&byte| byte == b'=')
+            .count();
+        let unpadded = &normalized[..normalized.len() - padding];
+        general_purpose::STANDARD_NO_PAD
+            .decode(unpadded)
+            .map_err(|err| ToolError::from(err).context("Invalid Base64"))?
+    } else {
+        payload
+    };
+
+    Ok(DataURL {
+        mime_type,
+        charset,
+        base64,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_base64_and_percent_encoded_urls() {
+        assert_eq!(
+            generate("hi?", "text/plain", true),
+            "data:text/plain;charset=utf-8;base64,aGk_"
+        );
+        assert_eq!(
+            generate("a,b #1", "text/html", false),
+            "data:text/html,a%2Cb %231"
+        );
+    }
+
+    #[test]
+    fn parses_generated_urls() {
+        let url = parse(&generate("hi?", "text/plain", true)).unwrap();
+        assert_eq!(url.mime_type, "text/plain");
+        assert_eq!(url.charset.as_deref(), Some("utf-8"));
+        assert!(url.base64);
+        assert_eq!(url.data, b"hi?");
+
+        let url = parse(&generate("a,b #1", "text/html", false)).unwrap();
+        assert_eq!(url.mime_type, "text/html");
+        assert_eq!(url.charset, None);
+        assert!(!url.base64);
+        assert_eq!(url.data, b"a,b #1");
+    }
+
+    #[test]
+    fn defaults_to_us_ascii_text() {
+        let url = parse("data:,Hello%2C%20World").unwrap();
+        assert_eq!(url.mime_type, "text/plain");
+        assert_eq!(url.charset.as_deref(), Some("US-ASCII"));
+        assert_eq!(url.data, b"Hello, World");
+    }
+
+    #[test]
+    fn decodes_standard_base64() {
+        let url = parse("DATA:image/PNG;base64,iVBORw0KGgo=").unwrap();
+        assert_eq!(url.mime_type, "image/png");
+        assert_eq!(url.data, b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn generates_and_finds_binary_urls() {
+        let url = generate_bytes(b"\x89PNG\r\n\x1a\n", "image/png");
+        assert_eq!(url, "data:image/png;base64,iVBORw0KGgo=");
+        let css = format!("background-image: url(\"{}\");", url);
+        assert_eq!(find(&css), Some(url.as_str()));
+        let html = format!("<img src='{}' alt=\"\">", url);
+        assert_eq!(
+            parse(find(&html).unwrap()).unwrap().data,
+            b"\x89PNG\r\n\x1a\n"
+        );
+        assert_eq!(find("iVBORw0KGgo="), None);
+    }
+
+    #[test]
+    fn rejects_malformed_urls() {
+        assert!(parse("https://example.com").is_err());
+        assert!(parse("data:text/plain").is_err());
+        let err = parse("data:;base64,!!").unwrap_err();
+        assert!(err.to_string().starts_with("Invalid Base64"));
+    }
+}
@@ -0,0 +1,421 @@
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+use similar::{Algorithm, DiffTag, TextDiff};
+
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Granularity {
+    Character,
+    Word,
+    Line,
+}
+
+/// What to disregard when comparing the two texts.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Ignore {
+    pub surrounding_whitespace: bool,
+    pub all_whitespace: bool,
+    pub case: bool,
+    pub blank_lines: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RowKind {
+    Equal,
+    Delete,
+    Insert,
+    Modify,
+}
+
+/// A span of deleted or inserted text. Spans are coloured when rendered so
+/// that they follow the theme.
+#[derive(Clone, Copy)]
+pub enum Change {
+    Delete,
+    Insert,
+}
+
+/// One line on one side of the side-by-side view.
+#[derive(Clone)]
+pub struct Line {
+    pub number: usize,
+    pub text: String,
+    pub changes: Vec<(Range<usize>, Change)>,
+}
+
+/// A row of the side-by-side view, pairing a line of the original with the
+/// line of the modified text it lines up with. Either may be missing when a
+/// line was only deleted or only inserted.
+#[derive(Clone)]
+pub struct Row {
+    pub kind: RowKind,
+    pub original: Option<Line>,
+    pub modified: Option<Line>,
+}
+
+/// Compares two texts and merges them into one, marking the spans that were
+/// deleted from the original and inserted into the modified text.
+pub fn inline(
+    old: &str,
+    new: &str,
+    granularity: &Granularity,
+    ignore: &Ignore,
+) -> (String, Vec<(Range<usize>, Change)>) {
+    let old_tokens = tokenize(old, granularity, ignore);
+    let new_tokens = tokenize(new, granularity, ignore);
+
+    let mut text = String::with_capacity(old.len() + new.len());
+    let mut changes = Vec::new();
+    for step in diff_tokens(&old_tokens, &new_tokens) {
+        let (value, change) = match step {
+            Step::Equal(_, j) | Step::SkipNew(j) => (&new[new_tokens[j].range.clone()], None),
+            Step::Delete(i) => (&old[old_tokens[i].range.clone()], Some(Change::Delete)),
+            Step::Insert(j) => (&new[new_tokens[j].range.clone()], Some(Change::Insert)),
+            Step::SkipOld(_) => continue,
+        };
+        let pos = text.len();
+        text.push_str(value);
+        if let Some(change) = change {
+            changes.push((pos..text.len(), change));
+        }
+    }
+    (text, changes)
+}
+
+/// Pairs up the lines of both texts. Changed lines that line up with one
+/// another are highlighted within the line at the chosen granularity, or
+/// by word when comparing whole lines. Ignored lines are shown on their
+/// own side only.
+pub fn side_by_side(old: &str, new: &str, granularity: &Granularity, ignore: &Ignore) -> Vec<Row> {
+    let old_tokens = tokenize(old, &Granularity::Line, ignore);
+    let new_tokens = tokenize(new, &Granularity::Line, ignore);
+    let line = |text: &str, tokens: &[Token], index: usize| Line {
+        number: index + 1,
+        text: text[tokens[index].range.clone()]
+            .trim_end_matches(['\n', '\r'])
+            .to_string(),
+        changes: Vec::new(),
+    };
+
+    let mut rows = Vec::new();
+    let mut deleted = Vec::new();
+    let mut inserted = Vec::new();
+    let steps = diff_tokens(&old_tokens, &new_tokens);
+    for step in steps.into_iter().map(Some).chain([None]) {
+        match step {
+            Some(Step::Delete(i)) => {
+                deleted.push(line(old, &old_tokens, i));
+                continue;
+            }
+            Some(Step::Insert(j)) => {
+                inserted.push(line(new, &new_tokens, j));
+                continue;
+            }
+            _ => {}
+        }
+
+        let mut deleted = deleted.drain(..);
+        let mut inserted = inserted.drain(..);
+        loop {
+            rows.push(match (deleted.next(), inserted.next()) {
+                (Some(original), Some(modified)) => {
+                    let (original, modified) =
+                        highlight_changes(original, modified, granularity, ignore);
+                    Row {
+                        kind: RowKind::Modify,
+                        original: Some(original),
+                        modified: Some(modified),
+                    }
+                }
+                (Some(original), None) => Row {
+                    kind: RowKind::Delete,
+                    original: Some(original),
+                    modified: None,
+                },
+                (None, Some(modified)) => Row {
+                    kind: RowKind::Insert,
+                    original: None,
+                    modified: Some(modified),
+                },
+                (None, None) => break,
+            });
+        }
+
+        let (original, modified) = match step {
+            Some(Step::Equal(i, j)) => (Some(i), Some(j)),
+            Some(Step::SkipOld(i)) => (Some(i), None),
+            Some(Step::SkipNew(j)) => (None, Some(j)),
+            _ => continue,
+        };
+        rows.push(Row {
+            kind: RowKind::Equal,
+            original: original.map(|i| line(old, &old_tokens, i)),
+            modified: modified.map(|j| line(new, &new_tokens, j)),
+        });
+    }
+    rows
+}
+
+fn highlight_changes(
+    mut original: Line,
+    mut modified: Line,
+    granularity: &Granularity,
+    ignore: &Ignore,
+) -> (Line, Line) {
+    let granularity = match granularity {
+        Granularity::Character => Granularity::Character,
+        Granularity::Word | Granularity::Line => Granularity::Word,
+    };
+    let old_tokens = tokenize(&original.text, &granularity, ignore);
+    let new_tokens = tokenize(&modified.text, &granularity, ignore);
+    for step in diff_tokens(&old_tokens, &new_tokens) {
+        match step {
+            Step::Delete(i) => original
+                .changes
+                .push((old_tokens[i].range.clone(), Change::Delete)),
+            Step::Insert(j) => modified
+                .changes
+                .push((new_tokens[j].range.clone(), Change::Insert)),
+            _ => {}
+        }
+    }
+    (original, modified)
+}
+
+/// A unified diff of the lines of both texts, with `context_lines` unchanged
+/// lines around each change.
+pub fn unified(old: &str, new: &str, context_lines: usize) -> String {
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(context_lines)
+        .header("original", "modified")
+        .to_string()
+}
+
+/// A piece of text compared as a whole. Pieces without a key are left out of
+/// the comparison, as the ignore options ask.
+struct Token {
+    range: Range<usize>,
+    key: Option<String>,
+}
+
+/// Splits `text` into tokens of the given granularity. Lines are split into
+/// words or characters without crossing a line break, so that leading and
+/// trailing whitespace and blank lines can be told apart.
+fn tokenize(text: &str, granularity: &Granularity, ignore: &Ignore) -> Vec<Token> {
+    let fold = |value: &str| match ignore.case {
+        true => value.to_lowercase(),
+        false => value.to_string(),
+    };
+    let ignore_whitespace = ignore.surrounding_whitespace || ignore.all_whitespace;
+
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        let blank = ignore.blank_lines && content.trim().is_empty();
+
+        if *granularity == Granularity::Line {
+            let key = match () {
+                _ if blank => None,
+                _ if ignore.all_whitespace => Some(content.split_whitespace().collect()),
+                _ if ignore.surrounding_whitespace => Some(content.trim().to_string()),
+                _ => Some(line.to_string()),
+            };
+            tokens.push(Token {
+                range: start..start + line.len(),
+                key: key.map(|key| fold(&key)),
+            });
+            start += line.len();
+            continue;
+        }
+
+        let leading_end = content.len() - content.trim_start().len();
+        let trailing_start = content.trim_end().len();
+        let mut pieces: Vec<Range<usize>> = Vec::new();
+        for (offset, c) in content.char_indices() {
+            let end = offset + c.len_utf8();
+            match pieces.last_mut() {
+                Some(last)
+                    if *granularity == Granularity::Word
+                        && content[last.clone()].ends_with(char::is_whitespace)
+                            == c.is_whitespace() =>
+                {
+                    last.end = end
+                }
+                _ => pieces.push(offset..end),
+            }
+        }
+        for piece in pieces {
+            let value = &content[piece.clone()];
+            let surrounding = piece.start < leading_end || piece.start >= trailing_start;
+            let key = match () {
+                _ if blank => None,
+                _ if !value.starts_with(char::is_whitespace) => Some(fold(value)),
+                _ if ignore.all_whitespace => None,
+                _ if ignore.surrounding_whitespace && surrounding => None,
+                _ => Some(value.to_string()),
+            };
+            tokens.push(Token {
+                range: start + piece.start..start + piece.end,
+                key,
+            });
+        }
+        if content.len() < line.len() {
+            let key = match () {
+                _ if blank => None,
+                _ if ignore_whitespace => Some("\n".to_string()),
+                _ => Some(line[content.len()..].to_string()),
+            };
+            tokens.push(Token {
+                range: start + content.len()..start + line.len(),
+                key,
+            });
+        }
+        start += line.len();
+    }
+    tokens
+}
+
+/// A step through both token lists, in the order the tokens appear.
+#[derive(Clone, Copy)]
+enum Step {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+    SkipOld(usize),
+    SkipNew(usize),
+}
+
+/// Compares the keyed tokens of both lists, and walks through the ignored
+/// ones where they fall between them.
+fn diff_tokens(old: &[Token], new: &[Token]) -> Vec<Step> {
+    let keyed = |tokens: &[Token]| -> Vec<usize> {
+        (0..tokens.len())
+            .filter(|&i| tokens[i].key.is_some())
+            .collect()
+    };
+    let keys = |tokens: &[Token], indices: &[usize]| -> Vec<String> {
+        indices
+            .iter()
+            .map(|&i| tokens[i].key.clone().unwrap_or_default())
+            .collect()
+    };
+    let old_indices = keyed(old);
+    let new_indices = keyed(new);
+    let ops = similar::capture_diff_slices(
+        Algorithm::Myers,
+        &keys(old, &old_indices),
+        &keys(new, &new_indices),
+    );
+
+    let mut steps = Vec::new();
+    let mut next_old = 0;
+    let mut next_new = 0;
+    let mut skip = |steps: &mut Vec<Step>, old_end: Option<usize>, new_end: Option<usize>| {
+        if let Some(old_end) = old_end {
+            steps.extend((next_old..old_end).map(Step::SkipOld));
+            next_old = old_end + 1;
+        }
+        if let Some(new_end) = new_end {
+            steps.extend((next_new..new_end).map(Step::SkipNew));
+            next_new = new_end + 1;
+        }
+    };
+    for op in ops {
+        let (tag, old_range, new_range) = op.as_tag_tuple();
+        if tag == DiffTag::Equal {
+            for (a, b) in old_range.zip(new_range) {
+                let (i, j) = (old_indices[a], new_indices[b]);
+                skip(&mut steps, Some(i), Some(j));
+                steps.push(Step::Equal(i, j));
+            }
+            continue;
+        }
+        for a in old_range {
+            let i = old_indices[a];
+            skip(&mut steps, Some(i), None);
+            steps.push(Step::Delete(i));
+        }
+        for b in new_range {
+            let j = new_indices[b];
+            skip(&mut steps, None, Some(j));
+            steps.push(Step::Insert(j));
+        }
+    }
+    skip(&mut steps, Some(old.len()), Some(new.len()));
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marked(text: &str, changes: &[(Range<usize>, Change)]) -> Vec<(String, bool)> {
+        changes
+            .iter()
+            .map(|(range, change)| {
+                (
+                    text[range.clone()].to_string(),
+                    matches!(change, Change::Insert),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn marks_changed_words() {
+        let (text, changes) = inline(
+            "the quick fox",
+            "the slow fox",
+            &Granularity::Word,
+            &Ignore::default(),
+        );
+        assert_eq!(text, "the quickslow fox");
+        assert_eq!(
+            marked(&text, &changes),
+            [("quick".to_string(), false), ("slow".to_string(), true)]
+        );
+    }
+
+    #[test]
+    fn ignores_case_when_asked() {
+        let ignore = Ignore {
+            case: true,
+            ..Ignore::default()
+        };
+        let (text, changes) = inline("Hello", "hello", &Granularity::Word, &ignore);
+        assert_eq!(text, "hello");
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn pairs_changed_lines_side_by_side() {
+        let rows = side_by_side(
+            "a\nb\nc\n",
+            "a\nB\nc\nd\n",
+            &Granularity::Word,
+            &Ignore::default(),
+        );
+        let kinds: Vec<RowKind> = rows.iter().map(|row| row.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                RowKind::Equal,
+                RowKind::Modify,
+                RowKind::Equal,
+                RowKind::Insert
+            ]
+        );
+        let modified = rows[1].modified.as_ref().unwrap();
+        assert_eq!((modified.number, modified.text.as_str()), (2, "B"));
+    }
+
+    #[test]
+    fn writes_unified_diffs() {
+        assert_eq!(
+            unified("a\nb\n", "a\nc\n", 3),
+            "--- original\n+++ modified\n@@ -1,2 +1,2 @@\n a\n-b\n+c\n"
+        );
+    }
+}
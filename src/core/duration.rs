@@ -0,0 +1,396 @@
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// The units a duration is broken into, largest first, by their names in
+/// words and in Go's notation.
+const PARTS: [(i128, &str, &str); 7] = [
+    (86_400 * NANOS_PER_SECOND, "day", "d"),
+    (3_600 * NANOS_PER_SECOND, "hour", "h"),
+    (60 * NANOS_PER_SECOND, "minute", "m"),
+    (NANOS_PER_SECOND, "second", "s"),
+    (1_000_000, "millisecond", "ms"),
+    (1_000, "microsecond", "µs"),
+    (1, "nanosecond", "ns"),
+];
+
+/// The unit of a duration written as a bare number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum NumberUnit {
+    #[default]
+    Milliseconds,
+    Seconds,
+}
+
+impl NumberUnit {
+    pub const ALL: [Self; 2] = [Self::Milliseconds, Self::Seconds];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Milliseconds => "Milliseconds",
+            Self::Seconds => "Seconds",
+        }
+    }
+
+    fn nanos(&self) -> i128 {
+        match self {
+            Self::Milliseconds => 1_000_000,
+            Self::Seconds => NANOS_PER_SECOND,
+        }
+    }
+}
+
+/// Reads a duration written as amounts and units, as in "1h30m", "90s" or
+/// "2 hours, 5 minutes", in ISO 8601, as in "PT2H30M", as a clock time, as in
+/// "1:30:00", or as a bare number in `unit`.
+pub fn parse(value: &str, unit: NumberUnit) -> Result<Duration, ToolError> {
+    let value = value.trim();
+    let invalid = || {
+        ToolError::new(format!(
+            "{:?} is not a duration such as 1h30m, PT1H30M, or 1:30:00",
+            value
+        ))
+    };
+    let (negative, rest) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, value.strip_prefix('+').unwrap_or(value).trim_start()),
+    };
+
+    let nanos = if let Ok(amount) = rest.parse::<f64>() {
+        scale(rest, amount, unit.nanos())
+    } else if let Some(iso) = rest.strip_prefix(['P', 'p']) {
+        parse_iso(iso)
+    } else if rest.contains(':') {
+        parse_clock(rest)
+    } else {
+        parse_units(rest)
+    }
+    .ok_or_else(invalid)?;
+
+    let nanos = if negative { -nanos } else { nanos };
+    let seconds = i64::try_from(nanos.div_euclid(NANOS_PER_SECOND)).map_err(|_| invalid())?;
+    Duration::new(seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32)
+        .ok_or_else(|| ToolError::new("Duration is out of range"))
+}
+
+/// Multiplies an amount by a unit, keeping whole amounts exact.
+fn scale(text: &str, amount: f64, nanos: i128) -> Option<i128> {
+    if !amount.is_finite() || amount < 0. {
+        return None;
+    }
+    match text.parse::<i128>() {
+        Ok(amount) => amount.checked_mul(nanos),
+        Err(_) => Some((amount * nanos as f64).round() as i128),
+    }
+}
+
+fn unit_nanos(unit: &str) -> Option<i128> {
+    let nanos = match unit.to_lowercase().as_str() {
+        "w" | "wk" | "wks" | "week" | "weeks" => 7 * 86_400 * NANOS_PER_SECOND,
+        "d" | "day" | "days" => 86_400 * NANOS_PER_SECOND,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3_600 * NANOS_PER_SECOND,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60 * NANOS_PER_SECOND,
+        "s" | "sec" | "secs" | "second" | "seconds" => NANOS_PER_SECOND,
+        "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => 1_000_000,
+        "us" | "µs" | "μs" | "microsecond" | "microseconds" => 1_000,
+        "ns" | "nanosecond" | "nanoseconds" => 1,
+        _ => return None,
+    };
+    Some(nanos)
+}
+
+fn parse_units(value: &str) -> Option<i128> {
+    let mut total: i128 = 0;
+    let mut rest = value;
+    let mut any = false;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        rest = rest.strip_prefix("and ").unwrap_or(rest);
+        if rest.is_empty() {
+            break;
+        }
+        let split = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let (amount, tail) = rest.split_at(split);
+        let tail = tail.trim_start();
+        let split = tail
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(split);
+        total = total.checked_add(scale(amount, amount.parse().ok()?, unit_nanos(unit)?)?)?;
+        rest = tail;
+        any = true;
+    }
+    any.then_some(total)
+}
+
+/// Reads the part of an ISO 8601 duration after the P. Years and months are
+/// refused, as their length depends on the date they are counted from.
+fn parse_iso(value: &str) -> Option<i128> {
+    let mut total: i128 = 0;
+    let mut any = false;
+    let mut time = false;
+    let mut amount = String::new();
+    for c in value.chars() {
+        let nanos = match (c.to_ascii_uppercase(), time) {
+            ('T', false) if amount.is_empty() => {
+                time = true;
+                continue;
+            }
+            (c, _) if c.is_ascii_digit() || c == '.' || c == ',' => {
+                amount.push(if c == ',' { '.' } else { c });
+                continue;
+            }
+            ('W', false) => 7 * 86_400 * NANOS_PER_SECOND,
+            ('D', false) => 86_400 * NANOS_PER_SECOND,
+            ('H', true) => 3_600 * NANOS_PER_SECOND,
+            ('M', true) => 60 * NANOS_PER_SECOND,
+            ('S', true) => NANOS_PER_SECOND,
+            _ => return None,
+        };
+        let text = std::mem::take(&mut amount);
+        total = total.checked_add(scale(&text, text.parse().ok()?, nanos)?)?;
+        any = true;
+    }
+    (any && amount.is_empty()).then_some(total)
+}
+
+/// Reads hours, minutes and seconds as in 1:30:00, or minutes and seconds as
+/// in 4:05.5.
+fn parse_clock(value: &str) -> Option<i128> {
+    let parts = value.split(':').collect::<Vec<_>>();
+    let units = match parts.len() {
+        2 => &[60 * NANOS_PER_SECOND, NANOS_PER_SECOND][..],
+        3 => &[
+            3_600 * NANOS_PER_SECOND,
+            60 * NANOS_PER_SECOND,
+            NANOS_PER_SECOND,
+        ][..],
+        _ => return None,
+    };
+    let last = parts.len() - 1;
+    parts
+        .iter()
+        .zip(units)
+        .enumerate()
+        .try_fold(0, |total: i128, (i, (part, nanos))| {
+            // Only the seconds may have a fraction.
+            if part.is_empty() || (i < last && !part.bytes().all(|b| b.is_ascii_digit())) {
+                return None;
+            }
+            total.checked_add(scale(part, part.parse().ok()?, *nanos)?)
+        })
+}
+
+fn total_nanos(duration: Duration) -> i128 {
+    duration.num_seconds() as i128 * NANOS_PER_SECOND + duration.subsec_nanos() as i128
+}
+
+/// Writes a duration in words, as in "1 hour 30 minutes".
+pub fn humanize(duration: Duration) -> String {
+    let nanos = total_nanos(duration);
+    let mut rest = nanos.abs();
+    let words = PARTS
+        .iter()
+        .filter_map(|(unit, name, _)| {
+            let amount = rest / unit;
+            rest %= unit;
+            match amount {
+                0 => None,
+                1 => Some(format!("1 {}", name)),
+                amount => Some(format!("{} {}s", amount, name)),
+            }
+        })
+        .collect::<Vec<_>>();
+    match (words.is_empty(), nanos < 0) {
+        (true, _) => "0 seconds".to_string(),
+        (false, true) => format!("minus {}", words.join(" ")),
+        (false, false) => words.join(" "),
+    }
+}
+
+/// Writes `nanos` divided by `unit`, with as many decimals as it needs.
+fn divide(nanos: i128, unit: i128) -> String {
+    let whole = nanos / unit;
+    let fraction = (nanos % unit).abs();
+    let sign = if nanos < 0 && whole == 0 { "-" } else { "" };
+    if fraction == 0 {
+        return format!("{}{}", sign, whole);
+    }
+    let digits = unit.ilog10() as usize;
+    let fraction = format!("{:0width$}", fraction, width = digits);
+    format!("{}{}.{}", sign, whole, fraction.trim_end_matches('0'))
+}
+
+/// Rounds `nanos` divided by `unit` to a few decimals, for units that most
+/// durations do not divide evenly.
+fn approximate(nanos: i128, unit: i128) -> String {
+    let value = format!("{:.6}", nanos as f64 / unit as f64);
+    value
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+fn to_iso(nanos: i128) -> String {
+    let sign = if nanos < 0 { "-" } else { "" };
+    let nanos = nanos.abs();
+    let days = nanos / PARTS[0].0;
+    let hours = nanos % PARTS[0].0 / PARTS[1].0;
+    let minutes = nanos % PARTS[1].0 / PARTS[2].0;
+    let seconds = nanos % PARTS[2].0;
+    let mut iso = format!("{}P", sign);
+    if days > 0 {
+        iso.push_str(&format!("{}D", days));
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || days == 0 {
+        iso.push('T');
+        if hours > 0 {
+            iso.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            iso.push_str(&format!("{}M", minutes));
+        }
+        if seconds > 0 || (hours == 0 && minutes == 0) {
+            iso.push_str(&format!("{}S", divide(seconds, NANOS_PER_SECOND)));
+        }
+    }
+    iso
+}
+
+/// Writes a duration as Go's `time.Duration` prints it: hours, minutes and
+/// seconds, or the largest sub-second unit for durations under a second.
+fn to_go(nanos: i128) -> String {
+    let sign = if nanos < 0 { "-" } else { "" };
+    let nanos = nanos.abs();
+    if nanos == 0 {
+        return "0s".to_string();
+    }
+    if nanos < NANOS_PER_SECOND {
+        let (unit, _, name) = PARTS[4..]
+            .iter()
+            .find(|(unit, _, _)| nanos >= *unit)
+            .unwrap_or(&PARTS[6]);
+        return format!("{}{}{}", sign, divide(nanos, *unit), name);
+    }
+    let hours = nanos / PARTS[1].0;
+    let minutes = nanos % PARTS[1].0 / PARTS[2].0;
+    let seconds = divide(nanos % PARTS[2].0, NANOS_PER_SECOND);
+    match (hours, minutes) {
+        (0, 0) => format!("{}{}s", sign, seconds),
+        (0, minutes) => format!("{}{}m{}s", sign, minutes, seconds),
+        (hours, minutes) => format!("{}{}h{}m{}s", sign, hours, minutes, seconds),
+    }
+}
+
+fn to_clock(nanos: i128) -> String {
+    let sign = if nanos < 0 { "-" } else { "" };
+    let nanos = nanos.abs();
+    let hours = nanos / PARTS[1].0;
+    let minutes = nanos % PARTS[1].0 / PARTS[2].0;
+    let seconds = nanos % PARTS[2].0;
+    let fraction = match seconds % NANOS_PER_SECOND {
+        0 => String::new(),
+        _ => divide(seconds, NANOS_PER_SECOND)
+            .split_once('.')
+            .map(|(_, fraction)| format!(".{}", fraction))
+            .unwrap_or_default(),
+    };
+    format!(
+        "{}{:02}:{:02}:{:02}{}",
+        sign,
+        hours,
+        minutes,
+        seconds / NANOS_PER_SECOND,
+        fraction
+    )
+}
+
+/// Writes a duration in words, as totals in each unit, and in the notations
+/// [`parse`] reads.
+pub fn formats(duration: Duration) -> Vec<(&'static str, String)> {
+    let nanos = total_nanos(duration);
+    vec![
+        ("Human", humanize(duration)),
+        ("Nanoseconds", nanos.to_string()),
+        ("Milliseconds", divide(nanos, 1_000_000)),
+        ("Seconds", divide(nanos, NANOS_PER_SECOND)),
+        ("Minutes", approximate(nanos, PARTS[2].0)),
+        ("Hours", approximate(nanos, PARTS[1].0)),
+        ("Days", approximate(nanos, PARTS[0].0)),
+        ("ISO 8601", to_iso(nanos)),
+        ("Go", to_go(nanos)),
+        ("Clock", to_clock(nanos)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(value: &str) -> Duration {
+        parse(value, NumberUnit::Milliseconds).unwrap()
+    }
+
+    #[test]
+    fn reads_durations() {
+        let expected = Duration::minutes(90);
+        for value in [
+            "1h30m",
+            "1h 30m",
+            "1.5h",
+            "90 minutes",
+            "1 hour, 30 minutes",
+            "1 hour and 30 minutes",
+            "PT1H30M",
+            "PT1.5H",
+            "1:30:00",
+            "90:00",
+            "5400000",
+        ] {
+            assert_eq!(parsed(value), expected, "{}", value);
+        }
+        assert_eq!(parsed("-90s"), Duration::seconds(-90));
+        assert_eq!(parsed("P1DT2H"), Duration::hours(26));
+        assert_eq!(parsed("1.5µs"), Duration::nanoseconds(1_500));
+        assert_eq!(
+            parse("90", NumberUnit::Seconds).unwrap(),
+            Duration::seconds(90)
+        );
+        for value in ["", "soon", "1 fortnight", "P1Y", "PT", "1:2:3:4", "1.5:00"] {
+            assert!(parse(value, NumberUnit::Milliseconds).is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn humanizes_durations() {
+        assert_eq!(humanize(Duration::minutes(90)), "1 hour 30 minutes");
+        assert_eq!(humanize(Duration::seconds(-86_401)), "minus 1 day 1 second");
+        assert_eq!(humanize(Duration::zero()), "0 seconds");
+    }
+
+    #[test]
+    fn writes_durations_in_every_format() {
+        let formats = formats(parsed("1d 2h 3m 4.5s"));
+        let format = |name| &formats.iter().find(|(n, _)| *n == name).unwrap().1;
+        assert_eq!(format("Seconds"), "93784.5");
+        assert_eq!(format("Hours"), "26.05125");
+        assert_eq!(format("ISO 8601"), "P1DT2H3M4.5S");
+        assert_eq!(format("Go"), "26h3m4.5s");
+        assert_eq!(format("Clock"), "26:03:04.5");
+        for name in ["Seconds", "ISO 8601", "Go", "Clock"] {
+            assert_eq!(
+                parse(format(name), NumberUnit::Seconds).unwrap(),
+                parsed("1d 2h 3m 4.5s"),
+                "{}",
+                name
+            );
+        }
+        assert_eq!(to_go(1_500), "1.5µs");
+        assert_eq!(to_iso(0), "PT0S");
+    }
+}
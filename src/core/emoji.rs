@@ -0,0 +1,805 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Category {
+    Smileys,
+    People,
+    Nature,
+    Food,
+    Activities,
+    Travel,
+    Objects,
+    Symbols,
+    Flags,
+    Arrows,
+    Math,
+    BoxDrawing,
+    Typography,
+}
+
+impl Category {
+    pub const ALL: [Self; 13] = [
+        Self::Smileys,
+        Self::People,
+        Self::Nature,
+        Self::Food,
+        Self::Activities,
+        Self::Travel,
+        Self::Objects,
+        Self::Symbols,
+        Self::Flags,
+        Self::Arrows,
+        Self::Math,
+        Self::BoxDrawing,
+        Self::Typography,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Smileys => "Smileys & Emotion",
+            Self::People => "People & Body",
+            Self::Nature => "Animals & Nature",
+            Self::Food => "Food & Drink",
+            Self::Activities => "Activities",
+            Self::Travel => "Travel & Places",
+            Self::Objects => "Objects",
+            Self::Symbols => "Symbols",
+            Self::Flags => "Flags",
+            Self::Arrows => "Arrows",
+            Self::Math => "Math",
+            Self::BoxDrawing => "Box Drawing",
+            Self::Typography => "Typography",
+        }
+    }
+
+    /// The category's entries as (text, name, shortcode) tuples, with an
+    /// empty shortcode for symbols that have none.
+    fn entries(&self) -> &'static [(&'static str, &'static str, &'static str)] {
+        match self {
+            Self::Smileys => SMILEYS,
+            Self::People => PEOPLE,
+            Self::Nature => NATURE,
+            Self::Food => FOOD,
+            Self::Activities => ACTIVITIES,
+            Self::Travel => TRAVEL,
+            Self::Objects => OBJECTS,
+            Self::Symbols => SYMBOLS,
+            Self::Flags => FLAGS,
+            Self::Arrows => ARROWS,
+            Self::Math => MATH,
+            Self::BoxDrawing => BOX_DRAWING,
+            Self::Typography => TYPOGRAPHY,
+        }
+    }
+}
+
+/// An emoji or symbol, which may take more than one code point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub text: &'static str,
+    /// The CLDR short name of an emoji, or the Unicode name of a symbol, in
+    /// lowercase.
+    pub name: &'static str,
+    /// The GitHub and Slack shortcode, without its colons.
+    pub shortcode: Option<&'static str>,
+    pub category: Category,
+}
+
+impl Symbol {
+    pub fn code_points(&self) -> String {
+        self.text
+            .chars()
+            .map(|c| format!("U+{:04X}", c as u32))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Whether every term of the query is found in the name, the shortcode,
+    /// or, for terms starting with "U+", the code points.
+    fn matches(&self, terms: &[String]) -> bool {
+        let code_points = self.code_points().to_lowercase();
+        terms.iter().all(|term| {
+            self.name.contains(term.as_str())
+                || self
+                    .shortcode
+                    .is_some_and(|code| code.contains(term.as_str()))
+                || (term.starts_with("u+") && code_points.contains(term.as_str()))
+        })
+    }
+}
+
+/// Lists every symbol, in category order.
+pub fn symbols() -> impl Iterator<Item = Symbol> {
+    Category::ALL.into_iter().flat_map(|category| {
+        category
+            .entries()
+            .iter()
+            .map(move |&(text, name, shortcode)| Symbol {
+                text,
+                name,
+                shortcode: (!shortcode.is_empty()).then_some(shortcode),
+                category,
+            })
+    })
+}
+
+/// Finds the symbols in `category`, or in all of them, that match `query`:
+/// words of their names, a shortcode with or without its colons, code points
+/// such as "U+2192", or the symbol itself. Exact name and shortcode matches
+/// come first.
+pub fn search(query: &str, category: Option<Category>) -> Vec<Symbol> {
+    let query = query.trim();
+    let terms: Vec<String> = query
+        .to_lowercase()
+        .split_whitespace()
+        .map(|term| term.trim_matches(':').to_string())
+        .filter(|term| !term.is_empty())
+        .collect();
+    let exact = terms.join(" ");
+    let mut found: Vec<Symbol> = symbols()
+        .filter(|symbol| category.is_none_or(|category| symbol.category == category))
+        .filter(|symbol| symbol.text == query || symbol.matches(&terms))
+        .collect();
+    found.sort_by_key(|symbol| symbol.name != exact && symbol.shortcode != Some(exact.as_str()));
+    found
+}
+
+// Emoji that show as text unless followed by a variation selector are
+// written with escapes, so that the selector is not lost.
+
+const SMILEYS: &[(&str, &str, &str)] = &[
+    ("😀", "grinning face", "grinning"),
+    ("😃", "grinning face with big eyes", "smiley"),
+    ("😄", "grinning face with smiling eyes", "smile"),
+    ("😁", "beaming face with smiling eyes", "grin"),
+    ("😆", "grinning squinting face", "laughing"),
+    ("😅", "grinning face with sweat", "sweat_smile"),
+    ("🤣", "rolling on the floor laughing", "rofl"),
+    ("😂", "face with tears of joy", "joy"),
+    ("🙂", "slightly smiling face", "slightly_smiling_face"),
+    ("🙃", "upside-down face", "upside_down_face"),
+    ("😉", "winking face", "wink"),
+    ("😊", "smiling face with smiling eyes", "blush"),
+    ("😇", "smiling face with halo", "innocent"),
+    (
+        "🥰",
+        "smiling face with hearts",
+        "smiling_face_with_three_hearts",
+    ),
+    ("😍", "smiling face with heart-eyes", "heart_eyes"),
+    ("🤩", "star-struck", "star_struck"),
+    ("😘", "face blowing a kiss", "kissing_heart"),
+    ("😋", "face savoring food", "yum"),
+    ("😛", "face with tongue", "stuck_out_tongue"),
+    (
+        "😜",
+        "winking face with tongue",
+        "stuck_out_tongue_winking_eye",
+    ),
+    ("🤪", "zany face", "zany_face"),
+    ("🤔", "thinking face", "thinking"),
+    ("🤨", "face with raised eyebrow", "raised_eyebrow"),
+    ("😐", "neutral face", "neutral_face"),
+    ("😑", "expressionless face", "expressionless"),
+    ("😶", "face without mouth", "no_mouth"),
+    ("🙄", "face with rolling eyes", "roll_eyes"),
+    ("😏", "smirking face", "smirk"),
+    ("😬", "grimacing face", "grimacing"),
+    ("🤥", "lying face", "lying_face"),
+    ("😌", "relieved face", "relieved"),
+    ("😔", "pensive face", "pensive"),
+    ("😴", "sleeping face", "sleeping"),
+    ("🤒", "face with thermometer", "face_with_thermometer"),
+    ("🤯", "exploding head", "exploding_head"),
+    ("🥳", "partying face", "partying_face"),
+    ("😎", "smiling face with sunglasses", "sunglasses"),
+    ("🤓", "nerd face", "nerd_face"),
+    ("😕", "confused face", "confused"),
+    ("😟", "worried face", "worried"),
+    ("😮", "face with open mouth", "open_mouth"),
+    ("😲", "astonished face", "astonished"),
+    ("🥺", "pleading face", "pleading_face"),
+    ("😢", "crying face", "cry"),
+    ("😭", "loudly crying face", "sob"),
+    ("😱", "face screaming in fear", "scream"),
+    ("😤", "face with steam from nose", "triumph"),
+    ("😡", "enraged face", "rage"),
+    ("😠", "angry face", "angry"),
+    ("🤬", "face with symbols on mouth", "cursing_face"),
+    ("💀", "skull", "skull"),
+    ("💩", "pile of poo", "hankey"),
+    ("🤡", "clown face", "clown_face"),
+    ("👻", "ghost", "ghost"),
+    ("👽", "alien", "alien"),
+    ("🤖", "robot", "robot"),
+    ("😺", "grinning cat", "smiley_cat"),
+    ("🙈", "see-no-evil monkey", "see_no_evil"),
+    ("💯", "hundred points", "100"),
+    ("💥", "collision", "boom"),
+    ("💤", "zzz", "zzz"),
+    ("\u{2764}\u{FE0F}", "red heart", "heart"),
+    ("🧡", "orange heart", "orange_heart"),
+    ("💛", "yellow heart", "yellow_heart"),
+    ("💚", "green heart", "green_heart"),
+    ("💙", "blue heart", "blue_heart"),
+    ("💜", "purple heart", "purple_heart"),
+    ("🖤", "black heart", "black_heart"),
+    ("💔", "broken heart", "broken_heart"),
+    ("💕", "two hearts", "two_hearts"),
+];
+
+const PEOPLE: &[(&str, &str, &str)] = &[
+    ("👋", "waving hand", "wave"),
+    ("🤚", "raised back of hand", "raised_back_of_hand"),
+    ("✋", "raised hand", "hand"),
+    ("🖖", "vulcan salute", "vulcan_salute"),
+    ("👌", "ok hand", "ok_hand"),
+    ("🤌", "pinched fingers", "pinched_fingers"),
+    ("\u{270C}\u{FE0F}", "victory hand", "v"),
+    ("🤞", "crossed fingers", "crossed_fingers"),
+    ("🤘", "sign of the horns", "metal"),
+    ("👈", "backhand index pointing left", "point_left"),
+    ("👉", "backhand index pointing right", "point_right"),
+    ("👆", "backhand index pointing up", "point_up_2"),
+    ("👇", "backhand index pointing down", "point_down"),
+    ("👍", "thumbs up", "+1"),
+    ("👎", "thumbs down", "-1"),
+    ("✊", "raised fist", "fist_raised"),
+    ("👊", "oncoming fist", "fist_oncoming"),
+    ("👏", "clapping hands", "clap"),
+    ("🙌", "raising hands", "raised_hands"),
+    ("🤝", "handshake", "handshake"),
+    ("🙏", "folded hands", "pray"),
+    ("💪", "flexed biceps", "muscle"),
+    ("🧠", "brain", "brain"),
+    ("👀", "eyes", "eyes"),
+    ("👶", "baby", "baby"),
+    ("🧒", "child", "child"),
+    ("🧑", "person", "adult"),
+    ("👩", "woman", "woman"),
+    ("👨", "man", "man"),
+    ("🧓", "older person", "older_adult"),
+    ("\u{1F9D1}\u{200D}\u{1F4BB}", "technologist", "technologist"),
+    ("🤷", "person shrugging", "shrug"),
+    ("🤦", "person facepalming", "facepalm"),
+    ("🙋", "person raising hand", "raising_hand"),
+    ("🏃", "person running", "runner"),
+    ("💃", "woman dancing", "dancer"),
+];
+
+const NATURE: &[(&str, &str, &str)] = &[
+    ("🐶", "dog face", "dog"),
+    ("🐱", "cat face", "cat"),
+    ("🐭", "mouse face", "mouse"),
+    ("🦊", "fox", "fox_face"),
+    ("🐻", "bear", "bear"),
+    ("🐼", "panda", "panda_face"),
+    ("🐨", "koala", "koala"),
+    ("🐯", "tiger face", "tiger"),
+    ("🦁", "lion", "lion"),
+    ("🐮", "cow face", "cow"),
+    ("🐷", "pig face", "pig"),
+    ("🐸", "frog", "frog"),
+    ("🐵", "monkey face", "monkey_face"),
+    ("🐔", "chicken", "chicken"),
+    ("🐧", "penguin", "penguin"),
+    ("🐦", "bird", "bird"),
+    ("🦄", "unicorn", "unicorn"),
+    ("🐝", "honeybee", "bee"),
+    ("🐛", "bug", "bug"),
+    ("🦋", "butterfly", "butterfly"),
+    ("🐢", "turtle", "turtle"),
+    ("🐍", "snake", "snake"),
+    ("🐙", "octopus", "octopus"),
+    ("🐳", "spouting whale", "whale"),
+    ("🐬", "dolphin", "dolphin"),
+    ("🦀", "crab", "crab"),
+    ("🌵", "cactus", "cactus"),
+    ("🌲", "evergreen tree", "evergreen_tree"),
+    ("🌳", "deciduous tree", "deciduous_tree"),
+    ("🍀", "four leaf clover", "four_leaf_clover"),
+    ("🍁", "maple leaf", "maple_leaf"),
+    ("🌸", "cherry blossom", "cherry_blossom"),
+    ("🌹", "rose", "rose"),
+    ("🌻", "sunflower", "sunflower"),
+    ("\u{2600}\u{FE0F}", "sun", "sunny"),
+    ("🌙", "crescent moon", "crescent_moon"),
+    ("⭐", "star", "star"),
+    ("🌈", "rainbow", "rainbow"),
+    ("\u{2601}\u{FE0F}", "cloud", "cloud"),
+    ("⚡", "high voltage", "zap"),
+    ("\u{2744}\u{FE0F}", "snowflake", "snowflake"),
+    ("🔥", "fire", "fire"),
+    ("💧", "droplet", "droplet"),
+    ("🌊", "water wave", "ocean"),
+];
+
+const FOOD: &[(&str, &str, &str)] = &[
+    ("🍎", "red apple", "apple"),
+    ("🍊", "tangerine", "tangerine"),
+    ("🍋", "lemon", "lemon"),
+    ("🍌", "banana", "banana"),
+    ("🍉", "watermelon", "watermelon"),
+    ("🍇", "grapes", "grapes"),
+    ("🍓", "strawberry", "strawberry"),
+    ("🍒", "cherries", "cherries"),
+    ("🍑", "peach", "peach"),
+    ("🥭", "mango", "mango"),
+    ("🍍", "pineapple", "pineapple"),
+    ("🥑", "avocado", "avocado"),
+    ("🥕", "carrot", "carrot"),
+    ("🌽", "ear of corn", "corn"),
+    ("\u{1F336}\u{FE0F}", "hot pepper", "hot_pepper"),
+    ("🍞", "bread", "bread"),
+    ("🧀", "cheese wedge", "cheese"),
+    ("🍔", "hamburger", "hamburger"),
+    ("🍟", "french fries", "fries"),
+    ("🍕", "pizza", "pizza"),
+    ("🌭", "hot dog", "hotdog"),
+    ("🌮", "taco", "taco"),
+    ("🍣", "sushi", "sushi"),
+    ("🍜", "steaming bowl", "ramen"),
+    ("🍩", "doughnut", "doughnut"),
+    ("🍪", "cookie", "cookie"),
+    ("🎂", "birthday cake", "birthday"),
+    ("🍫", "chocolate bar", "chocolate_bar"),
+    ("🍿", "popcorn", "popcorn"),
+    ("☕", "hot beverage", "coffee"),
+    ("🍵", "teacup without handle", "tea"),
+    ("🍺", "beer mug", "beer"),
+    ("🍷", "wine glass", "wine_glass"),
+    ("🥂", "clinking glasses", "clinking_glasses"),
+];
+
+const ACTIVITIES: &[(&str, &str, &str)] = &[
+    ("⚽", "soccer ball", "soccer"),
+    ("🏀", "basketball", "basketball"),
+    ("🏈", "american football", "football"),
+    ("⚾", "baseball", "baseball"),
+    ("🎾", "tennis", "tennis"),
+    ("🏐", "volleyball", "volleyball"),
+    ("🏓", "ping pong", "ping_pong"),
+    ("🎱", "pool 8 ball", "8ball"),
+    ("⛳", "flag in hole", "golf"),
+    ("🎯", "bullseye", "dart"),
+    ("🎮", "video game", "video_game"),
+    ("\u{1F579}\u{FE0F}", "joystick", "joystick"),
+    ("🎲", "game die", "game_die"),
+    ("\u{265F}\u{FE0F}", "chess pawn", "chess_pawn"),
+    ("🧩", "puzzle piece", "jigsaw"),
+    ("🎨", "artist palette", "art"),
+    ("🎭", "performing arts", "performing_arts"),
+    ("🎬", "clapper board", "clapper"),
+    ("🎤", "microphone", "microphone"),
+    ("🎧", "headphone", "headphones"),
+    ("🎸", "guitar", "guitar"),
+    ("🎹", "musical keyboard", "musical_keyboard"),
+    ("🏆", "trophy", "trophy"),
+    ("🥇", "1st place medal", "1st_place_medal"),
+    ("🎉", "party popper", "tada"),
+    ("🎈", "balloon", "balloon"),
+    ("🎁", "wrapped gift", "gift"),
+    ("🎄", "christmas tree", "christmas_tree"),
+    ("✨", "sparkles", "sparkles"),
+];
+
+const TRAVEL: &[(&str, &str, &str)] = &[
+    ("🚗", "automobile", "car"),
+    ("🚕", "taxi", "taxi"),
+    ("🚌", "bus", "bus"),
+    ("🚑", "ambulance", "ambulance"),
+    ("🚒", "fire engine", "fire_engine"),
+    ("🚓", "police car", "police_car"),
+    ("🚲", "bicycle", "bike"),
+    ("🛵", "motor scooter", "motor_scooter"),
+    ("🚂", "locomotive", "steam_locomotive"),
+    ("🚆", "train", "train2"),
+    ("\u{2708}\u{FE0F}", "airplane", "airplane"),
+    ("🚀", "rocket", "rocket"),
+    ("🛸", "flying saucer", "flying_saucer"),
+    ("🚁", "helicopter", "helicopter"),
+    ("⛵", "sailboat", "boat"),
+    ("🚢", "ship", "ship"),
+    ("⚓", "anchor", "anchor"),
+    ("🚦", "vertical traffic light", "vertical_traffic_light"),
+    ("🚧", "construction", "construction"),
+    ("🏠", "house", "house"),
+    ("🏢", "office building", "office"),
+    ("🏥", "hospital", "hospital"),
+    ("🏫", "school", "school"),
+    ("🏰", "castle", "european_castle"),
+    ("🗼", "tokyo tower", "tokyo_tower"),
+    ("🗽", "statue of liberty", "statue_of_liberty"),
+    ("\u{26F0}\u{FE0F}", "mountain", "mountain"),
+    ("\u{1F3D6}\u{FE0F}", "beach with umbrella", "beach_umbrella"),
+    ("🌋", "volcano", "volcano"),
+    ("🌍", "globe showing europe-africa", "earth_africa"),
+    ("🌎", "globe showing americas", "earth_americas"),
+    ("🌏", "globe showing asia-australia", "earth_asia"),
+    ("\u{1F5FA}\u{FE0F}", "world map", "world_map"),
+    ("⏰", "alarm clock", "alarm_clock"),
+    ("⌛", "hourglass done", "hourglass"),
+];
+
+const OBJECTS: &[(&str, &str, &str)] = &[
+    ("⌚", "watch", "watch"),
+    ("📱", "mobile phone", "iphone"),
+    ("💻", "laptop", "computer"),
+    ("\u{2328}\u{FE0F}", "keyboard", "keyboard"),
+    ("\u{1F5A5}\u{FE0F}", "desktop computer", "desktop_computer"),
+    ("\u{1F5A8}\u{FE0F}", "printer", "printer"),
+    ("\u{1F5B1}\u{FE0F}", "computer mouse", "computer_mouse"),
+    ("💾", "floppy disk", "floppy_disk"),
+    ("💿", "optical disk", "cd"),
+    ("📷", "camera", "camera"),
+    ("🔋", "battery", "battery"),
+    ("🔌", "electric plug", "electric_plug"),
+    ("💡", "light bulb", "bulb"),
+    ("🔦", "flashlight", "flashlight"),
+    ("📚", "books", "books"),
+    ("📖", "open book", "book"),
+    ("📝", "memo", "memo"),
+    ("\u{270F}\u{FE0F}", "pencil", "pencil2"),
+    ("📎", "paperclip", "paperclip"),
+    ("📌", "pushpin", "pushpin"),
+    ("📅", "calendar", "date"),
+    ("📈", "chart increasing", "chart_with_upwards_trend"),
+    ("📉", "chart decreasing", "chart_with_downwards_trend"),
+    ("📊", "bar chart", "bar_chart"),
+    ("📦", "package", "package"),
+    ("📧", "e-mail", "email"),
+    ("📬", "open mailbox with raised flag", "mailbox_with_mail"),
+    ("🔒", "locked", "lock"),
+    ("🔓", "unlocked", "unlock"),
+    ("🔑", "key", "key"),
+    ("🔨", "hammer", "hammer"),
+    ("🔧", "wrench", "wrench"),
+    ("\u{2699}\u{FE0F}", "gear", "gear"),
+    ("🧪", "test tube", "test_tube"),
+    ("🔍", "magnifying glass tilted left", "mag"),
+    ("\u{1F5D1}\u{FE0F}", "wastebasket", "wastebasket"),
+    ("💰", "money bag", "moneybag"),
+    ("💳", "credit card", "credit_card"),
+    ("🔔", "bell", "bell"),
+    ("📣", "megaphone", "mega"),
+];
+
+const SYMBOLS: &[(&str, &str, &str)] = &[
+    ("✅", "check mark button", "white_check_mark"),
+    (
+        "\u{2611}\u{FE0F}",
+        "check box with check",
+        "ballot_box_with_check",
+    ),
+    ("\u{2714}\u{FE0F}", "check mark", "heavy_check_mark"),
+    ("❌", "cross mark", "x"),
+    ("❎", "cross mark button", "negative_squared_cross_mark"),
+    ("➕", "plus", "heavy_plus_sign"),
+    ("➖", "minus", "heavy_minus_sign"),
+    ("➗", "divide", "heavy_division_sign"),
+    ("❓", "red question mark", "question"),
+    ("❗", "red exclamation mark", "exclamation"),
+    ("\u{26A0}\u{FE0F}", "warning", "warning"),
+    ("⛔", "no entry", "no_entry"),
+    ("🚫", "prohibited", "no_entry_sign"),
+    ("\u{267B}\u{FE0F}", "recycling symbol", "recycle"),
+    (
+        "\u{2733}\u{FE0F}",
+        "eight-spoked asterisk",
+        "eight_spoked_asterisk",
+    ),
+    ("🔴", "red circle", "red_circle"),
+    ("🟠", "orange circle", "orange_circle"),
+    ("🟡", "yellow circle", "yellow_circle"),
+    ("🟢", "green circle", "green_circle"),
+    ("🔵", "blue circle", "large_blue_circle"),
+    ("🟣", "purple circle", "purple_circle"),
+    ("⚫", "black circle", "black_circle"),
+    ("⚪", "white circle", "white_circle"),
+    ("🟥", "red square", "red_square"),
+    ("🟩", "green square", "green_square"),
+    ("🔶", "large orange diamond", "large_orange_diamond"),
+    ("🔷", "large blue diamond", "large_blue_diamond"),
+    ("\u{2139}\u{FE0F}", "information", "information_source"),
+    ("🆗", "ok button", "ok"),
+    ("🆕", "new button", "new"),
+    ("🆓", "free button", "free"),
+    ("🔝", "top arrow", "top"),
+    ("🔜", "soon arrow", "soon"),
+    ("\u{00A9}\u{FE0F}", "copyright", "copyright"),
+    ("\u{00AE}\u{FE0F}", "registered", "registered"),
+    ("\u{2122}\u{FE0F}", "trade mark", "tm"),
+    ("#\u{FE0F}\u{20E3}", "keycap: #", "hash"),
+    ("1\u{FE0F}\u{20E3}", "keycap: 1", "one"),
+    ("🔟", "keycap: 10", "keycap_ten"),
+    ("\u{27A1}\u{FE0F}", "right arrow", "arrow_right"),
+    ("\u{2B05}\u{FE0F}", "left arrow", "arrow_left"),
+    ("\u{2B06}\u{FE0F}", "up arrow", "arrow_up"),
+    ("\u{2B07}\u{FE0F}", "down arrow", "arrow_down"),
+    (
+        "🔄",
+        "counterclockwise arrows button",
+        "arrows_counterclockwise",
+    ),
+    ("🔁", "repeat button", "repeat"),
+    ("\u{25B6}\u{FE0F}", "play button", "arrow_forward"),
+    ("\u{23F8}\u{FE0F}", "pause button", "pause_button"),
+    ("\u{23F9}\u{FE0F}", "stop button", "stop_button"),
+    ("🔇", "muted speaker", "mute"),
+    ("🔊", "speaker high volume", "loud_sound"),
+];
+
+const FLAGS: &[(&str, &str, &str)] = &[
+    ("🏁", "chequered flag", "checkered_flag"),
+    ("🚩", "triangular flag", "triangular_flag_on_post"),
+    ("\u{1F3F3}\u{FE0F}", "white flag", "white_flag"),
+    ("🏴", "black flag", "black_flag"),
+    (
+        "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+        "rainbow flag",
+        "rainbow_flag",
+    ),
+    ("🇺🇳", "flag: united nations", "united_nations"),
+    ("🇪🇺", "flag: european union", "eu"),
+    ("🇺🇸", "flag: united states", "us"),
+    ("🇬🇧", "flag: united kingdom", "gb"),
+    ("🇨🇦", "flag: canada", "canada"),
+    ("🇲🇽", "flag: mexico", "mexico"),
+    ("🇧🇷", "flag: brazil", "brazil"),
+    ("🇫🇷", "flag: france", "fr"),
+    ("🇩🇪", "flag: germany", "de"),
+    ("🇪🇸", "flag: spain", "es"),
+    ("🇮🇹", "flag: italy", "it"),
+    ("🇳🇱", "flag: netherlands", "netherlands"),
+    ("🇸🇪", "flag: sweden", "sweden"),
+    ("🇺🇦", "flag: ukraine", "ukraine"),
+    ("🇮🇳", "flag: india", "india"),
+    ("🇧🇩", "flag: bangladesh", "bangladesh"),
+    ("🇨🇳", "flag: china", "cn"),
+    ("🇯🇵", "flag: japan", "jp"),
+    ("🇰🇷", "flag: south korea", "kr"),
+    ("🇦🇺", "flag: australia", "australia"),
+    ("🇳🇿", "flag: new zealand", "new_zealand"),
+    ("🇿🇦", "flag: south africa", "south_africa"),
+    ("🇳🇬", "flag: nigeria", "nigeria"),
+    ("🇪🇬", "flag: egypt", "egypt"),
+];
+
+const ARROWS: &[(&str, &str, &str)] = &[
+    ("←", "leftwards arrow", ""),
+    ("↑", "upwards arrow", ""),
+    ("→", "rightwards arrow", ""),
+    ("↓", "downwards arrow", ""),
+    ("↔", "left right arrow", ""),
+    ("↕", "up down arrow", ""),
+    ("↖", "north west arrow", ""),
+    ("↗", "north east arrow", ""),
+    ("↘", "south east arrow", ""),
+    ("↙", "south west arrow", ""),
+    ("↩", "leftwards arrow with hook", ""),
+    ("↪", "rightwards arrow with hook", ""),
+    ("↵", "downwards arrow with corner leftwards", ""),
+    ("↻", "clockwise open circle arrow", ""),
+    ("↺", "anticlockwise open circle arrow", ""),
+    ("⇐", "leftwards double arrow", ""),
+    ("⇑", "upwards double arrow", ""),
+    ("⇒", "rightwards double arrow", ""),
+    ("⇓", "downwards double arrow", ""),
+    ("⇔", "left right double arrow", ""),
+    ("⇄", "rightwards arrow over leftwards arrow", ""),
+    ("⇆", "leftwards arrow over rightwards arrow", ""),
+    ("⇤", "leftwards arrow to bar", ""),
+    ("⇥", "rightwards arrow to bar", ""),
+    ("⇧", "upwards white arrow", ""),
+    ("⟵", "long leftwards arrow", ""),
+    ("⟶", "long rightwards arrow", ""),
+    ("⟷", "long left right arrow", ""),
+    ("⟹", "long rightwards double arrow", ""),
+    ("➔", "heavy wide-headed rightwards arrow", ""),
+    ("➜", "heavy round-tipped rightwards arrow", ""),
+    ("▲", "black up-pointing triangle", ""),
+    ("▼", "black down-pointing triangle", ""),
+    ("◀", "black left-pointing triangle", ""),
+    ("▶", "black right-pointing triangle", ""),
+    ("⌃", "up arrowhead", ""),
+    ("⌄", "down arrowhead", ""),
+    ("«", "left-pointing double angle quotation mark", ""),
+    ("»", "right-pointing double angle quotation mark", ""),
+    ("‹", "single left-pointing angle quotation mark", ""),
+    ("›", "single right-pointing angle quotation mark", ""),
+];
+
+const MATH: &[(&str, &str, &str)] = &[
+    ("±", "plus-minus sign", ""),
+    ("∓", "minus-or-plus sign", ""),
+    ("×", "multiplication sign", ""),
+    ("÷", "division sign", ""),
+    ("−", "minus sign", ""),
+    ("≠", "not equal to", ""),
+    ("≈", "almost equal to", ""),
+    ("≡", "identical to", ""),
+    ("≤", "less-than or equal to", ""),
+    ("≥", "greater-than or equal to", ""),
+    ("≪", "much less-than", ""),
+    ("≫", "much greater-than", ""),
+    ("∞", "infinity", ""),
+    ("√", "square root", ""),
+    ("∛", "cube root", ""),
+    ("∑", "n-ary summation", ""),
+    ("∏", "n-ary product", ""),
+    ("∫", "integral", ""),
+    ("∂", "partial differential", ""),
+    ("∆", "increment", ""),
+    ("∇", "nabla", ""),
+    ("∈", "element of", ""),
+    ("∉", "not an element of", ""),
+    ("∋", "contains as member", ""),
+    ("⊂", "subset of", ""),
+    ("⊃", "superset of", ""),
+    ("⊆", "subset of or equal to", ""),
+    ("⊇", "superset of or equal to", ""),
+    ("∪", "union", ""),
+    ("∩", "intersection", ""),
+    ("∅", "empty set", ""),
+    ("∀", "for all", ""),
+    ("∃", "there exists", ""),
+    ("¬", "not sign", ""),
+    ("∧", "logical and", ""),
+    ("∨", "logical or", ""),
+    ("⊕", "circled plus", ""),
+    ("⊗", "circled times", ""),
+    ("∝", "proportional to", ""),
+    ("∴", "therefore", ""),
+    ("∵", "because", ""),
+    ("°", "degree sign", ""),
+    ("′", "prime", ""),
+    ("″", "double prime", ""),
+    ("‰", "per mille sign", ""),
+    ("π", "greek small letter pi", ""),
+    ("θ", "greek small letter theta", ""),
+    ("λ", "greek small letter lamda", ""),
+    ("µ", "micro sign", ""),
+    ("σ", "greek small letter sigma", ""),
+    ("Ω", "greek capital letter omega", ""),
+    ("½", "vulgar fraction one half", ""),
+    ("¼", "vulgar fraction one quarter", ""),
+    ("¾", "vulgar fraction three quarters", ""),
+    ("²", "superscript two", ""),
+    ("³", "superscript three", ""),
+    ("ℕ", "double-struck capital n", ""),
+    ("ℤ", "double-struck capital z", ""),
+    ("ℚ", "double-struck capital q", ""),
+    ("ℝ", "double-struck capital r", ""),
+    ("ℂ", "double-struck capital c", ""),
+];
+
+const BOX_DRAWING: &[(&str, &str, &str)] = &[
+    ("─", "box drawings light horizontal", ""),
+    ("│", "box drawings light vertical", ""),
+    ("┌", "box drawings light down and right", ""),
+    ("┐", "box drawings light down and left", ""),
+    ("└", "box drawings light up and right", ""),
+    ("┘", "box drawings light up and left", ""),
+    ("├", "box drawings light vertical and right", ""),
+    ("┤", "box drawings light vertical and left", ""),
+    ("┬", "box drawings light down and horizontal", ""),
+    ("┴", "box drawings light up and horizontal", ""),
+    ("┼", "box drawings light vertical and horizontal", ""),
+    ("━", "box drawings heavy horizontal", ""),
+    ("┃", "box drawings heavy vertical", ""),
+    ("┏", "box drawings heavy down and right", ""),
+    ("┓", "box drawings heavy down and left", ""),
+    ("┗", "box drawings heavy up and right", ""),
+    ("┛", "box drawings heavy up and left", ""),
+    ("═", "box drawings double horizontal", ""),
+    ("║", "box drawings double vertical", ""),
+    ("╔", "box drawings double down and right", ""),
+    ("╗", "box drawings double down and left", ""),
+    ("╚", "box drawings double up and right", ""),
+    ("╝", "box drawings double up and left", ""),
+    ("╠", "box drawings double vertical and right", ""),
+    ("╣", "box drawings double vertical and left", ""),
+    ("╦", "box drawings double down and horizontal", ""),
+    ("╩", "box drawings double up and horizontal", ""),
+    ("╬", "box drawings double vertical and horizontal", ""),
+    ("╭", "box drawings light arc down and right", ""),
+    ("╮", "box drawings light arc down and left", ""),
+    ("╯", "box drawings light arc up and left", ""),
+    ("╰", "box drawings light arc up and right", ""),
+    ("┄", "box drawings light triple dash horizontal", ""),
+    ("┆", "box drawings light triple dash vertical", ""),
+    ("╌", "box drawings light double dash horizontal", ""),
+    ("█", "full block", ""),
+    ("▀", "upper half block", ""),
+    ("▄", "lower half block", ""),
+    ("▌", "left half block", ""),
+    ("▐", "right half block", ""),
+    ("░", "light shade", ""),
+    ("▒", "medium shade", ""),
+    ("▓", "dark shade", ""),
+    ("■", "black square", ""),
+    ("□", "white square", ""),
+];
+
+const TYPOGRAPHY: &[(&str, &str, &str)] = &[
+    ("•", "bullet", ""),
+    ("·", "middle dot", ""),
+    ("…", "horizontal ellipsis", ""),
+    ("–", "en dash", ""),
+    ("—", "em dash", ""),
+    ("‘", "left single quotation mark", ""),
+    ("’", "right single quotation mark", ""),
+    ("“", "left double quotation mark", ""),
+    ("”", "right double quotation mark", ""),
+    ("§", "section sign", ""),
+    ("¶", "pilcrow sign", ""),
+    ("†", "dagger", ""),
+    ("‡", "double dagger", ""),
+    ("©", "copyright sign", ""),
+    ("®", "registered sign", ""),
+    ("™", "trade mark sign", ""),
+    ("✓", "check mark", ""),
+    ("✗", "ballot x", ""),
+    ("★", "black star", ""),
+    ("☆", "white star", ""),
+    ("♠", "black spade suit", ""),
+    ("♥", "black heart suit", ""),
+    ("♦", "black diamond suit", ""),
+    ("♣", "black club suit", ""),
+    ("⌘", "place of interest sign", ""),
+    ("⌥", "option key", ""),
+    ("⎋", "broken circle with northwest arrow", ""),
+    ("⏎", "return symbol", ""),
+    ("€", "euro sign", ""),
+    ("£", "pound sign", ""),
+    ("¥", "yen sign", ""),
+    ("¢", "cent sign", ""),
+    ("₹", "indian rupee sign", ""),
+    ("৳", "bengali rupee sign", ""),
+    ("₿", "bitcoin sign", ""),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_code_points() {
+        let heart = search(":heart:", None)[0];
+        assert_eq!(heart.text, "❤️");
+        assert_eq!(heart.code_points(), "U+2764 U+FE0F");
+        let technologist = search("technologist", None)[0];
+        assert_eq!(technologist.code_points(), "U+1F9D1 U+200D U+1F4BB");
+    }
+
+    #[test]
+    fn searches_names_shortcodes_and_code_points() {
+        let texts = |query, category| {
+            search(query, category)
+                .iter()
+                .map(|symbol| symbol.text)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(texts("+1", None), ["👍"]);
+        assert_eq!(
+            texts("rightwards double", Some(Category::Arrows)),
+            ["⇒", "⟹"]
+        );
+        assert_eq!(texts("u+2192", None), ["→"]);
+        assert_eq!(texts("∞", None), ["∞"]);
+        assert_eq!(texts("Light Arc", None), ["╭", "╮", "╯", "╰"]);
+        // Exact matches come before the symbols that only contain the query.
+        assert_eq!(texts("fire", None)[0], "🔥");
+        assert_eq!(search("", Some(Category::Math)).len(), MATH.len());
+        assert!(texts("nothing like this", None).is_empty());
+    }
+
+    #[test]
+    fn shortcodes_are_unique() {
+        let mut shortcodes: Vec<_> = symbols().filter_map(|symbol| symbol.shortcode).collect();
+        let count = shortcodes.len();
+        shortcodes.sort();
+        shortcodes.dedup();
+        assert_eq!(shortcodes.len(), count);
+    }
+}
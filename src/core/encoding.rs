@@ -0,0 +1,259 @@
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+/// The text encodings that can be converted between.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Encoding {
+    #[default]
+    UTF8,
+    UTF16LE,
+    UTF16BE,
+    Latin1,
+}
+
+impl Encoding {
+    pub const ALL: [Self; 4] = [Self::UTF8, Self::UTF16LE, Self::UTF16BE, Self::Latin1];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UTF8 => "UTF-8",
+            Self::UTF16LE => "UTF-16 LE",
+            Self::UTF16BE => "UTF-16 BE",
+            Self::Latin1 => "Latin-1",
+        }
+    }
+}
+
+/// Text decoded from bytes, with invalid sequences replaced by U+FFFD.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decoded {
+    pub text: String,
+    /// The byte ranges of the invalid sequences.
+    pub invalid: Vec<Range<usize>>,
+}
+
+pub fn encode(value: &str, encoding: Encoding) -> Result<Vec<u8>, ToolError> {
+    match encoding {
+        Encoding::UTF8 => Ok(value.as_bytes().to_vec()),
+        Encoding::UTF16LE => Ok(value.encode_utf16().flat_map(u16::to_le_bytes).collect()),
+        Encoding::UTF16BE => Ok(value.encode_utf16().flat_map(u16::to_be_bytes).collect()),
+        Encoding::Latin1 => value
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                u8::try_from(c).map_err(|_| {
+                    ToolError::new(format!("U+{:04X} has no Latin-1 encoding", c as u32))
+                        .at_offset(i)
+                })
+            })
+            .collect(),
+    }
+}
+
+pub fn decode(bytes: &[u8], encoding: Encoding) -> Decoded {
+    let mut text = String::with_capacity(bytes.len());
+    let mut invalid = Vec::new();
+    match encoding {
+        Encoding::UTF8 => {
+            let mut offset = 0;
+            for chunk in bytes.utf8_chunks() {
+                text.push_str(chunk.valid());
+                offset += chunk.valid().len();
+                if !chunk.invalid().is_empty() {
+                    text.push(char::REPLACEMENT_CHARACTER);
+                    invalid.push(offset..offset + chunk.invalid().len());
+                    offset += chunk.invalid().len();
+                }
+            }
+        }
+        Encoding::UTF16LE | Encoding::UTF16BE => {
+            let units = bytes.chunks_exact(2).map(|pair| {
+                let pair = [pair[0], pair[1]];
+                match encoding {
+                    Encoding::UTF16LE => u16::from_le_bytes(pair),
+                    _ => u16::from_be_bytes(pair),
+                }
+            });
+            let mut offset = 0;
+            for c in char::decode_utf16(units) {
+                match c {
+                    Ok(c) => {
+                        text.push(c);
+                        offset += c.len_utf16() * 2;
+                    }
+                    Err(_) => {
+                        text.push(char::REPLACEMENT_CHARACTER);
+                        invalid.push(offset..offset + 2);
+                        offset += 2;
+                    }
+                }
+            }
+            // A trailing odd byte is half a code unit.
+            if bytes.len() % 2 == 1 {
+                text.push(char::REPLACEMENT_CHARACTER);
+                invalid.push(bytes.len() - 1..bytes.len());
+            }
+        }
+        Encoding::Latin1 => text.extend(bytes.iter().map(|&byte| byte as char)),
+    }
+    Decoded { text, invalid }
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parses hex bytes, with or without spaces, commas, and `0x` or `\x`
+/// prefixes between them.
+pub fn parse_hex(value: &str) -> Result<Vec<u8>, ToolError> {
+    // Prefixes are blanked rather than removed so that offsets still match.
+    let value = value
+        .replace("0x", "  ")
+        .replace("0X", "  ")
+        .replace("\\x", "  ");
+    let mut digits = Vec::new();
+    for (i, c) in value.chars().enumerate() {
+        match c {
+            c if c.is_ascii_hexdigit() => digits.push(c as u8),
+            c if c.is_whitespace() || c == ',' => {}
+            c => {
+                return Err(ToolError::new(format!("Unexpected character '{}'", c))
+                    .context("Invalid hex")
+                    .at_offset(i));
+            }
+        }
+    }
+    if !digits.len().is_multiple_of(2) {
+        return Err(ToolError::new(format!(
+            "Expected an even number of digits, found {}",
+            digits.len()
+        ))
+        .context("Invalid hex"));
+    }
+    Ok(digits
+        .chunks(2)
+        .map(|pair| {
+            let digit = |d: u8| (d as char).to_digit(16).unwrap_or_default() as u8;
+            digit(pair[0]) << 4 | digit(pair[1])
+        })
+        .collect())
+}
+
+/// How bytes are written as text in an input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum InputEncoding {
+    /// Hex if the input is valid hex, and Base64 otherwise.
+    #[default]
+    Auto,
+    Base64,
+    Hex,
+}
+
+impl InputEncoding {
+    pub const ALL: [Self; 3] = [Self::Auto, Self::Base64, Self::Hex];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Auto => "Detect Automatically",
+            Self::Base64 => "Base64",
+            Self::Hex => "Hex",
+        }
+    }
+}
+
+/// Reads the bytes written in `text` as hex or Base64. Base64 may be
+/// URL-safe and may leave out its padding.
+pub fn parse_bytes(text: &str, encoding: InputEncoding) -> Result<Vec<u8>, ToolError> {
+    let from_base64 = || {
+        let mut text = text
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c {
+                '-' => '+',
+                '_' => '/',
+                c => c,
+            })
+            .collect::<String>();
+        while !text.len().is_multiple_of(4) {
+            text.push('=');
+        }
+        super::base64::decode_bytes(&text)
+    };
+    match encoding {
+        InputEncoding::Auto => parse_hex(text).or_else(|_| from_base64()),
+        InputEncoding::Base64 => from_base64(),
+        InputEncoding::Hex => parse_hex(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_text() {
+        let hex = |encoding| to_hex(&encode("hé🦀", encoding).unwrap());
+        assert_eq!(hex(Encoding::UTF8), "68 c3 a9 f0 9f a6 80");
+        assert_eq!(hex(Encoding::UTF16LE), "68 00 e9 00 3e d8 80 dd");
+        assert_eq!(hex(Encoding::UTF16BE), "00 68 00 e9 d8 3e dd 80");
+        assert_eq!(to_hex(&encode("hé", Encoding::Latin1).unwrap()), "68 e9");
+        let err = encode("hé🦀", Encoding::Latin1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "U+1F980 has no Latin-1 encoding (at character 3)"
+        );
+    }
+
+    #[test]
+    fn round_trips_every_encoding() {
+        for encoding in Encoding::ALL {
+            let bytes = encode("Grüße", encoding).unwrap();
+            assert_eq!(decode(&bytes, encoding).text, "Grüße");
+        }
+    }
+
+    #[test]
+    fn flags_invalid_sequences() {
+        let decoded = decode(b"a\xffb\xe2\x82", Encoding::UTF8);
+        assert_eq!(decoded.text, "a\u{FFFD}b\u{FFFD}");
+        assert_eq!(decoded.invalid, vec![1..2, 3..5]);
+
+        // A lone high surrogate, then an odd trailing byte.
+        let decoded = decode(b"\x3e\xd8\x61\x00\x62", Encoding::UTF16LE);
+        assert_eq!(decoded.text, "\u{FFFD}a\u{FFFD}");
+        assert_eq!(decoded.invalid, vec![0..2, 4..5]);
+
+        assert!(decode(b"\xff\x00", Encoding::Latin1).invalid.is_empty());
+    }
+
+    #[test]
+    fn parses_hex() {
+        assert_eq!(parse_hex("48 65 6c").unwrap(), b"Hel");
+        assert_eq!(parse_hex("0x48,0x65 \\x6C6c").unwrap(), b"Hell");
+        assert!(parse_hex("4").is_err());
+        assert!(parse_hex("zz").is_err());
+    }
+
+    #[test]
+    fn parses_bytes_as_hex_or_base64() {
+        assert_eq!(parse_bytes("48 65", InputEncoding::Auto).unwrap(), b"He");
+        assert_eq!(parse_bytes("SGk", InputEncoding::Auto).unwrap(), b"Hi");
+        assert_eq!(
+            parse_bytes("-_8", InputEncoding::Base64).unwrap(),
+            b"\xfb\xff"
+        );
+        assert_eq!(
+            parse_bytes("4865", InputEncoding::Base64).unwrap(),
+            b"\xe3\xce\xb9"
+        );
+        assert!(parse_bytes("SGk", InputEncoding::Hex).is_err());
+    }
+}
@@ -0,0 +1,447 @@
+use std::{iter::Peekable, str::CharIndices};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+/// The languages that strings can be escaped for.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Language {
+    #[default]
+    JSON,
+    JavaScript,
+    Rust,
+    C,
+    CSV,
+    XML,
+    Shell,
+}
+
+impl Language {
+    pub const ALL: [Self; 7] = [
+        Self::JSON,
+        Self::JavaScript,
+        Self::Rust,
+        Self::C,
+        Self::CSV,
+        Self::XML,
+        Self::Shell,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::JSON => "JSON",
+            Self::JavaScript => "JavaScript",
+            Self::Rust => "Rust",
+            Self::C => "C",
+            Self::CSV => "CSV",
+            Self::XML => "XML",
+            Self::Shell => "Shell",
+        }
+    }
+}
+
+/// Escapes `value` into a literal that can be pasted into source in
+/// `language`: a double-quoted string for JSON, JavaScript, Rust, and C, a
+/// field for CSV, text content for XML, and a single-quoted word for shells.
+pub fn escape(value: &str, language: Language) -> String {
+    match language {
+        Language::CSV => escape_csv(value),
+        Language::XML => escape_xml(value),
+        Language::Shell => escape_shell(value),
+        _ => escape_backslashes(value, language),
+    }
+}
+
+/// Reverses `escape`. The quotes around a string literal may be left out.
+pub fn unescape(value: &str, language: Language) -> Result<String, ToolError> {
+    match language {
+        Language::CSV => unescape_csv(value),
+        Language::XML => unescape_xml(value),
+        Language::Shell => unescape_shell(value),
+        _ => {
+            let quotes: &[char] = match language {
+                Language::JavaScript => &['"', '\'', '`'],
+                _ => &['"'],
+            };
+            let unquoted = quotes
+                .iter()
+                .find_map(|&quote| {
+                    value
+                        .strip_prefix(quote)
+                        .and_then(|value| value.strip_suffix(quote))
+                })
+                .unwrap_or(value);
+            let offset = (value.len() - unquoted.len()) / 2;
+            unescape_backslashes(unquoted, language)
+                .map_err(|(err, at)| err.at_offset(value[..offset + at].chars().count()))
+        }
+    }
+}
+
+fn escape_backslashes(value: &str, language: Language) -> String {
+    let mut escaped = String::from('"');
+    for c in value.chars() {
+        match (language, c) {
+            (_, '"') => escaped.push_str("\\\""),
+            (_, '\\') => escaped.push_str("\\\\"),
+            (_, '\n') => escaped.push_str("\\n"),
+            (_, '\r') => escaped.push_str("\\r"),
+            (_, '\t') => escaped.push_str("\\t"),
+            (Language::Rust, '\0') => escaped.push_str("\\0"),
+            (Language::Rust, c) if c.is_control() => {
+                escaped.push_str(&format!("\\u{{{:x}}}", c as u32))
+            }
+            (_, '\x08') => escaped.push_str("\\b"),
+            (_, '\x0c') => escaped.push_str("\\f"),
+            (Language::JavaScript | Language::C, '\x0b') => escaped.push_str("\\v"),
+            (Language::C, '\x07') => escaped.push_str("\\a"),
+            (Language::C, c) if c.is_ascii_control() => {
+                escaped.push_str(&format!("\\{:03o}", c as u32))
+            }
+            (Language::JavaScript, c) if c.is_ascii_control() => {
+                escaped.push_str(&format!("\\x{:02X}", c as u32))
+            }
+            // Line and paragraph separators end a line in JavaScript.
+            (Language::JavaScript, '\u{2028}' | '\u{2029}') => {
+                escaped.push_str(&format!("\\u{:04X}", c as u32))
+            }
+            (Language::JSON, c) if c.is_ascii_control() && c != '\x7f' => {
+                escaped.push_str(&format!("\\u{:04X}", c as u32))
+            }
+            (_, c) => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Unescapes the backslash escapes of a string literal, returning the byte
+/// offset of the offending escape with any error.
+fn unescape_backslashes(value: &str, language: Language) -> Result<String, (ToolError, usize)> {
+    use Language::*;
+
+    // C escapes stand for bytes, which only need to form UTF-8 once joined.
+    let mut bytes = Vec::with_capacity(value.len());
+    let push = |bytes: &mut Vec<u8>, c: char| {
+        bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes())
+    };
+    let mut chars = value.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        if c != '\\' {
+            push(&mut bytes, c);
+            continue;
+        }
+        let Some((_, escape)) = chars.next() else {
+            return Err((ToolError::new("unfinished escape"), offset));
+        };
+        let invalid = |message: &str| (ToolError::new(message.to_string()), offset);
+        let unknown = || invalid(&format!("unknown escape \\{}", escape));
+        let c = match (language, escape) {
+            (_, '"' | '\\') => escape,
+            (_, 'n') => '\n',
+            (_, 'r') => '\r',
+            (_, 't') => '\t',
+            (JSON | JavaScript | C, 'b') => '\x08',
+            (JSON | JavaScript | C, 'f') => '\x0c',
+            (JavaScript | C, 'v') => '\x0b',
+            (C, 'a') => '\x07',
+            (JSON | JavaScript, '/') => '/',
+            (JavaScript | Rust | C, '\'') => '\'',
+            (C, '?') => '?',
+            (JavaScript, '\n') => continue,
+            (Rust, '\n') => {
+                while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+                continue;
+            }
+            (JavaScript | Rust, '0') if !chars.peek().is_some_and(|(_, c)| c.is_ascii_digit()) => {
+                '\0'
+            }
+            (C, '0'..='7') => {
+                let mut byte = escape.to_digit(8).unwrap_or_default();
+                for _ in 0..2 {
+                    match chars.peek().and_then(|(_, c)| c.to_digit(8)) {
+                        Some(digit) => {
+                            chars.next();
+                            byte = byte * 8 + digit;
+                        }
+                        None => break,
+                    }
+                }
+                let byte = u8::try_from(byte).map_err(|_| invalid("octal escape is too large"))?;
+                bytes.push(byte);
+                continue;
+            }
+            (C, 'x') => {
+                let byte =
+                    read_hex(&mut chars, 1, 2).ok_or_else(|| invalid("expected hex digits"))?;
+                bytes.push(byte as u8);
+                continue;
+            }
+            (JavaScript, 'x') => {
+                let code =
+                    read_hex(&mut chars, 2, 2).ok_or_else(|| invalid("expected 2 hex digits"))?;
+                char::from_u32(code).unwrap_or_default()
+            }
+            (Rust, 'x') => {
+                let code = read_hex(&mut chars, 2, 2)
+                    .filter(|&code| code <= 0x7f)
+                    .ok_or_else(|| invalid("expected 2 hex digits up to 7F"))?;
+                char::from_u32(code).unwrap_or_default()
+            }
+            (JavaScript | Rust, 'u') if chars.next_if(|(_, c)| *c == '{').is_some() => {
+                let code = read_hex(&mut chars, 1, 6)
+                    .filter(|_| chars.next_if(|(_, c)| *c == '}').is_some())
+                    .ok_or_else(|| invalid("expected hex digits in braces"))?;
+                char::from_u32(code).ok_or_else(|| invalid("not a Unicode scalar value"))?
+            }
+            (JSON | JavaScript, 'u') => {
+                let high =
+                    read_hex(&mut chars, 4, 4).ok_or_else(|| invalid("expected 4 hex digits"))?;
+                let code = if (0xd800..0xdc00).contains(&high) {
+                    // A character outside the BMP is escaped as a surrogate
+                    // pair.
+                    let low = chars
+                        .next_if(|(_, c)| *c == '\\')
+                        .and_then(|_| chars.next_if(|(_, c)| *c == 'u'))
+                        .and_then(|_| read_hex(&mut chars, 4, 4))
+                        .filter(|low| (0xdc00..0xe000).contains(low))
+                        .ok_or_else(|| invalid("unpaired surrogate"))?;
+                    0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+                } else {
+                    high
+                };
+                char::from_u32(code).ok_or_else(|| invalid("unpaired surrogate"))?
+            }
+            (C, 'u' | 'U') => {
+                let digits = if escape == 'u' { 4 } else { 8 };
+                read_hex(&mut chars, digits, digits)
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| invalid(&format!("expected {} hex digits", digits)))?
+            }
+            _ => return Err(unknown()),
+        };
+        push(&mut bytes, c);
+    }
+    String::from_utf8(bytes).map_err(|err| (ToolError::from(err), 0))
+}
+
+/// Reads between `min` and `max` hex digits as a number.
+fn read_hex(chars: &mut Peekable<CharIndices>, min: usize, max: usize) -> Option<u32> {
+    let mut code = 0;
+    let mut count = 0;
+    while count < max
+        && let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_hexdigit())
+    {
+        code = code * 16 + c.to_digit(16)?;
+        count += 1;
+    }
+    (count >= min).then_some(code)
+}
+
+/// Quotes a CSV field if it holds a delimiter, quote, or line break.
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn unescape_csv(value: &str) -> Result<String, ToolError> {
+    let Some(quoted) = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+    else {
+        if value.starts_with('"') {
+            return Err(ToolError::new("quoted field is never closed"));
+        }
+        return Ok(value.to_string());
+    };
+    let mut unescaped = String::with_capacity(quoted.len());
+    let mut chars = quoted.char_indices();
+    while let Some((offset, c)) = chars.next() {
+        if c == '"' && chars.next().map(|(_, c)| c) != Some('"') {
+            let offset = quoted[..offset].chars().count() + 1;
+            return Err(ToolError::new("quote inside a field must be doubled").at_offset(offset));
+        }
+        unescaped.push(c);
+    }
+    Ok(unescaped)
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Replaces the five predefined XML entities and character references.
+fn unescape_xml(value: &str) -> Result<String, ToolError> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        let offset = value[..value.len() - rest.len() + start].chars().count();
+        let Some(end) = rest[start..].find(';') else {
+            return Err(ToolError::new("entity is never closed with ;").at_offset(offset));
+        };
+        let name = &rest[start + 1..start + end];
+        let c = match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => name
+                    .strip_prefix('#')
+                    .and_then(|decimal| decimal.parse().ok()),
+            }
+            .and_then(char::from_u32),
+        };
+        let c = c.ok_or_else(|| {
+            ToolError::new(format!("unknown entity &{};", name)).at_offset(offset)
+        })?;
+        unescaped.push(c);
+        rest = &rest[start + end + 1..];
+    }
+    unescaped.push_str(rest);
+    Ok(unescaped)
+}
+
+/// Single-quotes a word for POSIX shells, which take everything up to the
+/// next single quote literally.
+fn escape_shell(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Removes the quoting from a shell word, as a POSIX shell would.
+fn unescape_shell(value: &str) -> Result<String, ToolError> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars().enumerate().peekable();
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, '\n')) | None => {}
+                Some((_, c)) => unescaped.push(c),
+            },
+            '\'' => loop {
+                match chars.next() {
+                    Some((_, '\'')) => break,
+                    Some((_, c)) => unescaped.push(c),
+                    None => {
+                        return Err(ToolError::new("quote is never closed").at_offset(offset));
+                    }
+                }
+            },
+            '"' => loop {
+                match chars.next() {
+                    Some((_, '"')) => break,
+                    // Inside double quotes, a backslash only escapes the
+                    // characters that would otherwise be special.
+                    Some((_, '\\')) => match chars.next_if(|(_, c)| "$`\"\\\n".contains(*c)) {
+                        Some((_, '\n')) => {}
+                        Some((_, c)) => unescaped.push(c),
+                        None => unescaped.push('\\'),
+                    },
+                    Some((_, c)) => unescaped.push(c),
+                    None => {
+                        return Err(ToolError::new("quote is never closed").at_offset(offset));
+                    }
+                }
+            },
+            c => unescaped.push(c),
+        }
+    }
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "Tab\tquote\" apostrophe' back\\slash\nünï ☃ 🦀 bell\x07 <&>";
+
+    #[test]
+    fn round_trips_every_language() {
+        for language in Language::ALL {
+            let escaped = escape(SAMPLE, language);
+            assert_eq!(
+                unescape(&escaped, language).unwrap(),
+                SAMPLE,
+                "{} escaped as {}",
+                language.name(),
+                escaped
+            );
+        }
+    }
+
+    #[test]
+    fn escapes_string_literals() {
+        let value = "a\"b\\c\nd\x07";
+        assert_eq!(escape(value, Language::JSON), r#""a\"b\\c\nd\u0007""#);
+        assert_eq!(escape(value, Language::JavaScript), r#""a\"b\\c\nd\x07""#);
+        assert_eq!(escape(value, Language::Rust), r#""a\"b\\c\nd\u{7}""#);
+        assert_eq!(escape(value, Language::C), r#""a\"b\\c\nd\a""#);
+    }
+
+    #[test]
+    fn escapes_fields_text_and_words() {
+        assert_eq!(escape("plain", Language::CSV), "plain");
+        assert_eq!(escape("a,\"b\"", Language::CSV), r#""a,""b""""#);
+        assert_eq!(
+            escape("<a href=\"x\">", Language::XML),
+            "&lt;a href=&quot;x&quot;&gt;"
+        );
+        assert_eq!(escape("it's", Language::Shell), r"'it'\''s'");
+    }
+
+    #[test]
+    fn unescapes_without_quotes() {
+        assert_eq!(unescape(r"a\tb", Language::JSON).unwrap(), "a\tb");
+        assert_eq!(unescape(r"'it\'s'", Language::JavaScript).unwrap(), "it's");
+    }
+
+    #[test]
+    fn unescapes_language_specific_escapes() {
+        assert_eq!(unescape(r"\uD83E\uDD80", Language::JSON).unwrap(), "🦀");
+        assert_eq!(
+            unescape(r"\u{1F980}\x41", Language::JavaScript).unwrap(),
+            "🦀A"
+        );
+        assert_eq!(
+            unescape("\\u{1F980}\\\n    x", Language::Rust).unwrap(),
+            "🦀x"
+        );
+        assert_eq!(unescape(r"\303\274\x41\101", Language::C).unwrap(), "üAA");
+        assert_eq!(unescape("&#65;&#x42;&amp;", Language::XML).unwrap(), "AB&");
+        assert_eq!(
+            unescape(r#"a\ b"c\$\d"'e'"#, Language::Shell).unwrap(),
+            r"a bc$\de"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_escapes() {
+        let err = unescape(r#""ab\q""#, Language::JSON).unwrap_err();
+        assert_eq!(err.to_string(), r"unknown escape \q (at character 4)");
+        assert!(unescape(r"\uD83E", Language::JSON).is_err());
+        assert!(unescape(r"\x80", Language::Rust).is_err());
+        assert!(unescape(r"\'", Language::JSON).is_err());
+        assert!(unescape("\"a\"b\"", Language::CSV).is_err());
+        assert!(unescape("&nbsp;", Language::XML).is_err());
+        assert!(unescape("'open", Language::Shell).is_err());
+    }
+}
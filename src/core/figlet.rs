@@ -0,0 +1,141 @@
+use figlet_rs::{FIGlet, Toilet};
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+/// The FIGlet and TOIlet fonts bundled with figlet-rs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Font {
+    #[default]
+    Standard,
+    Small,
+    Big,
+    Slant,
+    Future,
+    SmallBlock,
+    Mono9,
+    Mono12,
+    WideTerm,
+}
+
+impl Font {
+    pub const ALL: [Self; 9] = [
+        Self::Standard,
+        Self::Small,
+        Self::Big,
+        Self::Slant,
+        Self::Future,
+        Self::SmallBlock,
+        Self::Mono9,
+        Self::Mono12,
+        Self::WideTerm,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::Small => "Small",
+            Self::Big => "Big",
+            Self::Slant => "Slant",
+            Self::Future => "Future",
+            Self::SmallBlock => "Small Block",
+            Self::Mono9 => "Mono 9",
+            Self::Mono12 => "Mono 12",
+            Self::WideTerm => "Wide Term",
+        }
+    }
+}
+
+/// Loads a bundled font, which takes long enough to be worth keeping.
+pub fn load(font: Font) -> Result<FIGlet, ToolError> {
+    // TOIlet fonts share the FIGlet format, so they render the same way.
+    let toilet = |font: Toilet| FIGlet {
+        header_line: font.header_line,
+        comments: font.comments,
+        fonts: font.fonts,
+    };
+    match font {
+        Font::Standard => FIGlet::standard(),
+        Font::Small => FIGlet::small(),
+        Font::Big => FIGlet::big(),
+        Font::Slant => FIGlet::slant(),
+        Font::Future => Toilet::future().map(toilet),
+        Font::SmallBlock => Toilet::smblock().map(toilet),
+        Font::Mono9 => Toilet::mono9().map(toilet),
+        Font::Mono12 => Toilet::mono12().map(toilet),
+        Font::WideTerm => Toilet::wideterm().map(toilet),
+    }
+    .map_err(|err| ToolError::new(err).context(&format!("Cannot load {}", font.name())))
+}
+
+/// Text drawn as ASCII art.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Banner {
+    pub text: String,
+    /// The characters the font has no glyphs for, which are left out.
+    pub missing: Vec<char>,
+}
+
+/// Draws each line of `text` in `figlet`, one under the other.
+pub fn render(figlet: &FIGlet, text: &str) -> Banner {
+    let mut missing = Vec::new();
+    let mut rows = Vec::new();
+    for line in text.lines() {
+        for c in line.chars() {
+            if !figlet.fonts.contains_key(&(c as u32)) && !missing.contains(&c) {
+                missing.push(c);
+            }
+        }
+        match figlet.convert(line) {
+            Some(figure) => rows.extend(
+                figure
+                    .to_string()
+                    .lines()
+                    .map(|row| row.trim_end().to_string()),
+            ),
+            None => rows.push(String::new()),
+        }
+    }
+    Banner {
+        text: rows.join("\n"),
+        missing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_lines_in_each_font() {
+        let banner = render(&load(Font::Standard).unwrap(), "Hi");
+        assert_eq!(
+            banner.text,
+            [
+                " _   _ _",
+                "| | | (_)",
+                "| |_| | |",
+                "|  _  | |",
+                "|_| |_|_|",
+                ""
+            ]
+            .join("\n")
+        );
+        for font in Font::ALL {
+            let banner = render(&load(font).unwrap(), "utools\n\n42");
+            // Each line is drawn at the font's height, with the blank line
+            // between them kept.
+            assert!(banner.text.lines().count() >= 3, "{}", font.name());
+            assert!(!banner.text.trim().is_empty(), "{}", font.name());
+            assert!(banner.missing.is_empty(), "{}", font.name());
+        }
+    }
+
+    #[test]
+    fn lists_missing_characters() {
+        let slant = load(Font::Slant).unwrap();
+        let banner = render(&slant, "a→b→c");
+        assert_eq!(banner.missing, ['→']);
+        assert_eq!(banner.text, render(&slant, "abc").text);
+    }
+}
@@ -0,0 +1,214 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{color::Color, css_unit::format_amount};
+
+/// Tailwind's `bg-gradient-to-*` directions, clockwise from the top, one
+/// every 45 degrees.
+const TAILWIND_DIRECTIONS: [&str; 8] = ["t", "tr", "r", "br", "b", "bl", "l", "tl"];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Kind {
+    #[default]
+    Linear,
+    Radial,
+}
+
+impl Kind {
+    pub const ALL: [Self; 2] = [Self::Linear, Self::Radial];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Linear => "Linear",
+            Self::Radial => "Radial",
+        }
+    }
+}
+
+/// A color at a position along the gradient, in percent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stop {
+    pub color: Color,
+    pub position: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    pub kind: Kind,
+    /// The direction of a linear gradient in degrees, clockwise from the top,
+    /// as CSS measures it.
+    pub angle: f32,
+    pub stops: Vec<Stop>,
+}
+
+impl Gradient {
+    fn sorted_stops(&self) -> Vec<Stop> {
+        let mut stops = self.stops.clone();
+        stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+        stops
+    }
+
+    pub fn to_css(&self) -> String {
+        let stops = self
+            .sorted_stops()
+            .iter()
+            .map(|stop| {
+                format!(
+                    "{} {}%",
+                    stop.color.to_hex(),
+                    format_amount(stop.position as f64)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        match self.kind {
+            Kind::Linear => format!(
+                "linear-gradient({}deg, {})",
+                format_amount(self.angle as f64),
+                stops
+            ),
+            Kind::Radial => format!("radial-gradient(circle, {})", stops),
+        }
+    }
+
+    /// Writes the gradient as Tailwind classes: the `bg-gradient-to-*`
+    /// utilities when the gradient fits them, or else an arbitrary value.
+    pub fn to_tailwind(&self) -> String {
+        let stops = self.sorted_stops();
+        let angle = self.angle.rem_euclid(360.);
+        if self.kind != Kind::Linear || angle % 45. != 0. || !(2..=3).contains(&stops.len()) {
+            return format!("bg-[{}]", self.to_css().replace(' ', "_"));
+        }
+        let mut classes = vec![format!(
+            "bg-gradient-to-{}",
+            TAILWIND_DIRECTIONS[(angle / 45.) as usize]
+        )];
+        let roles = match stops.len() {
+            2 => &[("from", 0.), ("to", 100.)][..],
+            _ => &[("from", 0.), ("via", 50.), ("to", 100.)][..],
+        };
+        for (stop, (role, default)) in stops.iter().zip(roles) {
+            classes.push(format!("{}-[{}]", role, stop.color.to_hex()));
+            if stop.position != *default {
+                classes.push(format!("{}-{}%", role, format_amount(stop.position as f64)));
+            }
+        }
+        classes.join(" ")
+    }
+
+    /// Draws the gradient filling a `width` by `height` SVG, laid out as a
+    /// browser lays out the CSS gradient over a box of that size.
+    pub fn to_svg(&self, width: u32, height: u32) -> String {
+        let (w, h) = (width as f32, height as f32);
+        let number = |value: f32| format_amount(value as f64);
+        let stops = self
+            .sorted_stops()
+            .iter()
+            .map(|stop| {
+                format!(
+                    r#"<stop offset="{}%" stop-color="rgb({},{},{})" stop-opacity="{}"/>"#,
+                    number(stop.position),
+                    stop.color.r,
+                    stop.color.g,
+                    stop.color.b,
+                    number(stop.color.a)
+                )
+            })
+            .collect::<String>();
+        let gradient = match self.kind {
+            Kind::Linear => {
+                // The gradient line runs through the center, long enough for
+                // the corners to reach its ends.
+                let angle = self.angle.to_radians();
+                let (dx, dy) = (angle.sin(), -angle.cos());
+                let half = ((w * dx).abs() + (h * dy).abs()) / 2.;
+                format!(
+                    r#"<linearGradient id="g" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}">{}</linearGradient>"#,
+                    number(w / 2. - dx * half),
+                    number(h / 2. - dy * half),
+                    number(w / 2. + dx * half),
+                    number(h / 2. + dy * half),
+                    stops
+                )
+            }
+            Kind::Radial => format!(
+                r#"<radialGradient id="g" gradientUnits="userSpaceOnUse" cx="{}" cy="{}" r="{}">{}</radialGradient>"#,
+                number(w / 2.),
+                number(h / 2.),
+                number(w.hypot(h) / 2.),
+                stops
+            ),
+        };
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}"><defs>{gradient}</defs><rect width="{w}" height="{h}" fill="url(#g)"/></svg>"#
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient(kind: Kind, angle: f32, stops: &[(u32, f32)]) -> Gradient {
+        Gradient {
+            kind,
+            angle,
+            stops: stops
+                .iter()
+                .map(|(rgb, position)| Stop {
+                    color: Color::from_rgb(*rgb),
+                    position: *position,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn writes_css_with_sorted_stops() {
+        let linear = gradient(Kind::Linear, 90., &[(0x0000ff, 100.), (0xff0000, 0.)]);
+        assert_eq!(
+            linear.to_css(),
+            "linear-gradient(90deg, #ff0000 0%, #0000ff 100%)"
+        );
+        let radial = gradient(Kind::Radial, 90., &[(0xffffff, 0.), (0x000000, 70.)]);
+        assert_eq!(
+            radial.to_css(),
+            "radial-gradient(circle, #ffffff 0%, #000000 70%)"
+        );
+    }
+
+    #[test]
+    fn writes_tailwind_classes() {
+        let linear = gradient(Kind::Linear, 135., &[(0xff0000, 0.), (0x0000ff, 100.)]);
+        assert_eq!(
+            linear.to_tailwind(),
+            "bg-gradient-to-br from-[#ff0000] to-[#0000ff]"
+        );
+        let three = gradient(
+            Kind::Linear,
+            -90.,
+            &[(0xff0000, 10.), (0x00ff00, 50.), (0x0000ff, 100.)],
+        );
+        assert_eq!(
+            three.to_tailwind(),
+            "bg-gradient-to-l from-[#ff0000] from-10% via-[#00ff00] to-[#0000ff]"
+        );
+        let angled = gradient(Kind::Linear, 30., &[(0xff0000, 0.), (0x0000ff, 100.)]);
+        assert_eq!(
+            angled.to_tailwind(),
+            "bg-[linear-gradient(30deg,_#ff0000_0%,_#0000ff_100%)]"
+        );
+    }
+
+    #[test]
+    fn draws_svg_along_the_css_gradient_line() {
+        let svg = gradient(Kind::Linear, 90., &[(0xff0000, 0.), (0x0000ff, 100.)]).to_svg(200, 100);
+        assert!(
+            svg.contains(r#"x1="0" y1="50" x2="200" y2="50""#),
+            "{}",
+            svg
+        );
+        assert!(
+            svg.contains(r#"<stop offset="100%" stop-color="rgb(0,0,255)" stop-opacity="1"/>"#)
+        );
+    }
+}
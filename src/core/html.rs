@@ -0,0 +1,635 @@
+use std::{fmt::Write, sync::LazyLock};
+
+use html_escape::decode_html_entities;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+static ENTITY_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"&(?:#[0-9]+|#[xX][0-9a-fA-F]+|[A-Za-z][A-Za-z0-9]*);").unwrap());
+
+/// How characters are written when encoded as entities.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum EntityStyle {
+    /// `&amp;`, falling back to decimal for characters without a name.
+    #[default]
+    Named,
+    /// `&#38;`
+    Decimal,
+    /// `&#x26;`
+    Hex,
+}
+
+/// The named entities used when encoding: the reserved characters, Latin-1,
+/// and common typographic symbols.
+const NAMED_ENTITIES: &[(char, &str)] = &[
+    ('&', "amp"),
+    ('<', "lt"),
+    ('>', "gt"),
+    ('"', "quot"),
+    ('\'', "apos"),
+    ('\u{a0}', "nbsp"),
+    ('¡', "iexcl"),
+    ('¢', "cent"),
+    ('£', "pound"),
+    ('¤', "curren"),
+    ('¥', "yen"),
+    ('¦', "brvbar"),
+    ('§', "sect"),
+    ('¨', "uml"),
+    ('©', "copy"),
+    ('ª', "ordf"),
+    ('«', "laquo"),
+    ('¬', "not"),
+    ('\u{ad}', "shy"),
+    ('®', "reg"),
+    ('¯', "macr"),
+    ('°', "deg"),
+    ('±', "plusmn"),
+    ('²', "sup2"),
+    ('³', "sup3"),
+    ('´', "acute"),
+    ('µ', "micro"),
+    ('¶', "para"),
+    ('·', "middot"),
+    ('¸', "cedil"),
+    ('¹', "sup1"),
+    ('º', "ordm"),
+    ('»', "raquo"),
+    ('¼', "frac14"),
+    ('½', "frac12"),
+    ('¾', "frac34"),
+    ('¿', "iquest"),
+    ('À', "Agrave"),
+    ('Á', "Aacute"),
+    ('Â', "Acirc"),
+    ('Ã', "Atilde"),
+    ('Ä', "Auml"),
+    ('Å', "Aring"),
+    ('Æ', "AElig"),
+    ('Ç', "Ccedil"),
+    ('È', "Egrave"),
+    ('É', "Eacute"),
+    ('Ê', "Ecirc"),
+    ('Ë', "Euml"),
+    ('Ì', "Igrave"),
+    ('Í', "Iacute"),
+    ('Î', "Icirc"),
+    ('Ï', "Iuml"),
+    ('Ð', "ETH"),
+    ('Ñ', "Ntilde"),
+    ('Ò', "Ograve"),
+    ('Ó', "Oacute"),
+    ('Ô', "Ocirc"),
+    ('Õ', "Otilde"),
+    ('Ö', "Ouml"),
+    ('×', "times"),
+    ('Ø', "Oslash"),
+    ('Ù', "Ugrave"),
+    ('Ú', "Uacute"),
+    ('Û', "Ucirc"),
+    ('Ü', "Uuml"),
+    ('Ý', "Yacute"),
+    ('Þ', "THORN"),
+    ('ß', "szlig"),
+    ('à', "agrave"),
+    ('á', "aacute"),
+    ('â', "acirc"),
+    ('ã', "atilde"),
+    ('ä', "auml"),
+    ('å', "aring"),
+    ('æ', "aelig"),
+    ('ç', "ccedil"),
+    ('è', "egrave"),
+    ('é', "eacute"),
+    ('ê', "ecirc"),
+    ('ë', "euml"),
+    ('ì', "igrave"),
+    ('í', "iacute"),
+    ('î', "icirc"),
+    ('ï', "iuml"),
+    ('ð', "eth"),
+    ('ñ', "ntilde"),
+    ('ò', "ograve"),
+    ('ó', "oacute"),
+    ('ô', "ocirc"),
+    ('õ', "otilde"),
+    ('ö', "ouml"),
+    ('÷', "divide"),
+    ('ø', "oslash"),
+    ('ù', "ugrave"),
+    ('ú', "uacute"),
+    ('û', "ucirc"),
+    ('ü', "uuml"),
+    ('ý', "yacute"),
+    ('þ', "thorn"),
+    ('ÿ', "yuml"),
+    ('Œ', "OElig"),
+    ('œ', "oelig"),
+    ('Š', "Scaron"),
+    ('š', "scaron"),
+    ('Ÿ', "Yuml"),
+    ('ƒ', "fnof"),
+    ('ˆ', "circ"),
+    ('˜', "tilde"),
+    ('–', "ndash"),
+    ('—', "mdash"),
+    ('‘', "lsquo"),
+    ('’', "rsquo"),
+    ('‚', "sbquo"),
+    ('“', "ldquo"),
+    ('”', "rdquo"),
+    ('„', "bdquo"),
+    ('†', "dagger"),
+    ('‡', "Dagger"),
+    ('•', "bull"),
+    ('…', "hellip"),
+    ('‰', "permil"),
+    ('‹', "lsaquo"),
+    ('›', "rsaquo"),
+    ('€', "euro"),
+    ('™', "trade"),
+    ('←', "larr"),
+    ('↑', "uarr"),
+    ('→', "rarr"),
+    ('↓', "darr"),
+    ('↔', "harr"),
+    ('∞', "infin"),
+    ('≠', "ne"),
+    ('≤', "le"),
+    ('≥', "ge"),
+];
+
+/// Elements that never have content or a closing tag.
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose content is kept exactly as written.
+const RAW_TEXT_ELEMENTS: [&str; 4] = ["script", "style", "pre", "textarea"];
+
+/// Elements that flow with the text around them, and so stay on the same
+/// line as it.
+const INLINE_ELEMENTS: [&str; 30] = [
+    "a", "abbr", "b", "bdi", "bdo", "br", "cite", "code", "data", "dfn", "em", "i", "img", "kbd",
+    "label", "mark", "q", "s", "samp", "small", "span", "strong", "sub", "sup", "time", "u", "var",
+    "wbr", "del", "ins",
+];
+
+/// A node of an HTML document. Tags are kept as written so that attribute
+/// quoting and casing survive formatting.
+enum Node<'a> {
+    Element {
+        name: String,
+        open: &'a str,
+        children: Vec<Node<'a>>,
+        close: Option<&'a str>,
+    },
+    Raw {
+        name: String,
+        open: &'a str,
+        content: &'a str,
+        close: Option<&'a str>,
+    },
+    Text(&'a str),
+    /// Comments, the doctype, and void or self-closing tags.
+    Other(&'a str),
+}
+
+impl Node<'_> {
+    fn is_inline(&self) -> bool {
+        match self {
+            Node::Text(_) => true,
+            Node::Other(tag) => INLINE_ELEMENTS.contains(&tag_name(tag).as_str()),
+            Node::Element { name, children, .. } => {
+                INLINE_ELEMENTS.contains(&name.as_str()) && children.iter().all(Node::is_inline)
+            }
+            Node::Raw { .. } => false,
+        }
+    }
+}
+
+/// Returns the lowercased name of a tag such as `<div class="a">` or
+/// `</div>`.
+fn tag_name(tag: &str) -> String {
+    tag.trim_start_matches('<')
+        .trim_start_matches('/')
+        .split(|c: char| c.is_whitespace() || c == '/' || c == '>')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// Finds the end of a tag, skipping over quoted attribute values, which may
+/// contain `>`.
+fn tag_end(source: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in source.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return Some(i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Whether an element that is still open ends where `name` opens, as a `<li>`
+/// ends at the next `<li>` without a closing tag.
+fn closes_implicitly(open: &str, name: &str) -> bool {
+    match open {
+        "li" | "option" | "tr" => open == name,
+        // A paragraph cannot hold block elements, so any of them ends it.
+        "p" => !INLINE_ELEMENTS.contains(&name),
+        "dt" | "dd" => matches!(name, "dt" | "dd"),
+        "td" | "th" => matches!(name, "td" | "th" | "tr"),
+        _ => false,
+    }
+}
+
+/// Parses HTML into a tree, tolerating the end tags HTML lets you leave out.
+/// Only tags and comments that never end are treated as errors.
+fn parse(source: &str) -> Result<Vec<Node<'_>>, ToolError> {
+    // Each open element, with the index of its first child in `nodes`.
+    let mut stack: Vec<(String, &str, usize)> = Vec::new();
+    let mut nodes: Vec<Node> = Vec::new();
+
+    fn close_top<'a>(
+        stack: &mut Vec<(String, &'a str, usize)>,
+        nodes: &mut Vec<Node<'a>>,
+        close: Option<&'a str>,
+    ) {
+        if let Some((name, open, start)) = stack.pop() {
+            let children = nodes.split_off(start);
+            nodes.push(Node::Element {
+                name,
+                open,
+                children,
+                close,
+            });
+        }
+    }
+
+    let mut offset = 0;
+    while offset < source.len() {
+        let rest = &source[offset..];
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            nodes.push(Node::Text(&rest[..end]));
+            offset += end;
+            continue;
+        }
+
+        let end = if rest.starts_with("<!--") {
+            rest.find("-->")
+                .map(|i| i + 3)
+                .ok_or_else(|| ToolError::new("comment is never closed").at_offset(offset))?
+        } else {
+            tag_end(rest).ok_or_else(|| ToolError::new("tag is never closed").at_offset(offset))?
+        };
+        let tag = &rest[..end];
+        offset += end;
+        let name = tag_name(tag);
+
+        if tag.starts_with("<!") || tag.starts_with("<?") {
+            nodes.push(Node::Other(tag));
+        } else if tag.starts_with("</") {
+            // A closing tag with nothing open to match is dropped.
+            if let Some(depth) = stack.iter().rposition(|(open, _, _)| *open == name) {
+                while stack.len() > depth + 1 {
+                    close_top(&mut stack, &mut nodes, None);
+                }
+                close_top(&mut stack, &mut nodes, Some(tag));
+            }
+        } else {
+            while stack
+                .last()
+                .is_some_and(|(open, _, _)| closes_implicitly(open, &name))
+            {
+                close_top(&mut stack, &mut nodes, None);
+            }
+            if tag.ends_with("/>") || VOID_ELEMENTS.contains(&name.as_str()) {
+                nodes.push(Node::Other(tag));
+            } else if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                let rest = &source[offset..];
+                let content_end = rest
+                    .to_lowercase()
+                    .find(&format!("</{}", name))
+                    .unwrap_or(rest.len());
+                let content = &rest[..content_end];
+                offset += content_end;
+                let close_end = tag_end(&source[offset..]).unwrap_or(source.len() - offset);
+                let close = &source[offset..offset + close_end];
+                offset += close_end;
+                nodes.push(Node::Raw {
+                    name,
+                    open: tag,
+                    content,
+                    close: (!close.is_empty()).then_some(close),
+                });
+            } else {
+                stack.push((name, tag, nodes.len()));
+            }
+        }
+    }
+    while !stack.is_empty() {
+        close_top(&mut stack, &mut nodes, None);
+    }
+    Ok(nodes)
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            space = true;
+        } else {
+            if space {
+                collapsed.push(' ');
+            }
+            collapsed.push(c);
+            space = false;
+        }
+    }
+    if space {
+        collapsed.push(' ');
+    }
+    collapsed
+}
+
+fn format_inline(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => collapse_whitespace(text),
+            Node::Other(tag) => tag.to_string(),
+            Node::Element {
+                open,
+                children,
+                close,
+                ..
+            } => format!(
+                "{}{}{}",
+                open,
+                format_inline(children),
+                close.unwrap_or_default()
+            ),
+            Node::Raw {
+                open,
+                content,
+                close,
+                ..
+            } => format!("{}{}{}", open, content, close.unwrap_or_default()),
+        })
+        .collect()
+}
+
+fn format_pretty(nodes: &[Node], indent: &str, depth: usize, lines: &mut Vec<String>) {
+    let prefix = indent.repeat(depth);
+    // Runs of text and inline elements are kept together on one line.
+    let mut i = 0;
+    while i < nodes.len() {
+        if nodes[i].is_inline() {
+            let start = i;
+            while i < nodes.len() && nodes[i].is_inline() {
+                i += 1;
+            }
+            let line = format_inline(&nodes[start..i]);
+            if !line.trim().is_empty() {
+                lines.push(format!("{}{}", prefix, line.trim()));
+            }
+            continue;
+        }
+
+        match &nodes[i] {
+            Node::Element {
+                open,
+                children,
+                close,
+                ..
+            } => {
+                if children.iter().all(Node::is_inline) {
+                    lines.push(format!(
+                        "{}{}{}{}",
+                        prefix,
+                        open,
+                        format_inline(children).trim(),
+                        close.unwrap_or_default()
+                    ));
+                } else {
+                    lines.push(format!("{}{}", prefix, open));
+                    format_pretty(children, indent, depth + 1, lines);
+                    if let Some(close) = close {
+                        lines.push(format!("{}{}", prefix, close));
+                    }
+                }
+            }
+            Node::Raw {
+                name,
+                open,
+                content,
+                close,
+            } => {
+                let close = close.unwrap_or_default();
+                if matches!(name.as_str(), "pre" | "textarea") || content.trim().is_empty() {
+                    // Whitespace matters here, so the content is left as is.
+                    let content = if content.trim().is_empty() {
+                        ""
+                    } else {
+                        content
+                    };
+                    lines.push(format!("{}{}{}{}", prefix, open, content, close));
+                } else {
+                    lines.push(format!("{}{}", prefix, open));
+                    let content_lines: Vec<&str> = content
+                        .lines()
+                        .skip_while(|line| line.trim().is_empty())
+                        .collect();
+                    let common = content_lines
+                        .iter()
+                        .filter(|line| !line.trim().is_empty())
+                        .map(|line| line.len() - line.trim_start().len())
+                        .min()
+                        .unwrap_or(0);
+                    let inner = indent.repeat(depth + 1);
+                    for line in content_lines {
+                        if line.trim().is_empty() {
+                            lines.push(String::new());
+                        } else {
+                            lines.push(format!("{}{}", inner, line[common..].trim_end()));
+                        }
+                    }
+                    while lines.last().is_some_and(|line| line.is_empty()) {
+                        lines.pop();
+                    }
+                    lines.push(format!("{}{}", prefix, close));
+                }
+            }
+            Node::Other(tag) => lines.push(format!("{}{}", prefix, tag)),
+            Node::Text(_) => {}
+        }
+        i += 1;
+    }
+}
+
+/// Collapses whitespace in text and drops it between block elements, where
+/// it does not show.
+fn format_minified(nodes: &[Node]) -> String {
+    let mut minified = String::new();
+    for (i, node) in nodes.iter().enumerate() {
+        match node {
+            Node::Text(text) if text.trim().is_empty() => {
+                let between_inline = i > 0
+                    && nodes[i - 1].is_inline()
+                    && nodes.get(i + 1).is_some_and(Node::is_inline);
+                if between_inline {
+                    minified.push(' ');
+                }
+            }
+            Node::Text(text) => minified += &collapse_whitespace(text),
+            Node::Other(tag) => minified += tag,
+            Node::Element {
+                open,
+                children,
+                close,
+                ..
+            } => {
+                minified += open;
+                minified += &format_minified(children);
+                minified += close.unwrap_or_default();
+            }
+            Node::Raw {
+                open,
+                content,
+                close,
+                ..
+            } => {
+                minified += open;
+                minified += content;
+                minified += close.unwrap_or_default();
+            }
+        }
+    }
+    minified
+}
+
+/// Pretty-prints HTML, indenting each level by `indentation_size` spaces.
+pub fn format(source: &str, indentation_size: usize) -> Result<String, ToolError> {
+    let nodes = parse(source).map_err(|err| err.context("Malformed HTML"))?;
+    let mut lines = Vec::new();
+    format_pretty(&nodes, &" ".repeat(indentation_size), 0, &mut lines);
+    Ok(lines.join("\n"))
+}
+
+pub fn minify(source: &str) -> Result<String, ToolError> {
+    let nodes = parse(source).map_err(|err| err.context("Malformed HTML"))?;
+    Ok(format_minified(&nodes).trim().to_string())
+}
+
+/// Whether the text holds a named, decimal or hexadecimal character reference.
+pub fn has_entities(value: &str) -> bool {
+    ENTITY_PATTERN.is_match(value)
+}
+
+/// Encodes the characters reserved in HTML (`&`, `<`, `>`, `"`, and `'`) as
+/// entities in the given style, along with every non-ASCII character if
+/// `non_ascii` is set.
+pub fn encode_entities(value: &str, style: EntityStyle, non_ascii: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for c in value.chars() {
+        let reserved = matches!(c, '&' | '<' | '>' | '"' | '\'');
+        if !reserved && (c.is_ascii() || !non_ascii) {
+            encoded.push(c);
+            continue;
+        }
+        let name = NAMED_ENTITIES
+            .iter()
+            .find(|(named, _)| *named == c)
+            .map(|(_, name)| name);
+        let _ = match (style, name) {
+            (EntityStyle::Named, Some(name)) => write!(encoded, "&{};", name),
+            (EntityStyle::Hex, _) => write!(encoded, "&#x{:X};", c as u32),
+            _ => write!(encoded, "&#{};", c as u32),
+        };
+    }
+    encoded
+}
+
+pub fn decode_entities(value: &str) -> String {
+    decode_html_entities(value).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_block_elements_on_their_own_lines() {
+        assert_eq!(
+            format("<div><p>Hello <b>world</b></p></div>", 2).unwrap(),
+            "<div>\n  <p>Hello <b>world</b></p>\n</div>"
+        );
+    }
+
+    #[test]
+    fn minifies() {
+        assert_eq!(
+            minify("<ul>\n  <li>One</li>\n  <li>Two</li>\n</ul>\n").unwrap(),
+            "<ul><li>One</li><li>Two</li></ul>"
+        );
+    }
+
+    #[test]
+    fn rejects_tags_that_never_end() {
+        let err = format("<div", 2).unwrap_err();
+        assert!(err.to_string().starts_with("Malformed HTML"));
+    }
+
+    #[test]
+    fn round_trips_entities() {
+        for style in [EntityStyle::Named, EntityStyle::Decimal, EntityStyle::Hex] {
+            let encoded = encode_entities("<a href='x'> & \"café\" €", style, true);
+            assert!(has_entities(&encoded));
+            assert_eq!(decode_entities(&encoded), "<a href='x'> & \"café\" €");
+        }
+        assert_eq!(decode_entities("&#x41;&#66;&eacute;"), "ABé");
+        assert!(!has_entities("AT&T"));
+    }
+
+    #[test]
+    fn encodes_in_the_chosen_style() {
+        assert_eq!(
+            encode_entities("& é", EntityStyle::Named, true),
+            "&amp; &eacute;"
+        );
+        assert_eq!(
+            encode_entities("& é", EntityStyle::Decimal, true),
+            "&#38; &#233;"
+        );
+        assert_eq!(
+            encode_entities("& é", EntityStyle::Hex, true),
+            "&#x26; &#xE9;"
+        );
+        assert_eq!(encode_entities("✓", EntityStyle::Named, true), "&#10003;");
+    }
+
+    #[test]
+    fn leaves_non_ascii_alone_unless_asked() {
+        assert_eq!(
+            encode_entities("<é>", EntityStyle::Named, false),
+            "&lt;é&gt;"
+        );
+    }
+
+    #[test]
+    fn names_decode_to_their_characters() {
+        for (c, name) in NAMED_ENTITIES {
+            assert_eq!(decode_entities(&format!("&{};", name)), c.to_string());
+        }
+    }
+}
@@ -0,0 +1,461 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ToolError, core::json};
+
+/// How long a request may take, including reading the response body.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long a client sending a request to the listener may take.
+const SERVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The most of a received request body that is kept.
+const MAX_BODY: usize = 10 * 1024 * 1024;
+
+/// The longest request line or header line accepted, in bytes.
+const MAX_LINE: usize = 8 * 1024;
+
+/// The most headers accepted in a request.
+const MAX_HEADERS: usize = 100;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Method {
+    #[default]
+    GET,
+    POST,
+    PUT,
+    PATCH,
+    DELETE,
+    HEAD,
+    OPTIONS,
+}
+
+impl Method {
+    pub const ALL: [Self; 7] = [
+        Self::GET,
+        Self::POST,
+        Self::PUT,
+        Self::PATCH,
+        Self::DELETE,
+        Self::HEAD,
+        Self::OPTIONS,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::GET => "GET",
+            Self::POST => "POST",
+            Self::PUT => "PUT",
+            Self::PATCH => "PATCH",
+            Self::DELETE => "DELETE",
+            Self::HEAD => "HEAD",
+            Self::OPTIONS => "OPTIONS",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct Response {
+    /// The status code and its reason, e.g. "404 Not Found".
+    pub status: String,
+    pub elapsed: Duration,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn content_type(&self) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A request received by the listener.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Captured {
+    pub method: String,
+    /// The path and query string, as sent.
+    pub target: String,
+    pub version: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Captured {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Parses headers written one per line as `Name: Value`. Blank lines and
+/// lines starting with `#` are skipped.
+pub fn parse_headers(value: &str) -> Result<Vec<(String, String)>, ToolError> {
+    value
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| {
+            let Some((name, value)) = line.split_once(':') else {
+                return Err(ToolError::new("Expected Name: Value")
+                    .context("Invalid header")
+                    .at_line_column(i + 1, 1));
+            };
+            let name = name.trim();
+            if name.is_empty() || name.contains(char::is_whitespace) {
+                return Err(ToolError::new(format!("\"{}\" is not a header name", name))
+                    .context("Invalid header")
+                    .at_line_column(i + 1, 1));
+            }
+            Ok((name.to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Whether a header carries credentials, such as `Authorization`, `Cookie`,
+/// or an API key.
+pub fn is_credential_header(name: &str) -> bool {
+    let name = name.trim().to_ascii_lowercase();
+    [
+        "auth", "cookie", "key", "token", "secret", "password", "session",
+    ]
+    .iter()
+    .any(|word| name.contains(word))
+}
+
+/// Drops the lines of `value` that set credential headers, leaving the rest
+/// as written.
+pub fn strip_credential_headers(value: &str) -> String {
+    value
+        .lines()
+        .filter(|line| {
+            line.split_once(':')
+                .is_none_or(|(name, _)| !is_credential_header(name))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Sends the request, blocking until the whole response has been read.
+pub fn send(request: &Request) -> Result<Response, ToolError> {
+    let error = |err: reqwest::Error| ToolError::new(err.to_string()).context("Request failed");
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(TIMEOUT)
+        .build()
+        .map_err(error)?;
+    let method = reqwest::Method::from_bytes(request.method.name().as_bytes())
+        .map_err(|err| ToolError::new(err.to_string()))?;
+    let mut builder = client.request(method, request.url.trim());
+    for (name, value) in &request.headers {
+        builder = builder.header(name, value);
+    }
+    if !request.body.is_empty() {
+        builder = builder.body(request.body.clone());
+    }
+
+    let started = Instant::now();
+    let response = builder.send().map_err(error)?;
+    let status = response.status();
+    let headers = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                String::from_utf8_lossy(value.as_bytes()).to_string(),
+            )
+        })
+        .collect();
+    let body = response.bytes().map_err(error)?.to_vec();
+    Ok(Response {
+        status: format!(
+            "{} {}",
+            status.as_u16(),
+            status.canonical_reason().unwrap_or_default()
+        )
+        .trim_end()
+        .to_string(),
+        elapsed: started.elapsed(),
+        headers,
+        body,
+    })
+}
+
+/// Reads an HTTP/1.x request, including a body sent with `Content-Length`
+/// or chunked transfer encoding.
+pub fn read_request(reader: &mut impl BufRead) -> Result<Captured, ToolError> {
+    let error = |message: &str| ToolError::new(message).context("Invalid request");
+
+    let line = read_line(reader)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target), Some(version), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(error("Expected a request line"));
+    };
+    let mut captured = Captured {
+        method: method.to_string(),
+        target: target.to_string(),
+        version: version.to_string(),
+        headers: Vec::new(),
+        body: Vec::new(),
+    };
+
+    loop {
+        let line = read_line(reader)?;
+        if line.is_empty() {
+            break;
+        }
+        if captured.headers.len() == MAX_HEADERS {
+            return Err(error("Too many headers"));
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(error("Expected a header"));
+        };
+        captured
+            .headers
+            .push((name.trim().to_string(), value.trim().to_string()));
+    }
+
+    if captured
+        .header("transfer-encoding")
+        .is_some_and(|value| value.eq_ignore_ascii_case("chunked"))
+    {
+        loop {
+            let line = read_line(reader)?;
+            let size = line.split(';').next().unwrap_or_default().trim();
+            let size =
+                usize::from_str_radix(size, 16).map_err(|_| error("Expected a chunk size"))?;
+            if size == 0 {
+                break;
+            }
+            if size > MAX_BODY - captured.body.len() {
+                return Err(error("Body is too large"));
+            }
+            let start = captured.body.len();
+            captured.body.resize(start + size, 0);
+            reader
+                .read_exact(&mut captured.body[start..])
+                .map_err(io_error)?;
+            read_line(reader)?;
+        }
+    } else if let Some(length) = captured.header("content-length") {
+        let length: usize = length
+            .parse()
+            .map_err(|_| error("Expected a numeric Content-Length"))?;
+        if length > MAX_BODY {
+            return Err(error("Body is too large"));
+        }
+        captured.body.resize(length, 0);
+        reader.read_exact(&mut captured.body).map_err(io_error)?;
+    }
+    Ok(captured)
+}
+
+/// Reads a request from a connection made to the listener and answers it
+/// with an empty `200 OK`.
+pub fn serve(stream: TcpStream) -> Result<Captured, ToolError> {
+    stream.set_nonblocking(false).map_err(io_error)?;
+    stream
+        .set_read_timeout(Some(SERVE_TIMEOUT))
+        .map_err(io_error)?;
+    let captured = read_request(&mut BufReader::new(&stream))?;
+    (&stream)
+        .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+        .map_err(io_error)?;
+    Ok(captured)
+}
+
+fn read_line(reader: &mut impl BufRead) -> Result<String, ToolError> {
+    let mut line = String::new();
+    reader
+        .take(MAX_LINE as u64 + 1)
+        .read_line(&mut line)
+        .map_err(io_error)?;
+    if line.len() > MAX_LINE {
+        return Err(ToolError::new("Line is too long").context("Invalid request"));
+    }
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn io_error(err: std::io::Error) -> ToolError {
+    ToolError::new(err.to_string()).context("Invalid request")
+}
+
+/// Turns a response body into text for display, pretty-printing it if it is
+/// JSON. Returns whether it was.
+pub fn format_body(
+    body: &[u8],
+    content_type: Option<&str>,
+    indentation_size: usize,
+) -> (String, bool) {
+    let Ok(text) = std::str::from_utf8(body) else {
+        return (format!("Binary response ({} bytes)", body.len()), false);
+    };
+    let looks_like_json = content_type.is_some_and(|content_type| content_type.contains("json"))
+        || text.trim_start().starts_with(['{', '[']);
+    if looks_like_json && let Ok(formatted) = json::format(text, indentation_size) {
+        return (formatted, true);
+    }
+    (text.to_string(), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_headers() {
+        let headers = parse_headers(
+            "Accept: application/json\n\n# Disabled: yes\nAuthorization: Bearer a:b\n",
+        )
+        .unwrap();
+        assert_eq!(
+            headers,
+            vec![
+                ("Accept".to_string(), "application/json".to_string()),
+                ("Authorization".to_string(), "Bearer a:b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_headers() {
+        let err = parse_headers("Accept: */*\nnot a header").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid header: Expected Name: Value (line 2, column 1)"
+        );
+        assert!(parse_headers("Bad Name: value").is_err());
+    }
+
+    #[test]
+    fn strips_credential_headers() {
+        assert_eq!(
+            strip_credential_headers(
+                "Accept: */*\nAuthorization: Bearer abc\nCookie: id=1\nX-API-Key: k\n\
+                 X-Auth-Token: t\n# Disabled: yes\nUser-Agent: utools"
+            ),
+            "Accept: */*\n# Disabled: yes\nUser-Agent: utools"
+        );
+        assert!(!is_credential_header("Content-Type"));
+    }
+
+    #[test]
+    fn reads_requests() {
+        let request = read_request(
+            &mut "POST /hook?a=1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: 7\r\n\r\n{\"a\":1}extra"
+                .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.target, "/hook?a=1");
+        assert_eq!(request.version, "HTTP/1.1");
+        assert_eq!(request.header("host"), Some("localhost"));
+        assert_eq!(request.body, br#"{"a":1}"#);
+
+        let request = read_request(&mut "GET / HTTP/1.0\n\n".as_bytes()).unwrap();
+        assert!(request.headers.is_empty());
+        assert!(request.body.is_empty());
+    }
+
+    #[test]
+    fn reads_chunked_bodies() {
+        let request = read_request(
+            &mut "PUT / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nWiki\r\n5;x=y\r\npedia\r\n0\r\n\r\n"
+                .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(request.body, b"Wikipedia");
+    }
+
+    #[test]
+    fn serves_requests_over_loopback() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(address).unwrap();
+            stream
+                .write_all(b"DELETE /items/1 HTTP/1.1\r\nHost: localhost\r\n\r\n")
+                .unwrap();
+            let mut response = String::new();
+            std::io::Read::read_to_string(&mut stream, &mut response).unwrap();
+            response
+        });
+        let (stream, _) = listener.accept().unwrap();
+        let request = serve(stream).unwrap();
+        assert_eq!(request.method, "DELETE");
+        assert_eq!(request.target, "/items/1");
+        assert!(client.join().unwrap().starts_with("HTTP/1.1 200 OK\r\n"));
+    }
+
+    #[test]
+    fn rejects_malformed_requests() {
+        assert!(read_request(&mut "hello\r\n\r\n".as_bytes()).is_err());
+        assert!(read_request(&mut "GET / HTTP/1.1\r\nbad header\r\n\r\n".as_bytes()).is_err());
+        assert!(
+            read_request(&mut "POST / HTTP/1.1\r\nContent-Length: 9\r\n\r\nshort".as_bytes())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_oversized_requests() {
+        let err = read_request(
+            &mut "POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n1\r\na\r\nffffffffffffffff\r\n"
+                .as_bytes(),
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Invalid request: Body is too large");
+
+        let line = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE));
+        let err = read_request(&mut line.as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid request: Line is too long");
+
+        let headers = format!(
+            "GET / HTTP/1.1\r\n{}\r\n",
+            "X-A: 1\r\n".repeat(MAX_HEADERS + 1)
+        );
+        let err = read_request(&mut headers.as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid request: Too many headers");
+    }
+
+    #[test]
+    fn formats_json_bodies() {
+        assert_eq!(
+            format_body(br#"{"a":1}"#, Some("application/json; charset=utf-8"), 2),
+            ("{\n  \"a\": 1\n}".to_string(), true)
+        );
+        assert_eq!(
+            format_body(br#"[1]"#, None, 2),
+            ("[\n  1\n]".to_string(), true)
+        );
+        assert_eq!(
+            format_body(b"<p>hi</p>", Some("text/html"), 2),
+            ("<p>hi</p>".to_string(), false)
+        );
+        assert_eq!(
+            format_body(b"\xff\xfe", None, 2),
+            ("Binary response (2 bytes)".to_string(), false)
+        );
+    }
+}
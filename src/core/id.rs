@@ -0,0 +1,345 @@
+use chrono::{DateTime, SecondsFormat};
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+/// The kinds of identifiers that can be inspected.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Kind {
+    Auto,
+    UUID,
+    ULID,
+    Snowflake,
+}
+
+impl Kind {
+    /// Recognises an identifier by its shape: hex digits and hyphens for a
+    /// UUID, 26 Crockford Base32 digits for a ULID, and a decimal number for a
+    /// Snowflake ID.
+    pub fn detect(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.len() == 26 && value.chars().all(|c| decode_crockford(c).is_some()) {
+            Some(Self::ULID)
+        } else if !value.is_empty()
+            && value.len() <= 20
+            && value.bytes().all(|b| b.is_ascii_digit())
+        {
+            Some(Self::Snowflake)
+        } else if parse_uuid(value).is_ok() {
+            Some(Self::UUID)
+        } else {
+            None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Auto => "Auto",
+            Self::UUID => "UUID",
+            Self::ULID => "ULID",
+            Self::Snowflake => "Snowflake",
+        }
+    }
+}
+
+/// The epoch of Twitter's Snowflake IDs, in milliseconds since the Unix epoch.
+pub const TWITTER_EPOCH: i64 = 1288834974657;
+
+/// The epoch of Discord's Snowflake IDs, in milliseconds since the Unix epoch.
+pub const DISCORD_EPOCH: i64 = 1420070400000;
+
+/// Parses an identifier of the given kind, or of whichever kind it looks like,
+/// into named fields. Snowflake timestamps count milliseconds from
+/// `snowflake_epoch`.
+pub fn inspect(
+    value: &str,
+    kind: Kind,
+    snowflake_epoch: i64,
+) -> Result<Vec<(&'static str, String)>, ToolError> {
+    let value = value.trim();
+    let kind = match kind {
+        Kind::Auto => Kind::detect(value)
+            .ok_or_else(|| ToolError::new("not a UUID, ULID, or Snowflake ID"))?,
+        kind => kind,
+    };
+    let mut fields = vec![("Type", kind.name().to_string())];
+    match kind {
+        Kind::UUID => inspect_uuid(parse_uuid(value)?, &mut fields),
+        Kind::ULID => inspect_ulid(value, &mut fields)?,
+        _ => inspect_snowflake(value, snowflake_epoch, &mut fields)?,
+    }
+    Ok(fields)
+}
+
+/// Parses a UUID in its hyphenated or simple form, optionally in braces or
+/// prefixed with `urn:uuid:`.
+fn parse_uuid(value: &str) -> Result<[u8; 16], ToolError> {
+    let value = value
+        .strip_prefix('{')
+        .and_then(|value| value.strip_suffix('}'))
+        .or_else(|| {
+            value
+                .get(..9)
+                .filter(|prefix| prefix.eq_ignore_ascii_case("urn:uuid:"))
+                .map(|_| &value[9..])
+        })
+        .unwrap_or(value);
+    let hyphenated = value.len() == 36
+        && [8, 13, 18, 23]
+            .iter()
+            .all(|&index| value.as_bytes()[index] == b'-');
+    let digits: String = if hyphenated {
+        value.split('-').collect()
+    } else {
+        value.to_string()
+    };
+    if digits.len() != 32 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ToolError::new("expected 32 hex digits").context("Invalid UUID"));
+    }
+    let mut bytes = [0; 16];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16)?;
+    }
+    Ok(bytes)
+}
+
+fn inspect_uuid(bytes: [u8; 16], fields: &mut Vec<(&'static str, String)>) {
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    fields.push((
+        "Canonical",
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        ),
+    ));
+    if bytes == [0; 16] {
+        fields.push(("Version", "Nil UUID".to_string()));
+        return;
+    }
+    if bytes == [0xff; 16] {
+        fields.push(("Version", "Max UUID".to_string()));
+        return;
+    }
+
+    let version = bytes[6] >> 4;
+    let description = match version {
+        1 => "Gregorian time-based",
+        2 => "DCE security",
+        3 => "Name-based, MD5",
+        4 => "Random",
+        5 => "Name-based, SHA-1",
+        6 => "Reordered time-based",
+        7 => "Unix time-based",
+        8 => "Custom",
+        _ => "Unknown",
+    };
+    fields.push(("Version", format!("{} ({})", version, description)));
+    let variant = match bytes[8] {
+        0x00..=0x7f => "NCS, reserved for backward compatibility",
+        0x80..=0xbf => "RFC 9562",
+        0xc0..=0xdf => "Microsoft, reserved for backward compatibility",
+        _ => "Reserved for future definition",
+    };
+    fields.push(("Variant", variant.to_string()));
+
+    let read = |range: std::ops::Range<usize>| {
+        bytes[range]
+            .iter()
+            .fold(0u64, |value, &byte| value << 8 | byte as u64)
+    };
+    // Time-based UUIDs count 100 nanosecond intervals since the Gregorian
+    // calendar began on 1582-10-15.
+    let gregorian = match version {
+        1 => Some(read(6..8) & 0x0fff).map(|high| high << 48 | read(4..6) << 32 | read(0..4)),
+        6 => Some(read(0..6) << 12 | read(6..8) & 0x0fff),
+        _ => None,
+    };
+    if let Some(intervals) = gregorian {
+        let unix_nanoseconds = (intervals as i128 - GREGORIAN_OFFSET as i128) * 100;
+        push_timestamp(fields, (unix_nanoseconds / 1_000_000) as i64);
+        fields.push(("Clock Sequence", (read(8..10) & 0x3fff).to_string()));
+        let node: Vec<String> = bytes[10..]
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        fields.push(("Node", node.join(":")));
+    }
+    if version == 7 {
+        push_timestamp(fields, read(0..6) as i64);
+    }
+}
+
+/// The number of 100 nanosecond intervals between the start of the Gregorian
+/// calendar and the Unix epoch.
+const GREGORIAN_OFFSET: u64 = 0x01b2_1dd2_1381_4000;
+
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Decodes a Crockford Base32 digit, reading I and L as 1 and O as 0.
+fn decode_crockford(c: char) -> Option<u8> {
+    let c = match c.to_ascii_uppercase() {
+        'I' | 'L' => '1',
+        'O' => '0',
+        c => c,
+    };
+    CROCKFORD_ALPHABET
+        .iter()
+        .position(|&digit| digit as char == c)
+        .map(|position| position as u8)
+}
+
+fn inspect_ulid(value: &str, fields: &mut Vec<(&'static str, String)>) -> Result<(), ToolError> {
+    if value.len() != 26 {
+        return Err(ToolError::new("expected 26 characters").context("Invalid ULID"));
+    }
+    let mut ulid: u128 = 0;
+    for (offset, c) in value.chars().enumerate() {
+        let digit = decode_crockford(c).ok_or_else(|| {
+            ToolError::new(format!("{:?} is not a Crockford Base32 digit", c))
+                .context("Invalid ULID")
+                .at_offset(offset)
+        })?;
+        // The first digit can only carry 3 of the 128 bits.
+        if offset == 0 && digit > 7 {
+            return Err(ToolError::new("value is too large")
+                .context("Invalid ULID")
+                .at_offset(0));
+        }
+        ulid = ulid << 5 | digit as u128;
+    }
+    let canonical: String = (0..26)
+        .rev()
+        .map(|i| CROCKFORD_ALPHABET[(ulid >> (i * 5)) as usize & 0x1f] as char)
+        .collect();
+    fields.push(("Canonical", canonical));
+    push_timestamp(fields, (ulid >> 80) as i64);
+    fields.push(("Randomness", format!("{:020x}", ulid & ((1 << 80) - 1))));
+    let hex = format!("{:032x}", ulid);
+    fields.push((
+        "As UUID",
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        ),
+    ));
+    Ok(())
+}
+
+fn inspect_snowflake(
+    value: &str,
+    epoch: i64,
+    fields: &mut Vec<(&'static str, String)>,
+) -> Result<(), ToolError> {
+    let id: u64 = value
+        .parse()
+        .map_err(|err| ToolError::from(err).context("Invalid Snowflake ID"))?;
+    push_timestamp(fields, (id >> 22) as i64 + epoch);
+    fields.push(("Worker", (id >> 12 & 0x3ff).to_string()));
+    fields.push(("Sequence", (id & 0xfff).to_string()));
+    Ok(())
+}
+
+/// Adds the time, in UTC, and the Unix timestamp in milliseconds.
+fn push_timestamp(fields: &mut Vec<(&'static str, String)>, milliseconds: i64) {
+    let time = DateTime::from_timestamp_millis(milliseconds)
+        .map(|time| time.to_rfc3339_opts(SecondsFormat::Millis, true))
+        .unwrap_or_else(|| "Out of range".to_string());
+    fields.push(("Timestamp", time));
+    fields.push(("Unix Milliseconds", milliseconds.to_string()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field<'a>(fields: &'a [(&'static str, String)], name: &str) -> &'a str {
+        fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value.as_str())
+            .unwrap_or_else(|| panic!("no {} field", name))
+    }
+
+    #[test]
+    fn detects_kinds() {
+        assert_eq!(
+            Kind::detect("550e8400-e29b-41d4-a716-446655440000"),
+            Some(Kind::UUID)
+        );
+        assert_eq!(
+            Kind::detect("{550E8400E29B41D4A716446655440000}"),
+            Some(Kind::UUID)
+        );
+        assert_eq!(Kind::detect("01ARZ3NDEKTSV4RRFFQ69G5FAV"), Some(Kind::ULID));
+        assert_eq!(Kind::detect("1541815603606036480"), Some(Kind::Snowflake));
+        assert_eq!(Kind::detect("hello"), None);
+    }
+
+    #[test]
+    fn inspects_random_uuids() {
+        let fields = inspect(
+            "urn:uuid:550E8400-E29B-41D4-A716-446655440000",
+            Kind::Auto,
+            0,
+        )
+        .unwrap();
+        assert_eq!(
+            field(&fields, "Canonical"),
+            "550e8400-e29b-41d4-a716-446655440000"
+        );
+        assert_eq!(field(&fields, "Version"), "4 (Random)");
+        assert_eq!(field(&fields, "Variant"), "RFC 9562");
+        assert!(!fields.iter().any(|(name, _)| *name == "Timestamp"));
+    }
+
+    #[test]
+    fn inspects_time_based_uuids() {
+        // The examples from RFC 9562, appendix A.
+        let fields = inspect("C232AB00-9414-11EC-B3C8-9F6BDECED846", Kind::UUID, 0).unwrap();
+        assert_eq!(field(&fields, "Version"), "1 (Gregorian time-based)");
+        assert_eq!(field(&fields, "Timestamp"), "2022-02-22T19:22:22.000Z");
+        assert_eq!(field(&fields, "Clock Sequence"), "13256");
+        assert_eq!(field(&fields, "Node"), "9f:6b:de:ce:d8:46");
+
+        let fields = inspect("1EC9414C-232A-6B00-B3C8-9F6BDECED846", Kind::UUID, 0).unwrap();
+        assert_eq!(field(&fields, "Timestamp"), "2022-02-22T19:22:22.000Z");
+
+        let fields = inspect("017F22E2-79B0-7CC3-98C4-DC0C0C07398F", Kind::UUID, 0).unwrap();
+        assert_eq!(field(&fields, "Version"), "7 (Unix time-based)");
+        assert_eq!(field(&fields, "Timestamp"), "2022-02-22T19:22:22.000Z");
+        assert_eq!(field(&fields, "Unix Milliseconds"), "1645557742000");
+    }
+
+    #[test]
+    fn inspects_ulids() {
+        let fields = inspect("01arz3ndektsv4rrffq69g5fav", Kind::Auto, 0).unwrap();
+        assert_eq!(field(&fields, "Type"), "ULID");
+        assert_eq!(field(&fields, "Canonical"), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        assert_eq!(field(&fields, "Unix Milliseconds"), "1469922850259");
+        assert!(inspect("81ARZ3NDEKTSV4RRFFQ69G5FAV", Kind::ULID, 0).is_err());
+    }
+
+    #[test]
+    fn inspects_snowflakes_from_an_epoch() {
+        let fields = inspect("175928847299117063", Kind::Auto, DISCORD_EPOCH).unwrap();
+        assert_eq!(field(&fields, "Timestamp"), "2016-04-30T11:18:25.796Z");
+        assert_eq!(field(&fields, "Worker"), "32");
+        assert_eq!(field(&fields, "Sequence"), "7");
+    }
+
+    #[test]
+    fn rejects_malformed_ids() {
+        assert!(inspect("not an id", Kind::Auto, 0).is_err());
+        assert!(inspect("550e8400-e29b-41d4-a716", Kind::UUID, 0).is_err());
+        assert!(inspect("-1", Kind::Snowflake, 0).is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_json::ser::{PrettyFormatter, Serializer};
+
+use crate::ToolError;
+
+/// Changes made to a document as it is formatted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Normalization {
+    /// Sorts the keys of every object alphabetically.
+    pub sort_keys: bool,
+    /// Drops object members whose value is null. Nulls in arrays are kept,
+    /// since dropping them would shift the items after them.
+    pub strip_nulls: bool,
+    /// Writes non-ASCII characters as `\u` escapes rather than as they are.
+    pub escape_unicode: bool,
+}
+
+fn parse(source: &str) -> Result<Value, ToolError> {
+    serde_json::from_str(source).map_err(|err| ToolError::from(err).context("Invalid JSON"))
+}
+
+fn normalize(value: &mut Value, normalization: &Normalization) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                normalize(item, normalization);
+            }
+        }
+        Value::Object(map) => {
+            if normalization.strip_nulls {
+                map.retain(|_, value| !value.is_null());
+            }
+            if normalization.sort_keys {
+                map.sort_keys();
+            }
+            for (_, value) in map.iter_mut() {
+                normalize(value, normalization);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Escapes every non-ASCII character in serialized JSON. They can only
+/// appear inside strings, so the whole text can be escaped at once.
+fn escape_unicode(json: String) -> String {
+    if json.is_ascii() {
+        return json;
+    }
+    let mut escaped = String::with_capacity(json.len() + 16);
+    for c in json.chars() {
+        if c.is_ascii() {
+            escaped.push(c);
+        } else {
+            for unit in c.encode_utf16(&mut [0; 2]) {
+                escaped.push_str(&format!("\\u{:04x}", unit));
+            }
+        }
+    }
+    escaped
+}
+
+/// Pretty-prints JSON, indenting each level by `indentation_size` spaces.
+pub fn format(source: &str, indentation_size: usize) -> Result<String, ToolError> {
+    format_with(source, indentation_size, &Normalization::default())
+}
+
+pub fn compact(source: &str) -> Result<String, ToolError> {
+    compact_with(source, &Normalization::default())
+}
+
+/// Like [`format`], but normalizes the document first.
+pub fn format_with(
+    source: &str,
+    indentation_size: usize,
+    normalization: &Normalization,
+) -> Result<String, ToolError> {
+    let mut json_value = parse(source)?;
+    normalize(&mut json_value, normalization);
+    let indent = b" ".repeat(indentation_size);
+    let formatter = PrettyFormatter::with_indent(indent.as_slice());
+    let mut writer = Vec::with_capacity(128);
+    let mut serializer = Serializer::with_formatter(&mut writer, formatter);
+    json_value.serialize(&mut serializer)?;
+    let formatted = String::from_utf8(writer)?;
+    Ok(match normalization.escape_unicode {
+        true => escape_unicode(formatted),
+        false => formatted,
+    })
+}
+
+/// Like [`compact`], but normalizes the document first.
+pub fn compact_with(source: &str, normalization: &Normalization) -> Result<String, ToolError> {
+    let mut json_value = parse(source)?;
+    normalize(&mut json_value, normalization);
+    let compacted = serde_json::to_string(&json_value)?;
+    Ok(match normalization.escape_unicode {
+        true => escape_unicode(compacted),
+        false => compacted,
+    })
+}
+
+/// Quotes and escapes text as a JSON string.
+pub fn encode_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Unquotes and unescapes a JSON string.
+pub fn decode_string(value: &str) -> Result<String, ToolError> {
+    serde_json::from_str::<String>(value)
+        .map_err(|err| ToolError::from(err).context("Invalid JSON string"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_with_the_given_indentation() {
+        assert_eq!(
+            format(r#"{"a":[1,2]}"#, 4).unwrap(),
+            "{\n    \"a\": [\n        1,\n        2\n    ]\n}"
+        );
+    }
+
+    #[test]
+    fn compacts() {
+        assert_eq!(compact("{ \"a\" : [ 1, 2 ] }").unwrap(), r#"{"a":[1,2]}"#);
+    }
+
+    #[test]
+    fn sorts_keys_and_strips_nulls_at_every_level() {
+        let normalization = Normalization {
+            sort_keys: true,
+            strip_nulls: true,
+            escape_unicode: false,
+        };
+        assert_eq!(
+            compact_with(
+                r#"{"b":{"z":1,"y":null,"x":[{"d":2,"c":null},null]},"a":null}"#,
+                &normalization
+            )
+            .unwrap(),
+            r#"{"b":{"x":[{"d":2},null],"z":1}}"#
+        );
+    }
+
+    #[test]
+    fn escapes_and_unescapes_unicode() {
+        let escape = Normalization {
+            escape_unicode: true,
+            ..Normalization::default()
+        };
+        assert_eq!(
+            compact_with(r#"{"café":"😀"}"#, &escape).unwrap(),
+            r#"{"caf\u00e9":"\ud83d\ude00"}"#
+        );
+        assert_eq!(
+            format_with(
+                r#"{"caf\u00e9": "\ud83d\ude00"}"#,
+                2,
+                &Normalization::default()
+            )
+            .unwrap(),
+            "{\n  \"café\": \"😀\"\n}"
+        );
+    }
+
+    #[test]
+    fn reports_where_parsing_failed() {
+        let err = format("{\n  \"a\": }", 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid JSON: expected value (line 2, column 8)"
+        );
+    }
+
+    #[test]
+    fn round_trips_strings() {
+        let encoded = encode_string("say \"hi\"\n");
+        assert_eq!(encoded, r#""say \"hi\"\n""#);
+        assert_eq!(decode_string(&encoded).unwrap(), "say \"hi\"\n");
+        assert!(decode_string("not quoted").is_err());
+    }
+}
@@ -0,0 +1,318 @@
+use std::{cmp::Ordering, collections::HashSet, ops::Range, sync::LazyLock};
+
+use rand::{Rng, seq::SliceRandom};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// How lines are ordered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Sort {
+    #[default]
+    None,
+    Ascending,
+    Descending,
+    /// Alphabetical, but with runs of digits compared as numbers, so that
+    /// "file2" comes before "file10".
+    Natural,
+    /// By the number each line starts with, with other lines last.
+    Numeric,
+}
+
+/// Whether line numbers are added to or stripped from the lines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum LineNumbers {
+    #[default]
+    Keep,
+    Add,
+    Strip,
+}
+
+/// The operations to apply to each line, in the order they are listed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Operations {
+    pub trim: bool,
+    pub sort: Sort,
+    pub remove_duplicates: bool,
+    pub reverse: bool,
+    pub shuffle: bool,
+    pub line_numbers: LineNumbers,
+}
+
+static LINE_NUMBER_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*\d+[.:)]?[ \t]").unwrap());
+
+/// Applies `operations` to the lines of `text`. Line numbers are stripped
+/// first and added last, so that the other operations see the lines alone.
+pub fn apply(text: &str, operations: &Operations, rng: &mut impl Rng) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if operations.line_numbers == LineNumbers::Strip {
+        for line in &mut lines {
+            if let Some(number) = LINE_NUMBER_PATTERN.find(line) {
+                *line = &line[number.end()..];
+            }
+        }
+    }
+    if operations.trim {
+        for line in &mut lines {
+            *line = line.trim();
+        }
+    }
+    match operations.sort {
+        Sort::None => {}
+        Sort::Ascending => lines.sort(),
+        Sort::Descending => lines.sort_by(|a, b| b.cmp(a)),
+        Sort::Natural => lines.sort_by(|a, b| natural_cmp(a, b)),
+        Sort::Numeric => lines.sort_by(|a, b| match (leading_number(a), leading_number(b)) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }),
+    }
+    if operations.remove_duplicates {
+        let mut seen = HashSet::new();
+        lines.retain(|line| seen.insert(*line));
+    }
+    if operations.reverse {
+        lines.reverse();
+    }
+    if operations.shuffle {
+        lines.shuffle(rng);
+    }
+    if operations.line_numbers == LineNumbers::Add {
+        let width = lines.len().to_string().len();
+        return lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| format!("{:>width$}. {}", i + 1, line))
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+    lines.join("\n")
+}
+
+/// A line with its whitespace drawn as visible characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VisibleLine {
+    pub text: String,
+    /// The byte range of `text` that shows the whitespace at the end of the
+    /// line, before its line ending.
+    pub trailing: Range<usize>,
+}
+
+/// Draws spaces as `·`, tabs as `→`, no-break spaces as `⍽`, other
+/// whitespace as `␣`, and ends the line with `␍␊` for CRLF or `␊` for LF, so
+/// lines that look identical can be told apart. `line` includes its line
+/// ending, and a line without one, such as the last line of most text, gets
+/// no marker.
+pub fn show_whitespace(line: &str) -> VisibleLine {
+    let (content, ending) = match line.strip_suffix('\n') {
+        Some(content) => match content.strip_suffix('\r') {
+            Some(content) => (content, "␍␊"),
+            None => (content, "␊"),
+        },
+        None => (line, ""),
+    };
+    let body = content.trim_end();
+    let mut text = body.chars().map(visible_char).collect::<String>();
+    let start = text.len();
+    text.extend(content[body.len()..].chars().map(visible_char));
+    let trailing = start..text.len();
+    text.push_str(ending);
+    VisibleLine { text, trailing }
+}
+
+/// Draws `c` the way `show_whitespace` does: whitespace other than a line
+/// feed as a visible mark, and anything else as itself.
+pub fn visible_char(c: char) -> char {
+    match c {
+        ' ' => '·',
+        '\t' => '→',
+        '\u{a0}' => '⍽',
+        '\r' => '␍',
+        '\n' => '\n',
+        c if c.is_whitespace() => '␣',
+        c => c,
+    }
+}
+
+/// Draws the whitespace in `text` the way `show_whitespace` does, keeping
+/// each line feed after its `␊`, and moves `ranges` of `text` along so they
+/// cover the same characters in what is returned.
+pub fn show_whitespace_in(text: &str, ranges: &mut [Range<usize>]) -> String {
+    let mut visible = String::with_capacity(text.len());
+    let mut offsets = vec![0; text.len() + 1];
+    for (i, c) in text.char_indices() {
+        offsets[i] = visible.len();
+        if c == '\n' {
+            visible.push('␊');
+        }
+        visible.push(visible_char(c));
+    }
+    offsets[text.len()] = visible.len();
+    for range in ranges {
+        *range = offsets[range.start]..offsets[range.end];
+    }
+    visible
+}
+
+/// Parses the number a line starts with, ignoring leading whitespace.
+fn leading_number(line: &str) -> Option<f64> {
+    let line = line.trim_start();
+    let end = line
+        .char_indices()
+        .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && matches!(c, '-' | '+'))))
+        .map_or(line.len(), |(i, _)| i);
+    // Back off until what is left parses, e.g. "1.5." or "3-".
+    (1..=end).rev().find_map(|end| line[..end].parse().ok())
+}
+
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        let (Some(x), Some(y)) = (a.chars().next(), b.chars().next()) else {
+            return a.len().cmp(&b.len());
+        };
+        let ordering = if x.is_ascii_digit() && y.is_ascii_digit() {
+            let digits = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+            let (x, y) = (&a[..digits(a)], &b[..digits(b)]);
+            a = &a[x.len()..];
+            b = &b[y.len()..];
+            let (x, y) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+            x.len().cmp(&y.len()).then_with(|| x.cmp(y))
+        } else {
+            a = &a[x.len_utf8()..];
+            b = &b[y.len_utf8()..];
+            x.cmp(&y)
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    fn run(text: &str, operations: Operations) -> String {
+        apply(text, &operations, &mut StdRng::seed_from_u64(0))
+    }
+
+    #[test]
+    fn sorts_lines() {
+        let text = "file10\nfile2\nFile1\nfile1";
+        let sort = |sort| {
+            run(
+                text,
+                Operations {
+                    sort,
+                    ..Default::default()
+                },
+            )
+        };
+        assert_eq!(sort(Sort::None), text);
+        assert_eq!(sort(Sort::Ascending), "File1\nfile1\nfile10\nfile2");
+        assert_eq!(sort(Sort::Descending), "file2\nfile10\nfile1\nFile1");
+        assert_eq!(sort(Sort::Natural), "File1\nfile1\nfile2\nfile10");
+    }
+
+    #[test]
+    fn sorts_numerically_with_other_lines_last() {
+        let operations = Operations {
+            sort: Sort::Numeric,
+            ..Default::default()
+        };
+        assert_eq!(
+            run("10 apples\nnone\n-2\n3.5 pears\n", operations),
+            "-2\n3.5 pears\n10 apples\nnone"
+        );
+    }
+
+    #[test]
+    fn chains_operations() {
+        let operations = Operations {
+            trim: true,
+            sort: Sort::Ascending,
+            remove_duplicates: true,
+            reverse: true,
+            ..Default::default()
+        };
+        assert_eq!(run("  b\na \nb\n c", operations), "c\nb\na");
+    }
+
+    #[test]
+    fn adds_and_strips_line_numbers() {
+        let text = (1..=10)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let numbered = run(
+            &text,
+            Operations {
+                line_numbers: LineNumbers::Add,
+                ..Default::default()
+            },
+        );
+        assert!(numbered.starts_with(" 1. 1\n 2. 2\n"));
+        assert!(numbered.ends_with("\n10. 10"));
+        let stripped = run(
+            &numbered,
+            Operations {
+                line_numbers: LineNumbers::Strip,
+                ..Default::default()
+            },
+        );
+        assert_eq!(stripped, text);
+    }
+
+    #[test]
+    fn shuffles_every_line() {
+        let text = "a\nb\nc\nd\ne";
+        let shuffled = run(
+            text,
+            Operations {
+                shuffle: true,
+                ..Default::default()
+            },
+        );
+        let mut lines: Vec<&str> = shuffled.lines().collect();
+        lines.sort();
+        assert_eq!(lines.join("\n"), text);
+    }
+
+    #[test]
+    fn shows_whitespace() {
+        assert_eq!(
+            show_whitespace("a b\t \r\n"),
+            VisibleLine {
+                text: "a·b→·␍␊".to_string(),
+                trailing: "a·b".len().."a·b→·".len(),
+            }
+        );
+        assert_eq!(show_whitespace("\u{a0}x\n").text, "⍽x␊");
+        assert!(show_whitespace("x\n").trailing.is_empty());
+    }
+
+    #[test]
+    fn shows_no_ending_without_a_newline() {
+        let lines: Vec<String> = "a\r\nb\nc "
+            .split_inclusive('\n')
+            .map(|line| show_whitespace(line).text)
+            .collect();
+        assert_eq!(lines, ["a␍␊", "b␊", "c·"]);
+        assert_eq!(show_whitespace("x\r").text, "x␍");
+    }
+
+    #[test]
+    fn shows_whitespace_in_text() {
+        let mut ranges = ["a ".len().."a b\r\n".len(), 0..1];
+        let text = show_whitespace_in("a b\r\nc\t", &mut ranges);
+        assert_eq!(text, "a·b␍␊\nc→");
+        assert_eq!(&text[ranges[0].clone()], "b␍␊\n");
+        assert_eq!(&text[ranges[1].clone()], "a");
+    }
+}
@@ -0,0 +1,225 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+/// How the six bytes of a MAC address are written out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Format {
+    /// `00:1a:2b:3c:4d:5e`
+    #[default]
+    Colon,
+    /// `00-1a-2b-3c-4d-5e`
+    Dash,
+    /// `001a.2b3c.4d5e`, as Cisco writes them.
+    Dot,
+    /// `001a2b3c4d5e`
+    Bare,
+}
+
+impl Format {
+    pub const ALL: [Self; 4] = [Self::Colon, Self::Dash, Self::Dot, Self::Bare];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Colon => "Colon",
+            Self::Dash => "Dash",
+            Self::Dot => "Cisco Dot",
+            Self::Bare => "Bare",
+        }
+    }
+}
+
+/// Parses a MAC address in any of the formats, ignoring case.
+pub fn parse(value: &str) -> Result<[u8; 6], ToolError> {
+    let value = value.trim();
+    let mut digits = Vec::with_capacity(12);
+    for (i, c) in value.chars().enumerate() {
+        match c {
+            c if c.is_ascii_hexdigit() => digits.push(c.to_digit(16).unwrap_or_default() as u8),
+            ':' | '-' | '.' => {}
+            c => {
+                return Err(ToolError::new(format!("Unexpected character '{}'", c))
+                    .context("Invalid MAC address")
+                    .at_offset(i));
+            }
+        }
+    }
+    if digits.len() != 12 {
+        return Err(
+            ToolError::new(format!("Expected 12 hex digits, found {}", digits.len()))
+                .context("Invalid MAC address"),
+        );
+    }
+    let mut bytes = [0; 6];
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+        *byte = pair[0] << 4 | pair[1];
+    }
+    Ok(bytes)
+}
+
+pub fn format(bytes: &[u8; 6], format: Format, uppercase: bool) -> String {
+    let hex: Vec<String> = bytes
+        .iter()
+        .map(|byte| {
+            if uppercase {
+                format!("{:02X}", byte)
+            } else {
+                format!("{:02x}", byte)
+            }
+        })
+        .collect();
+    match format {
+        Format::Colon => hex.join(":"),
+        Format::Dash => hex.join("-"),
+        Format::Dot => hex
+            .chunks(2)
+            .map(|pair| pair.concat())
+            .collect::<Vec<_>>()
+            .join("."),
+        Format::Bare => hex.concat(),
+    }
+}
+
+/// Whether the address was assigned by the manufacturer rather than set
+/// locally, e.g. for privacy or by a virtual machine.
+pub fn is_locally_administered(bytes: &[u8; 6]) -> bool {
+    bytes[0] & 0x02 != 0
+}
+
+pub fn is_multicast(bytes: &[u8; 6]) -> bool {
+    bytes[0] & 0x01 != 0
+}
+
+/// The modified EUI-64 interface identifier that IPv6 derives from the
+/// address: `ff:fe` goes in the middle and the universal/local bit flips.
+pub fn eui64(bytes: &[u8; 6]) -> [u8; 8] {
+    [
+        bytes[0] ^ 0x02,
+        bytes[1],
+        bytes[2],
+        0xff,
+        0xfe,
+        bytes[3],
+        bytes[4],
+        bytes[5],
+    ]
+}
+
+/// Lists the address in every format along with what its bits say.
+pub fn inspect(bytes: &[u8; 6], uppercase: bool) -> Vec<(&'static str, String)> {
+    let mut fields: Vec<(&'static str, String)> = Format::ALL
+        .into_iter()
+        .map(|f| (f.name(), format(bytes, f, uppercase)))
+        .collect();
+    let oui = format(bytes, Format::Colon, uppercase)[..8].to_string();
+    fields.push(("OUI", oui));
+    fields.push((
+        "Administration",
+        if is_locally_administered(bytes) {
+            "Locally administered (LAA)"
+        } else {
+            "Universally administered (UAA)"
+        }
+        .to_string(),
+    ));
+    fields.push((
+        "Transmission",
+        if bytes.iter().all(|&byte| byte == 0xff) {
+            "Broadcast"
+        } else if is_multicast(bytes) {
+            "Multicast"
+        } else {
+            "Unicast"
+        }
+        .to_string(),
+    ));
+    let eui64 = eui64(bytes);
+    fields.push((
+        "Modified EUI-64",
+        eui64
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<Vec<_>>()
+            .join(":"),
+    ));
+    let groups: Vec<String> = eui64
+        .chunks(2)
+        .map(|pair| format!("{:x}", u16::from_be_bytes([pair[0], pair[1]])))
+        .collect();
+    fields.push(("IPv6 Link-Local", format!("fe80::{}", groups.join(":"))));
+    fields
+}
+
+/// Generates a random unicast address, locally administered so that it
+/// cannot clash with one assigned by a manufacturer.
+pub fn random(rng: &mut impl Rng) -> [u8; 6] {
+    let mut bytes: [u8; 6] = rng.random();
+    bytes[0] = (bytes[0] | 0x02) & !0x01;
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    const MAC: [u8; 6] = [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e];
+
+    #[test]
+    fn parses_every_format() {
+        for value in [
+            "00:1a:2b:3c:4d:5e",
+            "00-1A-2B-3C-4D-5E",
+            "001a.2b3c.4d5e",
+            " 001A2B3C4D5E ",
+        ] {
+            assert_eq!(parse(value).unwrap(), MAC, "{}", value);
+        }
+        assert!(parse("00:1a:2b:3c:4d").is_err());
+        assert!(parse("00:1a:2b:3c:4d:5g").is_err());
+    }
+
+    #[test]
+    fn formats_addresses() {
+        assert_eq!(format(&MAC, Format::Colon, false), "00:1a:2b:3c:4d:5e");
+        assert_eq!(format(&MAC, Format::Dash, true), "00-1A-2B-3C-4D-5E");
+        assert_eq!(format(&MAC, Format::Dot, false), "001a.2b3c.4d5e");
+        assert_eq!(format(&MAC, Format::Bare, false), "001a2b3c4d5e");
+    }
+
+    #[test]
+    fn reads_flag_bits() {
+        assert!(!is_locally_administered(&MAC));
+        assert!(!is_multicast(&MAC));
+        let multicast = parse("01:00:5e:00:00:fb").unwrap();
+        assert!(is_multicast(&multicast));
+        let local = parse("02:42:ac:11:00:02").unwrap();
+        assert!(is_locally_administered(&local));
+    }
+
+    #[test]
+    fn derives_ipv6_link_local_address() {
+        let fields = inspect(&MAC, false);
+        let field = |name| {
+            fields
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, value)| value.as_str())
+        };
+        assert_eq!(field("OUI"), Some("00:1a:2b"));
+        assert_eq!(field("Modified EUI-64"), Some("02:1a:2b:ff:fe:3c:4d:5e"));
+        assert_eq!(field("IPv6 Link-Local"), Some("fe80::21a:2bff:fe3c:4d5e"));
+    }
+
+    #[test]
+    fn generates_locally_administered_addresses() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..32 {
+            let bytes = random(&mut rng);
+            assert!(is_locally_administered(&bytes));
+            assert!(!is_multicast(&bytes));
+        }
+    }
+}
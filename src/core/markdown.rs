@@ -0,0 +1,823 @@
+use scraper::{ElementRef, Html, Node, Selector};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    ToolError,
+    core::csv::{self, Quoting},
+};
+
+/// What a table is written in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TableSource {
+    /// Markdown if the first lines look like a Markdown table, TSV if the
+    /// first line holds a tab, as cells copied from a spreadsheet do, and CSV
+    /// otherwise.
+    #[default]
+    Auto,
+    CSV,
+    TSV,
+    Markdown,
+}
+
+impl TableSource {
+    pub const ALL: [Self; 4] = [Self::Auto, Self::CSV, Self::TSV, Self::Markdown];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Auto => "Detect Automatically",
+            Self::CSV => "CSV",
+            Self::TSV => "TSV or Spreadsheet Cells",
+            Self::Markdown => "Markdown Table",
+        }
+    }
+}
+
+/// How the columns of the table are aligned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TableAlignment {
+    /// Keeps the alignments of a Markdown table, and leaves those of other
+    /// tables unspecified.
+    #[default]
+    Keep,
+    /// Right-aligns columns that hold only numbers, and left-aligns the rest.
+    NumbersRight,
+    Left,
+    Center,
+    Right,
+    Unspecified,
+}
+
+impl TableAlignment {
+    pub const ALL: [Self; 6] = [
+        Self::Keep,
+        Self::NumbersRight,
+        Self::Left,
+        Self::Center,
+        Self::Right,
+        Self::Unspecified,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Keep => "Keep Existing",
+            Self::NumbersRight => "Numbers Right, Text Left",
+            Self::Left => "Left",
+            Self::Center => "Center",
+            Self::Right => "Right",
+            Self::Unspecified => "Unspecified",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Alignment {
+    Unspecified,
+    Left,
+    Center,
+    Right,
+}
+
+/// Splits a Markdown table row into its cells, leaving escaped pipes in
+/// place.
+fn split_row(line: &str) -> Vec<String> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = match line.strip_suffix('|') {
+        Some(rest) if !rest.ends_with('\\') => rest,
+        _ => line,
+    };
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                cell.push(c);
+                if let Some(next) = chars.next() {
+                    cell.push(next);
+                }
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            c => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+/// Reads a delimiter row like `| :--- | ---: |`, or returns `None` if the
+/// line is not one.
+fn parse_delimiter_row(line: &str) -> Option<Vec<Alignment>> {
+    if !line.contains('-') {
+        return None;
+    }
+    split_row(line)
+        .iter()
+        .map(|cell| {
+            let left = cell.starts_with(':');
+            let right = cell.ends_with(':');
+            let dashes = cell.trim_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+                return None;
+            }
+            Some(match (left, right) {
+                (true, true) => Alignment::Center,
+                (true, false) => Alignment::Left,
+                (false, true) => Alignment::Right,
+                (false, false) => Alignment::Unspecified,
+            })
+        })
+        .collect()
+}
+
+fn parse_markdown(source: &str) -> Result<(Vec<Vec<String>>, Vec<Alignment>), ToolError> {
+    let lines = source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect::<Vec<_>>();
+    let Some(&(_, header)) = lines.first() else {
+        return Err(ToolError::new("the table is empty"));
+    };
+    let Some(alignments) = lines.get(1).and_then(|(_, line)| parse_delimiter_row(line)) else {
+        let line = lines.get(1).map_or(lines[0].0 + 2, |(i, _)| i + 1);
+        return Err(
+            ToolError::new("expected a delimiter row like | --- | --- |").at_line_column(line, 1),
+        );
+    };
+    let mut rows = vec![split_row(header)];
+    rows.extend(lines[2..].iter().map(|(_, line)| split_row(line)));
+    Ok((rows, alignments))
+}
+
+fn is_markdown(source: &str) -> bool {
+    let mut lines = source.lines().filter(|line| !line.trim().is_empty());
+    let first = lines.next().unwrap_or_default();
+    first.trim_start().starts_with('|') || lines.next().and_then(parse_delimiter_row).is_some()
+}
+
+fn is_number(cell: &str) -> bool {
+    let cell = cell
+        .trim()
+        .trim_start_matches(['-', '+', '$', '€', '£'])
+        .trim_end_matches('%');
+    !cell.is_empty()
+        && cell.chars().any(|c| c.is_ascii_digit())
+        && cell
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | ','))
+}
+
+/// Escapes text from CSV for a Markdown cell, where pipes end the cell and
+/// line breaks end the row.
+fn escape_cell(cell: &str) -> String {
+    cell.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+/// Writes rows as a Markdown table with its columns padded to line up, taking
+/// the first row as the header.
+fn write_table(rows: &[Vec<String>], alignments: &[Alignment]) -> String {
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let alignments = (0..column_count)
+        .map(|i| alignments.get(i).copied().unwrap_or(Alignment::Unspecified))
+        .collect::<Vec<_>>();
+    let widths = (0..column_count)
+        .map(|i| {
+            rows.iter()
+                .map(|row| row.get(i).map_or(0, |cell| cell.chars().count()))
+                .max()
+                .unwrap_or_default()
+                .max(3)
+        })
+        .collect::<Vec<_>>();
+
+    let format_row = |row: &Vec<String>| {
+        let cells = widths
+            .iter()
+            .zip(&alignments)
+            .enumerate()
+            .map(|(i, (&width, alignment))| {
+                let cell = row.get(i).map_or("", String::as_str);
+                match alignment {
+                    Alignment::Right => format!("{:>width$}", cell),
+                    Alignment::Center => format!("{:^width$}", cell),
+                    Alignment::Left | Alignment::Unspecified => format!("{:<width$}", cell),
+                }
+            })
+            .collect::<Vec<_>>();
+        format!("| {} |", cells.join(" | "))
+    };
+    let delimiter_row = widths
+        .iter()
+        .zip(&alignments)
+        .map(|(&width, alignment)| match alignment {
+            Alignment::Unspecified => "-".repeat(width),
+            Alignment::Left => format!(":{}", "-".repeat(width - 1)),
+            Alignment::Right => format!("{}:", "-".repeat(width - 1)),
+            Alignment::Center => format!(":{}:", "-".repeat(width - 2)),
+        })
+        .collect::<Vec<_>>();
+
+    let Some((header, body)) = rows.split_first() else {
+        return String::new();
+    };
+    let mut lines = vec![
+        format_row(header),
+        format!("| {} |", delimiter_row.join(" | ")),
+    ];
+    lines.extend(body.iter().map(format_row));
+    lines.join("\n")
+}
+
+/// Converts CSV, TSV, or a Markdown table into a Markdown table with its
+/// columns padded to line up. The first row is taken as the header.
+pub fn format_table(
+    source: &str,
+    from: TableSource,
+    alignment: TableAlignment,
+) -> Result<String, ToolError> {
+    let from = match from {
+        TableSource::Auto if is_markdown(source) => TableSource::Markdown,
+        TableSource::Auto
+            if source
+                .lines()
+                .find(|line| !line.trim().is_empty())
+                .is_some_and(|line| line.contains('\t')) =>
+        {
+            TableSource::TSV
+        }
+        TableSource::Auto => TableSource::CSV,
+        from => from,
+    };
+    let (rows, existing) = match from {
+        TableSource::Markdown => {
+            parse_markdown(source).map_err(|err| err.context("Invalid Markdown table"))?
+        }
+        _ => {
+            let (delimiter, name) = match from {
+                TableSource::TSV => ('\t', "Invalid TSV"),
+                _ => (',', "Invalid CSV"),
+            };
+            let rows = csv::parse(source, delimiter, Quoting::Minimal)
+                .map_err(|err| err.context(name))?
+                .into_iter()
+                .filter(|row| row.iter().any(|cell| !cell.trim().is_empty()))
+                .map(|row| row.iter().map(|cell| escape_cell(cell.trim())).collect())
+                .collect::<Vec<Vec<String>>>();
+            (rows, Vec::new())
+        }
+    };
+    if rows.is_empty() {
+        return Err(ToolError::new("the table is empty"));
+    }
+
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let alignments = (0..column_count)
+        .map(|i| match alignment {
+            TableAlignment::Keep => existing.get(i).copied().unwrap_or(Alignment::Unspecified),
+            TableAlignment::NumbersRight => {
+                let mut body = rows[1..]
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .filter(|c| !c.is_empty());
+                match body.clone().next().is_some() && body.all(|cell| is_number(cell)) {
+                    true => Alignment::Right,
+                    false => Alignment::Left,
+                }
+            }
+            TableAlignment::Left => Alignment::Left,
+            TableAlignment::Center => Alignment::Center,
+            TableAlignment::Right => Alignment::Right,
+            TableAlignment::Unspecified => Alignment::Unspecified,
+        })
+        .collect::<Vec<_>>();
+    Ok(write_table(&rows, &alignments))
+}
+
+/// What is kept when converting HTML to Markdown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HtmlOptions {
+    /// Keeps images as `![alt](src)`, rather than dropping them.
+    pub images: bool,
+    /// Keeps links as `[text](href)`, rather than only their text.
+    pub links: bool,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        Self {
+            images: true,
+            links: true,
+        }
+    }
+}
+
+/// Stands in for a `<br>` until the text around it has been tidied, since it
+/// becomes a different thing in a paragraph, a heading, and a table cell.
+const BREAK: char = '\u{e000}';
+
+/// Elements whose content is never shown.
+const HIDDEN: [&str; 14] = [
+    "button", "canvas", "head", "iframe", "link", "meta", "noscript", "script", "select", "style",
+    "svg", "template", "textarea", "title",
+];
+
+/// Elements that start a new block rather than flowing with the text around
+/// them.
+const BLOCKS: [&str; 34] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "center",
+    "dd",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+/// Escapes the characters that Markdown would read as formatting.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Collapses runs of whitespace into single spaces and drops the spaces
+/// around line breaks and at either end.
+fn tidy(text: &str) -> String {
+    text.split(BREAK)
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join(&BREAK.to_string())
+        .trim_matches(|c: char| c == BREAK || c.is_whitespace())
+        .to_string()
+}
+
+/// Wraps text in a code span, using more backticks than it holds in a row.
+fn code_span(text: &str) -> String {
+    let fence = "`".repeat(longest_run(text, '`') + 1);
+    match text.starts_with('`') || text.ends_with('`') {
+        true => format!("{fence} {text} {fence}"),
+        false => format!("{fence}{text}{fence}"),
+    }
+}
+
+fn longest_run(text: &str, c: char) -> usize {
+    text.split(|other| other != c)
+        .map(str::len)
+        .max()
+        .unwrap_or_default()
+}
+
+/// Wraps the text in emphasis markers, keeping the whitespace around it
+/// outside of them, where Markdown expects it.
+fn emphasize(text: &str, marker: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+    let leading = if text.starts_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+    let trailing = if text.ends_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+    format!("{leading}{marker}{trimmed}{marker}{trailing}")
+}
+
+/// Whether a block is a list, which stays tight against the text of the
+/// item holding it.
+fn is_list(block: &str) -> bool {
+    block.starts_with("- ")
+        || block
+            .split_once(". ")
+            .is_some_and(|(n, _)| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+struct Converter<'a> {
+    options: HtmlOptions,
+    base: Option<&'a Url>,
+}
+
+impl Converter<'_> {
+    fn resolve(&self, url: &str) -> String {
+        match self.base.and_then(|base| base.join(url).ok()) {
+            Some(url) => url.to_string(),
+            None => url.to_string(),
+        }
+    }
+
+    /// Converts the children of an element, gathering the text between
+    /// blocks into paragraphs.
+    fn blocks(&self, element: ElementRef, blocks: &mut Vec<String>) {
+        let mut text = String::new();
+        for child in element.children() {
+            match ElementRef::wrap(child) {
+                Some(child) if BLOCKS.contains(&child.value().name()) => {
+                    self.paragraph(&mut text, blocks);
+                    self.block(child, blocks);
+                }
+                Some(child) => text.push_str(&self.inline(child)),
+                None => {
+                    if let Node::Text(value) = child.value() {
+                        text.push_str(&escape_text(value));
+                    }
+                }
+            }
+        }
+        self.paragraph(&mut text, blocks);
+    }
+
+    fn paragraph(&self, text: &mut String, blocks: &mut Vec<String>) {
+        let paragraph = tidy(&std::mem::take(text));
+        if paragraph.is_empty() {
+            return;
+        }
+        // Text that happens to start like a heading, quote, or list item is
+        // escaped so it stays a paragraph.
+        let paragraph = match paragraph.chars().next() {
+            Some('#' | '>') => format!("\\{paragraph}"),
+            Some('-' | '+') if paragraph[1..].starts_with(' ') => format!("\\{paragraph}"),
+            _ if is_list(&paragraph) => paragraph.replacen(". ", "\\. ", 1),
+            _ => paragraph,
+        };
+        blocks.push(paragraph.replace(BREAK, "\\\n"));
+    }
+
+    fn block(&self, element: ElementRef, blocks: &mut Vec<String>) {
+        let name = element.value().name();
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let text = tidy(&self.inline_children(element)).replace(BREAK, " ");
+                if !text.is_empty() {
+                    blocks.push(format!(
+                        "{} {}",
+                        "#".repeat(usize::from(name.as_bytes()[1] - b'0')),
+                        text
+                    ));
+                }
+            }
+            "hr" => blocks.push("---".to_string()),
+            "pre" => blocks.push(self.code_block(element)),
+            "ul" | "ol" => self.list(element, blocks),
+            "blockquote" => {
+                let mut quoted = Vec::new();
+                self.blocks(element, &mut quoted);
+                if !quoted.is_empty() {
+                    let lines = quoted
+                        .join("\n\n")
+                        .lines()
+                        .map(|line| format!("> {line}").trim_end().to_string())
+                        .collect::<Vec<_>>();
+                    blocks.push(lines.join("\n"));
+                }
+            }
+            "table" => self.table(element, blocks),
+            _ => self.blocks(element, blocks),
+        }
+    }
+
+    fn code_block(&self, element: ElementRef) -> String {
+        let text = element.text().collect::<String>();
+        let text = text.strip_prefix('\n').unwrap_or(&text).trim_end();
+        let language = std::iter::once(element)
+            .chain(
+                element
+                    .child_elements()
+                    .filter(|child| child.value().name() == "code"),
+            )
+            .filter_map(|element| element.attr("class"))
+            .flat_map(str::split_whitespace)
+            .find_map(|class| {
+                class
+                    .strip_prefix("language-")
+                    .or_else(|| class.strip_prefix("lang-"))
+            })
+            .unwrap_or_default();
+        let fence = "`".repeat(longest_run(text, '`').max(2) + 1);
+        format!("{fence}{language}\n{text}\n{fence}")
+    }
+
+    fn list(&self, element: ElementRef, blocks: &mut Vec<String>) {
+        let ordered = element.value().name() == "ol";
+        let start = element
+            .attr("start")
+            .and_then(|start| start.parse::<usize>().ok())
+            .unwrap_or(1);
+        let items = element
+            .child_elements()
+            .filter(|child| child.value().name() == "li")
+            .enumerate()
+            .map(|(i, item)| {
+                let marker = match ordered {
+                    true => format!("{}.", start + i),
+                    false => "-".to_string(),
+                };
+                let mut inner = Vec::new();
+                self.blocks(item, &mut inner);
+                let mut content = String::new();
+                for (j, block) in inner.iter().enumerate() {
+                    if j > 0 {
+                        content.push_str(if is_list(block) { "\n" } else { "\n\n" });
+                    }
+                    content.push_str(block);
+                }
+                let indent = " ".repeat(marker.len() + 1);
+                let mut lines = vec![format!(
+                    "{} {}",
+                    marker,
+                    content.lines().next().unwrap_or_default()
+                )];
+                lines.extend(content.lines().skip(1).map(|line| match line.is_empty() {
+                    true => String::new(),
+                    false => format!("{indent}{line}"),
+                }));
+                lines.join("\n").trim_end().to_string()
+            })
+            .collect::<Vec<_>>();
+        if !items.is_empty() {
+            blocks.push(items.join("\n"));
+        }
+    }
+
+    fn table(&self, element: ElementRef, blocks: &mut Vec<String>) {
+        // Only the rows of this table are read; nested tables are flattened
+        // into the cells holding them.
+        let rows = element
+            .child_elements()
+            .flat_map(|child| match child.value().name() {
+                "tr" => vec![child],
+                "thead" | "tbody" | "tfoot" => child
+                    .child_elements()
+                    .filter(|row| row.value().name() == "tr")
+                    .collect(),
+                _ => Vec::new(),
+            })
+            .map(|row| {
+                row.child_elements()
+                    .filter(|cell| matches!(cell.value().name(), "th" | "td"))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|cells| !cells.is_empty())
+            .collect::<Vec<_>>();
+        let Some(header) = rows.first() else {
+            return;
+        };
+        let alignments = header
+            .iter()
+            .map(|cell| {
+                let style = cell.attr("style").unwrap_or_default().replace(' ', "");
+                match cell.attr("align").unwrap_or_default() {
+                    "left" => Alignment::Left,
+                    "center" => Alignment::Center,
+                    "right" => Alignment::Right,
+                    _ if style.contains("text-align:left") => Alignment::Left,
+                    _ if style.contains("text-align:center") => Alignment::Center,
+                    _ if style.contains("text-align:right") => Alignment::Right,
+                    _ => Alignment::Unspecified,
+                }
+            })
+            .collect::<Vec<_>>();
+        let rows = rows
+            .iter()
+            .map(|cells| {
+                cells
+                    .iter()
+                    .flat_map(|cell| {
+                        let span = cell
+                            .attr("colspan")
+                            .and_then(|span| span.parse::<usize>().ok())
+                            .unwrap_or(1)
+                            .max(1);
+                        let text = tidy(&self.inline_children(*cell))
+                            .replace('|', "\\|")
+                            .replace(BREAK, "<br>");
+                        std::iter::once(text).chain(std::iter::repeat_n(String::new(), span - 1))
+                    })
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        blocks.push(write_table(&rows, &alignments));
+    }
+
+    fn inline_children(&self, element: ElementRef) -> String {
+        element
+            .children()
+            .map(|child| match ElementRef::wrap(child) {
+                // Blocks inside inline content, such as paragraphs in a table
+                // cell, are run together with spaces.
+                Some(child) if BLOCKS.contains(&child.value().name()) => {
+                    format!(" {} ", self.inline_children(child))
+                }
+                Some(child) => self.inline(child),
+                None => match child.value() {
+                    Node::Text(value) => escape_text(value),
+                    _ => String::new(),
+                },
+            })
+            .collect()
+    }
+
+    fn inline(&self, element: ElementRef) -> String {
+        let name = element.value().name();
+        match name {
+            _ if HIDDEN.contains(&name) => String::new(),
+            "br" => BREAK.to_string(),
+            "strong" | "b" => emphasize(&self.inline_children(element), "**"),
+            "em" | "i" => emphasize(&self.inline_children(element), "*"),
+            "del" | "s" | "strike" => emphasize(&self.inline_children(element), "~~"),
+            "code" | "kbd" | "samp" | "tt" => {
+                let text = element.text().collect::<String>();
+                let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                match text.is_empty() {
+                    true => String::new(),
+                    false => code_span(&text),
+                }
+            }
+            "a" => {
+                let text = self.inline_children(element);
+                let Some(href) = element.attr("href").filter(|href| {
+                    self.options.links
+                        && !href.is_empty()
+                        && !href.trim_start().starts_with("javascript:")
+                }) else {
+                    return text;
+                };
+                let trimmed = tidy(&text);
+                if trimmed.is_empty() {
+                    return text;
+                }
+                let href = self.resolve(href).replace(' ', "%20");
+                match element.attr("title") {
+                    Some(title) => {
+                        format!("[{}]({} \"{}\")", trimmed, href, title.replace('"', "\\\""))
+                    }
+                    None => format!("[{}]({})", trimmed, href),
+                }
+            }
+            "img" => {
+                let Some(src) = element.attr("src").filter(|_| self.options.images) else {
+                    return String::new();
+                };
+                let alt = escape_text(element.attr("alt").unwrap_or_default());
+                let src = self.resolve(src).replace(' ', "%20");
+                match element.attr("title") {
+                    Some(title) => {
+                        format!("![{}]({} \"{}\")", alt, src, title.replace('"', "\\\""))
+                    }
+                    None => format!("![{}]({})", alt, src),
+                }
+            }
+            "input" => match element.attr("type") {
+                Some("checkbox") if element.attr("checked").is_some() => "[x] ".to_string(),
+                Some("checkbox") => "[ ] ".to_string(),
+                _ => String::new(),
+            },
+            _ => self.inline_children(element),
+        }
+    }
+}
+
+/// Converts HTML, either a whole document or a fragment, into Markdown.
+/// Relative links and images are resolved against `base` when it is given,
+/// as for a page fetched from that URL.
+pub fn from_html(source: &str, base: Option<&Url>, options: HtmlOptions) -> String {
+    let document = Html::parse_document(source);
+    let body = Selector::parse("body")
+        .ok()
+        .and_then(|selector| document.select(&selector).next())
+        .unwrap_or_else(|| document.root_element());
+    let mut blocks = Vec::new();
+    Converter { options, base }.blocks(body, &mut blocks);
+    blocks.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_csv_and_spreadsheet_cells() {
+        let csv = "Name,Price,Notes\nApple,1.20,\"red, crisp\"\nFig,12,a|b\n";
+        assert_eq!(
+            format_table(csv, TableSource::Auto, TableAlignment::NumbersRight).unwrap(),
+            "| Name  | Price | Notes      |
+| :---- | ----: | :--------- |
+| Apple |  1.20 | red, crisp |
+| Fig   |    12 | a\\|b       |"
+        );
+        assert_eq!(
+            format_table("a\tb\n1\t2", TableSource::Auto, TableAlignment::Keep).unwrap(),
+            "| a   | b   |\n| --- | --- |\n| 1   | 2   |"
+        );
+    }
+
+    #[test]
+    fn realigns_markdown_tables() {
+        let table = "Name|Qty\n:-:|--:\n|Widget|3|\n|Gadget \\| Pro|12";
+        assert_eq!(
+            format_table(table, TableSource::Auto, TableAlignment::Keep).unwrap(),
+            "|     Name      | Qty |
+| :-----------: | --: |
+|    Widget     |   3 |
+| Gadget \\| Pro |  12 |"
+        );
+        assert_eq!(
+            format_table("| a |\n| b |", TableSource::Markdown, TableAlignment::Keep)
+                .unwrap_err()
+                .to_string(),
+            "Invalid Markdown table: expected a delimiter row like | --- | --- | (line 2, column 1)"
+        );
+    }
+
+    #[test]
+    fn converts_html() {
+        let html = r#"<html><head><title>Ignored</title></head><body>
+            <h2>Getting  <em>started</em></h2>
+            <p>Run <code>cargo run</code> or read <a href="/docs" title="Docs">the docs</a>.<br>
+            Costs 5*2 [approx].</p>
+            <ul><li>One</li><li><strong>Two</strong><ol start="3"><li>Three</li></ol></li></ul>
+            <blockquote><p>Quoted</p><p>Twice</p></blockquote>
+            <pre><code class="language-rust">fn main() {}
+</code></pre>
+            <img src="logo.png" alt="Logo">
+            <table><tr><th>A</th><th align="right">B</th></tr><tr><td>x|y</td><td>2</td></tr></table>
+            <script>alert(1)</script>
+        </body></html>"#;
+        let base = Url::parse("https://example.com/guide/").unwrap();
+        assert_eq!(
+            from_html(html, Some(&base), HtmlOptions::default()),
+            r#"## Getting *started*
+
+Run `cargo run` or read [the docs](https://example.com/docs "Docs").\
+Costs 5\*2 \[approx\].
+
+- One
+- **Two**
+  3. Three
+
+> Quoted
+>
+> Twice
+
+```rust
+fn main() {}
+```
+
+![Logo](https://example.com/guide/logo.png)
+
+| A    |   B |
+| ---- | --: |
+| x\|y |   2 |"#
+        );
+        assert_eq!(
+            from_html(
+                r#"<p><a href="a">Link</a> <img src="b.png"></p><p>1. Not a list</p>"#,
+                None,
+                HtmlOptions {
+                    images: false,
+                    links: false,
+                }
+            ),
+            "Link\n\n1\\. Not a list"
+        );
+    }
+}
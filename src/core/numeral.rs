@@ -0,0 +1,234 @@
+use crate::ToolError;
+
+const ROMAN: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// The names of successive powers of a thousand, on the short scale, up to
+/// the largest an `i128` reaches.
+const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+/// The largest number Roman numerals can write without a bar over letters.
+pub const ROMAN_MAX: u32 = 3999;
+
+/// Whether a value looks like a Roman numeral rather than a number.
+pub fn is_roman(value: &str) -> bool {
+    let value = value.trim();
+    !value.is_empty()
+        && value.chars().all(|c| {
+            matches!(
+                c.to_ascii_uppercase(),
+                'I' | 'V' | 'X' | 'L' | 'C' | 'D' | 'M'
+            )
+        })
+}
+
+pub fn to_roman(number: u32) -> Result<String, ToolError> {
+    if !(1..=ROMAN_MAX).contains(&number) {
+        return Err(ToolError::new(format!(
+            "Roman numerals only go from 1 to {}",
+            ROMAN_MAX
+        )));
+    }
+    let mut rest = number;
+    let mut roman = String::new();
+    for (value, letters) in ROMAN {
+        while rest >= value {
+            roman.push_str(letters);
+            rest -= value;
+        }
+    }
+    Ok(roman)
+}
+
+/// Reads a Roman numeral, refusing ones not written the standard way, such
+/// as IIII or IC.
+pub fn parse_roman(value: &str) -> Result<u32, ToolError> {
+    let upper = value.trim().to_ascii_uppercase();
+    let mut rest = upper.as_str();
+    let mut number = 0;
+    for (value, letters) in ROMAN {
+        while let Some(tail) = rest.strip_prefix(letters) {
+            number += value;
+            rest = tail;
+        }
+    }
+    match rest.is_empty() && to_roman(number).is_ok_and(|roman| roman == upper) {
+        true => Ok(number),
+        false => Err(ToolError::new(format!(
+            "{:?} is not a Roman numeral written the standard way",
+            value.trim()
+        ))),
+    }
+}
+
+/// Writes a number below a thousand in words.
+fn hundreds_to_words(number: u128) -> String {
+    let (hundreds, rest) = (number / 100, (number % 100) as usize);
+    let mut words = Vec::new();
+    if hundreds > 0 {
+        words.push(format!("{} hundred", ONES[hundreds as usize]));
+    }
+    match rest {
+        0 => {}
+        1..20 => words.push(ONES[rest].to_string()),
+        _ if rest % 10 == 0 => words.push(TENS[rest / 10].to_string()),
+        _ => words.push(format!("{}-{}", TENS[rest / 10], ONES[rest % 10])),
+    }
+    words.join(" ")
+}
+
+/// Writes a number in English words, as in "one thousand two hundred
+/// thirty-four".
+pub fn to_words(number: i128) -> String {
+    if number == 0 {
+        return ONES[0].to_string();
+    }
+    let mut rest = number.unsigned_abs();
+    let mut groups = Vec::new();
+    let mut scale = 0;
+    while rest > 0 {
+        let group = rest % 1000;
+        if group > 0 {
+            let words = hundreds_to_words(group);
+            groups.push(match SCALES[scale] {
+                "" => words,
+                name => format!("{} {}", words, name),
+            });
+        }
+        rest /= 1000;
+        scale += 1;
+    }
+    groups.reverse();
+    let words = groups.join(" ");
+    match number < 0 {
+        true => format!("minus {}", words),
+        false => words,
+    }
+}
+
+/// Writes a number as an ordinal in words, as in "twenty-first".
+pub fn to_ordinal_words(number: i128) -> String {
+    let words = to_words(number);
+    // Only the last word changes, after any hyphen.
+    let split = words.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = words.split_at(split);
+    let last = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        last if last.ends_with('y') => format!("{}ieth", &last[..last.len() - 1]),
+        last => format!("{}th", last),
+    };
+    format!("{}{}", head, last)
+}
+
+/// Writes a number with its ordinal suffix, as in 21st or 112th.
+pub fn to_ordinal(number: i128) -> String {
+    let magnitude = number.unsigned_abs();
+    let suffix = match (magnitude % 10, magnitude % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", number, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_roman_numerals() {
+        assert_eq!(to_roman(1994).unwrap(), "MCMXCIV");
+        assert_eq!(to_roman(3999).unwrap(), "MMMCMXCIX");
+        assert!(to_roman(0).is_err());
+        assert_eq!(parse_roman("mcmxciv").unwrap(), 1994);
+        assert!(parse_roman("IIII").is_err());
+        assert!(parse_roman("IC").is_err());
+        assert!(is_roman(" xiv "));
+        assert!(!is_roman("14"));
+    }
+
+    #[test]
+    fn spells_numbers() {
+        assert_eq!(to_words(0), "zero");
+        assert_eq!(to_words(-15), "minus fifteen");
+        assert_eq!(
+            to_words(1_002_345),
+            "one million two thousand three hundred forty-five"
+        );
+        assert_eq!(to_words(7_000_000_000), "seven billion");
+        assert!(to_words(i128::MIN).starts_with("minus one hundred seventy undecillion"));
+    }
+
+    #[test]
+    fn writes_ordinals() {
+        assert_eq!(to_ordinal(1), "1st");
+        assert_eq!(to_ordinal(112), "112th");
+        assert_eq!(to_ordinal(-23), "-23rd");
+        assert_eq!(to_ordinal_words(21), "twenty-first");
+        assert_eq!(to_ordinal_words(40), "fortieth");
+        assert_eq!(to_ordinal_words(112), "one hundred twelfth");
+        assert_eq!(to_ordinal_words(1_000), "one thousandth");
+    }
+}
@@ -0,0 +1,339 @@
+use url::Url;
+
+/// Parameters that mark a URL as part of an OAuth or OpenID Connect flow.
+const MARKERS: [&str; 6] = [
+    "response_type",
+    "client_id",
+    "code",
+    "access_token",
+    "id_token",
+    "error",
+];
+
+/// Which step of the flow a URL belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// The client sending the user to the authorization endpoint.
+    AuthorizationRequest,
+    /// The authorization server redirecting back with a code or tokens.
+    Callback,
+    /// The authorization server redirecting back with an error.
+    ErrorCallback,
+}
+
+impl Step {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AuthorizationRequest => "Authorization Request",
+            Self::Callback => "Authorization Callback",
+            Self::ErrorCallback => "Error Callback",
+        }
+    }
+}
+
+/// An OAuth URL broken into its parameters, with notes on anything unsafe
+/// or unusual about them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inspection {
+    pub step: Step,
+    /// The URL without its query and fragment, if a whole URL was given.
+    pub endpoint: Option<String>,
+    /// Decoded parameters in order, from the query and then the fragment.
+    pub parameters: Vec<Parameter>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: String,
+    pub value: String,
+    /// Whether the parameter came from the fragment rather than the query.
+    pub in_fragment: bool,
+}
+
+impl Inspection {
+    fn get(&self, name: &str) -> Option<&Parameter> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.name == name)
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.get(name).map(|parameter| parameter.value.as_str())
+    }
+
+    /// The parameters as an aligned table, each with what its value means
+    /// when that is not obvious.
+    pub fn table(&self) -> String {
+        let width = self
+            .parameters
+            .iter()
+            .map(|parameter| parameter.name.len() + usize::from(parameter.in_fragment))
+            .max()
+            .unwrap_or_default()
+            .max("Parameter".len());
+        let mut lines = vec![format!("{:width$}  Value", "Parameter")];
+        for parameter in &self.parameters {
+            let name = match parameter.in_fragment {
+                true => format!("#{}", parameter.name),
+                false => parameter.name.clone(),
+            };
+            let mut line = format!("{:width$}  {}", name, parameter.value);
+            if let Some(meaning) = meaning(&parameter.name, &parameter.value) {
+                line.push_str(&format!("  ({})", meaning));
+            }
+            lines.push(line.trim_end().to_string());
+        }
+        lines.join("\n")
+    }
+
+    /// The step, the endpoint, and the notes, one per line.
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!("Message: {}", self.step.name())];
+        if let Some(endpoint) = &self.endpoint {
+            lines.push(format!("Endpoint: {}", endpoint));
+        }
+        if let Some(scope) = self.value("scope") {
+            lines.push(format!(
+                "Scopes: {}",
+                scope.split_whitespace().collect::<Vec<_>>().join(", ")
+            ));
+        }
+        if !self.notes.is_empty() {
+            lines.push(String::new());
+            lines.extend(self.notes.iter().map(|note| format!("• {}", note)));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Explains values whose meaning is not obvious from the value itself.
+fn meaning(name: &str, value: &str) -> Option<String> {
+    Some(match (name, value) {
+        ("response_type", "code") => "authorization code flow".to_string(),
+        ("response_type", "token") => "implicit flow".to_string(),
+        ("response_type", "id_token" | "id_token token") => {
+            "OpenID Connect implicit flow".to_string()
+        }
+        ("response_type", value) if value.contains("code") && value.contains(' ') => {
+            "OpenID Connect hybrid flow".to_string()
+        }
+        ("code_challenge_method", "S256") => "PKCE with SHA-256".to_string(),
+        ("code_challenge_method", "plain") => "PKCE without hashing".to_string(),
+        ("response_mode", "form_post") => "returned in a POST body".to_string(),
+        ("response_mode", "fragment") => "returned after the #".to_string(),
+        ("response_mode", "query") => "returned in the query string".to_string(),
+        ("expires_in" | "max_age", value) => {
+            let seconds = value.parse::<u64>().ok()?;
+            match seconds {
+                0..60 => format!("{} seconds", seconds),
+                60..3600 => format!("{} minutes", seconds / 60),
+                _ => format!("{:.1} hours", seconds as f64 / 3600.),
+            }
+        }
+        ("id_token" | "access_token" | "login_hint" | "id_token_hint", value)
+            if value.starts_with("eyJ") && value.matches('.').count() == 2 =>
+        {
+            "a JWT".to_string()
+        }
+        _ => return None,
+    })
+}
+
+fn parse_pairs(text: &str, in_fragment: bool) -> Vec<Parameter> {
+    url::form_urlencoded::parse(text.as_bytes())
+        .map(|(name, value)| Parameter {
+            name: name.into_owned(),
+            value: value.into_owned(),
+            in_fragment,
+        })
+        .collect()
+}
+
+/// Breaks an OAuth or OpenID Connect authorization URL, callback URL, or
+/// bare query string into its parameters, or returns `None` if it has none
+/// of the parameters that mark one.
+pub fn inspect(value: &str) -> Option<Inspection> {
+    let value = value.trim();
+    let (endpoint, parameters) = match Url::parse(value) {
+        Ok(url) if url.has_host() => {
+            let mut parameters = parse_pairs(url.query().unwrap_or_default(), false);
+            parameters.extend(parse_pairs(url.fragment().unwrap_or_default(), true));
+            let mut endpoint = url.clone();
+            endpoint.set_query(None);
+            endpoint.set_fragment(None);
+            (Some(endpoint.to_string()), parameters)
+        }
+        _ => {
+            let (query, in_fragment) = match value.strip_prefix('#') {
+                Some(fragment) => (fragment, true),
+                None => (value.trim_start_matches('?'), false),
+            };
+            (None, parse_pairs(query, in_fragment))
+        }
+    };
+    let has = |name: &str| parameters.iter().any(|parameter| parameter.name == name);
+    if !MARKERS.into_iter().any(has) {
+        return None;
+    }
+    let step = if has("error") {
+        Step::ErrorCallback
+    } else if has("response_type") || (has("client_id") && has("redirect_uri")) {
+        Step::AuthorizationRequest
+    } else {
+        Step::Callback
+    };
+    let mut inspection = Inspection {
+        step,
+        endpoint,
+        parameters,
+        notes: Vec::new(),
+    };
+    inspection.notes = notes(&inspection);
+    Some(inspection)
+}
+
+fn notes(inspection: &Inspection) -> Vec<String> {
+    let mut notes = Vec::new();
+    let response_type = inspection.value("response_type").unwrap_or_default();
+    let response_types = response_type.split_whitespace().collect::<Vec<_>>();
+    let scopes = inspection
+        .value("scope")
+        .unwrap_or_default()
+        .split_whitespace()
+        .collect::<Vec<_>>();
+    match inspection.step {
+        Step::AuthorizationRequest => {
+            if inspection.get("state").is_none() {
+                notes.push(
+                    "There is no state, so the callback cannot be tied to this request, which \
+                     leaves it open to cross-site request forgery."
+                        .to_string(),
+                );
+            }
+            if response_types.contains(&"code") && inspection.get("code_challenge").is_none() {
+                notes.push(
+                    "There is no PKCE code_challenge. PKCE is recommended for every client and \
+                     required for public ones."
+                        .to_string(),
+                );
+            }
+            if inspection.value("code_challenge_method") == Some("plain") {
+                notes.push(
+                    "The plain PKCE method protects nothing if the request leaks; use S256."
+                        .to_string(),
+                );
+            }
+            if response_types.contains(&"token") {
+                notes.push(
+                    "The implicit flow returns tokens in the URL and is deprecated; use the \
+                     authorization code flow with PKCE."
+                        .to_string(),
+                );
+            }
+            if response_types.contains(&"id_token") && inspection.get("nonce").is_none() {
+                notes.push(
+                    "OpenID Connect requires a nonce when the ID token comes from the \
+                     authorization endpoint."
+                        .to_string(),
+                );
+            }
+            if scopes.is_empty() && inspection.get("scope").is_none() {
+                notes.push("There is no scope, so the server's default scopes apply.".to_string());
+            } else if response_types.contains(&"id_token") && !scopes.contains(&"openid") {
+                notes.push("An ID token is requested without the openid scope.".to_string());
+            }
+            if let Some(redirect_uri) = inspection.value("redirect_uri")
+                && let Ok(url) = Url::parse(redirect_uri)
+                && url.scheme() == "http"
+                && !matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"))
+            {
+                notes.push(
+                    "The redirect_uri uses plain HTTP, so codes and tokens cross the network \
+                     unencrypted."
+                        .to_string(),
+                );
+            }
+        }
+        Step::Callback => {
+            if inspection.get("state").is_none() {
+                notes.push(
+                    "There is no state to check against the one sent in the request.".to_string(),
+                );
+            }
+            for name in ["access_token", "id_token"] {
+                if inspection
+                    .get(name)
+                    .is_some_and(|parameter| !parameter.in_fragment)
+                {
+                    notes.push(format!(
+                        "The {} is in the query string, where it ends up in server logs and \
+                         Referer headers.",
+                        name
+                    ));
+                }
+            }
+        }
+        Step::ErrorCallback => {
+            if let Some(description) = inspection.value("error_description") {
+                notes.push(format!("The server says: {}", description));
+            }
+        }
+    }
+    notes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inspects_an_authorization_request() {
+        let inspection = inspect(
+            "https://auth.example.com/authorize?response_type=code&client_id=app\
+             &redirect_uri=http%3A%2F%2Fapp.example.com%2Fcb&scope=openid+email\
+             &code_challenge=abc&code_challenge_method=plain",
+        )
+        .unwrap();
+        assert_eq!(inspection.step, Step::AuthorizationRequest);
+        assert_eq!(
+            inspection.table(),
+            "Parameter              Value
+response_type          code  (authorization code flow)
+client_id              app
+redirect_uri           http://app.example.com/cb
+scope                  openid email
+code_challenge         abc
+code_challenge_method  plain  (PKCE without hashing)"
+        );
+        let summary = inspection.summary();
+        assert!(summary.starts_with(
+            "Message: Authorization Request\nEndpoint: https://auth.example.com/authorize\n\
+             Scopes: openid, email\n\n• There is no state"
+        ));
+        assert!(summary.contains("plain PKCE method"));
+        assert!(summary.contains("plain HTTP"));
+    }
+
+    #[test]
+    fn inspects_callbacks() {
+        let inspection = inspect(
+            "https://app.example.com/cb#access_token=xyz&token_type=Bearer&expires_in=3600&state=s",
+        )
+        .unwrap();
+        assert_eq!(inspection.step, Step::Callback);
+        assert!(inspection.notes.is_empty());
+        assert!(
+            inspection
+                .table()
+                .contains("#expires_in    3600  (1.0 hours)")
+        );
+
+        let inspection = inspect("?error=access_denied&error_description=User+declined").unwrap();
+        assert_eq!(inspection.step, Step::ErrorCallback);
+        assert_eq!(inspection.endpoint, None);
+        assert_eq!(inspection.notes, ["The server says: User declined"]);
+
+        assert_eq!(inspect("https://example.com/?q=rust"), None);
+    }
+}
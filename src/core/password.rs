@@ -0,0 +1,221 @@
+use argon2::{
+    Argon2, Params, PasswordHash, PasswordHasher as _, PasswordVerifier as _, Version,
+    password_hash::SaltString,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+const SALT_LENGTH: usize = 16;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Algorithm {
+    #[default]
+    Bcrypt,
+    Argon2id,
+}
+
+impl Algorithm {
+    pub const ALL: [Self; 2] = [Self::Bcrypt, Self::Argon2id];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Bcrypt => "bcrypt",
+            Self::Argon2id => "Argon2id",
+        }
+    }
+}
+
+/// The work factors for hashing. The defaults follow the OWASP password
+/// storage recommendations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Cost {
+    /// bcrypt runs 2^cost rounds of key expansion.
+    pub bcrypt_cost: u32,
+    /// Argon2 memory in KiB.
+    pub memory: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Cost {
+    pub const RECOMMENDED: Self = Self {
+        bcrypt_cost: 10,
+        memory: 19456,
+        iterations: 2,
+        parallelism: 1,
+    };
+}
+
+impl Default for Cost {
+    fn default() -> Self {
+        Self::RECOMMENDED
+    }
+}
+
+/// Hashes the password with a random salt, returning the hash in the usual
+/// `$2b$` or `$argon2id$` string form.
+pub fn hash(
+    password: &str,
+    algorithm: Algorithm,
+    cost: &Cost,
+    rng: &mut impl Rng,
+) -> Result<String, ToolError> {
+    let salt: [u8; SALT_LENGTH] = rng.random();
+    match algorithm {
+        Algorithm::Bcrypt => {
+            if !(4..=31).contains(&cost.bcrypt_cost) {
+                return Err(ToolError::new("Cost must be from 4 to 31").context("Invalid cost"));
+            }
+            let parts = bcrypt::hash_with_salt(password, cost.bcrypt_cost, salt)
+                .map_err(|err| ToolError::new(err.to_string()).context("Hashing failed"))?;
+            Ok(parts.format_for_version(bcrypt::Version::TwoB))
+        }
+        Algorithm::Argon2id => {
+            let params = params(cost.memory, cost.iterations, cost.parallelism)
+                .map_err(|err| err.context("Invalid cost"))?;
+            let salt = SaltString::encode_b64(&salt)
+                .map_err(|err| ToolError::new(err.to_string()).context("Hashing failed"))?;
+            let hash = Argon2::new(argon2::Algorithm::Argon2id, Version::V0x13, params)
+                .hash_password(password.as_bytes(), &salt)
+                .map_err(|err| ToolError::new(err.to_string()).context("Hashing failed"))?;
+            Ok(hash.to_string())
+        }
+    }
+}
+
+/// Checks the password against a bcrypt (`$2a$`, `$2b$`, `$2y$`) or Argon2
+/// (`$argon2id$`, `$argon2i$`, `$argon2d$`) hash.
+pub fn verify(password: &str, hash: &str) -> Result<bool, ToolError> {
+    let hash = hash.trim();
+    let invalid = |message: String| ToolError::new(message).context("Invalid hash");
+    if hash.starts_with("$2") {
+        return bcrypt::verify(password, hash).map_err(|err| invalid(err.to_string()));
+    }
+    if !hash.starts_with("$argon2") {
+        return Err(invalid(
+            "Expected a bcrypt ($2b$) or Argon2 ($argon2id$) hash".to_string(),
+        ));
+    }
+    let hash = PasswordHash::new(hash).map_err(|err| invalid(err.to_string()))?;
+    if hash.version != Some(Version::V0x13.into()) {
+        return Err(invalid("Only version 19 is supported".to_string()));
+    }
+    let stated = Params::try_from(&hash).map_err(|err| invalid(err.to_string()))?;
+    params(stated.m_cost(), stated.t_cost(), stated.p_cost())
+        .map_err(|err| err.context("Invalid hash"))?;
+    match Argon2::default().verify_password(password.as_bytes(), &hash) {
+        Ok(()) => Ok(true),
+        Err(argon2::password_hash::Error::Password) => Ok(false),
+        Err(err) => Err(invalid(err.to_string())),
+    }
+}
+
+/// Checks the Argon2 work factors, turning away more memory than a desktop
+/// has before any is allocated.
+fn params(memory: u32, iterations: u32, parallelism: u32) -> Result<Params, ToolError> {
+    if memory > 4 * 1024 * 1024 {
+        return Err(ToolError::new("Memory must be at most 4 GiB"));
+    }
+    Params::new(memory, iterations, parallelism, None)
+        .map_err(|err| ToolError::new(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    #[test]
+    fn verifies_bcrypt_hashes() {
+        for (password, hash) in [
+            (
+                "U*U",
+                "$2b$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW",
+            ),
+            (
+                "U*U",
+                "$2b$04$abcdefghijklmnopqrstuuCFaEytnzrfaPZJKbS76hh9vqd9r8v2S",
+            ),
+            (
+                "",
+                "$2b$04$abcdefghijklmnopqrstuubyCG3zY1GIXMyxfivm.ClDiInHzxjiq",
+            ),
+            (
+                "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789chars after 72 are ignored",
+                "$2b$04$abcdefghijklmnopqrstuuRAip/W0RPQX4QKkqYqXE3GIXWH518Sm",
+            ),
+            (
+                "pässwörd",
+                "$2y$04$abcdefghijklmnopqrstuuyx2n0Zzopyr9QuYTMCfOJJOj526QVoC",
+            ),
+        ] {
+            assert!(verify(password, hash).unwrap(), "{}", hash);
+        }
+        assert!(
+            !verify(
+                "U*V",
+                "$2b$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn verifies_argon2_hashes() {
+        // The reference implementation's outputs for "password" and "somesalt".
+        for hash in [
+            "$argon2id$v=19$m=256,t=2,p=1$c29tZXNhbHQ$nf65EOgLrQMR/uIPnA4rEsF5h7TKyQwu9U1bMCHGi/4",
+            "$argon2i$v=19$m=256,t=2,p=1$c29tZXNhbHQ$iekCn0Y3spW+sCcFanM2xBT63UP2sghkUoHLIUpWRS8",
+            "$argon2d$v=19$m=256,t=2,p=1$c29tZXNhbHQ$JcTui6RIBUtJ78gE5Hi52CO+H5vS6Z9R1uxAB6OhUB8",
+        ] {
+            assert!(verify("password", hash).unwrap(), "{}", hash);
+            assert!(!verify("passw0rd", hash).unwrap(), "{}", hash);
+        }
+    }
+
+    #[test]
+    fn hashes_and_verifies_round_trip() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let cost = Cost {
+            bcrypt_cost: 4,
+            memory: 64,
+            iterations: 1,
+            parallelism: 2,
+        };
+
+        let hashed = hash("hunter2", Algorithm::Bcrypt, &cost, &mut rng).unwrap();
+        assert!(hashed.starts_with("$2b$04$"));
+        assert_eq!(hashed.len(), 60);
+        assert!(verify("hunter2", &hashed).unwrap());
+        assert!(!verify("hunter3", &hashed).unwrap());
+
+        let hashed = hash("hunter2", Algorithm::Argon2id, &cost, &mut rng).unwrap();
+        assert!(hashed.starts_with("$argon2id$v=19$m=64,t=1,p=2$"));
+        assert!(verify("hunter2", &hashed).unwrap());
+        assert!(!verify("hunter3", &hashed).unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let cost = Cost {
+            bcrypt_cost: 3,
+            ..Cost::default()
+        };
+        assert!(hash("a", Algorithm::Bcrypt, &cost, &mut rng).is_err());
+        let cost = Cost {
+            memory: 4,
+            ..Cost::default()
+        };
+        assert!(hash("a", Algorithm::Argon2id, &cost, &mut rng).is_err());
+
+        assert!(verify("a", "plain").is_err());
+        assert!(verify("a", "$2b$04$short").is_err());
+        assert!(verify("a", "$argon2id$v=16$m=64,t=1,p=1$c29tZXNhbHQ$AAAAAAAA").is_err());
+        assert!(verify("a", "$argon2id$v=19$m=64,t=1,x=1$c29tZXNhbHQ$AAAAAAAA").is_err());
+    }
+}
@@ -0,0 +1,1074 @@
+use std::io::Read;
+
+use crate::ToolError;
+use flate2::read::GzDecoder;
+
+/// The largest field number protobuf allows.
+const MAX_FIELD_NUMBER: u64 = (1 << 29) - 1;
+
+/// How deeply messages are nested before decoding gives up.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Clone, Debug, PartialEq)]
+enum WireValue {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(Vec<u8>),
+    Group(Vec<WireField>),
+    Fixed32(u32),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct WireField {
+    number: u32,
+    value: WireValue,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn error(&self, message: &str) -> ToolError {
+        ToolError::new(message).at_offset(self.offset)
+    }
+
+    fn varint(&mut self) -> Result<u64, ToolError> {
+        let mut value = 0u64;
+        for i in 0..10 {
+            let Some(&byte) = self.bytes.get(self.offset) else {
+                return Err(self.error("message ends inside a varint"));
+            };
+            self.offset += 1;
+            value |= u64::from(byte & 0x7f) << (7 * i);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(self.error("varint is longer than 10 bytes"))
+    }
+
+    fn take(&mut self, length: usize) -> Result<&[u8], ToolError> {
+        if self.bytes.len() - self.offset < length {
+            return Err(self.error(&format!(
+                "field needs {} bytes but only {} are left",
+                length,
+                self.bytes.len() - self.offset
+            )));
+        }
+        let bytes = &self.bytes[self.offset..self.offset + length];
+        self.offset += length;
+        Ok(bytes)
+    }
+
+    /// Reads fields until the end of the bytes, or until the end of the group
+    /// numbered `group`.
+    fn fields(&mut self, group: Option<u32>, depth: usize) -> Result<Vec<WireField>, ToolError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("groups are nested too deeply"));
+        }
+        let mut fields = Vec::new();
+        while self.offset < self.bytes.len() {
+            let start = self.offset;
+            let tag = self.varint()?;
+            let number = tag >> 3;
+            if number == 0 || number > MAX_FIELD_NUMBER {
+                self.offset = start;
+                return Err(self.error(&format!("invalid field number {}", number)));
+            }
+            let number = number as u32;
+            let value = match tag & 7 {
+                0 => WireValue::Varint(self.varint()?),
+                1 => WireValue::Fixed64(u64::from_le_bytes(
+                    self.take(8)?.try_into().unwrap_or_default(),
+                )),
+                2 => {
+                    let length = self.varint()?;
+                    let length = usize::try_from(length).unwrap_or(usize::MAX);
+                    WireValue::Bytes(self.take(length)?.to_vec())
+                }
+                3 => WireValue::Group(self.fields(Some(number), depth + 1)?),
+                4 if group == Some(number) => return Ok(fields),
+                4 => {
+                    self.offset = start;
+                    return Err(self.error(&format!("unexpected end of group {}", number)));
+                }
+                5 => WireValue::Fixed32(u32::from_le_bytes(
+                    self.take(4)?.try_into().unwrap_or_default(),
+                )),
+                wire_type => {
+                    self.offset = start;
+                    return Err(self.error(&format!("invalid wire type {}", wire_type)));
+                }
+            };
+            fields.push(WireField { number, value });
+        }
+        match group {
+            Some(number) => Err(self.error(&format!("group {} is never ended", number))),
+            None => Ok(fields),
+        }
+    }
+}
+
+fn parse_message(bytes: &[u8]) -> Result<Vec<WireField>, ToolError> {
+    Reader { bytes, offset: 0 }.fields(None, 0)
+}
+
+/// Splits gRPC frames, each a compressed flag and a big-endian length, or
+/// returns `None` if the bytes are not exactly a sequence of frames. A
+/// message cannot start with a 0 or 1 byte, as field 0 does not exist, so
+/// frames are never mistaken for a bare message.
+fn split_grpc_frames(mut bytes: &[u8]) -> Option<Vec<(bool, &[u8])>> {
+    let mut frames = Vec::new();
+    while !bytes.is_empty() {
+        let (&flag, rest) = bytes.split_first()?;
+        let length = u32::from_be_bytes(rest.get(..4)?.try_into().ok()?) as usize;
+        let payload = rest.get(4..4 + length)?;
+        if flag > 1 {
+            return None;
+        }
+        frames.push((flag == 1, payload));
+        bytes = &rest[4 + length..];
+    }
+    (!frames.is_empty()).then_some(frames)
+}
+
+/// A message type from a `.proto` file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MessageType {
+    /// The fully qualified name, e.g. `example.Person.Address`.
+    pub name: String,
+    fields: Vec<FieldDefinition>,
+    /// Whether this is the entry type generated for a map field.
+    map_entry: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct FieldDefinition {
+    name: String,
+    number: u32,
+    /// The type as written, resolved later against the message's scope.
+    type_name: String,
+    repeated: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct EnumType {
+    name: String,
+    values: Vec<(String, i64)>,
+}
+
+/// The message and enum types defined in a `.proto` file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Schema {
+    messages: Vec<MessageType>,
+    enums: Vec<EnumType>,
+}
+
+impl Schema {
+    /// The names of the message types that can be decoded, in the order
+    /// they are defined.
+    pub fn message_names(&self) -> Vec<String> {
+        self.messages
+            .iter()
+            .filter(|message| !message.map_entry)
+            .map(|message| message.name.clone())
+            .collect()
+    }
+
+    fn message(&self, name: &str) -> Option<&MessageType> {
+        self.messages.iter().find(|message| message.name == name)
+    }
+
+    fn enum_type(&self, name: &str) -> Option<&EnumType> {
+        self.enums.iter().find(|enum_type| enum_type.name == name)
+    }
+
+    /// Finds the fully qualified name that `type_name` refers to from within
+    /// `scope`, searching the innermost scopes first as protoc does.
+    fn resolve(&self, type_name: &str, scope: &str) -> Option<String> {
+        let exists = |name: &str| self.message(name).is_some() || self.enum_type(name).is_some();
+        if let Some(absolute) = type_name.strip_prefix('.') {
+            return exists(absolute).then(|| absolute.to_string());
+        }
+        let mut scope = scope;
+        loop {
+            let candidate = match scope {
+                "" => type_name.to_string(),
+                scope => format!("{}.{}", scope, type_name),
+            };
+            if exists(&candidate) {
+                return Some(candidate);
+            }
+            if scope.is_empty() {
+                return None;
+            }
+            scope = scope.rsplit_once('.').map_or("", |(parent, _)| parent);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Identifier(String),
+    Number(String),
+    String(String),
+    Symbol(char),
+}
+
+/// Splits a `.proto` file into tokens, each with its line and column.
+fn tokenize(source: &str) -> Result<Vec<(Token, usize, usize)>, ToolError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    let (mut line, mut column) = (1, 1);
+    let advance = |c: char, line: &mut usize, column: &mut usize| {
+        if c == '\n' {
+            *line += 1;
+            *column = 1;
+        } else {
+            *column += 1;
+        }
+    };
+    while let Some(&c) = chars.peek() {
+        let (start_line, start_column) = (line, column);
+        if c.is_whitespace() {
+            chars.next();
+            advance(c, &mut line, &mut column);
+        } else if c == '/' {
+            chars.next();
+            advance(c, &mut line, &mut column);
+            match chars.next() {
+                Some('/') => {
+                    column += 1;
+                    for c in chars.by_ref() {
+                        advance(c, &mut line, &mut column);
+                        if c == '\n' {
+                            break;
+                        }
+                    }
+                }
+                Some('*') => {
+                    column += 1;
+                    let mut previous = ' ';
+                    let mut closed = false;
+                    for c in chars.by_ref() {
+                        advance(c, &mut line, &mut column);
+                        if previous == '*' && c == '/' {
+                            closed = true;
+                            break;
+                        }
+                        previous = c;
+                    }
+                    if !closed {
+                        return Err(ToolError::new("comment is never closed")
+                            .at_line_column(start_line, start_column));
+                    }
+                }
+                _ => {
+                    return Err(
+                        ToolError::new("unexpected '/'").at_line_column(start_line, start_column)
+                    );
+                }
+            }
+        } else if c.is_ascii_alphabetic() || c == '_' || c == '.' {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if !(c.is_ascii_alphanumeric() || c == '_' || c == '.') {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+                advance(c, &mut line, &mut column);
+            }
+            tokens.push((Token::Identifier(word), start_line, start_column));
+        } else if c.is_ascii_digit() || c == '-' || c == '+' {
+            let mut number = String::new();
+            while let Some(&c) = chars.peek() {
+                if !(c.is_ascii_alphanumeric() || c == '-' || c == '+' || c == '.') {
+                    break;
+                }
+                number.push(c);
+                chars.next();
+                advance(c, &mut line, &mut column);
+            }
+            tokens.push((Token::Number(number), start_line, start_column));
+        } else if c == '"' || c == '\'' {
+            let quote = c;
+            chars.next();
+            advance(c, &mut line, &mut column);
+            let mut string = String::new();
+            let mut closed = false;
+            while let Some(c) = chars.next() {
+                advance(c, &mut line, &mut column);
+                if c == quote {
+                    closed = true;
+                    break;
+                }
+                if c == '\\'
+                    && let Some(escaped) = chars.next()
+                {
+                    advance(escaped, &mut line, &mut column);
+                    string.push(escaped);
+                } else {
+                    string.push(c);
+                }
+            }
+            if !closed {
+                return Err(ToolError::new("string is never closed")
+                    .at_line_column(start_line, start_column));
+            }
+            tokens.push((Token::String(string), start_line, start_column));
+        } else {
+            chars.next();
+            advance(c, &mut line, &mut column);
+            tokens.push((Token::Symbol(c), start_line, start_column));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize, usize)>,
+    index: usize,
+    package: String,
+    schema: Schema,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(token, _, _)| token)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self
+            .tokens
+            .get(self.index)
+            .map(|(token, _, _)| token.clone());
+        self.index += 1;
+        token
+    }
+
+    fn error(&self, message: impl Into<String>) -> ToolError {
+        let error = ToolError::new(message);
+        match self
+            .tokens
+            .get(self.index.min(self.tokens.len().saturating_sub(1)))
+        {
+            Some((_, line, column)) => error.at_line_column(*line, *column),
+            None => error,
+        }
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.peek() == Some(&Token::Symbol(symbol))
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), ToolError> {
+        if self.is_symbol(symbol) {
+            self.index += 1;
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{}'", symbol)))
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, ToolError> {
+        match self.peek() {
+            Some(Token::Identifier(name)) => {
+                let name = name.clone();
+                self.index += 1;
+                Ok(name)
+            }
+            _ => Err(self.error("expected a name")),
+        }
+    }
+
+    fn number(&mut self) -> Result<i64, ToolError> {
+        let Some(Token::Number(number)) = self.peek() else {
+            return Err(self.error("expected a number"));
+        };
+        let (negative, digits) = match number.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, number.trim_start_matches('+')),
+        };
+        let value = if let Some(hex) = digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+            i64::from_str_radix(hex, 16)
+        } else if digits.len() > 1 && digits.starts_with('0') {
+            i64::from_str_radix(&digits[1..], 8)
+        } else {
+            digits.parse()
+        }
+        .map_err(|_| self.error(format!("invalid number {}", number)))?;
+        self.index += 1;
+        Ok(if negative { -value } else { value })
+    }
+
+    /// Skips a statement up to its `;`, or a block up to its closing brace.
+    fn skip_statement(&mut self) -> Result<(), ToolError> {
+        let mut depth = 0;
+        while let Some(token) = self.next() {
+            match token {
+                Token::Symbol(';') if depth == 0 => return Ok(()),
+                Token::Symbol('{') => depth += 1,
+                Token::Symbol('}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        // Blocks may be followed by an optional `;`.
+                        if self.is_symbol(';') {
+                            self.index += 1;
+                        }
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Err(self.error("unexpected end of file"))
+    }
+
+    fn qualify(&self, scope: &str, name: &str) -> String {
+        match (scope, self.package.as_str()) {
+            ("", "") => name.to_string(),
+            ("", package) => format!("{}.{}", package, name),
+            (scope, _) => format!("{}.{}", scope, name),
+        }
+    }
+
+    fn file(&mut self) -> Result<(), ToolError> {
+        while let Some(token) = self.peek().cloned() {
+            match token {
+                Token::Identifier(keyword) if keyword == "package" => {
+                    self.index += 1;
+                    self.package = self.identifier()?;
+                    self.expect_symbol(';')?;
+                }
+                Token::Identifier(keyword) if keyword == "message" => {
+                    self.index += 1;
+                    self.message("")?;
+                }
+                Token::Identifier(keyword) if keyword == "enum" => {
+                    self.index += 1;
+                    self.enumeration("")?;
+                }
+                Token::Symbol(';') => self.index += 1,
+                // Syntax, imports, options, services, and extensions do not
+                // affect how messages decode.
+                Token::Identifier(_) => self.skip_statement()?,
+                _ => return Err(self.error("expected a definition")),
+            }
+        }
+        Ok(())
+    }
+
+    fn message(&mut self, scope: &str) -> Result<(), ToolError> {
+        let name = self.identifier()?;
+        let name = self.qualify(scope, &name);
+        self.expect_symbol('{')?;
+        let index = self.schema.messages.len();
+        self.schema.messages.push(MessageType {
+            name: name.clone(),
+            fields: Vec::new(),
+            map_entry: false,
+        });
+        let mut fields = Vec::new();
+        self.message_body(&name, &mut fields)?;
+        self.schema.messages[index].fields = fields;
+        Ok(())
+    }
+
+    /// Reads fields up to the closing brace. `oneof` bodies share this, as
+    /// their fields belong to the enclosing message.
+    fn message_body(
+        &mut self,
+        scope: &str,
+        fields: &mut Vec<FieldDefinition>,
+    ) -> Result<(), ToolError> {
+        loop {
+            let Some(token) = self.peek().cloned() else {
+                return Err(self.error("expected '}'"));
+            };
+            match token {
+                Token::Symbol('}') => {
+                    self.index += 1;
+                    return Ok(());
+                }
+                Token::Symbol(';') => self.index += 1,
+                Token::Identifier(keyword) => match keyword.as_str() {
+                    "message" => {
+                        self.index += 1;
+                        self.message(scope)?;
+                    }
+                    "enum" => {
+                        self.index += 1;
+                        self.enumeration(scope)?;
+                    }
+                    "oneof" => {
+                        self.index += 1;
+                        self.identifier()?;
+                        self.expect_symbol('{')?;
+                        self.message_body(scope, fields)?;
+                    }
+                    "option" | "reserved" | "extensions" | "extend" => self.skip_statement()?,
+                    "map"
+                        if self.tokens.get(self.index + 1).map(|(token, _, _)| token)
+                            == Some(&Token::Symbol('<')) =>
+                    {
+                        self.index += 1;
+                        fields.push(self.map_field(scope)?);
+                    }
+                    _ => fields.push(self.field()?),
+                },
+                _ => return Err(self.error("expected a field")),
+            }
+        }
+    }
+
+    fn field(&mut self) -> Result<FieldDefinition, ToolError> {
+        let mut type_name = self.identifier()?;
+        let mut repeated = false;
+        if matches!(type_name.as_str(), "repeated" | "optional" | "required") {
+            repeated = type_name == "repeated";
+            type_name = self.identifier()?;
+        }
+        if type_name == "group" {
+            return Err(self.error("groups are not supported"));
+        }
+        let name = self.identifier()?;
+        self.expect_symbol('=')?;
+        let number = self.field_number()?;
+        self.field_end()?;
+        Ok(FieldDefinition {
+            name,
+            number,
+            type_name,
+            repeated,
+        })
+    }
+
+    /// Reads `map<K, V> name = N;`, defining the entry message protoc would
+    /// generate for it.
+    fn map_field(&mut self, scope: &str) -> Result<FieldDefinition, ToolError> {
+        self.expect_symbol('<')?;
+        let key = self.identifier()?;
+        self.expect_symbol(',')?;
+        let value = self.identifier()?;
+        self.expect_symbol('>')?;
+        let name = self.identifier()?;
+        self.expect_symbol('=')?;
+        let number = self.field_number()?;
+        self.field_end()?;
+        let entry_name = format!(
+            "{}Entry",
+            name.split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    chars.next().map_or(String::new(), |first| {
+                        first.to_ascii_uppercase().to_string() + chars.as_str()
+                    })
+                })
+                .collect::<String>()
+        );
+        let entry = format!("{}.{}", scope, entry_name);
+        self.schema.messages.push(MessageType {
+            name: entry,
+            fields: vec![
+                FieldDefinition {
+                    name: "key".to_string(),
+                    number: 1,
+                    type_name: key,
+                    repeated: false,
+                },
+                FieldDefinition {
+                    name: "value".to_string(),
+                    number: 2,
+                    type_name: value,
+                    repeated: false,
+                },
+            ],
+            map_entry: true,
+        });
+        Ok(FieldDefinition {
+            name,
+            number,
+            type_name: entry_name,
+            repeated: true,
+        })
+    }
+
+    fn field_number(&mut self) -> Result<u32, ToolError> {
+        let number = self.number()?;
+        u32::try_from(number)
+            .ok()
+            .filter(|&number| number > 0 && u64::from(number) <= MAX_FIELD_NUMBER)
+            .ok_or_else(|| self.error(format!("invalid field number {}", number)))
+    }
+
+    /// Skips any `[...]` field options and the closing `;`.
+    fn field_end(&mut self) -> Result<(), ToolError> {
+        if self.is_symbol('[') {
+            while let Some(token) = self.next() {
+                if token == Token::Symbol(']') {
+                    break;
+                }
+            }
+        }
+        self.expect_symbol(';')
+    }
+
+    fn enumeration(&mut self, scope: &str) -> Result<(), ToolError> {
+        let name = self.identifier()?;
+        let name = self.qualify(scope, &name);
+        self.expect_symbol('{')?;
+        let mut values = Vec::new();
+        loop {
+            match self.peek().cloned() {
+                Some(Token::Symbol('}')) => {
+                    self.index += 1;
+                    break;
+                }
+                Some(Token::Symbol(';')) => self.index += 1,
+                Some(Token::Identifier(keyword))
+                    if matches!(keyword.as_str(), "option" | "reserved") =>
+                {
+                    self.skip_statement()?
+                }
+                Some(Token::Identifier(_)) => {
+                    let value_name = self.identifier()?;
+                    self.expect_symbol('=')?;
+                    let number = self.number()?;
+                    self.field_end()?;
+                    values.push((value_name, number));
+                }
+                _ => return Err(self.error("expected an enum value")),
+            }
+        }
+        self.schema.enums.push(EnumType { name, values });
+        Ok(())
+    }
+}
+
+/// Reads the message and enum definitions in a `.proto` file. Services,
+/// options, and imports are skipped, so types from imported files are
+/// decoded as unknown fields.
+pub fn parse_schema(source: &str) -> Result<Schema, ToolError> {
+    let tokens = tokenize(source).map_err(|err| err.context("Invalid .proto file"))?;
+    let mut parser = Parser {
+        tokens,
+        index: 0,
+        package: String::new(),
+        schema: Schema::default(),
+    };
+    parser
+        .file()
+        .map_err(|err| err.context("Invalid .proto file"))?;
+    Ok(parser.schema)
+}
+
+fn quote_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+fn quote_bytes(bytes: &[u8]) -> String {
+    let mut quoted = String::from("\"");
+    for &byte in bytes {
+        match byte {
+            b'"' => quoted.push_str("\\\""),
+            b'\\' => quoted.push_str("\\\\"),
+            0x20..=0x7e => quoted.push(byte as char),
+            _ => quoted.push_str(&format!("\\x{:02x}", byte)),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Whether bytes look like text rather than a nested message: valid UTF-8
+/// without control characters besides whitespace.
+fn is_text(bytes: &[u8]) -> bool {
+    std::str::from_utf8(bytes).is_ok_and(|text| {
+        text.chars()
+            .all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'))
+    })
+}
+
+struct Printer<'a> {
+    schema: Option<&'a Schema>,
+    lines: Vec<String>,
+}
+
+impl Printer<'_> {
+    fn line(&mut self, depth: usize, text: String) {
+        self.lines.push(format!("{}{}", "  ".repeat(depth), text));
+    }
+
+    /// Prints fields by number and wire type, guessing whether
+    /// length-delimited fields hold text, a message, or bytes.
+    fn raw(&mut self, fields: &[WireField], depth: usize) {
+        for field in fields {
+            let number = field.number;
+            match &field.value {
+                WireValue::Varint(value) => {
+                    let signed = *value as i64;
+                    let text = match signed < 0 {
+                        true => format!("{}: {}  # int64 {}", number, value, signed),
+                        false => format!("{}: {}", number, value),
+                    };
+                    self.line(depth, text.replacen(':', " (varint):", 1));
+                }
+                WireValue::Fixed64(value) => self.line(
+                    depth,
+                    format!(
+                        "{} (i64): 0x{:016x}  # double {}, int {}",
+                        number,
+                        value,
+                        f64::from_bits(*value),
+                        *value as i64
+                    ),
+                ),
+                WireValue::Fixed32(value) => self.line(
+                    depth,
+                    format!(
+                        "{} (i32): 0x{:08x}  # float {}, int {}",
+                        number,
+                        value,
+                        f32::from_bits(*value),
+                        *value as i32
+                    ),
+                ),
+                WireValue::Group(fields) => {
+                    self.line(depth, format!("{} (group) {{", number));
+                    self.raw(fields, depth + 1);
+                    self.line(depth, "}".to_string());
+                }
+                WireValue::Bytes(bytes) => self.raw_bytes(number, bytes, depth),
+            }
+        }
+    }
+
+    fn raw_bytes(&mut self, number: u32, bytes: &[u8], depth: usize) {
+        if !bytes.is_empty()
+            && !is_text(bytes)
+            && depth < MAX_DEPTH
+            && let Ok(fields) = parse_message(bytes)
+        {
+            self.line(depth, format!("{} (len) {{", number));
+            self.raw(&fields, depth + 1);
+            self.line(depth, "}".to_string());
+            return;
+        }
+        match std::str::from_utf8(bytes) {
+            Ok(text) if is_text(bytes) => {
+                self.line(depth, format!("{} (len): {}", number, quote_string(text)))
+            }
+            _ => self.line(depth, format!("{} (len): {}", number, quote_bytes(bytes))),
+        }
+    }
+
+    /// Prints fields by name, decoding them as the schema's types. Fields the
+    /// schema does not know, or whose wire type does not match, are printed
+    /// raw.
+    fn typed(&mut self, fields: &[WireField], message: &MessageType, depth: usize) {
+        let Some(schema) = self.schema else {
+            return self.raw(fields, depth);
+        };
+        for field in fields {
+            let Some(definition) = message
+                .fields
+                .iter()
+                .find(|definition| definition.number == field.number)
+            else {
+                self.raw(std::slice::from_ref(field), depth);
+                continue;
+            };
+            let name = &definition.name;
+            let type_name = definition.type_name.as_str();
+            let resolved = schema.resolve(type_name, &message.name);
+            let printed = match (&field.value, resolved) {
+                (WireValue::Bytes(bytes), Some(resolved))
+                    if schema.message(&resolved).is_some() && depth < MAX_DEPTH =>
+                {
+                    match parse_message(bytes) {
+                        Ok(nested) => {
+                            let nested_type = schema.message(&resolved).cloned();
+                            self.line(depth, format!("{} {{", name));
+                            if let Some(nested_type) = nested_type {
+                                self.typed(&nested, &nested_type, depth + 1);
+                            }
+                            self.line(depth, "}".to_string());
+                            true
+                        }
+                        Err(_) => false,
+                    }
+                }
+                (WireValue::Bytes(bytes), _)
+                    if definition.repeated && is_packable(type_name, schema, &message.name) =>
+                {
+                    match unpack(bytes, type_name) {
+                        Some(values) => {
+                            for value in values {
+                                let text = scalar(&value, type_name, schema, &message.name);
+                                if let Some(text) = text {
+                                    self.line(depth, format!("{}: {}", name, text));
+                                }
+                            }
+                            true
+                        }
+                        None => false,
+                    }
+                }
+                (value, _) => match scalar(value, type_name, schema, &message.name) {
+                    Some(text) => {
+                        self.line(depth, format!("{}: {}", name, text));
+                        true
+                    }
+                    None => false,
+                },
+            };
+            if !printed {
+                self.raw(std::slice::from_ref(field), depth);
+            }
+        }
+    }
+}
+
+/// Whether a repeated field of this type may be packed into one
+/// length-delimited field: any numeric scalar or enum.
+fn is_packable(type_name: &str, schema: &Schema, scope: &str) -> bool {
+    !matches!(type_name, "string" | "bytes")
+        && (scalar_wire_type(type_name).is_some()
+            || schema
+                .resolve(type_name, scope)
+                .is_some_and(|name| schema.enum_type(&name).is_some()))
+}
+
+fn scalar_wire_type(type_name: &str) -> Option<u8> {
+    match type_name {
+        "int32" | "int64" | "uint32" | "uint64" | "sint32" | "sint64" | "bool" => Some(0),
+        "fixed64" | "sfixed64" | "double" => Some(1),
+        "string" | "bytes" => Some(2),
+        "fixed32" | "sfixed32" | "float" => Some(5),
+        _ => None,
+    }
+}
+
+fn unpack(bytes: &[u8], type_name: &str) -> Option<Vec<WireValue>> {
+    let mut reader = Reader { bytes, offset: 0 };
+    let mut values = Vec::new();
+    while reader.offset < bytes.len() {
+        values.push(match scalar_wire_type(type_name).unwrap_or(0) {
+            1 => WireValue::Fixed64(u64::from_le_bytes(reader.take(8).ok()?.try_into().ok()?)),
+            5 => WireValue::Fixed32(u32::from_le_bytes(reader.take(4).ok()?.try_into().ok()?)),
+            _ => WireValue::Varint(reader.varint().ok()?),
+        });
+    }
+    Some(values)
+}
+
+/// Formats a value as a scalar or enum type, or returns `None` if its wire
+/// type does not fit.
+fn scalar(value: &WireValue, type_name: &str, schema: &Schema, scope: &str) -> Option<String> {
+    let zigzag = |n: u64| ((n >> 1) as i64) ^ -((n & 1) as i64);
+    Some(match (value, type_name) {
+        (WireValue::Varint(n), "int32") => (*n as i32).to_string(),
+        (WireValue::Varint(n), "int64") => (*n as i64).to_string(),
+        (WireValue::Varint(n), "uint32") => (*n as u32).to_string(),
+        (WireValue::Varint(n), "uint64") => n.to_string(),
+        (WireValue::Varint(n), "sint32") => (zigzag(*n) as i32).to_string(),
+        (WireValue::Varint(n), "sint64") => zigzag(*n).to_string(),
+        (WireValue::Varint(n), "bool") => (*n != 0).to_string(),
+        (WireValue::Fixed64(n), "fixed64") => n.to_string(),
+        (WireValue::Fixed64(n), "sfixed64") => (*n as i64).to_string(),
+        (WireValue::Fixed64(n), "double") => f64::from_bits(*n).to_string(),
+        (WireValue::Fixed32(n), "fixed32") => n.to_string(),
+        (WireValue::Fixed32(n), "sfixed32") => (*n as i32).to_string(),
+        (WireValue::Fixed32(n), "float") => f32::from_bits(*n).to_string(),
+        (WireValue::Bytes(bytes), "string") => match std::str::from_utf8(bytes) {
+            Ok(text) => quote_string(text),
+            Err(_) => quote_bytes(bytes),
+        },
+        (WireValue::Bytes(bytes), "bytes") => quote_bytes(bytes),
+        (WireValue::Varint(n), type_name) => {
+            let enum_type = schema
+                .resolve(type_name, scope)
+                .and_then(|name| schema.enum_type(&name))?;
+            let number = *n as i32 as i64;
+            enum_type
+                .values
+                .iter()
+                .find(|(_, value)| *value == number)
+                .map_or_else(|| number.to_string(), |(name, _)| name.clone())
+        }
+        _ => return None,
+    })
+}
+
+fn print_message(bytes: &[u8], schema: Option<(&Schema, &str)>) -> Result<String, ToolError> {
+    let fields = parse_message(bytes)?;
+    let mut printer = Printer {
+        schema: schema.map(|(schema, _)| schema),
+        lines: Vec::new(),
+    };
+    match schema.and_then(|(schema, name)| schema.message(name)) {
+        Some(message) => printer.typed(&fields, message, 0),
+        None => printer.raw(&fields, 0),
+    }
+    Ok(printer.lines.join("\n"))
+}
+
+/// Decodes protobuf bytes into a text tree, like `protoc --decode_raw` but
+/// with wire types. With a schema and message type, fields are named and
+/// decoded by their declared types. Bytes framed as gRPC messages are split
+/// into their frames, and gzip-compressed frames are decompressed.
+pub fn decode(bytes: &[u8], schema: Option<(&Schema, &str)>) -> Result<String, ToolError> {
+    let Some(frames) = split_grpc_frames(bytes) else {
+        return print_message(bytes, schema).map_err(|err| err.context("Invalid protobuf"));
+    };
+    let mut blocks = Vec::new();
+    for (i, (compressed, payload)) in frames.into_iter().enumerate() {
+        let context = format!("Invalid gRPC frame {}", i + 1);
+        let payload = match compressed {
+            true => {
+                let mut decompressed = Vec::new();
+                GzDecoder::new(payload)
+                    .read_to_end(&mut decompressed)
+                    .map_err(|err| ToolError::new(err.to_string()).context(&context))?;
+                decompressed
+            }
+            false => payload.to_vec(),
+        };
+        let message = print_message(&payload, schema).map_err(|err| err.context(&context))?;
+        blocks.push(format!(
+            "# gRPC frame {}, {} bytes{}\n{}",
+            i + 1,
+            payload.len(),
+            if compressed { ", gzip" } else { "" },
+            message
+        ));
+    }
+    Ok(blocks.join("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::encoding::{InputEncoding, parse_bytes};
+
+    /// A `Person` with id 150, name "testing", an address, two packed tags,
+    /// and a float score, as `protoc --encode` writes it.
+    const PERSON: &str =
+        "08 96 01 12 07 74 65 73 74 69 6e 67 1a 05 0a 03 4f 73 6c 22 02 01 02 2d 00 00 c0 3f 30 01";
+
+    const PROTO: &str = r#"
+        syntax = "proto3";
+        package example;
+
+        import "google/protobuf/timestamp.proto";
+
+        /* A person. */
+        message Person {
+            int32 id = 1;
+            string name = 2; // Their full name.
+            Address address = 3;
+            repeated int32 tags = 4 [packed = true];
+            float score = 5;
+            Status status = 6;
+            map<string, int64> counts = 7;
+
+            message Address {
+                string city = 1;
+            }
+        }
+
+        enum Status {
+            option allow_alias = true;
+            UNKNOWN = 0;
+            ACTIVE = 1;
+        }
+
+        service People {
+            rpc Get (Person) returns (Person) {}
+        }
+    "#;
+
+    #[test]
+    fn decodes_raw_fields() {
+        let bytes = parse_bytes(PERSON, InputEncoding::Auto).unwrap();
+        assert_eq!(
+            decode(&bytes, None).unwrap(),
+            r#"1 (varint): 150
+2 (len): "testing"
+3 (len) {
+  1 (len): "Osl"
+}
+4 (len): "\x01\x02"
+5 (i32): 0x3fc00000  # float 1.5, int 1069547520
+6 (varint): 1"#
+        );
+    }
+
+    #[test]
+    fn decodes_with_a_schema() {
+        let schema = parse_schema(PROTO).unwrap();
+        assert_eq!(
+            schema.message_names(),
+            ["example.Person", "example.Person.Address"]
+        );
+        let mut bytes = parse_bytes(PERSON, InputEncoding::Hex).unwrap();
+        // counts {"a": 2}, and field 9, which the schema does not know.
+        bytes.extend([0x3a, 0x05, 0x0a, 0x01, b'a', 0x10, 0x02, 0x48, 0x7f]);
+        assert_eq!(
+            decode(&bytes, Some((&schema, "example.Person"))).unwrap(),
+            r#"id: 150
+name: "testing"
+address {
+  city: "Osl"
+}
+tags: 1
+tags: 2
+score: 1.5
+status: ACTIVE
+counts {
+  key: "a"
+  value: 2
+}
+9 (varint): 127"#
+        );
+    }
+
+    #[test]
+    fn splits_grpc_frames() {
+        let mut bytes = vec![0, 0, 0, 0, 2, 0x08, 0x01];
+        bytes.extend([0, 0, 0, 0, 3, 0x08, 0xff, 0x01]);
+        assert_eq!(
+            decode(&bytes, None).unwrap(),
+            "# gRPC frame 1, 2 bytes\n1 (varint): 1\n\n# gRPC frame 2, 3 bytes\n1 (varint): 255"
+        );
+    }
+
+    #[test]
+    fn reads_base64_and_groups() {
+        // Field 1 as a group holding field 2 = 1, in URL-safe Base64
+        // without padding.
+        let bytes = parse_bytes("CxABDA", InputEncoding::Auto).unwrap();
+        assert_eq!(bytes, [0x0b, 0x10, 0x01, 0x0c]);
+        assert_eq!(
+            decode(&bytes, None).unwrap(),
+            "1 (group) {\n  2 (varint): 1\n}"
+        );
+    }
+
+    #[test]
+    fn reports_where_decoding_failed() {
+        let err = decode(&[0x08, 0x96, 0x01, 0x12, 0x05, 0x61], None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid protobuf: field needs 5 bytes but only 1 are left (at character 6)"
+        );
+        let err = parse_schema("message A {\n  int32 a = 0;\n}").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid .proto file: invalid field number 0 (line 2, column 14)"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use std::ops::Range;
+
+use regex::RegexBuilder;
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+/// How the pattern is matched against the text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Options {
+    /// Treats the pattern as a regular expression, and expands `$1` and
+    /// `${name}` in the replacement to the captured groups.
+    pub regex: bool,
+    pub ignore_case: bool,
+}
+
+/// A match in the text and what it is to be replaced with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Replacement {
+    pub range: Range<usize>,
+    pub with: String,
+}
+
+/// Finds every match of `pattern` in `text`, in order, along with its
+/// replacement.
+pub fn find(
+    text: &str,
+    pattern: &str,
+    replacement: &str,
+    options: Options,
+) -> Result<Vec<Replacement>, ToolError> {
+    if pattern.is_empty() {
+        return Ok(Vec::new());
+    }
+    let source = if options.regex {
+        pattern.to_string()
+    } else {
+        regex::escape(pattern)
+    };
+    let regex = RegexBuilder::new(&source)
+        .case_insensitive(options.ignore_case)
+        .build()
+        .map_err(|err| ToolError::new(err.to_string()).context("Invalid regular expression"))?;
+    Ok(regex
+        .captures_iter(text)
+        .map(|captures| {
+            let mut with = String::new();
+            if options.regex {
+                captures.expand(replacement, &mut with);
+            } else {
+                with.push_str(replacement);
+            }
+            Replacement {
+                range: captures.get_match().range(),
+                with,
+            }
+        })
+        .collect())
+}
+
+/// Replaces the matches found by `find` in `text`.
+pub fn apply(text: &str, replacements: &[Replacement]) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+    for replacement in replacements {
+        result.push_str(&text[last..replacement.range.start]);
+        result.push_str(&replacement.with);
+        last = replacement.range.end;
+    }
+    result.push_str(&text[last..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replace(text: &str, pattern: &str, replacement: &str, options: Options) -> String {
+        apply(text, &find(text, pattern, replacement, options).unwrap())
+    }
+
+    #[test]
+    fn replaces_literally() {
+        let options = Options::default();
+        assert_eq!(replace("a.b.c", ".", "$1", options), "a$1b$1c");
+        assert_eq!(replace("Cat cat", "cat", "dog", options), "Cat dog");
+        assert_eq!(
+            replace(
+                "Cat cat",
+                "cat",
+                "dog",
+                Options {
+                    ignore_case: true,
+                    ..options
+                }
+            ),
+            "dog dog"
+        );
+        assert_eq!(replace("abc", "", "x", options), "abc");
+    }
+
+    #[test]
+    fn substitutes_capture_groups() {
+        let options = Options {
+            regex: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            replace("2024-01-31", r"(\d+)-(\d+)-(\d+)", "$3/$2/$1", options),
+            "31/01/2024"
+        );
+        assert_eq!(
+            replace(
+                "John Smith",
+                r"(?<first>\w+) (?<last>\w+)",
+                "${last}, $first",
+                options
+            ),
+            "Smith, John"
+        );
+    }
+
+    #[test]
+    fn finds_match_ranges() {
+        let replacements = find("one two one", "one", "1", Options::default()).unwrap();
+        assert_eq!(
+            replacements
+                .iter()
+                .map(|replacement| replacement.range.clone())
+                .collect::<Vec<_>>(),
+            vec![0..3, 8..11]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        let options = Options {
+            regex: true,
+            ..Default::default()
+        };
+        assert!(find("abc", "(", "", options).is_err());
+        // Literal patterns are escaped, so the same pattern is fine.
+        assert!(find("abc", "(", "", Options::default()).is_ok());
+    }
+}
@@ -0,0 +1,357 @@
+use std::io::Read;
+
+use chrono::{DateTime, Utc};
+use flate2::read::DeflateDecoder;
+use percent_encoding::percent_decode_str;
+use roxmltree::{Document, Node, NodeType};
+
+use crate::ToolError;
+
+/// A SAML message laid out for reading.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inspection {
+    /// The XML, indented with its tags as they were sent.
+    pub formatted: String,
+    /// The message's issuer, status, and assertions, one per line.
+    pub summary: String,
+}
+
+/// Decodes a `SAMLRequest` or `SAMLResponse` to its XML. Takes the Base64
+/// value on its own, a POST body, or a whole redirect URL, and inflates the
+/// XML if it was deflated for the redirect binding.
+pub fn decode(value: &str) -> Result<String, ToolError> {
+    let mut value = value.trim().to_string();
+
+    // Accept a whole redirect URL or query string and pick out the message.
+    for key in ["SAMLRequest=", "SAMLResponse="] {
+        if let Some(start) = value.find(key) {
+            let rest = &value[start + key.len()..];
+            value = rest.split('&').next().unwrap_or_default().to_string();
+            break;
+        }
+    }
+    if value.contains('%') {
+        value = percent_decode_str(&value)
+            .decode_utf8()
+            .map_err(|err| ToolError::from(err).context("Invalid URL encoding"))?
+            .to_string();
+    }
+    value.retain(|c| !c.is_whitespace());
+
+    let bytes = super::base64::decode_bytes(&value)?;
+
+    // The POST binding carries plain XML, while the redirect binding
+    // deflates it first.
+    if bytes.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'<') {
+        return String::from_utf8(bytes)
+            .map_err(|err| ToolError::from(err).context("Invalid SAML message"));
+    }
+    let mut xml = String::new();
+    DeflateDecoder::new(bytes.as_slice())
+        .read_to_string(&mut xml)
+        .map_err(|err| {
+            ToolError::new(format!("not XML and could not be inflated: {}", err))
+                .context("Invalid SAML message")
+        })?;
+    Ok(xml)
+}
+
+/// Lays out decoded XML for reading, judging validity periods against `now`.
+pub fn inspect(xml: &str, now: DateTime<Utc>) -> Result<Inspection, ToolError> {
+    let document =
+        Document::parse(xml).map_err(|err| ToolError::from(err).context("Invalid XML"))?;
+    Ok(Inspection {
+        formatted: pretty_print(&document, xml),
+        summary: summarize(&document, now),
+    })
+}
+
+fn pretty_print(document: &Document, source: &str) -> String {
+    let mut out = String::new();
+    for child in document.root().children() {
+        print_node(child, source, 0, &mut out);
+    }
+    out.trim_end().to_string()
+}
+
+fn print_node(node: Node, source: &str, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    match node.node_type() {
+        NodeType::Element => {
+            // Tags are copied from the source so that prefixes and namespace
+            // declarations appear exactly as they were sent.
+            let range = node.range();
+            let children: Vec<Node> = node
+                .children()
+                .filter(|child| !child.is_text() || !child.text().unwrap_or("").trim().is_empty())
+                .collect();
+            let (Some(first), Some(last)) = (node.first_child(), node.last_child()) else {
+                out.push_str(&format!("{}{}\n", indent, &source[range]));
+                return;
+            };
+            let start_tag = &source[range.start..first.range().start];
+            let end_tag = &source[last.range().end..range.end];
+            if let [only] = children.as_slice()
+                && only.is_text()
+            {
+                out.push_str(&format!(
+                    "{}{}{}{}\n",
+                    indent,
+                    start_tag,
+                    source[only.range()].trim(),
+                    end_tag
+                ));
+                return;
+            }
+            out.push_str(&format!("{}{}\n", indent, start_tag));
+            for child in children {
+                print_node(child, source, depth + 1, out);
+            }
+            out.push_str(&format!("{}{}\n", indent, end_tag));
+        }
+        NodeType::Text => {
+            let text = source[node.range()].trim();
+            if !text.is_empty() {
+                out.push_str(&format!("{}{}\n", indent, text));
+            }
+        }
+        _ => out.push_str(&format!("{}{}\n", indent, &source[node.range()])),
+    }
+}
+
+fn summarize(document: &Document, now: DateTime<Utc>) -> String {
+    let root = document.root_element();
+    let mut lines = vec![format!("Message: {}", root.tag_name().name())];
+
+    let mut field = |label: &str, value: Option<&str>| {
+        if let Some(value) = value {
+            lines.push(format!("{}: {}", label, value));
+        }
+    };
+    field("ID", root.attribute("ID"));
+    field("Issue Instant", root.attribute("IssueInstant"));
+    field("Destination", root.attribute("Destination"));
+    field(
+        "Issuer",
+        root.children()
+            .find(|n| is_named(n, "Issuer"))
+            .and_then(|n| n.text()),
+    );
+    field(
+        "Status",
+        find(root, "StatusCode").and_then(|n| n.attribute("Value")),
+    );
+
+    for assertion in root.descendants().filter(|n| is_named(n, "Assertion")) {
+        lines.push(String::new());
+        lines.push(format!(
+            "Assertion {}",
+            assertion.attribute("ID").unwrap_or_default()
+        ));
+        if let Some(issuer) = find(assertion, "Issuer").and_then(|n| n.text()) {
+            lines.push(format!("  Issuer: {}", issuer));
+        }
+        if let Some(name_id) = find(assertion, "NameID") {
+            lines.push(format!(
+                "  Subject: {} ({})",
+                name_id.text().unwrap_or_default(),
+                name_id.attribute("Format").unwrap_or("unspecified")
+            ));
+        }
+        if let Some(conditions) = find(assertion, "Conditions") {
+            lines.push(format!(
+                "  Conditions: {}",
+                validity(
+                    conditions.attribute("NotBefore"),
+                    conditions.attribute("NotOnOrAfter"),
+                    now
+                )
+            ));
+        }
+        for audience in assertion.descendants().filter(|n| is_named(n, "Audience")) {
+            lines.push(format!(
+                "  Audience: {}",
+                audience.text().unwrap_or_default()
+            ));
+        }
+        if let Some(confirmation) = find(assertion, "SubjectConfirmationData") {
+            lines.push(format!(
+                "  Subject Confirmation: {}",
+                validity(
+                    confirmation.attribute("NotBefore"),
+                    confirmation.attribute("NotOnOrAfter"),
+                    now
+                )
+            ));
+            if let Some(recipient) = confirmation.attribute("Recipient") {
+                lines.push(format!("  Recipient: {}", recipient));
+            }
+        }
+        if let Some(authn) = find(assertion, "AuthnStatement") {
+            if let Some(instant) = authn.attribute("AuthnInstant") {
+                lines.push(format!("  Authenticated: {}", instant));
+            }
+            if let Some(session) = authn.attribute("SessionNotOnOrAfter") {
+                lines.push(format!("  Session: {}", validity(None, Some(session), now)));
+            }
+        }
+        for attribute in assertion.descendants().filter(|n| is_named(n, "Attribute")) {
+            let values: Vec<&str> = attribute
+                .children()
+                .filter(|n| is_named(n, "AttributeValue"))
+                .filter_map(|n| n.text())
+                .collect();
+            lines.push(format!(
+                "  Attribute {}: {}",
+                attribute.attribute("Name").unwrap_or_default(),
+                values.join(", ")
+            ));
+        }
+    }
+
+    if root
+        .descendants()
+        .any(|n| is_named(&n, "EncryptedAssertion"))
+    {
+        lines.push(String::new());
+        lines.push("Encrypted assertion present; its contents cannot be shown.".to_string());
+    }
+
+    lines.join("\n")
+}
+
+fn find<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.descendants().find(|n| is_named(n, name))
+}
+
+fn validity(not_before: Option<&str>, not_on_or_after: Option<&str>, now: DateTime<Utc>) -> String {
+    let parse =
+        |value: Option<&str>| value.and_then(|value| DateTime::parse_from_rfc3339(value).ok());
+    let state = match (parse(not_before), parse(not_on_or_after)) {
+        (Some(start), _) if now < start => "not yet valid",
+        (_, Some(end)) if now >= end => "expired",
+        _ => "valid now",
+    };
+    format!(
+        "{} → {} ({})",
+        not_before.unwrap_or("…"),
+        not_on_or_after.unwrap_or("…"),
+        state
+    )
+}
+
+fn is_named(node: &Node, name: &str) -> bool {
+    // SAML documents use several prefixes for the same namespaces, so only
+    // the local name is compared.
+    node.is_element() && node.tag_name().name() == name
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::core::{
+        base64::encode_bytes,
+        compression::{self, Format, Level},
+    };
+
+    const REQUEST: &str = r#"<samlp:AuthnRequest xmlns:samlp="urn:oasis:names:tc:SAML:2.0:protocol" xmlns:saml="urn:oasis:names:tc:SAML:2.0:assertion" ID="_r1" IssueInstant="2026-01-01T00:00:00Z" Destination="https://idp.example.com/sso"><saml:Issuer>https://sp.example.com</saml:Issuer></samlp:AuthnRequest>"#;
+
+    const RESPONSE: &str = r#"<samlp:Response xmlns:samlp="urn:oasis:names:tc:SAML:2.0:protocol" xmlns:saml="urn:oasis:names:tc:SAML:2.0:assertion" ID="_s1">
+  <saml:Issuer>https://idp.example.com</saml:Issuer>
+  <samlp:Status><samlp:StatusCode Value="urn:oasis:names:tc:SAML:2.0:status:Success"/></samlp:Status>
+  <saml:Assertion ID="_a1">
+    <saml:Subject><saml:NameID Format="urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress">ada@example.com</saml:NameID></saml:Subject>
+    <saml:Conditions NotBefore="2026-01-01T00:00:00Z" NotOnOrAfter="2026-01-01T01:00:00Z"/>
+    <saml:AttributeStatement>
+      <saml:Attribute Name="role"><saml:AttributeValue>admin</saml:AttributeValue><saml:AttributeValue>dev</saml:AttributeValue></saml:Attribute>
+    </saml:AttributeStatement>
+  </saml:Assertion>
+</samlp:Response>"#;
+
+    #[test]
+    fn decodes_deflated_redirect_messages() {
+        let deflated = compression::compress(REQUEST.as_bytes(), Format::Deflate, Level::Default);
+        let encoded = encode_bytes(&deflated)
+            .replace('+', "%2B")
+            .replace('/', "%2F")
+            .replace('=', "%3D");
+        let url = format!(
+            "https://idp.example.com/sso?SAMLRequest={}&RelayState=x",
+            encoded
+        );
+        assert_eq!(decode(&url).unwrap(), REQUEST);
+    }
+
+    #[test]
+    fn decodes_plain_post_messages() {
+        let encoded = encode_bytes(RESPONSE.as_bytes());
+        assert_eq!(decode(&encoded).unwrap(), RESPONSE);
+        assert_eq!(
+            decode(&format!("SAMLResponse={}", encoded)).unwrap(),
+            RESPONSE
+        );
+    }
+
+    #[test]
+    fn reports_undecodable_messages() {
+        assert!(
+            decode("not base64!")
+                .unwrap_err()
+                .to_string()
+                .starts_with("Invalid Base64")
+        );
+        assert!(
+            decode(&encode_bytes(b"plain text"))
+                .unwrap_err()
+                .to_string()
+                .starts_with("Invalid SAML message: not XML and could not be inflated")
+        );
+        assert!(
+            inspect("<a>", Utc::now())
+                .unwrap_err()
+                .to_string()
+                .starts_with("Invalid XML")
+        );
+    }
+
+    #[test]
+    fn summarizes_messages() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 30, 0).unwrap();
+        let inspection = inspect(REQUEST, now).unwrap();
+        assert_eq!(
+            inspection.formatted,
+            r#"<samlp:AuthnRequest xmlns:samlp="urn:oasis:names:tc:SAML:2.0:protocol" xmlns:saml="urn:oasis:names:tc:SAML:2.0:assertion" ID="_r1" IssueInstant="2026-01-01T00:00:00Z" Destination="https://idp.example.com/sso">
+  <saml:Issuer>https://sp.example.com</saml:Issuer>
+</samlp:AuthnRequest>"#
+        );
+        assert_eq!(
+            inspection.summary,
+            "Message: AuthnRequest
+ID: _r1
+Issue Instant: 2026-01-01T00:00:00Z
+Destination: https://idp.example.com/sso
+Issuer: https://sp.example.com"
+        );
+
+        let summary = inspect(RESPONSE, now).unwrap().summary;
+        assert!(summary.contains("Status: urn:oasis:names:tc:SAML:2.0:status:Success"));
+        assert!(summary.contains(
+            "  Subject: ada@example.com (urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress)"
+        ));
+        assert!(
+            summary
+                .contains("  Conditions: 2026-01-01T00:00:00Z → 2026-01-01T01:00:00Z (valid now)")
+        );
+        assert!(summary.contains("  Attribute role: admin, dev"));
+
+        let later = Utc.with_ymd_and_hms(2026, 1, 1, 2, 0, 0).unwrap();
+        assert!(
+            inspect(RESPONSE, later)
+                .unwrap()
+                .summary
+                .contains("(expired)")
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use crate::core::{color::Color, css_unit::format_amount};
+
+/// A CSS `box-shadow`, with lengths in pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoxShadow {
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub blur: f32,
+    pub spread: f32,
+    pub color: Color,
+}
+
+/// Writes a length in pixels, leaving zero without a unit as CSS allows.
+fn length(px: f32) -> String {
+    match format_amount(px as f64).as_str() {
+        "0" => "0".to_string(),
+        amount => format!("{}px", amount),
+    }
+}
+
+impl BoxShadow {
+    /// The value of the `box-shadow` property.
+    pub fn to_css(&self) -> String {
+        format!(
+            "{} {} {} {} {}",
+            length(self.offset_x),
+            length(self.offset_y),
+            length(self.blur),
+            length(self.spread),
+            self.color.to_rgb_string()
+        )
+    }
+}
+
+/// The `box-shadow` and `border-radius` declarations for a box.
+pub fn declarations(shadow: &BoxShadow, radius: f32) -> Vec<(&'static str, String)> {
+    vec![
+        ("box-shadow", shadow.to_css()),
+        ("border-radius", length(radius)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_box_shadows() {
+        let shadow = BoxShadow {
+            offset_x: 0.,
+            offset_y: 8.,
+            blur: 24.5,
+            spread: -4.,
+            color: Color {
+                a: 0.25,
+                ..Color::from_rgb(0x000000)
+            },
+        };
+        assert_eq!(
+            declarations(&shadow, 12.),
+            [
+                (
+                    "box-shadow",
+                    "0 8px 24.5px -4px rgba(0, 0, 0, 0.25)".to_string()
+                ),
+                ("border-radius", "12px".to_string()),
+            ]
+        );
+    }
+}
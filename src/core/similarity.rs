@@ -0,0 +1,214 @@
+/// The most cells of the edit distance table that are kept to list the edit
+/// operations. Longer strings only get their distance, which needs two rows.
+const MAX_EDIT_CELLS: usize = 1_000_000;
+
+/// A step in turning one string into another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditOp {
+    Keep(char),
+    Substitute(char, char),
+    Delete(char),
+    Insert(char),
+}
+
+/// The Levenshtein distance between `a` and `b`: the fewest single-character
+/// insertions, deletions, and substitutions that turn one into the other.
+pub fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// The steps of a shortest edit from `a` to `b`, or `None` if the strings
+/// are too long to work them out.
+pub fn edit_ops(a: &[char], b: &[char]) -> Option<Vec<EditOp>> {
+    let (n, m) = (a.len(), b.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_EDIT_CELLS {
+        return None;
+    }
+    let mut dist = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    dist[0] = (0..=m).collect();
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            dist[i][j] = (dist[i - 1][j - 1] + cost)
+                .min(dist[i - 1][j] + 1)
+                .min(dist[i][j - 1] + 1);
+        }
+    }
+
+    // Walk back from the bottom-right corner, preferring diagonal moves so
+    // that substitutions are reported instead of delete/insert pairs.
+    let mut ops = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            if dist[i][j] == dist[i - 1][j - 1] + cost {
+                ops.push(if cost == 0 {
+                    EditOp::Keep(a[i - 1])
+                } else {
+                    EditOp::Substitute(a[i - 1], b[j - 1])
+                });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if i > 0 && dist[i][j] == dist[i - 1][j] + 1 {
+            ops.push(EditOp::Delete(a[i - 1]));
+            i -= 1;
+        } else {
+            ops.push(EditOp::Insert(b[j - 1]));
+            j -= 1;
+        }
+    }
+    ops.reverse();
+    Some(ops)
+}
+
+/// How alike two strings are as a percentage, from their edit distance and
+/// lengths. Two empty strings are identical.
+pub fn similarity(distance: usize, a_len: usize, b_len: usize) -> f64 {
+    let longest = a_len.max(b_len);
+    if longest == 0 {
+        100.
+    } else {
+        (1. - distance as f64 / longest as f64) * 100.
+    }
+}
+
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *ca {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.;
+    }
+
+    let a_seq = a
+        .iter()
+        .zip(&a_matched)
+        .filter(|(_, m)| **m)
+        .map(|(c, _)| c);
+    let b_seq = b
+        .iter()
+        .zip(&b_matched)
+        .filter(|(_, m)| **m)
+        .map(|(c, _)| c);
+    let transpositions = a_seq.zip(b_seq).filter(|(x, y)| x != y).count() / 2;
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.
+}
+
+/// The Jaro-Winkler similarity of `a` and `b`, from 0 for nothing in common
+/// to 1 for identical, favouring strings that share a prefix.
+pub fn jaro_winkler(a: &[char], b: &[char]) -> f64 {
+    let jaro = jaro(a, b);
+    let prefix = a
+        .iter()
+        .zip(b.iter())
+        .take(4)
+        .take_while(|(x, y)| x == y)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1. - jaro)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn measures_levenshtein_distance() {
+        assert_eq!(levenshtein(&chars("kitten"), &chars("sitting")), 3);
+        assert_eq!(levenshtein(&chars("flaw"), &chars("lawn")), 2);
+        assert_eq!(levenshtein(&chars("same"), &chars("same")), 0);
+        assert_eq!(levenshtein(&chars(""), &chars("abc")), 3);
+        assert_eq!(levenshtein(&chars("abc"), &chars("")), 3);
+        assert_eq!(levenshtein(&chars(""), &chars("")), 0);
+    }
+
+    #[test]
+    fn lists_edit_operations() {
+        assert_eq!(
+            edit_ops(&chars("cat"), &chars("cut!")),
+            Some(vec![
+                EditOp::Keep('c'),
+                EditOp::Substitute('a', 'u'),
+                EditOp::Keep('t'),
+                EditOp::Insert('!'),
+            ])
+        );
+        assert_eq!(
+            edit_ops(&chars("ab"), &chars("")),
+            Some(vec![EditOp::Delete('a'), EditOp::Delete('b')])
+        );
+    }
+
+    #[test]
+    fn skips_edit_operations_for_long_strings() {
+        let long = vec!['a'; 2_000];
+        assert_eq!(edit_ops(&long, &long), None);
+        assert_eq!(levenshtein(&long, &long), 0);
+    }
+
+    #[test]
+    fn measures_jaro_winkler_similarity() {
+        let close = |a: &str, b: &str, expected: f64| {
+            let actual = jaro_winkler(&chars(a), &chars(b));
+            assert!((actual - expected).abs() < 0.001, "{a}/{b}: {actual}");
+        };
+        close("MARTHA", "MARHTA", 0.961);
+        close("DIXON", "DICKSONX", 0.813);
+        close("DWAYNE", "DUANE", 0.840);
+        close("", "", 1.);
+        close("abc", "", 0.);
+        close("abc", "xyz", 0.);
+    }
+
+    #[test]
+    fn measures_similarity_percentage() {
+        assert_eq!(similarity(0, 0, 0), 100.);
+        assert_eq!(similarity(3, 3, 0), 0.);
+        assert_eq!(similarity(1, 4, 4), 75.);
+        assert!((similarity(3, 6, 7) - 57.142).abs() < 0.001);
+    }
+}
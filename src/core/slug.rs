@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+/// What joins the words of a slug.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Separator {
+    #[default]
+    Hyphen,
+    Underscore,
+    Dot,
+}
+
+impl Separator {
+    pub const ALL: [Self; 3] = [Self::Hyphen, Self::Underscore, Self::Dot];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Hyphen => "Hyphen (-)",
+            Self::Underscore => "Underscore (_)",
+            Self::Dot => "Dot (.)",
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hyphen => "-",
+            Self::Underscore => "_",
+            Self::Dot => ".",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Options {
+    pub separator: Separator,
+    /// The most characters a slug may have. Whole words are dropped from the
+    /// end to fit, so slugs are only cut mid-word when the first word is too
+    /// long by itself.
+    pub max_length: Option<usize>,
+    pub lowercase: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            separator: Separator::default(),
+            max_length: None,
+            lowercase: true,
+        }
+    }
+}
+
+/// Turns a title into a URL slug, transliterating other scripts and accented
+/// letters into ASCII.
+pub fn slugify(title: &str, options: &Options) -> String {
+    let ascii = deunicode::deunicode(title)
+        .replace('&', " and ")
+        // Apostrophes join the parts of a word, as in "don't".
+        .replace('\'', "");
+    let words = ascii
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| match options.lowercase {
+            true => word.to_ascii_lowercase(),
+            false => word.to_string(),
+        });
+
+    let separator = options.separator.as_str();
+    let mut slug = String::new();
+    for word in words {
+        let length = match slug.is_empty() {
+            true => word.len(),
+            false => slug.len() + separator.len() + word.len(),
+        };
+        match options.max_length {
+            Some(max_length) if length > max_length => {
+                if slug.is_empty() {
+                    slug = word[..max_length].to_string();
+                }
+                break;
+            }
+            _ => {}
+        }
+        if !slug.is_empty() {
+            slug.push_str(separator);
+        }
+        slug.push_str(&word);
+    }
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugifies_titles() {
+        let options = Options::default();
+        assert_eq!(
+            slugify("  Crème Brûlée & Straße: Don't Panic!  ", &options),
+            "creme-brulee-and-strasse-dont-panic"
+        );
+        assert_eq!(slugify("Привет, мир", &options), "privet-mir");
+        assert_eq!(slugify("¡¿?!", &options), "");
+        assert_eq!(
+            slugify(
+                "Hello World Again",
+                &Options {
+                    separator: Separator::Underscore,
+                    lowercase: false,
+                    ..options
+                }
+            ),
+            "Hello_World_Again"
+        );
+    }
+
+    #[test]
+    fn keeps_whole_words_within_the_max_length() {
+        let options = Options {
+            max_length: Some(12),
+            ..Options::default()
+        };
+        assert_eq!(slugify("The quick brown fox", &options), "the-quick");
+        assert_eq!(slugify("Supercalifragilistic", &options), "supercalifra");
+    }
+}
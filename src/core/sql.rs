@@ -0,0 +1,486 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+const KEYWORDS: &[&str] = &[
+    "ADD",
+    "ALL",
+    "ALTER",
+    "AND",
+    "ANY",
+    "AS",
+    "ASC",
+    "BEGIN",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "COMMIT",
+    "CONFLICT",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "DROP",
+    "ELSE",
+    "END",
+    "ESCAPE",
+    "EXCEPT",
+    "EXISTS",
+    "FALSE",
+    "FETCH",
+    "FILTER",
+    "FIRST",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "IF",
+    "ILIKE",
+    "IN",
+    "INDEX",
+    "INNER",
+    "INSERT",
+    "INTERSECT",
+    "INTERVAL",
+    "INTO",
+    "IS",
+    "JOIN",
+    "KEY",
+    "LAST",
+    "LATERAL",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NATURAL",
+    "NEXT",
+    "NOT",
+    "NOTHING",
+    "NULL",
+    "NULLS",
+    "OFFSET",
+    "ON",
+    "ONLY",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVER",
+    "PARTITION",
+    "PRIMARY",
+    "RECURSIVE",
+    "REFERENCES",
+    "RENAME",
+    "REPLACE",
+    "RETURNING",
+    "REVOKE",
+    "RIGHT",
+    "ROLLBACK",
+    "ROW",
+    "ROWS",
+    "SELECT",
+    "SET",
+    "SOME",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRANSACTION",
+    "TRUE",
+    "TRUNCATE",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "VIEW",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+    "WITHIN",
+];
+
+/// Keywords that start a clause. They go on a line of their own, with the
+/// rest of the clause indented beneath them.
+const CLAUSES: &[&[&str]] = &[
+    &["SELECT", "DISTINCT"],
+    &["SELECT"],
+    &["FROM"],
+    &["WHERE"],
+    &["GROUP", "BY"],
+    &["ORDER", "BY"],
+    &["HAVING"],
+    &["LIMIT"],
+    &["OFFSET"],
+    &["WINDOW"],
+    &["INSERT", "INTO"],
+    &["VALUES"],
+    &["UPDATE"],
+    &["SET"],
+    &["DELETE", "FROM"],
+    &["RETURNING"],
+    &["WITH", "RECURSIVE"],
+    &["WITH"],
+];
+
+/// Keywords that join queries. They go on a line of their own between them.
+const SET_OPERATIONS: &[&[&str]] = &[&["UNION", "ALL"], &["UNION"], &["INTERSECT"], &["EXCEPT"]];
+
+/// Keywords that start a new line within a clause.
+const JOINS: &[&[&str]] = &[
+    &["LEFT", "OUTER", "JOIN"],
+    &["RIGHT", "OUTER", "JOIN"],
+    &["FULL", "OUTER", "JOIN"],
+    &["INNER", "JOIN"],
+    &["LEFT", "JOIN"],
+    &["RIGHT", "JOIN"],
+    &["FULL", "JOIN"],
+    &["CROSS", "JOIN"],
+    &["NATURAL", "JOIN"],
+    &["JOIN"],
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum KeywordCase {
+    Upper,
+    Lower,
+    Preserve,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Word(&'a str),
+    /// String literals and quoted identifiers, which are never recased.
+    Quoted(&'a str),
+    Symbol(&'a str),
+    LineComment(&'a str),
+    BlockComment(&'a str),
+}
+
+impl Token<'_> {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+}
+
+fn is_keyword(word: &str) -> bool {
+    KEYWORDS
+        .iter()
+        .any(|keyword| keyword.eq_ignore_ascii_case(word))
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$' | '@' | '#')
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token<'_>>, ToolError> {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+    while let Some(c) = source[offset..].chars().next() {
+        let rest = &source[offset..];
+        let next = rest[c.len_utf8()..].chars().next();
+        let len = if c.is_whitespace() {
+            offset += c.len_utf8();
+            continue;
+        } else if rest.starts_with("--") {
+            let len = rest.find('\n').unwrap_or(rest.len());
+            tokens.push(Token::LineComment(rest[..len].trim_end()));
+            len
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            let len = comment
+                .find("*/")
+                .map(|i| i + 4)
+                .ok_or_else(|| ToolError::new("comment is never closed").at_offset(offset))?;
+            tokens.push(Token::BlockComment(&rest[..len]));
+            len
+        } else if matches!(c, '\'' | '"' | '`' | '[') {
+            let close = if c == '[' { ']' } else { c };
+            // A doubled closing quote stands for the quote itself.
+            let mut end = None;
+            let mut chars = rest.char_indices().skip(1).peekable();
+            while let Some((i, ch)) = chars.next() {
+                if ch == close {
+                    if chars.peek().is_some_and(|(_, ch)| *ch == close) {
+                        chars.next();
+                    } else {
+                        end = Some(i + 1);
+                        break;
+                    }
+                }
+            }
+            let len = end.ok_or_else(|| {
+                ToolError::new(format!("{} is never closed", c)).at_offset(offset)
+            })?;
+            tokens.push(Token::Quoted(&rest[..len]));
+            len
+        } else if is_word_char(c) || (c == ':' && next.is_some_and(char::is_alphabetic)) {
+            // Numbers may hold a decimal point; elsewhere a dot separates
+            // names and is a token of its own.
+            let numeric = c.is_ascii_digit();
+            let len = rest
+                .char_indices()
+                .skip(1)
+                .find(|(_, c)| !(is_word_char(*c) || numeric && *c == '.'))
+                .map(|(i, _)| i)
+                .unwrap_or(rest.len());
+            tokens.push(Token::Word(&rest[..len]));
+            len
+        } else {
+            let len = ["->>", "->", "::", "<=", ">=", "<>", "!=", "||"]
+                .iter()
+                .find(|symbol| rest.starts_with(*symbol))
+                .map(|symbol| symbol.len())
+                .unwrap_or(c.len_utf8());
+            tokens.push(Token::Symbol(&rest[..len]));
+            len
+        };
+        offset += len;
+    }
+    Ok(tokens)
+}
+
+/// Matches the longest of `phrases` at the start of `tokens`, returning how
+/// many tokens it covers.
+fn match_phrase(tokens: &[Token], phrases: &[&[&str]]) -> Option<usize> {
+    phrases
+        .iter()
+        .find(|phrase| {
+            phrase.len() <= tokens.len()
+                && phrase
+                    .iter()
+                    .zip(tokens)
+                    .all(|(keyword, token)| token.is_keyword(keyword))
+        })
+        .map(|phrase| phrase.len())
+}
+
+/// Builds the formatted query, keeping track of the indentation and of
+/// whether a space is needed before the next token.
+struct Writer {
+    out: String,
+    indent: String,
+    keyword_case: KeywordCase,
+    compact: bool,
+    /// The previous token, as written, and whether it was a keyword.
+    previous: Option<(String, bool)>,
+}
+
+impl Writer {
+    /// Starts a new line at `level`, unless the current line is still empty,
+    /// in which case only its indentation changes.
+    fn newline(&mut self, level: usize) {
+        if self.compact || self.out.is_empty() {
+            return;
+        }
+        self.out
+            .truncate(self.out.trim_end_matches([' ', '\t']).len());
+        if !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+        self.out.push_str(&self.indent.repeat(level));
+        self.previous = None;
+    }
+
+    fn write(&mut self, token: &Token) {
+        let text = match token {
+            Token::Word(word) if is_keyword(word) => match self.keyword_case {
+                KeywordCase::Upper => word.to_uppercase(),
+                KeywordCase::Lower => word.to_lowercase(),
+                KeywordCase::Preserve => word.to_string(),
+            },
+            Token::Word(text)
+            | Token::Quoted(text)
+            | Token::Symbol(text)
+            | Token::LineComment(text)
+            | Token::BlockComment(text) => text.to_string(),
+        };
+        let keyword = matches!(token, Token::Word(word) if is_keyword(word));
+        let space = match &self.previous {
+            None => false,
+            Some((previous, previous_keyword)) => {
+                // Function calls keep the parenthesis next to the name.
+                let call = text == "("
+                    && !previous_keyword
+                    && previous.chars().last().is_some_and(is_word_char);
+                !call
+                    && !matches!(previous.as_str(), "(" | "." | "::")
+                    && !matches!(text.as_str(), "," | ";" | ")" | "." | "::")
+            }
+        };
+        if space {
+            self.out.push(' ');
+        }
+        self.out.push_str(&text);
+        self.previous = Some((text, keyword));
+    }
+
+    fn write_keywords(&mut self, tokens: &[Token]) {
+        for token in tokens {
+            self.write(token);
+        }
+    }
+}
+
+/// Formats SQL with each clause on its own line and its items indented
+/// beneath it, or all on one line if `compact` is set. Parentheses around
+/// subqueries are indented like a clause; other parentheses stay inline.
+pub fn format(
+    source: &str,
+    indentation_size: usize,
+    keyword_case: KeywordCase,
+    compact: bool,
+) -> Result<String, ToolError> {
+    let tokens = tokenize(source).map_err(|err| err.context("Invalid SQL"))?;
+    let mut writer = Writer {
+        out: String::new(),
+        indent: " ".repeat(indentation_size),
+        keyword_case,
+        compact,
+        previous: None,
+    };
+    // The indentation level of the current clause keywords.
+    let mut level = 0;
+    // For each open parenthesis, whether it holds a subquery and the level
+    // to return to when it closes.
+    let mut parens: Vec<(bool, usize)> = Vec::new();
+    let mut in_between = false;
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+        let rest = &tokens[i..];
+        let inline = parens.last().is_some_and(|(subquery, _)| !subquery);
+
+        if !inline && let Some(len) = match_phrase(rest, CLAUSES) {
+            writer.newline(level);
+            writer.write_keywords(&rest[..len]);
+            writer.newline(level + 1);
+            i += len;
+            continue;
+        }
+        if !inline && let Some(len) = match_phrase(rest, SET_OPERATIONS) {
+            writer.newline(level);
+            writer.write_keywords(&rest[..len]);
+            i += len;
+            continue;
+        }
+        if !inline && let Some(len) = match_phrase(rest, JOINS) {
+            writer.newline(level + 1);
+            writer.write_keywords(&rest[..len]);
+            i += len;
+            continue;
+        }
+
+        match token {
+            Token::Word(_) if token.is_keyword("BETWEEN") => {
+                in_between = true;
+                writer.write(&token);
+            }
+            Token::Word(_) if token.is_keyword("AND") && in_between => {
+                in_between = false;
+                writer.write(&token);
+            }
+            Token::Word(_) if !inline && (token.is_keyword("AND") || token.is_keyword("OR")) => {
+                writer.newline(level + 1);
+                writer.write(&token);
+            }
+            Token::Symbol("(") => {
+                let subquery = tokens
+                    .get(i + 1)
+                    .is_some_and(|next| next.is_keyword("SELECT") || next.is_keyword("WITH"));
+                writer.write(&token);
+                parens.push((subquery, level));
+                if subquery {
+                    level += 2;
+                }
+            }
+            Token::Symbol(")") => {
+                if let Some((subquery, outer)) = parens.pop() {
+                    level = outer;
+                    if subquery {
+                        writer.newline(level + 1);
+                    }
+                }
+                writer.write(&token);
+            }
+            Token::Symbol(",") => {
+                writer.write(&token);
+                if !inline {
+                    writer.newline(level + 1);
+                }
+            }
+            Token::Symbol(";") => {
+                writer.write(&token);
+                level = 0;
+                parens.clear();
+                // Statements are kept apart by a blank line.
+                writer.newline(0);
+                if !compact {
+                    writer.out.push('\n');
+                }
+            }
+            Token::LineComment(_) => {
+                writer.write(&token);
+                // Whatever follows a line comment must start a new line,
+                // even when compacting.
+                if compact {
+                    writer.out.push('\n');
+                    writer.previous = None;
+                } else {
+                    writer.newline(level + 1);
+                }
+            }
+            _ => writer.write(&token),
+        }
+        i += 1;
+    }
+    Ok(writer.out.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puts_clauses_on_their_own_lines() {
+        assert_eq!(
+            format(
+                "select a, b from t where x = 1",
+                2,
+                KeywordCase::Upper,
+                false
+            )
+            .unwrap(),
+            "SELECT\n  a,\n  b\nFROM\n  t\nWHERE\n  x = 1"
+        );
+    }
+
+    #[test]
+    fn compacts_onto_one_line() {
+        assert_eq!(
+            format("SELECT a\nFROM t", 2, KeywordCase::Lower, true).unwrap(),
+            "select a from t"
+        );
+    }
+
+    #[test]
+    fn leaves_quoted_text_alone() {
+        assert_eq!(
+            format("select 'from' from t", 2, KeywordCase::Upper, true).unwrap(),
+            "SELECT 'from' FROM t"
+        );
+    }
+
+    #[test]
+    fn rejects_unclosed_comments() {
+        let err = format("select /* a", 2, KeywordCase::Upper, false).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid SQL"));
+    }
+}
@@ -0,0 +1,682 @@
+use std::fmt;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Local, LocalResult, Months, NaiveDate,
+    NaiveDateTime, NaiveTime, Offset, SecondsFormat, TimeZone, Utc,
+};
+use chrono_tz::{OffsetComponents, OffsetName, TZ_VARIANTS, Tz};
+use serde::{Deserialize, Serialize};
+
+use crate::ToolError;
+
+/// The date formats tried, in order, when converting a date to a timestamp.
+/// Dates without a time are taken as midnight.
+const DATE_TIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M",
+];
+
+/// Date formats, besides RFC 3339 and RFC 2822, that carry their own offset,
+/// along with the names they are reported by.
+const OFFSET_DATE_FORMATS: [(&str, &str); 6] = [
+    ("%Y-%m-%dT%H:%M:%S%.f%z", "ISO 8601"),
+    ("%Y-%m-%d %H:%M:%S%.f%z", "ISO 8601"),
+    ("%Y-%m-%dT%H:%M%z", "ISO 8601"),
+    ("%Y-%m-%d %H:%M%z", "ISO 8601"),
+    ("%Y%m%dT%H%M%S%z", "ISO 8601 Basic"),
+    ("%a %b %d %Y %H:%M:%S GMT%z", "JavaScript Date"),
+];
+
+/// The obsolete HTTP date formats, which are always in GMT.
+const GMT_DATE_FORMATS: [(&str, &str); 2] = [
+    ("%A, %d-%b-%y %H:%M:%S GMT", "RFC 850"),
+    ("%a %b %e %H:%M:%S %Y", "ANSI C asctime()"),
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Unit {
+    Auto,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl Unit {
+    /// Guesses the unit from the number of digits, taking the one that puts
+    /// the timestamp closest to now: 10 digits in seconds, 13 in
+    /// milliseconds, 16 in microseconds, and 19 in nanoseconds.
+    pub fn detect(value: i64) -> Unit {
+        match value.unsigned_abs().checked_ilog10().unwrap_or(0) + 1 {
+            ..=11 => Unit::Seconds,
+            12..=14 => Unit::Milliseconds,
+            15..=17 => Unit::Microseconds,
+            _ => Unit::Nanoseconds,
+        }
+    }
+
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Unit::Auto => "auto",
+            Unit::Seconds => "s",
+            Unit::Milliseconds => "ms",
+            Unit::Microseconds => "µs",
+            Unit::Nanoseconds => "ns",
+        }
+    }
+}
+
+/// Reads a timestamp in the given unit, or in the unit its length suggests.
+pub fn parse_timestamp(value: &str, unit: Unit) -> Result<DateTime<Utc>, ToolError> {
+    let timestamp: i64 = value
+        .trim()
+        .parse()
+        .map_err(|err| ToolError::from(err).context("Invalid timestamp"))?;
+    let unit = match unit {
+        Unit::Auto => Unit::detect(timestamp),
+        unit => unit,
+    };
+    let converted = match unit {
+        Unit::Milliseconds => DateTime::from_timestamp_millis(timestamp),
+        Unit::Microseconds => DateTime::from_timestamp_micros(timestamp),
+        Unit::Nanoseconds => Some(DateTime::from_timestamp_nanos(timestamp)),
+        _ => DateTime::from_timestamp(timestamp, 0),
+    };
+    converted.ok_or_else(|| ToolError::new("Timestamp is out of range"))
+}
+
+/// Describes how long ago, or how far ahead, a point in time is.
+pub fn format_relative_time(duration: Duration) -> String {
+    if duration.num_seconds() < 0 {
+        let future_seconds = -duration.num_seconds();
+        return format!("in {} seconds", future_seconds);
+    }
+
+    let seconds = duration.num_seconds();
+    let minutes = duration.num_minutes();
+    let hours = duration.num_hours();
+    let days = duration.num_days();
+
+    if days > 0 {
+        format!("{} days ago", days)
+    } else if hours > 0 {
+        format!("{} hours ago", hours)
+    } else if minutes > 0 {
+        format!("{} minutes ago", minutes)
+    } else {
+        format!("{} seconds ago", seconds)
+    }
+}
+
+/// Reads a date and time without an offset, as in 2024-05-01 13:45:00.
+pub fn parse_date_time(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
+}
+
+/// Converts a date to a point in time. Dates that carry their own offset,
+/// as in RFC 3339 or RFC 2822, ignore `zone`; others are read as the wall
+/// time in `zone`, which is "Local", "UTC", or an IANA zone name.
+pub fn date_to_timestamp(value: &str, zone: &str) -> Result<DateTime<Utc>, ToolError> {
+    let value = value.trim();
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(date_time.to_utc());
+    }
+    if let Ok(date_time) = DateTime::parse_from_rfc2822(value) {
+        return Ok(date_time.to_utc());
+    }
+    let naive = parse_date_time(value).ok_or_else(|| {
+        ToolError::new(format!(
+            "{:?} is not a date such as 2024-05-01 13:45:00",
+            value
+        ))
+    })?;
+
+    let resolve = |local: LocalResult<DateTime<Utc>>| match local {
+        // When clocks go back, the earlier of the two instants is used.
+        LocalResult::Single(date_time) | LocalResult::Ambiguous(date_time, _) => Ok(date_time),
+        LocalResult::None => Err(ToolError::new(format!(
+            "{} does not exist in {}, as clocks skip over it",
+            naive, zone
+        ))),
+    };
+    match zone {
+        "UTC" => Ok(naive.and_utc()),
+        "Local" => resolve(
+            Local
+                .from_local_datetime(&naive)
+                .map(|date_time| date_time.to_utc()),
+        ),
+        zone => {
+            let tz: Tz = zone
+                .parse()
+                .map_err(|_| ToolError::new(format!("unknown timezone {:?}", zone)))?;
+            resolve(
+                tz.from_local_datetime(&naive)
+                    .map(|date_time| date_time.to_utc()),
+            )
+        }
+    }
+}
+
+/// The zones a date can be read in: local time and UTC, followed by every
+/// IANA zone.
+pub fn zone_names() -> Vec<&'static str> {
+    ["Local", "UTC"]
+        .into_iter()
+        .chain(TZ_VARIANTS.iter().map(|tz| tz.name()))
+        .collect()
+}
+
+/// Writes an offset as in UTC+05:30.
+pub fn format_offset(offset: FixedOffset) -> String {
+    let seconds = offset.local_minus_utc();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.abs() / 60;
+    format!("UTC{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+}
+
+/// A point in time as the clocks of one zone show it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZoneTime {
+    pub date_time: NaiveDateTime,
+    pub offset: FixedOffset,
+    /// The zone's abbreviation at that time, such as CEST, for zones that
+    /// have one rather than only a numeric offset.
+    pub abbreviation: Option<String>,
+    /// Whether daylight saving time is in effect. Always false for local
+    /// time, whose rules are not known.
+    pub dst: bool,
+}
+
+/// Reads `at` in `zone`, which is "Local", "UTC", or an IANA zone name.
+pub fn to_zone(at: DateTime<Utc>, zone: &str) -> Result<ZoneTime, ToolError> {
+    if zone == "Local" {
+        let local = at.with_timezone(&Local);
+        return Ok(ZoneTime {
+            date_time: local.naive_local(),
+            offset: local.offset().fix(),
+            abbreviation: None,
+            dst: false,
+        });
+    }
+    let tz: Tz = zone
+        .parse()
+        .map_err(|_| ToolError::new(format!("unknown timezone {:?}", zone)))?;
+    let local = at.with_timezone(&tz);
+    let offset = local.offset();
+    Ok(ZoneTime {
+        date_time: local.naive_local(),
+        offset: offset.fix(),
+        abbreviation: offset
+            .abbreviation()
+            .filter(|abbreviation| !abbreviation.starts_with(['+', '-']))
+            .map(str::to_string),
+        dst: !offset.dst_offset().is_zero(),
+    })
+}
+
+/// A date read by [`parse_any_date`], along with the name of the format it
+/// was written in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsedDate {
+    pub at: DateTime<FixedOffset>,
+    pub format: &'static str,
+}
+
+/// Reads a date in any common format: ISO 8601 and RFC 3339, RFC 2822 and
+/// HTTP dates, the output of JavaScript's `Date.toString()`, or a Unix
+/// timestamp. Dates without an offset are read as the wall time in `zone`,
+/// and timestamps are shown in it.
+pub fn parse_any_date(value: &str, zone: &str) -> Result<ParsedDate, ToolError> {
+    let value = value.trim();
+    let in_zone = |at: DateTime<Utc>| -> Result<DateTime<FixedOffset>, ToolError> {
+        Ok(at.with_timezone(&to_zone(at, zone)?.offset))
+    };
+    let parsed = |at, format| Ok(ParsedDate { at, format });
+
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        return parsed(
+            in_zone(parse_timestamp(value, Unit::Auto)?)?,
+            "Unix Timestamp",
+        );
+    }
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return parsed(at, "RFC 3339");
+    }
+    if let Ok(at) = DateTime::parse_from_rfc2822(value) {
+        return parsed(at, "RFC 2822");
+    }
+    // Date.toString() ends with the zone's name in parentheses, and ISO 8601
+    // allows Z in place of a zero offset.
+    let offset_value = match value.split_once(" (") {
+        Some((value, _)) => value.to_string(),
+        None => match value.strip_suffix(['Z', 'z']) {
+            Some(value) => format!("{}+0000", value),
+            None => value.to_string(),
+        },
+    };
+    for (format, name) in OFFSET_DATE_FORMATS {
+        if let Ok(at) = DateTime::parse_from_str(&offset_value, format) {
+            return parsed(at, name);
+        }
+    }
+    for (format, name) in GMT_DATE_FORMATS {
+        if let Ok(at) = NaiveDateTime::parse_from_str(value, format) {
+            return parsed(at.and_utc().fixed_offset(), name);
+        }
+    }
+    if parse_date_time(value).is_some() {
+        return parsed(
+            in_zone(date_to_timestamp(value, zone)?)?,
+            "ISO 8601, No Offset",
+        );
+    }
+    Err(ToolError::new(format!(
+        "{:?} is not a date in a format such as ISO 8601 or RFC 2822",
+        value
+    )))
+}
+
+/// Writes a date in each of the formats [`parse_any_date`] reads, keeping
+/// its offset in those that can carry one.
+pub fn date_formats(at: DateTime<FixedOffset>) -> Vec<(&'static str, String)> {
+    let utc = at.to_utc();
+    vec![
+        ("ISO 8601", at.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+        (
+            "ISO 8601 (UTC)",
+            utc.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        ),
+        ("ISO 8601 Basic", at.format("%Y%m%dT%H%M%S%z").to_string()),
+        ("ISO 8601 Week Date", at.format("%G-W%V-%u").to_string()),
+        ("ISO 8601 Ordinal Date", at.format("%Y-%j").to_string()),
+        ("RFC 3339", at.to_rfc3339()),
+        ("RFC 2822", at.to_rfc2822()),
+        (
+            "HTTP Date",
+            utc.format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+        ),
+        (
+            "RFC 850",
+            utc.format("%A, %d-%b-%y %H:%M:%S GMT").to_string(),
+        ),
+        (
+            "ANSI C asctime()",
+            utc.format("%a %b %e %H:%M:%S %Y").to_string(),
+        ),
+        (
+            "JavaScript Date",
+            at.format("%a %b %d %Y %H:%M:%S GMT%z").to_string(),
+        ),
+        ("Unix Timestamp", utc.timestamp().to_string()),
+        ("Unix Timestamp (ms)", utc.timestamp_millis().to_string()),
+    ]
+}
+
+/// A calendar duration, such as 1 year, 2 months and 3 days, whose length in
+/// days depends on the date it is counted from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Period {
+    pub negative: bool,
+    pub years: u32,
+    pub months: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+}
+
+impl Period {
+    /// The period from `start` to `end`, counted in whole months first and
+    /// then in days and time. It is negative if `end` comes first.
+    pub fn between(start: NaiveDateTime, end: NaiveDateTime) -> Period {
+        let (negative, start, end) = match end < start {
+            true => (true, end, start),
+            false => (false, start, end),
+        };
+        let mut months =
+            (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+        let mut shifted = add_months(start, months);
+        // Counting from the 31st, a month ends on the last day of shorter
+        // months, so a month may have passed on an earlier day of the month.
+        if shifted > end {
+            months -= 1;
+            shifted = add_months(start, months);
+        }
+        let rest = (end - shifted).num_seconds();
+        Period {
+            negative,
+            years: months as u32 / 12,
+            months: months as u32 % 12,
+            days: (rest / 86_400) as u32,
+            hours: (rest % 86_400 / 3_600) as u32,
+            minutes: (rest % 3_600 / 60) as u32,
+            seconds: (rest % 60) as u32,
+        }
+    }
+
+    /// Reads a period written as amounts and units, as in "1y 2mo 3d 4h" or
+    /// "-2 weeks", or in ISO 8601, as in "P1Y2M3DT4H".
+    pub fn parse(value: &str) -> Result<Period, ToolError> {
+        let value = value.trim();
+        let invalid = || {
+            ToolError::new(format!(
+                "{:?} is not a duration such as 1y 2mo 3d or P1Y2M3D",
+                value
+            ))
+        };
+        let (negative, rest) = match value.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+        let mut period = Period {
+            negative,
+            ..Period::default()
+        };
+        if let Some(iso) = rest.strip_prefix(['P', 'p']) {
+            let mut time = false;
+            let mut amount = String::new();
+            for c in iso.chars() {
+                match c.to_ascii_uppercase() {
+                    'T' if amount.is_empty() => time = true,
+                    c if c.is_ascii_digit() => amount.push(c),
+                    unit => {
+                        let amount = std::mem::take(&mut amount).parse().map_err(|_| invalid())?;
+                        let unit = match (unit, time) {
+                            ('Y', false) => "y",
+                            ('M', false) => "mo",
+                            ('W', false) => "w",
+                            ('D', false) => "d",
+                            ('H', true) => "h",
+                            ('M', true) => "min",
+                            ('S', true) => "s",
+                            _ => return Err(invalid()),
+                        };
+                        period.add(amount, unit).ok_or_else(invalid)?;
+                    }
+                }
+            }
+            return match amount.is_empty() && !iso.is_empty() {
+                true => Ok(period),
+                false => Err(invalid()),
+            };
+        }
+
+        let mut words = rest
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|word| !word.is_empty() && *word != "and")
+            .peekable();
+        if words.peek().is_none() {
+            return Err(invalid());
+        }
+        while let Some(word) = words.next() {
+            let split = word
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(word.len());
+            let (amount, unit) = word.split_at(split);
+            let amount = amount.parse().map_err(|_| invalid())?;
+            // The unit may follow the amount after a space, as in "3 days".
+            let unit = match unit {
+                "" => words.next().ok_or_else(invalid)?,
+                unit => unit,
+            };
+            period.add(amount, unit).ok_or_else(invalid)?;
+        }
+        Ok(period)
+    }
+
+    fn add(&mut self, amount: u32, unit: &str) -> Option<()> {
+        let field = match unit.to_lowercase().as_str() {
+            "y" | "yr" | "yrs" | "year" | "years" => &mut self.years,
+            "mo" | "mon" | "month" | "months" => &mut self.months,
+            "w" | "wk" | "wks" | "week" | "weeks" => {
+                self.days = self.days.checked_add(amount.checked_mul(7)?)?;
+                return Some(());
+            }
+            "d" | "day" | "days" => &mut self.days,
+            "h" | "hr" | "hrs" | "hour" | "hours" => &mut self.hours,
+            "m" | "min" | "mins" | "minute" | "minutes" => &mut self.minutes,
+            "s" | "sec" | "secs" | "second" | "seconds" => &mut self.seconds,
+            _ => return None,
+        };
+        *field = field.checked_add(amount)?;
+        Some(())
+    }
+
+    /// Moves `at` forward by the period, or back if it is negative. Months
+    /// are added before days, and a date past the end of a shorter month
+    /// becomes its last day.
+    pub fn apply(&self, at: NaiveDateTime) -> Option<NaiveDateTime> {
+        let months = Months::new(self.years.checked_mul(12)?.checked_add(self.months)?);
+        let time = Duration::days(self.days.into())
+            + Duration::hours(self.hours.into())
+            + Duration::minutes(self.minutes.into())
+            + Duration::seconds(self.seconds.into());
+        match self.negative {
+            false => at.checked_add_months(months)?.checked_add_signed(time),
+            true => at.checked_sub_months(months)?.checked_sub_signed(time),
+        }
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = [
+            (self.years, "year"),
+            (self.months, "month"),
+            (self.days, "day"),
+            (self.hours, "hour"),
+            (self.minutes, "minute"),
+            (self.seconds, "second"),
+        ]
+        .into_iter()
+        .filter(|(amount, _)| *amount > 0)
+        .map(|(amount, unit)| match amount {
+            1 => format!("1 {}", unit),
+            amount => format!("{} {}s", amount, unit),
+        })
+        .collect::<Vec<_>>();
+        match parts.is_empty() {
+            true => write!(f, "0 days"),
+            false => write!(
+                f,
+                "{}{}",
+                if self.negative { "-" } else { "" },
+                parts.join(", ")
+            ),
+        }
+    }
+}
+
+fn add_months(at: NaiveDateTime, months: i32) -> NaiveDateTime {
+    at.checked_add_months(Months::new(months as u32))
+        .unwrap_or(NaiveDateTime::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_the_unit_from_the_number_of_digits() {
+        let expected = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        for value in [
+            "1700000000",
+            "1700000000000",
+            "1700000000000000",
+            "1700000000000000000",
+        ] {
+            assert_eq!(parse_timestamp(value, Unit::Auto).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn uses_the_given_unit() {
+        let parsed = parse_timestamp("1700000000", Unit::Milliseconds).unwrap();
+        assert_eq!(parsed, DateTime::from_timestamp(1_700_000, 0).unwrap());
+        assert!(parse_timestamp("soon", Unit::Auto).is_err());
+    }
+
+    #[test]
+    fn describes_relative_times() {
+        assert_eq!(
+            format_relative_time(Duration::seconds(30)),
+            "30 seconds ago"
+        );
+        assert_eq!(format_relative_time(Duration::hours(5)), "5 hours ago");
+        assert_eq!(format_relative_time(Duration::days(2)), "2 days ago");
+        assert_eq!(
+            format_relative_time(Duration::seconds(-10)),
+            "in 10 seconds"
+        );
+    }
+
+    #[test]
+    fn reads_dates_in_a_zone() {
+        let expected = DateTime::from_timestamp(1_714_571_100, 0).unwrap();
+        assert_eq!(
+            date_to_timestamp("2024-05-01 13:45", "UTC").unwrap(),
+            expected
+        );
+        assert_eq!(
+            date_to_timestamp("2024-05-01T15:45:00", "Europe/Berlin").unwrap(),
+            expected
+        );
+        assert_eq!(
+            date_to_timestamp("2024-05-01T13:45:00Z", "Asia/Tokyo").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn rejects_times_skipped_by_clocks() {
+        assert!(date_to_timestamp("2024-03-31 02:30", "Europe/Berlin").is_err());
+        assert!(date_to_timestamp("2024-05-01", "Mars/Olympus").is_err());
+    }
+
+    #[test]
+    fn reads_times_in_other_zones() {
+        let at = DateTime::from_timestamp(1_714_571_100, 0).unwrap();
+        let berlin = to_zone(at, "Europe/Berlin").unwrap();
+        assert_eq!(berlin.date_time.to_string(), "2024-05-01 15:45:00");
+        assert_eq!(format_offset(berlin.offset), "UTC+02:00");
+        assert_eq!(berlin.abbreviation.as_deref(), Some("CEST"));
+        assert!(berlin.dst);
+
+        let kolkata = to_zone(at, "Asia/Kolkata").unwrap();
+        assert_eq!(format_offset(kolkata.offset), "UTC+05:30");
+        assert!(!kolkata.dst);
+        assert_eq!(
+            format_offset(to_zone(at, "America/St_Johns").unwrap().offset),
+            "UTC-02:30"
+        );
+        assert_eq!(to_zone(at, "Asia/Dubai").unwrap().abbreviation, None);
+        assert!(to_zone(at, "Mars/Olympus").is_err());
+    }
+
+    #[test]
+    fn counts_the_period_between_dates() {
+        let date = |value| parse_date_time(value).unwrap();
+        let period = Period::between(date("2024-01-31"), date("2025-03-01 06:30"));
+        assert_eq!(
+            period.to_string(),
+            "1 year, 1 month, 1 day, 6 hours, 30 minutes"
+        );
+        let period = Period::between(date("2024-03-31"), date("2024-02-29"));
+        assert_eq!(period.to_string(), "-1 month, 2 days");
+        assert_eq!(
+            Period::between(date("2024-05-01"), date("2024-05-01")).to_string(),
+            "0 days"
+        );
+    }
+
+    #[test]
+    fn reads_periods() {
+        let expected = Period {
+            years: 1,
+            months: 2,
+            days: 17,
+            hours: 4,
+            ..Period::default()
+        };
+        assert_eq!(Period::parse("1y 2mo 2w 3d 4h").unwrap(), expected);
+        assert_eq!(
+            Period::parse("1 year, 2 months, 17 days and 4 hours").unwrap(),
+            expected
+        );
+        assert_eq!(Period::parse("P1Y2M2W3DT4H").unwrap(), expected);
+        assert!(Period::parse("-P1D").unwrap().negative);
+        assert!(Period::parse("3 fortnights").is_err());
+        assert!(Period::parse("P1H").is_err());
+        assert!(Period::parse("").is_err());
+    }
+
+    #[test]
+    fn applies_periods_to_dates() {
+        let date = parse_date_time("2024-01-31").unwrap();
+        let later = Period::parse("1mo 1d").unwrap().apply(date).unwrap();
+        assert_eq!(later.to_string(), "2024-03-01 00:00:00");
+        let earlier = Period::parse("-1y 12h").unwrap().apply(date).unwrap();
+        assert_eq!(earlier.to_string(), "2023-01-30 12:00:00");
+    }
+
+    #[test]
+    fn reads_dates_in_any_format() {
+        let expected = DateTime::from_timestamp(1_714_571_100, 0).unwrap();
+        for (value, format) in [
+            ("2024-05-01T15:45:00+02:00", "RFC 3339"),
+            ("2024-05-01 13:45Z", "ISO 8601"),
+            ("20240501T134500Z", "ISO 8601 Basic"),
+            ("Wed, 01 May 2024 13:45:00 GMT", "RFC 2822"),
+            ("Wednesday, 01-May-24 13:45:00 GMT", "RFC 850"),
+            ("Wed May  1 13:45:00 2024", "ANSI C asctime()"),
+            (
+                "Wed May 01 2024 15:45:00 GMT+0200 (Central European Summer Time)",
+                "JavaScript Date",
+            ),
+            ("1714571100", "Unix Timestamp"),
+            ("2024-05-01 22:45", "ISO 8601, No Offset"),
+        ] {
+            let parsed = parse_any_date(value, "Asia/Tokyo").unwrap();
+            assert_eq!(parsed.at, expected, "{}", value);
+            assert_eq!(parsed.format, format, "{}", value);
+        }
+        assert_eq!(
+            parse_any_date("1714571100", "Asia/Tokyo")
+                .unwrap()
+                .at
+                .to_rfc3339(),
+            "2024-05-01T22:45:00+09:00"
+        );
+        assert!(parse_any_date("next tuesday", "UTC").is_err());
+    }
+
+    #[test]
+    fn writes_dates_in_every_format() {
+        let at = DateTime::parse_from_rfc3339("2024-05-01T15:45:00+02:00").unwrap();
+        let formats = date_formats(at);
+        let format = |name| &formats.iter().find(|(n, _)| *n == name).unwrap().1;
+        assert_eq!(format("ISO 8601 (UTC)"), "2024-05-01T13:45:00Z");
+        assert_eq!(format("ISO 8601 Week Date"), "2024-W18-3");
+        assert_eq!(format("HTTP Date"), "Wed, 01 May 2024 13:45:00 GMT");
+        assert_eq!(format("ANSI C asctime()"), "Wed May  1 13:45:00 2024");
+        // Week and ordinal dates leave out the time.
+        let timed = |(name, _): &&(&str, String)| {
+            !matches!(*name, "ISO 8601 Week Date" | "ISO 8601 Ordinal Date")
+        };
+        for (name, value) in formats.iter().filter(timed) {
+            assert_eq!(parse_any_date(value, "UTC").unwrap().at, at, "{}", name);
+        }
+    }
+}
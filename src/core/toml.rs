@@ -0,0 +1,229 @@
+use serde_json::{Map, Number, Value};
+
+use crate::ToolError;
+
+/// Converts a TOML error's byte span in `source` to a line and column.
+fn position(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+fn to_json(value: ::toml::Value, path: &str) -> Result<Value, ToolError> {
+    Ok(match value {
+        ::toml::Value::String(s) => Value::String(s),
+        ::toml::Value::Integer(n) => Value::from(n),
+        ::toml::Value::Float(n) => {
+            Value::Number(Number::from_f64(n).ok_or_else(|| {
+                ToolError::new(format!("{} at {} has no JSON equivalent", n, path))
+            })?)
+        }
+        ::toml::Value::Boolean(b) => Value::Bool(b),
+        // JSON has no dates, so they become strings in RFC 3339 form.
+        ::toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        ::toml::Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| to_json(item, &format!("{}[{}]", path, i)))
+                .collect::<Result<_, _>>()?,
+        ),
+        ::toml::Value::Table(table) => Value::Object(table_to_json(table, path)?),
+    })
+}
+
+fn table_to_json(table: ::toml::Table, path: &str) -> Result<Map<String, Value>, ToolError> {
+    table
+        .into_iter()
+        .map(|(key, value)| {
+            let path = join(path, &key);
+            Ok((key, to_json(value, &path)?))
+        })
+        .collect()
+}
+
+fn to_toml(value: Value, path: &str) -> Result<::toml::Value, ToolError> {
+    Ok(match value {
+        Value::Null => {
+            return Err(ToolError::new(format!(
+                "null at {} has no TOML equivalent",
+                path
+            )));
+        }
+        Value::Bool(b) => ::toml::Value::Boolean(b),
+        Value::Number(n) => match n.as_i64() {
+            Some(n) => ::toml::Value::Integer(n),
+            None if n.is_u64() => {
+                return Err(ToolError::new(format!(
+                    "{} at {} is too large for a TOML integer",
+                    n, path
+                )));
+            }
+            None => ::toml::Value::Float(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => ::toml::Value::String(s),
+        Value::Array(items) => ::toml::Value::Array(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| to_toml(item, &format!("{}[{}]", path, i)))
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Object(map) => ::toml::Value::Table(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let path = join(path, &key);
+                    Ok((key, to_toml(value, &path)?))
+                })
+                .collect::<Result<_, ToolError>>()?,
+        ),
+    })
+}
+
+/// Appends a key to a dotted path, quoting it if it is not a bare key.
+fn join(path: &str, key: &str) -> String {
+    let bare = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    let key = match bare {
+        true => key.to_string(),
+        false => serde_json::to_string(key).unwrap_or_default(),
+    };
+    match path {
+        "" => key,
+        path => format!("{}.{}", path, key),
+    }
+}
+
+/// Converts a TOML document to JSON, indenting each level by
+/// `indentation_size` spaces. Dates and times become strings.
+pub fn to_json_string(source: &str, indentation_size: usize) -> Result<String, ToolError> {
+    let table = source.parse::<::toml::Table>().map_err(|err| {
+        let error = ToolError::new(err.message().trim_end());
+        let error = match err.span() {
+            Some(span) => {
+                let (line, column) = position(source, span.start);
+                error.at_line_column(line, column)
+            }
+            None => error,
+        };
+        error.context("Invalid TOML")
+    })?;
+    let json =
+        Value::Object(table_to_json(table, "").map_err(|err| err.context("Cannot convert TOML"))?);
+    super::json::format(&serde_json::to_string(&json)?, indentation_size)
+}
+
+/// Converts a JSON object to a TOML document. Nulls are rejected, since TOML
+/// has no way to write them.
+pub fn from_json_string(source: &str) -> Result<String, ToolError> {
+    let json = serde_json::from_str::<Value>(source)
+        .map_err(|err| ToolError::from(err).context("Invalid JSON"))?;
+    let ::toml::Value::Table(table) =
+        to_toml(json, "").map_err(|err| err.context("Cannot convert JSON"))?
+    else {
+        return Err(
+            ToolError::new("expected an object at the top level").context("Cannot convert JSON")
+        );
+    };
+    ::toml::to_string_pretty(&table)
+        .map_err(|err| ToolError::new(err.to_string()).context("Cannot convert JSON"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_toml_to_json() {
+        let toml = r#"
+[package]
+name = "utools"
+version = "0.1.0"
+released = 2025-01-02T03:04:05Z
+
+[dependencies]
+serde = { version = "1", features = ["derive"] }
+
+[[bin]]
+name = "utools"
+"#;
+        assert_eq!(
+            to_json_string(toml, 2).unwrap(),
+            r#"{
+  "package": {
+    "name": "utools",
+    "version": "0.1.0",
+    "released": "2025-01-02T03:04:05Z"
+  },
+  "dependencies": {
+    "serde": {
+      "version": "1",
+      "features": [
+        "derive"
+      ]
+    }
+  },
+  "bin": [
+    {
+      "name": "utools"
+    }
+  ]
+}"#
+        );
+    }
+
+    #[test]
+    fn converts_json_to_toml() {
+        let json = r#"{"title": "x", "n": 1.5, "owner": {"name": "Ada", "tags": ["a", "b"]},
+            "servers": [{"ip": "10.0.0.1"}, {"ip": "10.0.0.2"}]}"#;
+        let toml = from_json_string(json).unwrap();
+        assert_eq!(
+            toml,
+            r#"title = "x"
+n = 1.5
+
+[owner]
+name = "Ada"
+tags = [
+    "a",
+    "b",
+]
+
+[[servers]]
+ip = "10.0.0.1"
+
+[[servers]]
+ip = "10.0.0.2"
+"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Value>(&to_json_string(&toml, 2).unwrap()).unwrap(),
+            serde_json::from_str::<Value>(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_where_toml_is_invalid() {
+        let err = to_json_string("a = 1\nb = ", 2).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid TOML: "));
+        assert!(err.to_string().ends_with("(line 2, column 5)"));
+    }
+
+    #[test]
+    fn rejects_what_toml_cannot_hold() {
+        assert_eq!(
+            from_json_string(r#"{"a": {"b c": [1, null]}}"#)
+                .unwrap_err()
+                .to_string(),
+            r#"Cannot convert JSON: null at a."b c"[1] has no TOML equivalent"#
+        );
+        assert_eq!(
+            from_json_string("[1]").unwrap_err().to_string(),
+            "Cannot convert JSON: expected an object at the top level"
+        );
+        assert!(to_json_string("x = nan", 2).is_err());
+    }
+}
@@ -0,0 +1,299 @@
+use percent_encoding::{NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use url::form_urlencoded;
+
+use crate::ToolError;
+
+/// How arrays and objects are written in a query string.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ArrayStyle {
+    /// `a[]=1&a[]=2` for arrays and `o[k]=v` for objects, as PHP, Rails, and
+    /// the `qs` package read them.
+    #[default]
+    Brackets,
+    /// `a=1&a=2`, with keys taken as they are.
+    Repeat,
+}
+
+impl ArrayStyle {
+    pub const ALL: [Self; 2] = [Self::Brackets, Self::Repeat];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Brackets => "Brackets (a[]=1&a[]=2)",
+            Self::Repeat => "Repeated Keys (a=1&a=2)",
+        }
+    }
+}
+
+enum Segment<'a> {
+    Key(&'a str),
+    /// `[]`, which appends to an array.
+    Push,
+    Index(usize),
+}
+
+/// Percent-encodes everything but ASCII letters and digits.
+pub fn encode(value: &str) -> String {
+    utf8_percent_encode(value, NON_ALPHANUMERIC).to_string()
+}
+
+pub fn decode(value: &str) -> Result<String, ToolError> {
+    Ok(percent_decode_str(value).decode_utf8()?.to_string())
+}
+
+/// Splits a bracketed key like `a[b][]` into its segments. Keys that are not
+/// well formed are taken as they are.
+fn parse_key(key: &str) -> Vec<Segment<'_>> {
+    let Some(open) = key.find('[').filter(|&i| i > 0 && key.ends_with(']')) else {
+        return vec![Segment::Key(key)];
+    };
+    let mut segments = vec![Segment::Key(&key[..open])];
+    for inner in key[open + 1..key.len() - 1].split("][") {
+        if inner.contains(['[', ']']) {
+            return vec![Segment::Key(key)];
+        }
+        segments.push(match inner {
+            "" => Segment::Push,
+            inner => match inner.parse::<usize>() {
+                Ok(i) => Segment::Index(i),
+                Err(_) => Segment::Key(inner),
+            },
+        });
+    }
+    segments
+}
+
+fn insert(slot: &mut Value, path: &[Segment], value: String, key: &str) -> Result<(), ToolError> {
+    let conflict = || ToolError::new(format!("{} conflicts with an earlier key", key));
+    let Some((segment, rest)) = path.split_first() else {
+        // A key seen more than once collects its values into an array.
+        match slot {
+            Value::Null => *slot = Value::String(value),
+            Value::String(first) => {
+                *slot = Value::Array(vec![Value::String(std::mem::take(first)), value.into()])
+            }
+            Value::Array(items) => items.push(value.into()),
+            Value::Bool(_) | Value::Number(_) | Value::Object(_) => return Err(conflict()),
+        }
+        return Ok(());
+    };
+    match segment {
+        Segment::Key(name) => {
+            if slot.is_null() {
+                *slot = Value::Object(Map::new());
+            }
+            let Value::Object(map) = slot else {
+                return Err(conflict());
+            };
+            let child = map.entry(name.to_string()).or_insert(Value::Null);
+            insert(child, rest, value, key)
+        }
+        Segment::Push | Segment::Index(_) => {
+            if slot.is_null() {
+                *slot = Value::Array(Vec::new());
+            }
+            let Value::Array(items) = slot else {
+                return Err(conflict());
+            };
+            // Indexes past the end append, so sparse arrays are compacted.
+            let i = match segment {
+                Segment::Index(i) if *i < items.len() => *i,
+                _ => {
+                    items.push(Value::Null);
+                    items.len() - 1
+                }
+            };
+            insert(&mut items[i], rest, value, key)
+        }
+    }
+}
+
+fn decode_component(value: &str) -> Result<String, ToolError> {
+    decode(&value.replace('+', " "))
+}
+
+/// Converts a query string to a JSON object, with repeated keys collected
+/// into arrays. A leading URL up to the `?` and a trailing fragment are
+/// ignored. Values are always strings.
+pub fn query_to_json(query: &str, style: ArrayStyle, decoding: bool) -> Result<Value, ToolError> {
+    let query = query.trim();
+    let query = query.split_once('?').map_or(query, |(_, query)| query);
+    let query = query.split_once('#').map_or(query, |(query, _)| query);
+    let mut root = Value::Object(Map::new());
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let (key, value) = match decoding {
+            true => (decode_component(key)?, decode_component(value)?),
+            false => (key.to_string(), value.to_string()),
+        };
+        let path = match style {
+            ArrayStyle::Brackets => parse_key(&key),
+            ArrayStyle::Repeat => vec![Segment::Key(&key)],
+        };
+        insert(&mut root, &path, value, &key)?;
+    }
+    Ok(root)
+}
+
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => Some(String::new()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) => Some(s.clone()),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+fn flatten(
+    key: String,
+    value: &Value,
+    style: ArrayStyle,
+    pairs: &mut Vec<(String, String)>,
+) -> Result<(), ToolError> {
+    if let Some(value) = scalar(value) {
+        pairs.push((key, value));
+        return Ok(());
+    }
+    match (value, style) {
+        (Value::Array(items), ArrayStyle::Brackets) => {
+            // Arrays of scalars use `[]`, but arrays of arrays or objects
+            // need indexes to tell where one item ends and the next begins.
+            let indexed = items.iter().any(|item| scalar(item).is_none());
+            for (i, item) in items.iter().enumerate() {
+                let key = match indexed {
+                    true => format!("{}[{}]", key, i),
+                    false => format!("{}[]", key),
+                };
+                flatten(key, item, style, pairs)?;
+            }
+        }
+        (Value::Object(map), ArrayStyle::Brackets) => {
+            for (name, value) in map {
+                flatten(format!("{}[{}]", key, name), value, style, pairs)?;
+            }
+        }
+        (Value::Array(items), ArrayStyle::Repeat) => {
+            for item in items {
+                let Some(value) = scalar(item) else {
+                    return Err(ToolError::new(format!(
+                        "{} holds nested values, which need brackets",
+                        key
+                    )));
+                };
+                pairs.push((key.clone(), value));
+            }
+        }
+        _ => {
+            return Err(ToolError::new(format!(
+                "{} holds nested values, which need brackets",
+                key
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn encode_component(value: &str) -> String {
+    form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+/// Converts a JSON object to a query string. Brackets in keys are left
+/// unencoded so that the structure stays readable.
+pub fn json_to_query(json: &Value, style: ArrayStyle, encoding: bool) -> Result<String, ToolError> {
+    let Value::Object(map) = json else {
+        return Err(ToolError::new("expected an object at the top level"));
+    };
+    let mut pairs = Vec::new();
+    for (key, value) in map {
+        flatten(key.clone(), value, style, &mut pairs)?;
+    }
+    Ok(pairs
+        .iter()
+        .map(|(key, value)| match encoding {
+            true => {
+                let key = key
+                    .split_inclusive(['[', ']'])
+                    .map(|part| match part.strip_suffix(['[', ']']) {
+                        Some(name) => encode_component(name) + &part[name.len()..],
+                        None => encode_component(part),
+                    })
+                    .collect::<String>();
+                format!("{}={}", key, encode_component(value))
+            }
+            false => format!("{}={}", key, value),
+        })
+        .collect::<Vec<_>>()
+        .join("&"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_everything_but_letters_and_digits() {
+        assert_eq!(encode("a b&c=ü"), "a%20b%26c%3D%C3%BC");
+    }
+
+    #[test]
+    fn decodes_percent_escapes() {
+        assert_eq!(decode("a%20b%26c%3D%C3%BC").unwrap(), "a b&c=ü");
+    }
+
+    #[test]
+    fn rejects_escapes_that_are_not_utf8() {
+        assert!(decode("%FF").is_err());
+    }
+
+    #[test]
+    fn parses_bracketed_queries() {
+        let json = query_to_json(
+            "https://example.com/?a=1&b[]=2&b[]=3&o[k]=x+y&o[l][0][m]=%C3%BC&o[l][1][m]=z&a=4#top",
+            ArrayStyle::Brackets,
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "a": ["1", "4"],
+                "b": ["2", "3"],
+                "o": {"k": "x y", "l": [{"m": "ü"}, {"m": "z"}]}
+            })
+        );
+        assert_eq!(
+            json_to_query(&json, ArrayStyle::Brackets, true).unwrap(),
+            "a[]=1&a[]=4&b[]=2&b[]=3&o[k]=x+y&o[l][0][m]=%C3%BC&o[l][1][m]=z"
+        );
+    }
+
+    #[test]
+    fn parses_repeated_keys_literally() {
+        let json = query_to_json("?a=1&a=2&b[]=x%20y&c", ArrayStyle::Repeat, false).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"a": ["1", "2"], "b[]": "x%20y", "c": ""})
+        );
+        assert_eq!(
+            json_to_query(&json, ArrayStyle::Repeat, false).unwrap(),
+            "a=1&a=2&b[]=x%20y&c="
+        );
+    }
+
+    #[test]
+    fn rejects_structures_the_style_cannot_hold() {
+        assert!(query_to_json("a=1&a[k]=2", ArrayStyle::Brackets, true).is_err());
+        assert!(
+            json_to_query(
+                &serde_json::json!({"o": {"k": 1}}),
+                ArrayStyle::Repeat,
+                true
+            )
+            .is_err()
+        );
+        assert!(json_to_query(&serde_json::json!([1]), ArrayStyle::Brackets, true).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use roxmltree::{Document, ParsingOptions};
+
+use crate::ToolError;
+
+/// A piece of an XML document, borrowed as written so that prefixes, entity
+/// references and attribute quoting survive formatting untouched.
+#[derive(Clone, Copy)]
+enum Token<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    Empty(&'a str),
+    Text(&'a str),
+}
+
+/// Checks that the XML is well-formed, reporting where it is not.
+pub fn validate(source: &str) -> Result<(), ToolError> {
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+    Document::parse_with_options(source, options)
+        .map(|_| ())
+        .map_err(|err| ToolError::from(err).context("Malformed XML"))
+}
+
+/// Splits well-formed XML into tags and the text between them. Comments,
+/// processing instructions and the doctype come out as `Empty`, since they
+/// sit on a line of their own like self-closing tags, and CDATA sections come
+/// out as `Text`.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    while !rest.is_empty() {
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+            continue;
+        }
+
+        let end = if rest.starts_with("<!--") {
+            rest.find("-->").map(|i| i + 3)
+        } else if rest.starts_with("<![CDATA[") {
+            rest.find("]]>").map(|i| i + 3)
+        } else if rest.starts_with("<?") {
+            rest.find("?>").map(|i| i + 2)
+        } else {
+            tag_end(rest)
+        }
+        .unwrap_or(rest.len());
+        let tag = &rest[..end];
+        tokens.push(if tag.starts_with("<![CDATA[") {
+            Token::Text(tag)
+        } else if tag.starts_with("</") {
+            Token::Close(tag)
+        } else if tag.starts_with("<!") || tag.starts_with("<?") || tag.ends_with("/>") {
+            Token::Empty(tag)
+        } else {
+            Token::Open(tag)
+        });
+        rest = &rest[end..];
+    }
+    tokens
+}
+
+/// Finds the end of a tag, skipping over quoted attribute values and the
+/// doctype's internal subset, either of which may contain `>`.
+fn tag_end(source: &str) -> Option<usize> {
+    let mut quote = None;
+    let mut depth = 0;
+    for (i, c) in source.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth -= 1,
+            (None, '>') if depth == 0 => return Some(i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn format_pretty(tokens: &[Token], indent: &str) -> String {
+    let tokens: Vec<Token> = tokens
+        .iter()
+        .copied()
+        .filter(|token| !matches!(token, Token::Text(text) if text.trim().is_empty()))
+        .collect();
+    let mut lines = Vec::new();
+    let mut depth = 0;
+    let mut i = 0;
+    while i < tokens.len() {
+        let line = match (tokens[i], tokens.get(i + 1), tokens.get(i + 2)) {
+            // Elements holding nothing but text stay on one line.
+            (Token::Open(open), Some(Token::Text(text)), Some(Token::Close(close))) => {
+                i += 2;
+                format!("{}{}{}", open, text.trim(), close)
+            }
+            (Token::Open(open), Some(Token::Close(close)), _) => {
+                i += 1;
+                format!("{}{}", open, close)
+            }
+            (Token::Open(open), _, _) => {
+                depth += 1;
+                lines.push(format!("{}{}", indent.repeat(depth - 1), open));
+                i += 1;
+                continue;
+            }
+            (Token::Close(close), _, _) => {
+                depth = depth.saturating_sub(1);
+                close.to_string()
+            }
+            (Token::Empty(tag), _, _) => tag.to_string(),
+            (Token::Text(text), _, _) => text.trim().to_string(),
+        };
+        lines.push(format!("{}{}", indent.repeat(depth), line));
+        i += 1;
+    }
+    lines.join("\n")
+}
+
+fn format_compact(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::Open(tag) | Token::Close(tag) | Token::Empty(tag) => *tag,
+            Token::Text(text) if text.trim().is_empty() => "",
+            Token::Text(text) => text,
+        })
+        .collect()
+}
+
+/// Pretty-prints XML, indenting each level by `indentation_size` spaces, if
+/// it is well-formed.
+pub fn format(source: &str, indentation_size: usize) -> Result<String, ToolError> {
+    validate(source)?;
+    Ok(format_pretty(
+        &tokenize(source),
+        &" ".repeat(indentation_size),
+    ))
+}
+
+pub fn compact(source: &str) -> Result<String, ToolError> {
+    validate(source)?;
+    Ok(format_compact(&tokenize(source)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_nested_elements() {
+        assert_eq!(
+            format("<a><b>x</b><c/></a>", 2).unwrap(),
+            "<a>\n  <b>x</b>\n  <c/>\n</a>"
+        );
+    }
+
+    #[test]
+    fn compacts_whitespace_between_tags() {
+        assert_eq!(compact("<a>\n  <b>x</b>\n</a>").unwrap(), "<a><b>x</b></a>");
+    }
+
+    #[test]
+    fn keeps_quoted_greater_than_signs_in_tags() {
+        assert_eq!(
+            format(r#"<a title="1 > 0"><b/></a>"#, 2).unwrap(),
+            "<a title=\"1 > 0\">\n  <b/>\n</a>"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_xml() {
+        let err = format("<a><b></a>", 2).unwrap_err();
+        assert!(err.to_string().starts_with("Malformed XML"));
+    }
+}
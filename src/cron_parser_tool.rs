@@ -0,0 +1,745 @@
+use std::collections::BTreeSet;
+
+use chrono::{
+    DateTime, Datelike, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+    Utc,
+};
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, StatefulInteractiveElement, Styled,
+    Subscription, Window, div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, StyledExt,
+    button::{Button, DropdownButton},
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const ORDINALS: [&str; 5] = ["first", "second", "third", "fourth", "fifth"];
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Zone {
+    Local,
+    Utc,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = date_time_tools, no_json)]
+pub struct SetZone(Zone);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = date_time_tools, no_json)]
+pub struct SetCount(usize);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    zone: Zone,
+    count: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Second,
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+    Year,
+}
+
+impl FieldKind {
+    fn name(self) -> &'static str {
+        match self {
+            FieldKind::Second => "Second",
+            FieldKind::Minute => "Minute",
+            FieldKind::Hour => "Hour",
+            FieldKind::DayOfMonth => "Day of Month",
+            FieldKind::Month => "Month",
+            FieldKind::DayOfWeek => "Day of Week",
+            FieldKind::Year => "Year",
+        }
+    }
+
+    fn unit(self) -> &'static str {
+        match self {
+            FieldKind::Second => "second",
+            FieldKind::Minute => "minute",
+            FieldKind::Hour => "hour",
+            FieldKind::DayOfMonth | FieldKind::DayOfWeek => "day",
+            FieldKind::Month => "month",
+            FieldKind::Year => "year",
+        }
+    }
+
+    /// The values `*` stands for. Day of week also accepts 7 for Sunday.
+    fn range(self) -> (u32, u32) {
+        match self {
+            FieldKind::Second | FieldKind::Minute => (0, 59),
+            FieldKind::Hour => (0, 23),
+            FieldKind::DayOfMonth => (1, 31),
+            FieldKind::Month => (1, 12),
+            FieldKind::DayOfWeek => (0, 6),
+            FieldKind::Year => (1970, 2199),
+        }
+    }
+
+    fn parse_value(self, value: &str) -> Result<u32, String> {
+        let names: &[&str] = match self {
+            FieldKind::Month => &MONTHS,
+            FieldKind::DayOfWeek => &WEEKDAYS,
+            _ => &[],
+        };
+        let first = if self == FieldKind::Month { 1 } else { 0 };
+        let parsed = match names
+            .iter()
+            .position(|name| value.len() == 3 && name[..3].eq_ignore_ascii_case(value))
+        {
+            Some(i) => i as u32 + first,
+            None => value
+                .parse()
+                .map_err(|_| format!("{:?} is not a valid value", value))?,
+        };
+        let (min, max) = self.range();
+        let max = if self == FieldKind::DayOfWeek { 7 } else { max };
+        if parsed < min || parsed > max {
+            return Err(format!("{} is out of range {}-{}", parsed, min, max));
+        }
+        Ok(parsed)
+    }
+
+    fn label(self, value: u32) -> String {
+        match self {
+            FieldKind::Month => MONTHS[value as usize - 1].to_string(),
+            FieldKind::DayOfWeek => WEEKDAYS[value as usize % 7].to_string(),
+            FieldKind::Year => value.to_string(),
+            _ => format!("{} {}", self.unit(), value),
+        }
+    }
+
+    fn describe_any(self) -> &'static str {
+        match self {
+            FieldKind::Second => "every second",
+            FieldKind::Minute => "every minute",
+            FieldKind::Hour => "every hour",
+            FieldKind::DayOfMonth => "every day",
+            FieldKind::Month => "every month",
+            FieldKind::DayOfWeek => "any day of the week",
+            FieldKind::Year => "every year",
+        }
+    }
+
+    fn describe_range(self, start: u32, end: u32) -> String {
+        match self {
+            FieldKind::Month | FieldKind::DayOfWeek => {
+                format!("{} through {}", self.label(start), self.label(end))
+            }
+            _ => format!("{}s {} through {}", self.unit(), start, end),
+        }
+    }
+}
+
+/// The values one field of a cron expression allows, along with the special
+/// day forms that cannot be listed up front.
+struct Field {
+    values: BTreeSet<u32>,
+    any: bool,
+    last_day: bool,
+    nth_weekdays: Vec<(u32, u32)>,
+    last_weekdays: Vec<u32>,
+    description: String,
+}
+
+impl Field {
+    fn parse(kind: FieldKind, text: &str) -> Result<Self, String> {
+        let mut field = Field {
+            values: BTreeSet::new(),
+            any: text == "*" || text == "?",
+            last_day: false,
+            nth_weekdays: Vec::new(),
+            last_weekdays: Vec::new(),
+            description: String::new(),
+        };
+        let mut descriptions = Vec::new();
+
+        for part in text.split(',') {
+            if part.is_empty() {
+                return Err("empty list item".to_string());
+            }
+            if kind == FieldKind::DayOfMonth && part.eq_ignore_ascii_case("L") {
+                field.last_day = true;
+                descriptions.push("the last day of the month".to_string());
+                continue;
+            }
+            if kind == FieldKind::DayOfWeek {
+                if let Some((day, nth)) = part.split_once('#') {
+                    let day = kind.parse_value(day)? % 7;
+                    let nth = nth
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|nth| (1..=5).contains(nth))
+                        .ok_or_else(|| format!("{:?} is not a week of the month", nth))?;
+                    field.nth_weekdays.push((day, nth));
+                    descriptions.push(format!(
+                        "the {} {} of the month",
+                        ORDINALS[nth as usize - 1],
+                        kind.label(day)
+                    ));
+                    continue;
+                }
+                if let Some(day) = part.strip_suffix(['L', 'l']).filter(|day| !day.is_empty()) {
+                    let day = kind.parse_value(day)? % 7;
+                    field.last_weekdays.push(day);
+                    descriptions.push(format!("the last {} of the month", kind.label(day)));
+                    continue;
+                }
+            }
+
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => {
+                    let step = step
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|step| *step > 0)
+                        .ok_or_else(|| format!("{:?} is not a valid step", step))?;
+                    (range, Some(step))
+                }
+                None => (part, None),
+            };
+            let (min, max) = kind.range();
+            let (start, end, description) = if range == "*" || range == "?" {
+                if range == "?" && !matches!(kind, FieldKind::DayOfMonth | FieldKind::DayOfWeek) {
+                    return Err("\"?\" is only allowed in the day fields".to_string());
+                }
+                let description = match step {
+                    Some(step) => format!("every {} {}s", step, kind.unit()),
+                    None => kind.describe_any().to_string(),
+                };
+                (min, max, description)
+            } else if let Some((start, end)) = range.split_once('-') {
+                let (start, end) = (kind.parse_value(start)?, kind.parse_value(end)?);
+                let description = match step {
+                    Some(step) => format!(
+                        "every {} {}s, {}",
+                        step,
+                        kind.unit(),
+                        kind.describe_range(start, end)
+                    ),
+                    None => kind.describe_range(start, end),
+                };
+                (start, end, description)
+            } else {
+                let start = kind.parse_value(range)?;
+                match step {
+                    Some(step) => (
+                        start,
+                        max,
+                        format!(
+                            "every {} {}s starting at {}",
+                            step,
+                            kind.unit(),
+                            kind.label(start)
+                        ),
+                    ),
+                    None => (start, start, kind.label(start)),
+                }
+            };
+            if start > end {
+                return Err(format!("range {:?} starts after it ends", range));
+            }
+            for value in (start..=end).step_by(step.unwrap_or(1) as usize) {
+                field.values.insert(if kind == FieldKind::DayOfWeek {
+                    value % 7
+                } else {
+                    value
+                });
+            }
+            descriptions.push(description);
+        }
+
+        field.description = descriptions.join(", ");
+        Ok(field)
+    }
+
+    fn fixed(value: u32) -> Self {
+        Field {
+            values: BTreeSet::from([value]),
+            any: false,
+            last_day: false,
+            nth_weekdays: Vec::new(),
+            last_weekdays: Vec::new(),
+            description: String::new(),
+        }
+    }
+
+    fn contains(&self, value: u32) -> bool {
+        self.any || self.values.contains(&value)
+    }
+}
+
+/// A parsed cron expression. Five fields are standard cron; six add seconds
+/// in front and seven add a year at the end, as in Quartz.
+struct Schedule {
+    second: Field,
+    minute: Field,
+    hour: Field,
+    day_of_month: Field,
+    month: Field,
+    day_of_week: Field,
+    year: Field,
+    fields: Vec<(FieldKind, String, String)>,
+}
+
+impl Schedule {
+    fn parse(expression: &str) -> Result<Self, ToolError> {
+        let expression = expression.trim();
+        let expression = match expression.to_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            "@reboot" => return Err(ToolError::new("@reboot runs at startup, not on a schedule")),
+            _ => expression,
+        };
+
+        use FieldKind::*;
+        let parts: Vec<&str> = expression.split_whitespace().collect();
+        let kinds: &[FieldKind] = match parts.len() {
+            5 => &[Minute, Hour, DayOfMonth, Month, DayOfWeek],
+            6 => &[Second, Minute, Hour, DayOfMonth, Month, DayOfWeek],
+            7 => &[Second, Minute, Hour, DayOfMonth, Month, DayOfWeek, Year],
+            n => {
+                return Err(ToolError::new(format!(
+                    "expected 5, 6, or 7 fields but found {}",
+                    n
+                )));
+            }
+        };
+
+        let mut schedule = Schedule {
+            second: Field::fixed(0),
+            minute: Field::fixed(0),
+            hour: Field::fixed(0),
+            day_of_month: Field::fixed(1),
+            month: Field::fixed(1),
+            day_of_week: Field::fixed(0),
+            year: Field::parse(Year, "*").map_err(ToolError::new)?,
+            fields: Vec::new(),
+        };
+        for (kind, text) in kinds.iter().zip(parts) {
+            let field = Field::parse(*kind, text)
+                .map_err(|err| ToolError::new(err).context(kind.name()))?;
+            schedule
+                .fields
+                .push((*kind, text.to_string(), field.description.clone()));
+            *match kind {
+                Second => &mut schedule.second,
+                Minute => &mut schedule.minute,
+                Hour => &mut schedule.hour,
+                DayOfMonth => &mut schedule.day_of_month,
+                Month => &mut schedule.month,
+                DayOfWeek => &mut schedule.day_of_week,
+                Year => &mut schedule.year,
+            } = field;
+        }
+        Ok(schedule)
+    }
+
+    /// Whether both day fields are restricted, in which case a day matches
+    /// when either of them does.
+    fn either_day(&self) -> bool {
+        !self.day_of_month.any && !self.day_of_week.any
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day = date.day();
+        let last_day = last_day_of_month(date);
+        let weekday = date.weekday().num_days_from_sunday();
+
+        let day_of_month =
+            self.day_of_month.contains(day) || (self.day_of_month.last_day && day == last_day);
+        let day_of_week = self.day_of_week.contains(weekday)
+            || self
+                .day_of_week
+                .nth_weekdays
+                .iter()
+                .any(|&(d, nth)| d == weekday && (day - 1) / 7 + 1 == nth)
+            || self
+                .day_of_week
+                .last_weekdays
+                .iter()
+                .any(|&d| d == weekday && day + 7 > last_day);
+
+        if self.either_day() {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        }
+    }
+
+    /// Finds the first time after `after` that the schedule allows, giving up
+    /// two centuries on.
+    fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut t = after.with_nanosecond(0)? + Duration::seconds(1);
+        let limit = after.year() + 200;
+        while t.year() <= limit {
+            if !self.year.contains(t.year() as u32) {
+                t = NaiveDate::from_ymd_opt(t.year() + 1, 1, 1)?.and_hms_opt(0, 0, 0)?;
+            } else if !self.month.contains(t.month()) {
+                let (year, month) = if t.month() == 12 {
+                    (t.year() + 1, 1)
+                } else {
+                    (t.year(), t.month() + 1)
+                };
+                t = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+            } else if !self.matches_day(t.date()) {
+                t = t.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+            } else if !self.hour.contains(t.hour()) {
+                t = t.with_minute(0)?.with_second(0)? + Duration::hours(1);
+            } else if !self.minute.contains(t.minute()) {
+                t = t.with_second(0)? + Duration::minutes(1);
+            } else if !self.second.contains(t.second()) {
+                t += Duration::seconds(1);
+            } else {
+                return Some(t);
+            }
+        }
+        None
+    }
+
+    /// Lists the next `count` times in `tz`. Times that fall in a DST gap are
+    /// skipped, and the earlier of two ambiguous times is used.
+    fn occurrences<Tz: TimeZone>(&self, now: DateTime<Tz>, count: usize) -> Vec<DateTime<Tz>> {
+        let tz = now.timezone();
+        let mut occurrences = Vec::new();
+        let mut t = now.naive_local();
+        while occurrences.len() < count {
+            let Some(next) = self.next_after(t) else {
+                break;
+            };
+            match tz.from_local_datetime(&next) {
+                LocalResult::Single(at) | LocalResult::Ambiguous(at, _) => occurrences.push(at),
+                LocalResult::None => {}
+            }
+            t = next;
+        }
+        occurrences
+    }
+}
+
+fn last_day_of_month(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|date| date.pred_opt())
+        .map(|date| date.day())
+        .unwrap_or(31)
+}
+
+pub struct CronParserTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    zone: Zone,
+    count: usize,
+    fields: Vec<(FieldKind, String, String)>,
+    either_day: bool,
+    occurrences: Vec<(String, String)>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl CronParserTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("Cron Expression, e.g. */15 9-17 * * MON-FRI")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&input, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            zone: Zone::Local,
+            count: 10,
+            fields: Vec::new(),
+            either_day: false,
+            occurrences: Vec::new(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn update_schedule(&mut self, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        self.fields.clear();
+        self.either_day = false;
+        self.occurrences.clear();
+        self.error = None;
+        if !value.trim().is_empty() {
+            match Schedule::parse(&value) {
+                Ok(schedule) => {
+                    let occurrences = match self.zone {
+                        Zone::Local => schedule
+                            .occurrences(Local::now(), self.count)
+                            .into_iter()
+                            .map(|at| at.with_timezone(&Utc))
+                            .collect(),
+                        Zone::Utc => schedule.occurrences(Utc::now(), self.count),
+                    };
+                    self.occurrences = occurrences
+                        .into_iter()
+                        .map(|at| {
+                            (
+                                at.with_timezone(&Local)
+                                    .format("%a %Y-%m-%d %H:%M:%S %:z")
+                                    .to_string(),
+                                at.format("%a %Y-%m-%d %H:%M:%S UTC").to_string(),
+                            )
+                        })
+                        .collect();
+                    self.either_day = schedule.either_day();
+                    self.fields = schedule.fields;
+                }
+                Err(err) => self.error = Some(err.context("Invalid cron expression")),
+            }
+        }
+        cx.notify();
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_schedule(cx);
+        }
+    }
+
+    fn on_refresh_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.update_schedule(cx);
+    }
+
+    fn on_action_set_zone(&mut self, action: &SetZone, _: &mut Window, cx: &mut Context<Self>) {
+        self.zone = action.0;
+        self.update_schedule(cx);
+    }
+
+    fn on_action_set_count(&mut self, action: &SetCount, _: &mut Window, cx: &mut Context<Self>) {
+        self.count = action.0;
+        self.update_schedule(cx);
+    }
+}
+
+impl Tool for CronParserTool {
+    fn title() -> &'static str {
+        "Cron Expression Parser"
+    }
+
+    fn short_title() -> &'static str {
+        "Cron"
+    }
+
+    fn description() -> &'static str {
+        "Explains cron expressions field by field and lists when they next run."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_refresh_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            zone: self.zone,
+            count: self.count,
+            input: include_input.then(|| self.input.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.zone = preset.zone;
+        self.count = preset.count;
+        match preset.input {
+            Some(input) => self.input.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            }),
+            None => self.update_schedule(cx),
+        }
+    }
+}
+
+impl Focusable for CronParserTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CronParserTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let zone = self.zone;
+        let count = self.count;
+        let muted = cx.theme().muted_foreground;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_zone))
+            .on_action(cx.listener(Self::on_action_set_count))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex().gap_2().child(
+                    DropdownButton::new("refresh-dropdown-button")
+                        .button(
+                            Button::new("refresh-button")
+                                .label(match zone {
+                                    Zone::Local => "Refresh (Local)",
+                                    Zone::Utc => "Refresh (UTC)",
+                                })
+                                .on_click(cx.listener(Self::on_refresh_click)),
+                        )
+                        .popup_menu(move |this, _, _| {
+                            let this = this
+                                .label("Schedule Time Zone")
+                                .menu_with_check(
+                                    "Local",
+                                    zone == Zone::Local,
+                                    Box::new(SetZone(Zone::Local)),
+                                )
+                                .menu_with_check(
+                                    "UTC",
+                                    zone == Zone::Utc,
+                                    Box::new(SetZone(Zone::Utc)),
+                                )
+                                .separator()
+                                .label("Occurrences");
+                            [5, 10, 25, 50].into_iter().fold(this, |this, n| {
+                                this.menu_with_check(
+                                    format!("Next {}", n),
+                                    count == n,
+                                    Box::new(SetCount(n)),
+                                )
+                            })
+                        }),
+                ),
+            )
+            .child(
+                TextInput::new(&self.input)
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(Label::new("Fields"))
+            .child(
+                div()
+                    .v_flex()
+                    .gap_1()
+                    .children(self.fields.iter().map(|(kind, text, description)| {
+                        h_flex()
+                            .gap_2()
+                            .child(div().w(px(120.)).text_color(muted).child(kind.name()))
+                            .child(
+                                div()
+                                    .w(px(160.))
+                                    .font_family(editor_font(cx))
+                                    .child(text.clone()),
+                            )
+                            .child(description.clone())
+                    }))
+                    .when(self.either_day, |this| {
+                        this.child(div().text_color(muted).child(
+                            "Both day fields are set, so it runs on days matching either of them.",
+                        ))
+                    }),
+            )
+            .child(Label::new("Next Occurrences"))
+            .child(
+                div()
+                    .id("occurrences")
+                    .v_flex()
+                    .flex_1()
+                    .gap_1()
+                    .overflow_y_scroll()
+                    .children(
+                        self.occurrences
+                            .iter()
+                            .enumerate()
+                            .map(|(i, (local, utc))| {
+                                h_flex()
+                                    .gap_4()
+                                    .font_family(editor_font(cx))
+                                    .child(div().w(px(320.)).child(local.clone()))
+                                    .child(div().w(px(280.)).text_color(muted).child(utc.clone()))
+                                    .child(
+                                        Clipboard::new(SharedString::from(format!(
+                                            "occurrence-clipboard-{}",
+                                            i
+                                        )))
+                                        .value(local.clone()),
+                                    )
+                            }),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,459 @@
+use std::sync::Arc;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, Image,
+    InteractiveElement, ObjectFit, ParentElement, Render, StatefulInteractiveElement, Styled,
+    StyledImage, Subscription, Window, div, img, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, IconName, Sizable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    clipboard::Clipboard,
+    color_picker::{ColorPicker, ColorPickerEvent, ColorPickerState},
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+    slider::{Slider, SliderEvent, SliderState},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    color_preview::{from_hsla, to_hsla},
+    core::{
+        base64::ImageType,
+        color,
+        gradient::{Gradient, Kind, Stop},
+    },
+    editor_font,
+    image_preview::preview_image,
+};
+
+/// The size the preview is drawn at before it is stretched to fit.
+const PREVIEW_SIZE: (u32, u32) = (960, 240);
+
+const DEFAULT_STOPS: [(&str, f32); 2] = [("#6366f1", 0.), ("#ec4899", 100.)];
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = css_tools, no_json)]
+pub struct SetKind(Kind);
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    kind: Kind,
+    angle: f32,
+    stops: Vec<(String, f32)>,
+}
+
+/// The inputs that edit one color stop.
+struct StopRow {
+    color: Entity<InputState>,
+    picker: Entity<ColorPickerState>,
+    position: Entity<SliderState>,
+    _subscriptions: Vec<Subscription>,
+}
+
+pub struct CSSGradientTool {
+    focus_handle: FocusHandle,
+    kind: Kind,
+    angle: Entity<SliderState>,
+    stops: Vec<StopRow>,
+    css: Entity<InputState>,
+    tailwind: Entity<InputState>,
+    preview: Option<Arc<Image>>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl CSSGradientTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let angle = cx.new(|_| {
+            SliderState::new()
+                .min(0.)
+                .max(360.)
+                .step(1.)
+                .default_value(90.)
+        });
+        let css = cx.new(|cx| InputState::new(window, cx));
+        let tailwind = cx.new(|cx| InputState::new(window, cx));
+        let _subscriptions = vec![cx.subscribe_in(&angle, window, Self::on_slider_event)];
+
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            kind: Kind::default(),
+            angle,
+            stops: Vec::new(),
+            css,
+            tailwind,
+            preview: None,
+            error: None,
+            _subscriptions,
+        };
+        for (color, position) in DEFAULT_STOPS {
+            this.push_stop(color.to_string(), position, window, cx);
+        }
+        this.update_output(window, cx);
+        this
+    }
+
+    fn push_stop(
+        &mut self,
+        value: String,
+        position: f32,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let color = cx.new(|cx| InputState::new(window, cx).default_value(value));
+        let picker = cx.new(|cx| ColorPickerState::new(window, cx));
+        let position = cx.new(|_| {
+            SliderState::new()
+                .min(0.)
+                .max(100.)
+                .step(1.)
+                .default_value(position)
+        });
+        let input = color.clone();
+        let _subscriptions = vec![
+            cx.subscribe_in(&color, window, Self::on_input_event),
+            cx.subscribe_in(&position, window, Self::on_slider_event),
+            cx.subscribe_in(&picker, window, move |_, _, e, window, cx| {
+                if let ColorPickerEvent::Change(Some(color)) = e {
+                    let hex = from_hsla(*color).to_hex();
+                    input.update(cx, |state, cx| {
+                        state.set_value(hex, window, cx);
+                    });
+                }
+            }),
+        ];
+        self.stops.push(StopRow {
+            color,
+            picker,
+            position,
+            _subscriptions,
+        });
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_output(window, cx);
+        }
+    }
+
+    fn on_slider_event(
+        &mut self,
+        _: &Entity<SliderState>,
+        e: &SliderEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let SliderEvent::Change(_) = e;
+        self.update_output(window, cx);
+    }
+
+    fn gradient(&self, cx: &App) -> Result<Gradient, ToolError> {
+        let stops = self
+            .stops
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let color = color::parse(&row.color.read(cx).value())
+                    .map_err(|err| err.context(&format!("Invalid color in stop {}", i + 1)))?;
+                Ok(Stop {
+                    color,
+                    position: row.position.read(cx).value().end(),
+                })
+            })
+            .collect::<Result<_, ToolError>>()?;
+        Ok(Gradient {
+            kind: self.kind,
+            angle: self.angle.read(cx).value().end(),
+            stops,
+        })
+    }
+
+    fn update_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let (css, tailwind) = match self.gradient(cx) {
+            Ok(gradient) => {
+                for (row, stop) in self.stops.iter().zip(&gradient.stops) {
+                    row.picker.update(cx, |state, cx| {
+                        state.set_value(to_hsla(stop.color), window, cx);
+                    });
+                }
+                let (width, height) = PREVIEW_SIZE;
+                let svg = gradient.to_svg(width, height);
+                self.preview = Some(preview_image(ImageType::Svg, svg.into_bytes()));
+                self.error = None;
+                (gradient.to_css(), gradient.to_tailwind())
+            }
+            Err(err) => {
+                self.error = Some(err);
+                (String::new(), String::new())
+            }
+        };
+        self.css.update(cx, |state, cx| {
+            state.set_value(css, window, cx);
+        });
+        self.tailwind.update(cx, |state, cx| {
+            state.set_value(tailwind, window, cx);
+        });
+        cx.notify();
+    }
+
+    /// Adds a stop halfway along the widest gap between stops, in the color
+    /// of the stop before it.
+    fn on_add_stop_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let mut stops = self
+            .stops
+            .iter()
+            .map(|row| {
+                (
+                    row.position.read(cx).value().end(),
+                    row.color.read(cx).value().to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let (position, color) = stops
+            .windows(2)
+            .max_by(|a, b| (a[1].0 - a[0].0).total_cmp(&(b[1].0 - b[0].0)))
+            .map_or((50., "#ffffff".to_string()), |pair| {
+                (((pair[0].0 + pair[1].0) / 2.).round(), pair[0].1.clone())
+            });
+        self.push_stop(color, position, window, cx);
+        self.update_output(window, cx);
+    }
+
+    fn on_remove_stop_click(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        if self.stops.len() > 2 {
+            self.stops.remove(ix);
+            self.update_output(window, cx);
+        }
+    }
+
+    /// Mirrors the stops, so that the gradient runs the other way.
+    fn on_reverse_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        for row in &self.stops {
+            let position = 100. - row.position.read(cx).value().end();
+            row.position.update(cx, |state, cx| {
+                state.set_value(position, window, cx);
+            });
+        }
+        self.update_output(window, cx);
+    }
+
+    fn on_action_set_kind(
+        &mut self,
+        action: &SetKind,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.kind = action.0;
+        self.update_output(window, cx);
+    }
+}
+
+impl Tool for CSSGradientTool {
+    fn title() -> &'static str {
+        "CSS Gradient Generator"
+    }
+
+    fn short_title() -> &'static str {
+        "Gradient"
+    }
+
+    fn description() -> &'static str {
+        "Builds linear and radial CSS gradients from color stops, with a live preview and Tailwind classes."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        let state = State {
+            kind: self.kind,
+            angle: self.angle.read(cx).value().end(),
+            stops: self
+                .stops
+                .iter()
+                .map(|row| {
+                    (
+                        row.color.read(cx).value().to_string(),
+                        row.position.read(cx).value().end(),
+                    )
+                })
+                .collect(),
+        };
+        serde_json::to_value(state).ok()
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(state) = State::deserialize(state) else {
+            return;
+        };
+        if state.stops.len() < 2 {
+            return;
+        }
+        self.kind = state.kind;
+        self.angle.update(cx, |slider, cx| {
+            slider.set_value(state.angle, window, cx);
+        });
+        self.stops.clear();
+        for (color, position) in state.stops {
+            self.push_stop(color, position, window, cx);
+        }
+        self.update_output(window, cx);
+    }
+}
+
+impl Focusable for CSSGradientTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CSSGradientTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let kind = self.kind;
+        let font = editor_font(cx);
+        let border = cx.theme().border;
+        let css = self.css.read(cx).value().clone();
+        let tailwind = self.tailwind.read(cx).value().clone();
+        let removable = self.stops.len() > 2;
+        let angle = self.angle.read(cx).value().end();
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_kind))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("add-stop-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("add-stop-button")
+                                    .label("Add Stop")
+                                    .primary()
+                                    .on_click(cx.listener(Self::on_add_stop_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                Kind::ALL
+                                    .into_iter()
+                                    .fold(this.label("Type"), |this, item| {
+                                        this.menu_with_check(
+                                            item.name(),
+                                            kind == item,
+                                            Box::new(SetKind(item)),
+                                        )
+                                    })
+                            }),
+                    )
+                    .child(
+                        Button::new("reverse-button")
+                            .label("Reverse")
+                            .on_click(cx.listener(Self::on_reverse_click)),
+                    ),
+            )
+            .child(
+                div()
+                    .h(px(200.))
+                    .flex_none()
+                    .rounded(cx.theme().radius)
+                    .border_1()
+                    .border_color(border)
+                    .overflow_hidden()
+                    .when_some(self.preview.clone(), |this, preview| {
+                        this.child(img(preview).size_full().object_fit(ObjectFit::Fill))
+                    }),
+            )
+            .when(kind == Kind::Linear, |this| {
+                this.child(
+                    h_flex()
+                        .gap_4()
+                        .child(Label::new(format!("Angle: {}°", angle)).w(px(160.)))
+                        .child(div().flex_1().child(Slider::new(&self.angle))),
+                )
+            })
+            .child(
+                div()
+                    .id("stops")
+                    .v_flex()
+                    .flex_1()
+                    .gap_2()
+                    .mt_2()
+                    .overflow_y_scroll()
+                    .children(self.stops.iter().enumerate().map(|(ix, row)| {
+                        let position = row.position.read(cx).value().end();
+                        h_flex()
+                            .gap_4()
+                            .child(ColorPicker::new(&row.picker).small())
+                            .child(
+                                div().w(px(160.)).flex_none().child(
+                                    TextInput::new(&row.color)
+                                        .font_family(font.clone())
+                                        .focus_bordered(false),
+                                ),
+                            )
+                            .child(div().flex_1().child(Slider::new(&row.position)))
+                            .child(Label::new(format!("{}%", position)).w(px(48.)))
+                            .child(
+                                Button::new(("remove-stop-button", ix))
+                                    .icon(IconName::Close)
+                                    .ghost()
+                                    .small()
+                                    .disabled(!removable)
+                                    .on_click(cx.listener(
+                                        move |this, _: &ClickEvent, window, cx| {
+                                            this.on_remove_stop_click(ix, window, cx)
+                                        },
+                                    )),
+                            )
+                    })),
+            )
+            .child(
+                h_flex().gap_2().child(Label::new("CSS").w(px(160.))).child(
+                    TextInput::new(&self.css)
+                        .font_family(font.clone())
+                        .text_size(px(15.))
+                        .focus_bordered(false)
+                        .suffix(Clipboard::new("css-clipboard").value(css)),
+                ),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Label::new("Tailwind").w(px(160.)))
+                    .child(
+                        TextInput::new(&self.tailwind)
+                            .font_family(font)
+                            .text_size(px(15.))
+                            .focus_bordered(false)
+                            .suffix(Clipboard::new("tailwind-clipboard").value(tailwind)),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,496 @@
+use std::ops::Range;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    HighlightStyle, InteractiveElement, ParentElement, Render, SharedString,
+    StatefulInteractiveElement, Styled, StyledText, Subscription, Window, div,
+    prelude::FluentBuilder, px, rems,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, Size, StyleSized, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use html_escape::{encode_double_quoted_attribute, encode_text};
+use scraper::{ElementRef, Html, Node, Selector};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum MatchOutput {
+    OuterHTML,
+    InnerHTML,
+    Text,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = html_tools, no_json)]
+pub struct SetMatchOutput(MatchOutput);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    match_output: MatchOutput,
+    selector: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+pub struct CSSSelectorTesterTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    selector: Entity<InputState>,
+    results: Entity<InputState>,
+    match_output: MatchOutput,
+    match_count: Option<usize>,
+    highlighted_text: String,
+    highlighted_ranges: Vec<Range<usize>>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl CSSSelectorTesterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Html.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("HTML Source")
+        });
+        let selector = cx.new(|cx| InputState::new(window, cx).placeholder("CSS Selector"));
+        let results = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Html.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Matches")
+        });
+        let _subscriptions = vec![
+            cx.subscribe_in(&selector, window, |this, _, e, window, cx| {
+                if let InputEvent::PressEnter { .. } = e {
+                    this.test(window, cx);
+                }
+            }),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            selector,
+            results,
+            match_output: MatchOutput::OuterHTML,
+            match_count: None,
+            highlighted_text: String::new(),
+            highlighted_ranges: Vec::new(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn test(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let source = self.editor.read(cx).value().to_string();
+        let selector = self.selector.read(cx).value().to_string();
+        if source.is_empty() || selector.trim().is_empty() {
+            self.error = None;
+            return;
+        }
+
+        let results_value = match Selector::parse(&selector) {
+            Ok(selector) => {
+                self.error = None;
+                let document = parse_html(&source);
+                let matches: Vec<ElementRef> = document.select(&selector).collect();
+
+                let mut serialized = String::with_capacity(source.len());
+                let mut ranges = Vec::new();
+                serialize_document(&document, &matches, &mut serialized, &mut ranges);
+
+                self.match_count = Some(matches.len());
+                self.highlighted_text = serialized;
+                self.highlighted_ranges = ranges;
+                matches
+                    .iter()
+                    .map(|element| match self.match_output {
+                        MatchOutput::OuterHTML => element.html(),
+                        MatchOutput::InnerHTML => element.inner_html(),
+                        MatchOutput::Text => element.text().collect::<String>().trim().to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            }
+            Err(err) => {
+                self.match_count = None;
+                self.highlighted_text = String::new();
+                self.highlighted_ranges = Vec::new();
+                self.error = Some(ToolError::new(err.to_string()).context("Invalid selector"));
+                String::new()
+            }
+        };
+
+        self.results.update(cx, |state, cx| {
+            state.set_value(SharedString::from(results_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_test_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.test(window, cx);
+    }
+
+    fn on_edit_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.highlighted_text = String::new();
+        self.highlighted_ranges = Vec::new();
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.highlighted_text = String::new();
+            self.highlighted_ranges = Vec::new();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_results_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.results.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_match_output(
+        &mut self,
+        action: &SetMatchOutput,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.match_output = action.0;
+        if self.match_count.is_some() {
+            self.test(window, cx);
+        }
+        cx.notify();
+    }
+}
+
+fn parse_html(source: &str) -> Html {
+    let lowercase = source.to_lowercase();
+    if lowercase.contains("<html") || lowercase.contains("<!doctype") {
+        Html::parse_document(source)
+    } else {
+        Html::parse_fragment(source)
+    }
+}
+
+/// Re-serializes the parsed document, recording where each matched element
+/// ends up. html5ever does not keep source positions, so highlights are
+/// shown against this normalized markup rather than the pasted text.
+fn serialize_document(
+    document: &Html,
+    matches: &[ElementRef],
+    out: &mut String,
+    ranges: &mut Vec<Range<usize>>,
+) {
+    for child in document.tree.root().children() {
+        match child.value() {
+            Node::Doctype(doctype) => {
+                out.push_str(&format!("<!DOCTYPE {}>\n", doctype.name()));
+            }
+            Node::Element(_) => {
+                if let Some(element) = ElementRef::wrap(child) {
+                    if document.tree.root().value().is_fragment() {
+                        serialize_children(element, matches, out, ranges);
+                    } else {
+                        serialize_element(element, matches, out, ranges);
+                    }
+                }
+            }
+            Node::Comment(comment) => out.push_str(&format!("<!--{}-->", &**comment)),
+            _ => {}
+        }
+    }
+}
+
+fn serialize_element(
+    element: ElementRef,
+    matches: &[ElementRef],
+    out: &mut String,
+    ranges: &mut Vec<Range<usize>>,
+) {
+    let start = out.len();
+    let name = element.value().name();
+    out.push('<');
+    out.push_str(name);
+    for (attr, value) in element.value().attrs() {
+        out.push_str(&format!(
+            " {}=\"{}\"",
+            attr,
+            encode_double_quoted_attribute(value)
+        ));
+    }
+    out.push('>');
+    if !VOID_ELEMENTS.contains(&name) {
+        serialize_children(element, matches, out, ranges);
+        out.push_str(&format!("</{}>", name));
+    }
+    if matches.iter().any(|m| m.id() == element.id()) {
+        // Nested matches are already covered by the enclosing range.
+        ranges.retain(|range| range.start < start);
+        ranges.push(start..out.len());
+    }
+}
+
+fn serialize_children(
+    element: ElementRef,
+    matches: &[ElementRef],
+    out: &mut String,
+    ranges: &mut Vec<Range<usize>>,
+) {
+    let raw_text = matches!(element.value().name(), "script" | "style");
+    for child in element.children() {
+        match child.value() {
+            Node::Element(_) => {
+                if let Some(child) = ElementRef::wrap(child) {
+                    serialize_element(child, matches, out, ranges);
+                }
+            }
+            Node::Text(text) if raw_text => out.push_str(text),
+            Node::Text(text) => out.push_str(&encode_text(&**text)),
+            Node::Comment(comment) => out.push_str(&format!("<!--{}-->", &**comment)),
+            _ => {}
+        }
+    }
+}
+
+impl Tool for CSSSelectorTesterTool {
+    fn title() -> &'static str {
+        "CSS Selector Tester"
+    }
+
+    fn short_title() -> &'static str {
+        "Selector Tester"
+    }
+
+    fn description() -> &'static str {
+        "Tests CSS selectors against HTML and shows the matching elements."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_test_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_results_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.results.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            match_output: self.match_output,
+            selector: self.selector.read(cx).value().to_string(),
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_match_output(&SetMatchOutput(preset.match_output), window, cx);
+        self.selector.update(cx, |state, cx| {
+            state.set_value(preset.selector, window, cx);
+        });
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for CSSSelectorTesterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CSSSelectorTesterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let match_output = self.match_output;
+        let match_count = self.match_count;
+        let highlight = HighlightStyle {
+            background_color: Some(cx.theme().yellow.opacity(0.35)),
+            ..Default::default()
+        };
+        let highlights: Vec<_> = self
+            .highlighted_ranges
+            .iter()
+            .map(|range| (range.clone(), highlight))
+            .collect();
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_match_output))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("test-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("test-button")
+                                    .label("Test")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_test_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Output")
+                                    .menu_with_check(
+                                        "Outer HTML",
+                                        match_output == MatchOutput::OuterHTML,
+                                        Box::new(SetMatchOutput(MatchOutput::OuterHTML)),
+                                    )
+                                    .menu_with_check(
+                                        "Inner HTML",
+                                        match_output == MatchOutput::InnerHTML,
+                                        Box::new(SetMatchOutput(MatchOutput::InnerHTML)),
+                                    )
+                                    .menu_with_check(
+                                        "Text Content",
+                                        match_output == MatchOutput::Text,
+                                        Box::new(SetMatchOutput(MatchOutput::Text)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.selector)
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .when(!self.highlighted_text.is_empty(), |this| {
+                        this.child(
+                            Button::new("edit-button")
+                                .label("Edit")
+                                .on_click(cx.listener(Self::on_edit_click)),
+                        )
+                    })
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click)),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .when_else(
+                self.highlighted_text.is_empty(),
+                |this| {
+                    this.child(
+                        TextInput::new(&self.editor)
+                            .h_full()
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false),
+                    )
+                },
+                |this| {
+                    this.child(
+                        div()
+                            .id("highlighted-source")
+                            .size_full()
+                            .overflow_y_scroll()
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .line_height(rems(1.25))
+                            .bg(cx.theme().background)
+                            .text_color(cx.theme().foreground)
+                            .rounded(cx.theme().radius)
+                            .border_color(cx.theme().input)
+                            .border_1()
+                            .input_px(Size::default())
+                            .input_py(Size::default())
+                            .child(
+                                StyledText::new(self.highlighted_text.clone())
+                                    .with_highlights(highlights),
+                            ),
+                    )
+                },
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .when_some(match_count, |this, match_count| {
+                        this.child(Label::new(format!("{} matches", match_count)))
+                    })
+                    .child(
+                        Button::new("copy-results-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_results_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.results)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
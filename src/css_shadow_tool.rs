@@ -0,0 +1,383 @@
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    ParentElement, Render, SharedString, Styled, Subscription, Window, div, point,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, StyledExt,
+    button::{Button, ButtonVariants},
+    clipboard::Clipboard,
+    color_picker::{ColorPicker, ColorPickerEvent, ColorPickerState},
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+    slider::{Slider, SliderEvent, SliderState},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    color_preview::{from_hsla, to_hsla},
+    core::{
+        color::{self, Color},
+        shadow::{self, BoxShadow},
+    },
+    editor_font,
+};
+
+/// The sliders, by label, with their range, starting value and unit.
+const SLIDERS: [(&str, f32, f32, f32, &str); 6] = [
+    ("Offset X", -50., 50., 0., "px"),
+    ("Offset Y", -50., 50., 8., "px"),
+    ("Blur", 0., 100., 24., "px"),
+    ("Spread", -50., 50., 0., "px"),
+    ("Opacity", 0., 100., 25., "%"),
+    ("Border Radius", 0., 100., 12., "px"),
+];
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    color: String,
+    values: Vec<f32>,
+}
+
+pub struct CSSShadowTool {
+    focus_handle: FocusHandle,
+    color: Entity<InputState>,
+    picker: Entity<ColorPickerState>,
+    sliders: Vec<Entity<SliderState>>,
+    /// The shadow as drawn and the border radius, once the color is valid.
+    shadow: Option<(BoxShadow, f32)>,
+    fields: Vec<(&'static str, Entity<InputState>)>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl CSSShadowTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let color = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value("#000000")
+                .placeholder("Shadow color, e.g. #000000 or slategray")
+        });
+        let picker = cx.new(|cx| ColorPickerState::new(window, cx));
+        let sliders: Vec<_> = SLIDERS
+            .iter()
+            .map(|(_, min, max, value, _)| {
+                cx.new(|_| {
+                    SliderState::new()
+                        .min(*min)
+                        .max(*max)
+                        .step(1.)
+                        .default_value(*value)
+                })
+            })
+            .collect();
+        let mut _subscriptions = vec![
+            cx.subscribe_in(&color, window, Self::on_input_event),
+            cx.subscribe_in(&picker, window, Self::on_picker_event),
+        ];
+        _subscriptions.extend(
+            sliders
+                .iter()
+                .map(|slider| cx.subscribe_in(slider, window, Self::on_slider_event)),
+        );
+
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            color,
+            picker,
+            sliders,
+            shadow: None,
+            fields: Vec::new(),
+            error: None,
+            _subscriptions,
+        };
+        this.update_shadow(window, cx);
+        this
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_shadow(window, cx);
+        }
+    }
+
+    fn on_picker_event(
+        &mut self,
+        _: &Entity<ColorPickerState>,
+        e: &ColorPickerEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let ColorPickerEvent::Change(Some(color)) = e else {
+            return;
+        };
+        let hex = from_hsla(*color).to_hex();
+        self.color.update(cx, |state, cx| {
+            state.set_value(hex, window, cx);
+        });
+    }
+
+    fn on_slider_event(
+        &mut self,
+        _: &Entity<SliderState>,
+        e: &SliderEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let SliderEvent::Change(_) = e;
+        self.update_shadow(window, cx);
+    }
+
+    fn values(&self, cx: &App) -> [f32; 6] {
+        let mut values = [0.; 6];
+        for (value, slider) in values.iter_mut().zip(&self.sliders) {
+            *value = slider.read(cx).value().end();
+        }
+        values
+    }
+
+    fn update_shadow(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let [offset_x, offset_y, blur, spread, opacity, radius] = self.values(cx);
+        let value = self.color.read(cx).value().clone();
+        match color::parse(&value) {
+            Ok(color) => {
+                self.picker.update(cx, |state, cx| {
+                    state.set_value(to_hsla(color), window, cx);
+                });
+                let shadow = BoxShadow {
+                    offset_x,
+                    offset_y,
+                    blur,
+                    spread,
+                    color: Color {
+                        a: color.a * opacity / 100.,
+                        ..color
+                    },
+                };
+                self.fields = shadow::declarations(&shadow, radius)
+                    .into_iter()
+                    .map(|(name, value)| {
+                        (
+                            name,
+                            cx.new(|cx| InputState::new(window, cx).default_value(value)),
+                        )
+                    })
+                    .collect();
+                self.shadow = Some((shadow, radius));
+                self.error = None;
+            }
+            Err(err) => {
+                self.fields.clear();
+                self.shadow = None;
+                self.error = Some(err.context("Invalid color"));
+            }
+        }
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let css = self
+            .fields
+            .iter()
+            .map(|(name, state)| format!("{}: {};", name, state.read(cx).value()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        cx.write_to_clipboard(ClipboardItem::new_string(css));
+    }
+
+    fn on_reset_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        for (slider, (_, _, _, value, _)) in self.sliders.iter().zip(SLIDERS) {
+            slider.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            });
+        }
+        self.update_shadow(window, cx);
+    }
+}
+
+impl Tool for CSSShadowTool {
+    fn title() -> &'static str {
+        "CSS Shadow Generator"
+    }
+
+    fn short_title() -> &'static str {
+        "Shadow"
+    }
+
+    fn description() -> &'static str {
+        "Adjusts a box shadow and border radius with sliders on a live preview and writes the CSS for them."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        let state = State {
+            color: self.color.read(cx).value().to_string(),
+            values: self.values(cx).to_vec(),
+        };
+        serde_json::to_value(state).ok()
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(state) = State::deserialize(state) else {
+            return;
+        };
+        for (slider, value) in self.sliders.iter().zip(state.values) {
+            slider.update(cx, |slider, cx| {
+                slider.set_value(value, window, cx);
+            });
+        }
+        self.color.update(cx, |input, cx| {
+            input.set_value(state.color, window, cx);
+        });
+        self.update_shadow(window, cx);
+    }
+}
+
+impl Focusable for CSSShadowTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CSSShadowTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let font = editor_font(cx);
+        let values = self.values(cx);
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy CSS")
+                            .primary()
+                            .on_click(cx.listener(Self::on_copy_click)),
+                    )
+                    .child(
+                        Button::new("reset-button")
+                            .label("Reset")
+                            .on_click(cx.listener(Self::on_reset_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_4()
+                    .items_start()
+                    .child(
+                        div()
+                            .v_flex()
+                            .flex_1()
+                            .gap_2()
+                            .child(
+                                h_flex()
+                                    .gap_2()
+                                    .child(Label::new("Color").w(px(160.)))
+                                    .child(
+                                        div().flex_1().child(
+                                            TextInput::new(&self.color)
+                                                .font_family(font.clone())
+                                                .focus_bordered(false),
+                                        ),
+                                    )
+                                    .child(ColorPicker::new(&self.picker).label("Pick")),
+                            )
+                            .children(self.sliders.iter().zip(SLIDERS).zip(values).map(
+                                |((slider, (name, _, _, _, unit)), value)| {
+                                    h_flex()
+                                        .h(px(32.))
+                                        .gap_2()
+                                        .child(Label::new(name).w(px(160.)))
+                                        .child(div().flex_1().child(Slider::new(slider)))
+                                        .child(
+                                            div()
+                                                .w(px(56.))
+                                                .font_family(font.clone())
+                                                .child(format!("{}{}", value, unit)),
+                                        )
+                                },
+                            )),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .h(px(320.))
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .rounded(cx.theme().radius)
+                            .bg(cx.theme().muted)
+                            .when_some(self.shadow, |this, (shadow, radius)| {
+                                this.child(
+                                    div()
+                                        .size(px(160.))
+                                        .rounded(px(radius))
+                                        .bg(cx.theme().background)
+                                        .shadow(vec![gpui::BoxShadow {
+                                            color: to_hsla(shadow.color),
+                                            offset: point(px(shadow.offset_x), px(shadow.offset_y)),
+                                            blur_radius: px(shadow.blur),
+                                            spread_radius: px(shadow.spread),
+                                        }]),
+                                )
+                            }),
+                    ),
+            )
+            .child(
+                div()
+                    .v_flex()
+                    .gap_2()
+                    .mt_4()
+                    .children(self.fields.iter().enumerate().map(|(i, (name, state))| {
+                        let value = state.read(cx).value().clone();
+                        h_flex().gap_2().child(Label::new(*name).w(px(160.))).child(
+                            TextInput::new(state)
+                                .font_family(font.clone())
+                                .text_size(px(15.))
+                                .focus_bordered(false)
+                                .suffix(
+                                    Clipboard::new(SharedString::from(format!(
+                                        "field-clipboard-{}",
+                                        i
+                                    )))
+                                    .value(value),
+                                ),
+                        )
+                    })),
+            )
+    }
+}
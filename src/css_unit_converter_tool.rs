@@ -0,0 +1,344 @@
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, InteractiveElement,
+    ParentElement, Render, SharedString, StatefulInteractiveElement, Styled, Subscription, Window,
+    div, px,
+};
+
+use gpui_component::{
+    StyledExt,
+    button::Button,
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::css_unit::{self, Bases},
+    editor_font,
+};
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    input: String,
+    root: String,
+    parent: String,
+    min_size: String,
+    max_size: String,
+    min_viewport: String,
+    max_viewport: String,
+}
+
+pub struct CSSUnitConverterTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    root: Entity<InputState>,
+    parent: Entity<InputState>,
+    fields: Vec<(&'static str, Entity<InputState>)>,
+    min_size: Entity<InputState>,
+    max_size: Entity<InputState>,
+    min_viewport: Entity<InputState>,
+    max_viewport: Entity<InputState>,
+    clamp: Entity<InputState>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl CSSUnitConverterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("Length, e.g. 24px, 1.5rem, or 12pt")
+        });
+        let mut setting = |value: &str| {
+            let value = value.to_string();
+            cx.new(|cx| InputState::new(window, cx).default_value(value))
+        };
+        let root = setting("16px");
+        let parent = setting("16px");
+        let min_size = setting("16px");
+        let max_size = setting("32px");
+        let min_viewport = setting("320px");
+        let max_viewport = setting("1280px");
+        let clamp = cx.new(|cx| InputState::new(window, cx));
+        let _subscriptions = [
+            &input,
+            &root,
+            &parent,
+            &min_size,
+            &max_size,
+            &min_viewport,
+            &max_viewport,
+        ]
+        .into_iter()
+        .map(|state| cx.subscribe_in(state, window, Self::on_input_event))
+        .collect();
+
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            root,
+            parent,
+            fields: Vec::new(),
+            min_size,
+            max_size,
+            min_viewport,
+            max_viewport,
+            clamp,
+            error: None,
+            _subscriptions,
+        };
+        this.update_fields(window, cx);
+        this
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_fields(window, cx);
+        }
+    }
+
+    /// Reads a length from a setting, in pixels.
+    fn read_px(
+        &self,
+        state: &Entity<InputState>,
+        name: &str,
+        bases: &Bases,
+        cx: &App,
+    ) -> Result<f64, ToolError> {
+        css_unit::parse(&state.read(cx).value())
+            .map(|length| length.to_px(bases))
+            .map_err(|err| err.context(&format!("Invalid {}", name)))
+    }
+
+    fn read_bases(&self, cx: &App) -> Result<Bases, ToolError> {
+        let root = self.read_px(&self.root, "root font size", &Bases::default(), cx)?;
+        let parent = self.read_px(
+            &self.parent,
+            "parent font size",
+            &Bases { root, parent: root },
+            cx,
+        )?;
+        if root <= 0. || parent <= 0. {
+            return Err(ToolError::new("Font sizes must be greater than zero"));
+        }
+        Ok(Bases { root, parent })
+    }
+
+    fn read_clamp(&self, bases: &Bases, cx: &App) -> Result<String, ToolError> {
+        css_unit::fluid_clamp(
+            self.read_px(&self.min_size, "minimum size", bases, cx)?,
+            self.read_px(&self.max_size, "maximum size", bases, cx)?,
+            self.read_px(&self.min_viewport, "minimum viewport width", bases, cx)?,
+            self.read_px(&self.max_viewport, "maximum viewport width", bases, cx)?,
+            bases.root,
+        )
+    }
+
+    fn update_fields(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        let bases = self.read_bases(cx);
+        let lengths = match (&bases, value.trim()) {
+            (_, "") => Ok(Vec::new()),
+            (Ok(bases), value) => {
+                css_unit::parse(value).map(|length| css_unit::convert(length, bases))
+            }
+            (Err(err), _) => Err(err.clone()),
+        };
+        let clamp = bases.and_then(|bases| self.read_clamp(&bases, cx));
+        self.error = lengths.as_ref().err().or(clamp.as_ref().err()).cloned();
+
+        self.fields = lengths
+            .unwrap_or_default()
+            .into_iter()
+            .map(|length| {
+                let value = length.to_string();
+                (
+                    length.unit.name(),
+                    cx.new(|cx| InputState::new(window, cx).default_value(value)),
+                )
+            })
+            .collect();
+        self.clamp.update(cx, |state, cx| {
+            state.set_value(clamp.unwrap_or_default(), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.input.update(cx, |state, cx| {
+                state.set_value(value.trim().to_string(), window, cx);
+            });
+        }
+    }
+}
+
+impl Tool for CSSUnitConverterTool {
+    fn title() -> &'static str {
+        "CSS Unit Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "CSS Units"
+    }
+
+    fn description() -> &'static str {
+        "Converts lengths between px, rem, em, pt, and percentages, and builds clamp() values for fluid typography."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        let value = |state: &Entity<InputState>| state.read(cx).value().to_string();
+        let state = State {
+            input: value(&self.input),
+            root: value(&self.root),
+            parent: value(&self.parent),
+            min_size: value(&self.min_size),
+            max_size: value(&self.max_size),
+            min_viewport: value(&self.min_viewport),
+            max_viewport: value(&self.max_viewport),
+        };
+        serde_json::to_value(state).ok()
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(state) = State::deserialize(state) else {
+            return;
+        };
+        let values = [
+            (&self.input, state.input),
+            (&self.root, state.root),
+            (&self.parent, state.parent),
+            (&self.min_size, state.min_size),
+            (&self.max_size, state.max_size),
+            (&self.min_viewport, state.min_viewport),
+            (&self.max_viewport, state.max_viewport),
+        ];
+        for (input, value) in values {
+            input.update(cx, |input, cx| {
+                input.set_value(value, window, cx);
+            });
+        }
+    }
+}
+
+impl Focusable for CSSUnitConverterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CSSUnitConverterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let font = editor_font(cx);
+        let clamp = self.clamp.read(cx).value().clone();
+        let setting = |label: &'static str, state: &Entity<InputState>| {
+            div()
+                .v_flex()
+                .flex_1()
+                .gap_1()
+                .child(Label::new(label))
+                .child(
+                    TextInput::new(state)
+                        .font_family(font.clone())
+                        .focus_bordered(false),
+                )
+        };
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("paste-button")
+                        .label("Paste")
+                        .on_click(cx.listener(Self::on_paste_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.input)
+                    .font_family(font.clone())
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(setting("Root Font Size (rem)", &self.root))
+                    .child(setting("Parent Font Size (em, %)", &self.parent)),
+            )
+            .child(
+                div()
+                    .id("fields")
+                    .v_flex()
+                    .flex_1()
+                    .gap_2()
+                    .mt_4()
+                    .overflow_y_scroll()
+                    .children(self.fields.iter().enumerate().map(|(i, (name, state))| {
+                        let value = state.read(cx).value().clone();
+                        h_flex().gap_2().child(Label::new(*name).w(px(160.))).child(
+                            TextInput::new(state)
+                                .font_family(font.clone())
+                                .text_size(px(15.))
+                                .focus_bordered(false)
+                                .suffix(
+                                    Clipboard::new(SharedString::from(format!(
+                                        "field-clipboard-{}",
+                                        i
+                                    )))
+                                    .value(value),
+                                ),
+                        )
+                    })),
+            )
+            .child(Label::new("Fluid Typography").mt_4())
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(setting("Minimum Size", &self.min_size))
+                    .child(setting("Maximum Size", &self.max_size))
+                    .child(setting("From Viewport Width", &self.min_viewport))
+                    .child(setting("To Viewport Width", &self.max_viewport)),
+            )
+            .child(
+                TextInput::new(&self.clamp)
+                    .font_family(font.clone())
+                    .text_size(px(15.))
+                    .focus_bordered(false)
+                    .suffix(Clipboard::new("clamp-clipboard").value(clamp)),
+            )
+    }
+}
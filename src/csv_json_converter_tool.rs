@@ -0,0 +1,567 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    FontWeight, InteractiveElement, ParentElement, Render, SharedString,
+    StatefulInteractiveElement, Styled, Window, div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    v_flex,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::csv::{self, Quoting},
+    editor_font,
+};
+
+/// How many rows of the CSV side are shown in the preview table.
+const PREVIEW_ROWS: usize = 10;
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Direction {
+    CsvToJson,
+    JsonToCsv,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Delimiter {
+    Comma,
+    Semicolon,
+    Tab,
+    Pipe,
+}
+
+impl Delimiter {
+    fn as_char(&self) -> char {
+        match self {
+            Delimiter::Comma => ',',
+            Delimiter::Semicolon => ';',
+            Delimiter::Tab => '\t',
+            Delimiter::Pipe => '|',
+        }
+    }
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = csv_tools, no_json)]
+pub struct SetDirection(Direction);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = csv_tools, no_json)]
+pub struct SetDelimiter(Delimiter);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = csv_tools, no_json)]
+pub struct SetHeaderRow(bool);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = csv_tools, no_json)]
+pub struct SetQuoting(Quoting);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    direction: Direction,
+    delimiter: Delimiter,
+    header_row: bool,
+    quoting: Quoting,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct CSVJSONConverterTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    converted: Entity<InputState>,
+    direction: Direction,
+    delimiter: Delimiter,
+    header_row: bool,
+    quoting: Quoting,
+    /// The first rows of whichever side is CSV, as of the last conversion.
+    preview: Vec<Vec<String>>,
+    error: Option<ToolError>,
+}
+
+impl CSVJSONConverterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: true,
+                })
+                .default_value("")
+                .placeholder("CSV Source")
+        });
+        let converted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("JSON")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            converted,
+            direction: Direction::CsvToJson,
+            delimiter: Delimiter::Comma,
+            header_row: true,
+            quoting: Quoting::Minimal,
+            preview: Vec::new(),
+            error: None,
+        }
+    }
+
+    fn convert(&self, value: &str) -> Result<(String, Vec<Vec<String>>), ToolError> {
+        let delimiter = self.delimiter.as_char();
+        match self.direction {
+            Direction::CsvToJson => {
+                let rows = csv::parse(value, delimiter, self.quoting)
+                    .map_err(|err| err.context("Invalid CSV"))?;
+                Ok((rows_to_json(&rows, self.header_row), rows))
+            }
+            Direction::JsonToCsv => {
+                let json: Value = serde_json::from_str(value)
+                    .map_err(|err| ToolError::from(err).context("Invalid JSON"))?;
+                let rows = json_to_rows(&json, self.header_row)
+                    .map_err(|err| err.context("Cannot convert JSON"))?;
+                Ok((csv::write(&rows, delimiter, self.quoting), rows))
+            }
+        }
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        match self.convert(&value) {
+            Ok((converted, mut rows)) => {
+                self.error = None;
+                rows.truncate(PREVIEW_ROWS + usize::from(self.header_row));
+                self.preview = rows;
+                self.converted.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(converted), window, cx);
+                });
+            }
+            Err(err) => {
+                self.error = Some(err);
+                self.preview.clear();
+            }
+        }
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_converted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.converted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_direction(
+        &mut self,
+        action: &SetDirection,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.direction == action.0 {
+            return;
+        }
+        self.direction = action.0;
+        let (source, converted) = match self.direction {
+            Direction::CsvToJson => (("CSV Source", Language::Plain), ("JSON", Language::Json)),
+            Direction::JsonToCsv => (("JSON Source", Language::Json), ("CSV", Language::Plain)),
+        };
+        self.editor.update(cx, |state, cx| {
+            state.set_highlighter(source.1.name().to_string(), cx);
+            state.set_placeholder(source.0, window, cx);
+        });
+        self.converted.update(cx, |state, cx| {
+            state.set_highlighter(converted.1.name().to_string(), cx);
+            state.set_placeholder(converted.0, window, cx);
+        });
+        self.preview.clear();
+        cx.notify();
+    }
+
+    fn on_action_set_delimiter(
+        &mut self,
+        action: &SetDelimiter,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.delimiter = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_header_row(
+        &mut self,
+        action: &SetHeaderRow,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.header_row = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_quoting(
+        &mut self,
+        action: &SetQuoting,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.quoting = action.0;
+        cx.notify();
+    }
+}
+
+/// Turns rows into a JSON array, of objects keyed by the header row if there
+/// is one, or of arrays otherwise. The JSON is written by hand so that keys
+/// keep the order of the columns.
+fn rows_to_json(rows: &[Vec<String>], header_row: bool) -> String {
+    let string = |value: &str| serde_json::to_string(value).unwrap_or_default();
+    let (header, rows) = match rows.split_first() {
+        Some((header, rows)) if header_row => (Some(header), rows),
+        _ => (None, rows),
+    };
+
+    let items: Vec<String> = rows
+        .iter()
+        .map(|row| match header {
+            Some(header) => {
+                let fields: Vec<String> = row
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| {
+                        let key = header
+                            .get(i)
+                            .cloned()
+                            .unwrap_or_else(|| format!("Column {}", i + 1));
+                        format!("    {}: {}", string(&key), string(value))
+                    })
+                    .collect();
+                format!("  {{\n{}\n  }}", fields.join(",\n"))
+            }
+            None => {
+                let fields: Vec<String> = row.iter().map(|value| string(value)).collect();
+                format!("  [{}]", fields.join(", "))
+            }
+        })
+        .collect();
+    if items.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", items.join(",\n"))
+    }
+}
+
+/// Turns a JSON array of objects or arrays into rows. Objects are laid out
+/// by key, with the keys as a header row if one is wanted; nested values are
+/// written as JSON.
+fn json_to_rows(json: &Value, header_row: bool) -> Result<Vec<Vec<String>>, ToolError> {
+    let Value::Array(items) = json else {
+        return Err(ToolError::new("expected an array of objects or arrays"));
+    };
+    let cell = |value: Option<&Value>| match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(value) => value.to_string(),
+    };
+
+    let mut columns: Vec<&String> = Vec::new();
+    for item in items {
+        if let Value::Object(map) = item {
+            for key in map.keys() {
+                if !columns.contains(&key) {
+                    columns.push(key);
+                }
+            }
+        }
+    }
+
+    let mut rows = Vec::with_capacity(items.len() + 1);
+    if header_row && !columns.is_empty() {
+        rows.push(columns.iter().map(|column| column.to_string()).collect());
+    }
+    for (i, item) in items.iter().enumerate() {
+        rows.push(match item {
+            Value::Object(map) => columns
+                .iter()
+                .map(|column| cell(map.get(*column)))
+                .collect(),
+            Value::Array(values) => values.iter().map(|value| cell(Some(value))).collect(),
+            _ => {
+                return Err(ToolError::new(format!(
+                    "item {} is neither an object nor an array",
+                    i + 1
+                )));
+            }
+        });
+    }
+    Ok(rows)
+}
+
+impl Tool for CSVJSONConverterTool {
+    fn title() -> &'static str {
+        "CSV/JSON Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "CSV/JSON"
+    }
+
+    fn description() -> &'static str {
+        "Converts CSV or TSV to a JSON array of objects and back."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_converted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.converted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            direction: self.direction,
+            delimiter: self.delimiter,
+            header_row: self.header_row,
+            quoting: self.quoting,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_direction(&SetDirection(preset.direction), window, cx);
+        self.on_action_set_delimiter(&SetDelimiter(preset.delimiter), window, cx);
+        self.on_action_set_header_row(&SetHeaderRow(preset.header_row), window, cx);
+        self.on_action_set_quoting(&SetQuoting(preset.quoting), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for CSVJSONConverterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CSVJSONConverterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let direction = self.direction;
+        let delimiter = self.delimiter;
+        let header_row = self.header_row;
+        let quoting = self.quoting;
+        let border = cx.theme().border;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_direction))
+            .on_action(cx.listener(Self::on_action_set_delimiter))
+            .on_action(cx.listener(Self::on_action_set_header_row))
+            .on_action(cx.listener(Self::on_action_set_quoting))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("convert-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("convert-button")
+                                    .label(match direction {
+                                        Direction::CsvToJson => "CSV to JSON",
+                                        Direction::JsonToCsv => "JSON to CSV",
+                                    })
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Direction")
+                                    .menu_with_check(
+                                        "CSV to JSON",
+                                        direction == Direction::CsvToJson,
+                                        Box::new(SetDirection(Direction::CsvToJson)),
+                                    )
+                                    .menu_with_check(
+                                        "JSON to CSV",
+                                        direction == Direction::JsonToCsv,
+                                        Box::new(SetDirection(Direction::JsonToCsv)),
+                                    )
+                                    .label("Delimiter")
+                                    .menu_with_check(
+                                        "Comma",
+                                        delimiter == Delimiter::Comma,
+                                        Box::new(SetDelimiter(Delimiter::Comma)),
+                                    )
+                                    .menu_with_check(
+                                        "Semicolon",
+                                        delimiter == Delimiter::Semicolon,
+                                        Box::new(SetDelimiter(Delimiter::Semicolon)),
+                                    )
+                                    .menu_with_check(
+                                        "Tab",
+                                        delimiter == Delimiter::Tab,
+                                        Box::new(SetDelimiter(Delimiter::Tab)),
+                                    )
+                                    .menu_with_check(
+                                        "Pipe",
+                                        delimiter == Delimiter::Pipe,
+                                        Box::new(SetDelimiter(Delimiter::Pipe)),
+                                    )
+                                    .label("Header")
+                                    .menu_with_check(
+                                        "First Row Is Header",
+                                        header_row,
+                                        Box::new(SetHeaderRow(!header_row)),
+                                    )
+                                    .label("Quotes")
+                                    .menu_with_check(
+                                        "When Needed",
+                                        quoting == Quoting::Minimal,
+                                        Box::new(SetQuoting(Quoting::Minimal)),
+                                    )
+                                    .menu_with_check(
+                                        "Always",
+                                        quoting == Quoting::Always,
+                                        Box::new(SetQuoting(Quoting::Always)),
+                                    )
+                                    .menu_with_check(
+                                        "Never",
+                                        quoting == Quoting::Never,
+                                        Box::new(SetQuoting(Quoting::Never)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .when(!self.preview.is_empty(), |this| {
+                this.child(
+                    v_flex()
+                        .id("preview")
+                        .max_h(px(240.))
+                        .flex_none()
+                        .border_1()
+                        .border_color(border)
+                        .overflow_scroll()
+                        .children(self.preview.iter().enumerate().map(|(ix, row)| {
+                            h_flex()
+                                .when(ix > 0, |this| this.border_t_1().border_color(border))
+                                .when(ix == 0 && header_row, |this| {
+                                    this.font_weight(FontWeight::BOLD)
+                                })
+                                .children(row.iter().map(|cell| {
+                                    div()
+                                        .w(px(160.))
+                                        .flex_none()
+                                        .px_2()
+                                        .py_1()
+                                        .overflow_hidden()
+                                        .text_ellipsis()
+                                        .whitespace_nowrap()
+                                        .child(cell.clone())
+                                }))
+                        })),
+                )
+            })
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-converted-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_converted_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.converted)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -1,25 +1,47 @@
-use base64::{Engine as _, engine::general_purpose};
-use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
+use std::sync::Arc;
 
 use gpui::{
     Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
-    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div,
-    prelude::FluentBuilder, px,
+    Image, InteractiveElement, ObjectFit, ParentElement, Render, SharedString, Styled, StyledImage,
+    Window, div, img, prelude::FluentBuilder, px,
 };
 
 use gpui_component::{
-    Disableable, StyledExt,
+    ActiveTheme, Disableable, StyledExt,
     button::{Button, ButtonVariants, DropdownButton},
     h_flex,
     highlighter::Language,
     input::{InputState, TabSize, TextInput},
+    v_flex,
 };
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::Tool;
+use crate::{
+    Tool, ToolError,
+    core::{
+        base64::ImageType,
+        data_url::{self, DataURL},
+    },
+    editor_font,
+    image_preview::preview_image,
+};
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+enum Mode {
+    #[default]
+    Auto,
+    Generate,
+    Inspect,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = data_url_tools, no_json)]
+pub struct SetMode(Mode);
 
-#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
 enum Encoding {
     Base64,
     URL,
@@ -29,12 +51,21 @@ enum Encoding {
 #[action(namespace = data_url_tools, no_json)]
 pub struct SetEncoding(Encoding);
 
-const DATA_URL_ENCODE_SET: &AsciiSet = &CONTROLS.add(b',').add(b'%').add(b'#');
-
 #[derive(Action, Clone, PartialEq, Eq, Deserialize)]
 #[action(namespace = data_url_tools, no_json)]
 pub struct SetMimeTypeAutoDetect(bool);
 
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    #[serde(default)]
+    mode: Mode,
+    encoding: Encoding,
+    mime_type_auto_detect: bool,
+    mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
 pub struct DataURLGeneratorTool {
     focus_handle: FocusHandle,
     editor: Entity<InputState>,
@@ -42,6 +73,41 @@ pub struct DataURLGeneratorTool {
     encoding: Encoding,
     mime_type_auto_detect: bool,
     mime_type: Entity<InputState>,
+    mode: Mode,
+    inspected: Option<Inspected>,
+    error: Option<ToolError>,
+}
+
+/// A parsed data URL, with its payload ready to preview if it is an image.
+struct Inspected {
+    data_url: DataURL,
+    image: Option<(ImageType, Arc<Image>)>,
+}
+
+impl Inspected {
+    fn new(data_url: DataURL) -> Self {
+        let image = ImageType::detect(&data_url.data)
+            .map(|image_type| (image_type, preview_image(image_type, data_url.data.clone())));
+        Self { data_url, image }
+    }
+
+    fn description(&self) -> String {
+        let data_url = &self.data_url;
+        let encoding = if data_url.base64 { "Base64" } else { "URL" };
+        let mut description = format!("{}, ", data_url.mime_type);
+        if let Some(charset) = &data_url.charset {
+            description.push_str(&format!("charset {}, ", charset));
+        }
+        description.push_str(&format!(
+            "{} encoded, {} bytes",
+            encoding,
+            data_url.data.len()
+        ));
+        if let Some((image_type, _)) = &self.image {
+            description.push_str(&format!(", {} image", image_type.name()));
+        }
+        description
+    }
 }
 
 impl DataURLGeneratorTool {
@@ -85,34 +151,71 @@ impl DataURLGeneratorTool {
             encoding: Encoding::Base64,
             mime_type_auto_detect: true,
             mime_type,
+            mode: Mode::Auto,
+            inspected: None,
+            error: None,
+        }
+    }
+
+    /// The mode to run in, with `Auto` resolved against the input.
+    fn effective_mode(&self, cx: &App) -> Mode {
+        match self.mode {
+            Mode::Auto => {
+                let value = self.editor.read(cx).value();
+                if value
+                    .trim_start()
+                    .get(..5)
+                    .is_some_and(|scheme| scheme.eq_ignore_ascii_case("data:"))
+                {
+                    Mode::Inspect
+                } else {
+                    Mode::Generate
+                }
+            }
+            mode => mode,
         }
     }
 
     fn on_generate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        match self.effective_mode(cx) {
+            Mode::Inspect => self.inspect(window, cx),
+            _ => self.generate(window, cx),
+        }
+    }
+
+    fn generate(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         let value = self.editor.read(cx).value().clone();
-        let generated_value = match self.encoding {
-            Encoding::Base64 => format!(
-                "base64,{}",
-                general_purpose::URL_SAFE.encode(value.to_string())
-            ),
-            Encoding::URL => utf8_percent_encode(value.as_ref(), DATA_URL_ENCODE_SET).to_string(),
-        };
         let mime_type = self.mime_type.read(cx).value().clone();
-        let mime_type_extra = if mime_type == "text/plain" {
-            ";charset=utf-8"
-        } else {
-            ""
-        };
+        let generated_value =
+            data_url::generate(&value, &mime_type, self.encoding == Encoding::Base64);
+        self.inspected = None;
+        self.error = None;
         self.generated.update(cx, |state, cx| {
-            state.set_value(
-                SharedString::from(format!(
-                    "data:{}{};{}",
-                    mime_type, mime_type_extra, generated_value
-                )),
-                window,
-                cx,
-            );
-        })
+            state.set_value(SharedString::from(generated_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn inspect(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        match data_url::parse(&value) {
+            Ok(data_url) => {
+                self.error = None;
+                let inspected = Inspected::new(data_url);
+                // Images are previewed instead, and bytes that are not text
+                // are only described.
+                let decoded_value = match inspected.image {
+                    Some(_) => String::new(),
+                    None => String::from_utf8(inspected.data_url.data.clone()).unwrap_or_default(),
+                };
+                self.inspected = Some(inspected);
+                self.generated.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(decoded_value), window, cx);
+                });
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
     }
 
     fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
@@ -130,6 +233,14 @@ impl DataURLGeneratorTool {
         _window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        if let Some(Inspected {
+            image: Some((_, image)),
+            ..
+        }) = &self.inspected
+        {
+            cx.write_to_clipboard(ClipboardItem::new_image(image));
+            return;
+        }
         let value = self.generated.read(cx).value().clone();
         cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
     }
@@ -144,6 +255,11 @@ impl DataURLGeneratorTool {
         cx.notify();
     }
 
+    fn on_action_set_mode(&mut self, action: &SetMode, _: &mut Window, cx: &mut Context<Self>) {
+        self.mode = action.0;
+        cx.notify();
+    }
+
     fn on_action_set_mime_type_auto_detect(
         &mut self,
         action: &SetMimeTypeAutoDetect,
@@ -165,12 +281,65 @@ impl Tool for DataURLGeneratorTool {
     }
 
     fn description() -> &'static str {
-        "Converts text into a data URL."
+        "Converts text into a data URL, or inspects and decodes an existing one."
     }
 
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_generate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_generated_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.generated.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            mode: self.mode,
+            encoding: self.encoding.clone(),
+            mime_type_auto_detect: self.mime_type_auto_detect,
+            mime_type: self.mime_type.read(cx).value().to_string(),
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_mode(&SetMode(preset.mode), window, cx);
+        self.on_action_set_encoding(&SetEncoding(preset.encoding), window, cx);
+        self.on_action_set_mime_type_auto_detect(
+            &SetMimeTypeAutoDetect(preset.mime_type_auto_detect),
+            window,
+            cx,
+        );
+        self.mime_type.update(cx, |state, cx| {
+            state.set_value(preset.mime_type, window, cx);
+        });
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
 }
 
 impl Focusable for DataURLGeneratorTool {
@@ -188,8 +357,21 @@ impl Render for DataURLGeneratorTool {
         let value = self.editor.read(cx).value();
         let encoding = self.encoding.clone();
         let mime_type_auto_detect = self.mime_type_auto_detect;
+        let mode = self.mode;
+        let effective_mode = self.effective_mode(cx);
+        let label = match (mode, effective_mode) {
+            (Mode::Auto, Mode::Inspect) => "Inspect (Auto)",
+            (Mode::Auto, _) => "Generate (Auto)",
+            (_, Mode::Inspect) => "Inspect",
+            _ => "Generate",
+        };
+        let inspected = self
+            .inspected
+            .as_ref()
+            .filter(|_| effective_mode == Mode::Inspect);
 
         div()
+            .on_action(cx.listener(Self::on_action_set_mode))
             .on_action(cx.listener(Self::on_action_set_encoding))
             .on_action(cx.listener(Self::on_action_set_mime_type_auto_detect))
             .v_flex()
@@ -203,13 +385,29 @@ impl Render for DataURLGeneratorTool {
                             .primary()
                             .button(
                                 Button::new("generate-button")
-                                    .label("Generate")
+                                    .label(label)
                                     .primary()
                                     .disabled(value.is_empty())
                                     .on_click(cx.listener(Self::on_generate_click)),
                             )
                             .popup_menu(move |this, _, _| {
-                                this.label("Encoding Size")
+                                this.label("Mode")
+                                    .menu_with_check(
+                                        "Auto-Detect",
+                                        mode == Mode::Auto,
+                                        Box::new(SetMode(Mode::Auto)),
+                                    )
+                                    .menu_with_check(
+                                        "Generate",
+                                        mode == Mode::Generate,
+                                        Box::new(SetMode(Mode::Generate)),
+                                    )
+                                    .menu_with_check(
+                                        "Inspect",
+                                        mode == Mode::Inspect,
+                                        Box::new(SetMode(Mode::Inspect)),
+                                    )
+                                    .label("Encoding Size")
                                     .menu_with_check(
                                         "Base64",
                                         encoding == Encoding::Base64,
@@ -240,30 +438,62 @@ impl Render for DataURLGeneratorTool {
                             .ml_auto(),
                     ),
             )
-            .when(!mime_type_auto_detect, |this| {
-                this.child(TextInput::new(&self.mime_type).focus_bordered(false))
-            })
+            .when(
+                !mime_type_auto_detect && effective_mode == Mode::Generate,
+                |this| this.child(TextInput::new(&self.mime_type).focus_bordered(false)),
+            )
             .child(
                 TextInput::new(&self.editor)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
             .child(
-                h_flex().gap_2().child(
-                    Button::new("copy-generated-button")
-                        .label("Copy")
-                        .on_click(cx.listener(Self::on_copy_generated_click))
-                        .ml_auto(),
-                ),
+                h_flex()
+                    .gap_2()
+                    .when_some(inspected, |this, inspected| {
+                        this.child(
+                            div()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(inspected.description()),
+                        )
+                    })
+                    .child(
+                        Button::new("copy-generated-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_generated_click))
+                            .ml_auto(),
+                    ),
             )
-            .child(
-                TextInput::new(&self.generated)
-                    .h_full()
-                    .font_family("Space Mono")
-                    .text_size(px(15.))
-                    .focus_bordered(false),
+            .map(
+                |this| match inspected.and_then(|inspected| inspected.image.clone()) {
+                    Some((_, image)) => this.child(
+                        v_flex()
+                            .h_full()
+                            .min_h_0()
+                            .p_2()
+                            .items_center()
+                            .justify_center()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .rounded(cx.theme().radius)
+                            .child(
+                                img(image)
+                                    .flex_1()
+                                    .min_h_0()
+                                    .w_full()
+                                    .object_fit(ObjectFit::Contain),
+                            ),
+                    ),
+                    None => this.child(
+                        TextInput::new(&self.generated)
+                            .h_full()
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false),
+                    ),
+                },
             )
     }
 }
@@ -0,0 +1,386 @@
+use chrono::{Local, NaiveDateTime, NaiveTime};
+
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, IntoElement,
+    ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    StyledExt,
+    button::Button,
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::timestamp::{Period, parse_date_time},
+};
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    start: String,
+    end: String,
+    date: String,
+    period: String,
+}
+
+pub struct DateCalculatorTool {
+    focus_handle: FocusHandle,
+    start: Entity<InputState>,
+    end: Entity<InputState>,
+    between: Entity<InputState>,
+    total_days: Entity<InputState>,
+    total_hours: Entity<InputState>,
+    date: Entity<InputState>,
+    period: Entity<InputState>,
+    added: Entity<InputState>,
+    subtracted: Entity<InputState>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl DateCalculatorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let start =
+            cx.new(|cx| InputState::new(window, cx).placeholder("Start Date, e.g. 2024-01-31"));
+        let end =
+            cx.new(|cx| InputState::new(window, cx).placeholder("End Date, e.g. 2025-03-01 06:30"));
+        let between = cx.new(|cx| InputState::new(window, cx));
+        let total_days = cx.new(|cx| InputState::new(window, cx));
+        let total_hours = cx.new(|cx| InputState::new(window, cx));
+        let date = cx.new(|cx| InputState::new(window, cx).placeholder("Date, e.g. 2024-01-31"));
+        let period = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("Duration, e.g. 1y 2mo 3d or P1Y2M3D")
+        });
+        let added = cx.new(|cx| InputState::new(window, cx));
+        let subtracted = cx.new(|cx| InputState::new(window, cx));
+        let _subscriptions = vec![
+            cx.subscribe_in(&start, window, Self::on_between_event),
+            cx.subscribe_in(&end, window, Self::on_between_event),
+            cx.subscribe_in(&date, window, Self::on_shift_event),
+            cx.subscribe_in(&period, window, Self::on_shift_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            start,
+            end,
+            between,
+            total_days,
+            total_hours,
+            date,
+            period,
+            added,
+            subtracted,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_between_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            let start = self.start.read(cx).value().clone();
+            let end = self.end.read(cx).value().clone();
+            let dates = read_date(&start, "start date")
+                .and_then(|start| Ok(start.zip(read_date(&end, "end date")?)));
+            let dates = self.take_error(dates);
+            let fields = [
+                (
+                    &self.between,
+                    dates.map(|(start, end)| Period::between(start, end).to_string()),
+                ),
+                (
+                    &self.total_days,
+                    dates.map(|(start, end)| (end - start).num_days().to_string()),
+                ),
+                (
+                    &self.total_hours,
+                    dates.map(|(start, end)| (end - start).num_hours().to_string()),
+                ),
+            ];
+            Self::set_fields(fields, window, cx);
+        }
+    }
+
+    fn on_shift_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            let date = self.date.read(cx).value().clone();
+            let period = self.period.read(cx).value().clone();
+            let shift = read_date(&date, "date").and_then(|date| {
+                let period = match period.trim() {
+                    "" => None,
+                    period => Some(Period::parse(period)?),
+                };
+                Ok(date.zip(period))
+            });
+            let shift = self.take_error(shift);
+            let fields = [
+                (
+                    &self.added,
+                    shift.map(|(date, period)| format_date_time(period.apply(date))),
+                ),
+                (
+                    &self.subtracted,
+                    shift.map(|(date, period)| {
+                        let period = Period {
+                            negative: !period.negative,
+                            ..period
+                        };
+                        format_date_time(period.apply(date))
+                    }),
+                ),
+            ];
+            Self::set_fields(fields, window, cx);
+        }
+    }
+
+    /// Keeps the error, if any, to be shown above the tool, leaving fields
+    /// that depend on the input empty.
+    fn take_error<T>(&mut self, result: Result<Option<T>, ToolError>) -> Option<T> {
+        match result {
+            Ok(value) => {
+                self.error = None;
+                value
+            }
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        }
+    }
+
+    fn set_fields<const N: usize>(
+        fields: [(&Entity<InputState>, Option<String>); N],
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        for (state, value) in fields {
+            state.update(cx, |state, cx| {
+                state.set_value(value.unwrap_or_default(), window, cx);
+            });
+        }
+        cx.notify();
+    }
+
+    fn on_today_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        for state in [&self.start, &self.date] {
+            state.update(cx, |state, cx| {
+                state.set_value(today.clone(), window, cx);
+            });
+        }
+    }
+
+    fn render_field(
+        &self,
+        id: &'static str,
+        label: &'static str,
+        state: &Entity<InputState>,
+        cx: &App,
+    ) -> impl IntoElement {
+        let value: SharedString = state.read(cx).value().clone();
+
+        div()
+            .v_flex()
+            .w_full()
+            .gap_2()
+            .child(Label::new(label))
+            .child(
+                TextInput::new(state)
+                    .text_size(px(15.))
+                    .focus_bordered(false)
+                    .suffix(Clipboard::new(id).value_fn(move |_, _| value.clone())),
+            )
+    }
+}
+
+/// Reads a date, or nothing if the input is empty.
+fn read_date(value: &str, name: &str) -> Result<Option<NaiveDateTime>, ToolError> {
+    match value.trim() {
+        "" => Ok(None),
+        value => parse_date_time(value).map(Some).ok_or_else(|| {
+            ToolError::new(format!(
+                "{:?} is not a {} such as 2024-05-01 13:45:00",
+                value, name
+            ))
+        }),
+    }
+}
+
+fn format_date_time(date_time: Option<NaiveDateTime>) -> String {
+    match date_time {
+        Some(date_time) if date_time.time() == NaiveTime::MIN => {
+            date_time.format("%Y-%m-%d (%A)").to_string()
+        }
+        Some(date_time) => date_time.format("%Y-%m-%d %H:%M:%S (%A)").to_string(),
+        None => "Out of range".to_string(),
+    }
+}
+
+impl Tool for DateCalculatorTool {
+    fn title() -> &'static str {
+        "Date Calculator"
+    }
+
+    fn short_title() -> &'static str {
+        "Date Calculator"
+    }
+
+    fn description() -> &'static str {
+        "Finds the years, months, and days between two dates, or adds and subtracts a duration from a date."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        let state = State {
+            start: self.start.read(cx).value().to_string(),
+            end: self.end.read(cx).value().to_string(),
+            date: self.date.read(cx).value().to_string(),
+            period: self.period.read(cx).value().to_string(),
+        };
+        serde_json::to_value(state).ok()
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(state) = State::deserialize(state) else {
+            return;
+        };
+        for (input, value) in [
+            (&self.start, state.start),
+            (&self.end, state.end),
+            (&self.date, state.date),
+            (&self.period, state.period),
+        ] {
+            input.update(cx, |input, cx| {
+                input.set_value(value, window, cx);
+            });
+        }
+    }
+}
+
+impl Focusable for DateCalculatorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for DateCalculatorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("today-button")
+                        .label("Today")
+                        .on_click(cx.listener(Self::on_today_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .child(
+                        div().w_full().child(
+                            TextInput::new(&self.start)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        div().w_full().child(
+                            TextInput::new(&self.end)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    ),
+            )
+            .child(self.render_field("between-clipboard", "Duration", &self.between, cx))
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .items_start()
+                    .child(self.render_field(
+                        "total-days-clipboard",
+                        "Total Days",
+                        &self.total_days,
+                        cx,
+                    ))
+                    .child(self.render_field(
+                        "total-hours-clipboard",
+                        "Total Hours",
+                        &self.total_hours,
+                        cx,
+                    )),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .mt_4()
+                    .child(
+                        div().w_full().child(
+                            TextInput::new(&self.date)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        div().w_full().child(
+                            TextInput::new(&self.period)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .items_start()
+                    .child(self.render_field("added-clipboard", "Date + Duration", &self.added, cx))
+                    .child(self.render_field(
+                        "subtracted-clipboard",
+                        "Date − Duration",
+                        &self.subtracted,
+                        cx,
+                    )),
+            )
+    }
+}
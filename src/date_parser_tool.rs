@@ -0,0 +1,290 @@
+use chrono::Utc;
+
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, InteractiveElement,
+    ParentElement, Render, SharedString, StatefulInteractiveElement, Styled, Subscription, Window,
+    div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, IndexPath, StyledExt,
+    button::{Button, ButtonVariants},
+    clipboard::Clipboard,
+    dropdown::{Dropdown, DropdownEvent, DropdownState, SearchableVec},
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+    v_flex,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::timestamp::{ParsedDate, date_formats, parse_any_date, zone_names},
+    editor_font,
+};
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    input: String,
+    zone: Option<SharedString>,
+}
+
+pub struct DateParserTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    zone: Entity<DropdownState<SearchableVec<SharedString>>>,
+    parsed: Option<ParsedDate>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl DateParserTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Date, e.g. Wed, 01 May 2024 13:45:00 GMT or 2024-05-01T13:45:00Z")
+        });
+        let zones: Vec<SharedString> = zone_names().into_iter().map(SharedString::from).collect();
+        let zone = cx.new(|cx| {
+            DropdownState::new(
+                SearchableVec::new(zones),
+                Some(IndexPath::default()),
+                window,
+                cx,
+            )
+        });
+        let _subscriptions = vec![
+            cx.subscribe_in(&input, window, Self::on_input_event),
+            cx.subscribe_in(&zone, window, Self::on_zone_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            zone,
+            parsed: None,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_parsed(cx);
+        }
+    }
+
+    fn on_zone_event(
+        &mut self,
+        _: &Entity<DropdownState<SearchableVec<SharedString>>>,
+        e: &DropdownEvent<SearchableVec<SharedString>>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let DropdownEvent::Confirm(_) = e;
+        self.update_parsed(cx);
+    }
+
+    fn update_parsed(&mut self, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        let zone = self.zone.read(cx).selected_value().cloned();
+        let parsed = match value.trim() {
+            "" => Ok(None),
+            value => {
+                parse_any_date(value, zone.as_ref().map_or("Local", |zone| zone.as_str())).map(Some)
+            }
+        };
+        match parsed {
+            Ok(parsed) => {
+                self.parsed = parsed;
+                self.error = None;
+            }
+            Err(err) => {
+                self.parsed = None;
+                self.error = Some(err);
+            }
+        }
+        cx.notify();
+    }
+
+    fn on_parse_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.update_parsed(cx);
+    }
+
+    fn on_now_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = Utc::now().to_rfc3339();
+        self.input.update(cx, |state, cx| {
+            state.set_value(value, window, cx);
+        });
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.input.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+}
+
+impl Tool for DateParserTool {
+    fn title() -> &'static str {
+        "Date Format Parser"
+    }
+
+    fn short_title() -> &'static str {
+        "Date Parser"
+    }
+
+    fn description() -> &'static str {
+        "Reads dates in ISO 8601, RFC 2822, HTTP, and JavaScript formats and writes them in all the others."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_parse_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        let state = State {
+            input: self.input.read(cx).value().to_string(),
+            zone: self.zone.read(cx).selected_value().cloned(),
+        };
+        serde_json::to_value(state).ok()
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(state) = State::deserialize(state) else {
+            return;
+        };
+        if let Some(zone) = state.zone {
+            self.zone.update(cx, |dropdown, cx| {
+                dropdown.set_selected_value(&zone, window, cx);
+            });
+        }
+        self.input.update(cx, |input, cx| {
+            input.set_value(state.input, window, cx);
+        });
+    }
+}
+
+impl Focusable for DateParserTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for DateParserTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let formats = self
+            .parsed
+            .as_ref()
+            .map(|parsed| date_formats(parsed.at))
+            .unwrap_or_default();
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("parse-button")
+                            .label("Parse")
+                            .primary()
+                            .on_click(cx.listener(Self::on_parse_click)),
+                    )
+                    .child(
+                        Button::new("now-button")
+                            .label("Now")
+                            .on_click(cx.listener(Self::on_now_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.input)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        div().w(px(240.)).flex_none().child(
+                            Dropdown::new(&self.zone)
+                                .placeholder("Timezone")
+                                .menu_width(px(320.)),
+                        ),
+                    ),
+            )
+            .when_some(self.parsed.as_ref(), |this, parsed| {
+                this.child(Label::new(format!("Read as {}", parsed.format)).mt_4())
+            })
+            .child(
+                v_flex()
+                    .id("formats")
+                    .flex_1()
+                    .overflow_y_scroll()
+                    .children(formats.into_iter().enumerate().map(|(ix, (name, value))| {
+                        h_flex()
+                            .h(px(36.))
+                            .gap_4()
+                            .px_2()
+                            .border_b_1()
+                            .border_color(cx.theme().border)
+                            .child(
+                                div()
+                                    .w(px(200.))
+                                    .flex_none()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(name),
+                            )
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .font_family(editor_font(cx))
+                                    .child(value.clone()),
+                            )
+                            .child(Clipboard::new(("format-clipboard", ix)).value(value))
+                    })),
+            )
+    }
+}
@@ -0,0 +1,261 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, StatefulInteractiveElement, Styled,
+    Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::duration::{self, NumberUnit},
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = date_time_tools, no_json)]
+pub struct SetNumberUnit(NumberUnit);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    unit: NumberUnit,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct DurationParserTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    /// The unit of durations written as a bare number.
+    unit: NumberUnit,
+    fields: Vec<(&'static str, Entity<InputState>)>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl DurationParserTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("Duration, e.g. 1h30m, PT2H30M, or 5400000")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&input, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            unit: NumberUnit::default(),
+            fields: Vec::new(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_fields(window, cx);
+        }
+    }
+
+    fn update_fields(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        self.error = None;
+        if value.trim().is_empty() {
+            self.fields.clear();
+            cx.notify();
+            return;
+        }
+        match duration::parse(&value, self.unit) {
+            Ok(parsed) => {
+                self.fields = duration::formats(parsed)
+                    .into_iter()
+                    .map(|(name, value)| {
+                        (
+                            name,
+                            cx.new(|cx| InputState::new(window, cx).default_value(value)),
+                        )
+                    })
+                    .collect();
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_parse_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_fields(window, cx);
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.input.update(cx, |state, cx| {
+                state.set_value(value.trim().to_string(), window, cx);
+            });
+            self.update_fields(window, cx);
+        }
+    }
+
+    fn on_action_set_number_unit(
+        &mut self,
+        action: &SetNumberUnit,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.unit = action.0;
+        self.update_fields(window, cx);
+    }
+}
+
+impl Tool for DurationParserTool {
+    fn title() -> &'static str {
+        "Duration Parser"
+    }
+
+    fn short_title() -> &'static str {
+        "Duration"
+    }
+
+    fn description() -> &'static str {
+        "Reads durations such as 1h30m, PT2H30M, or milliseconds and writes them out in words and in each unit."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_parse_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            unit: self.unit,
+            input: include_input.then(|| self.input.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        if let Some(input) = preset.input {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.on_action_set_number_unit(&SetNumberUnit(preset.unit), window, cx);
+    }
+}
+
+impl Focusable for DurationParserTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for DurationParserTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.input.read(cx).value();
+        let unit = self.unit;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_number_unit))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("parse-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("parse-button")
+                                    .label("Parse")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_parse_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                NumberUnit::ALL.into_iter().fold(
+                                    this.label("Bare Numbers In"),
+                                    |this, number_unit| {
+                                        this.menu_with_check(
+                                            number_unit.name(),
+                                            unit == number_unit,
+                                            Box::new(SetNumberUnit(number_unit)),
+                                        )
+                                    },
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.input)
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                div()
+                    .id("fields")
+                    .v_flex()
+                    .flex_1()
+                    .gap_2()
+                    .mt_4()
+                    .overflow_y_scroll()
+                    .children(self.fields.iter().enumerate().map(|(i, (name, state))| {
+                        let value = state.read(cx).value().clone();
+                        h_flex().gap_2().child(Label::new(*name).w(px(160.))).child(
+                            TextInput::new(state)
+                                .text_size(px(15.))
+                                .focus_bordered(false)
+                                .suffix(
+                                    Clipboard::new(SharedString::from(format!(
+                                        "field-clipboard-{}",
+                                        i
+                                    )))
+                                    .value(value),
+                                ),
+                        )
+                    })),
+            )
+    }
+}
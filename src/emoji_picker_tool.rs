@@ -0,0 +1,343 @@
+use std::ops::Range;
+
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Div, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, StatefulInteractiveElement, Styled,
+    Subscription, Window, div, prelude::FluentBuilder, px, uniform_list,
+};
+
+use gpui_component::{
+    ActiveTheme, Selectable, Sizable, StyledExt,
+    button::Button,
+    checkbox::Checkbox,
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool,
+    core::emoji::{self, Category, Symbol},
+    editor_font,
+};
+
+/// The number of symbols on each row of the grid.
+const GRID_COLUMNS: usize = 10;
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    search: String,
+    category: Option<Category>,
+    show_details: bool,
+}
+
+pub struct EmojiPickerTool {
+    focus_handle: FocusHandle,
+    search: Entity<InputState>,
+    category: Option<Category>,
+    show_details: bool,
+    symbols: Vec<Symbol>,
+    /// The symbol last clicked, and so copied.
+    selected: Option<Symbol>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl EmojiPickerTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let search = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("Search, e.g. rocket, :tada:, arrow, or U+2192")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&search, window, Self::on_search_event)];
+
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            search,
+            category: None,
+            show_details: false,
+            symbols: Vec::new(),
+            selected: None,
+            _subscriptions,
+        };
+        this.filter(cx);
+        this
+    }
+
+    fn filter(&mut self, cx: &mut Context<Self>) {
+        let query = self.search.read(cx).value().clone();
+        self.symbols = emoji::search(&query, self.category);
+        cx.notify();
+    }
+
+    fn on_search_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.filter(cx);
+        }
+    }
+
+    fn on_show_details_click(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.show_details = *checked;
+        cx.notify();
+    }
+
+    fn set_category(&mut self, category: Option<Category>, cx: &mut Context<Self>) {
+        self.category = category;
+        self.filter(cx);
+    }
+
+    fn select(&mut self, symbol: Symbol, cx: &mut Context<Self>) {
+        cx.write_to_clipboard(ClipboardItem::new_string(symbol.text.to_string()));
+        self.selected = Some(symbol);
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if let Some(symbol) = self.selected {
+            cx.write_to_clipboard(ClipboardItem::new_string(symbol.text.to_string()));
+        }
+    }
+
+    fn render_rows(
+        &mut self,
+        range: Range<usize>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Vec<Div> {
+        let theme = cx.theme();
+        let (hover, selected_bg, muted) = (theme.accent, theme.secondary, theme.muted_foreground);
+        let font = editor_font(cx);
+        let height = if self.show_details { 88. } else { 52. };
+        range
+            .map(|row| {
+                let start = row * GRID_COLUMNS;
+                let end = (start + GRID_COLUMNS).min(self.symbols.len());
+                h_flex()
+                    .h(px(height))
+                    .gap_1()
+                    .children((start..end).map(|i| {
+                        let symbol = self.symbols[i];
+                        div()
+                            .id(("symbol", i))
+                            .flex_1()
+                            .min_w_0()
+                            .h_full()
+                            .v_flex()
+                            .items_center()
+                            .justify_center()
+                            .rounded(cx.theme().radius)
+                            .cursor_pointer()
+                            .when(self.selected == Some(symbol), |this| this.bg(selected_bg))
+                            .hover(|this| this.bg(hover))
+                            .on_click(cx.listener(move |this, _: &ClickEvent, _, cx| {
+                                this.select(symbol, cx);
+                            }))
+                            .child(div().text_size(px(28.)).child(symbol.text))
+                            .when(self.show_details, |this| {
+                                this.child(
+                                    div()
+                                        .w_full()
+                                        .px_1()
+                                        .text_size(px(11.))
+                                        .text_color(muted)
+                                        .text_center()
+                                        .truncate()
+                                        .font_family(font.clone())
+                                        .child(symbol.code_points()),
+                                )
+                                .child(
+                                    div()
+                                        .w_full()
+                                        .px_1()
+                                        .text_size(px(11.))
+                                        .text_center()
+                                        .truncate()
+                                        .child(
+                                            symbol
+                                                .shortcode
+                                                .map(|code| format!(":{}:", code))
+                                                .unwrap_or_default(),
+                                        ),
+                                )
+                            })
+                    }))
+                    // Keeps the symbols of the last row lined up with the rest.
+                    .children((end..start + GRID_COLUMNS).map(|_| div().flex_1()))
+            })
+            .collect()
+    }
+
+    fn render_selected(&self, symbol: Symbol, cx: &mut Context<Self>) -> Div {
+        let font = editor_font(cx);
+        let detail = |id: &'static str, label: &'static str, value: String| {
+            h_flex()
+                .gap_2()
+                .child(Label::new(label).w(px(96.)))
+                .child(div().font_family(font.clone()).child(value.clone()))
+                .child(Clipboard::new(id).value(SharedString::from(value)))
+        };
+
+        h_flex()
+            .gap_4()
+            .p_2()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .child(div().text_size(px(40.)).child(symbol.text))
+            .child(
+                div().v_flex().gap_1().child(Label::new(symbol.name)).child(
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(symbol.category.name()),
+                ),
+            )
+            .child(
+                div()
+                    .v_flex()
+                    .gap_1()
+                    .ml_auto()
+                    .child(detail(
+                        "code-points-clipboard",
+                        "Code Points",
+                        symbol.code_points(),
+                    ))
+                    .when_some(symbol.shortcode, |this, code| {
+                        this.child(detail(
+                            "shortcode-clipboard",
+                            "Shortcode",
+                            format!(":{}:", code),
+                        ))
+                    }),
+            )
+    }
+}
+
+impl Tool for EmojiPickerTool {
+    fn title() -> &'static str {
+        "Emoji Picker"
+    }
+
+    fn short_title() -> &'static str {
+        "Emoji"
+    }
+
+    fn description() -> &'static str {
+        "Finds emoji, arrows, math, and box drawing symbols by name or shortcode and copies them with a click."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        let state = State {
+            search: self.search.read(cx).value().to_string(),
+            category: self.category,
+            show_details: self.show_details,
+        };
+        serde_json::to_value(state).ok()
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(state) = State::deserialize(state) else {
+            return;
+        };
+        self.category = state.category;
+        self.show_details = state.show_details;
+        self.search.update(cx, |input, cx| {
+            input.set_value(state.search, window, cx);
+        });
+        self.filter(cx);
+    }
+}
+
+impl Focusable for EmojiPickerTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for EmojiPickerTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let rows = self.symbols.len().div_ceil(GRID_COLUMNS);
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.search)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        Checkbox::new("show-details-checkbox")
+                            .label("Show Code Points")
+                            .checked(self.show_details)
+                            .on_click(cx.listener(Self::on_show_details_click)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_1()
+                    .flex_wrap()
+                    .child(
+                        Button::new("category-all-button")
+                            .label("All")
+                            .small()
+                            .selected(self.category.is_none())
+                            .on_click(cx.listener(|this, _: &ClickEvent, _, cx| {
+                                this.set_category(None, cx);
+                            })),
+                    )
+                    .children(Category::ALL.into_iter().enumerate().map(|(i, category)| {
+                        Button::new(("category-button", i))
+                            .label(category.name())
+                            .small()
+                            .selected(self.category == Some(category))
+                            .on_click(cx.listener(move |this, _: &ClickEvent, _, cx| {
+                                this.set_category(Some(category), cx);
+                            }))
+                    })),
+            )
+            .child(Label::new(format!("{} symbols", self.symbols.len())))
+            .child(
+                uniform_list(
+                    "symbols",
+                    rows,
+                    cx.processor(|this, range: Range<usize>, window, cx| {
+                        this.render_rows(range, window, cx)
+                    }),
+                )
+                .flex_1(),
+            )
+            .when_some(self.selected, |this, symbol| {
+                this.child(self.render_selected(symbol, cx))
+            })
+    }
+}
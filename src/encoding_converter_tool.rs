@@ -0,0 +1,374 @@
+use std::ops::Range;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::encoding::{self, Encoding},
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetEncoding(Encoding);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    encoding: Encoding,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+/// How many invalid sequences are spelled out before the rest are counted.
+const INVALID_LISTED: usize = 8;
+
+pub struct EncodingConverterTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    encoded: Entity<InputState>,
+    encoding: Encoding,
+    /// The bytes last encoded or decoded.
+    bytes: Vec<u8>,
+    /// The byte ranges of invalid sequences found by the last decode.
+    invalid: Vec<Range<usize>>,
+    error: Option<ToolError>,
+}
+
+impl EncodingConverterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text")
+        });
+        let encoded = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Hex Bytes")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            encoded,
+            encoding: Encoding::UTF8,
+            bytes: Vec::new(),
+            invalid: Vec::new(),
+            error: None,
+        }
+    }
+
+    fn on_encode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        self.invalid = Vec::new();
+        match encoding::encode(&value, self.encoding) {
+            Ok(bytes) => {
+                let encoded_value = encoding::to_hex(&bytes);
+                self.bytes = bytes;
+                self.error = None;
+                self.encoded.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(encoded_value), window, cx);
+                });
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_decode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.encoded.read(cx).value().clone();
+        match encoding::parse_hex(&value) {
+            Ok(bytes) => {
+                let decoded = encoding::decode(&bytes, self.encoding);
+                self.bytes = bytes;
+                self.invalid = decoded.invalid;
+                self.error = None;
+                self.editor.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(decoded.text), window, cx);
+                });
+            }
+            Err(err) => {
+                self.invalid = Vec::new();
+                self.error = Some(err);
+            }
+        }
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            });
+            self.on_encode_click(&ClickEvent::default(), window, cx);
+        }
+    }
+
+    fn on_copy_encoded_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.encoded.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_encoded_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.encoded.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            });
+            self.on_decode_click(&ClickEvent::default(), window, cx);
+        }
+    }
+
+    /// Switching encodings re-encodes the text, which is how text decoded
+    /// from one encoding is converted to another.
+    fn on_action_set_encoding(
+        &mut self,
+        action: &SetEncoding,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.encoding = action.0;
+        if !self.editor.read(cx).value().is_empty() {
+            self.on_encode_click(&ClickEvent::default(), window, cx);
+        }
+        cx.notify();
+    }
+
+    fn invalid_description(&self) -> String {
+        let listed = self
+            .invalid
+            .iter()
+            .take(INVALID_LISTED)
+            .map(|range| {
+                format!(
+                    "{} at byte {}",
+                    encoding::to_hex(&self.bytes[range.clone()]),
+                    range.start
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let more = match self.invalid.len().saturating_sub(INVALID_LISTED) {
+            0 => String::new(),
+            more => format!(", and {} more", more),
+        };
+        match self.invalid.len() {
+            1 => format!("1 invalid {} sequence: {}", self.encoding.name(), listed),
+            count => format!(
+                "{} invalid {} sequences: {}{}",
+                count,
+                self.encoding.name(),
+                listed,
+                more
+            ),
+        }
+    }
+}
+
+impl Tool for EncodingConverterTool {
+    fn title() -> &'static str {
+        "Encoding Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "Encoding"
+    }
+
+    fn description() -> &'static str {
+        "Encodes text as UTF-8, UTF-16, or Latin-1 bytes in hex and decodes hex bytes, flagging invalid sequences."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_encode_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_encoded_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.encoded.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            encoding: self.encoding,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.on_action_set_encoding(&SetEncoding(preset.encoding), window, cx);
+    }
+}
+
+impl Focusable for EncodingConverterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for EncodingConverterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let encoded = self.encoded.read(cx).value();
+        let encoding = self.encoding;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_encoding))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("encode-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("encode-button")
+                                    .label(format!("Encode ({})", encoding.name()))
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_encode_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                Encoding::ALL.into_iter().fold(
+                                    this.label("Encoding"),
+                                    |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            encoding == option,
+                                            Box::new(SetEncoding(option)),
+                                        )
+                                    },
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("decode-button")
+                            .label(format!("Decode ({})", encoding.name()))
+                            .disabled(encoded.is_empty())
+                            .on_click(cx.listener(Self::on_decode_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .when(!self.bytes.is_empty(), |this| {
+                        this.child(Label::new(format!("{} bytes", self.bytes.len())))
+                    })
+                    .child(
+                        Button::new("copy-encoded-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_encoded_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-encoded-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_encoded_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.encoded)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .when(!self.invalid.is_empty(), |this| {
+                this.child(
+                    div()
+                        .text_color(cx.theme().red)
+                        .child(self.invalid_description()),
+                )
+            })
+    }
+}
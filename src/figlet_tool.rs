@@ -0,0 +1,263 @@
+use figlet_rs::FIGlet;
+
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    ParentElement, Render, Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    Selectable, Sizable, StyledExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::figlet::{self, Font},
+    editor_font,
+};
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    #[serde(default)]
+    font: Font,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct FigletTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    output: Entity<InputState>,
+    font: Font,
+    /// The chosen font, loaded once rather than for every keystroke.
+    figlet: Option<FIGlet>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl FigletTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .multi_line()
+                .default_value("Hello")
+                .placeholder("Text")
+        });
+        let output = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .soft_wrap(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("ASCII art")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&input, window, Self::on_input_event)];
+
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            output,
+            font: Font::default(),
+            figlet: None,
+            error: None,
+            _subscriptions,
+        };
+        this.set_font(Font::default(), window, cx);
+        this
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_output(window, cx);
+        }
+    }
+
+    fn set_font(&mut self, font: Font, window: &mut Window, cx: &mut Context<Self>) {
+        self.font = font;
+        self.figlet = match figlet::load(font) {
+            Ok(figlet) => Some(figlet),
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        };
+        self.update_output(window, cx);
+    }
+
+    fn update_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(figlet) = &self.figlet else {
+            cx.notify();
+            return;
+        };
+        let value = self.input.read(cx).value().clone();
+        let banner = figlet::render(figlet, &value);
+        self.error = (!banner.missing.is_empty()).then(|| {
+            ToolError::new(format!(
+                "{} has no glyphs for {}",
+                self.font.name(),
+                banner
+                    .missing
+                    .iter()
+                    .map(|c| format!("{:?}", c))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        });
+        self.output.update(cx, |state, cx| {
+            state.set_value(banner.text, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.output.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.input.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            });
+        }
+    }
+}
+
+impl Tool for FigletTool {
+    fn title() -> &'static str {
+        "ASCII Art Generator"
+    }
+
+    fn short_title() -> &'static str {
+        "ASCII Art"
+    }
+
+    fn description() -> &'static str {
+        "Draws text as ASCII art banners in a choice of bundled FIGlet fonts."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.output.clone())
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            font: self.font,
+            input: include_input.then(|| self.input.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        if let Some(input) = preset.input {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.set_font(preset.font, window, cx);
+    }
+}
+
+impl Focusable for FigletTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for FigletTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .primary()
+                            .on_click(cx.listener(Self::on_copy_click)),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.input)
+                    .h(px(96.))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .gap_1()
+                    .flex_wrap()
+                    .child(Label::new("Font").mr_2())
+                    .children(Font::ALL.into_iter().enumerate().map(|(i, font)| {
+                        Button::new(("font-button", i))
+                            .label(font.name())
+                            .small()
+                            .selected(self.font == font)
+                            .on_click(cx.listener(move |this, _: &ClickEvent, window, cx| {
+                                this.set_font(font, window, cx);
+                            }))
+                    })),
+            )
+            .child(
+                TextInput::new(&self.output)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(13.))
+                    .focus_bordered(false),
+            )
+    }
+}
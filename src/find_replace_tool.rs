@@ -0,0 +1,372 @@
+use std::ops::Range;
+
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    HighlightStyle, InteractiveElement, ParentElement, Render, StatefulInteractiveElement,
+    StrikethroughStyle, Styled, StyledText, Subscription, Window, div, px, rems,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, Size, StyleSized, StyledExt,
+    button::{Button, ButtonVariants},
+    checkbox::Checkbox,
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::replace::{self, Options, Replacement},
+    editor_font,
+};
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    options: Options,
+    pattern: String,
+    replacement: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct FindReplaceTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    pattern: Entity<InputState>,
+    replacement: Entity<InputState>,
+    options: Options,
+    replacements: Vec<Replacement>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl FindReplaceTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text")
+        });
+        let pattern = cx.new(|cx| InputState::new(window, cx).placeholder("Find"));
+        let replacement = cx.new(|cx| InputState::new(window, cx).placeholder("Replace With"));
+        let _subscriptions = vec![
+            cx.subscribe_in(&editor, window, Self::on_input_event),
+            cx.subscribe_in(&pattern, window, Self::on_input_event),
+            cx.subscribe_in(&replacement, window, Self::on_input_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            pattern,
+            replacement,
+            options: Options::default(),
+            replacements: Vec::new(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_replacements(cx);
+        }
+    }
+
+    fn update_replacements(&mut self, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let pattern = self.pattern.read(cx).value().clone();
+        let replacement = self.replacement.read(cx).value().clone();
+        match replace::find(&value, &pattern, &replacement, self.options) {
+            Ok(replacements) => {
+                self.replacements = replacements;
+                self.error = None;
+            }
+            Err(err) => {
+                self.replacements = Vec::new();
+                self.error = Some(err);
+            }
+        }
+        cx.notify();
+    }
+
+    fn replaced(&self, cx: &App) -> String {
+        replace::apply(&self.editor.read(cx).value(), &self.replacements)
+    }
+
+    fn on_replace_all_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.replacements.is_empty() {
+            return;
+        }
+        let value = self.replaced(cx);
+        self.editor.update(cx, |state, cx| {
+            state.set_value(value, window, cx);
+        });
+        self.update_replacements(cx);
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            });
+            self.update_replacements(cx);
+        }
+    }
+
+    fn on_copy_replaced_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.replaced(cx);
+        cx.write_to_clipboard(ClipboardItem::new_string(value));
+    }
+
+    fn on_regex_click(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.options.regex = *checked;
+        self.update_replacements(cx);
+    }
+
+    fn on_ignore_case_click(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.options.ignore_case = *checked;
+        self.update_replacements(cx);
+    }
+
+    /// Lays out the text with each match struck through, followed by what
+    /// replaces it.
+    fn preview(&self, cx: &App) -> (String, Vec<(Range<usize>, HighlightStyle)>) {
+        let value = self.editor.read(cx).value();
+        let removed = HighlightStyle {
+            background_color: Some(cx.theme().red.opacity(0.25)),
+            strikethrough: Some(StrikethroughStyle {
+                thickness: px(1.),
+                color: Some(cx.theme().red),
+            }),
+            ..Default::default()
+        };
+        let inserted = HighlightStyle {
+            background_color: Some(cx.theme().green.opacity(0.25)),
+            ..Default::default()
+        };
+
+        let mut text = String::with_capacity(value.len());
+        let mut highlights = Vec::new();
+        let mut last = 0;
+        for replacement in &self.replacements {
+            text.push_str(&value[last..replacement.range.start]);
+            let start = text.len();
+            text.push_str(&value[replacement.range.clone()]);
+            highlights.push((start..text.len(), removed));
+            let start = text.len();
+            text.push_str(&replacement.with);
+            highlights.push((start..text.len(), inserted));
+            last = replacement.range.end;
+        }
+        text.push_str(&value[last..]);
+        highlights.retain(|(range, _)| !range.is_empty());
+        (text, highlights)
+    }
+}
+
+impl Tool for FindReplaceTool {
+    fn title() -> &'static str {
+        "Find and Replace"
+    }
+
+    fn short_title() -> &'static str {
+        "Replace"
+    }
+
+    fn description() -> &'static str {
+        "Finds and replaces text or regular expressions, with capture groups and a preview of the changes."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_replace_all_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_replaced_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            options: self.options,
+            pattern: self.pattern.read(cx).value().to_string(),
+            replacement: self.replacement.read(cx).value().to_string(),
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.options = preset.options;
+        self.pattern.update(cx, |state, cx| {
+            state.set_value(preset.pattern, window, cx);
+        });
+        self.replacement.update(cx, |state, cx| {
+            state.set_value(preset.replacement, window, cx);
+        });
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.update_replacements(cx);
+    }
+}
+
+impl Focusable for FindReplaceTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for FindReplaceTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let (preview, highlights) = self.preview(cx);
+        let status = match self.replacements.len() {
+            1 => "1 match".to_string(),
+            count => format!("{} matches", count),
+        };
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("replace-all-button")
+                            .label("Replace All")
+                            .primary()
+                            .disabled(self.replacements.is_empty())
+                            .on_click(cx.listener(Self::on_replace_all_click)),
+                    )
+                    .child(
+                        Checkbox::new("regex-checkbox")
+                            .label("Regex")
+                            .checked(self.options.regex)
+                            .on_click(cx.listener(Self::on_regex_click)),
+                    )
+                    .child(
+                        Checkbox::new("ignore-case-checkbox")
+                            .label("Ignore Case")
+                            .checked(self.options.ignore_case)
+                            .on_click(cx.listener(Self::on_ignore_case_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.pattern)
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.replacement)
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(Label::new(status)).child(
+                    Button::new("copy-replaced-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_replaced_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                div()
+                    .id("preview")
+                    .size_full()
+                    .overflow_y_scroll()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .line_height(rems(1.25))
+                    .bg(cx.theme().background)
+                    .text_color(cx.theme().foreground)
+                    .rounded(cx.theme().radius)
+                    .border_color(cx.theme().input)
+                    .border_1()
+                    .input_px(Size::default())
+                    .input_py(Size::default())
+                    .child(StyledText::new(preview).with_highlights(highlights)),
+            )
+    }
+}
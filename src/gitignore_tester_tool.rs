@@ -0,0 +1,407 @@
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    ParentElement, Render, SharedString, Styled, Subscription, Window, div, prelude::FluentBuilder,
+    px,
+};
+
+use gpui_component::{
+    StyledExt,
+    button::Button,
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use ignore::{
+    Match,
+    gitignore::{Gitignore, GitignoreBuilder},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+struct Rule {
+    line: usize,
+    pattern: String,
+    matcher: Gitignore,
+}
+
+struct RuleMatch<'a> {
+    rule: &'a Rule,
+    ignored: bool,
+    parent: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    rules: String,
+    paths: String,
+}
+
+pub struct GitignoreTesterTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    paths: Entity<InputState>,
+    results: Entity<InputState>,
+    ignored_count: Option<(usize, usize)>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl GitignoreTesterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder(".gitignore")
+        });
+        let paths = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Paths, one per line (end directories with /)")
+        });
+        let results = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Results")
+        });
+        let _subscriptions = vec![
+            cx.subscribe_in(&editor, window, Self::on_input_event),
+            cx.subscribe_in(&paths, window, Self::on_input_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            paths,
+            results,
+            ignored_count: None,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_results(window, cx);
+        }
+    }
+
+    fn update_results(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let source = self.editor.read(cx).value().clone();
+        let paths = self.paths.read(cx).value().clone();
+
+        let results_value = match parse_rules(&source) {
+            Ok(rules) => {
+                self.error = None;
+                let paths: Vec<&str> = paths
+                    .lines()
+                    .map(str::trim)
+                    .filter(|path| !path.is_empty())
+                    .collect();
+                let results: Vec<(&str, Option<RuleMatch>)> = paths
+                    .iter()
+                    .map(|path| (*path, test_path(&rules, path)))
+                    .collect();
+                let ignored = results
+                    .iter()
+                    .filter(|(_, result)| result.as_ref().is_some_and(|result| result.ignored))
+                    .count();
+                self.ignored_count = (!paths.is_empty()).then_some((ignored, paths.len()));
+                results
+                    .iter()
+                    .map(|(path, result)| format_result(path, result.as_ref()))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            Err(err) => {
+                self.ignored_count = None;
+                self.error = Some(err);
+                String::new()
+            }
+        };
+
+        self.results.update(cx, |state, cx| {
+            state.set_value(SharedString::from(results_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_paste_paths_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.paths.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_results_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.results.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+}
+
+/// Builds one matcher per pattern so that a match can be traced back to the
+/// line it came from.
+fn parse_rules(source: &str) -> Result<Vec<Rule>, ToolError> {
+    let invalid = |i: usize, err: ignore::Error| {
+        ToolError::new(err.to_string()).context(&format!("Invalid pattern on line {}", i + 1))
+    };
+    let mut rules = Vec::new();
+    for (i, line) in source.lines().enumerate() {
+        let mut builder = GitignoreBuilder::new("");
+        builder
+            .add_line(None, line)
+            .map_err(|err| invalid(i, err))?;
+        let matcher = builder.build().map_err(|err| invalid(i, err))?;
+        if !matcher.is_empty() {
+            rules.push(Rule {
+                line: i + 1,
+                pattern: line.trim_end().to_string(),
+                matcher,
+            });
+        }
+    }
+    Ok(rules)
+}
+
+fn last_match<'a>(rules: &'a [Rule], path: &str, is_dir: bool) -> Option<RuleMatch<'a>> {
+    // Later patterns take precedence, so the first match from the bottom
+    // decides.
+    rules
+        .iter()
+        .rev()
+        .find_map(|rule| match rule.matcher.matched(path, is_dir) {
+            Match::None => None,
+            matched => Some(RuleMatch {
+                rule,
+                ignored: matched.is_ignore(),
+                parent: None,
+            }),
+        })
+}
+
+fn test_path<'a>(rules: &'a [Rule], path: &str) -> Option<RuleMatch<'a>> {
+    let is_dir = path.ends_with('/');
+    let path = path.trim_start_matches("./").trim_matches('/');
+    let segments: Vec<&str> = path.split('/').collect();
+
+    // Git does not descend into excluded directories, so a negation cannot
+    // bring back anything beneath one.
+    for end in 1..segments.len() {
+        let parent = segments[..end].join("/");
+        if let Some(matched) = last_match(rules, &parent, true)
+            && matched.ignored
+        {
+            return Some(RuleMatch {
+                parent: Some(format!("{}/", parent)),
+                ..matched
+            });
+        }
+    }
+    last_match(rules, path, is_dir)
+}
+
+fn format_result(path: &str, result: Option<&RuleMatch>) -> String {
+    match result {
+        None => format!("Not Ignored\t{}", path),
+        Some(result) => {
+            let status = if result.ignored {
+                "Ignored"
+            } else {
+                "Not Ignored"
+            };
+            let via = match &result.parent {
+                Some(parent) => format!(" (parent {} is ignored)", parent),
+                None => String::new(),
+            };
+            format!(
+                "{}\t{}\tline {}: {}{}",
+                status, path, result.rule.line, result.rule.pattern, via
+            )
+        }
+    }
+}
+
+impl Tool for GitignoreTesterTool {
+    fn title() -> &'static str {
+        ".gitignore Tester"
+    }
+
+    fn short_title() -> &'static str {
+        ".gitignore Tester"
+    }
+
+    fn description() -> &'static str {
+        "Tests file paths against .gitignore rules and shows which rule matched each one."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_results_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.results.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        let state = State {
+            rules: self.editor.read(cx).value().to_string(),
+            paths: self.paths.read(cx).value().to_string(),
+        };
+        serde_json::to_value(state).ok()
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(state) = State::deserialize(state) else {
+            return;
+        };
+        self.editor.update(cx, |editor, cx| {
+            editor.set_value(state.rules, window, cx);
+        });
+        self.paths.update(cx, |paths, cx| {
+            paths.set_value(state.paths, window, cx);
+        });
+    }
+}
+
+impl Focusable for GitignoreTesterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for GitignoreTesterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    )
+                    .child(
+                        Button::new("paste-paths-button")
+                            .label("Paste Paths")
+                            .on_click(cx.listener(Self::on_paste_paths_click)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .size_full()
+                    .gap_2()
+                    .child(
+                        div().size_full().child(
+                            TextInput::new(&self.editor)
+                                .h_full()
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        div().size_full().child(
+                            TextInput::new(&self.paths)
+                                .h_full()
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .when_some(self.ignored_count, |this, (ignored, total)| {
+                        this.child(Label::new(format!("{} of {} ignored", ignored, total)))
+                    })
+                    .child(
+                        Button::new("copy-results-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_results_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.results)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
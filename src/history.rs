@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+
+use gpui::Global;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config;
+
+/// The number of runs kept for each tool.
+pub const MAX_ENTRIES: usize = 20;
+
+/// A past run of a tool: its state, input included, and the output it gave.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub state: Value,
+    pub output: String,
+    /// When the run happened, in seconds since the Unix epoch.
+    pub timestamp: i64,
+}
+
+/// The last few runs of each tool, newest first and keyed by tool class. It
+/// is kept in `history.json` next to the settings.
+#[derive(Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    tools: BTreeMap<String, Vec<HistoryEntry>>,
+}
+
+impl Global for History {}
+
+impl History {
+    /// Loads the saved history, starting afresh if there is none or the file
+    /// cannot be read.
+    pub fn load() -> Self {
+        config::load("history.json")
+    }
+
+    pub fn save(&self) -> Result<()> {
+        config::save("history.json", self)
+    }
+
+    pub fn entries(&self, klass: &str) -> &[HistoryEntry] {
+        self.tools.get(klass).map_or(&[], |entries| entries)
+    }
+
+    /// Records a run, dropping any earlier run with the same state and output
+    /// and the oldest once there are more than `MAX_ENTRIES`.
+    pub fn record(&mut self, klass: &str, entry: HistoryEntry) {
+        let entries = self.tools.entry(klass.to_string()).or_default();
+        entries.retain(|e| e.state != entry.state || e.output != entry.output);
+        entries.insert(0, entry);
+        entries.truncate(MAX_ENTRIES);
+    }
+
+    pub fn clear(&mut self, klass: &str) {
+        self.tools.remove(klass);
+    }
+}
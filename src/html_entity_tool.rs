@@ -0,0 +1,403 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool,
+    core::html::{EntityStyle, decode_entities, encode_entities, has_entities},
+    editor_font,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Direction {
+    Auto,
+    Encode,
+    Decode,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = html_tools, no_json)]
+pub struct SetDirection(Direction);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = html_tools, no_json)]
+pub struct SetEntityStyle(EntityStyle);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = html_tools, no_json)]
+pub struct SetEncodeNonASCII(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    direction: Direction,
+    #[serde(default)]
+    entity_style: EntityStyle,
+    #[serde(default)]
+    encode_non_ascii: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct HTMLEntityTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    converted: Entity<InputState>,
+    direction: Direction,
+    entity_style: EntityStyle,
+    /// Whether encoding covers every non-ASCII character, rather than only
+    /// the characters reserved in HTML.
+    encode_non_ascii: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl HTMLEntityTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text or Encoded HTML")
+        });
+        let converted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Converted")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            converted,
+            direction: Direction::Auto,
+            entity_style: EntityStyle::Named,
+            encode_non_ascii: false,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_converted(window, cx);
+        }
+    }
+
+    /// The direction to convert in, with `Auto` resolved against the input.
+    fn effective_direction(&self, cx: &App) -> Direction {
+        match self.direction {
+            Direction::Auto => {
+                if has_entities(&self.editor.read(cx).value()) {
+                    Direction::Decode
+                } else {
+                    Direction::Encode
+                }
+            }
+            direction => direction,
+        }
+    }
+
+    fn update_converted(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let converted_value = match self.effective_direction(cx) {
+            Direction::Decode => decode_entities(&value),
+            _ => encode_entities(&value, self.entity_style, self.encode_non_ascii),
+        };
+        self.converted.update(cx, |state, cx| {
+            state.set_value(SharedString::from(converted_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_converted(window, cx);
+    }
+
+    fn on_swap_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.direction = match self.direction {
+            Direction::Auto => Direction::Auto,
+            Direction::Encode => Direction::Decode,
+            Direction::Decode => Direction::Encode,
+        };
+        let value = self.converted.read(cx).value().clone();
+        self.editor.update(cx, |state, cx| {
+            state.set_value(value, window, cx);
+        });
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_converted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.converted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_direction(
+        &mut self,
+        action: &SetDirection,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.direction = action.0;
+        self.update_converted(window, cx);
+    }
+
+    fn on_action_set_entity_style(
+        &mut self,
+        action: &SetEntityStyle,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.entity_style = action.0;
+        self.update_converted(window, cx);
+    }
+
+    fn on_action_set_encode_non_ascii(
+        &mut self,
+        action: &SetEncodeNonASCII,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.encode_non_ascii = action.0;
+        self.update_converted(window, cx);
+    }
+}
+
+impl Tool for HTMLEntityTool {
+    fn title() -> &'static str {
+        "HTML Entity Encoder/Decoder"
+    }
+
+    fn short_title() -> &'static str {
+        "Entities"
+    }
+
+    fn description() -> &'static str {
+        "Converts text to and from named, decimal, or hex HTML entities as you type."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_converted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.converted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            direction: self.direction,
+            entity_style: self.entity_style,
+            encode_non_ascii: self.encode_non_ascii,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.entity_style = preset.entity_style;
+        self.encode_non_ascii = preset.encode_non_ascii;
+        self.on_action_set_direction(&SetDirection(preset.direction), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for HTMLEntityTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for HTMLEntityTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let direction = self.direction;
+        let entity_style = self.entity_style;
+        let encode_non_ascii = self.encode_non_ascii;
+        let label = match (direction, self.effective_direction(cx)) {
+            (Direction::Auto, Direction::Decode) => "Decode (Auto)",
+            (Direction::Auto, _) => "Encode (Auto)",
+            (_, Direction::Decode) => "Decode",
+            _ => "Encode",
+        };
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_direction))
+            .on_action(cx.listener(Self::on_action_set_entity_style))
+            .on_action(cx.listener(Self::on_action_set_encode_non_ascii))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("convert-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("convert-button")
+                                    .label(label)
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Direction")
+                                    .menu_with_check(
+                                        "Auto-Detect",
+                                        direction == Direction::Auto,
+                                        Box::new(SetDirection(Direction::Auto)),
+                                    )
+                                    .menu_with_check(
+                                        "Encode",
+                                        direction == Direction::Encode,
+                                        Box::new(SetDirection(Direction::Encode)),
+                                    )
+                                    .menu_with_check(
+                                        "Decode",
+                                        direction == Direction::Decode,
+                                        Box::new(SetDirection(Direction::Decode)),
+                                    )
+                                    .label("Entities")
+                                    .menu_with_check(
+                                        "Named (&amp;)",
+                                        entity_style == EntityStyle::Named,
+                                        Box::new(SetEntityStyle(EntityStyle::Named)),
+                                    )
+                                    .menu_with_check(
+                                        "Decimal (&#38;)",
+                                        entity_style == EntityStyle::Decimal,
+                                        Box::new(SetEntityStyle(EntityStyle::Decimal)),
+                                    )
+                                    .menu_with_check(
+                                        "Hex (&#x26;)",
+                                        entity_style == EntityStyle::Hex,
+                                        Box::new(SetEntityStyle(EntityStyle::Hex)),
+                                    )
+                                    .label("Encode")
+                                    .menu_with_check(
+                                        "Reserved Characters",
+                                        !encode_non_ascii,
+                                        Box::new(SetEncodeNonASCII(false)),
+                                    )
+                                    .menu_with_check(
+                                        "All Non-ASCII Characters",
+                                        encode_non_ascii,
+                                        Box::new(SetEncodeNonASCII(true)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("swap-button")
+                            .label("Swap")
+                            .on_click(cx.listener(Self::on_swap_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-converted-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_converted_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.converted)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,471 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, Selectable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use scraper::{ElementRef, Html};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{Tool, ToolError, editor_font};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum ExportFormat {
+    Csv,
+    Markdown,
+    Json,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = html_tools, no_json)]
+pub struct SetExportFormat(ExportFormat);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    export_format: ExportFormat,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+struct ExtractedTable {
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+}
+
+impl ExtractedTable {
+    fn column_count(&self) -> usize {
+        self.header
+            .iter()
+            .chain(self.rows.iter())
+            .map(|row| row.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        let mut names = self.header.clone().unwrap_or_default();
+        for i in names.len()..self.column_count() {
+            names.push(format!("Column {}", i + 1));
+        }
+        names
+    }
+}
+
+pub struct HTMLTableExtractorTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    exported: Entity<InputState>,
+    tables: Vec<ExtractedTable>,
+    selected_table: usize,
+    export_format: ExportFormat,
+    error: Option<ToolError>,
+}
+
+impl HTMLTableExtractorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Html.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("HTML Source")
+        });
+        let exported = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Exported Table")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            exported,
+            tables: Vec::new(),
+            selected_table: 0,
+            export_format: ExportFormat::Csv,
+            error: None,
+        }
+    }
+
+    fn update_exported(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let exported_value = match self.tables.get(self.selected_table) {
+            Some(table) => match self.export_format {
+                ExportFormat::Csv => table_to_csv(table),
+                ExportFormat::Markdown => table_to_markdown(table),
+                ExportFormat::Json => table_to_json(table),
+            },
+            None => String::new(),
+        };
+        self.exported.update(cx, |state, cx| {
+            state.set_value(SharedString::from(exported_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_extract_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        self.tables = extract_tables(&value);
+        self.selected_table = 0;
+        self.error = self
+            .tables
+            .is_empty()
+            .then(|| ToolError::new("The HTML has no table elements"));
+        self.update_exported(window, cx);
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_exported_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.exported.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_export_format(
+        &mut self,
+        action: &SetExportFormat,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.export_format = action.0;
+        self.update_exported(window, cx);
+    }
+}
+
+fn extract_tables(html: &str) -> Vec<ExtractedTable> {
+    let document = Html::parse_document(html);
+    document
+        .root_element()
+        .descendent_elements()
+        .filter(|element| element.value().name() == "table")
+        .map(extract_table)
+        .collect()
+}
+
+fn extract_table(table: ElementRef) -> ExtractedTable {
+    // Only rows that belong to this table are collected; rows of nested
+    // tables are reached through their own `table` element instead.
+    let mut header = None;
+    let mut rows = Vec::new();
+    for child in table.child_elements() {
+        let section = child.value().name();
+        let section_rows: Vec<ElementRef> = match section {
+            "tr" => vec![child],
+            "thead" | "tbody" | "tfoot" => child
+                .child_elements()
+                .filter(|row| row.value().name() == "tr")
+                .collect(),
+            _ => continue,
+        };
+        for row in section_rows {
+            let cells: Vec<ElementRef> = row
+                .child_elements()
+                .filter(|cell| matches!(cell.value().name(), "th" | "td"))
+                .collect();
+            if cells.is_empty() {
+                continue;
+            }
+            let all_headings = cells.iter().all(|cell| cell.value().name() == "th");
+            let values: Vec<String> = cells
+                .iter()
+                .flat_map(|cell| {
+                    let span = cell
+                        .attr("colspan")
+                        .and_then(|span| span.parse::<usize>().ok())
+                        .unwrap_or(1)
+                        .max(1);
+                    std::iter::repeat_n(cell_text(cell), span)
+                })
+                .collect();
+            if header.is_none() && rows.is_empty() && (section == "thead" || all_headings) {
+                header = Some(values);
+            } else {
+                rows.push(values);
+            }
+        }
+    }
+    ExtractedTable { header, rows }
+}
+
+fn cell_text(cell: &ElementRef) -> String {
+    cell.text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn table_to_csv(table: &ExtractedTable) -> String {
+    let escape = |field: &String| {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.clone()
+        }
+    };
+    table
+        .header
+        .iter()
+        .chain(table.rows.iter())
+        .map(|row| row.iter().map(escape).collect::<Vec<_>>().join(","))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn table_to_markdown(table: &ExtractedTable) -> String {
+    let column_count = table.column_count();
+    let format_row = |row: &Vec<String>| {
+        let cells: Vec<String> = (0..column_count)
+            .map(|i| {
+                row.get(i)
+                    .map(|cell| cell.replace('|', "\\|"))
+                    .unwrap_or_default()
+            })
+            .collect();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let mut lines = vec![
+        format_row(&table.column_names()),
+        format!("|{}", " --- |".repeat(column_count)),
+    ];
+    lines.extend(table.rows.iter().map(format_row));
+    lines.join("\n")
+}
+
+fn table_to_json(table: &ExtractedTable) -> String {
+    let rows: Vec<Value> = match &table.header {
+        Some(_) => {
+            let names = table.column_names();
+            table
+                .rows
+                .iter()
+                .map(|row| {
+                    let object: Map<String, Value> = names
+                        .iter()
+                        .zip(row.iter())
+                        .map(|(name, cell)| (name.clone(), Value::String(cell.clone())))
+                        .collect();
+                    Value::Object(object)
+                })
+                .collect()
+        }
+        None => table
+            .rows
+            .iter()
+            .map(|row| Value::Array(row.iter().cloned().map(Value::String).collect()))
+            .collect(),
+    };
+    serde_json::to_string_pretty(&Value::Array(rows)).unwrap_or_default()
+}
+
+impl Tool for HTMLTableExtractorTool {
+    fn title() -> &'static str {
+        "HTML Table Extractor"
+    }
+
+    fn short_title() -> &'static str {
+        "Table Extractor"
+    }
+
+    fn description() -> &'static str {
+        "Extracts tables from HTML and exports them as CSV, Markdown, or JSON."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_extract_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_exported_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.exported.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            export_format: self.export_format,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_export_format(&SetExportFormat(preset.export_format), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for HTMLTableExtractorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for HTMLTableExtractorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let export_format = self.export_format;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_export_format))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("extract-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("extract-button")
+                                    .label("Extract")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_extract_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Format")
+                                    .menu_with_check(
+                                        "CSV",
+                                        export_format == ExportFormat::Csv,
+                                        Box::new(SetExportFormat(ExportFormat::Csv)),
+                                    )
+                                    .menu_with_check(
+                                        "Markdown",
+                                        export_format == ExportFormat::Markdown,
+                                        Box::new(SetExportFormat(ExportFormat::Markdown)),
+                                    )
+                                    .menu_with_check(
+                                        "JSON",
+                                        export_format == ExportFormat::Json,
+                                        Box::new(SetExportFormat(ExportFormat::Json)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .flex_wrap()
+                    .children(self.tables.iter().enumerate().map(|(ix, table)| {
+                        Button::new(("table-button", ix))
+                            .label(format!(
+                                "Table {} ({}×{})",
+                                ix + 1,
+                                table.rows.len(),
+                                table.column_count()
+                            ))
+                            .selected(ix == self.selected_table)
+                            .on_click(cx.listener(move |this, _: &ClickEvent, window, cx| {
+                                this.selected_table = ix;
+                                this.update_exported(window, cx);
+                            }))
+                    }))
+                    .when(self.tables.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("No tables extracted"),
+                        )
+                    })
+                    .child(
+                        Button::new("copy-exported-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_exported_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.exported)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,340 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use crate::{
+    Tool, ToolError,
+    core::{
+        http::{self, Method, Request},
+        markdown::{self, HtmlOptions},
+    },
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = html_tools, no_json)]
+pub struct SetKeepImages(bool);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = html_tools, no_json)]
+pub struct SetKeepLinks(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    options: HtmlOptions,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct HTMLToMarkdownTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    converted: Entity<InputState>,
+    options: HtmlOptions,
+    summary: Option<String>,
+    busy: bool,
+    error: Option<ToolError>,
+}
+
+impl HTMLToMarkdownTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Html.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("HTML Source or URL")
+        });
+        let converted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Markdown.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Markdown")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            converted,
+            options: HtmlOptions::default(),
+            summary: None,
+            busy: false,
+            error: None,
+        }
+    }
+
+    fn set_converted(&mut self, value: String, window: &mut Window, cx: &mut Context<Self>) {
+        self.converted.update(cx, |state, cx| {
+            state.set_value(SharedString::from(value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn convert(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.busy {
+            return;
+        }
+        let value = self.editor.read(cx).value().trim().to_string();
+        let options = self.options;
+        self.error = None;
+        self.summary = None;
+
+        // A lone URL is fetched and its page converted, with relative links
+        // resolved against it.
+        let Some(url) = Url::parse(&value)
+            .ok()
+            .filter(|url| matches!(url.scheme(), "http" | "https"))
+        else {
+            self.set_converted(markdown::from_html(&value, None, options), window, cx);
+            return;
+        };
+        let request = Request {
+            method: Method::GET,
+            url: url.to_string(),
+            headers: vec![("Accept".to_string(), "text/html".to_string())],
+            body: String::new(),
+        };
+        self.busy = true;
+        cx.notify();
+        cx.spawn_in(window, async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { http::send(&request) })
+                .await;
+            this.update_in(cx, |this, window, cx| {
+                this.busy = false;
+                let converted = match result {
+                    Ok(response) => {
+                        this.summary = Some(format!("Fetched {} · {}", url, response.status));
+                        let html = String::from_utf8_lossy(&response.body);
+                        markdown::from_html(&html, Some(&url), options)
+                    }
+                    Err(err) => {
+                        this.error = Some(err);
+                        String::new()
+                    }
+                };
+                this.set_converted(converted, window, cx);
+            })
+        })
+        .detach();
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.convert(window, cx);
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_converted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.converted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_keep_images(
+        &mut self,
+        action: &SetKeepImages,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.options.images = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_keep_links(
+        &mut self,
+        action: &SetKeepLinks,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.options.links = action.0;
+        cx.notify();
+    }
+}
+
+impl Tool for HTMLToMarkdownTool {
+    fn title() -> &'static str {
+        "HTML to Markdown"
+    }
+
+    fn short_title() -> &'static str {
+        "HTML to Markdown"
+    }
+
+    fn description() -> &'static str {
+        "Converts HTML fragments or the page at a URL into clean Markdown."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.convert(window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_converted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.converted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            options: self.options,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.options = preset.options;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for HTMLToMarkdownTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for HTMLToMarkdownTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let HtmlOptions { images, links } = self.options;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_keep_images))
+            .on_action(cx.listener(Self::on_action_set_keep_links))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("convert-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("convert-button")
+                                    .label("Convert")
+                                    .primary()
+                                    .loading(self.busy)
+                                    .disabled(value.trim().is_empty() || self.busy)
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Keep")
+                                    .menu_with_check(
+                                        "Images",
+                                        images,
+                                        Box::new(SetKeepImages(!images)),
+                                    )
+                                    .menu_with_check("Links", links, Box::new(SetKeepLinks(!links)))
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .when_some(self.summary.clone(), |this, summary| {
+                        this.child(Label::new(summary))
+                    })
+                    .child(
+                        Button::new("copy-converted-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_converted_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.converted)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,388 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Subscription, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::http::{self, Method, Request},
+    default_indentation, editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = network_tools, no_json)]
+pub struct SetMethod(Method);
+
+/// Credential headers are left out, as presets, saved state and history are
+/// all written to disk.
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    method: Method,
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    headers: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct HTTPClientTool {
+    focus_handle: FocusHandle,
+    method: Method,
+    url: Entity<InputState>,
+    headers: Entity<InputState>,
+    editor: Entity<InputState>,
+    response_headers: Entity<InputState>,
+    response_body: Entity<InputState>,
+    /// The status line, timing, and size of the last response.
+    summary: Option<String>,
+    busy: bool,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl HTTPClientTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let url = cx.new(|cx| InputState::new(window, cx).placeholder("URL"));
+        let headers = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Headers (Name: Value)")
+        });
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Request Body")
+        });
+        let response_headers = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Response Headers")
+        });
+        let response_body = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Response Body")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&url, window, |this, _, e, window, cx| {
+            if let InputEvent::PressEnter { .. } = e {
+                this.send(window, cx);
+            }
+        })];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            method: Method::GET,
+            url,
+            headers,
+            editor,
+            response_headers,
+            response_body,
+            summary: None,
+            busy: false,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn send(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let url = self.url.read(cx).value().trim().to_string();
+        if self.busy || url.is_empty() {
+            return;
+        }
+        let headers = match http::parse_headers(&self.headers.read(cx).value()) {
+            Ok(headers) => headers,
+            Err(err) => {
+                self.error = Some(err);
+                cx.notify();
+                return;
+            }
+        };
+        let request = Request {
+            method: self.method,
+            url,
+            headers,
+            body: self.editor.read(cx).value().to_string(),
+        };
+
+        // The blocking client runs off the UI thread so a slow server does
+        // not freeze the window.
+        self.busy = true;
+        self.error = None;
+        cx.notify();
+        cx.spawn_in(window, async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { http::send(&request) })
+                .await;
+            this.update_in(cx, |this, window, cx| {
+                this.busy = false;
+                let (summary, headers, (body, json)) = match result {
+                    Ok(response) => (
+                        Some(format!(
+                            "{} · {} ms · {} bytes",
+                            response.status,
+                            response.elapsed.as_millis(),
+                            response.body.len()
+                        )),
+                        response
+                            .headers
+                            .iter()
+                            .map(|(name, value)| format!("{}: {}", name, value))
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                        http::format_body(
+                            &response.body,
+                            response.content_type(),
+                            default_indentation(cx),
+                        ),
+                    ),
+                    Err(err) => {
+                        this.error = Some(err);
+                        (None, String::new(), (String::new(), false))
+                    }
+                };
+                this.summary = summary;
+                this.response_headers.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(headers), window, cx);
+                });
+                let language = if json {
+                    Language::Json
+                } else {
+                    Language::Plain
+                };
+                this.response_body.update(cx, |state, cx| {
+                    state.set_highlighter(language.name().to_string(), cx);
+                    state.set_value(SharedString::from(body), window, cx);
+                });
+                cx.notify();
+            })
+        })
+        .detach();
+    }
+
+    fn on_send_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.send(window, cx);
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.response_body.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.url.update(cx, |state, cx| {
+                state.set_value(value.trim().to_string(), window, cx);
+            });
+            cx.notify();
+        }
+    }
+
+    fn on_action_set_method(&mut self, action: &SetMethod, _: &mut Window, cx: &mut Context<Self>) {
+        self.method = action.0;
+        cx.notify();
+    }
+}
+
+impl Tool for HTTPClientTool {
+    fn title() -> &'static str {
+        "HTTP Client"
+    }
+
+    fn short_title() -> &'static str {
+        "HTTP"
+    }
+
+    fn description() -> &'static str {
+        "Sends HTTP requests with custom headers and bodies and shows the status, timing, headers, and body of the response."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.send(window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.response_body.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            method: self.method,
+            url: self.url.read(cx).value().to_string(),
+            headers: http::strip_credential_headers(&self.headers.read(cx).value()),
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.url.update(cx, |state, cx| {
+            state.set_value(preset.url, window, cx);
+        });
+        self.headers.update(cx, |state, cx| {
+            state.set_value(preset.headers, window, cx);
+        });
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.on_action_set_method(&SetMethod(preset.method), window, cx);
+    }
+}
+
+impl Focusable for HTTPClientTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for HTTPClientTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let url = self.url.read(cx).value();
+        let method = self.method;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_method))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("send-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("send-button")
+                                    .label(format!("Send ({})", method.name()))
+                                    .primary()
+                                    .loading(self.busy)
+                                    .disabled(url.trim().is_empty() || self.busy)
+                                    .on_click(cx.listener(Self::on_send_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                Method::ALL.into_iter().fold(
+                                    this.label("Method"),
+                                    |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            method == option,
+                                            Box::new(SetMethod(option)),
+                                        )
+                                    },
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.url)
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .h(px(160.))
+                    .gap_2()
+                    .child(
+                        TextInput::new(&self.headers)
+                            .h_full()
+                            .flex_1()
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false),
+                    )
+                    .child(
+                        TextInput::new(&self.editor)
+                            .h_full()
+                            .flex_1()
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false),
+                    ),
+            )
+            .when_some(self.summary.clone(), |this, summary| {
+                this.child(Label::new(summary))
+            })
+            .child(
+                TextInput::new(&self.response_headers)
+                    .h(px(120.))
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                TextInput::new(&self.response_body)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,372 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, StatefulInteractiveElement, Styled,
+    Subscription, Window, div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::id::{self, DISCORD_EPOCH, Kind, TWITTER_EPOCH},
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = id_tools, no_json)]
+pub struct SetKind(Kind);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = id_tools, no_json)]
+pub struct SetEpoch(i64);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    kind: Kind,
+    epoch: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct IDInspectorTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    /// The epoch of Snowflake IDs, in milliseconds since the Unix epoch.
+    epoch: Entity<InputState>,
+    kind: Kind,
+    fields: Vec<(&'static str, Entity<InputState>)>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl IDInspectorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input =
+            cx.new(|cx| InputState::new(window, cx).placeholder("UUID, ULID, or Snowflake ID"));
+        let epoch = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value(TWITTER_EPOCH.to_string())
+                .placeholder("Epoch in milliseconds")
+        });
+        let _subscriptions = vec![
+            cx.subscribe_in(&input, window, Self::on_input_event),
+            cx.subscribe_in(&epoch, window, Self::on_input_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            epoch,
+            kind: Kind::Auto,
+            fields: Vec::new(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_fields(window, cx);
+        }
+    }
+
+    /// The kind of ID to parse the input as, with `Auto` resolved against it.
+    fn effective_kind(&self, cx: &App) -> Option<Kind> {
+        match self.kind {
+            Kind::Auto => Kind::detect(&self.input.read(cx).value()),
+            kind => Some(kind),
+        }
+    }
+
+    fn update_fields(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        self.error = None;
+        if value.trim().is_empty() {
+            self.fields.clear();
+            cx.notify();
+            return;
+        }
+        // The epoch only matters to Snowflake IDs, so a bad one is not an
+        // error for the others.
+        let epoch = match self.epoch.read(cx).value().trim().parse() {
+            Ok(epoch) => epoch,
+            Err(_) if self.effective_kind(cx) != Some(Kind::Snowflake) => 0,
+            Err(err) => {
+                self.error = Some(ToolError::from(err).context("Invalid epoch"));
+                cx.notify();
+                return;
+            }
+        };
+        match id::inspect(&value, self.kind, epoch) {
+            Ok(fields) => {
+                self.fields = fields
+                    .into_iter()
+                    .map(|(name, value)| {
+                        (
+                            name,
+                            cx.new(|cx| InputState::new(window, cx).default_value(value)),
+                        )
+                    })
+                    .collect();
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_inspect_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_fields(window, cx);
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.input.update(cx, |state, cx| {
+                state.set_value(value.trim().to_string(), window, cx);
+            });
+            self.update_fields(window, cx);
+        }
+    }
+
+    fn on_action_set_kind(
+        &mut self,
+        action: &SetKind,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.kind = action.0;
+        self.update_fields(window, cx);
+    }
+
+    fn on_action_set_epoch(
+        &mut self,
+        action: &SetEpoch,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.epoch.update(cx, |state, cx| {
+            state.set_value(action.0.to_string(), window, cx);
+        });
+        self.update_fields(window, cx);
+    }
+}
+
+impl Tool for IDInspectorTool {
+    fn title() -> &'static str {
+        "ID Inspector"
+    }
+
+    fn short_title() -> &'static str {
+        "Inspector"
+    }
+
+    fn description() -> &'static str {
+        "Decodes the version, variant, timestamp, and other fields of UUIDs, ULIDs, and Snowflake IDs."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_inspect_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            kind: self.kind,
+            epoch: self.epoch.read(cx).value().to_string(),
+            input: include_input.then(|| self.input.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.epoch.update(cx, |state, cx| {
+            state.set_value(preset.epoch, window, cx);
+        });
+        if let Some(input) = preset.input {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.on_action_set_kind(&SetKind(preset.kind), window, cx);
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.input.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+            self.update_fields(window, cx);
+        }
+    }
+}
+
+impl Focusable for IDInspectorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for IDInspectorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.input.read(cx).value();
+        let kind = self.kind;
+        let effective_kind = self.effective_kind(cx);
+        let epoch = self.epoch.read(cx).value().trim().parse::<i64>().ok();
+        let label = match (kind, effective_kind) {
+            (Kind::Auto, Some(effective_kind)) => {
+                format!("Inspect ({}, Auto)", effective_kind.name())
+            }
+            (Kind::Auto, None) => "Inspect".to_string(),
+            (kind, _) => format!("Inspect ({})", kind.name()),
+        };
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_kind))
+            .on_action(cx.listener(Self::on_action_set_epoch))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("inspect-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("inspect-button")
+                                    .label(label)
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_inspect_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Type")
+                                    .menu_with_check(
+                                        "Auto-Detect",
+                                        kind == Kind::Auto,
+                                        Box::new(SetKind(Kind::Auto)),
+                                    )
+                                    .menu_with_check(
+                                        "UUID",
+                                        kind == Kind::UUID,
+                                        Box::new(SetKind(Kind::UUID)),
+                                    )
+                                    .menu_with_check(
+                                        "ULID",
+                                        kind == Kind::ULID,
+                                        Box::new(SetKind(Kind::ULID)),
+                                    )
+                                    .menu_with_check(
+                                        "Snowflake",
+                                        kind == Kind::Snowflake,
+                                        Box::new(SetKind(Kind::Snowflake)),
+                                    )
+                                    .label("Snowflake Epoch")
+                                    .menu_with_check(
+                                        "Twitter (2010-11-04)",
+                                        epoch == Some(TWITTER_EPOCH),
+                                        Box::new(SetEpoch(TWITTER_EPOCH)),
+                                    )
+                                    .menu_with_check(
+                                        "Discord (2015-01-01)",
+                                        epoch == Some(DISCORD_EPOCH),
+                                        Box::new(SetEpoch(DISCORD_EPOCH)),
+                                    )
+                                    .menu_with_check(
+                                        "Unix (1970-01-01)",
+                                        epoch == Some(0),
+                                        Box::new(SetEpoch(0)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.input)
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .when(effective_kind == Some(Kind::Snowflake), |this| {
+                this.child(
+                    h_flex()
+                        .gap_2()
+                        .child(Label::new("Epoch (ms)").w(px(160.)))
+                        .child(
+                            TextInput::new(&self.epoch)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                )
+            })
+            .child(
+                div()
+                    .id("fields")
+                    .v_flex()
+                    .flex_1()
+                    .gap_2()
+                    .mt_4()
+                    .overflow_y_scroll()
+                    .children(self.fields.iter().enumerate().map(|(i, (name, state))| {
+                        let value = state.read(cx).value().clone();
+                        h_flex().gap_2().child(Label::new(*name).w(px(160.))).child(
+                            TextInput::new(state)
+                                .text_size(px(15.))
+                                .focus_bordered(false)
+                                .suffix(
+                                    Clipboard::new(SharedString::from(format!(
+                                        "field-clipboard-{}",
+                                        i
+                                    )))
+                                    .value(value),
+                                ),
+                        )
+                    })),
+            )
+    }
+}
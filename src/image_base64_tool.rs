@@ -0,0 +1,517 @@
+use std::{path::PathBuf, sync::Arc};
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardEntry, ClipboardItem, Context, Entity,
+    ExternalPaths, FocusHandle, Focusable, Image, InteractiveElement, ObjectFit, ParentElement,
+    PathPromptOptions, Render, Styled, StyledImage, Subscription, Window, div, img,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    v_flex,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    LiveUpdate, Tool, ToolError,
+    core::{
+        base64::{self, ImageType},
+        data_url,
+    },
+    editor_font,
+    image_preview::preview_image,
+};
+
+/// How a loaded image is written out.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+enum Output {
+    #[default]
+    DataURL,
+    CSS,
+    HTML,
+    Base64,
+}
+
+impl Output {
+    const ALL: [Self; 4] = [Self::DataURL, Self::CSS, Self::HTML, Self::Base64];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::DataURL => "Data URL",
+            Self::CSS => "CSS Background",
+            Self::HTML => "HTML Image",
+            Self::Base64 => "Plain Base64",
+        }
+    }
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = base64_tools, no_json)]
+pub struct SetOutput(Output);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    output: Output,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+/// An image, loaded from a file or decoded from the editor, with its preview.
+struct LoadedImage {
+    image_type: ImageType,
+    bytes: Vec<u8>,
+    image: Arc<Image>,
+}
+
+impl LoadedImage {
+    fn new(image_type: ImageType, bytes: Vec<u8>) -> Self {
+        let image = preview_image(image_type, bytes.clone());
+        Self {
+            image_type,
+            bytes,
+            image,
+        }
+    }
+
+    fn encode(&self, output: Output) -> String {
+        let data_url = data_url::generate_bytes(&self.bytes, self.image_type.mime_type());
+        match output {
+            Output::DataURL => data_url,
+            Output::CSS => format!("background-image: url(\"{}\");", data_url),
+            Output::HTML => format!("<img src=\"{}\" alt=\"\">", data_url),
+            Output::Base64 => base64::encode_bytes(&self.bytes),
+        }
+    }
+
+    fn description(&self) -> String {
+        let encoded = base64::encode_bytes(&self.bytes).len();
+        format!(
+            "{} image, {} bytes, {} characters in Base64",
+            self.image_type.name(),
+            self.bytes.len(),
+            encoded
+        )
+    }
+}
+
+pub struct ImageBase64Tool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    output: Output,
+    image: Option<LoadedImage>,
+    error: Option<ToolError>,
+    live: bool,
+    live_update: LiveUpdate,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ImageBase64Tool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .soft_wrap(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("data:image/png;base64,…")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            output: Output::default(),
+            image: None,
+            error: None,
+            live: false,
+            live_update: LiveUpdate::default(),
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e
+            && self.live
+        {
+            self.live_update.schedule(Self::update_image, window, cx);
+        }
+    }
+
+    fn on_preview_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_image(window, cx);
+    }
+
+    /// Decodes the editor, which may hold a data URL, CSS or HTML with one in
+    /// it, or plain Base64.
+    fn update_image(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        if value.trim().is_empty() {
+            self.image = None;
+            self.error = None;
+            cx.notify();
+            return;
+        }
+        let bytes = match data_url::find(&value) {
+            Some(url) => data_url::parse(url).map(|url| url.data),
+            None => base64::decode_bytes(&value.split_whitespace().collect::<String>()),
+        };
+        match bytes.and_then(|bytes| Self::read_image(bytes, "Data")) {
+            Ok(image) => {
+                // Keep the preview as it is when the editor was just written
+                // from the same image.
+                if self
+                    .image
+                    .as_ref()
+                    .is_none_or(|loaded| loaded.bytes != image.bytes)
+                {
+                    self.image = Some(image);
+                }
+                self.error = None;
+            }
+            Err(err) => {
+                self.image = None;
+                self.error = Some(err);
+            }
+        }
+        cx.notify();
+    }
+
+    fn read_image(bytes: Vec<u8>, what: &str) -> Result<LoadedImage, ToolError> {
+        match ImageType::detect(&bytes) {
+            Some(image_type) => Ok(LoadedImage::new(image_type, bytes)),
+            None => Err(ToolError::new(format!(
+                "{} is not a PNG, JPEG, GIF, WebP, BMP, or SVG image",
+                what
+            ))),
+        }
+    }
+
+    /// Shows an image and writes it into the editor in the chosen output.
+    fn set_image(&mut self, image: LoadedImage, window: &mut Window, cx: &mut Context<Self>) {
+        let encoded = image.encode(self.output);
+        self.image = Some(image);
+        self.error = None;
+        self.editor.update(cx, |state, cx| {
+            state.set_value(encoded, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_open_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        cx.spawn_in(window, async move |this, cx| {
+            if let Ok(Ok(Some(paths))) = paths.await
+                && let Some(path) = paths.into_iter().next()
+            {
+                this.update_in(cx, |this, window, cx| this.load_file(path, window, cx))
+                    .ok();
+            }
+        })
+        .detach();
+    }
+
+    fn on_drop(&mut self, paths: &ExternalPaths, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(path) = paths.paths().first() {
+            self.load_file(path.clone(), window, cx);
+        }
+    }
+
+    fn load_file(&mut self, path: PathBuf, window: &mut Window, cx: &mut Context<Self>) {
+        let name = path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        );
+        let image = std::fs::read(&path)
+            .map_err(|err| ToolError::new(format!("Cannot read {}: {}", name, err)))
+            .and_then(|bytes| Self::read_image(bytes, &name));
+        match image {
+            Ok(image) => self.set_image(image, window, cx),
+            Err(err) => {
+                self.error = Some(err);
+                cx.notify();
+            }
+        }
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    /// Pastes an image copied from another app, or a data URL or Base64 text.
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(clipboard) = cx.read_from_clipboard() else {
+            return;
+        };
+        let image = clipboard.entries().iter().find_map(|entry| match entry {
+            ClipboardEntry::Image(image) => Some(image.bytes.clone()),
+            _ => None,
+        });
+        if let Some(bytes) = image {
+            match Self::read_image(bytes, "The pasted image") {
+                Ok(image) => self.set_image(image, window, cx),
+                Err(err) => {
+                    self.error = Some(err);
+                    cx.notify();
+                }
+            }
+            return;
+        }
+        let value = clipboard.text().unwrap_or_default();
+        self.editor.update(cx, |state, cx| {
+            state.set_value(value, window, cx);
+        });
+        self.update_image(window, cx);
+    }
+
+    fn on_copy_image_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if let Some(loaded) = &self.image {
+            cx.write_to_clipboard(ClipboardItem::new_image(&loaded.image));
+        }
+    }
+
+    fn on_save_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(loaded) = &self.image else {
+            return;
+        };
+        let directory = std::env::current_dir().unwrap_or_default();
+        let name = format!("image.{}", loaded.image_type.extension());
+        let path = cx.prompt_for_new_path(&directory, Some(&name));
+        let bytes = loaded.bytes.clone();
+        cx.spawn_in(window, async move |_, _| {
+            if let Ok(Ok(Some(path))) = path.await {
+                std::fs::write(path, bytes).ok();
+            }
+        })
+        .detach();
+    }
+
+    fn on_action_set_output(
+        &mut self,
+        action: &SetOutput,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.output = action.0;
+        if let Some(image) = self.image.take() {
+            self.set_image(image, window, cx);
+        }
+        cx.notify();
+    }
+}
+
+impl Tool for ImageBase64Tool {
+    fn title() -> &'static str {
+        "Image to Base64"
+    }
+
+    fn short_title() -> &'static str {
+        "Image"
+    }
+
+    fn description() -> &'static str {
+        "Converts images into Base64 data URLs for CSS and HTML, and previews and saves the image in a data URL."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_preview_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn supports_live() -> bool {
+        true
+    }
+
+    fn set_live(&mut self, live: bool, window: &mut Window, cx: &mut Context<Self>) {
+        self.live = live;
+        if live {
+            self.update_image(window, cx);
+        }
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            output: self.output,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.output = preset.output;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+            self.update_image(window, cx);
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for ImageBase64Tool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ImageBase64Tool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let output = self.output;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_output))
+            .on_drop(cx.listener(Self::on_drop))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("open-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("open-button")
+                                    .label("Open Image…")
+                                    .primary()
+                                    .on_click(cx.listener(Self::on_open_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                Output::ALL.into_iter().fold(
+                                    this.label("Write As"),
+                                    |this, item| {
+                                        this.menu_with_check(
+                                            item.name(),
+                                            output == item,
+                                            Box::new(SetOutput(item)),
+                                        )
+                                    },
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("preview-button")
+                            .label("Preview")
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_preview_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .when_some(self.image.as_ref(), |this, loaded| {
+                this.child(
+                    h_flex()
+                        .gap_2()
+                        .child(
+                            Button::new("save-button")
+                                .label("Save to File…")
+                                .on_click(cx.listener(Self::on_save_click)),
+                        )
+                        .child(
+                            Button::new("copy-image-button")
+                                .label("Copy Image")
+                                .on_click(cx.listener(Self::on_copy_image_click))
+                                .ml_auto(),
+                        ),
+                )
+                .child(
+                    v_flex()
+                        .h_full()
+                        .min_h_0()
+                        .p_2()
+                        .gap_2()
+                        .items_center()
+                        .justify_center()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .rounded(cx.theme().radius)
+                        .child(
+                            img(loaded.image.clone())
+                                .flex_1()
+                                .min_h_0()
+                                .w_full()
+                                .object_fit(ObjectFit::Contain),
+                        )
+                        .child(
+                            div()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(loaded.description()),
+                        ),
+                )
+            })
+            .when(self.image.is_none(), |this| {
+                this.child(
+                    div().text_color(cx.theme().muted_foreground).child(
+                        "Drop an image here to encode it, or paste a data URL to preview it.",
+                    ),
+                )
+            })
+    }
+}
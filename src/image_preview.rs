@@ -0,0 +1,18 @@
+use std::sync::Arc;
+
+use gpui::{Image, ImageFormat};
+
+use crate::core::base64::ImageType;
+
+/// Wraps decoded image bytes so that they can be shown with `img`.
+pub fn preview_image(image_type: ImageType, bytes: Vec<u8>) -> Arc<Image> {
+    let format = match image_type {
+        ImageType::Png => ImageFormat::Png,
+        ImageType::Jpeg => ImageFormat::Jpeg,
+        ImageType::Gif => ImageFormat::Gif,
+        ImageType::Webp => ImageFormat::Webp,
+        ImageType::Bmp => ImageFormat::Bmp,
+        ImageType::Svg => ImageFormat::Svg,
+    };
+    Arc::new(Image::from_bytes(format, bytes))
+}
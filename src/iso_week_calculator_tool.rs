@@ -0,0 +1,381 @@
+use chrono::{Datelike, Local, NaiveDate, Weekday};
+
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, ParentElement, Render,
+    Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    StyledExt,
+    button::Button,
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde_json::Value;
+
+use crate::Tool;
+
+pub struct ISOWeekCalculatorTool {
+    focus_handle: FocusHandle,
+    date: Entity<InputState>,
+    iso_week: Entity<InputState>,
+    week_year: Entity<InputState>,
+    day_of_week: Entity<InputState>,
+    quarter: Entity<InputState>,
+    week: Entity<InputState>,
+    week_start: Entity<InputState>,
+    week_end: Entity<InputState>,
+    weeks_in_year: Entity<InputState>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ISOWeekCalculatorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let date = cx.new(|cx| InputState::new(window, cx).placeholder("Date, e.g. 2024-12-30"));
+        let iso_week = cx.new(|cx| InputState::new(window, cx));
+        let week_year = cx.new(|cx| InputState::new(window, cx));
+        let day_of_week = cx.new(|cx| InputState::new(window, cx));
+        let quarter = cx.new(|cx| InputState::new(window, cx));
+        let week = cx.new(|cx| InputState::new(window, cx).placeholder("ISO Week, e.g. 2025-W01"));
+        let week_start = cx.new(|cx| InputState::new(window, cx));
+        let week_end = cx.new(|cx| InputState::new(window, cx));
+        let weeks_in_year = cx.new(|cx| InputState::new(window, cx));
+        let _subscriptions = vec![
+            cx.subscribe_in(&date, window, Self::on_date_event),
+            cx.subscribe_in(&week, window, Self::on_week_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            date,
+            iso_week,
+            week_year,
+            day_of_week,
+            quarter,
+            week,
+            week_start,
+            week_end,
+            weeks_in_year,
+            _subscriptions,
+        }
+    }
+
+    fn on_date_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            let value = self.date.read(cx).value().clone();
+            let date = parse_date(value.trim());
+            let fields = [
+                (&self.iso_week, date.map(|date| format_iso_week(&date))),
+                (
+                    &self.week_year,
+                    date.map(|date| date.iso_week().year().to_string()),
+                ),
+                (
+                    &self.day_of_week,
+                    date.map(|date| {
+                        format!(
+                            "{} ({})",
+                            date.format("%A"),
+                            date.weekday().number_from_monday()
+                        )
+                    }),
+                ),
+                (
+                    &self.quarter,
+                    date.map(|date| format!("Q{}", date.month0() / 3 + 1)),
+                ),
+            ];
+            Self::set_fields(fields, window, cx);
+        }
+    }
+
+    fn on_week_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            let value = self.week.read(cx).value().clone();
+            let week = parse_iso_week(value.trim());
+            let fields = [
+                (
+                    &self.week_start,
+                    week.map(|(year, week)| {
+                        format_date(NaiveDate::from_isoywd_opt(year, week, Weekday::Mon))
+                    }),
+                ),
+                (
+                    &self.week_end,
+                    week.map(|(year, week)| {
+                        format_date(NaiveDate::from_isoywd_opt(year, week, Weekday::Sun))
+                    }),
+                ),
+                (
+                    &self.weeks_in_year,
+                    week.map(|(year, _)| weeks_in_year(year).to_string()),
+                ),
+            ];
+            Self::set_fields(fields, window, cx);
+        }
+    }
+
+    fn set_fields<const N: usize>(
+        fields: [(&Entity<InputState>, Option<String>); N],
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        for (state, value) in fields {
+            state.update(cx, |state, cx| {
+                state.set_value(value.unwrap_or_default(), window, cx);
+            });
+        }
+        cx.notify();
+    }
+
+    fn on_today_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let today = Local::now().date_naive();
+        self.date.update(cx, |state, cx| {
+            state.set_value(today.format("%Y-%m-%d").to_string(), window, cx);
+        });
+        self.week.update(cx, |state, cx| {
+            state.set_value(format_iso_week(&today), window, cx);
+        });
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    ["%Y-%m-%d", "%Y/%m/%d", "%Y%m%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+}
+
+/// Accepts `2025-W01`, `2025W01`, and `2025 1`. A trailing day, as in
+/// `2025-W01-3`, is ignored.
+fn parse_iso_week(value: &str) -> Option<(i32, u32)> {
+    let value = value.to_uppercase();
+    let (year, rest) = value
+        .split_once(['-', ' ', '/'])
+        .or_else(|| value.split_once('W'))?;
+    let rest = rest.trim_start_matches('W');
+    let week = rest.split(['-', ' ']).next()?;
+    let year: i32 = year.trim().parse().ok()?;
+    let week: u32 = week.trim().parse().ok()?;
+    (1..=weeks_in_year(year))
+        .contains(&week)
+        .then_some((year, week))
+}
+
+fn weeks_in_year(year: i32) -> u32 {
+    // December 28th always falls in the last ISO week of its year.
+    NaiveDate::from_ymd_opt(year, 12, 28)
+        .map(|date| date.iso_week().week())
+        .unwrap_or(52)
+}
+
+fn format_iso_week(date: &NaiveDate) -> String {
+    let week = date.iso_week();
+    format!("{}-W{:02}", week.year(), week.week())
+}
+
+fn format_date(date: Option<NaiveDate>) -> String {
+    date.map(|date| date.format("%Y-%m-%d (%A)").to_string())
+        .unwrap_or_default()
+}
+
+impl Tool for ISOWeekCalculatorTool {
+    fn title() -> &'static str {
+        "ISO Week Calculator"
+    }
+
+    fn short_title() -> &'static str {
+        "ISO Week"
+    }
+
+    fn description() -> &'static str {
+        "Finds the ISO week, week-year, weekday, and quarter of a date, or the dates of an ISO week."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.date.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.date.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
+}
+
+impl Focusable for ISOWeekCalculatorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ISOWeekCalculatorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let iso_week = self.iso_week.read(cx).value().clone();
+        let week_year = self.week_year.read(cx).value().clone();
+        let day_of_week = self.day_of_week.read(cx).value().clone();
+        let quarter = self.quarter.read(cx).value().clone();
+        let week_start = self.week_start.read(cx).value().clone();
+        let week_end = self.week_end.read(cx).value().clone();
+        let weeks_in_year = self.weeks_in_year.read(cx).value().clone();
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("today-button")
+                        .label("Today")
+                        .on_click(cx.listener(Self::on_today_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.date)
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .items_start()
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("ISO Week"))
+                            .child(
+                                TextInput::new(&self.iso_week)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("iso-week-clipboard")
+                                            .value_fn(move |_, _| iso_week.clone()),
+                                    ),
+                            )
+                            .child(Label::new("Day of Week"))
+                            .child(
+                                TextInput::new(&self.day_of_week)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("day-of-week-clipboard")
+                                            .value_fn(move |_, _| day_of_week.clone()),
+                                    ),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("Week-Year"))
+                            .child(
+                                TextInput::new(&self.week_year)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("week-year-clipboard")
+                                            .value_fn(move |_, _| week_year.clone()),
+                                    ),
+                            )
+                            .child(Label::new("Quarter"))
+                            .child(
+                                TextInput::new(&self.quarter)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("quarter-clipboard")
+                                            .value_fn(move |_, _| quarter.clone()),
+                                    ),
+                            ),
+                    ),
+            )
+            .child(
+                div().mt_4().child(
+                    TextInput::new(&self.week)
+                        .text_size(px(15.))
+                        .focus_bordered(false),
+                ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .items_start()
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("First Day"))
+                            .child(
+                                TextInput::new(&self.week_start)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("week-start-clipboard")
+                                            .value_fn(move |_, _| week_start.clone()),
+                                    ),
+                            )
+                            .child(Label::new("Weeks in Year"))
+                            .child(
+                                TextInput::new(&self.weeks_in_year)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("weeks-in-year-clipboard")
+                                            .value_fn(move |_, _| weeks_in_year.clone()),
+                                    ),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("Last Day"))
+                            .child(
+                                TextInput::new(&self.week_end)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("week-end-clipboard")
+                                            .value_fn(move |_, _| week_end.clone()),
+                                    ),
+                            ),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,337 @@
+use std::cmp::Ordering;
+
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+struct SortKey {
+    path: Vec<String>,
+    descending: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    keys: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct JSONArraySorterTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    keys: Entity<InputState>,
+    sorted: Entity<InputState>,
+    error: Option<ToolError>,
+}
+
+impl JSONArraySorterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("JSON Array")
+        });
+        let keys = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Sort Keys, e.g. name, -age, address.city (- for descending)")
+        });
+        let sorted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Sorted JSON")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            keys,
+            sorted,
+            error: None,
+        }
+    }
+
+    fn on_sort_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let keys = parse_sort_keys(&self.keys.read(cx).value());
+        let sorted_value = match serde_json::from_str::<Value>(&value) {
+            Ok(Value::Array(mut items)) => {
+                items.sort_by(|a, b| compare_items(a, b, &keys));
+                self.error = None;
+                serde_json::to_string_pretty(&Value::Array(items)).unwrap_or_default()
+            }
+            Ok(_) => {
+                self.error = Some(ToolError::new("Expected a JSON array"));
+                String::new()
+            }
+            Err(err) => {
+                self.error = Some(ToolError::from(err).context("Invalid JSON"));
+                String::new()
+            }
+        };
+        self.sorted.update(cx, |state, cx| {
+            state.set_value(SharedString::from(sorted_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_sorted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.sorted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+}
+
+fn parse_sort_keys(value: &str) -> Vec<SortKey> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(|key| {
+            let (key, descending) = match key.strip_prefix('-') {
+                Some(key) => (key, true),
+                None => (key.strip_prefix('+').unwrap_or(key), false),
+            };
+            SortKey {
+                path: key.split('.').map(str::to_string).collect(),
+                descending,
+            }
+        })
+        .collect()
+}
+
+fn lookup<'a>(value: &'a Value, path: &[String]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |value, segment| match value {
+        Value::Object(map) => map.get(segment),
+        Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+        _ => None,
+    })
+}
+
+fn compare_items(a: &Value, b: &Value, keys: &[SortKey]) -> Ordering {
+    // Without keys the items themselves are compared, which sorts arrays of
+    // plain numbers or strings.
+    if keys.is_empty() {
+        return compare_values(Some(a), Some(b));
+    }
+    keys.iter()
+        .map(|key| {
+            let a = lookup(a, &key.path);
+            let b = lookup(b, &key.path);
+            // Missing values stay at the end regardless of direction.
+            match (is_missing(a), is_missing(b)) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) if key.descending => compare_values(a, b).reverse(),
+                (false, false) => compare_values(a, b),
+            }
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+fn is_missing(value: Option<&Value>) -> bool {
+    matches!(value, None | Some(Value::Null))
+}
+
+fn compare_values(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+    let rank = |value: Option<&Value>| match value {
+        Some(Value::Bool(_)) => 0,
+        Some(Value::Number(_)) => 1,
+        Some(Value::String(_)) => 2,
+        Some(Value::Array(_)) => 3,
+        Some(Value::Object(_)) => 4,
+        Some(Value::Null) | None => 5,
+    };
+    match (a, b) {
+        (Some(Value::Bool(a)), Some(Value::Bool(b))) => a.cmp(b),
+        (Some(Value::Number(a)), Some(Value::Number(b))) => {
+            let a = a.as_f64().unwrap_or(f64::NAN);
+            let b = b.as_f64().unwrap_or(f64::NAN);
+            a.total_cmp(&b)
+        }
+        (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(b),
+        (Some(Value::Array(a)), Some(Value::Array(b))) => a.len().cmp(&b.len()),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+impl Tool for JSONArraySorterTool {
+    fn title() -> &'static str {
+        "JSON Array Sorter"
+    }
+
+    fn short_title() -> &'static str {
+        "Array Sorter"
+    }
+
+    fn description() -> &'static str {
+        "Sorts a JSON array of objects by one or more keys."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_sort_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_sorted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.sorted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            keys: self.keys.read(cx).value().to_string(),
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.keys.update(cx, |state, cx| {
+            state.set_value(preset.keys, window, cx);
+        });
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for JSONArraySorterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for JSONArraySorterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("sort-button")
+                            .label("Sort")
+                            .primary()
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_sort_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.keys)
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-sorted-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_sorted_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.sorted)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -1,22 +1,32 @@
 use gpui::{
-    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
-    ParentElement, Render, SharedString, Styled, Window, div, px,
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
 };
 
 use gpui_component::{
     Disableable, StyledExt,
-    button::{Button, ButtonVariants},
+    button::{Button, ButtonVariants, DropdownButton},
     h_flex,
     highlighter::Language,
     input::{InputState, TabSize, TextInput},
 };
 
-use crate::Tool;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{Tool, ToolError, core::json, default_indentation, editor_font};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetFormatDecoded(bool);
 
 pub struct JSONDecoderTool {
     focus_handle: FocusHandle,
     editor: Entity<InputState>,
     decoded: Entity<InputState>,
+    /// Whether decoded text that is itself JSON is pretty-printed.
+    format_decoded: bool,
+    error: Option<ToolError>,
 }
 
 impl JSONDecoderTool {
@@ -52,15 +62,31 @@ impl JSONDecoderTool {
             focus_handle: cx.focus_handle(),
             editor,
             decoded,
+            format_decoded: false,
+            error: None,
         }
     }
 
     fn on_decode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
         let value = self.editor.read(cx).value().clone();
-        self.decoded.update(cx, |state, cx| {
-            let decoded_value: String = serde_json::from_str(value.as_ref()).unwrap_or_default();
-            state.set_value(SharedString::from(decoded_value), window, cx);
-        })
+        match json::decode_string(&value) {
+            Ok(mut decoded_value) => {
+                self.error = None;
+                let mut language = Language::Plain;
+                if self.format_decoded
+                    && let Ok(formatted) = json::format(&decoded_value, default_indentation(cx))
+                {
+                    decoded_value = formatted;
+                    language = Language::Json;
+                }
+                self.decoded.update(cx, |state, cx| {
+                    state.set_highlighter(language.name().to_string(), cx);
+                    state.set_value(SharedString::from(decoded_value), window, cx);
+                });
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
     }
 
     fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
@@ -100,6 +126,16 @@ impl JSONDecoderTool {
             })
         }
     }
+
+    fn on_action_set_format_decoded(
+        &mut self,
+        action: &SetFormatDecoded,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.format_decoded = action.0;
+        cx.notify();
+    }
 }
 
 impl Tool for JSONDecoderTool {
@@ -112,12 +148,44 @@ impl Tool for JSONDecoderTool {
     }
 
     fn description() -> &'static str {
-        "Decodes a JSON encoded string back into raw text."
+        "Decodes a JSON encoded string back into raw text, formatting it if it holds embedded JSON."
     }
 
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_decode_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_decoded_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.decoded.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.editor.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
 }
 
 impl Focusable for JSONDecoderTool {
@@ -133,8 +201,10 @@ impl Render for JSONDecoderTool {
         cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
         let value = self.editor.read(cx).value();
+        let format_decoded = self.format_decoded;
 
         div()
+            .on_action(cx.listener(Self::on_action_set_format_decoded))
             .v_flex()
             .size_full()
             .gap_2()
@@ -142,11 +212,22 @@ impl Render for JSONDecoderTool {
                 h_flex()
                     .gap_2()
                     .child(
-                        Button::new("decode-button")
-                            .label("Decode")
+                        DropdownButton::new("decode-dropdown-button")
                             .primary()
-                            .disabled(value.is_empty())
-                            .on_click(cx.listener(Self::on_decode_click)),
+                            .button(
+                                Button::new("decode-button")
+                                    .label("Decode")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_decode_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Options").menu_with_check(
+                                    "Format Embedded JSON",
+                                    format_decoded,
+                                    Box::new(SetFormatDecoded(!format_decoded)),
+                                )
+                            }),
                     )
                     .child(
                         Button::new("copy-button")
@@ -163,7 +244,7 @@ impl Render for JSONDecoderTool {
             .child(
                 TextInput::new(&self.editor)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
@@ -185,7 +266,7 @@ impl Render for JSONDecoderTool {
             .child(
                 TextInput::new(&self.decoded)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
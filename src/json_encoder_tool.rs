@@ -1,22 +1,32 @@
 use gpui::{
-    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
-    ParentElement, Render, SharedString, Styled, Window, div, px,
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
 };
 
 use gpui_component::{
     Disableable, StyledExt,
-    button::{Button, ButtonVariants},
+    button::{Button, ButtonVariants, DropdownButton},
     h_flex,
     highlighter::Language,
     input::{InputState, TabSize, TextInput},
 };
 
-use crate::Tool;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{Tool, core::json, editor_font};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetCompactInput(bool);
 
 pub struct JSONEncoderTool {
     focus_handle: FocusHandle,
     editor: Entity<InputState>,
     encoded: Entity<InputState>,
+    /// Whether text that is itself JSON is compacted before it is encoded,
+    /// so that it embeds as a single line.
+    compact_input: bool,
 }
 
 impl JSONEncoderTool {
@@ -52,14 +62,19 @@ impl JSONEncoderTool {
             focus_handle: cx.focus_handle(),
             editor,
             encoded,
+            compact_input: false,
         }
     }
 
     fn on_encode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
-        let value = self.editor.read(cx).value().clone();
+        let mut value = self.editor.read(cx).value().to_string();
+        if self.compact_input
+            && let Ok(compacted) = json::compact(&value)
+        {
+            value = compacted;
+        }
         self.encoded.update(cx, |state, cx| {
-            let encoded_value = serde_json::to_string(value.as_ref()).unwrap_or_default();
-            state.set_value(SharedString::from(encoded_value), window, cx);
+            state.set_value(SharedString::from(json::encode_string(&value)), window, cx);
         })
     }
 
@@ -100,6 +115,16 @@ impl JSONEncoderTool {
             })
         }
     }
+
+    fn on_action_set_compact_input(
+        &mut self,
+        action: &SetCompactInput,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.compact_input = action.0;
+        cx.notify();
+    }
 }
 
 impl Tool for JSONEncoderTool {
@@ -112,12 +137,40 @@ impl Tool for JSONEncoderTool {
     }
 
     fn description() -> &'static str {
-        "Converts raw text into a JSON encoded string."
+        "Converts raw text, or JSON to embed in JSON, into a JSON encoded string."
     }
 
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_encode_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_encoded_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.encoded.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.editor.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
 }
 
 impl Focusable for JSONEncoderTool {
@@ -133,8 +186,10 @@ impl Render for JSONEncoderTool {
         cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
         let value = self.editor.read(cx).value();
+        let compact_input = self.compact_input;
 
         div()
+            .on_action(cx.listener(Self::on_action_set_compact_input))
             .v_flex()
             .size_full()
             .gap_2()
@@ -142,11 +197,22 @@ impl Render for JSONEncoderTool {
                 h_flex()
                     .gap_2()
                     .child(
-                        Button::new("encode-button")
-                            .label("Encode")
+                        DropdownButton::new("encode-dropdown-button")
                             .primary()
-                            .disabled(value.is_empty())
-                            .on_click(cx.listener(Self::on_encode_click)),
+                            .button(
+                                Button::new("encode-button")
+                                    .label("Encode")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_encode_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Options").menu_with_check(
+                                    "Compact JSON Input",
+                                    compact_input,
+                                    Box::new(SetCompactInput(!compact_input)),
+                                )
+                            }),
                     )
                     .child(
                         Button::new("copy-button")
@@ -163,7 +229,7 @@ impl Render for JSONEncoderTool {
             .child(
                 TextInput::new(&self.editor)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
@@ -185,7 +251,7 @@ impl Render for JSONEncoderTool {
             .child(
                 TextInput::new(&self.encoded)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
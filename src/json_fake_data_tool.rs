@@ -0,0 +1,536 @@
+use chrono::{Duration, Utc};
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use rand::{Rng, seq::IndexedRandom};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+
+use crate::{Tool, ToolError, editor_font};
+
+const SAMPLE_COUNTS: [usize; 5] = [1, 5, 10, 25, 100];
+
+// Self-referencing schemas would otherwise recurse forever.
+const MAX_DEPTH: usize = 8;
+
+const WORDS: [&str; 16] = [
+    "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "india", "juliet",
+    "kilo", "lima", "mike", "november", "oscar", "papa",
+];
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetSampleCount(usize);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    sample_count: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct JSONFakeDataTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    generated: Entity<InputState>,
+    sample_count: usize,
+    error: Option<ToolError>,
+}
+
+impl JSONFakeDataTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("JSON Schema")
+        });
+        let generated = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Generated JSON")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            generated,
+            sample_count: 1,
+            error: None,
+        }
+    }
+
+    fn on_generate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let generated_value = match serde_json::from_str::<Value>(&value) {
+            Ok(schema) => {
+                let generator = Generator { root: &schema };
+                let mut rng = rand::rng();
+                let output = if self.sample_count == 1 {
+                    generator.generate(&schema, &mut rng, 0)
+                } else {
+                    Value::Array(
+                        (0..self.sample_count)
+                            .map(|_| generator.generate(&schema, &mut rng, 0))
+                            .collect(),
+                    )
+                };
+                self.error = None;
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            }
+            Err(err) => {
+                self.error = Some(ToolError::from(err).context("Invalid JSON Schema"));
+                String::new()
+            }
+        };
+        self.generated.update(cx, |state, cx| {
+            state.set_value(SharedString::from(generated_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_generated_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.generated.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_sample_count(
+        &mut self,
+        action: &SetSampleCount,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.sample_count = action.0;
+        cx.notify();
+    }
+}
+
+struct Generator<'a> {
+    root: &'a Value,
+}
+
+impl Generator<'_> {
+    fn resolve<'s>(&'s self, schema: &'s Value) -> &'s Value {
+        // Only local references such as `#/$defs/name` can be followed.
+        match schema.get("$ref").and_then(Value::as_str) {
+            Some(reference) => reference
+                .strip_prefix('#')
+                .and_then(|pointer| self.root.pointer(pointer))
+                .unwrap_or(schema),
+            None => schema,
+        }
+    }
+
+    fn generate(&self, schema: &Value, rng: &mut impl Rng, depth: usize) -> Value {
+        let schema = self.resolve(schema);
+        if depth > MAX_DEPTH {
+            return Value::Null;
+        }
+        if let Some(value) = schema.get("const") {
+            return value.clone();
+        }
+        if let Some(choices) = schema.get("enum").and_then(Value::as_array)
+            && let Some(choice) = choices.choose(rng)
+        {
+            return choice.clone();
+        }
+        for key in ["oneOf", "anyOf"] {
+            if let Some(choices) = schema.get(key).and_then(Value::as_array)
+                && let Some(choice) = choices.choose(rng)
+            {
+                return self.generate(choice, rng, depth + 1);
+            }
+        }
+        if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+            // Keywords next to `allOf` apply as well, so the schema itself is
+            // merged in as the first part.
+            let mut base = schema.clone();
+            if let Some(map) = base.as_object_mut() {
+                map.remove("allOf");
+            }
+            let parts: Vec<Value> = std::iter::once(base).chain(parts.iter().cloned()).collect();
+            return self.generate(&self.merge(&parts), rng, depth + 1);
+        }
+
+        let schema_type = match schema.get("type") {
+            Some(Value::String(t)) => t.as_str(),
+            Some(Value::Array(types)) => {
+                types.choose(rng).and_then(Value::as_str).unwrap_or("null")
+            }
+            _ if schema.get("properties").is_some() => "object",
+            _ if schema.get("items").is_some() => "array",
+            _ => "string",
+        };
+        match schema_type {
+            "object" => self.generate_object(schema, rng, depth),
+            "array" => self.generate_array(schema, rng, depth),
+            "string" => Value::String(generate_string(schema, rng)),
+            "integer" => Value::Number(generate_integer(schema, rng).into()),
+            "number" => Number::from_f64(generate_number(schema, rng))
+                .map(Value::Number)
+                .unwrap_or(Value::Null),
+            "boolean" => Value::Bool(rng.random_bool(0.5)),
+            _ => Value::Null,
+        }
+    }
+
+    fn merge(&self, parts: &[Value]) -> Value {
+        let mut merged = Map::new();
+        for part in parts {
+            if let Value::Object(map) = self.resolve(part) {
+                for (key, value) in map {
+                    match (merged.get_mut(key), value) {
+                        (Some(Value::Object(existing)), Value::Object(more)) => {
+                            existing.extend(more.clone());
+                        }
+                        (Some(Value::Array(existing)), Value::Array(more)) => {
+                            existing.extend(more.iter().cloned());
+                        }
+                        _ => {
+                            merged.insert(key.clone(), value.clone());
+                        }
+                    }
+                }
+            }
+        }
+        Value::Object(merged)
+    }
+
+    fn generate_object(&self, schema: &Value, rng: &mut impl Rng, depth: usize) -> Value {
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|required| required.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let mut object = Map::new();
+        if let Some(Value::Object(properties)) = schema.get("properties") {
+            for (name, property) in properties {
+                if required.contains(&name.as_str()) || rng.random_bool(0.75) {
+                    object.insert(name.clone(), self.generate(property, rng, depth + 1));
+                }
+            }
+        }
+        Value::Object(object)
+    }
+
+    fn generate_array(&self, schema: &Value, rng: &mut impl Rng, depth: usize) -> Value {
+        let min = usize_field(schema, "minItems").unwrap_or(1);
+        let max = usize_field(schema, "maxItems")
+            .unwrap_or(min.max(3))
+            .max(min);
+        let length = rng.random_range(min..=max);
+        let items = schema.get("items").cloned().unwrap_or(Value::Bool(true));
+        let mut array: Vec<Value> = Vec::with_capacity(length);
+        for _ in 0..length {
+            let item = self.generate(&items, rng, depth + 1);
+            // Duplicates are dropped rather than retried, so a small enum
+            // cannot loop forever.
+            if schema.get("uniqueItems") == Some(&Value::Bool(true)) && array.contains(&item) {
+                continue;
+            }
+            array.push(item);
+        }
+        Value::Array(array)
+    }
+}
+
+fn usize_field(schema: &Value, key: &str) -> Option<usize> {
+    schema.get(key).and_then(Value::as_u64).map(|n| n as usize)
+}
+
+fn generate_string(schema: &Value, rng: &mut impl Rng) -> String {
+    let word = |rng: &mut _| *WORDS.choose(rng).unwrap_or(&"alpha");
+    let text = match schema.get("format").and_then(Value::as_str) {
+        Some("date-time") => (Utc::now() - Duration::minutes(rng.random_range(0..525_600)))
+            .format("%Y-%m-%dT%H:%M:%SZ")
+            .to_string(),
+        Some("date") => (Utc::now() - Duration::days(rng.random_range(0..3650)))
+            .format("%Y-%m-%d")
+            .to_string(),
+        Some("time") => format!(
+            "{:02}:{:02}:{:02}",
+            rng.random_range(0..24),
+            rng.random_range(0..60),
+            rng.random_range(0..60)
+        ),
+        Some("email") => format!("{}.{}@example.com", word(rng), word(rng)),
+        Some("hostname") => format!("{}.example.com", word(rng)),
+        Some("uri") | Some("url") => format!("https://example.com/{}/{}", word(rng), word(rng)),
+        Some("ipv4") => format!(
+            "{}.{}.{}.{}",
+            rng.random_range(1..=254),
+            rng.random_range(0..=255),
+            rng.random_range(0..=255),
+            rng.random_range(1..=254)
+        ),
+        Some("ipv6") => (0..8)
+            .map(|_| format!("{:x}", rng.random::<u16>()))
+            .collect::<Vec<_>>()
+            .join(":"),
+        Some("uuid") => {
+            let bytes: [u8; 16] = rng.random();
+            let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            format!(
+                "{}-{}-4{}-{}-{}",
+                &hex[0..8],
+                &hex[8..12],
+                &hex[13..16],
+                &hex[16..20],
+                &hex[20..32]
+            )
+        }
+        _ => (0..rng.random_range(1..=3))
+            .map(|_| word(rng))
+            .collect::<Vec<_>>()
+            .join(" "),
+    };
+
+    let min = usize_field(schema, "minLength").unwrap_or(0);
+    let max = usize_field(schema, "maxLength");
+    let mut text = text;
+    while text.chars().count() < min {
+        text.push_str(word(rng));
+    }
+    if let Some(max) = max {
+        text = text.chars().take(max.max(min)).collect();
+    }
+    text
+}
+
+fn bounds(schema: &Value) -> (Option<f64>, Option<f64>, Option<f64>, Option<f64>) {
+    let get = |key| schema.get(key).and_then(Value::as_f64);
+    (
+        get("minimum"),
+        get("exclusiveMinimum"),
+        get("maximum"),
+        get("exclusiveMaximum"),
+    )
+}
+
+fn generate_integer(schema: &Value, rng: &mut impl Rng) -> i64 {
+    let (minimum, exclusive_minimum, maximum, exclusive_maximum) = bounds(schema);
+    let low = match (minimum, exclusive_minimum) {
+        (_, Some(n)) => n.floor() as i64 + 1,
+        (Some(n), None) => n.ceil() as i64,
+        (None, None) => 0,
+    };
+    let high = match (maximum, exclusive_maximum) {
+        (_, Some(n)) => n.ceil() as i64 - 1,
+        (Some(n), None) => n.floor() as i64,
+        (None, None) => low.saturating_add(1000),
+    }
+    .max(low);
+    let value = rng.random_range(low..=high);
+    match schema.get("multipleOf").and_then(Value::as_i64) {
+        Some(step) if step > 0 => {
+            let rounded = value - value.rem_euclid(step);
+            if rounded < low {
+                rounded + step
+            } else {
+                rounded
+            }
+        }
+        _ => value,
+    }
+}
+
+fn generate_number(schema: &Value, rng: &mut impl Rng) -> f64 {
+    let (minimum, exclusive_minimum, maximum, exclusive_maximum) = bounds(schema);
+    let low = minimum.or(exclusive_minimum).unwrap_or(0.);
+    let high = maximum
+        .or(exclusive_maximum)
+        .unwrap_or(low + 1000.)
+        .max(low);
+    if low == high {
+        return low;
+    }
+    let value = rng.random_range(low..high);
+    (value * 100.).round() / 100.
+}
+
+impl Tool for JSONFakeDataTool {
+    fn title() -> &'static str {
+        "JSON Fake Data Generator"
+    }
+
+    fn short_title() -> &'static str {
+        "Fake Data"
+    }
+
+    fn description() -> &'static str {
+        "Generates sample JSON documents that conform to a JSON Schema."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_generate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_generated_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.generated.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            sample_count: self.sample_count,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_sample_count(&SetSampleCount(preset.sample_count), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for JSONFakeDataTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for JSONFakeDataTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let sample_count = self.sample_count;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_sample_count))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("generate-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("generate-button")
+                                    .label("Generate")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_generate_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                SAMPLE_COUNTS.iter().fold(
+                                    this.label("Documents"),
+                                    |this, &count| {
+                                        this.menu_with_check(
+                                            format!("{}", count),
+                                            sample_count == count,
+                                            Box::new(SetSampleCount(count)),
+                                        )
+                                    },
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-generated-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_generated_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.generated)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -11,18 +11,44 @@ use gpui_component::{
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_json::ser::{PrettyFormatter, Serializer};
 
-use crate::Tool;
+use crate::{
+    Tool, ToolError,
+    core::json::{self, Normalization},
+    default_indentation, editor_font,
+};
 
 #[derive(Action, Clone, PartialEq, Eq, Deserialize)]
 #[action(namespace = json_tools, no_json)]
 pub struct SetIndentationSize(usize);
 
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetSortKeys(bool);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetStripNulls(bool);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetEscapeUnicode(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    indentation_size: usize,
+    #[serde(default)]
+    normalization: Normalization,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
 pub struct JSONFormatterTool {
     focus_handle: FocusHandle,
     editor: Entity<InputState>,
     indentation_size: usize,
+    normalization: Normalization,
+    error: Option<ToolError>,
 }
 
 impl JSONFormatterTool {
@@ -46,31 +72,50 @@ impl JSONFormatterTool {
         Self {
             focus_handle: cx.focus_handle(),
             editor,
-            indentation_size: 2,
+            indentation_size: default_indentation(cx),
+            normalization: Normalization::default(),
+            error: None,
         }
     }
 
     fn on_format_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
-        self.editor.update(cx, |state, cx| {
-            let value = state.value();
-            let json_value: Value = serde_json::from_str(value.as_str()).unwrap();
-            let indent = b" ".repeat(self.indentation_size);
-            let formatter = PrettyFormatter::with_indent(indent.as_slice());
-            let mut writer = Vec::with_capacity(128);
-            let mut serializer = Serializer::with_formatter(&mut writer, formatter);
-            json_value.serialize(&mut serializer).unwrap();
-            let pretty_json = String::from_utf8(writer).unwrap();
-            state.set_value(SharedString::from(pretty_json), window, cx);
-        })
+        let indentation_size = self.indentation_size;
+        let normalization = self.normalization;
+        self.reformat(
+            |value| json::format_with(value, indentation_size, &normalization),
+            window,
+            cx,
+        );
     }
 
     fn on_compact_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
-        self.editor.update(cx, |state, cx| {
-            let value = state.value();
-            let json_value: Value = serde_json::from_str(value.as_str()).unwrap();
-            let compact_json = serde_json::to_string(&json_value).unwrap();
-            state.set_value(SharedString::from(compact_json), window, cx);
-        })
+        let normalization = self.normalization;
+        self.reformat(
+            |value| json::compact_with(value, &normalization),
+            window,
+            cx,
+        );
+    }
+
+    /// Replaces the editor's JSON with the output of `format`, or leaves it
+    /// untouched and reports where parsing failed.
+    fn reformat(
+        &mut self,
+        format: impl FnOnce(&str) -> Result<String, ToolError>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.editor.read(cx).value().clone();
+        match format(&value) {
+            Ok(formatted) => {
+                self.error = None;
+                self.editor.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(formatted), window, cx);
+                });
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
     }
 
     fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
@@ -96,6 +141,36 @@ impl JSONFormatterTool {
         self.indentation_size = action.0;
         cx.notify();
     }
+
+    fn on_action_set_sort_keys(
+        &mut self,
+        action: &SetSortKeys,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.normalization.sort_keys = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_strip_nulls(
+        &mut self,
+        action: &SetStripNulls,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.normalization.strip_nulls = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_escape_unicode(
+        &mut self,
+        action: &SetEscapeUnicode,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.normalization.escape_unicode = action.0;
+        cx.notify();
+    }
 }
 
 impl Tool for JSONFormatterTool {
@@ -108,12 +183,59 @@ impl Tool for JSONFormatterTool {
     }
 
     fn description() -> &'static str {
-        "Formats or compacts JSON data for better structure and clarity."
+        "Formats or compacts JSON data, optionally sorting keys, stripping nulls, and escaping Unicode."
     }
 
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_format_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.editor.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            indentation_size: self.indentation_size,
+            normalization: self.normalization,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_indentation_size(
+            &SetIndentationSize(preset.indentation_size),
+            window,
+            cx,
+        );
+        self.normalization = preset.normalization;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
 }
 
 impl Focusable for JSONFormatterTool {
@@ -130,9 +252,17 @@ impl Render for JSONFormatterTool {
     ) -> impl gpui::IntoElement {
         let value = self.editor.read(cx).value();
         let indentation_size = self.indentation_size;
+        let Normalization {
+            sort_keys,
+            strip_nulls,
+            escape_unicode,
+        } = self.normalization;
 
         div()
             .on_action(cx.listener(Self::on_action_set_indentation_size))
+            .on_action(cx.listener(Self::on_action_set_sort_keys))
+            .on_action(cx.listener(Self::on_action_set_strip_nulls))
+            .on_action(cx.listener(Self::on_action_set_escape_unicode))
             .v_flex()
             .size_full()
             .gap_2()
@@ -165,6 +295,28 @@ impl Render for JSONFormatterTool {
                                         indentation_size == 4,
                                         Box::new(SetIndentationSize(4)),
                                     )
+                                    .label("Options")
+                                    .menu_with_check(
+                                        "Sort Keys",
+                                        sort_keys,
+                                        Box::new(SetSortKeys(!sort_keys)),
+                                    )
+                                    .menu_with_check(
+                                        "Strip Nulls",
+                                        strip_nulls,
+                                        Box::new(SetStripNulls(!strip_nulls)),
+                                    )
+                                    .label("Non-ASCII Characters")
+                                    .menu_with_check(
+                                        "Unescaped",
+                                        !escape_unicode,
+                                        Box::new(SetEscapeUnicode(false)),
+                                    )
+                                    .menu_with_check(
+                                        "Escaped as \\uXXXX",
+                                        escape_unicode,
+                                        Box::new(SetEscapeUnicode(true)),
+                                    )
                             }),
                     )
                     .child(
@@ -188,7 +340,7 @@ impl Render for JSONFormatterTool {
             .child(
                 TextInput::new(&self.editor)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
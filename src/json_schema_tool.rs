@@ -0,0 +1,840 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, StatefulInteractiveElement, Styled,
+    Window, div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+    v_flex,
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+use crate::{Tool, ToolError, editor_font};
+
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+// Schemas that refer to themselves would otherwise recurse forever.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Mode {
+    Infer,
+    Validate,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetSchemaMode(Mode);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    mode: Mode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    document: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    schema: Option<String>,
+}
+
+struct Violation {
+    pointer: String,
+    message: String,
+}
+
+pub struct JSONSchemaTool {
+    focus_handle: FocusHandle,
+    document: Entity<InputState>,
+    schema: Entity<InputState>,
+    mode: Mode,
+    /// The violations found by the last validation, or `None` if nothing
+    /// has been validated since the last inference.
+    violations: Option<Vec<Violation>>,
+    error: Option<ToolError>,
+}
+
+impl JSONSchemaTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let document = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("JSON Document")
+        });
+        let schema = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("JSON Schema")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            document,
+            schema,
+            mode: Mode::Infer,
+            violations: None,
+            error: None,
+        }
+    }
+
+    fn parse(state: &Entity<InputState>, what: &str, cx: &App) -> Result<Value, ToolError> {
+        let value = state.read(cx).value().clone();
+        serde_json::from_str(&value).map_err(|err| ToolError::from(err).context(what))
+    }
+
+    fn on_run_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let result = match self.mode {
+            Mode::Infer => Self::parse(&self.document, "Invalid JSON", cx).map(|document| {
+                let mut schema = infer_schema(&document);
+                if let Value::Object(map) = &mut schema {
+                    map.insert("$schema".to_string(), Value::from(SCHEMA_DIALECT));
+                }
+                let schema = serde_json::to_string_pretty(&schema).unwrap_or_default();
+                self.schema.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(schema), window, cx);
+                });
+                self.violations = None;
+            }),
+            Mode::Validate => {
+                Self::parse(&self.document, "Invalid JSON", cx).and_then(|document| {
+                    let schema = Self::parse(&self.schema, "Invalid schema", cx)?;
+                    let mut validator = Validator::new(&schema);
+                    validator.validate(&schema, &document, "", 0);
+                    self.violations = Some(validator.violations);
+                    Ok(())
+                })
+            }
+        };
+        self.error = result.err();
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.schema.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.document.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_action_set_schema_mode(
+        &mut self,
+        action: &SetSchemaMode,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.mode = action.0;
+        cx.notify();
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Describes the shape of `value`. Every key of an object is required, and
+/// the items of an array are described by one schema that fits them all.
+fn infer_schema(value: &Value) -> Value {
+    match value {
+        Value::Array(items) => {
+            let mut schema = json!({ "type": "array" });
+            if let Some(items) = items.iter().map(infer_schema).reduce(merge_schemas) {
+                schema["items"] = items;
+            }
+            schema
+        }
+        Value::Object(map) => {
+            let properties: Map<String, Value> = map
+                .iter()
+                .map(|(key, value)| (key.clone(), infer_schema(value)))
+                .collect();
+            let required: Vec<Value> = map.keys().cloned().map(Value::String).collect();
+            json!({ "type": "object", "properties": properties, "required": required })
+        }
+        value => json!({ "type": type_name(value) }),
+    }
+}
+
+/// Combines two inferred schemas into one that accepts what either does.
+/// Objects keep only the keys required by both, and schemas of different
+/// types become a list of types, or an `anyOf` if any of them has more to it
+/// than its type.
+fn merge_schemas(a: Value, b: Value) -> Value {
+    if a == b {
+        return a;
+    }
+    let type_of = |schema: &Value| schema["type"].as_str().map(str::to_string);
+    match (type_of(&a).as_deref(), type_of(&b).as_deref()) {
+        (Some("object"), Some("object")) => {
+            let mut properties = a["properties"].as_object().cloned().unwrap_or_default();
+            for (key, schema) in b["properties"].as_object().cloned().unwrap_or_default() {
+                let merged = match properties.remove(&key) {
+                    Some(existing) => merge_schemas(existing, schema),
+                    None => schema,
+                };
+                properties.insert(key, merged);
+            }
+            let b_required = b["required"].as_array().cloned().unwrap_or_default();
+            let required: Vec<Value> = a["required"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|key| b_required.contains(key))
+                .collect();
+            json!({ "type": "object", "properties": properties, "required": required })
+        }
+        (Some("array"), Some("array")) => {
+            let items = match (a.get("items").cloned(), b.get("items").cloned()) {
+                (Some(a), Some(b)) => Some(merge_schemas(a, b)),
+                (a, b) => a.or(b),
+            };
+            let mut schema = json!({ "type": "array" });
+            if let Some(items) = items {
+                schema["items"] = items;
+            }
+            schema
+        }
+        (Some("integer" | "number"), Some("integer" | "number")) => json!({ "type": "number" }),
+        _ => {
+            let mut variants: Vec<Value> = Vec::new();
+            for schema in [a, b] {
+                let schemas = match schema.get("anyOf") {
+                    Some(Value::Array(schemas)) => schemas.clone(),
+                    _ => match &schema["type"] {
+                        Value::Array(types) => types.iter().map(|t| json!({ "type": t })).collect(),
+                        _ => vec![schema],
+                    },
+                };
+                for schema in schemas {
+                    match variants
+                        .iter()
+                        .position(|variant| variant["type"] == schema["type"])
+                    {
+                        Some(i) => {
+                            let existing = variants.remove(i);
+                            variants.insert(i, merge_schemas(existing, schema));
+                        }
+                        None => variants.push(schema),
+                    }
+                }
+            }
+            let simple = variants
+                .iter()
+                .all(|variant| variant.as_object().is_some_and(|map| map.len() == 1));
+            if simple {
+                let types: Vec<Value> = variants.into_iter().map(|v| v["type"].clone()).collect();
+                json!({ "type": types })
+            } else {
+                json!({ "anyOf": variants })
+            }
+        }
+    }
+}
+
+fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Checks a document against a schema, collecting every violation rather
+/// than stopping at the first. Covers the validation keywords of draft
+/// 2020-12 and draft 7; `format` is treated as an annotation only.
+struct Validator<'a> {
+    root: &'a Value,
+    violations: Vec<Violation>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(root: &'a Value) -> Self {
+        Self {
+            root,
+            violations: Vec::new(),
+        }
+    }
+
+    fn report(&mut self, pointer: &str, message: impl Into<String>) {
+        self.violations.push(Violation {
+            pointer: pointer.to_string(),
+            message: message.into(),
+        });
+    }
+
+    fn is_valid(&self, schema: &Value, value: &Value, depth: usize) -> bool {
+        let mut validator = Validator::new(self.root);
+        validator.validate(schema, value, "", depth);
+        validator.violations.is_empty()
+    }
+
+    fn resolve(&self, reference: &str) -> Option<&'a Value> {
+        let pointer = reference.strip_prefix('#')?;
+        let pointer = percent_encoding::percent_decode_str(pointer).decode_utf8_lossy();
+        self.root.pointer(&pointer)
+    }
+
+    fn validate(&mut self, schema: &Value, value: &Value, pointer: &str, depth: usize) {
+        if depth > MAX_DEPTH {
+            self.report(pointer, "schema nests too deeply; is a $ref circular?");
+            return;
+        }
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => return self.report(pointer, "no value is allowed here"),
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+
+        if let Some(Value::String(reference)) = schema.get("$ref") {
+            match self.resolve(reference) {
+                Some(target) => self.validate(target, value, pointer, depth + 1),
+                None => self.report(pointer, format!("cannot resolve $ref {:?}", reference)),
+            }
+        }
+
+        if let Some(expected) = schema.get("type") {
+            let actual = type_name(value);
+            let matches = |t: &Value| {
+                let t = t.as_str().unwrap_or_default();
+                t == actual
+                    || (t == "number" && actual == "integer")
+                    || (t == "integer" && value.as_f64().is_some_and(|n| n.fract() == 0.))
+            };
+            let ok = match expected {
+                Value::Array(types) => types.iter().any(matches),
+                t => matches(t),
+            };
+            if !ok {
+                let expected = match expected {
+                    Value::Array(types) => types
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .collect::<Vec<_>>()
+                        .join(" or "),
+                    t => t.as_str().unwrap_or_default().to_string(),
+                };
+                self.report(pointer, format!("expected {}, found {}", expected, actual));
+            }
+        }
+        if let Some(Value::Array(options)) = schema.get("enum")
+            && !options.contains(value)
+        {
+            self.report(pointer, "value is not one of the allowed values in enum");
+        }
+        if let Some(expected) = schema.get("const")
+            && expected != value
+        {
+            self.report(pointer, format!("expected the constant {}", expected));
+        }
+
+        match value {
+            Value::Number(n) => self.validate_number(schema, n.as_f64().unwrap_or(0.), pointer),
+            Value::String(s) => self.validate_string(schema, s, pointer),
+            Value::Array(items) => self.validate_array(schema, items, pointer, depth),
+            Value::Object(map) => self.validate_object(schema, map, pointer, depth),
+            _ => {}
+        }
+
+        if let Some(Value::Array(schemas)) = schema.get("allOf") {
+            for subschema in schemas {
+                self.validate(subschema, value, pointer, depth + 1);
+            }
+        }
+        if let Some(Value::Array(schemas)) = schema.get("anyOf")
+            && !schemas.iter().any(|s| self.is_valid(s, value, depth + 1))
+        {
+            self.report(pointer, "value matches none of the schemas in anyOf");
+        }
+        if let Some(Value::Array(schemas)) = schema.get("oneOf") {
+            let matched = schemas
+                .iter()
+                .filter(|s| self.is_valid(s, value, depth + 1))
+                .count();
+            if matched != 1 {
+                self.report(
+                    pointer,
+                    format!(
+                        "value matches {} of the schemas in oneOf, not exactly one",
+                        matched
+                    ),
+                );
+            }
+        }
+        if let Some(not) = schema.get("not")
+            && self.is_valid(not, value, depth + 1)
+        {
+            self.report(pointer, "value matches the schema in not");
+        }
+        if let Some(condition) = schema.get("if") {
+            let branch = if self.is_valid(condition, value, depth + 1) {
+                schema.get("then")
+            } else {
+                schema.get("else")
+            };
+            if let Some(branch) = branch {
+                self.validate(branch, value, pointer, depth + 1);
+            }
+        }
+    }
+
+    fn validate_number(&mut self, schema: &Map<String, Value>, n: f64, pointer: &str) {
+        let limit = |key: &str| schema.get(key).and_then(Value::as_f64);
+        // Draft 4 spells exclusive limits as booleans next to the limit.
+        let exclusive = |key: &str| schema.get(key).and_then(Value::as_bool).unwrap_or(false);
+        if let Some(minimum) = limit("minimum") {
+            if exclusive("exclusiveMinimum") && n <= minimum {
+                self.report(pointer, format!("{} is not greater than {}", n, minimum));
+            } else if n < minimum {
+                self.report(
+                    pointer,
+                    format!("{} is less than the minimum of {}", n, minimum),
+                );
+            }
+        }
+        if let Some(maximum) = limit("maximum") {
+            if exclusive("exclusiveMaximum") && n >= maximum {
+                self.report(pointer, format!("{} is not less than {}", n, maximum));
+            } else if n > maximum {
+                self.report(
+                    pointer,
+                    format!("{} is more than the maximum of {}", n, maximum),
+                );
+            }
+        }
+        if let Some(minimum) = limit("exclusiveMinimum")
+            && n <= minimum
+        {
+            self.report(pointer, format!("{} is not greater than {}", n, minimum));
+        }
+        if let Some(maximum) = limit("exclusiveMaximum")
+            && n >= maximum
+        {
+            self.report(pointer, format!("{} is not less than {}", n, maximum));
+        }
+        if let Some(divisor) = limit("multipleOf")
+            && divisor > 0.
+        {
+            let quotient = n / divisor;
+            if (quotient - quotient.round()).abs() > 1e-9 {
+                self.report(pointer, format!("{} is not a multiple of {}", n, divisor));
+            }
+        }
+    }
+
+    fn validate_string(&mut self, schema: &Map<String, Value>, s: &str, pointer: &str) {
+        let length = s.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+            && length < min
+        {
+            self.report(
+                pointer,
+                format!("string is shorter than {} characters", min),
+            );
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+            && length > max
+        {
+            self.report(pointer, format!("string is longer than {} characters", max));
+        }
+        if let Some(Value::String(pattern)) = schema.get("pattern") {
+            match Regex::new(pattern) {
+                Ok(regex) if !regex.is_match(s) => {
+                    self.report(pointer, format!("string does not match {:?}", pattern))
+                }
+                Ok(_) => {}
+                Err(_) => self.report(pointer, format!("schema pattern {:?} is invalid", pattern)),
+            }
+        }
+    }
+
+    fn validate_array(
+        &mut self,
+        schema: &Map<String, Value>,
+        items: &[Value],
+        pointer: &str,
+        depth: usize,
+    ) {
+        let count = items.len() as u64;
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+            && count < min
+        {
+            self.report(pointer, format!("array has fewer than {} items", min));
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+            && count > max
+        {
+            self.report(pointer, format!("array has more than {} items", max));
+        }
+        if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+            for (i, item) in items.iter().enumerate() {
+                if let Some(j) = items[..i].iter().position(|other| other == item) {
+                    self.report(
+                        &format!("{}/{}", pointer, i),
+                        format!("item is a duplicate of item {}", j),
+                    );
+                }
+            }
+        }
+
+        // Draft 2020-12 uses prefixItems and items; draft 7 uses an array in
+        // items and additionalItems.
+        let (prefix, rest) = match (schema.get("prefixItems"), schema.get("items")) {
+            (Some(Value::Array(prefix)), rest) => (prefix.as_slice(), rest),
+            (None, Some(Value::Array(prefix))) => {
+                (prefix.as_slice(), schema.get("additionalItems"))
+            }
+            (_, rest) => (&[][..], rest),
+        };
+        for (i, item) in items.iter().enumerate() {
+            let item_pointer = format!("{}/{}", pointer, i);
+            match prefix.get(i).or(rest) {
+                Some(subschema) => self.validate(subschema, item, &item_pointer, depth + 1),
+                None => break,
+            }
+        }
+
+        if let Some(contains) = schema.get("contains") {
+            let matched = items
+                .iter()
+                .filter(|item| self.is_valid(contains, item, depth + 1))
+                .count() as u64;
+            let min = schema
+                .get("minContains")
+                .and_then(Value::as_u64)
+                .unwrap_or(1);
+            if matched < min {
+                self.report(
+                    pointer,
+                    format!(
+                        "array has {} items matching contains, fewer than {}",
+                        matched, min
+                    ),
+                );
+            }
+            if let Some(max) = schema.get("maxContains").and_then(Value::as_u64)
+                && matched > max
+            {
+                self.report(
+                    pointer,
+                    format!(
+                        "array has {} items matching contains, more than {}",
+                        matched, max
+                    ),
+                );
+            }
+        }
+    }
+
+    fn validate_object(
+        &mut self,
+        schema: &Map<String, Value>,
+        map: &Map<String, Value>,
+        pointer: &str,
+        depth: usize,
+    ) {
+        let count = map.len() as u64;
+        if let Some(min) = schema.get("minProperties").and_then(Value::as_u64)
+            && count < min
+        {
+            self.report(pointer, format!("object has fewer than {} properties", min));
+        }
+        if let Some(max) = schema.get("maxProperties").and_then(Value::as_u64)
+            && count > max
+        {
+            self.report(pointer, format!("object has more than {} properties", max));
+        }
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !map.contains_key(key) {
+                    self.report(pointer, format!("required property {:?} is missing", key));
+                }
+            }
+        }
+        if let Some(Value::Object(dependencies)) = schema.get("dependentRequired") {
+            for (key, required) in dependencies {
+                if !map.contains_key(key) {
+                    continue;
+                }
+                for other in required
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                {
+                    if !map.contains_key(other) {
+                        self.report(
+                            pointer,
+                            format!("property {:?} is required when {:?} is present", other, key),
+                        );
+                    }
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let patterns: Vec<(Regex, &Value)> = schema
+            .get("patternProperties")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter_map(|(pattern, subschema)| Some((Regex::new(pattern).ok()?, subschema)))
+            .collect();
+        for (key, value) in map {
+            let value_pointer = format!("{}/{}", pointer, escape_pointer_token(key));
+            if let Some(names) = schema.get("propertyNames")
+                && !self.is_valid(names, &Value::String(key.clone()), depth + 1)
+            {
+                self.report(&value_pointer, "property name does not match propertyNames");
+            }
+            let mut matched = false;
+            if let Some(subschema) = properties.and_then(|properties| properties.get(key)) {
+                matched = true;
+                self.validate(subschema, value, &value_pointer, depth + 1);
+            }
+            for (regex, subschema) in &patterns {
+                if regex.is_match(key) {
+                    matched = true;
+                    self.validate(subschema, value, &value_pointer, depth + 1);
+                }
+            }
+            if !matched && let Some(additional) = schema.get("additionalProperties") {
+                if additional == &Value::Bool(false) {
+                    self.report(&value_pointer, format!("property {:?} is not allowed", key));
+                } else {
+                    self.validate(additional, value, &value_pointer, depth + 1);
+                }
+            }
+        }
+    }
+}
+
+impl Tool for JSONSchemaTool {
+    fn title() -> &'static str {
+        "JSON Schema"
+    }
+
+    fn short_title() -> &'static str {
+        "Schema"
+    }
+
+    fn description() -> &'static str {
+        "Infers a JSON Schema from a document, or validates a document against a schema."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_run_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.schema.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            mode: self.mode,
+            document: include_input.then(|| self.document.read(cx).value().to_string()),
+            schema: include_input.then(|| self.schema.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_schema_mode(&SetSchemaMode(preset.mode), window, cx);
+        if let Some(document) = preset.document {
+            self.document.update(cx, |state, cx| {
+                state.set_value(document, window, cx);
+            });
+        }
+        if let Some(schema) = preset.schema {
+            self.schema.update(cx, |state, cx| {
+                state.set_value(schema, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for JSONSchemaTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for JSONSchemaTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let document = self.document.read(cx).value();
+        let schema = self.schema.read(cx).value();
+        let mode = self.mode;
+        let disabled = match mode {
+            Mode::Infer => document.is_empty(),
+            Mode::Validate => document.is_empty() || schema.is_empty(),
+        };
+        let muted = cx.theme().muted_foreground;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_schema_mode))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("run-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("run-button")
+                                    .label(match mode {
+                                        Mode::Infer => "Infer Schema",
+                                        Mode::Validate => "Validate",
+                                    })
+                                    .primary()
+                                    .disabled(disabled)
+                                    .on_click(cx.listener(Self::on_run_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Mode")
+                                    .menu_with_check(
+                                        "Infer Schema",
+                                        mode == Mode::Infer,
+                                        Box::new(SetSchemaMode(Mode::Infer)),
+                                    )
+                                    .menu_with_check(
+                                        "Validate",
+                                        mode == Mode::Validate,
+                                        Box::new(SetSchemaMode(Mode::Validate)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy Schema")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .flex_1()
+                    .gap_2()
+                    .child(
+                        v_flex()
+                            .h_full()
+                            .flex_1()
+                            .gap_2()
+                            .child(Label::new("Document"))
+                            .child(
+                                TextInput::new(&self.document)
+                                    .h_full()
+                                    .font_family(editor_font(cx))
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            ),
+                    )
+                    .child(
+                        v_flex()
+                            .h_full()
+                            .flex_1()
+                            .gap_2()
+                            .child(Label::new("Schema"))
+                            .child(
+                                TextInput::new(&self.schema)
+                                    .h_full()
+                                    .font_family(editor_font(cx))
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            ),
+                    ),
+            )
+            .when_some(self.violations.as_ref(), |this, violations| {
+                this.child(
+                    v_flex()
+                        .id("violations")
+                        .max_h(px(200.))
+                        .gap_1()
+                        .overflow_y_scroll()
+                        .child(Label::new(match violations.len() {
+                            0 => "The document is valid".to_string(),
+                            1 => "1 violation".to_string(),
+                            n => format!("{} violations", n),
+                        }))
+                        .children(violations.iter().map(|violation| {
+                            h_flex()
+                                .gap_2()
+                                .items_start()
+                                .child(div().font_family(editor_font(cx)).text_color(muted).child(
+                                    if violation.pointer.is_empty() {
+                                        "(root)".to_string()
+                                    } else {
+                                        violation.pointer.clone()
+                                    },
+                                ))
+                                .child(violation.message.clone())
+                        })),
+                )
+            })
+    }
+}
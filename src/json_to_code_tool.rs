@@ -0,0 +1,375 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+    v_flex,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::codegen::{self, Naming, Options, Target},
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetCodeTarget(Target);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetFieldNaming(Naming);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetAllOptional(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    target: Target,
+    naming: Naming,
+    all_optional: bool,
+    root_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+fn highlighter(target: Target) -> Language {
+    match target {
+        Target::Rust => Language::Rust,
+        Target::TypeScript => Language::TypeScript,
+        Target::Go => Language::Go,
+    }
+}
+
+pub struct JSONToCodeTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    root_name: Entity<InputState>,
+    code: Entity<InputState>,
+    target: Target,
+    naming: Naming,
+    all_optional: bool,
+    error: Option<ToolError>,
+}
+
+impl JSONToCodeTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Sample JSON")
+        });
+        let root_name = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value("Root")
+                .placeholder("Root Type Name")
+        });
+        let code = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(highlighter(Target::Rust).name().to_string())
+                .line_number(true)
+                .default_value("")
+                .placeholder("Generated Code")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            root_name,
+            code,
+            target: Target::Rust,
+            naming: Naming::Idiomatic,
+            all_optional: false,
+            error: None,
+        }
+    }
+
+    fn on_generate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let root_name = self.root_name.read(cx).value().clone();
+        let options = Options {
+            target: self.target,
+            naming: self.naming,
+            all_optional: self.all_optional,
+            root_name: &root_name,
+        };
+        let code_value = match codegen::generate(&value, &options) {
+            Ok(code) => {
+                self.error = None;
+                code
+            }
+            Err(err) => {
+                self.error = Some(err);
+                String::new()
+            }
+        };
+        let language = highlighter(self.target);
+        self.code.update(cx, |state, cx| {
+            state.set_highlighter(language.name().to_string(), cx);
+            state.set_value(SharedString::from(code_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.code.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    /// Regenerates the code after an option changes, if there is any.
+    fn refresh(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.code.read(cx).value().is_empty() {
+            cx.notify();
+        } else {
+            self.on_generate_click(&ClickEvent::default(), window, cx);
+        }
+    }
+
+    fn on_action_set_code_target(
+        &mut self,
+        action: &SetCodeTarget,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.target = action.0;
+        self.refresh(window, cx);
+    }
+
+    fn on_action_set_field_naming(
+        &mut self,
+        action: &SetFieldNaming,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.naming = action.0;
+        self.refresh(window, cx);
+    }
+
+    fn on_action_set_all_optional(
+        &mut self,
+        action: &SetAllOptional,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.all_optional = action.0;
+        self.refresh(window, cx);
+    }
+}
+
+impl Tool for JSONToCodeTool {
+    fn title() -> &'static str {
+        "JSON to Code"
+    }
+
+    fn short_title() -> &'static str {
+        "To Code"
+    }
+
+    fn description() -> &'static str {
+        "Infers types from sample JSON and generates Rust structs with serde derives, TypeScript interfaces, or Go structs."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_generate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.code.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            target: self.target,
+            naming: self.naming,
+            all_optional: self.all_optional,
+            root_name: self.root_name.read(cx).value().to_string(),
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.target = preset.target;
+        self.naming = preset.naming;
+        self.all_optional = preset.all_optional;
+        self.root_name.update(cx, |state, cx| {
+            state.set_value(preset.root_name, window, cx);
+        });
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.refresh(window, cx);
+    }
+}
+
+impl Focusable for JSONToCodeTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for JSONToCodeTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let target = self.target;
+        let naming = self.naming;
+        let all_optional = self.all_optional;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_code_target))
+            .on_action(cx.listener(Self::on_action_set_field_naming))
+            .on_action(cx.listener(Self::on_action_set_all_optional))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("generate-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("generate-button")
+                                    .label(format!("Generate {}", target.name()))
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_generate_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                let this = Target::ALL.into_iter().fold(
+                                    this.label("Language"),
+                                    |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            target == option,
+                                            Box::new(SetCodeTarget(option)),
+                                        )
+                                    },
+                                );
+                                Naming::ALL
+                                    .into_iter()
+                                    .fold(this.label("Field Names"), |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            naming == option,
+                                            Box::new(SetFieldNaming(option)),
+                                        )
+                                    })
+                                    .label("Options")
+                                    .menu_with_check(
+                                        "All Fields Optional",
+                                        all_optional,
+                                        Box::new(SetAllOptional(!all_optional)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        div().w(px(200.)).child(
+                            TextInput::new(&self.root_name)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy Code")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .flex_1()
+                    .gap_2()
+                    .child(
+                        v_flex()
+                            .h_full()
+                            .flex_1()
+                            .gap_2()
+                            .child(Label::new("JSON"))
+                            .child(
+                                TextInput::new(&self.editor)
+                                    .h_full()
+                                    .font_family(editor_font(cx))
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            ),
+                    )
+                    .child(
+                        v_flex()
+                            .h_full()
+                            .flex_1()
+                            .gap_2()
+                            .child(Label::new(target.name()))
+                            .child(
+                                TextInput::new(&self.code)
+                                    .h_full()
+                                    .font_family(editor_font(cx))
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            ),
+                    ),
+            )
+    }
+}
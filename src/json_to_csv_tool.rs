@@ -0,0 +1,433 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Delimiter {
+    Comma,
+    Semicolon,
+    Tab,
+    Pipe,
+}
+
+impl Delimiter {
+    fn as_char(&self) -> char {
+        match self {
+            Delimiter::Comma => ',',
+            Delimiter::Semicolon => ';',
+            Delimiter::Tab => '\t',
+            Delimiter::Pipe => '|',
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum MissingField {
+    Empty,
+    Null,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetDelimiter(Delimiter);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = json_tools, no_json)]
+pub struct SetMissingField(MissingField);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    delimiter: Delimiter,
+    missing_field: MissingField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct JSONToCSVTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    converted: Entity<InputState>,
+    delimiter: Delimiter,
+    missing_field: MissingField,
+    error: Option<ToolError>,
+}
+
+impl JSONToCSVTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("JSON Source")
+        });
+        let converted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: true,
+                })
+                .default_value("")
+                .placeholder("CSV")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            converted,
+            delimiter: Delimiter::Comma,
+            missing_field: MissingField::Empty,
+            error: None,
+        }
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let json = match serde_json::from_str::<Value>(&value) {
+            Ok(json) => json,
+            Err(err) => {
+                self.error = Some(ToolError::from(err).context("Invalid JSON"));
+                cx.notify();
+                return;
+            }
+        };
+        self.error = None;
+        let csv = json_to_csv(&json, self.delimiter.as_char(), self.missing_field);
+        self.converted.update(cx, |state, cx| {
+            state.set_value(SharedString::from(csv), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_converted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.converted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_delimiter(
+        &mut self,
+        action: &SetDelimiter,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.delimiter = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_missing_field(
+        &mut self,
+        action: &SetMissingField,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.missing_field = action.0;
+        cx.notify();
+    }
+}
+
+fn json_to_csv(json: &Value, delimiter: char, missing_field: MissingField) -> String {
+    let rows: Vec<Vec<(String, String)>> = match json {
+        Value::Array(items) => items.iter().map(flatten_row).collect(),
+        _ => vec![flatten_row(json)],
+    };
+
+    // Columns are ordered by first appearance so that rows missing a key
+    // do not reshuffle the header.
+    let mut columns: Vec<String> = Vec::new();
+    for row in &rows {
+        for (key, _) in row {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+    }
+
+    let missing = match missing_field {
+        MissingField::Empty => "",
+        MissingField::Null => "null",
+    };
+    let separator = delimiter.to_string();
+
+    let mut lines = Vec::with_capacity(rows.len() + 1);
+    lines.push(
+        columns
+            .iter()
+            .map(|column| escape_field(column, delimiter))
+            .collect::<Vec<_>>()
+            .join(&separator),
+    );
+    for row in &rows {
+        lines.push(
+            columns
+                .iter()
+                .map(|column| {
+                    row.iter()
+                        .find(|(key, _)| key == column)
+                        .map(|(_, value)| escape_field(value, delimiter))
+                        .unwrap_or_else(|| missing.to_string())
+                })
+                .collect::<Vec<_>>()
+                .join(&separator),
+        );
+    }
+    lines.join("\n")
+}
+
+fn flatten_row(value: &Value) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    match value {
+        Value::Object(_) | Value::Array(_) => flatten_value(value, "", &mut fields),
+        _ => fields.push(("value".to_string(), format_scalar(value))),
+    }
+    fields
+}
+
+fn flatten_value(value: &Value, prefix: &str, fields: &mut Vec<(String, String)>) {
+    let join = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", prefix, key)
+        }
+    };
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (k, v) in map {
+                flatten_value(v, &join(k), fields);
+            }
+        }
+        Value::Array(arr) if !arr.is_empty() => {
+            for (i, v) in arr.iter().enumerate() {
+                flatten_value(v, &join(&i.to_string()), fields);
+            }
+        }
+        _ => fields.push((prefix.to_string(), format_scalar(value))),
+    }
+}
+
+fn format_scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    }
+}
+
+fn escape_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains(['"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl Tool for JSONToCSVTool {
+    fn title() -> &'static str {
+        "JSON to CSV Flattener"
+    }
+
+    fn short_title() -> &'static str {
+        "To CSV"
+    }
+
+    fn description() -> &'static str {
+        "Flattens an array of JSON objects into CSV with dotted column names."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_converted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.converted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            delimiter: self.delimiter,
+            missing_field: self.missing_field,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_delimiter(&SetDelimiter(preset.delimiter), window, cx);
+        self.on_action_set_missing_field(&SetMissingField(preset.missing_field), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for JSONToCSVTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for JSONToCSVTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let delimiter = self.delimiter;
+        let missing_field = self.missing_field;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_delimiter))
+            .on_action(cx.listener(Self::on_action_set_missing_field))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("convert-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("convert-button")
+                                    .label("Convert")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Delimiter")
+                                    .menu_with_check(
+                                        "Comma",
+                                        delimiter == Delimiter::Comma,
+                                        Box::new(SetDelimiter(Delimiter::Comma)),
+                                    )
+                                    .menu_with_check(
+                                        "Semicolon",
+                                        delimiter == Delimiter::Semicolon,
+                                        Box::new(SetDelimiter(Delimiter::Semicolon)),
+                                    )
+                                    .menu_with_check(
+                                        "Tab",
+                                        delimiter == Delimiter::Tab,
+                                        Box::new(SetDelimiter(Delimiter::Tab)),
+                                    )
+                                    .menu_with_check(
+                                        "Pipe",
+                                        delimiter == Delimiter::Pipe,
+                                        Box::new(SetDelimiter(Delimiter::Pipe)),
+                                    )
+                                    .label("Missing Fields")
+                                    .menu_with_check(
+                                        "Empty",
+                                        missing_field == MissingField::Empty,
+                                        Box::new(SetMissingField(MissingField::Empty)),
+                                    )
+                                    .menu_with_check(
+                                        "null",
+                                        missing_field == MissingField::Null,
+                                        Box::new(SetMissingField(MissingField::Null)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-converted-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_converted_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.converted)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -1,24 +1,36 @@
+use std::{collections::HashMap, rc::Rc};
+
 use gpui::{
     App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
-    ParentElement, Render, SharedString, Styled, Window, div, prelude::FluentBuilder, px,
+    InteractiveElement, MouseButton, ParentElement, Render, SharedString, Styled, Subscription,
+    Window, div, prelude::FluentBuilder, px,
 };
 
 use gpui_component::{
-    Disableable, StyledExt,
+    Disableable, Icon, IconName, ListItem, Sizable, StyledExt, TreeItem, TreeState,
     button::{Button, ButtonVariants},
+    clipboard::Clipboard,
     h_flex,
     highlighter::Language,
-    input::{InputState, TabSize, TextInput},
-    ListItem, TreeItem, TreeState, tree,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    tag::Tag,
+    tree,
 };
 
-use crate::Tool;
+use crate::{Tool, ToolError, editor_font};
 
 pub struct JSONViewerTool {
     focus_handle: FocusHandle,
     editor: Entity<InputState>,
+    search: Entity<InputState>,
     tree_state: Entity<TreeState>,
+    json: Option<Rc<serde_json::Value>>,
+    /// The kind of value at each node of the tree, by node id.
+    kinds: Rc<HashMap<SharedString, &'static str>>,
+    expanded: bool,
     view_mode: bool,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl JSONViewerTool {
@@ -38,30 +50,84 @@ impl JSONViewerTool {
                 .default_value("")
                 .placeholder("JSON Source")
         });
+        let search = cx.new(|cx| InputState::new(window, cx).placeholder("Search Keys"));
         let tree_state = cx.new(|cx| TreeState::new(cx));
 
+        let _subscriptions = vec![cx.subscribe_in(&search, window, Self::on_search_event)];
+
         Self {
             focus_handle: cx.focus_handle(),
             editor,
+            search,
             tree_state,
+            json: None,
+            kinds: Rc::new(HashMap::new()),
+            expanded: true,
             view_mode: false,
+            error: None,
+            _subscriptions,
         }
     }
 
+    /// Rebuilds the tree from the parsed JSON, keeping only the keys that
+    /// match the search along with the nodes leading to them.
+    fn update_tree(&mut self, cx: &mut Context<Self>) {
+        let Some(json) = &self.json else {
+            return;
+        };
+        let query = self.search.read(cx).value().trim().to_lowercase();
+        let mut kinds = HashMap::new();
+        let items = json_to_tree_items(json, "", &query, self.expanded, &mut kinds);
+        self.kinds = Rc::new(kinds);
+        self.tree_state.update(cx, |state, cx| {
+            state.set_items(items, cx);
+        });
+        cx.notify();
+    }
+
     fn on_view_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
         if !self.view_mode {
             let value = self.editor.read(cx).value().clone();
-            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&value) {
-                let items = json_to_tree_items(&json, "root");
-                self.tree_state.update(cx, |state, cx| {
-                    state.set_items(items, cx);
-                });
+            match serde_json::from_str::<serde_json::Value>(&value) {
+                Ok(json) => {
+                    self.error = None;
+                    self.json = Some(Rc::new(json));
+                    self.expanded = true;
+                    self.update_tree(cx);
+                }
+                Err(err) => {
+                    self.error = Some(ToolError::from(err).context("Invalid JSON"));
+                    cx.notify();
+                    return;
+                }
             }
         }
         self.view_mode = !self.view_mode;
         cx.notify();
     }
 
+    fn on_expand_all_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.expanded = true;
+        self.update_tree(cx);
+    }
+
+    fn on_collapse_all_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.expanded = false;
+        self.update_tree(cx);
+    }
+
+    fn on_search_event(
+        &mut self,
+        _: &Entity<InputState>,
+        event: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = event {
+            self.update_tree(cx);
+        }
+    }
+
     fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
         let value = self.editor.read(cx).value().clone();
         cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
@@ -77,48 +143,85 @@ impl JSONViewerTool {
     }
 }
 
-fn json_to_tree_items(value: &serde_json::Value, key: &str) -> Vec<TreeItem> {
-    match value {
-        serde_json::Value::Object(map) => {
-            let children: Vec<TreeItem> = map
-                .iter()
-                .map(|(k, v)| {
-                    let id = format!("{}/{}", key, k);
-                    match v {
-                        serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
-                            let label = format_node_label(k, v);
-                            let child_items = json_to_tree_items(v, &id);
-                            TreeItem::new(id, label)
-                                .children(child_items)
-                                .expanded(true)
-                        }
-                        _ => TreeItem::new(id, format!("{}: {}", k, format_value(v))),
-                    }
-                })
-                .collect();
-            children
+/// Builds the tree items for the children of `value`. Node ids are JSON
+/// pointers, so they stay unique whatever the keys contain. While searching,
+/// a node is kept if its key matches or any node below it does, and the
+/// containers on the way are expanded so that the matches are in view.
+fn json_to_tree_items(
+    value: &serde_json::Value,
+    pointer: &str,
+    query: &str,
+    expanded: bool,
+    kinds: &mut HashMap<SharedString, &'static str>,
+) -> Vec<TreeItem> {
+    let children: Vec<(String, String, &serde_json::Value)> = match value {
+        serde_json::Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| (k.clone(), k.replace('~', "~0").replace('/', "~1"), v))
+            .collect(),
+        serde_json::Value::Array(arr) => arr
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("[{}]", i), i.to_string(), v))
+            .collect(),
+        _ => {
+            if !query.is_empty() {
+                return vec![];
+            }
+            kinds.insert(pointer.to_string().into(), kind(value));
+            return vec![TreeItem::new(pointer.to_string(), format_value(value))];
         }
-        serde_json::Value::Array(arr) => {
-            let children: Vec<TreeItem> = arr
-                .iter()
-                .enumerate()
-                .map(|(i, v)| {
-                    let id = format!("{}[{}]", key, i);
-                    match v {
-                        serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
-                            let label = format_node_label(&format!("[{}]", i), v);
-                            let child_items = json_to_tree_items(v, &id);
-                            TreeItem::new(id, label)
-                                .children(child_items)
-                                .expanded(true)
-                        }
-                        _ => TreeItem::new(id, format!("[{}]: {}", i, format_value(v))),
+    };
+
+    children
+        .into_iter()
+        .filter_map(|(key, segment, v)| {
+            let id = format!("{}/{}", pointer, segment);
+            let matches = query.is_empty() || key.to_lowercase().contains(query);
+            let item = match v {
+                serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
+                    // Everything under a matching key is shown.
+                    let child_query = if matches { "" } else { query };
+                    let child_items = json_to_tree_items(v, &id, child_query, expanded, kinds);
+                    if !matches && child_items.is_empty() {
+                        return None;
                     }
-                })
-                .collect();
-            children
+                    TreeItem::new(id.clone(), format_node_label(&key, v))
+                        .children(child_items)
+                        .expanded(expanded || !query.is_empty())
+                }
+                _ if matches => TreeItem::new(id.clone(), format!("{}: {}", key, format_value(v))),
+                _ => return None,
+            };
+            kinds.insert(id.into(), kind(v));
+            Some(item)
+        })
+        .collect()
+}
+
+fn kind(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
+/// The text copied for the node at `pointer`: strings as they are, and
+/// arrays and objects pretty-printed. It is only worked out when copied, as
+/// printing every container while building the tree takes time that grows
+/// with the square of the nesting depth.
+fn node_text(json: &serde_json::Value, pointer: &str) -> String {
+    match json.pointer(pointer) {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(value @ (serde_json::Value::Array(_) | serde_json::Value::Object(_))) => {
+            serde_json::to_string_pretty(value).unwrap_or_default()
         }
-        _ => vec![TreeItem::new(key.to_string(), format_value(value))],
+        Some(value) => value.to_string(),
+        None => String::new(),
     }
 }
 
@@ -156,6 +259,37 @@ impl Tool for JSONViewerTool {
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_view_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<serde_json::Value> {
+        Some(serde_json::Value::from(
+            self.editor.read(cx).value().to_string(),
+        ))
+    }
+
+    fn restore_state(
+        &mut self,
+        state: &serde_json::Value,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(input) = state.as_str() {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
 }
 
 impl Focusable for JSONViewerTool {
@@ -171,6 +305,9 @@ impl Render for JSONViewerTool {
         cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
         let value = self.editor.read(cx).value();
+        let view_mode = self.view_mode;
+        let kinds = self.kinds.clone();
+        let json = self.json.clone();
 
         div()
             .v_flex()
@@ -181,11 +318,23 @@ impl Render for JSONViewerTool {
                     .gap_2()
                     .child(
                         Button::new("view-button")
-                            .label(if self.view_mode { "Back" } else { "View" })
+                            .label(if view_mode { "Back" } else { "View" })
                             .primary()
                             .disabled(value.is_empty())
                             .on_click(cx.listener(Self::on_view_click)),
                     )
+                    .when(view_mode, |this| {
+                        this.child(
+                            Button::new("expand-all-button")
+                                .label("Expand All")
+                                .on_click(cx.listener(Self::on_expand_all_click)),
+                        )
+                        .child(
+                            Button::new("collapse-all-button")
+                                .label("Collapse All")
+                                .on_click(cx.listener(Self::on_collapse_all_click)),
+                        )
+                    })
                     .child(
                         Button::new("copy-button")
                             .label("Copy")
@@ -198,26 +347,69 @@ impl Render for JSONViewerTool {
                             .on_click(cx.listener(Self::on_paste_click)),
                     ),
             )
-            .when(self.view_mode, |this| {
-                this.child(
-                    tree(&self.tree_state, |_ix, entry, _selected, _window, _cx| {
-                        ListItem::new(SharedString::from(entry.item().id.clone()))
-                            .px(px(16.) * entry.depth() as f32)
-                            .child(
-                                div()
-                                    .font_family("Space Mono")
-                                    .text_size(px(15.))
-                                    .child(entry.item().label.clone()),
-                            )
-                    })
+            .when(view_mode, |this| {
+                this.child(TextInput::new(&self.search).cleanable()).child(
+                    tree(
+                        &self.tree_state,
+                        move |_ix, entry, _selected, _window, cx| {
+                            let item = entry.item();
+                            let kind = kinds.get(&item.id).copied();
+                            ListItem::new(item.id.clone())
+                                .px(px(16.) * entry.depth() as f32)
+                                .child(
+                                    h_flex()
+                                        .gap_2()
+                                        .child(
+                                            Icon::new(if entry.is_expanded() {
+                                                IconName::ChevronDown
+                                            } else {
+                                                IconName::ChevronRight
+                                            })
+                                            .small()
+                                            .when(!entry.is_folder(), |this| this.invisible()),
+                                        )
+                                        .child(
+                                            div()
+                                                .font_family(editor_font(cx))
+                                                .text_size(px(15.))
+                                                .child(item.label.clone()),
+                                        )
+                                        .when_some(kind, |this, kind| {
+                                            let json = json.clone();
+                                            let pointer = item.id.clone();
+                                            this.child(Tag::secondary().small().child(kind)).child(
+                                                // Copying should not also toggle the node.
+                                                div()
+                                                    .ml_auto()
+                                                    .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                                                        cx.stop_propagation()
+                                                    })
+                                                    .child(
+                                                        Clipboard::new(SharedString::from(
+                                                            format!("copy-{}", item.id),
+                                                        ))
+                                                        .value_fn(move |_, _| {
+                                                            json.as_deref()
+                                                                .map(|json| {
+                                                                    node_text(json, &pointer)
+                                                                })
+                                                                .unwrap_or_default()
+                                                                .into()
+                                                        }),
+                                                    ),
+                                            )
+                                        }),
+                                )
+                        },
+                    )
                     .h_full(),
                 )
             })
-            .when(!self.view_mode, |this| {
+            .when(!view_mode, |this| {
                 this.child(
                     TextInput::new(&self.editor)
                         .h_full()
-                        .font_family("Space Mono")
+                        .font_family(editor_font(cx))
                         .text_size(px(15.))
                         .focus_bordered(false),
                 )
@@ -0,0 +1,528 @@
+use std::str::FromStr;
+
+use base64::{Engine as _, engine::general_purpose};
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, crypto, jwk::Jwk};
+
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    ParentElement, Render, SharedString, Styled, Subscription, Window, div, prelude::FluentBuilder,
+    px,
+};
+
+use gpui_component::{
+    ActiveTheme, StyledExt,
+    button::Button,
+    checkbox::Checkbox,
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+const TIME_CLAIMS: [(&str, &str); 3] = [
+    ("iat", "Issued At"),
+    ("nbf", "Not Before"),
+    ("exp", "Expires"),
+];
+
+/// The key is left out, as it is often a secret and presets are saved to
+/// disk.
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    base64_secret: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+struct DecodedToken {
+    header: Value,
+    payload: Value,
+    message: String,
+    signature: String,
+}
+
+enum Verification {
+    Verified(Algorithm),
+    Invalid(Algorithm),
+    Unsigned,
+    Error(String),
+}
+
+pub struct JWTDecoderTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    header: Entity<InputState>,
+    payload: Entity<InputState>,
+    key: Entity<InputState>,
+    base64_secret: bool,
+    claims: Vec<(&'static str, String)>,
+    verification: Option<Verification>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl JWTDecoderTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("JWT")
+        });
+        let header = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Header")
+        });
+        let payload = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Payload")
+        });
+        let key = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("HMAC secret, or PEM or JWK public key")
+        });
+        let _subscriptions = vec![
+            cx.subscribe_in(&editor, window, Self::on_input_event),
+            cx.subscribe_in(&key, window, Self::on_input_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            header,
+            payload,
+            key,
+            base64_secret: false,
+            claims: Vec::new(),
+            verification: None,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_decoded(window, cx);
+        }
+    }
+
+    fn update_decoded(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let token = self.editor.read(cx).value().clone();
+        let key = self.key.read(cx).value().clone();
+        let (header_value, payload_value) = match decode_token(&token) {
+            Ok(decoded) => {
+                self.claims = time_claims(&decoded.payload, Utc::now());
+                self.verification = (!key.trim().is_empty() || decoded.signature.is_empty())
+                    .then(|| verify(&decoded, &key, self.base64_secret));
+                self.error = None;
+                (
+                    serde_json::to_string_pretty(&decoded.header).unwrap_or_default(),
+                    serde_json::to_string_pretty(&decoded.payload).unwrap_or_default(),
+                )
+            }
+            Err(err) => {
+                self.claims.clear();
+                self.verification = None;
+                self.error = (!token.trim().is_empty())
+                    .then(|| ToolError::new(err).context("Invalid token"));
+                (String::new(), String::new())
+            }
+        };
+        self.header.update(cx, |state, cx| {
+            state.set_value(SharedString::from(header_value), window, cx);
+        });
+        self.payload.update(cx, |state, cx| {
+            state.set_value(SharedString::from(payload_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_base64_secret_click(
+        &mut self,
+        checked: &bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.base64_secret = *checked;
+        self.update_decoded(window, cx);
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_header_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.header.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_copy_payload_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.payload.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+}
+
+fn decode_token(token: &str) -> Result<DecodedToken, String> {
+    let token: String = token
+        .trim()
+        .trim_start_matches("Bearer ")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let parts: Vec<&str> = token.split('.').collect();
+    let [header, payload, signature] = parts.as_slice() else {
+        return Err(match parts.len() {
+            5 => "encrypted tokens (JWE) are not supported".to_string(),
+            n => format!("expected 3 parts separated by dots, found {}", n),
+        });
+    };
+    Ok(DecodedToken {
+        header: decode_part(header).map_err(|err| format!("invalid header: {}", err))?,
+        payload: decode_part(payload).map_err(|err| format!("invalid payload: {}", err))?,
+        message: format!("{}.{}", header, payload),
+        signature: signature.to_string(),
+    })
+}
+
+fn decode_part(part: &str) -> Result<Value, String> {
+    let bytes = general_purpose::URL_SAFE_NO_PAD
+        .decode(part.trim_end_matches('='))
+        .map_err(|err| err.to_string())?;
+    serde_json::from_slice(&bytes).map_err(|err| err.to_string())
+}
+
+fn verify(token: &DecodedToken, key: &str, base64_secret: bool) -> Verification {
+    let alg = token.header["alg"].as_str().unwrap_or_default();
+    if alg.eq_ignore_ascii_case("none") || token.signature.is_empty() {
+        return Verification::Unsigned;
+    }
+    let algorithm = match Algorithm::from_str(alg) {
+        Ok(algorithm) => algorithm,
+        Err(_) => return Verification::Error(format!("unsupported algorithm \"{}\"", alg)),
+    };
+    let key = match decoding_key(algorithm, key.trim(), base64_secret) {
+        Ok(key) => key,
+        Err(err) => return Verification::Error(err),
+    };
+    match crypto::verify(&token.signature, token.message.as_bytes(), &key, algorithm) {
+        Ok(true) => Verification::Verified(algorithm),
+        Ok(false) => Verification::Invalid(algorithm),
+        Err(err) => Verification::Error(err.to_string()),
+    }
+}
+
+fn decoding_key(
+    algorithm: Algorithm,
+    key: &str,
+    base64_secret: bool,
+) -> Result<DecodingKey, String> {
+    if key.starts_with('{') {
+        let jwk: Jwk = serde_json::from_str(key).map_err(|err| format!("invalid JWK: {}", err))?;
+        return DecodingKey::from_jwk(&jwk).map_err(|err| format!("invalid JWK: {}", err));
+    }
+    let key_bytes = key.as_bytes();
+    match algorithm {
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 if base64_secret => {
+            DecodingKey::from_base64_secret(key)
+        }
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => {
+            Ok(DecodingKey::from_secret(key_bytes))
+        }
+        Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(key_bytes),
+        Algorithm::EdDSA => DecodingKey::from_ed_pem(key_bytes),
+        _ => DecodingKey::from_rsa_pem(key_bytes),
+    }
+    .map_err(|err| format!("invalid key: {}", err))
+}
+
+fn time_claims(payload: &Value, now: DateTime<Utc>) -> Vec<(&'static str, String)> {
+    TIME_CLAIMS
+        .iter()
+        .filter_map(|(claim, label)| {
+            let seconds = payload.get(claim)?.as_f64()? as i64;
+            let time = DateTime::from_timestamp(seconds, 0)?;
+            let delta = (time - now).num_seconds();
+            let relative = if delta >= 0 {
+                format!("in {}", format_duration(delta))
+            } else {
+                format!("{} ago", format_duration(-delta))
+            };
+            let state = match *claim {
+                "exp" if delta <= 0 => " — expired",
+                "nbf" if delta > 0 => " — not yet valid",
+                _ => "",
+            };
+            Some((
+                *label,
+                format!(
+                    "{} ({}){}",
+                    time.format("%Y-%m-%d %H:%M:%S UTC"),
+                    relative,
+                    state
+                ),
+            ))
+        })
+        .collect()
+}
+
+fn format_duration(seconds: i64) -> String {
+    let units = [("d", 86400), ("h", 3600), ("m", 60), ("s", 1)];
+    let parts: Vec<String> = units
+        .iter()
+        .scan(seconds, |rest, (unit, size)| {
+            let n = *rest / size;
+            *rest %= size;
+            Some((n, unit))
+        })
+        .filter(|(n, _)| *n > 0)
+        .take(2)
+        .map(|(n, unit)| format!("{}{}", n, unit))
+        .collect();
+    if parts.is_empty() {
+        "0s".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+impl Tool for JWTDecoderTool {
+    fn title() -> &'static str {
+        "JWT Decoder"
+    }
+
+    fn short_title() -> &'static str {
+        "JWT Decoder"
+    }
+
+    fn description() -> &'static str {
+        "Decodes JSON Web Tokens, shows their time claims, and verifies their signatures."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_payload_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.payload.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            base64_secret: self.base64_secret,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.base64_secret = preset.base64_secret;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.update_decoded(window, cx);
+    }
+}
+
+impl Focusable for JWTDecoderTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for JWTDecoderTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let verification = self
+            .verification
+            .as_ref()
+            .map(|verification| match verification {
+                Verification::Verified(algorithm) => (
+                    format!("Signature verified ({:?})", algorithm),
+                    cx.theme().green,
+                ),
+                Verification::Invalid(algorithm) => (
+                    format!("Invalid signature ({:?})", algorithm),
+                    cx.theme().red,
+                ),
+                Verification::Unsigned => ("Token is not signed".to_string(), cx.theme().yellow),
+                Verification::Error(err) => (format!("Error: {}", err), cx.theme().red),
+            });
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_1_5()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .h_full()
+                    .gap_2()
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .h_full()
+                            .gap_2()
+                            .child(
+                                h_flex().gap_2().child(Label::new("Header")).child(
+                                    Button::new("copy-header-button")
+                                        .label("Copy")
+                                        .on_click(cx.listener(Self::on_copy_header_click))
+                                        .ml_auto(),
+                                ),
+                            )
+                            .child(
+                                TextInput::new(&self.header)
+                                    .h_full()
+                                    .font_family(editor_font(cx))
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .h_full()
+                            .gap_2()
+                            .child(
+                                h_flex().gap_2().child(Label::new("Payload")).child(
+                                    Button::new("copy-payload-button")
+                                        .label("Copy")
+                                        .on_click(cx.listener(Self::on_copy_payload_click))
+                                        .ml_auto(),
+                                ),
+                            )
+                            .child(
+                                TextInput::new(&self.payload)
+                                    .h_full()
+                                    .font_family(editor_font(cx))
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            ),
+                    ),
+            )
+            .children(self.claims.iter().map(|(label, value)| {
+                h_flex()
+                    .gap_2()
+                    .child(Label::new(*label).w(px(120.)))
+                    .child(Label::new(value.clone()).text_color(cx.theme().muted_foreground))
+            }))
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Label::new("Verify Signature"))
+                    .child(
+                        Checkbox::new("base64-secret-checkbox")
+                            .label("Base64 Secret")
+                            .checked(self.base64_secret)
+                            .on_click(cx.listener(Self::on_base64_secret_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.key)
+                    .h_1_6()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .when_some(verification, |this, (message, color)| {
+                this.child(Label::new(message).text_color(color))
+            })
+    }
+}
@@ -0,0 +1,24 @@
+use gpui::{App, KeyBinding, actions};
+
+actions!(tool, [PrimaryAction, CopyOutput, PasteInput]);
+
+/// The key context set by `ToolContainer`, so the shortcuts reach whichever
+/// tool has focus.
+pub const TOOL_CONTEXT: &str = "ToolContainer";
+
+/// Binds the shortcuts shared by every tool: Cmd/Ctrl+Enter runs the tool's
+/// primary action, Cmd/Ctrl+Shift+C copies its output, and Cmd/Ctrl+Shift+V
+/// pastes into its input.
+///
+/// Must be called after `gpui_component::init`. Multi-line editors insert a
+/// line break on Cmd/Ctrl+Enter, and the binding for editors inside a tool
+/// only takes precedence over theirs because it is added later.
+pub fn bind_tool_keys(cx: &mut App) {
+    let input_context = format!("{} > Input", TOOL_CONTEXT);
+    cx.bind_keys([
+        KeyBinding::new("secondary-enter", PrimaryAction, Some(TOOL_CONTEXT)),
+        KeyBinding::new("secondary-enter", PrimaryAction, Some(&input_context)),
+        KeyBinding::new("secondary-shift-c", CopyOutput, Some(TOOL_CONTEXT)),
+        KeyBinding::new("secondary-shift-v", PasteInput, Some(TOOL_CONTEXT)),
+    ]);
+}
@@ -1,44 +1,220 @@
+mod age_encryption_tool;
 mod assets;
+mod auth_flow_inspector_tool;
 mod base64_decoder_tool;
 mod base64_encoder_tool;
+mod binary_format_decoder_tool;
+mod certificate_inspector_tool;
+mod checksum_tool;
+mod cli;
+mod color_converter_tool;
+mod color_preview;
+mod compression_tool;
+mod config;
+mod contrast_checker_tool;
+pub mod core;
+mod cron_parser_tool;
+mod css_gradient_tool;
+mod css_selector_tester_tool;
+mod css_shadow_tool;
+mod css_unit_converter_tool;
+mod csv_json_converter_tool;
 mod data_url_generator_tool;
-mod html_decoder_tool;
-mod html_encoder_tool;
+mod date_calculator_tool;
+mod date_parser_tool;
+mod duration_parser_tool;
+mod emoji_picker_tool;
+mod encoding_converter_tool;
+mod figlet_tool;
+mod find_replace_tool;
+mod gitignore_tester_tool;
+mod history;
+mod html_entity_tool;
+mod html_formatter_tool;
+mod html_table_extractor_tool;
+mod html_to_markdown_tool;
+mod http_client_tool;
+mod id_inspector_tool;
+mod image_base64_tool;
+mod image_preview;
+mod iso_week_calculator_tool;
+mod json_array_sorter_tool;
 mod json_decoder_tool;
 mod json_encoder_tool;
+mod json_fake_data_tool;
 mod json_formatter_tool;
+mod json_schema_tool;
+mod json_to_code_tool;
+mod json_to_csv_tool;
 mod json_viewer_tool;
+mod jwt_decoder_tool;
+mod keymap;
+mod live;
+mod log_viewer_tool;
+mod mac_address_tool;
+mod markdown_table_tool;
+mod markdown_toc_tool;
+mod nanoid_generator_tool;
+mod number_base_converter_tool;
+mod number_formats_tool;
+mod password_generator_tool;
+mod password_hash_tool;
+mod path_converter_tool;
+mod persistence;
+mod presets;
+mod protobuf_decoder_tool;
+mod query_string_json_converter_tool;
+mod random_number_generator_tool;
+mod registry;
+mod request_bin_tool;
+mod settings;
+mod settings_tool;
+mod slug_generator_tool;
+mod smart_paste;
+mod sql_formatter_tool;
+mod string_escape_tool;
+mod symbol_demangler_tool;
+mod symmetric_crypto_tool;
+mod template_renderer_tool;
+mod text_ansi_tool;
+mod text_binary_converter_tool;
 mod text_character_count_tool;
+mod text_column_aligner_tool;
 mod text_difference_tool;
+mod text_duplicate_lines_tool;
+mod text_indentation_tool;
+mod text_line_endings_tool;
+mod text_lines_tool;
+mod text_similarity_tool;
+mod text_transliteration_tool;
+mod timezone_browser_tool;
+mod timezone_converter_tool;
 mod title_bar;
+mod toml_json_converter_tool;
+mod tool_error;
+mod unicode_inspector_tool;
 mod unix_timestamp_converter_tool;
 mod url_decoder_tool;
 mod url_encoder_tool;
+mod url_parser_tool;
+mod uuid_generator_tool;
+mod xml_formatter_tool;
+mod xpath_tester_tool;
 
 pub use assets::Assets;
 use gpui::{
-    AnyView, App, AppContext, Bounds, Context, Entity, Focusable, InteractiveElement, IntoElement,
-    Render, SharedString, StatefulInteractiveElement, Styled, Window, WindowBounds, WindowKind,
-    WindowOptions, div, prelude::*, px, size,
+    AnyView, App, AppContext, Bounds, ClickEvent, Context, Entity, Focusable, InteractiveElement,
+    IntoElement, Render, SharedString, StatefulInteractiveElement, Styled, Window, WindowBounds,
+    WindowKind, WindowOptions, div, prelude::*, px, size,
 };
 
-use gpui_component::{ActiveTheme, Root, TitleBar, v_flex};
+use gpui_component::{
+    ActiveTheme, IconName, Root, Sizable, TitleBar,
+    alert::Alert,
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::InputState,
+    v_flex,
+};
+use serde_json::Value;
 
+pub use age_encryption_tool::AgeEncryptionTool;
+pub use auth_flow_inspector_tool::AuthFlowInspectorTool;
 pub use base64_decoder_tool::Base64DecoderTool;
 pub use base64_encoder_tool::Base64EncoderTool;
+pub use binary_format_decoder_tool::BinaryFormatDecoderTool;
+pub use certificate_inspector_tool::CertificateInspectorTool;
+pub use checksum_tool::ChecksumTool;
+pub use cli::run_cli;
+pub use color_converter_tool::ColorConverterTool;
+pub use compression_tool::CompressionTool;
+pub use contrast_checker_tool::ContrastCheckerTool;
+pub use cron_parser_tool::CronParserTool;
+pub use css_gradient_tool::CSSGradientTool;
+pub use css_selector_tester_tool::CSSSelectorTesterTool;
+pub use css_shadow_tool::CSSShadowTool;
+pub use css_unit_converter_tool::CSSUnitConverterTool;
+pub use csv_json_converter_tool::CSVJSONConverterTool;
 pub use data_url_generator_tool::DataURLGeneratorTool;
-pub use html_decoder_tool::HTMLDecoderTool;
-pub use html_encoder_tool::HTMLEncoderTool;
+pub use date_calculator_tool::DateCalculatorTool;
+pub use date_parser_tool::DateParserTool;
+pub use duration_parser_tool::DurationParserTool;
+pub use emoji_picker_tool::EmojiPickerTool;
+pub use encoding_converter_tool::EncodingConverterTool;
+pub use figlet_tool::FigletTool;
+pub use find_replace_tool::FindReplaceTool;
+pub use gitignore_tester_tool::GitignoreTesterTool;
+pub use history::{History, HistoryEntry};
+pub use html_entity_tool::HTMLEntityTool;
+pub use html_formatter_tool::HTMLFormatterTool;
+pub use html_table_extractor_tool::HTMLTableExtractorTool;
+pub use html_to_markdown_tool::HTMLToMarkdownTool;
+pub use http_client_tool::HTTPClientTool;
+pub use id_inspector_tool::IDInspectorTool;
+pub use image_base64_tool::ImageBase64Tool;
+pub use iso_week_calculator_tool::ISOWeekCalculatorTool;
+pub use json_array_sorter_tool::JSONArraySorterTool;
 pub use json_decoder_tool::JSONDecoderTool;
 pub use json_encoder_tool::JSONEncoderTool;
+pub use json_fake_data_tool::JSONFakeDataTool;
 pub use json_formatter_tool::JSONFormatterTool;
+pub use json_schema_tool::JSONSchemaTool;
+pub use json_to_code_tool::JSONToCodeTool;
+pub use json_to_csv_tool::JSONToCSVTool;
 pub use json_viewer_tool::JSONViewerTool;
+pub use jwt_decoder_tool::JWTDecoderTool;
+pub use keymap::{CopyOutput, PasteInput, PrimaryAction, bind_tool_keys};
+pub use live::LiveUpdate;
+pub use log_viewer_tool::LogViewerTool;
+pub use mac_address_tool::MACAddressTool;
+pub use markdown_table_tool::MarkdownTableTool;
+pub use markdown_toc_tool::MarkdownTOCTool;
+pub use nanoid_generator_tool::NanoidGeneratorTool;
+pub use number_base_converter_tool::NumberBaseConverterTool;
+pub use number_formats_tool::NumberFormatsTool;
+pub use password_generator_tool::PasswordGeneratorTool;
+pub use password_hash_tool::PasswordHashTool;
+pub use path_converter_tool::PathConverterTool;
+pub use persistence::SavedState;
+pub use presets::Presets;
+pub use protobuf_decoder_tool::ProtobufDecoderTool;
+pub use query_string_json_converter_tool::QueryStringJSONConverterTool;
+pub use random_number_generator_tool::RandomNumberGeneratorTool;
+pub use registry::{ToolEntry, find_tool, registered_tools, tool_groups};
+pub use request_bin_tool::RequestBinTool;
+pub use settings::{Appearance, Settings, default_indentation, editor_font};
+pub use settings_tool::SettingsTool;
+pub use slug_generator_tool::SlugGeneratorTool;
+pub use smart_paste::{Suggestion, suggest_tool};
+pub use sql_formatter_tool::SQLFormatterTool;
+pub use string_escape_tool::StringEscapeTool;
+pub use symbol_demangler_tool::SymbolDemanglerTool;
+pub use symmetric_crypto_tool::SymmetricCryptoTool;
+pub use template_renderer_tool::TemplateRendererTool;
+pub use text_ansi_tool::TextANSITool;
+pub use text_binary_converter_tool::TextBinaryConverterTool;
 pub use text_character_count_tool::TextCharacterCountTool;
+pub use text_column_aligner_tool::TextColumnAlignerTool;
 pub use text_difference_tool::TextDifferenceTool;
+pub use text_duplicate_lines_tool::TextDuplicateLinesTool;
+pub use text_indentation_tool::TextIndentationTool;
+pub use text_line_endings_tool::TextLineEndingsTool;
+pub use text_lines_tool::TextLinesTool;
+pub use text_similarity_tool::TextSimilarityTool;
+pub use text_transliteration_tool::TextTransliterationTool;
+pub use timezone_browser_tool::TimezoneBrowserTool;
+pub use timezone_converter_tool::TimezoneConverterTool;
 pub use title_bar::AppTitleBar;
+pub use toml_json_converter_tool::TOMLJSONConverterTool;
+pub use tool_error::{ErrorPosition, ToolError};
+pub use unicode_inspector_tool::UnicodeInspectorTool;
 pub use unix_timestamp_converter_tool::UnixTimestampConverterTool;
 pub use url_decoder_tool::URLDecoderTool;
 pub use url_encoder_tool::URLEncoderTool;
+pub use url_parser_tool::URLParserTool;
+pub use uuid_generator_tool::UUIDGeneratorTool;
+pub use xml_formatter_tool::XMLFormatterTool;
+pub use xpath_tester_tool::XPathTesterTool;
 
 pub fn create_new_window<F, E>(title: &str, crate_view_fn: F, cx: &mut App)
 where
@@ -114,7 +290,7 @@ impl ToolRoot {
 }
 
 impl Render for ToolRoot {
-    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         div()
             .font_family(cx.theme().font_family.clone())
             .size_full()
@@ -124,6 +300,8 @@ impl Render for ToolRoot {
                     .child(self.title_bar.clone())
                     .child(div().flex_1().overflow_hidden().child(self.view.clone())),
             )
+            .children(Root::render_modal_layer(window, cx))
+            .children(Root::render_notification_layer(window, cx))
     }
 }
 
@@ -147,22 +325,211 @@ pub trait Tool: Focusable + Render + Sized {
     where
         Self: 'static,
     {
-        if let Some(tool) = view.downcast::<Self>().ok() {
+        if let Ok(tool) = view.downcast::<Self>() {
             cx.update_entity(&tool, |tool, cx| {
                 tool.on_active(active, window, cx);
             });
         }
     }
+
+    /// Returns the tool's options, along with its input if `include_input`
+    /// is set, to be saved as a named preset. Tools without options return
+    /// `None` and offer no presets.
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let _ = include_input;
+        let _ = cx;
+        None
+    }
+    fn preset_any(view: AnyView, include_input: bool, cx: &App) -> Option<Value>
+    where
+        Self: 'static,
+    {
+        view.downcast::<Self>()
+            .ok()
+            .and_then(|tool| tool.read(cx).preset(include_input, cx))
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let _ = preset;
+        let _ = window;
+        let _ = cx;
+    }
+    fn apply_preset_any(view: AnyView, preset: &Value, window: &mut Window, cx: &mut App)
+    where
+        Self: 'static,
+    {
+        if let Ok(tool) = view.downcast::<Self>() {
+            cx.update_entity(&tool, |tool, cx| {
+                tool.apply_preset(preset, window, cx);
+            });
+        }
+    }
+
+    /// Returns the error from the tool's last operation, which is shown in a
+    /// banner above the tool until the next one succeeds.
+    fn error(&self) -> Option<ToolError> {
+        None
+    }
+    fn error_any(view: AnyView, cx: &App) -> Option<ToolError>
+    where
+        Self: 'static,
+    {
+        view.downcast::<Self>()
+            .ok()
+            .and_then(|tool| tool.read(cx).error())
+    }
+
+    /// Whether the tool can update its output as the input changes instead of
+    /// waiting for its primary button to be clicked. Live mode is off until
+    /// switched on from the tool header.
+    fn supports_live() -> bool {
+        false
+    }
+
+    fn set_live(&mut self, live: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let _ = live;
+        let _ = window;
+        let _ = cx;
+    }
+    fn set_live_any(view: AnyView, live: bool, window: &mut Window, cx: &mut App)
+    where
+        Self: 'static,
+    {
+        if let Ok(tool) = view.downcast::<Self>() {
+            cx.update_entity(&tool, |tool, cx| {
+                tool.set_live(live, window, cx);
+            });
+        }
+    }
+
+    /// Runs the tool's primary action, as clicking its Encode, Format, Convert
+    /// or similar button would. Bound to Cmd/Ctrl+Enter.
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let _ = window;
+        let _ = cx;
+    }
+    fn primary_action_any(view: AnyView, window: &mut Window, cx: &mut App)
+    where
+        Self: 'static,
+    {
+        if let Ok(tool) = view.downcast::<Self>() {
+            cx.update_entity(&tool, |tool, cx| {
+                tool.primary_action(window, cx);
+            });
+        }
+    }
+
+    /// Copies the tool's output to the clipboard. Bound to Cmd/Ctrl+Shift+C.
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let _ = window;
+        let _ = cx;
+    }
+    fn copy_output_any(view: AnyView, window: &mut Window, cx: &mut App)
+    where
+        Self: 'static,
+    {
+        if let Ok(tool) = view.downcast::<Self>() {
+            cx.update_entity(&tool, |tool, cx| {
+                tool.copy_output(window, cx);
+            });
+        }
+    }
+
+    /// Replaces the tool's input with the clipboard's contents. Bound to
+    /// Cmd/Ctrl+Shift+V.
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let _ = window;
+        let _ = cx;
+    }
+    fn paste_input_any(view: AnyView, window: &mut Window, cx: &mut App)
+    where
+        Self: 'static,
+    {
+        if let Ok(tool) = view.downcast::<Self>() {
+            cx.update_entity(&tool, |tool, cx| {
+                tool.paste_input(window, cx);
+            });
+        }
+    }
+
+    /// Returns everything needed to bring the tool back as it was after a
+    /// restart. Defaults to the tool's preset with its input included.
+    fn state(&self, cx: &App) -> Option<Value> {
+        self.preset(true, cx)
+    }
+    fn state_any(view: AnyView, cx: &App) -> Option<Value>
+    where
+        Self: 'static,
+    {
+        view.downcast::<Self>()
+            .ok()
+            .and_then(|tool| tool.read(cx).state(cx))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        self.apply_preset(state, window, cx);
+    }
+    fn restore_state_any(view: AnyView, state: &Value, window: &mut Window, cx: &mut App)
+    where
+        Self: 'static,
+    {
+        if let Ok(tool) = view.downcast::<Self>() {
+            cx.update_entity(&tool, |tool, cx| {
+                tool.restore_state(state, window, cx);
+            });
+        }
+    }
+
+    /// Returns the editor holding the tool's output. Each run that changes it
+    /// is kept in the tool's history along with the tool's state, and both
+    /// are put back when a run is restored. Tools without one keep no
+    /// history, and tools whose output is a secret, such as a generated
+    /// password or decrypted text, leave it out so it is never saved to disk.
+    fn output(&self) -> Option<Entity<InputState>> {
+        None
+    }
+    fn output_any(view: AnyView, cx: &App) -> Option<Entity<InputState>>
+    where
+        Self: 'static,
+    {
+        view.downcast::<Self>()
+            .ok()
+            .and_then(|tool| tool.read(cx).output())
+    }
 }
 
+type PresetFn = fn(AnyView, bool, &App) -> Option<Value>;
+type ApplyPresetFn = fn(AnyView, &Value, &mut Window, &mut App);
+type StateFn = fn(AnyView, &App) -> Option<Value>;
+type SetLiveFn = fn(AnyView, bool, &mut Window, &mut App);
+type ErrorFn = fn(AnyView, &App) -> Option<ToolError>;
+type ShortcutFn = fn(AnyView, &mut Window, &mut App);
+type OutputFn = fn(AnyView, &App) -> Option<Entity<InputState>>;
+
 pub struct ToolContainer {
     focus_handle: gpui::FocusHandle,
     pub name: SharedString,
     pub short_name: SharedString,
     pub description: SharedString,
+    /// Words the tool can be searched by besides its name.
+    pub keywords: &'static [&'static str],
     tool: Option<AnyView>,
     tool_klass: Option<SharedString>,
     on_active: Option<fn(AnyView, bool, &mut Window, &mut App)>,
+    preset: Option<PresetFn>,
+    apply_preset: Option<ApplyPresetFn>,
+    state: Option<StateFn>,
+    restore_state: Option<ApplyPresetFn>,
+    set_live: Option<SetLiveFn>,
+    live: bool,
+    error: Option<ErrorFn>,
+    primary_action: Option<ShortcutFn>,
+    copy_output: Option<ShortcutFn>,
+    paste_input: Option<ShortcutFn>,
+    output: Option<OutputFn>,
+    /// The output last recorded in the history, or restored from it.
+    recorded_output: Option<SharedString>,
+    history_open: bool,
 }
 
 impl ToolContainer {
@@ -174,9 +541,23 @@ impl ToolContainer {
             name: "".into(),
             short_name: "".into(),
             description: "".into(),
+            keywords: &[],
             tool: None,
             tool_klass: None,
             on_active: None,
+            preset: None,
+            apply_preset: None,
+            state: None,
+            restore_state: None,
+            set_live: None,
+            live: false,
+            error: None,
+            primary_action: None,
+            copy_output: None,
+            paste_input: None,
+            output: None,
+            recorded_output: None,
+            history_open: false,
         }
     }
 
@@ -187,17 +568,27 @@ impl ToolContainer {
         let tool = T::new_view(window, cx);
         let tool_klass = T::klass();
 
-        let view = cx.new(|cx| {
+        cx.new(|cx| {
             let mut tool = Self::new(window, cx)
                 .tool(tool.into(), tool_klass)
-                .on_active(T::on_active_any);
+                .on_active(T::on_active_any)
+                .on_preset(T::preset_any, T::apply_preset_any)
+                .on_state(T::state_any, T::restore_state_any)
+                .on_error(T::error_any)
+                .on_shortcuts(
+                    T::primary_action_any,
+                    T::copy_output_any,
+                    T::paste_input_any,
+                )
+                .on_output(T::output_any);
+            if T::supports_live() {
+                tool = tool.on_live(T::set_live_any);
+            }
             tool.name = name.into();
             tool.short_name = short_name.into();
             tool.description = description.into();
             tool
-        });
-
-        view
+        })
     }
 
     pub fn tool(mut self, tool: AnyView, tool_klass: impl Into<SharedString>) -> Self {
@@ -210,6 +601,288 @@ impl ToolContainer {
         self.on_active = Some(on_active);
         self
     }
+
+    pub fn on_preset(mut self, preset: PresetFn, apply_preset: ApplyPresetFn) -> Self {
+        self.preset = Some(preset);
+        self.apply_preset = Some(apply_preset);
+        self
+    }
+
+    pub fn on_state(mut self, state: StateFn, restore_state: ApplyPresetFn) -> Self {
+        self.state = Some(state);
+        self.restore_state = Some(restore_state);
+        self
+    }
+
+    pub fn on_live(mut self, set_live: SetLiveFn) -> Self {
+        self.set_live = Some(set_live);
+        self
+    }
+
+    pub fn on_error(mut self, error: ErrorFn) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    pub fn on_shortcuts(
+        mut self,
+        primary_action: ShortcutFn,
+        copy_output: ShortcutFn,
+        paste_input: ShortcutFn,
+    ) -> Self {
+        self.primary_action = Some(primary_action);
+        self.copy_output = Some(copy_output);
+        self.paste_input = Some(paste_input);
+        self
+    }
+
+    pub fn on_output(mut self, output: OutputFn) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    pub fn klass(&self) -> Option<SharedString> {
+        self.tool_klass.clone()
+    }
+
+    pub fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let tool = self.tool.clone()?;
+        (self.preset?)(tool, include_input, cx)
+    }
+
+    pub fn apply_preset(&self, preset: &Value, window: &mut Window, cx: &mut App) {
+        if let (Some(tool), Some(apply_preset)) = (self.tool.clone(), self.apply_preset) {
+            apply_preset(tool, preset, window, cx);
+        }
+    }
+
+    pub fn state(&self, cx: &App) -> Option<Value> {
+        let tool = self.tool.clone()?;
+        (self.state?)(tool, cx)
+    }
+
+    pub fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut App) {
+        if let (Some(tool), Some(restore_state)) = (self.tool.clone(), self.restore_state) {
+            restore_state(tool, state, window, cx);
+        }
+        // What the restored state shows is not a new run.
+        self.recorded_output = self
+            .output(cx)
+            .map(|output| output.read(cx).value().clone());
+    }
+
+    /// Replaces the tool's input with the clipboard's contents.
+    pub fn paste_input(&self, window: &mut Window, cx: &mut App) {
+        if let (Some(tool), Some(paste_input)) = (self.tool.clone(), self.paste_input) {
+            paste_input(tool, window, cx);
+        }
+    }
+
+    pub fn error(&self, cx: &App) -> Option<ToolError> {
+        let tool = self.tool.clone()?;
+        (self.error?)(tool, cx)
+    }
+
+    fn output(&self, cx: &App) -> Option<Entity<InputState>> {
+        let tool = self.tool.clone()?;
+        (self.output?)(tool, cx)
+    }
+
+    pub fn supports_live(&self) -> bool {
+        self.set_live.is_some()
+    }
+
+    pub fn live(&self) -> bool {
+        self.live
+    }
+
+    pub fn set_live(&mut self, live: bool, window: &mut Window, cx: &mut App) {
+        if let (Some(tool), Some(set_live)) = (self.tool.clone(), self.set_live) {
+            self.live = live;
+            set_live(tool, live, window, cx);
+        }
+    }
+}
+
+impl ToolContainer {
+    fn dispatch_shortcut(
+        &mut self,
+        shortcut: Option<ShortcutFn>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let (Some(tool), Some(shortcut)) = (self.tool.clone(), shortcut) {
+            shortcut(tool, window, cx);
+            cx.notify();
+        }
+    }
+
+    /// Records the tool's output in its history if the last click or shortcut
+    /// changed it.
+    fn record_history(&mut self, cx: &mut Context<Self>) {
+        let (Some(klass), Some(output)) = (self.klass(), self.output(cx)) else {
+            return;
+        };
+        let output = output.read(cx).value().clone();
+        if output.trim().is_empty() || self.recorded_output.as_ref() == Some(&output) {
+            return;
+        }
+        let Some(state) = self.state(cx) else {
+            return;
+        };
+        self.recorded_output = Some(output.clone());
+        let entry = HistoryEntry {
+            state,
+            output: output.to_string(),
+            timestamp: chrono::Utc::now().timestamp(),
+        };
+        cx.default_global::<History>().record(&klass, entry);
+        cx.notify();
+    }
+
+    fn restore_history(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(klass) = self.klass() else {
+            return;
+        };
+        let Some(entry) = cx
+            .default_global::<History>()
+            .entries(&klass)
+            .get(ix)
+            .cloned()
+        else {
+            return;
+        };
+        self.restore_state(&entry.state, window, cx);
+        if let Some(output) = self.output(cx) {
+            output.update(cx, |state, cx| {
+                state.set_value(entry.output.clone(), window, cx);
+            });
+        }
+        self.recorded_output = Some(entry.output.into());
+        cx.notify();
+    }
+
+    fn on_history_toggle_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.history_open = !self.history_open;
+        cx.notify();
+    }
+
+    fn on_history_clear_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if let Some(klass) = self.klass() {
+            cx.default_global::<History>().clear(&klass);
+        }
+        cx.notify();
+    }
+
+    fn render_history(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let entries = self
+            .klass()
+            .map(|klass| cx.default_global::<History>().entries(&klass).to_vec())
+            .unwrap_or_default();
+
+        v_flex()
+            .px_4()
+            .pb_4()
+            .gap_1()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("history-toggle-button")
+                            .icon(if self.history_open {
+                                IconName::ChevronDown
+                            } else {
+                                IconName::ChevronRight
+                            })
+                            .label(format!("History ({})", entries.len()))
+                            .small()
+                            .ghost()
+                            .on_click(cx.listener(Self::on_history_toggle_click)),
+                    )
+                    .when(self.history_open && !entries.is_empty(), |this| {
+                        this.child(
+                            Button::new("history-clear-button")
+                                .label("Clear")
+                                .small()
+                                .ghost()
+                                .on_click(cx.listener(Self::on_history_clear_click))
+                                .ml_auto(),
+                        )
+                    }),
+            )
+            .when(self.history_open, |this| {
+                this.children(entries.into_iter().enumerate().map(|(ix, entry)| {
+                    let time = chrono::DateTime::from_timestamp(entry.timestamp, 0)
+                        .map(|time| {
+                            time.with_timezone(&chrono::Local)
+                                .format("%Y-%m-%d %H:%M:%S")
+                                .to_string()
+                        })
+                        .unwrap_or_default();
+                    let preview: String = entry
+                        .output
+                        .lines()
+                        .find(|line| !line.trim().is_empty())
+                        .unwrap_or_default()
+                        .chars()
+                        .take(120)
+                        .collect();
+                    h_flex()
+                        .id(("history-entry", ix))
+                        .gap_4()
+                        .px_2()
+                        .py_1()
+                        .rounded(cx.theme().radius)
+                        .cursor_pointer()
+                        .hover(|this| this.bg(cx.theme().accent))
+                        .on_click(cx.listener(move |this, _: &ClickEvent, window, cx| {
+                            this.restore_history(ix, window, cx)
+                        }))
+                        .child(
+                            div()
+                                .flex_none()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(time),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .truncate()
+                                .font_family(editor_font(cx))
+                                .child(preview),
+                        )
+                }))
+            })
+    }
+
+    fn on_action_primary_action(
+        &mut self,
+        _: &PrimaryAction,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.dispatch_shortcut(self.primary_action, window, cx);
+        self.record_history(cx);
+    }
+
+    fn on_action_copy_output(
+        &mut self,
+        _: &CopyOutput,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.dispatch_shortcut(self.copy_output, window, cx);
+    }
+
+    fn on_action_paste_input(
+        &mut self,
+        _: &PasteInput,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.dispatch_shortcut(self.paste_input, window, cx);
+    }
 }
 
 impl Focusable for ToolContainer {
@@ -219,14 +892,28 @@ impl Focusable for ToolContainer {
 }
 
 impl Render for ToolContainer {
-    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let error = self.error(cx);
+
         v_flex()
             .id("tool-container")
             .size_full()
             .overflow_y_scroll()
+            .key_context(keymap::TOOL_CONTEXT)
             .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_action_primary_action))
+            .on_action(cx.listener(Self::on_action_copy_output))
+            .on_action(cx.listener(Self::on_action_paste_input))
             // .on_action(cx.listener(Self::on_action_panel_info))
             // .on_action(cx.listener(Self::on_action_toggle_search))
+            .when_some(error, |this, error| {
+                this.child(
+                    div()
+                        .px_4()
+                        .pt_4()
+                        .child(Alert::error("tool-error", error.to_string())),
+                )
+            })
             .when_some(self.tool.clone(), |this, tool| {
                 this.child(
                     v_flex()
@@ -234,8 +921,15 @@ impl Render for ToolContainer {
                         .w_full()
                         .flex_1()
                         .p_4()
+                        // Look for a new run once the click has been handled.
+                        .capture_any_mouse_up(cx.listener(|_, _, window, cx| {
+                            cx.defer_in(window, |this, _, cx| this.record_history(cx));
+                        }))
                         .child(tool),
                 )
             })
+            .when(self.output(cx).is_some(), |this| {
+                this.child(self.render_history(cx))
+            })
     }
 }
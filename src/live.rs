@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use gpui::{Context, Task, Window};
+
+/// How long the input must be left alone before a live tool updates.
+const LIVE_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Debounces live updates so that a tool converts once typing pauses rather
+/// than on every keystroke.
+#[derive(Default)]
+pub struct LiveUpdate {
+    _task: Option<Task<()>>,
+}
+
+impl LiveUpdate {
+    /// Runs `update` once the input has been left alone for a moment.
+    /// Scheduling again before then replaces the pending run.
+    pub fn schedule<T: 'static>(
+        &mut self,
+        update: fn(&mut T, &mut Window, &mut Context<T>),
+        window: &mut Window,
+        cx: &mut Context<T>,
+    ) {
+        self._task = Some(cx.spawn_in(window, async move |this, cx| {
+            cx.background_executor().timer(LIVE_DEBOUNCE).await;
+            this.update_in(cx, update).ok();
+        }));
+    }
+}
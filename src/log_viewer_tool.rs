@@ -0,0 +1,583 @@
+use std::{ops::Range, path::PathBuf, sync::LazyLock};
+
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Div, Entity, ExternalPaths, FocusHandle,
+    Focusable, InteractiveElement, ParentElement, PathPromptOptions, Render, Styled, Subscription,
+    Window, div, px, uniform_list,
+};
+
+use gpui_component::{
+    ActiveTheme, StyledExt,
+    button::{Button, ButtonVariants},
+    checkbox::Checkbox,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+static LEVEL_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(FATAL|CRITICAL|CRIT|ERROR|ERR|WARNING|WARN|INFO|NOTICE|DEBUG|TRACE)\b").unwrap()
+});
+
+// Structured loggers write the level in lowercase as a field.
+static LEVEL_FIELD_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    RegexBuilder::new(r#""?(?:level|lvl|severity)"?\s*[=:]\s*"?(\w+)"#)
+        .case_insensitive(true)
+        .build()
+        .unwrap()
+});
+
+static ISO_TIMESTAMP_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(\d{4})-(\d{2})-(\d{2})[T ](\d{2}):(\d{2}):(\d{2})(?:[.,](\d{1,9}))?(\s?(?:Z|[+-]\d{2}:?\d{2}))?",
+    )
+    .unwrap()
+});
+
+static CLF_TIMESTAMP_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\d{2}/[A-Z][a-z]{2}/\d{4}:\d{2}:\d{2}:\d{2} [+-]\d{4}").unwrap());
+
+static SYSLOG_TIMESTAMP_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Z][a-z]{2} [ \d]\d \d{2}:\d{2}:\d{2}").unwrap());
+
+static EPOCH_TIMESTAMP_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\[?(\d{13}|\d{10}(?:\.\d{1,6})?)\b").unwrap());
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    const ALL: [LogLevel; 4] = [
+        LogLevel::Error,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Debug,
+    ];
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.to_uppercase().as_str() {
+            "FATAL" | "CRITICAL" | "CRIT" | "ERROR" | "ERR" => Some(LogLevel::Error),
+            "WARNING" | "WARN" => Some(LogLevel::Warn),
+            "INFO" | "NOTICE" => Some(LogLevel::Info),
+            "DEBUG" | "TRACE" => Some(LogLevel::Debug),
+            _ => None,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "Error",
+            LogLevel::Warn => "Warn",
+            LogLevel::Info => "Info",
+            LogLevel::Debug => "Debug",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    shown_levels: Vec<LogLevel>,
+    filter: String,
+    use_regex: bool,
+    normalize_timestamps: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct LogViewerTool {
+    focus_handle: FocusHandle,
+    filter: Entity<InputState>,
+    path: Option<PathBuf>,
+    lines: Vec<String>,
+    levels: Vec<Option<LogLevel>>,
+    filtered: Vec<usize>,
+    shown_levels: Vec<LogLevel>,
+    use_regex: bool,
+    normalize_timestamps: bool,
+    filter_error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl LogViewerTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let filter = cx.new(|cx| InputState::new(window, cx).placeholder("Filter"));
+        let _subscriptions = vec![cx.subscribe_in(&filter, window, Self::on_filter_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            filter,
+            path: None,
+            lines: Vec::new(),
+            levels: Vec::new(),
+            filtered: Vec::new(),
+            shown_levels: LogLevel::ALL.to_vec(),
+            use_regex: false,
+            normalize_timestamps: false,
+            filter_error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_filter_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_filtered(cx);
+        }
+    }
+
+    fn set_text(&mut self, path: Option<PathBuf>, text: String, cx: &mut Context<Self>) {
+        self.path = path;
+        self.lines = text.lines().map(str::to_string).collect();
+        self.levels = detect_levels(&self.lines);
+        self.update_filtered(cx);
+    }
+
+    fn update_filtered(&mut self, cx: &mut Context<Self>) {
+        let query = self.filter.read(cx).value().to_string();
+        let matcher: Box<dyn Fn(&str) -> bool> = if query.is_empty() {
+            Box::new(|_| true)
+        } else if self.use_regex {
+            match Regex::new(&query) {
+                Ok(regex) => Box::new(move |line| regex.is_match(line)),
+                Err(err) => {
+                    self.filter_error =
+                        Some(ToolError::new(err.to_string()).context("Invalid regular expression"));
+                    cx.notify();
+                    return;
+                }
+            }
+        } else {
+            let query = query.to_lowercase();
+            Box::new(move |line| line.to_lowercase().contains(&query))
+        };
+        self.filter_error = None;
+
+        self.filtered = self
+            .lines
+            .iter()
+            .zip(self.levels.iter())
+            .enumerate()
+            .filter(|(_, (line, level))| {
+                level.is_none_or(|level| self.shown_levels.contains(&level)) && matcher(line)
+            })
+            .map(|(i, _)| i)
+            .collect();
+        cx.notify();
+    }
+
+    fn display_line(&self, i: usize) -> String {
+        if self.normalize_timestamps {
+            normalize_timestamp(&self.lines[i])
+        } else {
+            self.lines[i].clone()
+        }
+    }
+
+    fn on_open_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        cx.spawn_in(window, async move |this, cx| {
+            if let Ok(Ok(Some(paths))) = paths.await
+                && let Some(path) = paths.into_iter().next()
+            {
+                this.update_in(cx, |this, window, cx| this.load_file(path, window, cx))
+                    .ok();
+            }
+        })
+        .detach();
+    }
+
+    fn on_drop(&mut self, paths: &ExternalPaths, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(path) = paths.paths().first() {
+            self.load_file(path.clone(), window, cx);
+        }
+    }
+
+    fn load_file(&mut self, path: PathBuf, window: &mut Window, cx: &mut Context<Self>) {
+        cx.spawn_in(window, async move |this, cx| {
+            let read_path = path.clone();
+            let bytes = cx
+                .background_executor()
+                .spawn(async move { std::fs::read(read_path) })
+                .await;
+            if let Ok(bytes) = bytes {
+                let text = String::from_utf8_lossy(&bytes).to_string();
+                this.update(cx, |this, cx| this.set_text(Some(path), text, cx))
+                    .ok();
+            }
+        })
+        .detach();
+    }
+
+    fn on_level_click(&mut self, level: LogLevel, checked: bool, cx: &mut Context<Self>) {
+        self.shown_levels.retain(|shown| *shown != level);
+        if checked {
+            self.shown_levels.push(level);
+        }
+        self.update_filtered(cx);
+    }
+
+    fn on_regex_click(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.use_regex = *checked;
+        self.update_filtered(cx);
+    }
+
+    fn on_normalize_timestamps_click(
+        &mut self,
+        checked: &bool,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.normalize_timestamps = *checked;
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self
+            .filtered
+            .iter()
+            .map(|i| self.display_line(*i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        cx.write_to_clipboard(ClipboardItem::new_string(value));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.set_text(None, value, cx);
+        }
+    }
+
+    fn render_rows(
+        &mut self,
+        range: Range<usize>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Vec<Div> {
+        let theme = cx.theme();
+        range
+            .map(|i| {
+                let line = self.filtered[i];
+                let color = match self.levels[line] {
+                    Some(LogLevel::Error) => theme.red,
+                    Some(LogLevel::Warn) => theme.yellow,
+                    Some(LogLevel::Debug) => theme.muted_foreground,
+                    Some(LogLevel::Info) | None => theme.foreground,
+                };
+                h_flex()
+                    .h(px(22.))
+                    .gap_4()
+                    .font_family(editor_font(cx))
+                    .text_size(px(13.))
+                    .whitespace_nowrap()
+                    .child(
+                        div()
+                            .w(px(64.))
+                            .flex_none()
+                            .text_right()
+                            .text_color(theme.muted_foreground)
+                            .child((line + 1).to_string()),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .overflow_hidden()
+                            .text_color(color)
+                            .child(self.display_line(line)),
+                    )
+            })
+            .collect()
+    }
+}
+
+/// Assigns a level to each line. Lines without one, such as stack trace
+/// frames, take the level of the line before them so that filters keep
+/// multi-line entries together.
+fn detect_levels(lines: &[String]) -> Vec<Option<LogLevel>> {
+    let mut current = None;
+    lines
+        .iter()
+        .map(|line| {
+            let level = LEVEL_PATTERN
+                .captures(line)
+                .or_else(|| LEVEL_FIELD_PATTERN.captures(line))
+                .and_then(|captures| LogLevel::parse(&captures[1]));
+            if level.is_some() {
+                current = level;
+            }
+            current
+        })
+        .collect()
+}
+
+/// Rewrites the first recognized timestamp in a line as
+/// `YYYY-MM-DD HH:MM:SS.mmm`, converted to UTC when its offset is known.
+fn normalize_timestamp(line: &str) -> String {
+    let replace = |range: Range<usize>, value: String| {
+        format!("{}{}{}", &line[..range.start], value, &line[range.end..])
+    };
+
+    if let Some(captures) = ISO_TIMESTAMP_PATTERN.captures(line) {
+        let part = |i: usize| captures[i].parse::<u32>().unwrap_or(0);
+        let nanos = captures
+            .get(7)
+            .map(|fraction| {
+                format!("{:0<9}", fraction.as_str())
+                    .parse::<u32>()
+                    .unwrap_or(0)
+            })
+            .unwrap_or(0);
+        let datetime = NaiveDate::from_ymd_opt(captures[1].parse().unwrap_or(0), part(2), part(3))
+            .and_then(|date| date.and_hms_nano_opt(part(4), part(5), part(6), nanos));
+        if let Some(datetime) = datetime {
+            let offset = captures
+                .get(8)
+                .and_then(|offset| parse_offset(offset.as_str()));
+            return replace(
+                captures.get(0).unwrap().range(),
+                format_timestamp(datetime, offset),
+            );
+        }
+    }
+
+    if let Some(found) = CLF_TIMESTAMP_PATTERN.find(line)
+        && let Ok(datetime) = DateTime::parse_from_str(found.as_str(), "%d/%b/%Y:%H:%M:%S %z")
+    {
+        return replace(
+            found.range(),
+            format_timestamp(datetime.naive_local(), Some(*datetime.offset())),
+        );
+    }
+
+    // Syslog leaves out the year, so the current one is assumed.
+    if let Some(found) = SYSLOG_TIMESTAMP_PATTERN.find(line) {
+        let value = format!("{} {}", Local::now().year(), found.as_str());
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(&value, "%Y %b %e %H:%M:%S") {
+            return replace(found.range(), format_timestamp(datetime, None));
+        }
+    }
+
+    if let Some(captures) = EPOCH_TIMESTAMP_PATTERN.captures(line) {
+        let found = captures.get(1).unwrap();
+        let value = found.as_str();
+        let datetime = if value.len() == 13 && !value.contains('.') {
+            value
+                .parse::<i64>()
+                .ok()
+                .and_then(|millis| Utc.timestamp_millis_opt(millis).single())
+        } else {
+            value
+                .parse::<f64>()
+                .ok()
+                .and_then(|seconds| Utc.timestamp_micros((seconds * 1e6) as i64).single())
+        };
+        if let Some(datetime) = datetime {
+            return replace(
+                found.range(),
+                format_timestamp(datetime.naive_utc(), FixedOffset::east_opt(0)),
+            );
+        }
+    }
+
+    line.to_string()
+}
+
+fn parse_offset(value: &str) -> Option<FixedOffset> {
+    let value = value.trim();
+    if value == "Z" {
+        return FixedOffset::east_opt(0);
+    }
+    let sign = if value.starts_with('-') { -1 } else { 1 };
+    let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+    let hours: i32 = digits.get(..2)?.parse().ok()?;
+    let minutes: i32 = digits.get(2..4)?.parse().ok()?;
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+fn format_timestamp(datetime: NaiveDateTime, offset: Option<FixedOffset>) -> String {
+    match offset.and_then(|offset| offset.from_local_datetime(&datetime).single()) {
+        Some(datetime) => datetime
+            .with_timezone(&Utc)
+            .format("%Y-%m-%d %H:%M:%S%.3fZ")
+            .to_string(),
+        None => datetime.format("%Y-%m-%d %H:%M:%S%.3f").to_string(),
+    }
+}
+
+impl Tool for LogViewerTool {
+    fn title() -> &'static str {
+        "Log Viewer"
+    }
+
+    fn short_title() -> &'static str {
+        "Log Viewer"
+    }
+
+    fn description() -> &'static str {
+        "Colours log lines by level and filters them by level, text, or regular expression."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.filter_error.clone()
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            shown_levels: self.shown_levels.clone(),
+            filter: self.filter.read(cx).value().to_string(),
+            use_regex: self.use_regex,
+            normalize_timestamps: self.normalize_timestamps,
+            input: include_input.then(|| self.lines.join("\n")),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.shown_levels = preset.shown_levels;
+        self.use_regex = preset.use_regex;
+        self.normalize_timestamps = preset.normalize_timestamps;
+        self.filter.update(cx, |state, cx| {
+            state.set_value(preset.filter, window, cx);
+        });
+        match preset.input {
+            Some(input) => self.set_text(None, input, cx),
+            None => self.update_filtered(cx),
+        }
+    }
+}
+
+impl Focusable for LogViewerTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for LogViewerTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let status = match &self.path {
+            Some(path) => format!(
+                "{} of {} lines in {}",
+                self.filtered.len(),
+                self.lines.len(),
+                path.display()
+            ),
+            None => format!("{} of {} lines", self.filtered.len(), self.lines.len()),
+        };
+
+        div()
+            .on_drop(cx.listener(Self::on_drop))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("open-button")
+                            .label("Open File…")
+                            .primary()
+                            .on_click(cx.listener(Self::on_open_click)),
+                    )
+                    .children(LogLevel::ALL.map(|level| {
+                        Checkbox::new(level.label())
+                            .label(level.label())
+                            .checked(self.shown_levels.contains(&level))
+                            .on_click(cx.listener(move |this, checked: &bool, _, cx| {
+                                this.on_level_click(level, *checked, cx)
+                            }))
+                    }))
+                    .child(
+                        Checkbox::new("normalize-timestamps-checkbox")
+                            .label("Normalize Timestamps")
+                            .checked(self.normalize_timestamps)
+                            .on_click(cx.listener(Self::on_normalize_timestamps_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.filter)
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        Checkbox::new("regex-checkbox")
+                            .label("Regex")
+                            .checked(self.use_regex)
+                            .on_click(cx.listener(Self::on_regex_click)),
+                    ),
+            )
+            .child(h_flex().gap_2().child(Label::new(status)))
+            .child(
+                uniform_list(
+                    "log-lines",
+                    self.filtered.len(),
+                    cx.processor(|this, range: Range<usize>, window, cx| {
+                        this.render_rows(range, window, cx)
+                    }),
+                )
+                .size_full(),
+            )
+    }
+}
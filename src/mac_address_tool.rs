@@ -0,0 +1,273 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, StatefulInteractiveElement, Styled,
+    Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::mac::{self, Format},
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = network_tools, no_json)]
+pub struct SetUppercase(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    uppercase: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct MACAddressTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    uppercase: bool,
+    fields: Vec<(&'static str, Entity<InputState>)>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl MACAddressTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| InputState::new(window, cx).placeholder("MAC Address"));
+        let _subscriptions = vec![cx.subscribe_in(&input, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            uppercase: false,
+            fields: Vec::new(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_fields(window, cx);
+        }
+    }
+
+    fn update_fields(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        self.error = None;
+        if value.trim().is_empty() {
+            self.fields.clear();
+            cx.notify();
+            return;
+        }
+        match mac::parse(&value) {
+            Ok(bytes) => {
+                self.fields = mac::inspect(&bytes, self.uppercase)
+                    .into_iter()
+                    .map(|(name, value)| {
+                        (
+                            name,
+                            cx.new(|cx| InputState::new(window, cx).default_value(value)),
+                        )
+                    })
+                    .collect();
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_inspect_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_fields(window, cx);
+    }
+
+    fn on_generate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let bytes = mac::random(&mut rand::rng());
+        let value = mac::format(&bytes, Format::Colon, self.uppercase);
+        self.input.update(cx, |state, cx| {
+            state.set_value(value, window, cx);
+        });
+        self.update_fields(window, cx);
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.input.update(cx, |state, cx| {
+                state.set_value(value.trim().to_string(), window, cx);
+            });
+            self.update_fields(window, cx);
+        }
+    }
+
+    fn on_action_set_uppercase(
+        &mut self,
+        action: &SetUppercase,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.uppercase = action.0;
+        self.update_fields(window, cx);
+    }
+}
+
+impl Tool for MACAddressTool {
+    fn title() -> &'static str {
+        "MAC Address Inspector"
+    }
+
+    fn short_title() -> &'static str {
+        "MAC"
+    }
+
+    fn description() -> &'static str {
+        "Normalizes MAC addresses across formats, reads their administration and multicast bits, and generates random ones."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_inspect_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            uppercase: self.uppercase,
+            input: include_input.then(|| self.input.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        if let Some(input) = preset.input {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.on_action_set_uppercase(&SetUppercase(preset.uppercase), window, cx);
+    }
+}
+
+impl Focusable for MACAddressTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for MACAddressTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.input.read(cx).value();
+        let uppercase = self.uppercase;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_uppercase))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("inspect-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("inspect-button")
+                                    .label("Inspect")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_inspect_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Letter Case")
+                                    .menu_with_check(
+                                        "Lowercase",
+                                        !uppercase,
+                                        Box::new(SetUppercase(false)),
+                                    )
+                                    .menu_with_check(
+                                        "Uppercase",
+                                        uppercase,
+                                        Box::new(SetUppercase(true)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("generate-button")
+                            .label("Generate Random")
+                            .on_click(cx.listener(Self::on_generate_click)),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.input)
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                div()
+                    .id("fields")
+                    .v_flex()
+                    .flex_1()
+                    .gap_2()
+                    .mt_4()
+                    .overflow_y_scroll()
+                    .children(self.fields.iter().enumerate().map(|(i, (name, state))| {
+                        let value = state.read(cx).value().clone();
+                        h_flex().gap_2().child(Label::new(*name).w(px(160.))).child(
+                            TextInput::new(state)
+                                .text_size(px(15.))
+                                .focus_bordered(false)
+                                .suffix(
+                                    Clipboard::new(SharedString::from(format!(
+                                        "field-clipboard-{}",
+                                        i
+                                    )))
+                                    .value(value),
+                                ),
+                        )
+                    })),
+            )
+    }
+}
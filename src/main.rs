@@ -1,98 +1,104 @@
+use std::process::ExitCode;
+
 use gpui::{
-    App, Application, ClickEvent, Context, Entity, Subscription, Window, actions, div, prelude::*,
-    px, relative,
+    Action, App, Application, ClickEvent, Context, Corner, Entity, FocusHandle, Subscription,
+    Window, actions, div, prelude::*, px, relative,
 };
 use gpui_component::{
-    ActiveTheme as _, Theme, h_flex,
+    ActiveTheme as _, ContextModal, IconName, Sizable as _,
+    button::{Button, ButtonVariants},
+    checkbox::Checkbox,
+    h_flex,
     input::{InputEvent, InputState, TextInput},
+    popup_menu::{PopupMenu, PopupMenuExt},
     resizable::{ResizableState, h_resizable, resizable_panel},
     sidebar::{Sidebar, SidebarGroup, SidebarMenu, SidebarMenuItem},
+    switch::Switch,
     v_flex,
 };
+use serde::Deserialize;
 
 use utools::*;
 
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = gallery, no_json)]
+struct LoadPreset(String);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = gallery, no_json)]
+struct DeletePreset(String);
+
+actions!(gallery, [SavePreset]);
+
 struct Gallery {
+    focus_handle: FocusHandle,
     tools: Vec<(&'static str, Vec<Entity<ToolContainer>>)>,
     active_group_index: Option<usize>,
     active_index: Option<usize>,
     sidebar_collapsed: bool,
     search_input: Entity<InputState>,
     sidebar_state: Entity<ResizableState>,
+    preset_name: Entity<InputState>,
+    preset_include_input: bool,
+    /// The tool suggested for what is on the clipboard, until dismissed.
+    suggestion: Option<Suggestion>,
+    /// The clipboard text last inspected, so that a dismissed suggestion is
+    /// not made again for the same text.
+    inspected_clipboard: Option<String>,
     _subscriptions: Vec<Subscription>,
 }
 
 impl Gallery {
     pub fn new(init_tool: Option<&str>, window: &mut Window, cx: &mut Context<Self>) -> Self {
         let search_input = cx.new(|cx| InputState::new(window, cx).placeholder("Search"));
-        let _subscriptions = vec![cx.subscribe(&search_input, |this, _, e, cx| match e {
-            InputEvent::Change => {
-                this.active_group_index = Some(0);
-                this.active_index = Some(0);
-                cx.notify()
-            }
-            _ => {}
-        })];
-
-        let tools = vec![
-            (
-                "Base64",
-                vec![
-                    ToolContainer::panel::<Base64EncoderTool>(window, cx),
-                    ToolContainer::panel::<Base64DecoderTool>(window, cx),
-                ],
-            ),
-            (
-                "Data URL",
-                vec![ToolContainer::panel::<DataURLGeneratorTool>(window, cx)],
-            ),
-            (
-                "Date/Time",
-                vec![ToolContainer::panel::<UnixTimestampConverterTool>(
-                    window, cx,
-                )],
-            ),
-            (
-                "HTML",
-                vec![
-                    ToolContainer::panel::<HTMLEncoderTool>(window, cx),
-                    ToolContainer::panel::<HTMLDecoderTool>(window, cx),
-                ],
-            ),
-            (
-                "JSON",
-                vec![
-                    ToolContainer::panel::<JSONEncoderTool>(window, cx),
-                    ToolContainer::panel::<JSONDecoderTool>(window, cx),
-                    ToolContainer::panel::<JSONFormatterTool>(window, cx),
-                    ToolContainer::panel::<JSONViewerTool>(window, cx),
-                ],
-            ),
-            (
-                "URL",
-                vec![
-                    ToolContainer::panel::<URLEncoderTool>(window, cx),
-                    ToolContainer::panel::<URLDecoderTool>(window, cx),
-                ],
-            ),
-            (
-                "Text",
-                vec![
-                    ToolContainer::panel::<TextCharacterCountTool>(window, cx),
-                    ToolContainer::panel::<TextDifferenceTool>(window, cx),
-                ],
-            ),
+        let preset_name = cx.new(|cx| InputState::new(window, cx).placeholder("Preset Name"));
+        let _subscriptions = vec![
+            cx.subscribe(&search_input, |this, _, e, cx| {
+                if let InputEvent::Change = e {
+                    this.active_group_index = Some(0);
+                    this.active_index = Some(0);
+                    cx.notify()
+                }
+            }),
+            cx.on_app_quit(|this, cx| {
+                this.save_state(cx);
+                async {}
+            }),
+            cx.on_release(|this, cx| this.save_state(cx)),
+            cx.observe_window_activation(window, Self::on_window_activation),
         ];
+
+        let tools = tool_groups()
+            .into_iter()
+            .map(|(group, entries)| {
+                let panels = entries
+                    .iter()
+                    .map(|entry| entry.panel(window, cx))
+                    .collect();
+                (group, panels)
+            })
+            .collect();
         let mut this = Self {
+            focus_handle: cx.focus_handle(),
             search_input,
             tools,
             active_group_index: Some(0),
             active_index: Some(0),
             sidebar_collapsed: false,
             sidebar_state: ResizableState::new(cx),
+            preset_name,
+            preset_include_input: false,
+            suggestion: None,
+            inspected_clipboard: None,
             _subscriptions,
         };
 
+        // A tool named on the command line takes precedence over the startup
+        // tool chosen in the settings, which takes precedence over the tool
+        // that was open when the app last quit.
+        let startup_tool = cx.global::<Settings>().startup_tool.clone();
+        let init_tool = init_tool.or(startup_tool.as_deref());
+        this.restore_state(init_tool.is_none(), window, cx);
         if let Some(init_tool) = init_tool {
             this.set_active_tool(init_tool, window, cx);
         }
@@ -100,29 +106,123 @@ impl Gallery {
         this
     }
 
+    /// Restores each tool from the state saved when the app last quit, and
+    /// reopens the tool that was active then if `restore_active` is set.
+    fn restore_state(&mut self, restore_active: bool, window: &mut Window, cx: &mut App) {
+        let saved_state = SavedState::load();
+        for tool in self.tools.iter().flat_map(|(_, tools)| tools) {
+            let Some(klass) = tool.read(cx).klass() else {
+                continue;
+            };
+            if let Some(state) = saved_state.tools.get(klass.as_ref()) {
+                tool.update(cx, |tool, cx| tool.restore_state(state, window, cx));
+            }
+            if saved_state.live_tools.contains(klass.as_ref()) {
+                tool.update(cx, |tool, cx| tool.set_live(true, window, cx));
+            }
+        }
+        if restore_active && let Some(klass) = &saved_state.active_tool {
+            self.select_tool(klass, cx);
+        }
+    }
+
+    fn save_state(&self, cx: &App) {
+        let mut saved_state = SavedState {
+            active_tool: self
+                .active_tool(cx)
+                .and_then(|tool| tool.read(cx).klass())
+                .map(|klass| klass.to_string()),
+            ..Default::default()
+        };
+        for tool in self.tools.iter().flat_map(|(_, tools)| tools) {
+            let tool = tool.read(cx);
+            let Some(klass) = tool.klass() else {
+                continue;
+            };
+            if let Some(state) = tool.state(cx) {
+                saved_state.tools.insert(klass.to_string(), state);
+            }
+            if tool.live() {
+                saved_state.live_tools.insert(klass.to_string());
+            }
+        }
+        if let Err(err) = saved_state.save() {
+            eprintln!("Could not save state: {}", err);
+        }
+        if let Some(history) = cx.try_global::<History>()
+            && let Err(err) = history.save()
+        {
+            eprintln!("Could not save history: {}", err);
+        }
+    }
+
+    /// Opens the tool named on the command line, or searches for the name if
+    /// no tool goes by it.
     fn set_active_tool(&mut self, name: &str, window: &mut Window, cx: &mut App) {
+        if let Some(entry) = find_tool(name)
+            && self.select_tool(entry.klass, cx)
+        {
+            return;
+        }
+
         let name = name.to_string();
         self.search_input.update(cx, |this, cx| {
             this.set_value(&name, window, cx);
         })
     }
 
+    /// Selects the tool of class `klass` where it first appears in the
+    /// sidebar, returning false if the search hides it.
+    fn select_tool(&mut self, klass: &str, cx: &App) -> bool {
+        let position =
+            self.filtered_tools(cx)
+                .iter()
+                .enumerate()
+                .find_map(|(group_ix, (_, tools))| {
+                    tools
+                        .iter()
+                        .position(|tool| tool.read(cx).klass().is_some_and(|k| k == klass))
+                        .map(|ix| (group_ix, ix))
+                });
+        let Some((group_ix, ix)) = position else {
+            return false;
+        };
+        self.active_group_index = Some(group_ix);
+        self.active_index = Some(ix);
+        true
+    }
+
     fn view(init_tool: Option<&str>, window: &mut Window, cx: &mut App) -> Entity<Self> {
         cx.new(|cx| Self::new(init_tool, window, cx))
     }
-}
 
-impl Render for Gallery {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    /// The tools matching the search, grouped as in the sidebar, with the
+    /// favorites in a group of their own at the top.
+    fn filtered_tools(&self, cx: &App) -> Vec<(&'static str, Vec<Entity<ToolContainer>>)> {
         let query = self.search_input.read(cx).value().trim().to_lowercase();
-
-        let tools: Vec<_> = self
-            .tools
+        let favorites: Vec<_> = cx
+            .global::<Settings>()
+            .favorites
             .iter()
+            .filter_map(|klass| {
+                self.tools
+                    .iter()
+                    .flat_map(|(_, tools)| tools)
+                    .find(|tool| tool.read(cx).klass().is_some_and(|k| k == klass.as_str()))
+                    .cloned()
+            })
+            .collect();
+
+        std::iter::once(("Favorites", &favorites))
+            .chain(self.tools.iter().map(|(name, items)| (*name, items)))
             .filter_map(|(name, items)| {
                 let filtered_items: Vec<_> = items
                     .iter()
-                    .filter(|tool| tool.read(cx).name.to_lowercase().contains(&query))
+                    .filter(|tool| {
+                        let tool = tool.read(cx);
+                        tool.name.to_lowercase().contains(&query)
+                            || tool.keywords.iter().any(|keyword| keyword.contains(&query))
+                    })
                     .cloned()
                     .collect();
                 if !filtered_items.is_empty() {
@@ -131,19 +231,230 @@ impl Render for Gallery {
                     None
                 }
             })
-            .collect();
+            .collect()
+    }
+
+    fn active_tool(&self, cx: &App) -> Option<Entity<ToolContainer>> {
+        let tools = self.filtered_tools(cx);
+        let (_, group) = tools.get(self.active_group_index?)?;
+        group.get(self.active_index?).cloned()
+    }
 
-        let active_group = self.active_group_index.and_then(|index| tools.get(index));
-        let active_tool = self
-            .active_index
-            .and(active_group)
-            .and_then(|group| group.1.get(self.active_index.unwrap()));
+    /// Suggests a tool for what is on the clipboard when the window gains
+    /// focus, if smart paste is on.
+    fn on_window_activation(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !window.is_window_active() || !cx.global::<Settings>().smart_paste {
+            return;
+        }
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+        if self.inspected_clipboard.as_ref() == Some(&text) {
+            return;
+        }
+        self.suggestion = suggest_tool(&text);
+        self.inspected_clipboard = Some(text);
+        cx.notify();
+    }
+
+    /// Opens the suggested tool with the clipboard pasted into it.
+    fn on_suggestion_open_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(suggestion) = self.suggestion.take() else {
+            return;
+        };
+        let Some(tool) = self
+            .tools
+            .iter()
+            .flat_map(|(_, tools)| tools)
+            .find(|tool| {
+                tool.read(cx)
+                    .klass()
+                    .is_some_and(|klass| klass == suggestion.klass)
+            })
+            .cloned()
+        else {
+            return;
+        };
+
+        if !self.select_tool(suggestion.klass, cx) {
+            // The search hides the tool, so search for it instead.
+            let name = tool.read(cx).name.clone();
+            self.search_input.update(cx, |this, cx| {
+                this.set_value(name, window, cx);
+            });
+        }
+        tool.update(cx, |tool, cx| tool.paste_input(window, cx));
+        cx.notify();
+    }
+
+    fn on_suggestion_dismiss_click(
+        &mut self,
+        _: &ClickEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.suggestion = None;
+        cx.notify();
+    }
+
+    fn on_favorite_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(klass) = self.active_tool(cx).and_then(|tool| tool.read(cx).klass()) else {
+            return;
+        };
+        Settings::update(window, cx, |settings| {
+            if let Some(ix) = settings.favorites.iter().position(|k| *k == klass) {
+                settings.favorites.remove(ix);
+            } else {
+                settings.favorites.push(klass.to_string());
+            }
+        });
+        // The groups have changed, so find the tool again.
+        self.select_tool(&klass, cx);
+        cx.notify();
+    }
+
+    fn save_presets(window: &mut Window, cx: &mut App) {
+        if let Err(err) = cx.global::<Presets>().save() {
+            window.push_notification(format!("Could not save presets: {}", err), cx);
+        }
+    }
+
+    fn on_action_load_preset(
+        &mut self,
+        action: &LoadPreset,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(tool) = self.active_tool(cx) else {
+            return;
+        };
+        let Some(klass) = tool.read(cx).klass() else {
+            return;
+        };
+        if let Some(preset) = cx.global::<Presets>().get(&klass, &action.0).cloned() {
+            tool.update(cx, |tool, cx| tool.apply_preset(&preset, window, cx));
+        }
+    }
+
+    fn on_action_delete_preset(
+        &mut self,
+        action: &DeletePreset,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(klass) = self.active_tool(cx).and_then(|tool| tool.read(cx).klass()) else {
+            return;
+        };
+        cx.global_mut::<Presets>().remove(&klass, &action.0);
+        Self::save_presets(window, cx);
+        cx.notify();
+    }
+
+    fn on_action_save_preset(
+        &mut self,
+        _: &SavePreset,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let gallery = cx.entity();
+        let preset_name = self.preset_name.clone();
+        window.open_modal(cx, move |modal, _, cx| {
+            let include_input = gallery.read(cx).preset_include_input;
+            modal
+                .title("Save Preset")
+                .child(
+                    v_flex().gap_2().child(TextInput::new(&preset_name)).child(
+                        Checkbox::new("preset-include-input")
+                            .label("Include Input")
+                            .checked(include_input)
+                            .on_click({
+                                let gallery = gallery.clone();
+                                move |checked, _, cx| {
+                                    gallery.update(cx, |this, cx| {
+                                        this.preset_include_input = *checked;
+                                        cx.notify();
+                                    })
+                                }
+                            }),
+                    ),
+                )
+                .confirm()
+                .on_ok({
+                    let gallery = gallery.clone();
+                    move |_, window, cx| gallery.update(cx, |this, cx| this.save_preset(window, cx))
+                })
+        });
+    }
+
+    fn on_live_click(&mut self, checked: &bool, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(tool) = self.active_tool(cx) {
+            tool.update(cx, |tool, cx| tool.set_live(*checked, window, cx));
+        }
+        cx.notify();
+    }
+
+    fn save_preset(&mut self, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let name = self.preset_name.read(cx).value().trim().to_string();
+        if name.is_empty() {
+            return false;
+        }
+        let Some(tool) = self.active_tool(cx) else {
+            return true;
+        };
+        let tool = tool.read(cx);
+        let (Some(klass), Some(preset)) =
+            (tool.klass(), tool.preset(self.preset_include_input, cx))
+        else {
+            return true;
+        };
+        cx.global_mut::<Presets>().insert(&klass, &name, preset);
+        Self::save_presets(window, cx);
+        self.preset_name.update(cx, |state, cx| {
+            state.set_value("", window, cx);
+        });
+        cx.notify();
+        true
+    }
+}
+
+impl Render for Gallery {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let tools = self.filtered_tools(cx);
+        let active_tool = self.active_tool(cx);
         let (tool_name, description) =
             if let Some(tool) = active_tool.as_ref().map(|tool| tool.read(cx)) {
                 (tool.name.clone(), tool.description.clone())
             } else {
                 ("".into(), "".into())
             };
+        let preset_names = active_tool.as_ref().and_then(|tool| {
+            let tool = tool.read(cx);
+            tool.preset(false, cx)?;
+            Some(cx.global::<Presets>().names(&tool.klass()?))
+        });
+        let favorite = active_tool.as_ref().and_then(|tool| {
+            let klass = tool.read(cx).klass()?;
+            Some(
+                cx.global::<Settings>()
+                    .favorites
+                    .iter()
+                    .any(|k| *k == klass),
+            )
+        });
+        let live = active_tool.as_ref().and_then(|tool| {
+            let tool = tool.read(cx);
+            tool.supports_live().then(|| tool.live())
+        });
+        let suggestion = self.suggestion.and_then(|suggestion| {
+            let tool = find_tool(suggestion.klass)?;
+            Some((suggestion.kind, tool.title))
+        });
+        let focus_handle = self.focus_handle.clone();
 
         h_resizable("gallery-container", self.sidebar_state.clone())
             .child(
@@ -172,7 +483,7 @@ impl Render for Gallery {
                             )
                             .children(tools.clone().into_iter().enumerate().map(
                                 |(group_ix, (group_name, sub_tools))| {
-                                    SidebarGroup::new(*group_name).child(
+                                    SidebarGroup::new(group_name).child(
                                         SidebarMenu::new().children(
                                             sub_tools.iter().enumerate().map(|(ix, tool)| {
                                                 SidebarMenuItem::new(
@@ -198,6 +509,10 @@ impl Render for Gallery {
             )
             .child(
                 v_flex()
+                    .track_focus(&self.focus_handle)
+                    .on_action(cx.listener(Self::on_action_load_preset))
+                    .on_action(cx.listener(Self::on_action_delete_preset))
+                    .on_action(cx.listener(Self::on_action_save_preset))
                     .flex_1()
                     .h_full()
                     .overflow_x_hidden()
@@ -218,8 +533,88 @@ impl Render for Gallery {
                                             .text_color(cx.theme().muted_foreground)
                                             .child(description),
                                     ),
+                            )
+                            .child(
+                                h_flex()
+                                    .gap_4()
+                                    .when_some(favorite, |this, favorite| {
+                                        this.child(
+                                            Button::new("favorite-button")
+                                                .icon(if favorite {
+                                                    IconName::Star
+                                                } else {
+                                                    IconName::StarOff
+                                                })
+                                                .ghost()
+                                                .tooltip(if favorite {
+                                                    "Remove from Favorites"
+                                                } else {
+                                                    "Add to Favorites"
+                                                })
+                                                .on_click(cx.listener(Self::on_favorite_click)),
+                                        )
+                                    })
+                                    .when_some(live, |this, live| {
+                                        this.child(
+                                            Switch::new("live-switch")
+                                                .label("Live")
+                                                .checked(live)
+                                                .tooltip("Update the output as you type")
+                                                .on_click(cx.listener(Self::on_live_click)),
+                                        )
+                                    })
+                                    .when_some(preset_names, |this, preset_names| {
+                                        this.child(
+                                            Button::new("presets-button")
+                                                .label("Presets")
+                                                .ghost()
+                                                .popup_menu_with_anchor(
+                                                    Corner::TopRight,
+                                                    move |this, window, cx| {
+                                                        presets_menu(
+                                                            this,
+                                                            &preset_names,
+                                                            &focus_handle,
+                                                            window,
+                                                            cx,
+                                                        )
+                                                    },
+                                                ),
+                                        )
+                                    }),
                             ),
                     )
+                    .when_some(suggestion, |this, (kind, title)| {
+                        this.child(
+                            h_flex()
+                                .id("suggestion")
+                                .px_4()
+                                .py_2()
+                                .gap_2()
+                                .border_b_1()
+                                .border_color(cx.theme().border)
+                                .bg(cx.theme().accent)
+                                .child(
+                                    div()
+                                        .flex_1()
+                                        .child(format!("The clipboard looks like {}.", kind)),
+                                )
+                                .child(
+                                    Button::new("suggestion-open-button")
+                                        .label(format!("Open in {}", title))
+                                        .small()
+                                        .primary()
+                                        .on_click(cx.listener(Self::on_suggestion_open_click)),
+                                )
+                                .child(
+                                    Button::new("suggestion-dismiss-button")
+                                        .icon(IconName::Close)
+                                        .small()
+                                        .ghost()
+                                        .on_click(cx.listener(Self::on_suggestion_dismiss_click)),
+                                ),
+                        )
+                    })
                     .child(
                         div()
                             .id("tool")
@@ -234,24 +629,71 @@ impl Render for Gallery {
     }
 }
 
-fn main() {
+fn presets_menu(
+    menu: PopupMenu,
+    names: &[String],
+    focus_handle: &FocusHandle,
+    window: &mut Window,
+    cx: &mut Context<PopupMenu>,
+) -> PopupMenu {
+    let menu = menu
+        .action_context(focus_handle.clone())
+        .label(if names.is_empty() {
+            "No Saved Presets"
+        } else {
+            "Presets"
+        });
+    let menu = names.iter().fold(menu, |menu, name| {
+        menu.menu(name.clone(), Box::new(LoadPreset(name.clone())))
+    });
+
+    let names = names.to_vec();
+    let focus_handle = focus_handle.clone();
+    menu.separator()
+        .menu("Save Preset…", Box::new(SavePreset))
+        .submenu_with_disabled(
+            "Delete Preset",
+            names.is_empty(),
+            window,
+            cx,
+            move |menu, _, _| {
+                names
+                    .iter()
+                    .fold(menu.action_context(focus_handle.clone()), |menu, name| {
+                        menu.menu(name.clone(), Box::new(DeletePreset(name.clone())))
+                    })
+            },
+        )
+}
+
+fn main() -> ExitCode {
+    // Run `utools <command> [options] [file]` without opening a window.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(code) = run_cli(&args) {
+        return code;
+    }
+
     let app = Application::new().with_assets(Assets);
 
     // Parse `cargo run -- <tool_name>`
-    let name = std::env::args().nth(1);
+    let name = args.into_iter().next();
 
     app.run(|cx: &mut App| {
         gpui_component::init(cx);
+        bind_tool_keys(cx);
+        cx.set_global(Presets::load());
+        cx.set_global(History::load());
         cx.activate(true);
-        Theme::sync_system_appearance(None, cx);
-        Theme::global_mut(cx).font_family = "Space Grotesk".into();
-        Theme::global_mut(cx).font_size = px(17.);
+        let settings = Settings::load();
+        settings.apply(None, cx);
+        cx.set_global(settings);
         utools::create_new_window(
             "μTools",
             move |window, cx| Gallery::view(name.as_deref(), window, cx),
             cx,
         );
     });
+    ExitCode::SUCCESS
 }
 
 // Associate actions using the `actions!` macro (or `Action` derive macro)
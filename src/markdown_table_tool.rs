@@ -0,0 +1,296 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::markdown::{self, TableAlignment, TableSource},
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = markdown_tools, no_json)]
+pub struct SetTableSource(TableSource);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = markdown_tools, no_json)]
+pub struct SetTableAlignment(TableAlignment);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    source: TableSource,
+    alignment: TableAlignment,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct MarkdownTableTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    formatted: Entity<InputState>,
+    source: TableSource,
+    alignment: TableAlignment,
+    error: Option<ToolError>,
+}
+
+impl MarkdownTableTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: true,
+                })
+                .default_value("")
+                .placeholder("CSV, Spreadsheet Cells, or Markdown Table")
+        });
+        let formatted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Markdown.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Markdown Table")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            formatted,
+            source: TableSource::default(),
+            alignment: TableAlignment::default(),
+            error: None,
+        }
+    }
+
+    fn on_format_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        match markdown::format_table(&value, self.source, self.alignment) {
+            Ok(formatted) => {
+                self.error = None;
+                self.formatted.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(formatted), window, cx);
+                });
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_formatted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.formatted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_table_source(
+        &mut self,
+        action: &SetTableSource,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.source = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_table_alignment(
+        &mut self,
+        action: &SetTableAlignment,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.alignment = action.0;
+        cx.notify();
+    }
+}
+
+impl Tool for MarkdownTableTool {
+    fn title() -> &'static str {
+        "Markdown Table Formatter"
+    }
+
+    fn short_title() -> &'static str {
+        "Markdown Table"
+    }
+
+    fn description() -> &'static str {
+        "Turns CSV or spreadsheet cells into an aligned Markdown table, or tidies an existing one."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_format_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_formatted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.formatted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            source: self.source,
+            alignment: self.alignment,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_table_source(&SetTableSource(preset.source), window, cx);
+        self.on_action_set_table_alignment(&SetTableAlignment(preset.alignment), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for MarkdownTableTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for MarkdownTableTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let source = self.source;
+        let alignment = self.alignment;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_table_source))
+            .on_action(cx.listener(Self::on_action_set_table_alignment))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("format-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("format-button")
+                                    .label("Format")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_format_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                let this = TableSource::ALL.into_iter().fold(
+                                    this.label("Input"),
+                                    |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            source == option,
+                                            Box::new(SetTableSource(option)),
+                                        )
+                                    },
+                                );
+                                TableAlignment::ALL.into_iter().fold(
+                                    this.separator().label("Column Alignment"),
+                                    |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            alignment == option,
+                                            Box::new(SetTableAlignment(option)),
+                                        )
+                                    },
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-formatted-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_formatted_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.formatted)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
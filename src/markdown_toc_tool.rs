@@ -0,0 +1,357 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use markdown::{ParseOptions, mdast::Node};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum ListStyle {
+    Unordered,
+    Ordered,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = markdown_tools, no_json)]
+pub struct SetMaxDepth(u8);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = markdown_tools, no_json)]
+pub struct SetListStyle(ListStyle);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    max_depth: u8,
+    list_style: ListStyle,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct MarkdownTOCTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    generated: Entity<InputState>,
+    max_depth: u8,
+    list_style: ListStyle,
+    error: Option<ToolError>,
+}
+
+impl MarkdownTOCTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Markdown.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Markdown Source")
+        });
+        let generated = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Markdown.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Table of Contents")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            generated,
+            max_depth: 3,
+            list_style: ListStyle::Unordered,
+            error: None,
+        }
+    }
+
+    fn on_generate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let generated_value = match markdown::to_mdast(&value, &ParseOptions::gfm()) {
+            Ok(root) => {
+                self.error = None;
+                generate_toc(&root, self.max_depth, self.list_style)
+            }
+            Err(err) => {
+                self.error = Some(ToolError::new(err.to_string()).context("Invalid Markdown"));
+                String::new()
+            }
+        };
+        self.generated.update(cx, |state, cx| {
+            state.set_value(SharedString::from(generated_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_generated_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.generated.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_max_depth(
+        &mut self,
+        action: &SetMaxDepth,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.max_depth = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_list_style(
+        &mut self,
+        action: &SetListStyle,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.list_style = action.0;
+        cx.notify();
+    }
+}
+
+fn generate_toc(root: &Node, max_depth: u8, list_style: ListStyle) -> String {
+    // Anchors are computed for every heading, including the ones deeper than
+    // the limit, so that duplicate suffixes match what GitHub generates.
+    let mut slugs: Vec<String> = Vec::new();
+    let mut headings: Vec<(u8, String, String)> = Vec::new();
+    for node in root.children().into_iter().flatten() {
+        if let Node::Heading(heading) = node {
+            let text = node.to_string();
+            let base = slugify(&text);
+            let mut slug = base.clone();
+            let mut suffix = 1;
+            while slugs.contains(&slug) {
+                slug = format!("{}-{}", base, suffix);
+                suffix += 1;
+            }
+            slugs.push(slug.clone());
+            if heading.depth <= max_depth {
+                headings.push((heading.depth, text, slug));
+            }
+        }
+    }
+
+    let Some(top) = headings.iter().map(|(depth, _, _)| *depth).min() else {
+        return String::new();
+    };
+    let mut counters = [0usize; 6];
+    headings
+        .iter()
+        .map(|(depth, text, slug)| {
+            let level = (depth - top) as usize;
+            counters[level] += 1;
+            counters[level + 1..].fill(0);
+            let (indent, marker) = match list_style {
+                ListStyle::Unordered => ("  ".repeat(level), "-".to_string()),
+                ListStyle::Ordered => ("   ".repeat(level), format!("{}.", counters[level])),
+            };
+            format!("{}{} [{}](#{})", indent, marker, text.trim(), slug)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn slugify(text: &str) -> String {
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+impl Tool for MarkdownTOCTool {
+    fn title() -> &'static str {
+        "Markdown Table of Contents"
+    }
+
+    fn short_title() -> &'static str {
+        "Table of Contents"
+    }
+
+    fn description() -> &'static str {
+        "Generates a nested table of contents with anchor links from Markdown headings."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_generate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_generated_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.generated.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            max_depth: self.max_depth,
+            list_style: self.list_style,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_max_depth(&SetMaxDepth(preset.max_depth), window, cx);
+        self.on_action_set_list_style(&SetListStyle(preset.list_style), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for MarkdownTOCTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for MarkdownTOCTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let max_depth = self.max_depth;
+        let list_style = self.list_style;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_max_depth))
+            .on_action(cx.listener(Self::on_action_set_list_style))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("generate-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("generate-button")
+                                    .label("Generate")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_generate_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                (1..=6)
+                                    .fold(this.label("Depth"), |this, depth| {
+                                        this.menu_with_check(
+                                            format!("H1–H{}", depth),
+                                            max_depth == depth,
+                                            Box::new(SetMaxDepth(depth)),
+                                        )
+                                    })
+                                    .label("List")
+                                    .menu_with_check(
+                                        "Unordered",
+                                        list_style == ListStyle::Unordered,
+                                        Box::new(SetListStyle(ListStyle::Unordered)),
+                                    )
+                                    .menu_with_check(
+                                        "Ordered",
+                                        list_style == ListStyle::Ordered,
+                                        Box::new(SetListStyle(ListStyle::Ordered)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-generated-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_generated_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.generated)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
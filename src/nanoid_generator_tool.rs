@@ -0,0 +1,396 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize)]
+enum AlphabetPreset {
+    URLSafe,
+    Alphanumeric,
+    Lowercase,
+    Hex,
+    Numbers,
+    NoLookalikes,
+}
+
+impl AlphabetPreset {
+    const ALL: [AlphabetPreset; 6] = [
+        AlphabetPreset::URLSafe,
+        AlphabetPreset::Alphanumeric,
+        AlphabetPreset::Lowercase,
+        AlphabetPreset::Hex,
+        AlphabetPreset::Numbers,
+        AlphabetPreset::NoLookalikes,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            AlphabetPreset::URLSafe => "URL-Safe (Nanoid)",
+            AlphabetPreset::Alphanumeric => "Alphanumeric",
+            AlphabetPreset::Lowercase => "Lowercase and Digits",
+            AlphabetPreset::Hex => "Hexadecimal",
+            AlphabetPreset::Numbers => "Digits",
+            AlphabetPreset::NoLookalikes => "No Look-Alikes",
+        }
+    }
+
+    fn alphabet(&self) -> &'static str {
+        match self {
+            AlphabetPreset::URLSafe => {
+                "_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"
+            }
+            AlphabetPreset::Alphanumeric => {
+                "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"
+            }
+            AlphabetPreset::Lowercase => "0123456789abcdefghijklmnopqrstuvwxyz",
+            AlphabetPreset::Hex => "0123456789abcdef",
+            AlphabetPreset::Numbers => "0123456789",
+            // Drops characters that are easy to misread, like 1/l/I and 0/O.
+            AlphabetPreset::NoLookalikes => "346789ABCDEFGHJKLMNPQRTUVWXYabcdefghijkmnpqrtwxyz",
+        }
+    }
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = generator_tools, no_json)]
+pub struct SetAlphabetPreset(AlphabetPreset);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    alphabet: String,
+    length: String,
+    count: String,
+}
+
+pub struct NanoidGeneratorTool {
+    focus_handle: FocusHandle,
+    alphabet: Entity<InputState>,
+    length: Entity<InputState>,
+    count: Entity<InputState>,
+    generated: Entity<InputState>,
+    error: Option<ToolError>,
+}
+
+impl NanoidGeneratorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let alphabet = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value(AlphabetPreset::URLSafe.alphabet())
+                .placeholder("Alphabet")
+        });
+        let length = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value("21")
+                .placeholder("Length")
+        });
+        let count = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value("10")
+                .placeholder("Count")
+        });
+        let generated = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Generated IDs")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            alphabet,
+            length,
+            count,
+            generated,
+            error: None,
+        }
+    }
+
+    fn settings(&self, cx: &App) -> Result<(Vec<char>, usize, usize), ToolError> {
+        let alphabet = unique_chars(&self.alphabet.read(cx).value());
+        if alphabet.len() < 2 {
+            return Err(ToolError::new(
+                "The alphabet needs at least two distinct characters",
+            ));
+        }
+        let length = match self.length.read(cx).value().trim().parse::<usize>() {
+            Ok(length) if length > 0 => length,
+            _ => return Err(ToolError::new("Length must be a positive number")),
+        };
+        let count = match self.count.read(cx).value().trim().parse::<usize>() {
+            Ok(count) if count > 0 => count,
+            _ => return Err(ToolError::new("Count must be a positive number")),
+        };
+        Ok((alphabet, length, count))
+    }
+
+    fn on_generate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let generated_value = match self.settings(cx) {
+            Ok((alphabet, length, count)) => {
+                self.error = None;
+                let mut rng = rand::rng();
+                (0..count)
+                    .map(|_| {
+                        (0..length)
+                            .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            Err(err) => {
+                self.error = Some(err);
+                String::new()
+            }
+        };
+        self.generated.update(cx, |state, cx| {
+            state.set_value(SharedString::from(generated_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.generated.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_alphabet_preset(
+        &mut self,
+        action: &SetAlphabetPreset,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.alphabet.update(cx, |state, cx| {
+            state.set_value(action.0.alphabet(), window, cx);
+        });
+        cx.notify();
+    }
+}
+
+fn unique_chars(value: &str) -> Vec<char> {
+    let mut chars: Vec<char> = Vec::new();
+    for c in value.chars() {
+        if !chars.contains(&c) {
+            chars.push(c);
+        }
+    }
+    chars
+}
+
+/// Describes the entropy of the chosen settings and, using the birthday
+/// bound, how many IDs it takes before a collision becomes likely.
+fn collision_estimate(alphabet_size: usize, length: usize, count: usize) -> String {
+    let bits = length as f64 * (alphabet_size as f64).log2();
+    // n ≈ sqrt(2N · ln(1 / (1 - p))) for p = 1%, with N = 2^bits.
+    let one_percent = (bits / 2.).exp2() * (2. * (1. / 0.99f64).ln()).sqrt();
+    let pairs = count as f64 * (count as f64 - 1.) / 2.;
+    let batch = -(-pairs * (-bits).exp2()).exp_m1();
+    format!(
+        "{:.0} bits of entropy · ~{} IDs for a 1% chance of a collision · {} chance of a collision among {}",
+        bits,
+        format_quantity(one_percent),
+        format_probability(batch),
+        count
+    )
+}
+
+fn format_quantity(value: f64) -> String {
+    if value < 1e6 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1e}", value)
+    }
+}
+
+fn format_probability(value: f64) -> String {
+    if value == 0. {
+        "no".to_string()
+    } else if value >= 0.001 {
+        format!("{:.1}%", value * 100.)
+    } else {
+        format!("{:.1e}", value)
+    }
+}
+
+impl Tool for NanoidGeneratorTool {
+    fn title() -> &'static str {
+        "Nanoid Generator"
+    }
+
+    fn short_title() -> &'static str {
+        "Nanoid"
+    }
+
+    fn description() -> &'static str {
+        "Generates nanoids and random IDs from a custom alphabet and length."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_generate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.generated.clone())
+    }
+
+    fn preset(&self, _: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            alphabet: self.alphabet.read(cx).value().to_string(),
+            length: self.length.read(cx).value().to_string(),
+            count: self.count.read(cx).value().to_string(),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.alphabet.update(cx, |state, cx| {
+            state.set_value(preset.alphabet, window, cx);
+        });
+        self.length.update(cx, |state, cx| {
+            state.set_value(preset.length, window, cx);
+        });
+        self.count.update(cx, |state, cx| {
+            state.set_value(preset.count, window, cx);
+        });
+        cx.notify();
+    }
+}
+
+impl Focusable for NanoidGeneratorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for NanoidGeneratorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let estimate = self
+            .settings(cx)
+            .ok()
+            .map(|(alphabet, length, count)| collision_estimate(alphabet.len(), length, count));
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_alphabet_preset))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("generate-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("generate-button")
+                                    .label("Generate")
+                                    .primary()
+                                    .on_click(cx.listener(Self::on_generate_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                AlphabetPreset::ALL.iter().fold(
+                                    this.label("Alphabet"),
+                                    |this, preset| {
+                                        this.menu(
+                                            preset.label(),
+                                            Box::new(SetAlphabetPreset(*preset)),
+                                        )
+                                    },
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(Label::new("Alphabet"))
+            .child(
+                TextInput::new(&self.alphabet)
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("Length"))
+                            .child(
+                                TextInput::new(&self.length)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("Count"))
+                            .child(
+                                TextInput::new(&self.count)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            ),
+                    ),
+            )
+            .when_some(estimate, |this, estimate| this.child(Label::new(estimate)))
+            .child(
+                TextInput::new(&self.generated)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,564 @@
+use gpui::{
+    Action, App, AppContext, Context, Entity, FocusHandle, Focusable, InteractiveElement,
+    ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    ActiveTheme, StyledExt,
+    button::{Button, DropdownButton},
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+const WIDTHS: [u32; 4] = [8, 16, 32, 64];
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = number_tools, no_json)]
+pub struct SetInputBase(u32);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = number_tools, no_json)]
+pub struct SetGrouping(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    input_base: u32,
+    custom_base: String,
+    grouping: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+fn base_name(base: u32) -> String {
+    match base {
+        2 => "Binary".to_string(),
+        8 => "Octal".to_string(),
+        10 => "Decimal".to_string(),
+        16 => "Hexadecimal".to_string(),
+        base => format!("Base {}", base),
+    }
+}
+
+/// Parses a number in `base`. A `0b`, `0o`, or `0x` prefix switches to that
+/// base, and underscores, commas, and spaces between digits are ignored.
+fn parse_number(value: &str, base: u32) -> Result<i128, ToolError> {
+    let value: String = value
+        .chars()
+        .filter(|c| !matches!(c, '_' | ',' | ' '))
+        .collect();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value.strip_prefix('+').unwrap_or(&value)),
+    };
+    let lower = value.to_lowercase();
+    let prefixed = match lower.get(..2) {
+        Some("0b") => Some(2),
+        Some("0o") => Some(8),
+        Some("0x") => Some(16),
+        _ => None,
+    };
+    // In bases high enough for the prefix letter to be a digit, as with
+    // "0b1" in hex, the input is read as digits instead.
+    let (base, digits) = match prefixed {
+        Some(prefixed)
+            if prefixed == base
+                || lower[1..2]
+                    .chars()
+                    .all(|c| c.to_digit(36).unwrap_or(0) >= base) =>
+        {
+            (prefixed, &lower[2..])
+        }
+        _ => (base, &lower[..]),
+    };
+    if digits.is_empty() {
+        return Err(ToolError::new("no digits"));
+    }
+
+    let mut magnitude: u128 = 0;
+    for c in digits.chars() {
+        let digit = c
+            .to_digit(36)
+            .filter(|digit| *digit < base)
+            .ok_or_else(|| {
+                ToolError::new(format!(
+                    "{:?} is not a {} digit",
+                    c,
+                    base_name(base).to_lowercase()
+                ))
+            })?;
+        magnitude = magnitude
+            .checked_mul(base as u128)
+            .and_then(|magnitude| magnitude.checked_add(digit as u128))
+            .ok_or_else(|| ToolError::new("number is too large"))?;
+    }
+    let number = i128::try_from(magnitude).map_err(|_| ToolError::new("number is too large"))?;
+    Ok(if negative { -number } else { number })
+}
+
+fn to_base(mut value: u128, base: u32) -> String {
+    if value == 0 {
+        return "0".to_string();
+    }
+    let mut digits = Vec::new();
+    while value > 0 {
+        digits.push(DIGITS[(value % base as u128) as usize]);
+        value /= base as u128;
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap_or_default()
+}
+
+/// Separates digits into groups counted from the right: thousands with
+/// commas in decimal, nibbles in binary and hex, and threes in octal.
+fn group_digits(digits: &str, base: u32) -> String {
+    let (size, separator) = match base {
+        10 => (3, ','),
+        8 => (3, ' '),
+        _ => (4, ' '),
+    };
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(size) {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// How a number is stored in a `width`-bit two's complement integer, or
+/// `None` if it does not fit as either a signed or an unsigned one.
+struct TwosComplement {
+    width: u32,
+    bits: u128,
+}
+
+impl TwosComplement {
+    fn new(value: i128, width: u32) -> Option<Self> {
+        let min = -(1i128 << (width - 1));
+        let max = (1i128 << width) - 1;
+        (min..=max).contains(&value).then(|| Self {
+            width,
+            bits: value as u128 & ((1u128 << width) - 1),
+        })
+    }
+
+    fn signed(&self) -> i128 {
+        if self.bits >> (self.width - 1) & 1 == 1 {
+            self.bits as i128 - (1i128 << self.width)
+        } else {
+            self.bits as i128
+        }
+    }
+
+    fn padded(&self, base: u32) -> String {
+        let width = match base {
+            2 => self.width as usize,
+            _ => self.width as usize / 4,
+        };
+        format!("{:0>width$}", to_base(self.bits, base), width = width)
+    }
+}
+
+pub struct NumberBaseConverterTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    custom_base: Entity<InputState>,
+    binary: Entity<InputState>,
+    octal: Entity<InputState>,
+    decimal: Entity<InputState>,
+    hexadecimal: Entity<InputState>,
+    custom: Entity<InputState>,
+    input_base: u32,
+    grouping: bool,
+    value: Option<i128>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl NumberBaseConverterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input =
+            cx.new(|cx| InputState::new(window, cx).placeholder("Number, e.g. 0xff or -42"));
+        let custom_base = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value("36")
+                .placeholder("Base (2–36)")
+        });
+        let binary = cx.new(|cx| InputState::new(window, cx));
+        let octal = cx.new(|cx| InputState::new(window, cx));
+        let decimal = cx.new(|cx| InputState::new(window, cx));
+        let hexadecimal = cx.new(|cx| InputState::new(window, cx));
+        let custom = cx.new(|cx| InputState::new(window, cx));
+        let _subscriptions = vec![
+            cx.subscribe_in(&input, window, Self::on_input_event),
+            cx.subscribe_in(&custom_base, window, Self::on_input_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            custom_base,
+            binary,
+            octal,
+            decimal,
+            hexadecimal,
+            custom,
+            input_base: 10,
+            grouping: false,
+            value: None,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn custom_base(&self, cx: &App) -> Option<u32> {
+        self.custom_base
+            .read(cx)
+            .value()
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|base| (2..=36).contains(base))
+    }
+
+    fn format(&self, value: i128, base: u32) -> String {
+        let digits = to_base(value.unsigned_abs(), base);
+        let digits = if self.grouping {
+            group_digits(&digits, base)
+        } else {
+            digits
+        };
+        if value < 0 {
+            format!("-{}", digits)
+        } else {
+            digits
+        }
+    }
+
+    fn update_converted(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        let custom_base = self.custom_base(cx);
+        let input_base = if self.input_base == 0 {
+            custom_base
+        } else {
+            Some(self.input_base)
+        };
+
+        self.error = None;
+        self.value = None;
+        if !value.trim().is_empty() {
+            let parsed = match input_base {
+                Some(base) => parse_number(value.trim(), base),
+                None => Err(ToolError::new("the base must be between 2 and 36")),
+            };
+            match parsed {
+                Ok(value) => self.value = Some(value),
+                Err(err) => self.error = Some(err.context("Invalid number")),
+            }
+        }
+
+        let fields = [
+            (&self.binary, Some(2)),
+            (&self.octal, Some(8)),
+            (&self.decimal, Some(10)),
+            (&self.hexadecimal, Some(16)),
+            (&self.custom, custom_base),
+        ];
+        for (state, base) in fields {
+            let formatted = match (self.value, base) {
+                (Some(value), Some(base)) => self.format(value, base),
+                _ => String::new(),
+            };
+            state.update(cx, |state, cx| {
+                state.set_value(formatted, window, cx);
+            });
+        }
+        cx.notify();
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_converted(window, cx);
+        }
+    }
+
+    fn on_action_set_input_base(
+        &mut self,
+        action: &SetInputBase,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.input_base = action.0;
+        self.update_converted(window, cx);
+    }
+
+    fn on_action_set_grouping(
+        &mut self,
+        action: &SetGrouping,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.grouping = action.0;
+        self.update_converted(window, cx);
+    }
+}
+
+impl Tool for NumberBaseConverterTool {
+    fn title() -> &'static str {
+        "Number Base Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "Number Base"
+    }
+
+    fn description() -> &'static str {
+        "Converts numbers between binary, octal, decimal, hex, and any base from 2 to 36, with two's complement views."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            input_base: self.input_base,
+            custom_base: self.custom_base.read(cx).value().to_string(),
+            grouping: self.grouping,
+            input: include_input.then(|| self.input.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.input_base = preset.input_base;
+        self.grouping = preset.grouping;
+        self.custom_base.update(cx, |state, cx| {
+            state.set_value(preset.custom_base, window, cx);
+        });
+        if let Some(input) = preset.input {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.update_converted(window, cx);
+    }
+}
+
+impl Focusable for NumberBaseConverterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for NumberBaseConverterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let input_base = self.input_base;
+        let grouping = self.grouping;
+        let muted = cx.theme().muted_foreground;
+        let custom_label = match self.custom_base(cx) {
+            Some(base) => format!("Base {}", base),
+            None => "Base ?".to_string(),
+        };
+
+        let output = |id: &'static str, label: String, state: &Entity<InputState>, cx: &App| {
+            let value = state.read(cx).value().clone();
+            div()
+                .v_flex()
+                .w_full()
+                .gap_2()
+                .child(Label::new(label))
+                .child(
+                    TextInput::new(state)
+                        .font_family(editor_font(cx))
+                        .text_size(px(15.))
+                        .focus_bordered(false)
+                        .suffix(Clipboard::new(id).value_fn(move |_, _| value.clone())),
+                )
+        };
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_input_base))
+            .on_action(cx.listener(Self::on_action_set_grouping))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("input-base-dropdown-button")
+                            .button(Button::new("input-base-button").label(match input_base {
+                                0 => "From Custom Base".to_string(),
+                                base => format!("From {}", base_name(base)),
+                            }))
+                            .popup_menu(move |this, _, _| {
+                                let this = this.label("Input Base");
+                                let this = [2, 8, 10, 16].into_iter().fold(this, |this, base| {
+                                    this.menu_with_check(
+                                        base_name(base),
+                                        input_base == base,
+                                        Box::new(SetInputBase(base)),
+                                    )
+                                });
+                                this.menu_with_check(
+                                    "Custom Base",
+                                    input_base == 0,
+                                    Box::new(SetInputBase(0)),
+                                )
+                                .separator()
+                                .menu_with_check(
+                                    "Group Digits",
+                                    grouping,
+                                    Box::new(SetGrouping(!grouping)),
+                                )
+                            }),
+                    )
+                    .child(Label::new("Custom Base").ml_auto())
+                    .child(
+                        div().w(px(80.)).child(
+                            TextInput::new(&self.custom_base)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.input)
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .items_start()
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(output(
+                                "decimal-clipboard",
+                                "Decimal".into(),
+                                &self.decimal,
+                                cx,
+                            ))
+                            .child(output(
+                                "hexadecimal-clipboard",
+                                "Hexadecimal".into(),
+                                &self.hexadecimal,
+                                cx,
+                            ))
+                            .child(output("custom-clipboard", custom_label, &self.custom, cx)),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(output("octal-clipboard", "Octal".into(), &self.octal, cx))
+                            .child(output(
+                                "binary-clipboard",
+                                "Binary".into(),
+                                &self.binary,
+                                cx,
+                            )),
+                    ),
+            )
+            .child(Label::new("Two's Complement"))
+            .child(
+                div()
+                    .id("twos-complement")
+                    .v_flex()
+                    .gap_2()
+                    .children(WIDTHS.into_iter().map(|width| {
+                        let stored = self
+                            .value
+                            .and_then(|value| TwosComplement::new(value, width));
+                        let row = h_flex().gap_4().items_start().child(
+                            div()
+                                .w(px(60.))
+                                .text_color(muted)
+                                .child(format!("{}-bit", width)),
+                        );
+                        match (self.value, stored) {
+                            (None, _) => row,
+                            (Some(_), None) => {
+                                row.child(div().text_color(muted).child("Out of range"))
+                            }
+                            (Some(_), Some(stored)) => {
+                                let hex = format!("0x{}", stored.padded(16));
+                                let binary = group_digits(&stored.padded(2), 2);
+                                row.child(
+                                    div()
+                                        .v_flex()
+                                        .gap_1()
+                                        .font_family(editor_font(cx))
+                                        .child(
+                                            h_flex().gap_1().child(binary.clone()).child(
+                                                Clipboard::new(SharedString::from(format!(
+                                                    "twos-complement-binary-clipboard-{}",
+                                                    width
+                                                )))
+                                                .value(binary.replace(' ', "")),
+                                            ),
+                                        )
+                                        .child(
+                                            h_flex()
+                                                .gap_1()
+                                                .child(hex.clone())
+                                                .child(
+                                                    Clipboard::new(SharedString::from(format!(
+                                                        "twos-complement-hex-clipboard-{}",
+                                                        width
+                                                    )))
+                                                    .value(hex),
+                                                )
+                                                .child(div().ml_4().text_color(muted).child(
+                                                    format!(
+                                                        "signed {}, unsigned {}",
+                                                        stored.signed(),
+                                                        stored.bits
+                                                    ),
+                                                )),
+                                        ),
+                                )
+                            }
+                        }
+                    })),
+            )
+    }
+}
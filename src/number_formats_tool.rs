@@ -0,0 +1,245 @@
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, InteractiveElement,
+    ParentElement, Render, SharedString, StatefulInteractiveElement, Styled, Subscription, Window,
+    div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants},
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::numeral::{self, ROMAN_MAX},
+};
+
+pub struct NumberFormatsTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    fields: Vec<(&'static str, Entity<InputState>)>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl NumberFormatsTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Integer or Roman numeral, e.g. 1994 or MCMXCIV")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&input, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            fields: Vec::new(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_fields(window, cx);
+        }
+    }
+
+    fn update_fields(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        self.error = None;
+        if value.trim().is_empty() {
+            self.fields.clear();
+            cx.notify();
+            return;
+        }
+        match parse_number(&value) {
+            Ok(number) => {
+                let roman = u32::try_from(number)
+                    .ok()
+                    .and_then(|number| numeral::to_roman(number).ok());
+                let fields = [
+                    Some(("Decimal", number.to_string())),
+                    roman.map(|roman| ("Roman Numeral", roman)),
+                    Some(("Words", numeral::to_words(number))),
+                    Some(("Ordinal", numeral::to_ordinal(number))),
+                    Some(("Ordinal Words", numeral::to_ordinal_words(number))),
+                ];
+                self.fields = fields
+                    .into_iter()
+                    .flatten()
+                    .map(|(name, value)| {
+                        (
+                            name,
+                            cx.new(|cx| InputState::new(window, cx).default_value(value)),
+                        )
+                    })
+                    .collect();
+            }
+            Err(err) => {
+                self.fields.clear();
+                self.error = Some(err);
+            }
+        }
+        cx.notify();
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_fields(window, cx);
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.input.update(cx, |state, cx| {
+                state.set_value(value.trim().to_string(), window, cx);
+            });
+            self.update_fields(window, cx);
+        }
+    }
+}
+
+/// Reads an integer, ignoring digit separators, or a Roman numeral.
+fn parse_number(value: &str) -> Result<i128, ToolError> {
+    if numeral::is_roman(value) {
+        return numeral::parse_roman(value).map(i128::from);
+    }
+    let digits: String = value
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, '_' | ',' | ' '))
+        .collect();
+    digits.parse().map_err(|_| {
+        ToolError::new(format!(
+            "{:?} is not an integer or a Roman numeral up to {}",
+            value.trim(),
+            ROMAN_MAX
+        ))
+    })
+}
+
+impl Tool for NumberFormatsTool {
+    fn title() -> &'static str {
+        "Number Formats"
+    }
+
+    fn short_title() -> &'static str {
+        "Number Formats"
+    }
+
+    fn description() -> &'static str {
+        "Converts integers to and from Roman numerals, and spells them out in English words and ordinals."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.input.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+            self.update_fields(window, cx);
+        }
+    }
+}
+
+impl Focusable for NumberFormatsTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for NumberFormatsTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.input.read(cx).value();
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("convert-button")
+                            .label("Convert")
+                            .primary()
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_convert_click)),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.input)
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                div()
+                    .id("fields")
+                    .v_flex()
+                    .flex_1()
+                    .gap_2()
+                    .mt_4()
+                    .overflow_y_scroll()
+                    .children(self.fields.iter().enumerate().map(|(i, (name, state))| {
+                        let value = state.read(cx).value().clone();
+                        h_flex().gap_2().child(Label::new(*name).w(px(160.))).child(
+                            TextInput::new(state)
+                                .text_size(px(15.))
+                                .focus_bordered(false)
+                                .suffix(
+                                    Clipboard::new(SharedString::from(format!(
+                                        "field-clipboard-{}",
+                                        i
+                                    )))
+                                    .value(value),
+                                ),
+                        )
+                    })),
+            )
+    }
+}
@@ -0,0 +1,366 @@
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    ParentElement, Render, SharedString, Styled, Subscription, Window, div, prelude::FluentBuilder,
+    px,
+};
+
+use gpui_component::{
+    StyledExt,
+    button::{Button, ButtonVariants},
+    checkbox::Checkbox,
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+    slider::{Slider, SliderEvent, SliderState},
+};
+
+use rand::{Rng, seq::SliceRandom};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+/// Characters that are easy to mistake for one another, like 1/l/I and 0/O.
+const AMBIGUOUS: &str = "0O1Il|`'\"";
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    length: usize,
+    count: String,
+    uppercase: bool,
+    lowercase: bool,
+    digits: bool,
+    symbols: bool,
+    exclude_ambiguous: bool,
+}
+
+pub struct PasswordGeneratorTool {
+    focus_handle: FocusHandle,
+    length: Entity<SliderState>,
+    count: Entity<InputState>,
+    generated: Entity<InputState>,
+    uppercase: bool,
+    lowercase: bool,
+    digits: bool,
+    symbols: bool,
+    exclude_ambiguous: bool,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl PasswordGeneratorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let length = cx.new(|_| {
+            SliderState::new()
+                .min(4.)
+                .max(128.)
+                .step(1.)
+                .default_value(20.)
+        });
+        let count = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value("10")
+                .placeholder("Count")
+        });
+        let generated = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Generated Passwords")
+        });
+        let _subscriptions = vec![cx.subscribe(&length, |_, _, e: &SliderEvent, cx| {
+            let SliderEvent::Change(_) = e;
+            cx.notify();
+        })];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            length,
+            count,
+            generated,
+            uppercase: true,
+            lowercase: true,
+            digits: true,
+            symbols: true,
+            exclude_ambiguous: false,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn length(&self, cx: &App) -> usize {
+        self.length.read(cx).value().end() as usize
+    }
+
+    /// The characters of each chosen class. Every password takes at least one
+    /// character from each of them.
+    fn classes(&self) -> Vec<Vec<char>> {
+        [
+            (self.uppercase, UPPERCASE),
+            (self.lowercase, LOWERCASE),
+            (self.digits, DIGITS),
+            (self.symbols, SYMBOLS),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, class)| {
+            class
+                .chars()
+                .filter(|c| !self.exclude_ambiguous || !AMBIGUOUS.contains(*c))
+                .collect()
+        })
+        .collect()
+    }
+
+    fn settings(&self, cx: &App) -> Result<(Vec<Vec<char>>, usize, usize), ToolError> {
+        let classes = self.classes();
+        if classes.is_empty() {
+            return Err(ToolError::new("Choose at least one kind of character"));
+        }
+        let length = self.length(cx);
+        if length < classes.len() {
+            return Err(ToolError::new(
+                "The length is shorter than the number of character kinds",
+            ));
+        }
+        let count = match self.count.read(cx).value().trim().parse::<usize>() {
+            Ok(count) if count > 0 => count,
+            _ => return Err(ToolError::new("Count must be a positive number")),
+        };
+        Ok((classes, length, count))
+    }
+
+    fn on_generate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let generated_value = match self.settings(cx) {
+            Ok((classes, length, count)) => {
+                self.error = None;
+                (0..count)
+                    .map(|_| generate_password(&classes, length))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            Err(err) => {
+                self.error = Some(err);
+                String::new()
+            }
+        };
+        self.generated.update(cx, |state, cx| {
+            state.set_value(SharedString::from(generated_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.generated.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+}
+
+/// Generates a password with at least one character of each class. The
+/// thread-local generator is a CSPRNG seeded from the operating system.
+fn generate_password(classes: &[Vec<char>], length: usize) -> String {
+    let mut rng = rand::rng();
+    let pool: Vec<char> = classes.concat();
+    let mut password: Vec<char> = classes
+        .iter()
+        .map(|class| class[rng.random_range(0..class.len())])
+        .collect();
+    password.extend((password.len()..length).map(|_| pool[rng.random_range(0..pool.len())]));
+    password.shuffle(&mut rng);
+    password.into_iter().collect()
+}
+
+/// Describes the entropy of a password drawn uniformly from a pool of
+/// `pool_size` characters, and rates its strength.
+fn strength_estimate(pool_size: usize, length: usize) -> String {
+    let bits = length as f64 * (pool_size as f64).log2();
+    let strength = match bits {
+        ..40. => "Weak",
+        ..60. => "Fair",
+        ..80. => "Strong",
+        _ => "Very Strong",
+    };
+    format!(
+        "{:.0} bits of entropy · {} · {} possible characters",
+        bits, strength, pool_size
+    )
+}
+
+impl Tool for PasswordGeneratorTool {
+    fn title() -> &'static str {
+        "Password Generator"
+    }
+
+    fn short_title() -> &'static str {
+        "Password"
+    }
+
+    fn description() -> &'static str {
+        "Generates random passwords from chosen character kinds and rates their strength."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_generate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, _: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            length: self.length(cx),
+            count: self.count.read(cx).value().to_string(),
+            uppercase: self.uppercase,
+            lowercase: self.lowercase,
+            digits: self.digits,
+            symbols: self.symbols,
+            exclude_ambiguous: self.exclude_ambiguous,
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.length.update(cx, |state, cx| {
+            state.set_value(preset.length as f32, window, cx);
+        });
+        self.count.update(cx, |state, cx| {
+            state.set_value(preset.count, window, cx);
+        });
+        self.uppercase = preset.uppercase;
+        self.lowercase = preset.lowercase;
+        self.digits = preset.digits;
+        self.symbols = preset.symbols;
+        self.exclude_ambiguous = preset.exclude_ambiguous;
+        cx.notify();
+    }
+}
+
+impl Focusable for PasswordGeneratorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for PasswordGeneratorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let estimate = self
+            .settings(cx)
+            .ok()
+            .map(|(classes, length, _)| strength_estimate(classes.concat().len(), length));
+        let toggles = [
+            ("uppercase-checkbox", "A–Z", self.uppercase),
+            ("lowercase-checkbox", "a–z", self.lowercase),
+            ("digits-checkbox", "0–9", self.digits),
+            ("symbols-checkbox", "Symbols", self.symbols),
+            (
+                "exclude-ambiguous-checkbox",
+                "Exclude Look-Alikes",
+                self.exclude_ambiguous,
+            ),
+        ];
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("generate-button")
+                            .label("Generate")
+                            .primary()
+                            .on_click(cx.listener(Self::on_generate_click)),
+                    )
+                    .children(
+                        toggles
+                            .into_iter()
+                            .enumerate()
+                            .map(|(ix, (id, label, checked))| {
+                                Checkbox::new(id).label(label).checked(checked).on_click(
+                                    cx.listener(move |this, checked: &bool, _, cx| {
+                                        let toggle = match ix {
+                                            0 => &mut this.uppercase,
+                                            1 => &mut this.lowercase,
+                                            2 => &mut this.digits,
+                                            3 => &mut this.symbols,
+                                            _ => &mut this.exclude_ambiguous,
+                                        };
+                                        *toggle = *checked;
+                                        cx.notify();
+                                    }),
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_4()
+                    .child(
+                        div()
+                            .v_flex()
+                            .flex_1()
+                            .gap_2()
+                            .child(Label::new(format!("Length: {}", self.length(cx))))
+                            .child(Slider::new(&self.length)),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w(px(160.))
+                            .gap_2()
+                            .child(Label::new("Count"))
+                            .child(
+                                TextInput::new(&self.count)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            ),
+                    ),
+            )
+            .when_some(estimate, |this, estimate| this.child(Label::new(estimate)))
+            .child(
+                TextInput::new(&self.generated)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,408 @@
+use std::time::{Duration, Instant};
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    input::{InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::password::{self, Algorithm, Cost},
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = crypto_tools, no_json)]
+pub struct SetAlgorithm(Algorithm);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    algorithm: Algorithm,
+    #[serde(default)]
+    cost: Cost,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+/// What the last hash or verification found.
+enum Outcome {
+    Hashed(Duration),
+    Verified(bool, Duration),
+}
+
+pub struct PasswordHashTool {
+    focus_handle: FocusHandle,
+    algorithm: Algorithm,
+    password: Entity<InputState>,
+    bcrypt_cost: Entity<InputState>,
+    memory: Entity<InputState>,
+    iterations: Entity<InputState>,
+    parallelism: Entity<InputState>,
+    hash: Entity<InputState>,
+    outcome: Option<Outcome>,
+    busy: bool,
+    error: Option<ToolError>,
+}
+
+impl PasswordHashTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let cost = Cost::default();
+        let password = cx.new(|cx| {
+            InputState::new(window, cx)
+                .masked(true)
+                .placeholder("Password")
+        });
+        let number = |value: u32, placeholder: &'static str, window: &mut Window, cx: &mut App| {
+            cx.new(|cx| {
+                InputState::new(window, cx)
+                    .default_value(value.to_string())
+                    .placeholder(placeholder)
+            })
+        };
+        let bcrypt_cost = number(cost.bcrypt_cost, "Cost", window, cx);
+        let memory = number(cost.memory, "Memory (KiB)", window, cx);
+        let iterations = number(cost.iterations, "Iterations", window, cx);
+        let parallelism = number(cost.parallelism, "Parallelism", window, cx);
+        let hash = cx.new(|cx| InputState::new(window, cx).placeholder("Hash"));
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            algorithm: Algorithm::Bcrypt,
+            password,
+            bcrypt_cost,
+            memory,
+            iterations,
+            parallelism,
+            hash,
+            outcome: None,
+            busy: false,
+            error: None,
+        }
+    }
+
+    fn cost(&self, cx: &App) -> Result<Cost, ToolError> {
+        let number = |state: &Entity<InputState>, name: &str| {
+            state.read(cx).value().trim().parse::<u32>().map_err(|_| {
+                ToolError::new(format!("{} must be a number", name)).context("Invalid cost")
+            })
+        };
+        Ok(Cost {
+            bcrypt_cost: number(&self.bcrypt_cost, "Cost")?,
+            memory: number(&self.memory, "Memory")?,
+            iterations: number(&self.iterations, "Iterations")?,
+            parallelism: number(&self.parallelism, "Parallelism")?,
+        })
+    }
+
+    fn run<F>(&mut self, f: F, window: &mut Window, cx: &mut Context<Self>)
+    where
+        F: FnOnce() -> Result<(Outcome, Option<String>), ToolError> + Send + 'static,
+    {
+        // Both algorithms are slow on purpose, so the work happens off the
+        // UI thread.
+        self.busy = true;
+        self.error = None;
+        self.outcome = None;
+        cx.notify();
+        cx.spawn_in(window, async move |this, cx| {
+            let result = cx.background_executor().spawn(async move { f() }).await;
+            this.update_in(cx, |this, window, cx| {
+                this.busy = false;
+                match result {
+                    Ok((outcome, hash)) => {
+                        this.outcome = Some(outcome);
+                        if let Some(hash) = hash {
+                            this.hash.update(cx, |state, cx| {
+                                state.set_value(SharedString::from(hash), window, cx);
+                            });
+                        }
+                    }
+                    Err(err) => this.error = Some(err),
+                }
+                cx.notify();
+            })
+        })
+        .detach();
+    }
+
+    fn on_hash_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let cost = match self.cost(cx) {
+            Ok(cost) => cost,
+            Err(err) => {
+                self.error = Some(err);
+                cx.notify();
+                return;
+            }
+        };
+        let password = self.password.read(cx).value().to_string();
+        let algorithm = self.algorithm;
+        self.run(
+            move || {
+                let started = Instant::now();
+                let hash = password::hash(&password, algorithm, &cost, &mut rand::rng())?;
+                Ok((Outcome::Hashed(started.elapsed()), Some(hash)))
+            },
+            window,
+            cx,
+        );
+    }
+
+    fn on_verify_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let password = self.password.read(cx).value().to_string();
+        let hash = self.hash.read(cx).value().to_string();
+        self.run(
+            move || {
+                let started = Instant::now();
+                let matches = password::verify(&password, &hash)?;
+                Ok((Outcome::Verified(matches, started.elapsed()), None))
+            },
+            window,
+            cx,
+        );
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.hash.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.hash.update(cx, |state, cx| {
+                state.set_value(value.trim().to_string(), window, cx);
+            });
+            self.outcome = None;
+            cx.notify();
+        }
+    }
+
+    fn on_action_set_algorithm(
+        &mut self,
+        action: &SetAlgorithm,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.algorithm = action.0;
+        cx.notify();
+    }
+
+    fn render_number(
+        &self,
+        label: &'static str,
+        state: &Entity<InputState>,
+    ) -> impl gpui::IntoElement {
+        div()
+            .v_flex()
+            .w_full()
+            .gap_2()
+            .child(Label::new(label))
+            .child(
+                TextInput::new(state)
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
+
+impl Tool for PasswordHashTool {
+    fn title() -> &'static str {
+        "Password Hasher"
+    }
+
+    fn short_title() -> &'static str {
+        "Password"
+    }
+
+    fn description() -> &'static str {
+        "Hashes passwords with bcrypt or Argon2id at an adjustable cost and verifies passwords against existing hashes."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.busy {
+            self.on_hash_click(&ClickEvent::default(), window, cx);
+        }
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.hash.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            algorithm: self.algorithm,
+            cost: self.cost(cx).unwrap_or_default(),
+            input: include_input.then(|| self.hash.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        for (state, value) in [
+            (&self.bcrypt_cost, preset.cost.bcrypt_cost),
+            (&self.memory, preset.cost.memory),
+            (&self.iterations, preset.cost.iterations),
+            (&self.parallelism, preset.cost.parallelism),
+        ] {
+            state.update(cx, |state, cx| {
+                state.set_value(value.to_string(), window, cx);
+            });
+        }
+        if let Some(input) = preset.input {
+            self.hash.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.on_action_set_algorithm(&SetAlgorithm(preset.algorithm), window, cx);
+    }
+}
+
+impl Focusable for PasswordHashTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for PasswordHashTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let hash = self.hash.read(cx).value();
+        let algorithm = self.algorithm;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_algorithm))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("hash-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("hash-button")
+                                    .label(format!("Hash ({})", algorithm.name()))
+                                    .primary()
+                                    .loading(self.busy)
+                                    .disabled(self.busy)
+                                    .on_click(cx.listener(Self::on_hash_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                Algorithm::ALL.into_iter().fold(
+                                    this.label("Algorithm"),
+                                    |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            algorithm == option,
+                                            Box::new(SetAlgorithm(option)),
+                                        )
+                                    },
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("verify-button")
+                            .label("Verify")
+                            .loading(self.busy)
+                            .disabled(hash.is_empty() || self.busy)
+                            .on_click(cx.listener(Self::on_verify_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(Label::new("Password"))
+            .child(
+                TextInput::new(&self.password)
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .when(algorithm == Algorithm::Bcrypt, |this| {
+                        this.child(self.render_number("Cost (log₂ rounds)", &self.bcrypt_cost))
+                    })
+                    .when(algorithm == Algorithm::Argon2id, |this| {
+                        this.child(self.render_number("Memory (KiB)", &self.memory))
+                            .child(self.render_number("Iterations", &self.iterations))
+                            .child(self.render_number("Parallelism", &self.parallelism))
+                    }),
+            )
+            .child(Label::new("Hash"))
+            .child(
+                TextInput::new(&self.hash)
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .when_some(self.outcome.as_ref(), |this, outcome| {
+                let (text, color) = match outcome {
+                    Outcome::Hashed(elapsed) => (
+                        format!("Hashed in {} ms", elapsed.as_millis()),
+                        cx.theme().muted_foreground,
+                    ),
+                    Outcome::Verified(true, elapsed) => (
+                        format!("Password matches (checked in {} ms)", elapsed.as_millis()),
+                        cx.theme().green,
+                    ),
+                    Outcome::Verified(false, elapsed) => (
+                        format!(
+                            "Password does not match (checked in {} ms)",
+                            elapsed.as_millis()
+                        ),
+                        cx.theme().red,
+                    ),
+                };
+                this.child(div().text_color(color).child(text))
+            })
+    }
+}
@@ -0,0 +1,449 @@
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, ParentElement, Render,
+    Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    StyledExt,
+    button::Button,
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use percent_encoding::{AsciiSet, CONTROLS, percent_decode_str, utf8_percent_encode};
+
+use serde_json::Value;
+
+use crate::{Tool, editor_font};
+
+const FILE_URL_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+enum PathRoot {
+    Relative,
+    Unix,
+    Drive(char),
+    Unc { host: String, share: String },
+}
+
+struct ParsedPath {
+    root: PathRoot,
+    segments: Vec<String>,
+}
+
+pub struct PathConverterTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    windows: Entity<InputState>,
+    unix: Entity<InputState>,
+    wsl: Entity<InputState>,
+    file_url: Entity<InputState>,
+    shell: Entity<InputState>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl PathConverterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Path, e.g. C:\\Users\\me\\My Documents, /mnt/c/Users or file:///")
+        });
+        let windows = cx.new(|cx| InputState::new(window, cx));
+        let unix = cx.new(|cx| InputState::new(window, cx));
+        let wsl = cx.new(|cx| InputState::new(window, cx));
+        let file_url = cx.new(|cx| InputState::new(window, cx));
+        let shell = cx.new(|cx| InputState::new(window, cx));
+
+        let _subscriptions = vec![cx.subscribe_in(&input, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            windows,
+            unix,
+            wsl,
+            file_url,
+            shell,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_converted(window, cx);
+        }
+    }
+
+    fn update_converted(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        let converted = match value.trim() {
+            "" => None,
+            value => Some(parse_path(value)),
+        };
+        let fields = [
+            (&self.windows, converted.as_ref().map(format_windows)),
+            (&self.unix, converted.as_ref().map(format_unix)),
+            (&self.wsl, converted.as_ref().and_then(format_wsl)),
+            (&self.file_url, converted.as_ref().and_then(format_file_url)),
+            (
+                &self.shell,
+                converted
+                    .as_ref()
+                    .and_then(format_wsl)
+                    .map(|path| shell_quote(&path)),
+            ),
+        ];
+        for (state, value) in fields {
+            state.update(cx, |state, cx| {
+                state.set_value(value.unwrap_or_default(), window, cx);
+            });
+        }
+        cx.notify();
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.input.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+}
+
+fn parse_path(value: &str) -> ParsedPath {
+    let value = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value);
+
+    if value.len() >= 5 && value[..5].eq_ignore_ascii_case("file:") {
+        return parse_file_url(&value[5..]);
+    }
+
+    // Extended-length prefixes only lift the MAX_PATH limit; the path behind
+    // them is an ordinary drive or UNC path.
+    let value = match value.strip_prefix("\\\\?\\") {
+        Some(rest) => match rest.strip_prefix("UNC\\") {
+            Some(rest) => return parse_unc(rest),
+            None => rest,
+        },
+        None => value,
+    };
+
+    if let Some(rest) = value
+        .strip_prefix("\\\\")
+        .or_else(|| value.strip_prefix("//"))
+    {
+        return parse_unc(rest);
+    }
+
+    let mut chars = value.chars();
+    if let (Some(drive), Some(':')) = (chars.next(), chars.next())
+        && drive.is_ascii_alphabetic()
+    {
+        return ParsedPath {
+            root: PathRoot::Drive(drive.to_ascii_uppercase()),
+            segments: split_segments(chars.as_str()),
+        };
+    }
+
+    let segments = split_segments(value);
+    if !value.starts_with(['/', '\\']) {
+        return ParsedPath {
+            root: PathRoot::Relative,
+            segments,
+        };
+    }
+    if let [mnt, drive, ..] = segments.as_slice()
+        && mnt == "mnt"
+        && drive.len() == 1
+        && drive.chars().all(|c| c.is_ascii_alphabetic())
+    {
+        return ParsedPath {
+            root: PathRoot::Drive(drive.to_ascii_uppercase().chars().next().unwrap()),
+            segments: segments[2..].to_vec(),
+        };
+    }
+    ParsedPath {
+        root: PathRoot::Unix,
+        segments,
+    }
+}
+
+fn parse_file_url(value: &str) -> ParsedPath {
+    let (host, path) = match value.strip_prefix("//") {
+        Some(rest) => rest.split_at(rest.find('/').unwrap_or(rest.len())),
+        None => ("", value),
+    };
+    let path = percent_decode_str(path).decode_utf8_lossy();
+    if !host.is_empty() && !host.eq_ignore_ascii_case("localhost") {
+        return parse_unc(&format!("{}{}", host, path));
+    }
+
+    // Drive letters in file URLs sit behind a leading slash, and older
+    // producers write the colon as a pipe.
+    let path = path.strip_prefix('/').unwrap_or(&path);
+    let mut chars = path.chars();
+    if let (Some(drive), Some(':' | '|')) = (chars.next(), chars.next())
+        && drive.is_ascii_alphabetic()
+    {
+        return ParsedPath {
+            root: PathRoot::Drive(drive.to_ascii_uppercase()),
+            segments: split_segments(chars.as_str()),
+        };
+    }
+    ParsedPath {
+        root: PathRoot::Unix,
+        segments: split_segments(path),
+    }
+}
+
+fn parse_unc(value: &str) -> ParsedPath {
+    let mut segments = split_segments(value).into_iter();
+    let host = segments.next().unwrap_or_default();
+    let share = segments.next().unwrap_or_default();
+    ParsedPath {
+        root: PathRoot::Unc { host, share },
+        segments: segments.collect(),
+    }
+}
+
+fn split_segments(value: &str) -> Vec<String> {
+    value
+        .split(['/', '\\'])
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn format_windows(path: &ParsedPath) -> String {
+    let rest = path.segments.join("\\");
+    match &path.root {
+        PathRoot::Relative => rest,
+        PathRoot::Unix => format!("\\{}", rest),
+        PathRoot::Drive(drive) => format!("{}:\\{}", drive, rest),
+        PathRoot::Unc { host, share } => join_unc("\\\\", "\\", host, share, &rest),
+    }
+}
+
+fn format_unix(path: &ParsedPath) -> String {
+    let rest = path.segments.join("/");
+    match &path.root {
+        PathRoot::Relative => rest,
+        PathRoot::Unix => format!("/{}", rest),
+        PathRoot::Drive(drive) => format!("{}:/{}", drive, rest),
+        PathRoot::Unc { host, share } => join_unc("//", "/", host, share, &rest),
+    }
+}
+
+fn format_wsl(path: &ParsedPath) -> Option<String> {
+    let rest = path.segments.join("/");
+    match &path.root {
+        PathRoot::Relative => Some(rest),
+        PathRoot::Unix => Some(format!("/{}", rest)),
+        PathRoot::Drive(drive) => Some(
+            format!("/mnt/{}/{}", drive.to_ascii_lowercase(), rest)
+                .trim_end_matches('/')
+                .to_string(),
+        ),
+        // Network shares have to be mounted before WSL can reach them.
+        PathRoot::Unc { .. } => None,
+    }
+}
+
+fn format_file_url(path: &ParsedPath) -> Option<String> {
+    let rest = path
+        .segments
+        .iter()
+        .map(|segment| utf8_percent_encode(segment, FILE_URL_ENCODE_SET).to_string())
+        .collect::<Vec<_>>()
+        .join("/");
+    match &path.root {
+        PathRoot::Relative => None,
+        PathRoot::Unix => Some(format!("file:///{}", rest)),
+        PathRoot::Drive(drive) => Some(format!("file:///{}:/{}", drive, rest)),
+        PathRoot::Unc { host, share } => Some(join_unc(
+            "file://",
+            "/",
+            host,
+            &utf8_percent_encode(share, FILE_URL_ENCODE_SET).to_string(),
+            &rest,
+        )),
+    }
+}
+
+fn join_unc(prefix: &str, separator: &str, host: &str, share: &str, rest: &str) -> String {
+    let parts: Vec<&str> = [host, share, rest]
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect();
+    format!("{}{}", prefix, parts.join(separator))
+}
+
+fn shell_quote(value: &str) -> String {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "/._-+=:,@%".contains(c);
+    if !value.is_empty() && value.chars().all(is_safe) {
+        return value.to_string();
+    }
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+impl Tool for PathConverterTool {
+    fn title() -> &'static str {
+        "Path Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "Path Converter"
+    }
+
+    fn description() -> &'static str {
+        "Converts file paths between Windows, Unix, UNC, WSL, and file URL forms."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.input.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
+}
+
+impl Focusable for PathConverterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for PathConverterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let windows = self.windows.read(cx).value().clone();
+        let unix = self.unix.read(cx).value().clone();
+        let wsl = self.wsl.read(cx).value().clone();
+        let file_url = self.file_url.read(cx).value().clone();
+        let shell = self.shell.read(cx).value().clone();
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("paste-button")
+                        .label("Paste")
+                        .on_click(cx.listener(Self::on_paste_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.input)
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                div()
+                    .v_flex()
+                    .w_full()
+                    .gap_2()
+                    .mt_4()
+                    .child(Label::new("Windows"))
+                    .child(
+                        TextInput::new(&self.windows)
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false)
+                            .suffix(
+                                Clipboard::new("windows-clipboard")
+                                    .value_fn(move |_, _| windows.clone()),
+                            ),
+                    )
+                    .child(Label::new("Unix"))
+                    .child(
+                        TextInput::new(&self.unix)
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false)
+                            .suffix(
+                                Clipboard::new("unix-clipboard").value_fn(move |_, _| unix.clone()),
+                            ),
+                    )
+                    .child(Label::new("WSL"))
+                    .child(
+                        TextInput::new(&self.wsl)
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false)
+                            .suffix(
+                                Clipboard::new("wsl-clipboard").value_fn(move |_, _| wsl.clone()),
+                            ),
+                    )
+                    .child(Label::new("Shell-Escaped"))
+                    .child(
+                        TextInput::new(&self.shell)
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false)
+                            .suffix(
+                                Clipboard::new("shell-clipboard")
+                                    .value_fn(move |_, _| shell.clone()),
+                            ),
+                    )
+                    .child(Label::new("File URL"))
+                    .child(
+                        TextInput::new(&self.file_url)
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false)
+                            .suffix(
+                                Clipboard::new("file-url-clipboard")
+                                    .value_fn(move |_, _| file_url.clone()),
+                            ),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,33 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config;
+
+/// The state of each tool, keyed by tool class, which tools are in live mode,
+/// and the tool that was last open. It is kept in `state.json` in the app's config directory so that
+/// nothing is lost when the app quits.
+#[derive(Default, Serialize, Deserialize)]
+pub struct SavedState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_tool: Option<String>,
+    #[serde(default)]
+    pub tools: BTreeMap<String, Value>,
+    #[serde(default)]
+    pub live_tools: BTreeSet<String>,
+}
+
+impl SavedState {
+    /// Loads the saved state, starting afresh if there is none or the file
+    /// cannot be read.
+    pub fn load() -> Self {
+        config::load("state.json")
+    }
+
+    pub fn save(&self) -> Result<()> {
+        config::save("state.json", self)
+    }
+}
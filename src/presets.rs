@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+
+use gpui::Global;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config;
+
+/// Named tool presets, keyed by tool class and then by preset name. Presets are
+/// kept in `presets.json` in the app's config directory.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Presets {
+    tools: BTreeMap<String, BTreeMap<String, Value>>,
+}
+
+impl Global for Presets {}
+
+impl Presets {
+    /// Loads the saved presets, starting empty if there are none or the file
+    /// cannot be read.
+    pub fn load() -> Self {
+        config::load("presets.json")
+    }
+
+    pub fn save(&self) -> Result<()> {
+        config::save("presets.json", self)
+    }
+
+    pub fn names(&self, klass: &str) -> Vec<String> {
+        self.tools
+            .get(klass)
+            .map(|presets| presets.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, klass: &str, name: &str) -> Option<&Value> {
+        self.tools.get(klass)?.get(name)
+    }
+
+    pub fn insert(&mut self, klass: &str, name: &str, preset: Value) {
+        self.tools
+            .entry(klass.to_string())
+            .or_default()
+            .insert(name.to_string(), preset);
+    }
+
+    pub fn remove(&mut self, klass: &str, name: &str) {
+        if let Some(presets) = self.tools.get_mut(klass) {
+            presets.remove(name);
+            if presets.is_empty() {
+                self.tools.remove(klass);
+            }
+        }
+    }
+}
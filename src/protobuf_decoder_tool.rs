@@ -0,0 +1,405 @@
+use std::path::PathBuf;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, PathPromptOptions, Render, SharedString, Styled, Window,
+    div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::{
+        encoding::{self, InputEncoding},
+        protobuf::{self, Schema},
+    },
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = protobuf_tools, no_json)]
+pub struct SetInputEncoding(InputEncoding);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = protobuf_tools, no_json)]
+pub struct SetMessageType(Option<String>);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    encoding: InputEncoding,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+struct LoadedSchema {
+    path: PathBuf,
+    schema: Schema,
+}
+
+pub struct ProtobufDecoderTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    decoded: Entity<InputState>,
+    encoding: InputEncoding,
+    schema: Option<LoadedSchema>,
+    /// The message type the input is decoded as, or `None` to decode it raw.
+    message_type: Option<String>,
+    error: Option<ToolError>,
+}
+
+impl ProtobufDecoderTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Base64 or Hex Protobuf Bytes")
+        });
+        let decoded = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Decoded Fields")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            decoded,
+            encoding: InputEncoding::Auto,
+            schema: None,
+            message_type: None,
+            error: None,
+        }
+    }
+
+    fn decode(&self, value: &str) -> Result<String, ToolError> {
+        let bytes = encoding::parse_bytes(value, self.encoding)?;
+        let schema = self
+            .schema
+            .as_ref()
+            .zip(self.message_type.as_deref())
+            .map(|(loaded, name)| (&loaded.schema, name));
+        protobuf::decode(&bytes, schema)
+    }
+
+    fn on_decode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let decoded = match self.decode(&value) {
+            Ok(decoded) => {
+                self.error = None;
+                decoded
+            }
+            Err(err) => {
+                self.error = Some(err);
+                String::new()
+            }
+        };
+        self.decoded.update(cx, |state, cx| {
+            state.set_value(SharedString::from(decoded), window, cx);
+        });
+        cx.notify();
+    }
+
+    /// Decodes again after an option changes, if anything was decoded.
+    fn refresh(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.decoded.read(cx).value().is_empty() {
+            cx.notify();
+        } else {
+            self.on_decode_click(&ClickEvent::default(), window, cx);
+        }
+    }
+
+    fn on_load_schema_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        cx.spawn_in(window, async move |this, cx| {
+            if let Ok(Ok(Some(paths))) = paths.await
+                && let Some(path) = paths.into_iter().next()
+            {
+                this.update_in(cx, |this, window, cx| this.load_schema(path, window, cx))
+                    .ok();
+            }
+        })
+        .detach();
+    }
+
+    fn load_schema(&mut self, path: PathBuf, window: &mut Window, cx: &mut Context<Self>) {
+        let schema = std::fs::read_to_string(&path)
+            .map_err(|err| ToolError::new(err.to_string()).context("Cannot read .proto file"))
+            .and_then(|source| protobuf::parse_schema(&source));
+        match schema {
+            Ok(schema) => {
+                self.error = None;
+                self.message_type = schema.message_names().into_iter().next();
+                self.schema = Some(LoadedSchema { path, schema });
+                self.refresh(window, cx);
+            }
+            Err(err) => {
+                self.error = Some(err);
+                cx.notify();
+            }
+        }
+    }
+
+    fn on_clear_schema_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.schema = None;
+        self.message_type = None;
+        self.refresh(window, cx);
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.decoded.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_action_set_input_encoding(
+        &mut self,
+        action: &SetInputEncoding,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.encoding = action.0;
+        self.refresh(window, cx);
+    }
+
+    fn on_action_set_message_type(
+        &mut self,
+        action: &SetMessageType,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.message_type = action.0.clone();
+        self.refresh(window, cx);
+    }
+}
+
+impl Tool for ProtobufDecoderTool {
+    fn title() -> &'static str {
+        "Protobuf Decoder"
+    }
+
+    fn short_title() -> &'static str {
+        "Protobuf"
+    }
+
+    fn description() -> &'static str {
+        "Decodes Base64 or hex protobuf bytes into their wire-format fields, naming them from an optional .proto file."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_decode_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.decoded.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            encoding: self.encoding,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.encoding = preset.encoding;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.refresh(window, cx);
+    }
+}
+
+impl Focusable for ProtobufDecoderTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ProtobufDecoderTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let encoding = self.encoding;
+        let message_type = self.message_type.clone();
+        let message_names = self
+            .schema
+            .as_ref()
+            .map(|loaded| loaded.schema.message_names())
+            .unwrap_or_default();
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_input_encoding))
+            .on_action(cx.listener(Self::on_action_set_message_type))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("decode-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("decode-button")
+                                    .label("Decode")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_decode_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                let this = InputEncoding::ALL.into_iter().fold(
+                                    this.label("Input"),
+                                    |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            encoding == option,
+                                            Box::new(SetInputEncoding(option)),
+                                        )
+                                    },
+                                );
+                                if message_names.is_empty() {
+                                    return this;
+                                }
+                                message_names.iter().fold(
+                                    this.label("Message Type").menu_with_check(
+                                        "Raw Fields",
+                                        message_type.is_none(),
+                                        Box::new(SetMessageType(None)),
+                                    ),
+                                    |this, name| {
+                                        this.menu_with_check(
+                                            name.clone(),
+                                            message_type.as_ref() == Some(name),
+                                            Box::new(SetMessageType(Some(name.clone()))),
+                                        )
+                                    },
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("load-schema-button")
+                            .label("Load .proto…")
+                            .on_click(cx.listener(Self::on_load_schema_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h(px(120.))
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .map(|this| match &self.schema {
+                        Some(loaded) => this
+                            .child(Label::new(format!(
+                                "{} ({})",
+                                loaded.path.display(),
+                                self.message_type.as_deref().unwrap_or("raw fields")
+                            )))
+                            .child(
+                                Button::new("clear-schema-button")
+                                    .label("Clear")
+                                    .on_click(cx.listener(Self::on_clear_schema_click)),
+                            ),
+                        None => this.child(
+                            div()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("Load a .proto file to decode fields by name."),
+                        ),
+                    })
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.decoded)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
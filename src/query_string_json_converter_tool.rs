@@ -0,0 +1,383 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::{
+        json,
+        url::{self, ArrayStyle},
+    },
+    default_indentation, editor_font,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Direction {
+    QueryToJson,
+    JsonToQuery,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = url_tools, no_json)]
+pub struct SetQueryDirection(Direction);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = url_tools, no_json)]
+pub struct SetArrayStyle(ArrayStyle);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = url_tools, no_json)]
+pub struct SetPercentEncoding(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    direction: Direction,
+    array_style: ArrayStyle,
+    percent_encoding: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct QueryStringJSONConverterTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    converted: Entity<InputState>,
+    direction: Direction,
+    array_style: ArrayStyle,
+    /// Whether keys and values are percent-decoded when read and encoded
+    /// when written.
+    percent_encoding: bool,
+    error: Option<ToolError>,
+}
+
+impl QueryStringJSONConverterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Query String or URL, e.g. a=1&b[]=2&b[]=3")
+        });
+        let converted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("JSON")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            converted,
+            direction: Direction::QueryToJson,
+            array_style: ArrayStyle::Brackets,
+            percent_encoding: true,
+            error: None,
+        }
+    }
+
+    fn convert(&self, value: &str, cx: &App) -> Result<String, ToolError> {
+        match self.direction {
+            Direction::QueryToJson => {
+                let json = url::query_to_json(value, self.array_style, self.percent_encoding)
+                    .map_err(|err| err.context("Invalid query string"))?;
+                json::format(&json.to_string(), default_indentation(cx))
+            }
+            Direction::JsonToQuery => {
+                let json: Value = serde_json::from_str(value)
+                    .map_err(|err| ToolError::from(err).context("Invalid JSON"))?;
+                url::json_to_query(&json, self.array_style, self.percent_encoding)
+                    .map_err(|err| err.context("Cannot convert JSON"))
+            }
+        }
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        match self.convert(&value, cx) {
+            Ok(converted) => {
+                self.error = None;
+                self.converted.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(converted), window, cx);
+                });
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_converted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.converted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_query_direction(
+        &mut self,
+        action: &SetQueryDirection,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.direction == action.0 {
+            return;
+        }
+        self.direction = action.0;
+        let (source, converted) = match self.direction {
+            Direction::QueryToJson => (
+                ("Query String or URL, e.g. a=1&b[]=2&b[]=3", Language::Plain),
+                ("JSON", Language::Json),
+            ),
+            Direction::JsonToQuery => (
+                ("JSON Object", Language::Json),
+                ("Query String", Language::Plain),
+            ),
+        };
+        self.editor.update(cx, |state, cx| {
+            state.set_highlighter(source.1.name().to_string(), cx);
+            state.set_placeholder(source.0, window, cx);
+        });
+        self.converted.update(cx, |state, cx| {
+            state.set_highlighter(converted.1.name().to_string(), cx);
+            state.set_placeholder(converted.0, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_action_set_array_style(
+        &mut self,
+        action: &SetArrayStyle,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.array_style = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_percent_encoding(
+        &mut self,
+        action: &SetPercentEncoding,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.percent_encoding = action.0;
+        cx.notify();
+    }
+}
+
+impl Tool for QueryStringJSONConverterTool {
+    fn title() -> &'static str {
+        "Query String/JSON Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "Query/JSON"
+    }
+
+    fn description() -> &'static str {
+        "Converts URL query strings to JSON objects and back, with bracketed or repeated keys for arrays."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_converted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.converted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            direction: self.direction,
+            array_style: self.array_style,
+            percent_encoding: self.percent_encoding,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_query_direction(&SetQueryDirection(preset.direction), window, cx);
+        self.on_action_set_array_style(&SetArrayStyle(preset.array_style), window, cx);
+        self.on_action_set_percent_encoding(
+            &SetPercentEncoding(preset.percent_encoding),
+            window,
+            cx,
+        );
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for QueryStringJSONConverterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for QueryStringJSONConverterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let direction = self.direction;
+        let array_style = self.array_style;
+        let percent_encoding = self.percent_encoding;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_query_direction))
+            .on_action(cx.listener(Self::on_action_set_array_style))
+            .on_action(cx.listener(Self::on_action_set_percent_encoding))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("convert-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("convert-button")
+                                    .label(match direction {
+                                        Direction::QueryToJson => "Query String to JSON",
+                                        Direction::JsonToQuery => "JSON to Query String",
+                                    })
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                let this = this
+                                    .label("Direction")
+                                    .menu_with_check(
+                                        "Query String to JSON",
+                                        direction == Direction::QueryToJson,
+                                        Box::new(SetQueryDirection(Direction::QueryToJson)),
+                                    )
+                                    .menu_with_check(
+                                        "JSON to Query String",
+                                        direction == Direction::JsonToQuery,
+                                        Box::new(SetQueryDirection(Direction::JsonToQuery)),
+                                    );
+                                ArrayStyle::ALL
+                                    .into_iter()
+                                    .fold(this.label("Arrays"), |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            array_style == option,
+                                            Box::new(SetArrayStyle(option)),
+                                        )
+                                    })
+                                    .label("Options")
+                                    .menu_with_check(
+                                        "Percent-Encoding",
+                                        percent_encoding,
+                                        Box::new(SetPercentEncoding(!percent_encoding)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-converted-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_converted_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.converted)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
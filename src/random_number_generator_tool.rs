@@ -0,0 +1,514 @@
+use std::collections::HashSet;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum NumberKind {
+    Integer,
+    Float,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Distribution {
+    Uniform,
+    Normal,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum OutputFormat {
+    Lines,
+    Csv,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = generator_tools, no_json)]
+pub struct SetNumberKind(NumberKind);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = generator_tools, no_json)]
+pub struct SetDistribution(Distribution);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = generator_tools, no_json)]
+pub struct SetUnique(bool);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = generator_tools, no_json)]
+pub struct SetOutputFormat(OutputFormat);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    kind: NumberKind,
+    distribution: Distribution,
+    unique: bool,
+    output_format: OutputFormat,
+    min: String,
+    max: String,
+    count: String,
+    decimals: String,
+    seed: String,
+}
+
+struct Settings {
+    kind: NumberKind,
+    distribution: Distribution,
+    unique: bool,
+    min: f64,
+    max: f64,
+    count: usize,
+    decimals: usize,
+    seed: Option<u64>,
+}
+
+pub struct RandomNumberGeneratorTool {
+    focus_handle: FocusHandle,
+    min: Entity<InputState>,
+    max: Entity<InputState>,
+    count: Entity<InputState>,
+    decimals: Entity<InputState>,
+    seed: Entity<InputState>,
+    generated: Entity<InputState>,
+    kind: NumberKind,
+    distribution: Distribution,
+    unique: bool,
+    output_format: OutputFormat,
+    error: Option<ToolError>,
+}
+
+impl RandomNumberGeneratorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let min = cx.new(|cx| InputState::new(window, cx).default_value("1"));
+        let max = cx.new(|cx| InputState::new(window, cx).default_value("100"));
+        let count = cx.new(|cx| InputState::new(window, cx).default_value("10"));
+        let decimals = cx.new(|cx| InputState::new(window, cx).default_value("2"));
+        let seed = cx.new(|cx| InputState::new(window, cx).placeholder("Random"));
+        let generated = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Generated Numbers")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            min,
+            max,
+            count,
+            decimals,
+            seed,
+            generated,
+            kind: NumberKind::Integer,
+            distribution: Distribution::Uniform,
+            unique: false,
+            output_format: OutputFormat::Lines,
+            error: None,
+        }
+    }
+
+    fn settings(&self, cx: &App) -> Result<Settings, ToolError> {
+        let parse_bound = |state: &Entity<InputState>, name: &str| {
+            let value = state.read(cx).value();
+            match self.kind {
+                NumberKind::Integer => value.trim().parse::<i64>().map(|v| v as f64).ok(),
+                NumberKind::Float => value.trim().parse::<f64>().ok().filter(|v| v.is_finite()),
+            }
+            .ok_or_else(|| ToolError::new(format!("{} must be a number", name)))
+        };
+        let min = parse_bound(&self.min, "Minimum")?;
+        let max = parse_bound(&self.max, "Maximum")?;
+        if min > max {
+            return Err(ToolError::new("Minimum is greater than maximum"));
+        }
+        let count = match self.count.read(cx).value().trim().parse::<usize>() {
+            Ok(count) if count > 0 => count,
+            _ => return Err(ToolError::new("Count must be a positive number")),
+        };
+        let decimals = match self.kind {
+            NumberKind::Integer => 0,
+            NumberKind::Float => match self.decimals.read(cx).value().trim().parse::<usize>() {
+                Ok(decimals) if decimals <= 15 => decimals,
+                _ => return Err(ToolError::new("Decimals must be between 0 and 15")),
+            },
+        };
+        let seed = self.seed.read(cx).value();
+        let seed = match seed.trim() {
+            "" => None,
+            seed => Some(
+                seed.parse::<u64>()
+                    .map_err(|_| ToolError::new("Seed must be a non-negative integer"))?,
+            ),
+        };
+        Ok(Settings {
+            kind: self.kind,
+            distribution: self.distribution,
+            unique: self.unique,
+            min,
+            max,
+            count,
+            decimals,
+            seed,
+        })
+    }
+
+    fn on_generate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let separator = match self.output_format {
+            OutputFormat::Lines => "\n",
+            OutputFormat::Csv => ",",
+        };
+        let generated_value = match self.settings(cx).and_then(|settings| generate(&settings)) {
+            Ok(numbers) => {
+                self.error = None;
+                numbers.join(separator)
+            }
+            Err(err) => {
+                self.error = Some(err);
+                String::new()
+            }
+        };
+        self.generated.update(cx, |state, cx| {
+            state.set_value(SharedString::from(generated_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.generated.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_number_kind(
+        &mut self,
+        action: &SetNumberKind,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.kind = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_distribution(
+        &mut self,
+        action: &SetDistribution,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.distribution = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_unique(&mut self, action: &SetUnique, _: &mut Window, cx: &mut Context<Self>) {
+        self.unique = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_output_format(
+        &mut self,
+        action: &SetOutputFormat,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.output_format = action.0;
+        cx.notify();
+    }
+}
+
+fn generate(settings: &Settings) -> Result<Vec<String>, ToolError> {
+    let mut rng = match settings.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    };
+    let format = |value: f64| format!("{:.*}", settings.decimals, value);
+
+    if !settings.unique {
+        return Ok((0..settings.count)
+            .map(|_| format(sample(settings, &mut rng)))
+            .collect());
+    }
+
+    // A uniform pick of most of a small integer range is faster as a shuffle
+    // than by retrying duplicates.
+    if settings.kind == NumberKind::Integer {
+        let size = settings.max - settings.min + 1.;
+        if (settings.count as f64) > size {
+            return Err(ToolError::new(format!(
+                "Cannot pick {} unique integers from a range of {}",
+                settings.count, size
+            )));
+        }
+        if settings.distribution == Distribution::Uniform && (settings.count as f64) * 2. > size {
+            let mut values: Vec<i64> = (settings.min as i64..=settings.max as i64).collect();
+            values.shuffle(&mut rng);
+            values.truncate(settings.count);
+            return Ok(values.iter().map(|value| value.to_string()).collect());
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut values = Vec::with_capacity(settings.count);
+    let mut attempts = settings.count * 100 + 1000;
+    while values.len() < settings.count {
+        if attempts == 0 {
+            return Err(ToolError::new(format!(
+                "Could only find {} unique values in this range",
+                values.len()
+            )));
+        }
+        attempts -= 1;
+        let value = format(sample(settings, &mut rng));
+        if seen.insert(value.clone()) {
+            values.push(value);
+        }
+    }
+    Ok(values)
+}
+
+fn sample(settings: &Settings, rng: &mut StdRng) -> f64 {
+    let (min, max) = (settings.min, settings.max);
+    match settings.distribution {
+        Distribution::Uniform => match settings.kind {
+            NumberKind::Integer => rng.random_range(min as i64..=max as i64) as f64,
+            NumberKind::Float => rng.random_range(min..=max),
+        },
+        // Centred on the middle of the range, with the range spanning six
+        // standard deviations. Draws outside the range are redrawn.
+        Distribution::Normal => loop {
+            let value = (min + max) / 2. + standard_normal(rng) * (max - min) / 6.;
+            let value = match settings.kind {
+                NumberKind::Integer => value.round(),
+                NumberKind::Float => value,
+            };
+            if (min..=max).contains(&value) {
+                break value;
+            }
+        },
+    }
+}
+
+/// Draws from the standard normal distribution with the Box-Muller transform.
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = 1. - rng.random::<f64>();
+    let u2: f64 = rng.random();
+    (-2. * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+impl Tool for RandomNumberGeneratorTool {
+    fn title() -> &'static str {
+        "Random Number Generator"
+    }
+
+    fn short_title() -> &'static str {
+        "Random Numbers"
+    }
+
+    fn description() -> &'static str {
+        "Generates random integers or floats in a range, optionally unique, normally distributed, or seeded."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_generate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.generated.clone())
+    }
+
+    fn preset(&self, _: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            kind: self.kind,
+            distribution: self.distribution,
+            unique: self.unique,
+            output_format: self.output_format,
+            min: self.min.read(cx).value().to_string(),
+            max: self.max.read(cx).value().to_string(),
+            count: self.count.read(cx).value().to_string(),
+            decimals: self.decimals.read(cx).value().to_string(),
+            seed: self.seed.read(cx).value().to_string(),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_number_kind(&SetNumberKind(preset.kind), window, cx);
+        self.on_action_set_distribution(&SetDistribution(preset.distribution), window, cx);
+        self.on_action_set_unique(&SetUnique(preset.unique), window, cx);
+        self.on_action_set_output_format(&SetOutputFormat(preset.output_format), window, cx);
+        self.min.update(cx, |state, cx| {
+            state.set_value(preset.min, window, cx);
+        });
+        self.max.update(cx, |state, cx| {
+            state.set_value(preset.max, window, cx);
+        });
+        self.count.update(cx, |state, cx| {
+            state.set_value(preset.count, window, cx);
+        });
+        self.decimals.update(cx, |state, cx| {
+            state.set_value(preset.decimals, window, cx);
+        });
+        self.seed.update(cx, |state, cx| {
+            state.set_value(preset.seed, window, cx);
+        });
+        cx.notify();
+    }
+}
+
+impl Focusable for RandomNumberGeneratorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for RandomNumberGeneratorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let kind = self.kind;
+        let distribution = self.distribution;
+        let unique = self.unique;
+        let output_format = self.output_format;
+
+        let field = |label: &'static str, state: &Entity<InputState>| {
+            div()
+                .v_flex()
+                .w_full()
+                .gap_2()
+                .child(Label::new(label))
+                .child(
+                    TextInput::new(state)
+                        .text_size(px(15.))
+                        .focus_bordered(false),
+                )
+        };
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_number_kind))
+            .on_action(cx.listener(Self::on_action_set_distribution))
+            .on_action(cx.listener(Self::on_action_set_unique))
+            .on_action(cx.listener(Self::on_action_set_output_format))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("generate-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("generate-button")
+                                    .label("Generate")
+                                    .primary()
+                                    .on_click(cx.listener(Self::on_generate_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Type")
+                                    .menu_with_check(
+                                        "Integers",
+                                        kind == NumberKind::Integer,
+                                        Box::new(SetNumberKind(NumberKind::Integer)),
+                                    )
+                                    .menu_with_check(
+                                        "Floats",
+                                        kind == NumberKind::Float,
+                                        Box::new(SetNumberKind(NumberKind::Float)),
+                                    )
+                                    .separator()
+                                    .label("Distribution")
+                                    .menu_with_check(
+                                        "Uniform",
+                                        distribution == Distribution::Uniform,
+                                        Box::new(SetDistribution(Distribution::Uniform)),
+                                    )
+                                    .menu_with_check(
+                                        "Normal",
+                                        distribution == Distribution::Normal,
+                                        Box::new(SetDistribution(Distribution::Normal)),
+                                    )
+                                    .separator()
+                                    .label("Output")
+                                    .menu_with_check(
+                                        "One per Line",
+                                        output_format == OutputFormat::Lines,
+                                        Box::new(SetOutputFormat(OutputFormat::Lines)),
+                                    )
+                                    .menu_with_check(
+                                        "Comma-Separated",
+                                        output_format == OutputFormat::Csv,
+                                        Box::new(SetOutputFormat(OutputFormat::Csv)),
+                                    )
+                                    .separator()
+                                    .menu_with_check("Unique", unique, Box::new(SetUnique(!unique)))
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .child(field("Minimum", &self.min))
+                    .child(field("Maximum", &self.max))
+                    .child(field("Count", &self.count))
+                    .when(kind == NumberKind::Float, |this| {
+                        this.child(field("Decimals", &self.decimals))
+                    })
+                    .child(field("Seed", &self.seed)),
+            )
+            .child(
+                TextInput::new(&self.generated)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,209 @@
+use gpui::{App, Entity, Window};
+
+use crate::*;
+
+/// A tool as listed in the sidebar: the group it sits in, and the words it can
+/// be searched by besides its title.
+#[derive(Clone, Copy)]
+pub struct ToolEntry {
+    pub group: &'static str,
+    pub title: &'static str,
+    pub short_title: &'static str,
+    pub klass: &'static str,
+    pub keywords: &'static [&'static str],
+    panel: fn(&mut Window, &mut App) -> Entity<ToolContainer>,
+}
+
+impl ToolEntry {
+    fn new<T: Tool + 'static>(group: &'static str, keywords: &'static [&'static str]) -> Self {
+        Self {
+            group,
+            title: T::title(),
+            short_title: T::short_title(),
+            klass: T::klass(),
+            keywords,
+            panel: ToolContainer::panel::<T>,
+        }
+    }
+
+    pub fn panel(&self, window: &mut Window, cx: &mut App) -> Entity<ToolContainer> {
+        let panel = (self.panel)(window, cx);
+        let keywords = self.keywords;
+        panel.update(cx, |panel, _| panel.keywords = keywords);
+        panel
+    }
+
+    /// Whether the lowercase `query` appears in the tool's title or any of
+    /// its keywords.
+    pub fn matches(&self, query: &str) -> bool {
+        self.title.to_lowercase().contains(query)
+            || self.keywords.iter().any(|keyword| keyword.contains(query))
+    }
+}
+
+/// Every tool, in sidebar order. Groups appear in the order of their first
+/// tool, so keep each group's tools together.
+pub fn registered_tools() -> Vec<ToolEntry> {
+    vec![
+        ToolEntry::new::<Base64EncoderTool>("Base64", &["encode", "base64"]),
+        ToolEntry::new::<Base64DecoderTool>("Base64", &["decode", "base64"]),
+        ToolEntry::new::<ImageBase64Tool>("Base64", &["image", "data url", "png", "css", "embed"]),
+        ToolEntry::new::<DataURLGeneratorTool>("Data URL", &["data uri", "base64", "mime"]),
+        ToolEntry::new::<UnixTimestampConverterTool>("Date/Time", &["epoch", "time", "date"]),
+        ToolEntry::new::<DateParserTool>(
+            "Date/Time",
+            &["iso 8601", "rfc 2822", "rfc 3339", "http date"],
+        ),
+        ToolEntry::new::<TimezoneBrowserTool>("Date/Time", &["tz", "iana", "dst", "offset"]),
+        ToolEntry::new::<TimezoneConverterTool>(
+            "Date/Time",
+            &["tz", "convert", "meeting", "world clock"],
+        ),
+        ToolEntry::new::<ISOWeekCalculatorTool>("Date/Time", &["week number", "calendar"]),
+        ToolEntry::new::<DateCalculatorTool>(
+            "Date/Time",
+            &["duration", "difference", "days between", "age"],
+        ),
+        ToolEntry::new::<DurationParserTool>(
+            "Date/Time",
+            &["humanize", "iso 8601", "elapsed", "milliseconds"],
+        ),
+        ToolEntry::new::<CronParserTool>("Date/Time", &["crontab", "schedule"]),
+        ToolEntry::new::<HTMLEntityTool>("HTML", &["escape", "unescape", "encode", "decode"]),
+        ToolEntry::new::<HTMLFormatterTool>("HTML", &["beautify", "prettify", "minify"]),
+        ToolEntry::new::<HTMLTableExtractorTool>("HTML", &["csv", "markdown", "scrape"]),
+        ToolEntry::new::<HTMLToMarkdownTool>("HTML", &["markdown", "convert", "readability"]),
+        ToolEntry::new::<CSSSelectorTesterTool>("HTML", &["query", "scrape"]),
+        ToolEntry::new::<JSONEncoderTool>("JSON", &["escape", "string", "embed", "literal"]),
+        ToolEntry::new::<JSONDecoderTool>("JSON", &["unescape", "string", "embed", "literal"]),
+        ToolEntry::new::<JSONFormatterTool>("JSON", &["beautify", "prettify", "minify", "sort"]),
+        ToolEntry::new::<JSONViewerTool>("JSON", &["tree", "browse"]),
+        ToolEntry::new::<JSONToCSVTool>("JSON", &["flatten", "spreadsheet"]),
+        ToolEntry::new::<CSVJSONConverterTool>("JSON", &["tsv", "spreadsheet"]),
+        ToolEntry::new::<TOMLJSONConverterTool>("JSON", &["cargo", "config"]),
+        ToolEntry::new::<JSONFakeDataTool>("JSON", &["mock", "sample", "schema"]),
+        ToolEntry::new::<JSONSchemaTool>("JSON", &["validate", "infer"]),
+        ToolEntry::new::<JSONToCodeTool>("JSON", &["rust", "typescript", "golang", "struct"]),
+        ToolEntry::new::<JSONArraySorterTool>("JSON", &["sort", "order"]),
+        ToolEntry::new::<URLEncoderTool>("URL", &["percent", "escape", "encode"]),
+        ToolEntry::new::<URLDecoderTool>("URL", &["percent", "unescape", "decode"]),
+        ToolEntry::new::<URLParserTool>("URL", &["query string", "parameters"]),
+        ToolEntry::new::<QueryStringJSONConverterTool>("URL", &["query string", "form", "params"]),
+        ToolEntry::new::<SlugGeneratorTool>("URL", &["permalink", "seo", "transliterate"]),
+        ToolEntry::new::<MACAddressTool>("Network", &["oui", "ethernet", "eui-64"]),
+        ToolEntry::new::<HTTPClientTool>("Network", &["rest", "api", "request", "curl"]),
+        ToolEntry::new::<RequestBinTool>("Network", &["webhook", "listener", "echo", "server"]),
+        ToolEntry::new::<AuthFlowInspectorTool>("Network", &["saml", "sso", "oauth", "pkce"]),
+        ToolEntry::new::<ProtobufDecoderTool>("Binary", &["grpc", "proto", "wire format"]),
+        ToolEntry::new::<BinaryFormatDecoderTool>("Binary", &["msgpack", "cbor", "json"]),
+        ToolEntry::new::<CompressionTool>(
+            "Binary",
+            &["gzip", "zlib", "deflate", "inflate", "brotli"],
+        ),
+        ToolEntry::new::<TextCharacterCountTool>("Text", &["length", "words", "lines"]),
+        ToolEntry::new::<TextDifferenceTool>("Text", &["diff", "compare", "patch"]),
+        ToolEntry::new::<TextDuplicateLinesTool>("Text", &["unique", "dedupe", "count"]),
+        ToolEntry::new::<TextLinesTool>("Text", &["sort", "shuffle", "reverse", "line numbers"]),
+        ToolEntry::new::<FindReplaceTool>("Text", &["regex", "substitute", "search"]),
+        ToolEntry::new::<TextSimilarityTool>("Text", &["levenshtein", "edit distance"]),
+        ToolEntry::new::<TextTransliterationTool>("Text", &["accents", "ascii", "unicode"]),
+        ToolEntry::new::<UnicodeInspectorTool>("Text", &["code point", "invisible", "bidi"]),
+        ToolEntry::new::<EmojiPickerTool>(
+            "Text",
+            &[
+                "emoji",
+                "symbols",
+                "shortcode",
+                "arrows",
+                "box drawing",
+                "math",
+            ],
+        ),
+        ToolEntry::new::<TextBinaryConverterTool>("Text", &["hex", "bytes", "decimal"]),
+        ToolEntry::new::<EncodingConverterTool>("Text", &["utf-8", "utf-16", "latin-1", "charset"]),
+        ToolEntry::new::<TemplateRendererTool>("Text", &["handlebars", "mustache"]),
+        ToolEntry::new::<TextColumnAlignerTool>("Text", &["table", "columns"]),
+        ToolEntry::new::<TextIndentationTool>("Text", &["tabs", "spaces", "indent"]),
+        ToolEntry::new::<TextLineEndingsTool>("Text", &["crlf", "lf", "newline", "bom"]),
+        ToolEntry::new::<TextANSITool>("Text", &["terminal", "colour", "color", "escape"]),
+        ToolEntry::new::<StringEscapeTool>("Text", &["quote", "unescape", "literal", "shell"]),
+        ToolEntry::new::<LogViewerTool>("Text", &["logs", "filter"]),
+        ToolEntry::new::<SymbolDemanglerTool>("Text", &["rust", "c++", "backtrace"]),
+        ToolEntry::new::<XPathTesterTool>("XML", &["query", "html"]),
+        ToolEntry::new::<XMLFormatterTool>("XML", &["beautify", "prettify", "validate"]),
+        ToolEntry::new::<AgeEncryptionTool>("Crypto", &["encrypt", "decrypt", "passphrase"]),
+        ToolEntry::new::<ChecksumTool>("Crypto", &["crc32", "adler", "xxhash", "hmac", "webhook"]),
+        ToolEntry::new::<CertificateInspectorTool>("Crypto", &["tls", "ssl", "x509", "pem", "csr"]),
+        ToolEntry::new::<PasswordHashTool>("Crypto", &["bcrypt", "argon2", "verify"]),
+        ToolEntry::new::<SymmetricCryptoTool>("Crypto", &["aes", "chacha20", "aead", "pbkdf2"]),
+        ToolEntry::new::<MarkdownTOCTool>("Markdown", &["toc", "headings", "anchors"]),
+        ToolEntry::new::<MarkdownTableTool>("Markdown", &["table", "csv", "tsv", "align"]),
+        ToolEntry::new::<PathConverterTool>("Path", &["windows", "unix", "wsl", "file url"]),
+        ToolEntry::new::<GitignoreTesterTool>("Path", &["ignore", "glob"]),
+        ToolEntry::new::<NanoidGeneratorTool>("Generators", &["id", "random"]),
+        ToolEntry::new::<PasswordGeneratorTool>("Generators", &["random", "secret"]),
+        ToolEntry::new::<RandomNumberGeneratorTool>("Generators", &["random", "dice"]),
+        ToolEntry::new::<UUIDGeneratorTool>("Generators", &["guid", "id", "random"]),
+        ToolEntry::new::<IDInspectorTool>("Generators", &["uuid", "ulid", "snowflake", "decode"]),
+        ToolEntry::new::<FigletTool>("Generators", &["figlet", "banner", "ascii art", "text art"]),
+        ToolEntry::new::<JWTDecoderTool>("JWT", &["token", "bearer", "verify"]),
+        ToolEntry::new::<ColorConverterTool>("Color", &["colour", "hex", "rgb", "hsl"]),
+        ToolEntry::new::<ContrastCheckerTool>(
+            "Color",
+            &["wcag", "accessibility", "a11y", "contrast ratio"],
+        ),
+        ToolEntry::new::<CSSUnitConverterTool>(
+            "CSS",
+            &["px", "rem", "em", "pt", "clamp", "fluid typography"],
+        ),
+        ToolEntry::new::<CSSGradientTool>(
+            "CSS",
+            &[
+                "linear-gradient",
+                "radial-gradient",
+                "tailwind",
+                "background",
+            ],
+        ),
+        ToolEntry::new::<CSSShadowTool>(
+            "CSS",
+            &["box-shadow", "border-radius", "drop shadow", "rounded"],
+        ),
+        ToolEntry::new::<NumberBaseConverterTool>("Number", &["hex", "binary", "octal"]),
+        ToolEntry::new::<NumberFormatsTool>(
+            "Number",
+            &["roman numerals", "words", "spell", "ordinal"],
+        ),
+        ToolEntry::new::<SQLFormatterTool>("SQL", &["beautify", "prettify", "query"]),
+        ToolEntry::new::<SettingsTool>("Settings", &["preferences", "theme", "dark mode", "font"]),
+    ]
+}
+
+/// The tools grouped as in the sidebar.
+pub fn tool_groups() -> Vec<(&'static str, Vec<ToolEntry>)> {
+    let mut groups: Vec<(&'static str, Vec<ToolEntry>)> = Vec::new();
+    for tool in registered_tools() {
+        match groups.iter_mut().find(|(group, _)| *group == tool.group) {
+            Some((_, tools)) => tools.push(tool),
+            None => groups.push((tool.group, vec![tool])),
+        }
+    }
+    groups
+}
+
+/// Finds the tool named on the command line, by its title, short title, or
+/// class name, ignoring case. Falls back to the first tool that matches the
+/// name as a search query.
+pub fn find_tool(name: &str) -> Option<ToolEntry> {
+    let name = name.trim().to_lowercase();
+    let tools = registered_tools();
+    tools
+        .iter()
+        .find(|tool| {
+            [tool.title, tool.short_title, tool.klass]
+                .iter()
+                .any(|candidate| candidate.to_lowercase() == name)
+        })
+        .or_else(|| tools.iter().find(|tool| tool.matches(&name)))
+        .copied()
+}
@@ -0,0 +1,413 @@
+use std::{net::TcpListener, time::Duration};
+
+use chrono::{DateTime, Local};
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, StatefulInteractiveElement, Styled,
+    Task, Window, div, prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, ListItem, StyledExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::http::{self, Captured},
+    default_indentation, editor_font,
+};
+
+/// How often the listener checks for new connections.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How many requests are kept before the oldest are dropped.
+const MAX_REQUESTS: usize = 500;
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    port: String,
+}
+
+struct Received {
+    at: DateTime<Local>,
+    request: Captured,
+}
+
+pub struct RequestBinTool {
+    focus_handle: FocusHandle,
+    port: Entity<InputState>,
+    /// The port being listened on, while the listener runs.
+    listening: Option<u16>,
+    requests: Vec<Received>,
+    selected: Option<usize>,
+    headers: Entity<InputState>,
+    body: Entity<InputState>,
+    error: Option<ToolError>,
+    _listener: Option<Task<()>>,
+}
+
+impl RequestBinTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let port = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value("8080")
+                .placeholder("Port")
+        });
+        let headers = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Request Headers")
+        });
+        let body = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Request Body")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            port,
+            listening: None,
+            requests: Vec::new(),
+            selected: None,
+            headers,
+            body,
+            error: None,
+            _listener: None,
+        }
+    }
+
+    fn start(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let port = match self.port.read(cx).value().trim().parse::<u16>() {
+            Ok(port) if port > 0 => port,
+            _ => {
+                self.error = Some(
+                    ToolError::new("Expected a number from 1 to 65535").context("Invalid port"),
+                );
+                cx.notify();
+                return;
+            }
+        };
+        let listener = match TcpListener::bind(("127.0.0.1", port))
+            .and_then(|listener| listener.set_nonblocking(true).map(|_| listener))
+        {
+            Ok(listener) => listener,
+            Err(err) => {
+                self.error = Some(
+                    ToolError::new(err.to_string())
+                        .context(&format!("Could not listen on port {}", port)),
+                );
+                cx.notify();
+                return;
+            }
+        };
+
+        // The listener is owned by the task, so dropping the task on stop
+        // also closes the port.
+        self._listener = Some(cx.spawn_in(window, async move |this, cx| {
+            loop {
+                cx.background_executor().timer(POLL_INTERVAL).await;
+                while let Ok((stream, _)) = listener.accept() {
+                    let result = cx
+                        .background_executor()
+                        .spawn(async move { http::serve(stream) })
+                        .await;
+                    let updated = this.update_in(cx, |this, window, cx| {
+                        this.on_request(result, window, cx);
+                    });
+                    if updated.is_err() {
+                        return;
+                    }
+                }
+            }
+        }));
+        self.listening = Some(port);
+        self.error = None;
+        cx.notify();
+    }
+
+    fn stop(&mut self, cx: &mut Context<Self>) {
+        self._listener = None;
+        self.listening = None;
+        cx.notify();
+    }
+
+    fn on_request(
+        &mut self,
+        result: Result<Captured, ToolError>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match result {
+            Ok(request) => {
+                self.requests.push(Received {
+                    at: Local::now(),
+                    request,
+                });
+                if self.requests.len() > MAX_REQUESTS {
+                    self.requests.remove(0);
+                }
+                self.select(self.requests.len() - 1, window, cx);
+            }
+            Err(err) => {
+                self.error = Some(err);
+                cx.notify();
+            }
+        }
+    }
+
+    fn select(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(received) = self.requests.get(index) else {
+            return;
+        };
+        let request = &received.request;
+        let headers = std::iter::once(format!(
+            "{} {} {}",
+            request.method, request.target, request.version
+        ))
+        .chain(
+            request
+                .headers
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value)),
+        )
+        .collect::<Vec<_>>()
+        .join("\n");
+        let (body, json) = http::format_body(
+            &request.body,
+            request.header("content-type"),
+            default_indentation(cx),
+        );
+        self.selected = Some(index);
+        self.headers.update(cx, |state, cx| {
+            state.set_value(SharedString::from(headers), window, cx);
+        });
+        let language = if json {
+            Language::Json
+        } else {
+            Language::Plain
+        };
+        self.body.update(cx, |state, cx| {
+            state.set_highlighter(language.name().to_string(), cx);
+            state.set_value(SharedString::from(body), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_start_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if self.listening.is_some() {
+            self.stop(cx);
+        } else {
+            self.start(window, cx);
+        }
+    }
+
+    fn on_clear_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.requests.clear();
+        self.selected = None;
+        self.error = None;
+        for state in [&self.headers, &self.body] {
+            state.update(cx, |state, cx| {
+                state.set_value("", window, cx);
+            });
+        }
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.body.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+}
+
+impl Tool for RequestBinTool {
+    fn title() -> &'static str {
+        "Request Bin"
+    }
+
+    fn short_title() -> &'static str {
+        "Bin"
+    }
+
+    fn description() -> &'static str {
+        "Listens for HTTP requests on a local port and lists each one with its headers and body, for debugging webhooks."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_start_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.body.clone())
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, _: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            port: self.port.read(cx).value().to_string(),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.port.update(cx, |state, cx| {
+            state.set_value(preset.port, window, cx);
+        });
+        cx.notify();
+    }
+}
+
+impl Focusable for RequestBinTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for RequestBinTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let muted = cx.theme().muted_foreground;
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div().w(px(100.)).child(
+                            TextInput::new(&self.port)
+                                .text_size(px(15.))
+                                .disabled(self.listening.is_some())
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        Button::new("start-button")
+                            .label(if self.listening.is_some() {
+                                "Stop"
+                            } else {
+                                "Listen"
+                            })
+                            .primary()
+                            .on_click(cx.listener(Self::on_start_click)),
+                    )
+                    .child(
+                        Button::new("clear-button")
+                            .label("Clear")
+                            .disabled(self.requests.is_empty())
+                            .on_click(cx.listener(Self::on_clear_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(Label::new(match self.listening {
+                Some(port) => format!(
+                    "Listening on http://127.0.0.1:{}/ · {} requests",
+                    port,
+                    self.requests.len()
+                ),
+                None => "Not listening".to_string(),
+            }))
+            .child(
+                h_flex()
+                    .flex_1()
+                    .gap_2()
+                    .items_start()
+                    .child(
+                        div()
+                            .id("requests")
+                            .v_flex()
+                            .w(px(360.))
+                            .h_full()
+                            .overflow_y_scroll()
+                            .children(self.requests.iter().enumerate().rev().map(
+                                |(i, received)| {
+                                    ListItem::new(("request", i))
+                                        .selected(self.selected == Some(i))
+                                        .on_click(cx.listener(move |this, _, window, cx| {
+                                            this.select(i, window, cx);
+                                        }))
+                                        .child(
+                                            h_flex()
+                                                .gap_2()
+                                                .child(div().text_color(muted).child(
+                                                    received.at.format("%H:%M:%S").to_string(),
+                                                ))
+                                                .child(received.request.method.clone())
+                                                .child(
+                                                    div()
+                                                        .font_family(editor_font(cx))
+                                                        .truncate()
+                                                        .child(received.request.target.clone()),
+                                                ),
+                                        )
+                                },
+                            )),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .flex_1()
+                            .h_full()
+                            .gap_2()
+                            .child(
+                                TextInput::new(&self.headers)
+                                    .h(px(160.))
+                                    .font_family(editor_font(cx))
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            )
+                            .child(
+                                TextInput::new(&self.body)
+                                    .h_full()
+                                    .font_family(editor_font(cx))
+                                    .text_size(px(15.))
+                                    .focus_bordered(false),
+                            ),
+                    ),
+            )
+            .when(
+                self.requests.is_empty() && self.listening.is_some(),
+                |this| this.child(div().text_color(muted).child("Waiting for requests…")),
+            )
+    }
+}
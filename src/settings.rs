@@ -0,0 +1,131 @@
+use anyhow::Result;
+
+use gpui::{App, Global, SharedString, Window, px};
+use gpui_component::{Theme, ThemeMode};
+use serde::{Deserialize, Serialize};
+
+use crate::{Presets, config};
+
+const DEFAULT_UI_FONT: &str = "Space Grotesk";
+const DEFAULT_EDITOR_FONT: &str = "Space Mono";
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Appearance {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+/// App-wide preferences, kept in `settings.json` in the app's config
+/// directory and applied on launch.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub appearance: Appearance,
+    pub ui_font_family: String,
+    pub ui_font_size: f32,
+    pub editor_font_family: String,
+    /// The indentation formatters start with, in spaces.
+    pub indentation: usize,
+    /// The class of the tool to open on launch. Without one, the tool that
+    /// was open when the app last quit is opened again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub startup_tool: Option<String>,
+    /// Whether to look at the clipboard when the app gains focus and suggest
+    /// a tool for what is on it.
+    pub smart_paste: bool,
+    /// The classes of the tools starred as favorites, in the order starred.
+    pub favorites: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            appearance: Appearance::System,
+            ui_font_family: DEFAULT_UI_FONT.to_string(),
+            ui_font_size: 17.,
+            editor_font_family: DEFAULT_EDITOR_FONT.to_string(),
+            indentation: 2,
+            startup_tool: None,
+            smart_paste: false,
+            favorites: Vec::new(),
+        }
+    }
+}
+
+impl Global for Settings {}
+
+/// What an export holds: the settings along with every saved preset.
+#[derive(Serialize)]
+struct Export<'a> {
+    settings: &'a Settings,
+    presets: &'a Presets,
+}
+
+impl Settings {
+    /// Loads the saved settings, falling back to the defaults if there are
+    /// none or the file cannot be read.
+    pub fn load() -> Self {
+        config::load("settings.json")
+    }
+
+    pub fn save(&self) -> Result<()> {
+        config::save("settings.json", self)
+    }
+
+    /// The current settings and presets as pretty-printed JSON, for carrying
+    /// them over to another machine.
+    pub fn export(cx: &App) -> Result<String> {
+        let settings = cx.try_global::<Settings>().cloned().unwrap_or_default();
+        let empty = Presets::default();
+        let presets = cx.try_global::<Presets>().unwrap_or(&empty);
+        Ok(serde_json::to_string_pretty(&Export {
+            settings: &settings,
+            presets,
+        })?)
+    }
+
+    /// Changes the settings with `f`, then saves and applies them.
+    pub fn update(window: &mut Window, cx: &mut App, f: impl FnOnce(&mut Settings)) {
+        let mut settings = cx.try_global::<Settings>().cloned().unwrap_or_default();
+        f(&mut settings);
+        if let Err(err) = settings.save() {
+            eprintln!("Could not save settings: {}", err);
+        }
+        settings.apply(Some(window), cx);
+        cx.set_global(settings);
+        window.refresh();
+    }
+
+    /// Applies the appearance and interface font to the theme.
+    pub fn apply(&self, window: Option<&mut Window>, cx: &mut App) {
+        match self.appearance {
+            Appearance::System => Theme::sync_system_appearance(window, cx),
+            Appearance::Light => Theme::change(ThemeMode::Light, window, cx),
+            Appearance::Dark => Theme::change(ThemeMode::Dark, window, cx),
+        }
+        let theme = Theme::global_mut(cx);
+        theme.font_family = self.ui_font_family.clone().into();
+        theme.font_size = px(self.ui_font_size);
+    }
+}
+
+fn settings(cx: &App) -> Option<&Settings> {
+    cx.try_global::<Settings>()
+}
+
+/// The font family for editors and other monospaced text.
+pub fn editor_font(cx: &App) -> SharedString {
+    settings(cx)
+        .map_or(DEFAULT_EDITOR_FONT, |settings| &settings.editor_font_family)
+        .to_string()
+        .into()
+}
+
+/// The indentation, in spaces, that formatters start with.
+pub fn default_indentation(cx: &App) -> usize {
+    settings(cx).map_or(Settings::default().indentation, |settings| {
+        settings.indentation
+    })
+}
@@ -0,0 +1,356 @@
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, IntoElement,
+    ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    ContextModal, IndexPath, StyledExt,
+    button::Button,
+    dropdown::{Dropdown, DropdownEvent, DropdownState, SearchableVec},
+    h_flex,
+    label::Label,
+    switch::Switch,
+};
+
+use crate::{
+    Tool, registered_tools,
+    settings::{Appearance, Settings},
+};
+
+const APPEARANCES: [(Appearance, &str); 3] = [
+    (Appearance::System, "System"),
+    (Appearance::Light, "Light"),
+    (Appearance::Dark, "Dark"),
+];
+const FONT_SIZES: [f32; 8] = [13., 14., 15., 16., 17., 18., 19., 20.];
+const INDENTATIONS: [usize; 3] = [2, 3, 4];
+const LAST_OPEN_TOOL: &str = "Last Open Tool";
+
+type Choice = Entity<DropdownState<SearchableVec<SharedString>>>;
+
+pub struct SettingsTool {
+    focus_handle: FocusHandle,
+    appearance: Choice,
+    ui_font_family: Choice,
+    ui_font_size: Choice,
+    editor_font_family: Choice,
+    indentation: Choice,
+    startup_tool: Choice,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl SettingsTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let settings = cx.try_global::<Settings>().cloned().unwrap_or_default();
+
+        let mut fonts = cx.text_system().all_font_names();
+        fonts.extend([
+            settings.ui_font_family.clone(),
+            settings.editor_font_family.clone(),
+        ]);
+        fonts.sort_by_key(|font| font.to_lowercase());
+        fonts.dedup();
+
+        let appearance = choice(
+            APPEARANCES.iter().map(|(_, label)| label.to_string()),
+            APPEARANCES
+                .iter()
+                .position(|(appearance, _)| *appearance == settings.appearance),
+            window,
+            cx,
+        );
+        let ui_font_family = choice(
+            fonts.iter().cloned(),
+            fonts
+                .iter()
+                .position(|font| *font == settings.ui_font_family),
+            window,
+            cx,
+        );
+        let ui_font_size = choice(
+            FONT_SIZES.iter().map(|size| font_size_label(*size)),
+            FONT_SIZES
+                .iter()
+                .position(|size| *size == settings.ui_font_size),
+            window,
+            cx,
+        );
+        let editor_font_family = choice(
+            fonts.iter().cloned(),
+            fonts
+                .iter()
+                .position(|font| *font == settings.editor_font_family),
+            window,
+            cx,
+        );
+        let indentation = choice(
+            INDENTATIONS.iter().map(|size| indentation_label(*size)),
+            INDENTATIONS
+                .iter()
+                .position(|size| *size == settings.indentation),
+            window,
+            cx,
+        );
+        let tools = registered_tools();
+        let startup_tool = choice(
+            std::iter::once(LAST_OPEN_TOOL.to_string())
+                .chain(tools.iter().map(|tool| tool.title.to_string())),
+            match &settings.startup_tool {
+                Some(klass) => tools
+                    .iter()
+                    .position(|tool| tool.klass == klass)
+                    .map(|ix| ix + 1),
+                None => Some(0),
+            },
+            window,
+            cx,
+        );
+
+        let mut _subscriptions: Vec<_> = [
+            &appearance,
+            &ui_font_family,
+            &ui_font_size,
+            &editor_font_family,
+            &indentation,
+            &startup_tool,
+        ]
+        .into_iter()
+        .map(|dropdown| cx.subscribe_in(dropdown, window, Self::on_choice_event))
+        .collect();
+        // The appearance can also be switched from the title bar.
+        _subscriptions.push(cx.observe_global_in::<Settings>(window, Self::on_settings_change));
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            appearance,
+            ui_font_family,
+            ui_font_size,
+            editor_font_family,
+            indentation,
+            startup_tool,
+            _subscriptions,
+        }
+    }
+
+    fn on_choice_event(
+        &mut self,
+        _: &Choice,
+        e: &DropdownEvent<SearchableVec<SharedString>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let DropdownEvent::Confirm(_) = e;
+        let chosen = self.settings(cx);
+        Settings::update(window, cx, |settings| *settings = chosen);
+    }
+
+    fn on_settings_change(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let appearance = cx.global::<Settings>().appearance;
+        let Some((_, label)) = APPEARANCES.iter().find(|(a, _)| *a == appearance) else {
+            return;
+        };
+        let label = SharedString::from(*label);
+        self.appearance.update(cx, |dropdown, cx| {
+            if dropdown.selected_value() != Some(&label) {
+                dropdown.set_selected_value(&label, window, cx);
+            }
+        });
+    }
+
+    fn on_export_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let content = match Settings::export(cx) {
+            Ok(content) => content,
+            Err(err) => {
+                window.push_notification(format!("Could not export settings: {}", err), cx);
+                return;
+            }
+        };
+        let directory = dirs::home_dir()
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        let path = cx.prompt_for_new_path(&directory, Some("utools-settings.json"));
+        cx.spawn_in(window, async move |_, cx| {
+            if let Ok(Ok(Some(path))) = path.await
+                && let Err(err) = std::fs::write(path, content)
+            {
+                cx.update(|window, cx| {
+                    window.push_notification(format!("Could not export settings: {}", err), cx);
+                })
+                .ok();
+            }
+        })
+        .detach();
+    }
+
+    /// The settings as chosen, keeping the saved value of any setting left
+    /// unchosen.
+    fn settings(&self, cx: &App) -> Settings {
+        let mut settings = cx.try_global::<Settings>().cloned().unwrap_or_default();
+        if let Some(label) = self.appearance.read(cx).selected_value()
+            && let Some((appearance, _)) = APPEARANCES.iter().find(|(_, l)| *l == label.as_ref())
+        {
+            settings.appearance = *appearance;
+        }
+        if let Some(font) = self.ui_font_family.read(cx).selected_value() {
+            settings.ui_font_family = font.to_string();
+        }
+        if let Some(label) = self.ui_font_size.read(cx).selected_value()
+            && let Some(size) = FONT_SIZES
+                .iter()
+                .find(|size| font_size_label(**size) == *label)
+        {
+            settings.ui_font_size = *size;
+        }
+        if let Some(font) = self.editor_font_family.read(cx).selected_value() {
+            settings.editor_font_family = font.to_string();
+        }
+        if let Some(label) = self.indentation.read(cx).selected_value()
+            && let Some(size) = INDENTATIONS
+                .iter()
+                .find(|size| indentation_label(**size) == *label)
+        {
+            settings.indentation = *size;
+        }
+        if let Some(label) = self.startup_tool.read(cx).selected_value() {
+            settings.startup_tool = registered_tools()
+                .into_iter()
+                .find(|tool| tool.title == label.as_ref())
+                .map(|tool| tool.klass.to_string());
+        }
+        settings
+    }
+}
+
+fn font_size_label(size: f32) -> String {
+    format!("{} px", size)
+}
+
+fn indentation_label(size: usize) -> String {
+    format!("{} Spaces", size)
+}
+
+fn choice(
+    items: impl IntoIterator<Item = String>,
+    selected: Option<usize>,
+    window: &mut Window,
+    cx: &mut App,
+) -> Choice {
+    let items: Vec<SharedString> = items.into_iter().map(SharedString::from).collect();
+    cx.new(|cx| {
+        DropdownState::new(
+            SearchableVec::new(items),
+            selected.map(IndexPath::new),
+            window,
+            cx,
+        )
+    })
+}
+
+impl Tool for SettingsTool {
+    fn title() -> &'static str {
+        "Settings"
+    }
+
+    fn short_title() -> &'static str {
+        "Settings"
+    }
+
+    fn description() -> &'static str {
+        "Changes the appearance, fonts, default indentation, and the tool opened on launch, and exports them along with tool presets."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+}
+
+impl Focusable for SettingsTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+fn setting_row(
+    label: &'static str,
+    hint: &'static str,
+    control: impl IntoElement,
+) -> impl IntoElement {
+    h_flex()
+        .gap_4()
+        .child(
+            div()
+                .v_flex()
+                .w(px(320.))
+                .gap_1()
+                .child(Label::new(label))
+                .child(Label::new(hint).text_sm()),
+        )
+        .child(control)
+}
+
+impl Render for SettingsTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let smart_paste = cx
+            .try_global::<Settings>()
+            .is_some_and(|settings| settings.smart_paste);
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_4()
+            .child(setting_row(
+                "Appearance",
+                "Follow the system, or always use light or dark.",
+                Dropdown::new(&self.appearance).w(px(240.)),
+            ))
+            .child(setting_row(
+                "Interface Font",
+                "The font of menus, buttons, and labels.",
+                Dropdown::new(&self.ui_font_family).w(px(320.)),
+            ))
+            .child(setting_row(
+                "Interface Font Size",
+                "The size of interface text.",
+                Dropdown::new(&self.ui_font_size).w(px(240.)),
+            ))
+            .child(setting_row(
+                "Editor Font",
+                "The font of editors and other code.",
+                Dropdown::new(&self.editor_font_family).w(px(320.)),
+            ))
+            .child(setting_row(
+                "Default Indentation",
+                "The indentation formatters start with, from the next launch.",
+                Dropdown::new(&self.indentation).w(px(240.)),
+            ))
+            .child(setting_row(
+                "Startup Tool",
+                "The tool opened on launch.",
+                Dropdown::new(&self.startup_tool).w(px(320.)),
+            ))
+            .child(setting_row(
+                "Smart Paste",
+                "Suggest a tool for what is on the clipboard when the app gains focus.",
+                Switch::new("smart-paste-switch")
+                    .checked(smart_paste)
+                    .on_click(|checked, window, cx| {
+                        Settings::update(window, cx, |settings| settings.smart_paste = *checked);
+                    }),
+            ))
+            .child(setting_row(
+                "Export Settings",
+                "Save these settings and every tool preset to a JSON file.",
+                Button::new("export-button")
+                    .label("Export…")
+                    .on_click(cx.listener(Self::on_export_click)),
+            ))
+    }
+}
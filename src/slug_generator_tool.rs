@@ -0,0 +1,330 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool,
+    core::slug::{self, Options, Separator},
+    editor_font,
+};
+
+/// The limits offered for the length of a slug.
+const MAX_LENGTHS: [usize; 5] = [30, 50, 60, 80, 100];
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = url_tools, no_json)]
+pub struct SetSlugSeparator(Separator);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = url_tools, no_json)]
+pub struct SetSlugMaxLength(Option<usize>);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = url_tools, no_json)]
+pub struct SetSlugLowercase(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    options: Options,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct SlugGeneratorTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    generated: Entity<InputState>,
+    options: Options,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl SlugGeneratorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Titles, One per Line")
+        });
+        let generated = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Slugs")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            generated,
+            options: Options::default(),
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_generated(window, cx);
+        }
+    }
+
+    fn update_generated(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let generated_value = value
+            .lines()
+            .map(|line| slug::slugify(line, &self.options))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.generated.update(cx, |state, cx| {
+            state.set_value(SharedString::from(generated_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_generate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_generated(window, cx);
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_generated_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.generated.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_slug_separator(
+        &mut self,
+        action: &SetSlugSeparator,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.options.separator = action.0;
+        self.update_generated(window, cx);
+    }
+
+    fn on_action_set_slug_max_length(
+        &mut self,
+        action: &SetSlugMaxLength,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.options.max_length = action.0;
+        self.update_generated(window, cx);
+    }
+
+    fn on_action_set_slug_lowercase(
+        &mut self,
+        action: &SetSlugLowercase,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.options.lowercase = action.0;
+        self.update_generated(window, cx);
+    }
+}
+
+impl Tool for SlugGeneratorTool {
+    fn title() -> &'static str {
+        "Slug Generator"
+    }
+
+    fn short_title() -> &'static str {
+        "Slug"
+    }
+
+    fn description() -> &'static str {
+        "Turns titles into URL slugs as you type, transliterating accented and non-Latin letters."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_generate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_generated_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.generated.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            options: self.options,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.options = preset.options;
+        match preset.input {
+            Some(input) => self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            }),
+            None => self.update_generated(window, cx),
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for SlugGeneratorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for SlugGeneratorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let options = self.options;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_slug_separator))
+            .on_action(cx.listener(Self::on_action_set_slug_max_length))
+            .on_action(cx.listener(Self::on_action_set_slug_lowercase))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("generate-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("generate-button")
+                                    .label("Generate")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_generate_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                let this = Separator::ALL.into_iter().fold(
+                                    this.label("Separator"),
+                                    |this, separator| {
+                                        this.menu_with_check(
+                                            separator.name(),
+                                            options.separator == separator,
+                                            Box::new(SetSlugSeparator(separator)),
+                                        )
+                                    },
+                                );
+                                let this = MAX_LENGTHS.into_iter().fold(
+                                    this.separator().label("Max Length").menu_with_check(
+                                        "No Limit",
+                                        options.max_length.is_none(),
+                                        Box::new(SetSlugMaxLength(None)),
+                                    ),
+                                    |this, max_length| {
+                                        this.menu_with_check(
+                                            format!("{} Characters", max_length),
+                                            options.max_length == Some(max_length),
+                                            Box::new(SetSlugMaxLength(Some(max_length))),
+                                        )
+                                    },
+                                );
+                                this.separator().menu_with_check(
+                                    "Lowercase",
+                                    options.lowercase,
+                                    Box::new(SetSlugLowercase(!options.lowercase)),
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-generated-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_generated_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.generated)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,97 @@
+use base64::{Engine as _, engine::general_purpose};
+
+use crate::{
+    Base64DecoderTool, JSONFormatterTool, JWTDecoderTool, Tool, UnixTimestampConverterTool,
+};
+
+/// Clipboard contents longer than this are left alone rather than inspected.
+const MAX_LEN: usize = 1 << 20;
+
+/// A tool that looks suited to what is on the clipboard.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Suggestion {
+    /// The class of the suggested tool.
+    pub klass: &'static str,
+    /// What the clipboard looks like, to finish "The clipboard looks like …".
+    pub kind: &'static str,
+}
+
+impl Suggestion {
+    fn new<T: Tool>(kind: &'static str) -> Self {
+        Self {
+            klass: T::klass(),
+            kind,
+        }
+    }
+}
+
+/// Suggests the tool most likely wanted for `text`, if it looks like a JWT,
+/// a JSON document, a Unix timestamp, or Base64.
+pub fn suggest_tool(text: &str) -> Option<Suggestion> {
+    let text = text.trim();
+    if text.is_empty() || text.len() > MAX_LEN {
+        return None;
+    }
+
+    if is_jwt(text) {
+        Some(Suggestion::new::<JWTDecoderTool>("a JSON Web Token"))
+    } else if is_json(text) {
+        Some(Suggestion::new::<JSONFormatterTool>("JSON"))
+    } else if is_timestamp(text) {
+        Some(Suggestion::new::<UnixTimestampConverterTool>(
+            "a Unix timestamp",
+        ))
+    } else if is_base64(text) {
+        Some(Suggestion::new::<Base64DecoderTool>("Base64"))
+    } else {
+        None
+    }
+}
+
+/// Three Base64url segments, the first of them a JSON object. The signature
+/// may be empty for unsecured tokens.
+fn is_jwt(text: &str) -> bool {
+    let segments: Vec<&str> = text.split('.').collect();
+    let [header, payload, signature] = segments[..] else {
+        return false;
+    };
+    let base64url = |s: &str| {
+        s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+    };
+    header.starts_with("eyJ")
+        && !payload.is_empty()
+        && [header, payload, signature].into_iter().all(base64url)
+        && general_purpose::URL_SAFE_NO_PAD
+            .decode(header)
+            .is_ok_and(|header| serde_json::from_slice::<serde_json::Value>(&header).is_ok())
+}
+
+/// An object or array, as bare numbers and strings are rarely meant as JSON.
+fn is_json(text: &str) -> bool {
+    (text.starts_with('{') || text.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(text).is_ok()
+}
+
+/// Seconds, or milliseconds, since the epoch for dates from 2001 to 2286.
+fn is_timestamp(text: &str) -> bool {
+    matches!(text.len(), 10 | 13) && text.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Padded standard or URL-safe Base64 that decodes to text. Requiring text
+/// keeps ordinary words, which are often valid Base64 too, from matching.
+fn is_base64(text: &str) -> bool {
+    if text.len() < 8 || !text.len().is_multiple_of(4) || text.contains(char::is_whitespace) {
+        return false;
+    }
+    [general_purpose::STANDARD, general_purpose::URL_SAFE]
+        .iter()
+        .filter_map(|engine| engine.decode(text).ok())
+        .any(|decoded| {
+            std::str::from_utf8(&decoded).is_ok_and(|decoded| {
+                !decoded
+                    .chars()
+                    .any(|c| c.is_control() && c != '\n' && c != '\r' && c != '\t')
+            })
+        })
+}
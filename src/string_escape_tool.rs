@@ -0,0 +1,350 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language as HighlighterLanguage,
+    input::{InputEvent, InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::escape::{self, Language},
+    editor_font,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Direction {
+    Escape,
+    Unescape,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = string_escape_tools, no_json)]
+pub struct SetDirection(Direction);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = string_escape_tools, no_json)]
+pub struct SetLanguage(Language);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    direction: Direction,
+    language: Language,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct StringEscapeTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    converted: Entity<InputState>,
+    direction: Direction,
+    language: Language,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl StringEscapeTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(HighlighterLanguage::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text or Escaped String")
+        });
+        let converted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(HighlighterLanguage::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Converted")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            converted,
+            direction: Direction::Escape,
+            language: Language::JSON,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_converted(window, cx);
+        }
+    }
+
+    fn update_converted(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let result = match self.direction {
+            Direction::Escape => Ok(escape::escape(&value, self.language)),
+            Direction::Unescape => escape::unescape(&value, self.language),
+        };
+        match result {
+            Ok(converted_value) => {
+                self.error = None;
+                self.converted.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(converted_value), window, cx);
+                });
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_converted(window, cx);
+    }
+
+    fn on_swap_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.direction = match self.direction {
+            Direction::Escape => Direction::Unescape,
+            Direction::Unescape => Direction::Escape,
+        };
+        let value = self.converted.read(cx).value().clone();
+        self.editor.update(cx, |state, cx| {
+            state.set_value(value, window, cx);
+        });
+        self.update_converted(window, cx);
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            });
+            self.update_converted(window, cx);
+        }
+    }
+
+    fn on_copy_converted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.converted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_direction(
+        &mut self,
+        action: &SetDirection,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.direction = action.0;
+        self.update_converted(window, cx);
+    }
+
+    fn on_action_set_language(
+        &mut self,
+        action: &SetLanguage,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.language = action.0;
+        self.update_converted(window, cx);
+    }
+}
+
+impl Tool for StringEscapeTool {
+    fn title() -> &'static str {
+        "String Escape/Unescape"
+    }
+
+    fn short_title() -> &'static str {
+        "Escape"
+    }
+
+    fn description() -> &'static str {
+        "Escapes and unescapes strings for JSON, JavaScript, Rust, C, CSV, XML, and shell quoting."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_converted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.converted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            direction: self.direction,
+            language: self.language,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.language = preset.language;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.on_action_set_direction(&SetDirection(preset.direction), window, cx);
+    }
+}
+
+impl Focusable for StringEscapeTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for StringEscapeTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let direction = self.direction;
+        let language = self.language;
+        let label = match direction {
+            Direction::Escape => format!("Escape ({})", language.name()),
+            Direction::Unescape => format!("Unescape ({})", language.name()),
+        };
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_direction))
+            .on_action(cx.listener(Self::on_action_set_language))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("convert-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("convert-button")
+                                    .label(label)
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                let this = this
+                                    .label("Direction")
+                                    .menu_with_check(
+                                        "Escape",
+                                        direction == Direction::Escape,
+                                        Box::new(SetDirection(Direction::Escape)),
+                                    )
+                                    .menu_with_check(
+                                        "Unescape",
+                                        direction == Direction::Unescape,
+                                        Box::new(SetDirection(Direction::Unescape)),
+                                    )
+                                    .label("Language");
+                                Language::ALL.into_iter().fold(this, |this, item| {
+                                    this.menu_with_check(
+                                        item.name(),
+                                        language == item,
+                                        Box::new(SetLanguage(item)),
+                                    )
+                                })
+                            }),
+                    )
+                    .child(
+                        Button::new("swap-button")
+                            .label("Swap")
+                            .on_click(cx.listener(Self::on_swap_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-converted-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_converted_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.converted)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,472 @@
+use std::sync::LazyLock;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, editor_font};
+
+// Rust legacy symbols are Itanium-style `_ZN...E` names, so they are tried
+// with rustc-demangle before falling back to the C++ demangler.
+static SYMBOL_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b_{1,2}(?:ZN|R|Z)[0-9A-Za-z_$.]+").unwrap());
+
+static HASH_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"::h[0-9a-f]{16}\b").unwrap());
+
+static FRAME_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(\d+):\s+(?:0x[0-9a-fA-F]+ - )?(.+?)\s*$").unwrap());
+
+static LOCATION_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s+at (.+?)\s*$").unwrap());
+
+static RUSTC_SOURCE_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^/rustc/[0-9a-f]+/").unwrap());
+
+static REGISTRY_SOURCE_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^.*/\.cargo/registry/src/[^/]+/").unwrap());
+
+const RUNTIME_PREFIXES: &[&str] = &[
+    "std::",
+    "core::",
+    "alloc::",
+    "backtrace::",
+    "rust_begin_unwind",
+    "rust_panic",
+    "__rust",
+    "__libc_start",
+    "_start",
+    "start_thread",
+    "clone",
+    "__clone",
+    "<unknown>",
+];
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetStripHashes(bool);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetCompactFrames(bool);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetHideRuntimeFrames(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    strip_hashes: bool,
+    compact_frames: bool,
+    hide_runtime_frames: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+struct Frame {
+    index: String,
+    symbol: String,
+    location: Option<String>,
+}
+
+enum TraceLine {
+    Frame(Frame),
+    Other(String),
+}
+
+pub struct SymbolDemanglerTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    demangled: Entity<InputState>,
+    strip_hashes: bool,
+    compact_frames: bool,
+    hide_runtime_frames: bool,
+}
+
+impl SymbolDemanglerTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Backtrace or Mangled Symbols")
+        });
+        let demangled = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Demangled")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            demangled,
+            strip_hashes: true,
+            compact_frames: true,
+            hide_runtime_frames: false,
+        }
+    }
+
+    fn on_demangle_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let mut demangled_value = demangle_symbols(&value, self.strip_hashes);
+        if self.compact_frames || self.hide_runtime_frames {
+            demangled_value = format_trace(
+                &demangled_value,
+                self.compact_frames,
+                self.hide_runtime_frames,
+            );
+        }
+        self.demangled.update(cx, |state, cx| {
+            state.set_value(SharedString::from(demangled_value), window, cx);
+        })
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_demangled_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.demangled.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_strip_hashes(
+        &mut self,
+        action: &SetStripHashes,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.strip_hashes = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_compact_frames(
+        &mut self,
+        action: &SetCompactFrames,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.compact_frames = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_hide_runtime_frames(
+        &mut self,
+        action: &SetHideRuntimeFrames,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.hide_runtime_frames = action.0;
+        cx.notify();
+    }
+}
+
+fn demangle_symbols(text: &str, strip_hashes: bool) -> String {
+    let demangled = SYMBOL_PATTERN.replace_all(text, |captures: &Captures| {
+        let symbol = &captures[0];
+        if let Ok(demangled) = rustc_demangle::try_demangle(symbol) {
+            return match strip_hashes {
+                true => format!("{:#}", demangled),
+                false => demangled.to_string(),
+            };
+        }
+        // macOS adds an extra leading underscore to every symbol.
+        let symbol = symbol
+            .strip_prefix('_')
+            .filter(|s| s.starts_with("_Z"))
+            .unwrap_or(symbol);
+        cpp_demangle::Symbol::new(symbol)
+            .ok()
+            .and_then(|symbol| symbol.demangle().ok())
+            .unwrap_or_else(|| captures[0].to_string())
+    });
+    // Backtraces printed with RUST_BACKTRACE=full are already demangled but
+    // keep their hashes.
+    match strip_hashes {
+        true => HASH_PATTERN.replace_all(&demangled, "").to_string(),
+        false => demangled.to_string(),
+    }
+}
+
+fn parse_trace(text: &str) -> Vec<TraceLine> {
+    let mut lines: Vec<TraceLine> = Vec::new();
+    for line in text.lines() {
+        if let Some(captures) = LOCATION_PATTERN.captures(line)
+            && let Some(TraceLine::Frame(frame)) = lines.last_mut()
+            && frame.location.is_none()
+        {
+            frame.location = Some(captures[1].to_string());
+            continue;
+        }
+        match FRAME_PATTERN.captures(line) {
+            Some(captures) => lines.push(TraceLine::Frame(Frame {
+                index: captures[1].to_string(),
+                symbol: captures[2].to_string(),
+                location: None,
+            })),
+            None => lines.push(TraceLine::Other(line.to_string())),
+        }
+    }
+    lines
+}
+
+fn format_trace(text: &str, compact: bool, hide_runtime: bool) -> String {
+    let lines = parse_trace(text);
+    let mut out: Vec<String> = Vec::new();
+    let mut hidden = 0;
+    let flush_hidden = |out: &mut Vec<String>, hidden: &mut usize| {
+        if *hidden > 0 {
+            out.push(match *hidden {
+                1 => "      ... 1 runtime frame hidden".to_string(),
+                n => format!("      ... {} runtime frames hidden", n),
+            });
+            *hidden = 0;
+        }
+    };
+    for line in lines {
+        match line {
+            TraceLine::Frame(frame) => {
+                if hide_runtime && is_runtime_frame(&frame.symbol) {
+                    hidden += 1;
+                    continue;
+                }
+                flush_hidden(&mut out, &mut hidden);
+                match (compact, &frame.location) {
+                    (true, Some(location)) => out.push(format!(
+                        "{:>4}: {}  ({})",
+                        frame.index,
+                        frame.symbol,
+                        shorten_location(location)
+                    )),
+                    (true, None) => out.push(format!("{:>4}: {}", frame.index, frame.symbol)),
+                    (false, location) => {
+                        out.push(format!("{:>4}: {}", frame.index, frame.symbol));
+                        if let Some(location) = location {
+                            out.push(format!("             at {}", location));
+                        }
+                    }
+                }
+            }
+            TraceLine::Other(line) => {
+                flush_hidden(&mut out, &mut hidden);
+                out.push(line);
+            }
+        }
+    }
+    flush_hidden(&mut out, &mut hidden);
+    out.join("\n")
+}
+
+fn is_runtime_frame(symbol: &str) -> bool {
+    // Trait impls print as `<T as core::ops::FnOnce>::call_once`.
+    let symbol = symbol.trim_start_matches('<');
+    RUNTIME_PREFIXES
+        .iter()
+        .any(|prefix| symbol.starts_with(prefix))
+}
+
+fn shorten_location(location: &str) -> String {
+    let location = RUSTC_SOURCE_PATTERN.replace(location, "<rustc>/");
+    REGISTRY_SOURCE_PATTERN
+        .replace(&location, "<registry>/")
+        .to_string()
+}
+
+impl Tool for SymbolDemanglerTool {
+    fn title() -> &'static str {
+        "Symbol Demangler"
+    }
+
+    fn short_title() -> &'static str {
+        "Demangler"
+    }
+
+    fn description() -> &'static str {
+        "Demangles Rust and C++ symbols and tidies up pasted backtraces."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_demangle_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_demangled_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.demangled.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            strip_hashes: self.strip_hashes,
+            compact_frames: self.compact_frames,
+            hide_runtime_frames: self.hide_runtime_frames,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_strip_hashes(&SetStripHashes(preset.strip_hashes), window, cx);
+        self.on_action_set_compact_frames(&SetCompactFrames(preset.compact_frames), window, cx);
+        self.on_action_set_hide_runtime_frames(
+            &SetHideRuntimeFrames(preset.hide_runtime_frames),
+            window,
+            cx,
+        );
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for SymbolDemanglerTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for SymbolDemanglerTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let strip_hashes = self.strip_hashes;
+        let compact_frames = self.compact_frames;
+        let hide_runtime_frames = self.hide_runtime_frames;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_strip_hashes))
+            .on_action(cx.listener(Self::on_action_set_compact_frames))
+            .on_action(cx.listener(Self::on_action_set_hide_runtime_frames))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("demangle-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("demangle-button")
+                                    .label("Demangle")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_demangle_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Options")
+                                    .menu_with_check(
+                                        "Strip Hashes",
+                                        strip_hashes,
+                                        Box::new(SetStripHashes(!strip_hashes)),
+                                    )
+                                    .menu_with_check(
+                                        "Compact Frames",
+                                        compact_frames,
+                                        Box::new(SetCompactFrames(!compact_frames)),
+                                    )
+                                    .menu_with_check(
+                                        "Hide Runtime Frames",
+                                        hide_runtime_frames,
+                                        Box::new(SetHideRuntimeFrames(!hide_runtime_frames)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-demangled-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_demangled_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.demangled)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,357 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::cipher::{self, Cipher, KeyDerivation},
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = crypto_tools, no_json)]
+pub struct SetCipher(Cipher);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = crypto_tools, no_json)]
+pub struct SetKeyDerivation(KeyDerivation);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    cipher: Cipher,
+    key_derivation: KeyDerivation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct SymmetricCryptoTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    passphrase: Entity<InputState>,
+    output: Entity<InputState>,
+    cipher: Cipher,
+    key_derivation: KeyDerivation,
+    busy: bool,
+    error: Option<ToolError>,
+}
+
+impl SymmetricCryptoTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text or Base64 Ciphertext")
+        });
+        let passphrase = cx.new(|cx| {
+            InputState::new(window, cx)
+                .masked(true)
+                .placeholder("Passphrase")
+        });
+        let output = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Output")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            passphrase,
+            output,
+            cipher: Cipher::AES256GCM,
+            key_derivation: KeyDerivation::PBKDF2,
+            busy: false,
+            error: None,
+        }
+    }
+
+    fn run<F>(&mut self, f: F, window: &mut Window, cx: &mut Context<Self>)
+    where
+        F: FnOnce() -> Result<String, ToolError> + Send + 'static,
+    {
+        // Key derivation is deliberately slow, so the work happens off the UI
+        // thread.
+        self.busy = true;
+        self.error = None;
+        cx.notify();
+        cx.spawn_in(window, async move |this, cx| {
+            let result = cx.background_executor().spawn(async move { f() }).await;
+            this.update_in(cx, |this, window, cx| {
+                this.busy = false;
+                let output_value = match result {
+                    Ok(output) => output,
+                    Err(err) => {
+                        this.error = Some(err);
+                        String::new()
+                    }
+                };
+                this.output.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(output_value), window, cx);
+                });
+                cx.notify();
+            })
+        })
+        .detach();
+    }
+
+    fn on_encrypt_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let plaintext = self.editor.read(cx).value().to_string();
+        let passphrase = self.passphrase.read(cx).value().to_string();
+        let (cipher, key_derivation) = (self.cipher, self.key_derivation);
+        self.run(
+            move || {
+                cipher::encrypt(
+                    plaintext.as_bytes(),
+                    &passphrase,
+                    cipher,
+                    key_derivation,
+                    &mut rand::rng(),
+                )
+            },
+            window,
+            cx,
+        );
+    }
+
+    fn on_decrypt_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let ciphertext = self.editor.read(cx).value().to_string();
+        let passphrase = self.passphrase.read(cx).value().to_string();
+        let (cipher, key_derivation) = (self.cipher, self.key_derivation);
+        self.run(
+            move || {
+                let plaintext = cipher::decrypt(&ciphertext, &passphrase, cipher, key_derivation)?;
+                Ok(match String::from_utf8(plaintext) {
+                    Ok(text) => text,
+                    Err(err) => format!("Binary output ({} bytes)", err.as_bytes().len()),
+                })
+            },
+            window,
+            cx,
+        );
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_output_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.output.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_cipher(&mut self, action: &SetCipher, _: &mut Window, cx: &mut Context<Self>) {
+        self.cipher = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_key_derivation(
+        &mut self,
+        action: &SetKeyDerivation,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.key_derivation = action.0;
+        cx.notify();
+    }
+}
+
+impl Tool for SymmetricCryptoTool {
+    fn title() -> &'static str {
+        "Symmetric Encryption"
+    }
+
+    fn short_title() -> &'static str {
+        "Encryption"
+    }
+
+    fn description() -> &'static str {
+        "Encrypts and decrypts text with a passphrase using AES-256-GCM or ChaCha20-Poly1305 and PBKDF2 or Argon2id key derivation."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value();
+        let passphrase = self.passphrase.read(cx).value();
+        if self.busy || value.is_empty() || passphrase.is_empty() {
+            return;
+        }
+        self.on_encrypt_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_output_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            cipher: self.cipher,
+            key_derivation: self.key_derivation,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.on_action_set_cipher(&SetCipher(preset.cipher), window, cx);
+        self.on_action_set_key_derivation(&SetKeyDerivation(preset.key_derivation), window, cx);
+    }
+}
+
+impl Focusable for SymmetricCryptoTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for SymmetricCryptoTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let passphrase = self.passphrase.read(cx).value();
+        let disabled = value.is_empty() || passphrase.is_empty();
+        let cipher = self.cipher;
+        let key_derivation = self.key_derivation;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_cipher))
+            .on_action(cx.listener(Self::on_action_set_key_derivation))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("encrypt-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("encrypt-button")
+                                    .label(format!("Encrypt ({})", cipher.name()))
+                                    .primary()
+                                    .loading(self.busy)
+                                    .disabled(disabled || self.busy)
+                                    .on_click(cx.listener(Self::on_encrypt_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                let this = Cipher::ALL.into_iter().fold(
+                                    this.label("Cipher"),
+                                    |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            cipher == option,
+                                            Box::new(SetCipher(option)),
+                                        )
+                                    },
+                                );
+                                KeyDerivation::ALL.into_iter().fold(
+                                    this.separator().label("Key Derivation"),
+                                    |this, option| {
+                                        this.menu_with_check(
+                                            option.name(),
+                                            key_derivation == option,
+                                            Box::new(SetKeyDerivation(option)),
+                                        )
+                                    },
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("decrypt-button")
+                            .label(format!("Decrypt ({})", cipher.name()))
+                            .loading(self.busy)
+                            .disabled(disabled || self.busy)
+                            .on_click(cx.listener(Self::on_decrypt_click)),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(TextInput::new(&self.passphrase).focus_bordered(false))
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(div().text_color(cx.theme().muted_foreground).child(format!(
+                        "Base64 of salt, nonce, and ciphertext; key from {}",
+                        key_derivation.name()
+                    )))
+                    .child(
+                        Button::new("copy-output-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_output_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.output)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,360 @@
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    StyledExt,
+    button::Button,
+    checkbox::Checkbox,
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+};
+
+use handlebars::{Handlebars, no_escape};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    strict: bool,
+    escape_html: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    context: Option<String>,
+}
+
+pub struct TemplateRendererTool {
+    focus_handle: FocusHandle,
+    template: Entity<InputState>,
+    context: Entity<InputState>,
+    rendered: Entity<InputState>,
+    strict: bool,
+    escape_html: bool,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TemplateRendererTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let template = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Html.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Handlebars or Mustache Template")
+        });
+        let context = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Json.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("JSON Context")
+        });
+        let rendered = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 2,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Rendered Output")
+        });
+
+        let _subscriptions = vec![
+            cx.subscribe_in(&template, window, Self::on_input_event),
+            cx.subscribe_in(&context, window, Self::on_input_event),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            template,
+            context,
+            rendered,
+            strict: true,
+            escape_html: true,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_rendered(window, cx);
+        }
+    }
+
+    fn update_rendered(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let template = self.template.read(cx).value().clone();
+        let context = self.context.read(cx).value().clone();
+        let rendered_value =
+            match render_template(&template, &context, self.strict, self.escape_html) {
+                Ok(rendered) => {
+                    self.error = None;
+                    rendered
+                }
+                Err(err) => {
+                    self.error = Some(err);
+                    String::new()
+                }
+            };
+        self.rendered.update(cx, |state, cx| {
+            state.set_value(SharedString::from(rendered_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_strict_click(&mut self, checked: &bool, window: &mut Window, cx: &mut Context<Self>) {
+        self.strict = *checked;
+        self.update_rendered(window, cx);
+    }
+
+    fn on_escape_html_click(
+        &mut self,
+        checked: &bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.escape_html = *checked;
+        self.update_rendered(window, cx);
+    }
+
+    fn on_paste_template_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.template.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_paste_context_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.context.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_rendered_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.rendered.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+}
+
+fn render_template(
+    template: &str,
+    context: &str,
+    strict: bool,
+    escape_html: bool,
+) -> Result<String, ToolError> {
+    let context: Value = if context.trim().is_empty() {
+        Value::Object(Default::default())
+    } else {
+        serde_json::from_str(context)
+            .map_err(|err| ToolError::from(err).context("Invalid JSON context"))?
+    };
+
+    let mut handlebars = Handlebars::new();
+    // Strict mode turns a missing variable into an error instead of silently
+    // rendering an empty string.
+    handlebars.set_strict_mode(strict);
+    if !escape_html {
+        handlebars.register_escape_fn(no_escape);
+    }
+    handlebars
+        .render_template(template, &context)
+        .map_err(|err| ToolError::new(err.to_string()).context("Rendering failed"))
+}
+
+impl Tool for TemplateRendererTool {
+    fn title() -> &'static str {
+        "Template Renderer"
+    }
+
+    fn short_title() -> &'static str {
+        "Template"
+    }
+
+    fn description() -> &'static str {
+        "Renders Handlebars or Mustache templates against a JSON context as you type."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_rendered_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.rendered.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_template_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            strict: self.strict,
+            escape_html: self.escape_html,
+            template: include_input.then(|| self.template.read(cx).value().to_string()),
+            context: include_input.then(|| self.context.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.strict = preset.strict;
+        self.escape_html = preset.escape_html;
+        if let Some(template) = preset.template {
+            self.template.update(cx, |state, cx| {
+                state.set_value(template, window, cx);
+            });
+        }
+        if let Some(context) = preset.context {
+            self.context.update(cx, |state, cx| {
+                state.set_value(context, window, cx);
+            });
+        }
+        self.update_rendered(window, cx);
+    }
+}
+
+impl Focusable for TemplateRendererTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TemplateRendererTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_4()
+                    .child(
+                        Checkbox::new("strict-checkbox")
+                            .label("Strict")
+                            .checked(self.strict)
+                            .on_click(cx.listener(Self::on_strict_click)),
+                    )
+                    .child(
+                        Checkbox::new("escape-html-checkbox")
+                            .label("Escape HTML")
+                            .checked(self.escape_html)
+                            .on_click(cx.listener(Self::on_escape_html_click)),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .ml_auto()
+                            .child(
+                                Button::new("paste-template-button")
+                                    .label("Paste Template")
+                                    .on_click(cx.listener(Self::on_paste_template_click)),
+                            )
+                            .child(
+                                Button::new("paste-context-button")
+                                    .label("Paste Context")
+                                    .on_click(cx.listener(Self::on_paste_context_click)),
+                            ),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .h_full()
+                    .gap_2()
+                    .child(
+                        div().w_full().h_full().child(
+                            TextInput::new(&self.template)
+                                .h_full()
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        div().w_full().h_full().child(
+                            TextInput::new(&self.context)
+                                .h_full()
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    ),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-rendered-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_rendered_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.rendered)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,460 @@
+use std::ops::Range;
+
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable, FontStyle,
+    FontWeight, HighlightStyle, Hsla, InteractiveElement, ParentElement, Render, SharedString,
+    StatefulInteractiveElement, Styled, StyledText, Window, div, prelude::FluentBuilder, px, rems,
+    rgb,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, Size, StyleSized, StyledExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+// The xterm defaults for the 16 basic colours, normal then bright.
+const BASIC_COLORS: [u32; 16] = [
+    0x000000, 0xcd0000, 0x00cd00, 0xcdcd00, 0x0000ee, 0xcd00cd, 0x00cdcd, 0xe5e5e5, 0x7f7f7f,
+    0xff0000, 0x00ff00, 0xffff00, 0x5c5cff, 0xff00ff, 0x00ffff, 0xffffff,
+];
+
+#[derive(Clone, Copy, Default, PartialEq)]
+struct ANSIStyle {
+    fg: Option<u32>,
+    bg: Option<u32>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+    inverse: bool,
+}
+
+impl ANSIStyle {
+    fn apply_sgr(&mut self, params: &str) {
+        let codes: Vec<u32> = params
+            .split([';', ':'])
+            .map(|code| code.parse().unwrap_or(0))
+            .collect();
+        let mut codes = codes.into_iter();
+        while let Some(code) = codes.next() {
+            match code {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                7 => self.inverse = true,
+                9 => self.strikethrough = true,
+                21 | 22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                27 => self.inverse = false,
+                29 => self.strikethrough = false,
+                30..=37 => self.fg = Some(BASIC_COLORS[(code - 30) as usize]),
+                38 => self.fg = extended_color(&mut codes),
+                39 => self.fg = None,
+                40..=47 => self.bg = Some(BASIC_COLORS[(code - 40) as usize]),
+                48 => self.bg = extended_color(&mut codes),
+                49 => self.bg = None,
+                90..=97 => self.fg = Some(BASIC_COLORS[(code - 90 + 8) as usize]),
+                100..=107 => self.bg = Some(BASIC_COLORS[(code - 100 + 8) as usize]),
+                _ => {}
+            }
+        }
+    }
+
+    fn highlight(&self, foreground: Hsla, background: Hsla) -> HighlightStyle {
+        let mut fg = self.fg.map(|color| rgb(color).into());
+        let mut bg = self.bg.map(|color| rgb(color).into());
+        if self.inverse {
+            (fg, bg) = (bg.or(Some(background)), fg.or(Some(foreground)));
+        }
+        HighlightStyle {
+            color: fg,
+            background_color: bg,
+            font_weight: self.bold.then_some(FontWeight::BOLD),
+            font_style: self.italic.then_some(FontStyle::Italic),
+            underline: self.underline.then(Default::default),
+            strikethrough: self.strikethrough.then(Default::default),
+            fade_out: self.dim.then_some(0.4),
+        }
+    }
+}
+
+pub struct TextANSITool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    stripped: Entity<InputState>,
+    preview_text: String,
+    preview_styles: Vec<(Range<usize>, ANSIStyle)>,
+    previewing: bool,
+    error: Option<ToolError>,
+}
+
+impl TextANSITool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 8,
+                    hard_tabs: true,
+                })
+                .default_value("")
+                .placeholder("Terminal Output")
+        });
+        let stripped = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 8,
+                    hard_tabs: true,
+                })
+                .default_value("")
+                .placeholder("Plain Text")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            stripped,
+            preview_text: String::new(),
+            preview_styles: Vec::new(),
+            previewing: false,
+            error: None,
+        }
+    }
+
+    fn on_strip_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let parsed = parse_ansi(&value);
+        self.error = parsed.error();
+        self.stripped.update(cx, |state, cx| {
+            state.set_value(SharedString::from(parsed.text), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_preview_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let parsed = parse_ansi(&value);
+        self.error = parsed.error();
+        (self.preview_text, self.preview_styles) = (parsed.text, parsed.styles);
+        self.previewing = true;
+        cx.notify();
+    }
+
+    fn on_edit_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.previewing = false;
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.previewing = false;
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_stripped_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.stripped.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+}
+
+struct ParsedANSI {
+    text: String,
+    styles: Vec<(Range<usize>, ANSIStyle)>,
+    /// Whether the input ended partway through an escape sequence, as when
+    /// output is cut off.
+    truncated: bool,
+}
+
+impl ParsedANSI {
+    fn error(&self) -> Option<ToolError> {
+        self.truncated
+            .then(|| ToolError::new("The input ends partway through an escape sequence"))
+    }
+}
+
+/// Splits terminal output into its plain text and the styled ranges set by
+/// SGR sequences. Cursor movement, OSC sequences such as hyperlinks and
+/// window titles, and other escapes are dropped.
+fn parse_ansi(input: &str) -> ParsedANSI {
+    let mut text = String::with_capacity(input.len());
+    let mut styles: Vec<(Range<usize>, ANSIStyle)> = Vec::new();
+    let mut style = ANSIStyle::default();
+    let mut truncated = false;
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            let start = text.len();
+            text.push(c);
+            if style == ANSIStyle::default() {
+                continue;
+            }
+            match styles.last_mut() {
+                Some((range, last)) if range.end == start && *last == style => {
+                    range.end = text.len()
+                }
+                _ => styles.push((start..text.len(), style)),
+            }
+            continue;
+        }
+        match chars.next() {
+            Some('[') => {
+                let mut params = String::new();
+                truncated = true;
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        if c == 'm' {
+                            style.apply_sgr(&params);
+                        }
+                        truncated = false;
+                        break;
+                    }
+                    params.push(c);
+                }
+            }
+            Some(']') => {
+                // OSC runs until BEL or the ST sequence `ESC \`.
+                truncated = true;
+                while let Some(c) = chars.next() {
+                    if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                        truncated = false;
+                        break;
+                    }
+                }
+            }
+            Some('(' | ')') => {
+                truncated = chars.next().is_none();
+            }
+            Some(_) => {}
+            None => truncated = true,
+        }
+    }
+    ParsedANSI {
+        text,
+        styles,
+        truncated,
+    }
+}
+
+fn extended_color(codes: &mut impl Iterator<Item = u32>) -> Option<u32> {
+    match codes.next()? {
+        5 => {
+            let n = codes.next()?;
+            Some(match n {
+                0..=15 => BASIC_COLORS[n as usize],
+                16..=231 => {
+                    let level = |v: u32| if v == 0 { 0 } else { v * 40 + 55 };
+                    let n = n - 16;
+                    (level(n / 36) << 16) | (level(n / 6 % 6) << 8) | level(n % 6)
+                }
+                _ => {
+                    let gray = (n.min(255) - 232) * 10 + 8;
+                    (gray << 16) | (gray << 8) | gray
+                }
+            })
+        }
+        2 => {
+            let r = codes.next()?.min(255);
+            let g = codes.next()?.min(255);
+            let b = codes.next()?.min(255);
+            Some((r << 16) | (g << 8) | b)
+        }
+        _ => None,
+    }
+}
+
+impl Tool for TextANSITool {
+    fn title() -> &'static str {
+        "ANSI Escape Viewer"
+    }
+
+    fn short_title() -> &'static str {
+        "ANSI Escapes"
+    }
+
+    fn description() -> &'static str {
+        "Strips ANSI escape codes from terminal output or previews it with colours applied."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_strip_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_stripped_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.stripped.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.editor.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
+}
+
+impl Focusable for TextANSITool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TextANSITool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let foreground = cx.theme().foreground;
+        let background = cx.theme().background;
+        let highlights: Vec<_> = self
+            .preview_styles
+            .iter()
+            .map(|(range, style)| (range.clone(), style.highlight(foreground, background)))
+            .collect();
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("strip-button")
+                            .label("Strip")
+                            .primary()
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_strip_click)),
+                    )
+                    .child(
+                        Button::new("preview-button")
+                            .label("Preview")
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_preview_click)),
+                    )
+                    .when(self.previewing, |this| {
+                        this.child(
+                            Button::new("edit-button")
+                                .label("Edit")
+                                .on_click(cx.listener(Self::on_edit_click)),
+                        )
+                    })
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .when_else(
+                self.previewing,
+                |this| {
+                    this.child(
+                        div()
+                            .id("ansi-preview")
+                            .size_full()
+                            .overflow_y_scroll()
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .line_height(rems(1.25))
+                            .bg(background)
+                            .text_color(foreground)
+                            .rounded(cx.theme().radius)
+                            .border_color(cx.theme().input)
+                            .border_1()
+                            .input_px(Size::default())
+                            .input_py(Size::default())
+                            .child(
+                                StyledText::new(self.preview_text.clone())
+                                    .with_highlights(highlights),
+                            ),
+                    )
+                },
+                |this| {
+                    this.child(
+                        TextInput::new(&self.editor)
+                            .h_full()
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false),
+                    )
+                },
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-stripped-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_stripped_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.stripped)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,409 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, ToolError, editor_font};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum ByteFormat {
+    Binary,
+    Hex,
+    Decimal,
+}
+
+impl ByteFormat {
+    fn format_byte(&self, byte: u8) -> String {
+        match self {
+            ByteFormat::Binary => format!("{:08b}", byte),
+            ByteFormat::Hex => format!("{:02x}", byte),
+            ByteFormat::Decimal => format!("{}", byte),
+        }
+    }
+
+    fn parse_bytes(&self, value: &str) -> Result<Vec<u8>, ToolError> {
+        match self {
+            ByteFormat::Binary => parse_digits(value, 2, 8),
+            ByteFormat::Hex => {
+                let value = value.replace("0x", "").replace("0X", "");
+                parse_digits(&value, 16, 2)
+            }
+            ByteFormat::Decimal => value
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|token| !token.is_empty())
+                .map(|token| {
+                    token.parse::<u8>().map_err(|_| {
+                        ToolError::new(format!("\"{}\" is not a byte between 0 and 255", token))
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetByteFormat(ByteFormat);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetByteGrouping(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    byte_format: ByteFormat,
+    byte_grouping: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct TextBinaryConverterTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    encoded: Entity<InputState>,
+    byte_format: ByteFormat,
+    byte_grouping: bool,
+    error: Option<ToolError>,
+}
+
+impl TextBinaryConverterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text")
+        });
+        let encoded = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Bytes")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            encoded,
+            byte_format: ByteFormat::Binary,
+            byte_grouping: true,
+            error: None,
+        }
+    }
+
+    fn on_encode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let separator = if self.byte_grouping { " " } else { "" };
+        let encoded_value = value
+            .bytes()
+            .map(|byte| self.byte_format.format_byte(byte))
+            .collect::<Vec<_>>()
+            .join(separator);
+        self.error = None;
+        self.encoded.update(cx, |state, cx| {
+            state.set_value(SharedString::from(encoded_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_decode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.encoded.read(cx).value().clone();
+        let bytes = match self.byte_format.parse_bytes(&value) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                self.error = Some(err.context("Invalid bytes"));
+                cx.notify();
+                return;
+            }
+        };
+        self.error = None;
+        let decoded_value = String::from_utf8_lossy(&bytes).to_string();
+        self.editor.update(cx, |state, cx| {
+            state.set_value(SharedString::from(decoded_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_encoded_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.encoded.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_encoded_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.encoded.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_action_set_byte_format(
+        &mut self,
+        action: &SetByteFormat,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.byte_format = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_byte_grouping(
+        &mut self,
+        action: &SetByteGrouping,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.byte_grouping = action.0;
+        cx.notify();
+    }
+}
+
+fn parse_digits(value: &str, radix: u32, width: usize) -> Result<Vec<u8>, ToolError> {
+    // Whitespace is only a visual aid, so grouped and ungrouped input decode
+    // the same way.
+    let digits: Vec<char> = value.chars().filter(|c| !c.is_whitespace()).collect();
+    if let Some(c) = digits.iter().find(|c| !c.is_digit(radix)) {
+        return Err(ToolError::new(format!("unexpected character '{}'", c)));
+    }
+    if !digits.len().is_multiple_of(width) {
+        return Err(ToolError::new(format!(
+            "expected a multiple of {} digits, found {}",
+            width,
+            digits.len()
+        )));
+    }
+    digits
+        .chunks(width)
+        .map(|chunk| {
+            let chunk: String = chunk.iter().collect();
+            Ok(u8::from_str_radix(&chunk, radix)?)
+        })
+        .collect()
+}
+
+impl Tool for TextBinaryConverterTool {
+    fn title() -> &'static str {
+        "Binary Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "Binary"
+    }
+
+    fn description() -> &'static str {
+        "Converts text to and from its binary, hex, or decimal bytes."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_encode_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_encoded_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.encoded.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            byte_format: self.byte_format,
+            byte_grouping: self.byte_grouping,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_byte_format(&SetByteFormat(preset.byte_format), window, cx);
+        self.on_action_set_byte_grouping(&SetByteGrouping(preset.byte_grouping), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for TextBinaryConverterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TextBinaryConverterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let encoded = self.encoded.read(cx).value();
+        let byte_format = self.byte_format;
+        let byte_grouping = self.byte_grouping;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_byte_format))
+            .on_action(cx.listener(Self::on_action_set_byte_grouping))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("encode-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("encode-button")
+                                    .label("Encode")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_encode_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Format")
+                                    .menu_with_check(
+                                        "Binary",
+                                        byte_format == ByteFormat::Binary,
+                                        Box::new(SetByteFormat(ByteFormat::Binary)),
+                                    )
+                                    .menu_with_check(
+                                        "Hex",
+                                        byte_format == ByteFormat::Hex,
+                                        Box::new(SetByteFormat(ByteFormat::Hex)),
+                                    )
+                                    .menu_with_check(
+                                        "Decimal",
+                                        byte_format == ByteFormat::Decimal,
+                                        Box::new(SetByteFormat(ByteFormat::Decimal)),
+                                    )
+                                    .label("Grouping")
+                                    .menu_with_check(
+                                        "Space Between Bytes",
+                                        byte_grouping,
+                                        Box::new(SetByteGrouping(true)),
+                                    )
+                                    .menu_with_check(
+                                        "None",
+                                        !byte_grouping,
+                                        Box::new(SetByteGrouping(false)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("decode-button")
+                            .label("Decode")
+                            .disabled(encoded.is_empty())
+                            .on_click(cx.listener(Self::on_decode_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("copy-encoded-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_encoded_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-encoded-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_encoded_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.encoded)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
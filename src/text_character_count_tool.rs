@@ -1,26 +1,77 @@
-use gpui::prelude::FluentBuilder;
+use std::collections::HashMap;
+
 use gpui::{
     App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
-    InteractiveElement, ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
+    InteractiveElement, ParentElement, Render, SharedString, StatefulInteractiveElement, Styled,
+    Subscription, Window, div, px,
 };
 
 use gpui_component::{
-    button::{Button, ButtonVariants},
-    clipboard::Clipboard,
-    h_flex,
-    input::InputEvent,
-    input::InputState,
-    input::TextInput,
-    label::Label,
-    v_flex,
+    ActiveTheme, button::Button, clipboard::Clipboard, h_flex, input::InputEvent,
+    input::InputState, input::TextInput, label::Label, v_flex,
 };
 
-use crate::Tool;
+use serde_json::Value;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{Tool, editor_font};
+
+#[derive(Default)]
+struct Counts {
+    characters: usize,
+    words: usize,
+    lines: usize,
+    sentences: usize,
+    utf8_bytes: usize,
+    utf16_bytes: usize,
+    /// Each character with the number of times it appears, most frequent
+    /// first.
+    frequency: Vec<(String, usize)>,
+}
+
+impl Counts {
+    /// Counts characters as grapheme clusters, so that an emoji or a letter
+    /// with combining accents counts once however many bytes it takes.
+    fn new(value: &str) -> Self {
+        let mut frequency: HashMap<&str, usize> = HashMap::new();
+        for grapheme in value.graphemes(true) {
+            *frequency.entry(grapheme).or_default() += 1;
+        }
+        let mut frequency: Vec<(String, usize)> = frequency
+            .into_iter()
+            .map(|(grapheme, count)| (grapheme.to_string(), count))
+            .collect();
+        frequency.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        Self {
+            characters: frequency.iter().map(|(_, count)| count).sum(),
+            words: value.unicode_words().count(),
+            lines: value.lines().count(),
+            sentences: value
+                .unicode_sentences()
+                .filter(|sentence| !sentence.trim().is_empty())
+                .count(),
+            utf8_bytes: value.len(),
+            utf16_bytes: value.encode_utf16().count() * 2,
+            frequency,
+        }
+    }
+}
+
+fn display_character(character: &str) -> String {
+    match character {
+        " " => "Space".to_string(),
+        "\t" => "\\t".to_string(),
+        "\n" | "\r\n" => "\\n".to_string(),
+        "\r" => "\\r".to_string(),
+        _ => character.to_string(),
+    }
+}
 
 pub struct TextCharacterCountTool {
     focus_handle: FocusHandle,
     editor: Entity<InputState>,
-    character_count: usize,
+    counts: Counts,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -36,30 +87,22 @@ impl TextCharacterCountTool {
                 .default_value("")
                 .placeholder("Text")
         });
-        let _subscriptions = vec![cx.subscribe(&editor, |this, _, e, cx| match e {
-            InputEvent::Change => {
-                if this.character_count > 0 {
-                    this.character_count = 0;
-                    cx.notify()
-                }
+        let _subscriptions = vec![cx.subscribe(&editor, |this, _, e, cx| {
+            if let InputEvent::Change = e {
+                let value = this.editor.read(cx).value().clone();
+                this.counts = Counts::new(&value);
+                cx.notify()
             }
-            _ => {}
         })];
 
         Self {
             focus_handle: cx.focus_handle(),
             editor,
-            character_count: 0,
+            counts: Counts::default(),
             _subscriptions,
         }
     }
 
-    fn on_count_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-        let value = self.editor.read(cx).value().clone();
-        self.character_count = value.len();
-        cx.notify();
-    }
-
     fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
         let value = self.editor.read(cx).value().clone();
         cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
@@ -85,12 +128,28 @@ impl Tool for TextCharacterCountTool {
     }
 
     fn description() -> &'static str {
-        "Counts characters in any text and display the total."
+        "Counts characters, words, lines, sentences, and bytes in any text as you type."
     }
 
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.editor.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
 }
 
 impl Focusable for TextCharacterCountTool {
@@ -105,30 +164,27 @@ impl Render for TextCharacterCountTool {
         _: &mut gpui::Window,
         cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
-        let character_count = self.character_count;
+        let muted = cx.theme().muted_foreground;
+        let counts = &self.counts;
+        let stats = [
+            ("characters", counts.characters),
+            ("words", counts.words),
+            ("lines", counts.lines),
+            ("sentences", counts.sentences),
+            ("bytes (UTF-8)", counts.utf8_bytes),
+            ("bytes (UTF-16)", counts.utf16_bytes),
+        ];
 
         v_flex()
             .size_full()
             .gap_2()
             .child(
                 h_flex()
-                    .gap_2()
-                    .child(
-                        Button::new("count-button")
-                            .label("Count")
-                            .primary()
-                            .on_click(cx.listener(Self::on_count_click)),
-                    )
-                    .child(div().px_4().when(character_count > 0, |this| {
-                        this.child(
-                            Clipboard::new("count-clipboard")
-                                .content(move |_, _| {
-                                    Label::new(format!("{} characters", character_count))
-                                })
-                                .value_fn({
-                                    move |_, _| SharedString::from(format!("{}", character_count))
-                                }),
-                        )
+                    .gap_4()
+                    .children(stats.into_iter().map(|(label, count)| {
+                        Clipboard::new(SharedString::from(format!("{}-clipboard", label)))
+                            .content(move |_, _| Label::new(format!("{} {}", count, label)))
+                            .value_fn(move |_, _| SharedString::from(count.to_string()))
                     }))
                     .child(
                         Button::new("copy-button")
@@ -143,13 +199,44 @@ impl Render for TextCharacterCountTool {
                     ),
             )
             .child(
-                v_flex().id("source").w_full().flex_1().gap_2().child(
-                    TextInput::new(&self.editor)
-                        .h_full()
-                        .font_family("Space Mono")
-                        .text_size(px(15.))
-                        .focus_bordered(false),
-                ),
+                h_flex()
+                    .w_full()
+                    .flex_1()
+                    .gap_2()
+                    .items_start()
+                    .child(
+                        v_flex().id("source").h_full().flex_1().gap_2().child(
+                            TextInput::new(&self.editor)
+                                .h_full()
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        v_flex()
+                            .id("frequency")
+                            .w(px(200.))
+                            .h_full()
+                            .gap_1()
+                            .overflow_y_scroll()
+                            .child(Label::new("Frequency"))
+                            .children(counts.frequency.iter().map(|(character, count)| {
+                                h_flex()
+                                    .gap_2()
+                                    .child(
+                                        div()
+                                            .flex_1()
+                                            .font_family(editor_font(cx))
+                                            .child(display_character(character)),
+                                    )
+                                    .child(count.to_string())
+                                    .child(div().w(px(56.)).text_color(muted).child(format!(
+                                        "{:.1}%",
+                                        *count as f64 * 100. / counts.characters as f64
+                                    )))
+                            })),
+                    ),
             )
     }
 }
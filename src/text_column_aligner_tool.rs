@@ -0,0 +1,360 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, editor_font};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum ColumnDelimiter {
+    Comma,
+    Pipe,
+    Equals,
+    Colon,
+}
+
+impl ColumnDelimiter {
+    fn as_char(&self) -> char {
+        match self {
+            ColumnDelimiter::Comma => ',',
+            ColumnDelimiter::Pipe => '|',
+            ColumnDelimiter::Equals => '=',
+            ColumnDelimiter::Colon => ':',
+        }
+    }
+
+    // Assignments and key/value pairs only have one meaningful split point;
+    // anything after it belongs to the value.
+    fn splits_once(&self) -> bool {
+        matches!(self, ColumnDelimiter::Equals | ColumnDelimiter::Colon)
+    }
+
+    // Commas and colons hug the text before them, so the padding goes after
+    // the delimiter instead of before it.
+    fn trails_cell(&self) -> bool {
+        matches!(self, ColumnDelimiter::Comma | ColumnDelimiter::Colon)
+    }
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetColumnDelimiter(ColumnDelimiter);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    delimiter: ColumnDelimiter,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct TextColumnAlignerTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    aligned: Entity<InputState>,
+    delimiter: ColumnDelimiter,
+}
+
+impl TextColumnAlignerTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text")
+        });
+        let aligned = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Aligned Text")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            aligned,
+            delimiter: ColumnDelimiter::Equals,
+        }
+    }
+
+    fn on_align_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let aligned_value = align_columns(&value, self.delimiter);
+        self.aligned.update(cx, |state, cx| {
+            state.set_value(SharedString::from(aligned_value), window, cx);
+        })
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_aligned_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.aligned.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_column_delimiter(
+        &mut self,
+        action: &SetColumnDelimiter,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.delimiter = action.0;
+        cx.notify();
+    }
+}
+
+fn align_columns(text: &str, delimiter: ColumnDelimiter) -> String {
+    let separator = delimiter.as_char();
+    let rows: Vec<Option<(&str, Vec<String>)>> = text
+        .lines()
+        .map(|line| {
+            if !line.contains(separator) {
+                return None;
+            }
+            let content = line.trim_start();
+            let indent = &line[..line.len() - content.len()];
+            let cells: Vec<String> = if delimiter.splits_once() {
+                let (left, right) = content.split_once(separator).unwrap_or((content, ""));
+                vec![left.trim().to_string(), right.trim().to_string()]
+            } else {
+                content
+                    .split(separator)
+                    .map(|cell| cell.trim().to_string())
+                    .collect()
+            };
+            Some((indent, cells))
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = Vec::new();
+    for (_, cells) in rows.iter().flatten() {
+        for (i, cell) in cells.iter().enumerate() {
+            let width = cell.chars().count() + usize::from(delimiter.trails_cell());
+            match widths.get_mut(i) {
+                Some(w) => *w = (*w).max(width),
+                None => widths.push(width),
+            }
+        }
+    }
+
+    text.lines()
+        .zip(rows.iter())
+        .map(|(line, row)| {
+            let Some((indent, cells)) = row else {
+                return line.to_string();
+            };
+            let last = cells.len() - 1;
+            let mut out = String::new();
+            for (i, cell) in cells.iter().enumerate() {
+                if i == last {
+                    out.push_str(cell);
+                } else if delimiter.trails_cell() {
+                    let cell = format!("{}{}", cell, separator);
+                    out.push_str(&format!("{:<width$} ", cell, width = widths[i]));
+                } else {
+                    out.push_str(&format!(
+                        "{:<width$} {} ",
+                        cell,
+                        separator,
+                        width = widths[i]
+                    ));
+                }
+            }
+            // Trimming drops the padding around empty edge cells, as in
+            // `| a | b |`.
+            format!("{}{}", indent, out.trim())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Tool for TextColumnAlignerTool {
+    fn title() -> &'static str {
+        "Column Aligner"
+    }
+
+    fn short_title() -> &'static str {
+        "Align Columns"
+    }
+
+    fn description() -> &'static str {
+        "Aligns text into columns on a delimiter by padding with spaces."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_align_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_aligned_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.aligned.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            delimiter: self.delimiter,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_column_delimiter(&SetColumnDelimiter(preset.delimiter), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for TextColumnAlignerTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TextColumnAlignerTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let delimiter = self.delimiter;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_column_delimiter))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("align-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("align-button")
+                                    .label("Align")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_align_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Delimiter")
+                                    .menu_with_check(
+                                        "Equals Sign",
+                                        delimiter == ColumnDelimiter::Equals,
+                                        Box::new(SetColumnDelimiter(ColumnDelimiter::Equals)),
+                                    )
+                                    .menu_with_check(
+                                        "Colon",
+                                        delimiter == ColumnDelimiter::Colon,
+                                        Box::new(SetColumnDelimiter(ColumnDelimiter::Colon)),
+                                    )
+                                    .menu_with_check(
+                                        "Comma",
+                                        delimiter == ColumnDelimiter::Comma,
+                                        Box::new(SetColumnDelimiter(ColumnDelimiter::Comma)),
+                                    )
+                                    .menu_with_check(
+                                        "Pipe",
+                                        delimiter == ColumnDelimiter::Pipe,
+                                        Box::new(SetColumnDelimiter(ColumnDelimiter::Pipe)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-aligned-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_aligned_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.aligned)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -3,12 +3,12 @@ use std::ops::Range;
 use gpui::prelude::FluentBuilder;
 use gpui::{
     Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
-    HighlightStyle, Hsla, InteractiveElement, ParentElement, Render, Styled, StyledText, Window,
-    div, px, rems,
+    HighlightStyle, Hsla, InteractiveElement, ParentElement, Render, StatefulInteractiveElement,
+    Styled, StyledText, Window, div, px, rems,
 };
 
 use gpui_component::button::DropdownButton;
-use gpui_component::{ActiveTheme, Size, StyleSized, StyledExt};
+use gpui_component::{ActiveTheme, Colorize, Size, StyleSized, StyledExt};
 use gpui_component::{
     button::{Button, ButtonVariants},
     h_flex,
@@ -17,29 +17,89 @@ use gpui_component::{
     v_flex,
 };
 
-use serde::Deserialize;
-use similar::{ChangeTag, TextDiff};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::Tool;
+use crate::{
+    Tool,
+    core::{
+        diff::{self, Change, Granularity, Ignore, Line, Row, RowKind},
+        lines,
+    },
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = data_url_tools, no_json)]
+pub struct SetGranularity(Granularity);
 
-#[derive(Clone, PartialEq, Eq, Deserialize)]
-enum Granularity {
-    Character,
-    Word,
-    Line,
+#[derive(Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+enum ViewMode {
+    #[default]
+    Inline,
+    SideBySide,
 }
 
 #[derive(Action, Clone, PartialEq, Eq, Deserialize)]
 #[action(namespace = data_url_tools, no_json)]
-pub struct SetGranularity(Granularity);
+pub struct SetViewMode(ViewMode);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = data_url_tools, no_json)]
+pub struct SetContextLines(usize);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = data_url_tools, no_json)]
+pub struct SetShowWhitespace(bool);
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize)]
+enum IgnoreOption {
+    SurroundingWhitespace,
+    AllWhitespace,
+    Case,
+    BlankLines,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = data_url_tools, no_json)]
+pub struct SetIgnore(IgnoreOption, bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    granularity: Granularity,
+    #[serde(default)]
+    view_mode: ViewMode,
+    #[serde(default = "default_context_lines")]
+    context_lines: usize,
+    #[serde(default)]
+    ignore: Ignore,
+    #[serde(default)]
+    show_whitespace: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    original: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    modified: Option<String>,
+}
+
+fn default_context_lines() -> usize {
+    3
+}
 
 pub struct TextDifferenceTool {
     focus_handle: FocusHandle,
     original: Entity<InputState>,
     modified: Entity<InputState>,
     granularity: Granularity,
+    view_mode: ViewMode,
+    /// The number of unchanged lines around each hunk of the unified diff.
+    context_lines: usize,
+    ignore: Ignore,
+    /// Whether the differences draw whitespace and line endings as visible
+    /// characters.
+    show_whitespace: bool,
     difference_text: String,
-    difference_highlights: Vec<(Range<usize>, HighlightStyle)>,
+    difference_changes: Vec<(Range<usize>, Change)>,
+    difference_rows: Vec<Row>,
 }
 
 impl TextDifferenceTool {
@@ -66,8 +126,13 @@ impl TextDifferenceTool {
             original,
             modified,
             granularity: Granularity::Word,
+            view_mode: ViewMode::Inline,
+            context_lines: default_context_lines(),
+            ignore: Ignore::default(),
+            show_whitespace: false,
             difference_text: String::new(),
-            difference_highlights: Vec::new(),
+            difference_changes: Vec::new(),
+            difference_rows: Vec::new(),
         }
     }
 
@@ -75,45 +140,47 @@ impl TextDifferenceTool {
         let old = self.original.read(cx).value().clone();
         let new = self.modified.read(cx).value().clone();
 
-        let diff = match self.granularity {
-            Granularity::Character => TextDiff::from_chars(old.as_str(), new.as_str()),
-            Granularity::Word => TextDiff::from_words(old.as_str(), new.as_str()),
-            Granularity::Line => TextDiff::from_lines(old.as_str(), new.as_str()),
-        };
-
-        let colour_for = |tag: ChangeTag| -> Hsla {
-            match tag {
-                ChangeTag::Delete => cx.theme().red,
-                ChangeTag::Insert => cx.theme().green,
-                ChangeTag::Equal => cx.theme().foreground,
-            }
-        };
-
-        let mut text = String::with_capacity(old.len() + new.len());
-        let mut highlights = Vec::new();
-        for op in diff.ops() {
-            for change in diff.iter_changes(op) {
-                let pos = text.len();
-                text.push_str(change.value());
-                highlights.push((
-                    pos..text.len(),
-                    HighlightStyle {
-                        color: Some(colour_for(change.tag())),
-                        ..Default::default()
-                    },
-                ));
+        if self.view_mode == ViewMode::SideBySide {
+            self.difference_rows = diff::side_by_side(&old, &new, &self.granularity, &self.ignore);
+            if self.show_whitespace {
+                for line in self
+                    .difference_rows
+                    .iter_mut()
+                    .flat_map(|row| [row.original.as_mut(), row.modified.as_mut()])
+                    .flatten()
+                {
+                    show_whitespace(&mut line.text, &mut line.changes);
+                }
             }
+            cx.notify();
+            return;
         }
 
-        self.difference_text = text;
-        self.difference_highlights = highlights;
+        (self.difference_text, self.difference_changes) =
+            diff::inline(&old, &new, &self.granularity, &self.ignore);
+        if self.show_whitespace {
+            show_whitespace(&mut self.difference_text, &mut self.difference_changes);
+        }
 
         cx.notify();
     }
 
+    fn on_copy_unified_diff_click(
+        &mut self,
+        _: &ClickEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let old = self.original.read(cx).value().clone();
+        let new = self.modified.read(cx).value().clone();
+        let unified_diff = diff::unified(&old, &new, self.context_lines);
+        cx.write_to_clipboard(ClipboardItem::new_string(unified_diff));
+    }
+
     fn on_back_click(&mut self, _: &ClickEvent, _: &mut Window, _: &mut Context<Self>) {
         self.difference_text = String::new();
-        self.difference_highlights = Vec::new();
+        self.difference_changes = Vec::new();
+        self.difference_rows = Vec::new();
     }
 
     fn on_copy_original_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
@@ -163,6 +230,175 @@ impl TextDifferenceTool {
         self.granularity = action.0.clone();
         cx.notify();
     }
+
+    fn on_action_set_view_mode(
+        &mut self,
+        action: &SetViewMode,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.view_mode = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_context_lines(
+        &mut self,
+        action: &SetContextLines,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.context_lines = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_show_whitespace(
+        &mut self,
+        action: &SetShowWhitespace,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.show_whitespace = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_ignore(&mut self, action: &SetIgnore, _: &mut Window, cx: &mut Context<Self>) {
+        let SetIgnore(option, value) = *action;
+        let ignore = match option {
+            IgnoreOption::SurroundingWhitespace => &mut self.ignore.surrounding_whitespace,
+            IgnoreOption::AllWhitespace => &mut self.ignore.all_whitespace,
+            IgnoreOption::Case => &mut self.ignore.case,
+            IgnoreOption::BlankLines => &mut self.ignore.blank_lines,
+        };
+        *ignore = value;
+        cx.notify();
+    }
+}
+
+/// Draws the whitespace in `text` as visible characters, moving `changes`
+/// along with it.
+fn show_whitespace(text: &mut String, changes: &mut [(Range<usize>, Change)]) {
+    let mut ranges: Vec<_> = changes.iter().map(|(range, _)| range.clone()).collect();
+    *text = lines::show_whitespace_in(text, &mut ranges);
+    for ((range, _), moved) in changes.iter_mut().zip(ranges) {
+        *range = moved;
+    }
+}
+
+/// The colours of the difference views for the current theme. Light
+/// backgrounds take darker text and fainter tints than dark ones for the same
+/// contrast.
+struct Palette {
+    deleted: Hsla,
+    inserted: Hsla,
+    modified: Hsla,
+    row_opacity: f32,
+    span_opacity: f32,
+}
+
+impl Palette {
+    fn new(cx: &App) -> Self {
+        let theme = cx.theme();
+        if theme.is_dark() {
+            Self {
+                deleted: theme.red,
+                inserted: theme.green,
+                modified: theme.yellow,
+                row_opacity: 0.12,
+                span_opacity: 0.35,
+            }
+        } else {
+            Self {
+                deleted: theme.red.darken(0.2),
+                inserted: theme.green.darken(0.3),
+                modified: theme.yellow.darken(0.2),
+                row_opacity: 0.1,
+                span_opacity: 0.25,
+            }
+        }
+    }
+
+    fn colour(&self, change: Change) -> Hsla {
+        match change {
+            Change::Delete => self.deleted,
+            Change::Insert => self.inserted,
+        }
+    }
+
+    /// Colours changed text in the inline view.
+    fn text(&self, changes: Vec<(Range<usize>, Change)>) -> Vec<(Range<usize>, HighlightStyle)> {
+        changes
+            .into_iter()
+            .map(|(range, change)| {
+                let style = HighlightStyle {
+                    color: Some(self.colour(change)),
+                    ..Default::default()
+                };
+                (range, style)
+            })
+            .collect()
+    }
+
+    /// Marks changes within a line of the side-by-side view.
+    fn spans(&self, changes: Vec<(Range<usize>, Change)>) -> Vec<(Range<usize>, HighlightStyle)> {
+        changes
+            .into_iter()
+            .map(|(range, change)| {
+                let style = HighlightStyle {
+                    background_color: Some(self.colour(change).opacity(self.span_opacity)),
+                    ..Default::default()
+                };
+                (range, style)
+            })
+            .collect()
+    }
+}
+
+fn render_row(row: Row, palette: &Palette, cx: &App) -> impl gpui::IntoElement {
+    let (marker, background) = match row.kind {
+        RowKind::Equal => (" ", None),
+        RowKind::Delete => ("-", Some(palette.deleted.opacity(palette.row_opacity))),
+        RowKind::Insert => ("+", Some(palette.inserted.opacity(palette.row_opacity))),
+        RowKind::Modify => ("~", Some(palette.modified.opacity(palette.row_opacity))),
+    };
+    let side = |line: Option<Line>, missing: Hsla| {
+        h_flex()
+            .flex_1()
+            .min_w_0()
+            .items_start()
+            .when_some(background, |this, background| {
+                this.bg(if line.is_some() { background } else { missing })
+            })
+            .child(
+                div()
+                    .w(px(48.))
+                    .flex_none()
+                    .pr_2()
+                    .text_right()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(
+                        line.as_ref()
+                            .map_or(String::new(), |line| line.number.to_string()),
+                    ),
+            )
+            .child(
+                div()
+                    .w(px(16.))
+                    .flex_none()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(if line.is_some() { marker } else { "" }),
+            )
+            .child(div().flex_1().min_w_0().when_some(line, |this, line| {
+                this.child(StyledText::new(line.text).with_highlights(palette.spans(line.changes)))
+            }))
+    };
+
+    let missing = cx.theme().muted.opacity(0.5);
+    div()
+        .flex()
+        .w_full()
+        .child(side(row.original, missing))
+        .child(div().w(px(1.)).flex_none().bg(cx.theme().border))
+        .child(side(row.modified, missing))
 }
 
 impl Tool for TextDifferenceTool {
@@ -175,12 +411,59 @@ impl Tool for TextDifferenceTool {
     }
 
     fn description() -> &'static str {
-        "Shows differences between two texts."
+        "Shows differences between two texts, inline or side by side, and exports them as unified diffs."
     }
 
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_compare_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_unified_diff_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_original_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            granularity: self.granularity.clone(),
+            view_mode: self.view_mode,
+            context_lines: self.context_lines,
+            ignore: self.ignore,
+            show_whitespace: self.show_whitespace,
+            original: include_input.then(|| self.original.read(cx).value().to_string()),
+            modified: include_input.then(|| self.modified.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_granularity(&SetGranularity(preset.granularity), window, cx);
+        self.on_action_set_view_mode(&SetViewMode(preset.view_mode), window, cx);
+        self.on_action_set_context_lines(&SetContextLines(preset.context_lines), window, cx);
+        self.ignore = preset.ignore;
+        self.show_whitespace = preset.show_whitespace;
+        if let Some(original) = preset.original {
+            self.original.update(cx, |state, cx| {
+                state.set_value(original, window, cx);
+            });
+        }
+        if let Some(modified) = preset.modified {
+            self.modified.update(cx, |state, cx| {
+                state.set_value(modified, window, cx);
+            });
+        }
+        cx.notify();
+    }
 }
 
 impl Focusable for TextDifferenceTool {
@@ -196,16 +479,27 @@ impl Render for TextDifferenceTool {
         cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
         let granularity = self.granularity.clone();
+        let view_mode = self.view_mode;
+        let context_lines = self.context_lines;
+        let ignore = self.ignore;
+        let show_whitespace = self.show_whitespace;
         let difference_text = self.difference_text.clone();
-        let difference_highlights = self.difference_highlights.clone();
+        let palette = Palette::new(cx);
+        let difference_highlights = palette.text(self.difference_changes.clone());
+        let difference_rows = self.difference_rows.clone();
+        let side_by_side = !difference_rows.is_empty();
 
         div()
             .on_action(cx.listener(Self::on_action_set_granularity))
+            .on_action(cx.listener(Self::on_action_set_view_mode))
+            .on_action(cx.listener(Self::on_action_set_context_lines))
+            .on_action(cx.listener(Self::on_action_set_ignore))
+            .on_action(cx.listener(Self::on_action_set_show_whitespace))
             .v_flex()
             .size_full()
             .gap_2()
             .when_else(
-                self.difference_text == "",
+                self.difference_text.is_empty() && !side_by_side,
                 |this| {
                     this.child(
                         h_flex()
@@ -236,6 +530,57 @@ impl Render for TextDifferenceTool {
                                                 granularity == Granularity::Line,
                                                 Box::new(SetGranularity(Granularity::Line)),
                                             )
+                                            .separator()
+                                            .label("Ignore")
+                                            .menu_with_check(
+                                                "Leading/Trailing Whitespace",
+                                                ignore.surrounding_whitespace,
+                                                Box::new(SetIgnore(
+                                                    IgnoreOption::SurroundingWhitespace,
+                                                    !ignore.surrounding_whitespace,
+                                                )),
+                                            )
+                                            .menu_with_check(
+                                                "All Whitespace",
+                                                ignore.all_whitespace,
+                                                Box::new(SetIgnore(
+                                                    IgnoreOption::AllWhitespace,
+                                                    !ignore.all_whitespace,
+                                                )),
+                                            )
+                                            .menu_with_check(
+                                                "Case",
+                                                ignore.case,
+                                                Box::new(SetIgnore(
+                                                    IgnoreOption::Case,
+                                                    !ignore.case,
+                                                )),
+                                            )
+                                            .menu_with_check(
+                                                "Blank Lines",
+                                                ignore.blank_lines,
+                                                Box::new(SetIgnore(
+                                                    IgnoreOption::BlankLines,
+                                                    !ignore.blank_lines,
+                                                )),
+                                            )
+                                            .separator()
+                                            .label("View")
+                                            .menu_with_check(
+                                                "Inline",
+                                                view_mode == ViewMode::Inline,
+                                                Box::new(SetViewMode(ViewMode::Inline)),
+                                            )
+                                            .menu_with_check(
+                                                "Side by Side",
+                                                view_mode == ViewMode::SideBySide,
+                                                Box::new(SetViewMode(ViewMode::SideBySide)),
+                                            )
+                                            .menu_with_check(
+                                                "Show Whitespace",
+                                                show_whitespace,
+                                                Box::new(SetShowWhitespace(!show_whitespace)),
+                                            )
                                     }),
                             )
                             .child(
@@ -254,7 +599,7 @@ impl Render for TextDifferenceTool {
                         v_flex().id("origin").w_full().flex_1().gap_2().child(
                             TextInput::new(&self.original)
                                 .h_full()
-                                .font_family("Space Mono")
+                                .font_family(editor_font(cx))
                                 .text_size(px(15.))
                                 .focus_bordered(false),
                         ),
@@ -278,7 +623,7 @@ impl Render for TextDifferenceTool {
                         v_flex().id("modified").w_full().flex_1().gap_2().child(
                             TextInput::new(&self.modified)
                                 .h_full()
-                                .font_family("Space Mono")
+                                .font_family(editor_font(cx))
                                 .text_size(px(15.))
                                 .focus_bordered(false),
                         ),
@@ -286,18 +631,45 @@ impl Render for TextDifferenceTool {
                 },
                 |this| {
                     this.child(
-                        h_flex().gap_2().child(
-                            Button::new("back-button")
-                                .label("Back")
-                                .primary()
-                                .on_click(cx.listener(Self::on_back_click)),
-                        ),
+                        h_flex()
+                            .gap_2()
+                            .child(
+                                Button::new("back-button")
+                                    .label("Back")
+                                    .primary()
+                                    .on_click(cx.listener(Self::on_back_click)),
+                            )
+                            .child(
+                                DropdownButton::new("copy-unified-diff-dropdown-button")
+                                    .button(
+                                        Button::new("copy-unified-diff-button")
+                                            .label("Copy Unified Diff")
+                                            .on_click(
+                                                cx.listener(Self::on_copy_unified_diff_click),
+                                            ),
+                                    )
+                                    .popup_menu(move |this, _, _| {
+                                        [0, 1, 3, 5, 10].into_iter().fold(
+                                            this.label("Context Lines"),
+                                            |this, lines| {
+                                                this.menu_with_check(
+                                                    lines.to_string(),
+                                                    context_lines == lines,
+                                                    Box::new(SetContextLines(lines)),
+                                                )
+                                            },
+                                        )
+                                    })
+                                    .ml_auto(),
+                            ),
                     )
-                    .child(
-                        h_flex().id("source").w_full().flex_1().gap_2().child(
-                            div()
-                                .size_full()
-                                .font_family("Space Mono")
+                    .when(side_by_side, |this| {
+                        this.child(
+                            v_flex()
+                                .id("side-by-side")
+                                .w_full()
+                                .flex_1()
+                                .font_family(editor_font(cx))
                                 .text_size(px(15.))
                                 .line_height(rems(1.25))
                                 .bg(cx.theme().background)
@@ -305,15 +677,37 @@ impl Render for TextDifferenceTool {
                                 .rounded(cx.theme().radius)
                                 .border_color(cx.theme().input)
                                 .border_1()
-                                .input_px(Size::default())
-                                .input_py(Size::default())
-                                .when(cx.theme().shadow, |this| this.shadow_xs())
-                                .child(
-                                    StyledText::new(difference_text)
-                                        .with_highlights(difference_highlights),
+                                .overflow_y_scroll()
+                                .children(
+                                    difference_rows
+                                        .into_iter()
+                                        .map(|row| render_row(row, &palette, cx)),
                                 ),
-                        ),
-                    )
+                        )
+                    })
+                    .when(!side_by_side, |this| {
+                        this.child(
+                            h_flex().id("source").w_full().flex_1().gap_2().child(
+                                div()
+                                    .size_full()
+                                    .font_family(editor_font(cx))
+                                    .text_size(px(15.))
+                                    .line_height(rems(1.25))
+                                    .bg(cx.theme().background)
+                                    .text_color(cx.theme().foreground)
+                                    .rounded(cx.theme().radius)
+                                    .border_color(cx.theme().input)
+                                    .border_1()
+                                    .input_px(Size::default())
+                                    .input_py(Size::default())
+                                    .when(cx.theme().shadow, |this| this.shadow_xs())
+                                    .child(
+                                        StyledText::new(difference_text)
+                                            .with_highlights(difference_highlights),
+                                    ),
+                            ),
+                        )
+                    })
                 },
             )
     }
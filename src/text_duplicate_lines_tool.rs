@@ -0,0 +1,420 @@
+use std::{collections::HashMap, ops::Range};
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    HighlightStyle, InteractiveElement, ParentElement, Render, SharedString,
+    StatefulInteractiveElement, Styled, StyledText, Window, div, prelude::FluentBuilder, px, rems,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, Size, StyleSized, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool,
+    core::lines::{self, VisibleLine},
+    editor_font,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum LineFilter {
+    All,
+    Duplicates,
+    Uniques,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetLineFilter(LineFilter);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetShowWhitespace(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    line_filter: LineFilter,
+    #[serde(default)]
+    show_whitespace: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct TextDuplicateLinesTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    counted: Entity<InputState>,
+    line_filter: LineFilter,
+    /// Whether whitespace and line endings are drawn, so that lines that
+    /// only differ in them can be told apart.
+    show_whitespace: bool,
+    /// The counted lines as drawn with their whitespace, each after its
+    /// count.
+    visible_lines: Vec<(usize, VisibleLine)>,
+}
+
+impl TextDuplicateLinesTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text")
+        });
+        let counted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 8,
+                    hard_tabs: true,
+                })
+                .default_value("")
+                .placeholder("Line Counts")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            counted,
+            line_filter: LineFilter::All,
+            show_whitespace: false,
+            visible_lines: Vec::new(),
+        }
+    }
+
+    fn on_count_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let counts = count_lines(&value, self.show_whitespace)
+            .into_iter()
+            .filter(|(_, count)| match self.line_filter {
+                LineFilter::All => true,
+                LineFilter::Duplicates => *count > 1,
+                LineFilter::Uniques => *count == 1,
+            })
+            .collect::<Vec<_>>();
+        let counted_value = match self.show_whitespace {
+            true => {
+                self.visible_lines = counts
+                    .into_iter()
+                    .map(|(line, count)| (count, lines::show_whitespace(line)))
+                    .collect();
+                self.visible_text().0
+            }
+            false => {
+                self.visible_lines.clear();
+                counts
+                    .into_iter()
+                    .map(|(line, count)| format!("{}\t{}", count, line))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        };
+        self.counted.update(cx, |state, cx| {
+            state.set_value(SharedString::from(counted_value), window, cx);
+        })
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_counted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.counted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_line_filter(
+        &mut self,
+        action: &SetLineFilter,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.line_filter = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_show_whitespace(
+        &mut self,
+        action: &SetShowWhitespace,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.show_whitespace = action.0;
+        self.on_count_click(&ClickEvent::default(), window, cx);
+        cx.notify();
+    }
+
+    /// Lays out the counted lines with their whitespace drawn, returning the
+    /// ranges of trailing whitespace and of line endings.
+    fn visible_text(&self) -> (String, Vec<Range<usize>>, Vec<Range<usize>>) {
+        let width = self
+            .visible_lines
+            .iter()
+            .map(|(count, _)| count.to_string().len())
+            .max()
+            .unwrap_or_default();
+        let mut text = String::new();
+        let mut trailing = Vec::new();
+        let mut endings = Vec::new();
+        for (i, (count, line)) in self.visible_lines.iter().enumerate() {
+            if i > 0 {
+                text.push('\n');
+            }
+            text.push_str(&format!("{:>width$}  ", count));
+            let start = text.len();
+            text.push_str(&line.text);
+            trailing.push(start + line.trailing.start..start + line.trailing.end);
+            endings.push(start + line.trailing.end..text.len());
+        }
+        trailing.retain(|range| !range.is_empty());
+        endings.retain(|range| !range.is_empty());
+        (text, trailing, endings)
+    }
+}
+
+/// Counts the lines of `text`. With `keep_endings`, lines keep their line
+/// endings, and so are counted apart from the same lines ending in CRLF
+/// rather than LF, or ending the text without a newline.
+fn count_lines(text: &str, keep_endings: bool) -> Vec<(&str, usize)> {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    let lines: Box<dyn Iterator<Item = &str>> = match keep_endings {
+        true => Box::new(text.split_inclusive('\n')),
+        false => Box::new(text.lines()),
+    };
+    for line in lines {
+        match index.get(line) {
+            Some(&i) => counts[i].1 += 1,
+            None => {
+                index.insert(line, counts.len());
+                counts.push((line, 1));
+            }
+        }
+    }
+    // The sort is stable, so lines with equal counts keep the order in
+    // which they first appeared.
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    counts
+}
+
+impl Tool for TextDuplicateLinesTool {
+    fn title() -> &'static str {
+        "Duplicate Line Counter"
+    }
+
+    fn short_title() -> &'static str {
+        "Duplicate Lines"
+    }
+
+    fn description() -> &'static str {
+        "Groups identical lines with their counts, and can show the whitespace that sets lines apart."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_count_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_counted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.counted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            line_filter: self.line_filter,
+            show_whitespace: self.show_whitespace,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_line_filter(&SetLineFilter(preset.line_filter), window, cx);
+        self.show_whitespace = preset.show_whitespace;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for TextDuplicateLinesTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TextDuplicateLinesTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let line_filter = self.line_filter;
+        let show_whitespace = self.show_whitespace;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_line_filter))
+            .on_action(cx.listener(Self::on_action_set_show_whitespace))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("count-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("count-button")
+                                    .label("Count")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_count_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Show")
+                                    .menu_with_check(
+                                        "All Lines",
+                                        line_filter == LineFilter::All,
+                                        Box::new(SetLineFilter(LineFilter::All)),
+                                    )
+                                    .menu_with_check(
+                                        "Duplicates Only",
+                                        line_filter == LineFilter::Duplicates,
+                                        Box::new(SetLineFilter(LineFilter::Duplicates)),
+                                    )
+                                    .menu_with_check(
+                                        "Uniques Only",
+                                        line_filter == LineFilter::Uniques,
+                                        Box::new(SetLineFilter(LineFilter::Uniques)),
+                                    )
+                                    .separator()
+                                    .menu_with_check(
+                                        "Show Whitespace",
+                                        show_whitespace,
+                                        Box::new(SetShowWhitespace(!show_whitespace)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-counted-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_counted_click))
+                        .ml_auto(),
+                ),
+            )
+            .map(|this| match show_whitespace {
+                true => {
+                    let (text, trailing, endings) = self.visible_text();
+                    let trailing_style = HighlightStyle {
+                        background_color: Some(cx.theme().red.opacity(0.25)),
+                        ..Default::default()
+                    };
+                    let ending_style = HighlightStyle {
+                        color: Some(cx.theme().muted_foreground),
+                        ..Default::default()
+                    };
+                    let mut highlights = trailing
+                        .into_iter()
+                        .map(|range| (range, trailing_style))
+                        .chain(endings.into_iter().map(|range| (range, ending_style)))
+                        .collect::<Vec<_>>();
+                    highlights.sort_by_key(|(range, _)| range.start);
+                    this.child(
+                        div()
+                            .id("visible-lines")
+                            .size_full()
+                            .overflow_y_scroll()
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .line_height(rems(1.25))
+                            .bg(cx.theme().background)
+                            .text_color(cx.theme().foreground)
+                            .rounded(cx.theme().radius)
+                            .border_color(cx.theme().input)
+                            .border_1()
+                            .input_px(Size::default())
+                            .input_py(Size::default())
+                            .child(StyledText::new(text).with_highlights(highlights)),
+                    )
+                }
+                false => this.child(
+                    TextInput::new(&self.counted)
+                        .h_full()
+                        .font_family(editor_font(cx))
+                        .text_size(px(15.))
+                        .focus_bordered(false),
+                ),
+            })
+    }
+}
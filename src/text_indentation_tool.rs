@@ -0,0 +1,267 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, editor_font};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetTabWidth(usize);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    tab_width: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct TextIndentationTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    tab_width: usize,
+    changed_lines: Option<usize>,
+}
+
+impl TextIndentationTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: true,
+                })
+                .default_value("")
+                .placeholder("Text")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            tab_width: 4,
+            changed_lines: None,
+        }
+    }
+
+    fn convert(&mut self, use_tabs: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let (converted, changed_lines) = convert_indentation(&value, self.tab_width, use_tabs);
+        self.changed_lines = Some(changed_lines);
+        self.editor.update(cx, |state, cx| {
+            state.set_value(SharedString::from(converted), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_to_spaces_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.convert(false, window, cx);
+    }
+
+    fn on_to_tabs_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.convert(true, window, cx);
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_action_set_tab_width(
+        &mut self,
+        action: &SetTabWidth,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.tab_width = action.0;
+        cx.notify();
+    }
+}
+
+fn convert_indentation(text: &str, tab_width: usize, use_tabs: bool) -> (String, usize) {
+    let mut changed_lines = 0;
+    let lines: Vec<String> = text
+        .split('\n')
+        .map(|line| {
+            let content = line.trim_start_matches([' ', '\t']);
+            let indent = &line[..line.len() - content.len()];
+
+            // Tabs advance to the next tab stop, so the width of the
+            // indentation depends on where each tab sits.
+            let columns = indent.chars().fold(0, |column, c| match c {
+                '\t' => (column / tab_width + 1) * tab_width,
+                _ => column + 1,
+            });
+            let new_indent = if use_tabs {
+                format!(
+                    "{}{}",
+                    "\t".repeat(columns / tab_width),
+                    " ".repeat(columns % tab_width)
+                )
+            } else {
+                " ".repeat(columns)
+            };
+
+            if new_indent != indent {
+                changed_lines += 1;
+            }
+            format!("{}{}", new_indent, content)
+        })
+        .collect();
+    (lines.join("\n"), changed_lines)
+}
+
+impl Tool for TextIndentationTool {
+    fn title() -> &'static str {
+        "Tabs and Spaces Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "Tabs/Spaces"
+    }
+
+    fn description() -> &'static str {
+        "Converts leading indentation between tabs and spaces."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_to_spaces_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.editor.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            tab_width: self.tab_width,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_tab_width(&SetTabWidth(preset.tab_width), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for TextIndentationTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TextIndentationTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let tab_width = self.tab_width;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_tab_width))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("to-spaces-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("to-spaces-button")
+                                    .label("To Spaces")
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_to_spaces_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Tab Width")
+                                    .menu_with_check("2", tab_width == 2, Box::new(SetTabWidth(2)))
+                                    .menu_with_check("4", tab_width == 4, Box::new(SetTabWidth(4)))
+                                    .menu_with_check("8", tab_width == 8, Box::new(SetTabWidth(8)))
+                            }),
+                    )
+                    .child(
+                        Button::new("to-tabs-button")
+                            .label("To Tabs")
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_to_tabs_click)),
+                    )
+                    .when_some(self.changed_lines, |this, changed_lines| {
+                        this.child(Label::new(match changed_lines {
+                            1 => "1 line changed".to_string(),
+                            n => format!("{} lines changed", n),
+                        }))
+                    })
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,417 @@
+use std::path::PathBuf;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, PathPromptOptions, Render, SharedString, Styled, Window,
+    div, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Tool, editor_font};
+
+const BOM: char = '\u{feff}';
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum LineEnding {
+    Lf,
+    Crlf,
+    Cr,
+}
+
+impl LineEnding {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::Crlf => "\r\n",
+            LineEnding::Cr => "\r",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum BomMode {
+    Keep,
+    Add,
+    Remove,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetLineEnding(LineEnding);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetBomMode(BomMode);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    line_ending: LineEnding,
+    bom_mode: BomMode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+#[derive(Default)]
+struct LineEndingCounts {
+    lf: usize,
+    crlf: usize,
+    cr: usize,
+}
+
+pub struct TextLineEndingsTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    path: Option<PathBuf>,
+    line_ending: LineEnding,
+    bom_mode: BomMode,
+}
+
+impl TextLineEndingsTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            path: None,
+            line_ending: LineEnding::Lf,
+            bom_mode: BomMode::Keep,
+        }
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let converted = convert_line_endings(&value, self.line_ending, self.bom_mode);
+        self.editor.update(cx, |state, cx| {
+            state.set_value(SharedString::from(converted), window, cx);
+        })
+    }
+
+    fn on_open_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        cx.spawn_in(window, async move |this, cx| {
+            if let Ok(Ok(Some(paths))) = paths.await
+                && let Some(path) = paths.into_iter().next()
+                && let Ok(bytes) = std::fs::read(&path)
+            {
+                this.update_in(cx, |this, window, cx| {
+                    let value = String::from_utf8_lossy(&bytes).to_string();
+                    this.path = Some(path);
+                    this.editor.update(cx, |state, cx| {
+                        state.set_value(SharedString::from(value), window, cx);
+                    });
+                    cx.notify();
+                })
+                .ok();
+            }
+        })
+        .detach();
+    }
+
+    fn on_save_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let directory = self
+            .path
+            .as_ref()
+            .and_then(|path| path.parent().map(|parent| parent.to_path_buf()))
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        let name = self
+            .path
+            .as_ref()
+            .and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy().to_string());
+        let path = cx.prompt_for_new_path(&directory, name.as_deref());
+        let value = self.editor.read(cx).value().clone();
+        cx.spawn_in(window, async move |this, cx| {
+            if let Ok(Ok(Some(path))) = path.await
+                && std::fs::write(&path, value.as_bytes()).is_ok()
+            {
+                this.update(cx, |this, cx| {
+                    this.path = Some(path);
+                    cx.notify();
+                })
+                .ok();
+            }
+        })
+        .detach();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.path = None;
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_action_set_line_ending(
+        &mut self,
+        action: &SetLineEnding,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.line_ending = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_bom_mode(
+        &mut self,
+        action: &SetBomMode,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.bom_mode = action.0;
+        cx.notify();
+    }
+}
+
+fn count_line_endings(text: &str) -> LineEndingCounts {
+    let mut counts = LineEndingCounts::default();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' if chars.peek() == Some(&'\n') => {
+                chars.next();
+                counts.crlf += 1;
+            }
+            '\r' => counts.cr += 1,
+            '\n' => counts.lf += 1,
+            _ => {}
+        }
+    }
+    counts
+}
+
+fn convert_line_endings(text: &str, line_ending: LineEnding, bom_mode: BomMode) -> String {
+    let has_bom = text.starts_with(BOM);
+    let body = text.strip_prefix(BOM).unwrap_or(text);
+    // CRLF is folded first so that its CR is not treated as a line ending of
+    // its own.
+    let normalized = body.replace("\r\n", "\n").replace('\r', "\n");
+    let converted = match line_ending {
+        LineEnding::Lf => normalized,
+        _ => normalized.replace('\n', line_ending.as_str()),
+    };
+    let add_bom = match bom_mode {
+        BomMode::Keep => has_bom,
+        BomMode::Add => true,
+        BomMode::Remove => false,
+    };
+    if add_bom {
+        format!("{}{}", BOM, converted)
+    } else {
+        converted
+    }
+}
+
+impl Tool for TextLineEndingsTool {
+    fn title() -> &'static str {
+        "Line Ending Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "Line Endings"
+    }
+
+    fn description() -> &'static str {
+        "Detects and converts between LF, CRLF, and CR line endings and adds or removes a UTF-8 BOM."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.editor.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            line_ending: self.line_ending,
+            bom_mode: self.bom_mode,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_line_ending(&SetLineEnding(preset.line_ending), window, cx);
+        self.on_action_set_bom_mode(&SetBomMode(preset.bom_mode), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for TextLineEndingsTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TextLineEndingsTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let counts = count_line_endings(&value);
+        let has_bom = value.starts_with(BOM);
+        let line_ending = self.line_ending;
+        let bom_mode = self.bom_mode;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_line_ending))
+            .on_action(cx.listener(Self::on_action_set_bom_mode))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("convert-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("convert-button")
+                                    .label("Convert")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Line Endings")
+                                    .menu_with_check(
+                                        "LF (Unix)",
+                                        line_ending == LineEnding::Lf,
+                                        Box::new(SetLineEnding(LineEnding::Lf)),
+                                    )
+                                    .menu_with_check(
+                                        "CRLF (Windows)",
+                                        line_ending == LineEnding::Crlf,
+                                        Box::new(SetLineEnding(LineEnding::Crlf)),
+                                    )
+                                    .menu_with_check(
+                                        "CR (Classic Mac)",
+                                        line_ending == LineEnding::Cr,
+                                        Box::new(SetLineEnding(LineEnding::Cr)),
+                                    )
+                                    .label("Byte Order Mark")
+                                    .menu_with_check(
+                                        "Keep",
+                                        bom_mode == BomMode::Keep,
+                                        Box::new(SetBomMode(BomMode::Keep)),
+                                    )
+                                    .menu_with_check(
+                                        "Add",
+                                        bom_mode == BomMode::Add,
+                                        Box::new(SetBomMode(BomMode::Add)),
+                                    )
+                                    .menu_with_check(
+                                        "Remove",
+                                        bom_mode == BomMode::Remove,
+                                        Box::new(SetBomMode(BomMode::Remove)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("open-button")
+                            .label("Open File…")
+                            .on_click(cx.listener(Self::on_open_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("save-button")
+                            .label("Save…")
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_save_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click)),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_4()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!("LF: {}", counts.lf))
+                    .child(format!("CRLF: {}", counts.crlf))
+                    .child(format!("CR: {}", counts.cr))
+                    .child(format!("BOM: {}", if has_bom { "Yes" } else { "No" }))
+                    .children(
+                        self.path
+                            .as_ref()
+                            .map(|path| div().ml_auto().child(path.display().to_string())),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,359 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    checkbox::Checkbox,
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool,
+    core::lines::{self, LineNumbers, Operations, Sort},
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetSort(Sort);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetLineNumbers(LineNumbers);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    operations: Operations,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct TextLinesTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    processed: Entity<InputState>,
+    operations: Operations,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TextLinesTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text")
+        });
+        let processed = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Processed Lines")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            processed,
+            operations: Operations {
+                sort: Sort::Ascending,
+                ..Default::default()
+            },
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_processed(window, cx);
+        }
+    }
+
+    fn update_processed(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        let processed_value = lines::apply(&value, &self.operations, &mut rand::rng());
+        self.processed.update(cx, |state, cx| {
+            state.set_value(SharedString::from(processed_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_apply_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_processed(window, cx);
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            });
+            self.update_processed(window, cx);
+        }
+    }
+
+    fn on_copy_processed_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.processed.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_sort(
+        &mut self,
+        action: &SetSort,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.operations.sort = action.0;
+        self.update_processed(window, cx);
+    }
+
+    fn on_action_set_line_numbers(
+        &mut self,
+        action: &SetLineNumbers,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.operations.line_numbers = action.0;
+        self.update_processed(window, cx);
+    }
+}
+
+impl Tool for TextLinesTool {
+    fn title() -> &'static str {
+        "Line Sorter"
+    }
+
+    fn short_title() -> &'static str {
+        "Lines"
+    }
+
+    fn description() -> &'static str {
+        "Sorts, deduplicates, trims, reverses, shuffles, and numbers lines, in any combination."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_apply_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_processed_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.processed.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            operations: self.operations,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.operations = preset.operations;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.update_processed(window, cx);
+    }
+}
+
+impl Focusable for TextLinesTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TextLinesTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let sort = self.operations.sort;
+        let line_numbers = self.operations.line_numbers;
+        let toggles = [
+            ("trim-checkbox", "Trim", self.operations.trim),
+            (
+                "remove-duplicates-checkbox",
+                "Remove Duplicates",
+                self.operations.remove_duplicates,
+            ),
+            ("reverse-checkbox", "Reverse", self.operations.reverse),
+            ("shuffle-checkbox", "Shuffle", self.operations.shuffle),
+        ];
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_sort))
+            .on_action(cx.listener(Self::on_action_set_line_numbers))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("apply-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("apply-button")
+                                    .label("Apply")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_apply_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Sort")
+                                    .menu_with_check(
+                                        "Keep Order",
+                                        sort == Sort::None,
+                                        Box::new(SetSort(Sort::None)),
+                                    )
+                                    .menu_with_check(
+                                        "Ascending",
+                                        sort == Sort::Ascending,
+                                        Box::new(SetSort(Sort::Ascending)),
+                                    )
+                                    .menu_with_check(
+                                        "Descending",
+                                        sort == Sort::Descending,
+                                        Box::new(SetSort(Sort::Descending)),
+                                    )
+                                    .menu_with_check(
+                                        "Natural",
+                                        sort == Sort::Natural,
+                                        Box::new(SetSort(Sort::Natural)),
+                                    )
+                                    .menu_with_check(
+                                        "Numeric",
+                                        sort == Sort::Numeric,
+                                        Box::new(SetSort(Sort::Numeric)),
+                                    )
+                                    .label("Line Numbers")
+                                    .menu_with_check(
+                                        "Keep",
+                                        line_numbers == LineNumbers::Keep,
+                                        Box::new(SetLineNumbers(LineNumbers::Keep)),
+                                    )
+                                    .menu_with_check(
+                                        "Add",
+                                        line_numbers == LineNumbers::Add,
+                                        Box::new(SetLineNumbers(LineNumbers::Add)),
+                                    )
+                                    .menu_with_check(
+                                        "Strip",
+                                        line_numbers == LineNumbers::Strip,
+                                        Box::new(SetLineNumbers(LineNumbers::Strip)),
+                                    )
+                            }),
+                    )
+                    .children(
+                        toggles
+                            .into_iter()
+                            .enumerate()
+                            .map(|(ix, (id, label, checked))| {
+                                Checkbox::new(id).label(label).checked(checked).on_click(
+                                    cx.listener(move |this, checked: &bool, window, cx| {
+                                        let toggle = match ix {
+                                            0 => &mut this.operations.trim,
+                                            1 => &mut this.operations.remove_duplicates,
+                                            2 => &mut this.operations.reverse,
+                                            _ => &mut this.operations.shuffle,
+                                        };
+                                        *toggle = *checked;
+                                        this.update_processed(window, cx);
+                                    }),
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-processed-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_processed_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                TextInput::new(&self.processed)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,352 @@
+use std::ops::Range;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, HighlightStyle,
+    InteractiveElement, ParentElement, Render, Styled, StyledText, Window, div,
+    prelude::FluentBuilder, px, rems,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, Size, StyleSized, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputState, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool,
+    core::{
+        lines,
+        similarity::{self, EditOp},
+    },
+    editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetVisibleWhitespace(bool);
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    first: String,
+    second: String,
+    #[serde(default)]
+    show_whitespace: bool,
+}
+
+pub struct TextSimilarityTool {
+    focus_handle: FocusHandle,
+    first: Entity<InputState>,
+    second: Entity<InputState>,
+    levenshtein: Entity<InputState>,
+    jaro_winkler: Entity<InputState>,
+    similarity: Entity<InputState>,
+    /// The edit operations of the last comparison, or `None` if the strings
+    /// were too long to list them.
+    edit_ops: Option<Vec<EditOp>>,
+    /// Whether the edit operations draw whitespace as visible characters.
+    show_whitespace: bool,
+}
+
+impl TextSimilarityTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let first = cx.new(|cx| InputState::new(window, cx).placeholder("First String"));
+        let second = cx.new(|cx| InputState::new(window, cx).placeholder("Second String"));
+        let levenshtein = cx.new(|cx| InputState::new(window, cx));
+        let jaro_winkler = cx.new(|cx| InputState::new(window, cx));
+        let similarity = cx.new(|cx| InputState::new(window, cx));
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            first,
+            second,
+            levenshtein,
+            jaro_winkler,
+            similarity,
+            edit_ops: Some(Vec::new()),
+            show_whitespace: false,
+        }
+    }
+
+    fn on_compare_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let a: Vec<char> = self.first.read(cx).value().chars().collect();
+        let b: Vec<char> = self.second.read(cx).value().chars().collect();
+
+        let distance = similarity::levenshtein(&a, &b);
+        let similarity = similarity::similarity(distance, a.len(), b.len());
+
+        self.levenshtein.update(cx, |state, cx| {
+            state.set_value(format!("{}", distance), window, cx);
+        });
+        self.jaro_winkler.update(cx, |state, cx| {
+            state.set_value(
+                format!("{:.4}", similarity::jaro_winkler(&a, &b)),
+                window,
+                cx,
+            );
+        });
+        self.similarity.update(cx, |state, cx| {
+            state.set_value(format!("{:.2}%", similarity), window, cx);
+        });
+        self.edit_ops = similarity::edit_ops(&a, &b);
+        cx.notify();
+    }
+
+    fn on_swap_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let first = self.first.read(cx).value().clone();
+        let second = self.second.read(cx).value().clone();
+        self.first.update(cx, |state, cx| {
+            state.set_value(second, window, cx);
+        });
+        self.second.update(cx, |state, cx| {
+            state.set_value(first, window, cx);
+        });
+    }
+
+    fn on_action_set_show_whitespace(
+        &mut self,
+        action: &SetVisibleWhitespace,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.show_whitespace = action.0;
+        cx.notify();
+    }
+}
+
+impl Tool for TextSimilarityTool {
+    fn title() -> &'static str {
+        "String Similarity"
+    }
+
+    fn short_title() -> &'static str {
+        "Similarity"
+    }
+
+    fn description() -> &'static str {
+        "Measures the edit distance and similarity between two strings."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_compare_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        let state = State {
+            first: self.first.read(cx).value().to_string(),
+            second: self.second.read(cx).value().to_string(),
+            show_whitespace: self.show_whitespace,
+        };
+        serde_json::to_value(state).ok()
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(state) = State::deserialize(state) else {
+            return;
+        };
+        self.first.update(cx, |first, cx| {
+            first.set_value(state.first, window, cx);
+        });
+        self.second.update(cx, |second, cx| {
+            second.set_value(state.second, window, cx);
+        });
+        self.show_whitespace = state.show_whitespace;
+    }
+}
+
+impl Focusable for TextSimilarityTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TextSimilarityTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let first = self.first.read(cx).value();
+        let second = self.second.read(cx).value();
+        let levenshtein = self.levenshtein.read(cx).value().clone();
+        let jaro_winkler = self.jaro_winkler.read(cx).value().clone();
+        let similarity = self.similarity.read(cx).value().clone();
+        let show_whitespace = self.show_whitespace;
+
+        // Removed characters are shown in red and struck through, added ones
+        // in green, so a substitution reads as the old character followed by
+        // the new one.
+        let mut ops_text = String::new();
+        let mut ops_highlights: Vec<(Range<usize>, HighlightStyle)> = Vec::new();
+        let mut push = |c: char, style: Option<HighlightStyle>| {
+            let pos = ops_text.len();
+            ops_text.push(if show_whitespace {
+                lines::visible_char(c)
+            } else {
+                c
+            });
+            if let Some(style) = style {
+                ops_highlights.push((pos..ops_text.len(), style));
+            }
+        };
+        let removed = HighlightStyle {
+            color: Some(cx.theme().red),
+            strikethrough: Some(Default::default()),
+            ..Default::default()
+        };
+        let added = HighlightStyle {
+            color: Some(cx.theme().green),
+            ..Default::default()
+        };
+        for op in self.edit_ops.iter().flatten() {
+            match *op {
+                EditOp::Keep(c) => push(c, None),
+                EditOp::Substitute(old, new) => {
+                    push(old, Some(removed));
+                    push(new, Some(added));
+                }
+                EditOp::Delete(c) => push(c, Some(removed)),
+                EditOp::Insert(c) => push(c, Some(added)),
+            }
+        }
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_show_whitespace))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("compare-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("compare-button")
+                                    .label("Compare")
+                                    .primary()
+                                    .disabled(first.is_empty() && second.is_empty())
+                                    .on_click(cx.listener(Self::on_compare_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.menu_with_check(
+                                    "Show Whitespace",
+                                    show_whitespace,
+                                    Box::new(SetVisibleWhitespace(!show_whitespace)),
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("swap-button")
+                            .label("Swap")
+                            .on_click(cx.listener(Self::on_swap_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.first)
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                TextInput::new(&self.second)
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .mt_4()
+                    .items_start()
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("Levenshtein Distance"))
+                            .child(
+                                TextInput::new(&self.levenshtein)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("levenshtein-clipboard")
+                                            .value_fn(move |_, _| levenshtein.clone()),
+                                    ),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("Jaro-Winkler"))
+                            .child(
+                                TextInput::new(&self.jaro_winkler)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("jaro-winkler-clipboard")
+                                            .value_fn(move |_, _| jaro_winkler.clone()),
+                                    ),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("Similarity"))
+                            .child(
+                                TextInput::new(&self.similarity)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("similarity-clipboard")
+                                            .value_fn(move |_, _| similarity.clone()),
+                                    ),
+                            ),
+                    ),
+            )
+            .when(self.edit_ops.is_none(), |this| {
+                this.child(
+                    Label::new("The strings are too long to list the edit operations.")
+                        .text_sm()
+                        .mt_4(),
+                )
+            })
+            .when(!ops_text.is_empty(), |this| {
+                this.child(Label::new("Edit Operations").mt_4()).child(
+                    div()
+                        .w_full()
+                        .font_family(editor_font(cx))
+                        .text_size(px(15.))
+                        .line_height(rems(1.25))
+                        .bg(cx.theme().background)
+                        .text_color(cx.theme().foreground)
+                        .rounded(cx.theme().radius)
+                        .border_color(cx.theme().input)
+                        .border_1()
+                        .input_px(Size::default())
+                        .input_py(Size::default())
+                        .when(cx.theme().shadow, |this| this.shadow_xs())
+                        .child(StyledText::new(ops_text).with_highlights(ops_highlights)),
+                )
+            })
+    }
+}
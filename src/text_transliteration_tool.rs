@@ -0,0 +1,321 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+use crate::{Tool, editor_font};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Transliteration {
+    StripDiacritics,
+    Ascii,
+}
+
+impl Transliteration {
+    fn convert_char(&self, c: char) -> String {
+        match self {
+            Transliteration::StripDiacritics => c
+                .to_string()
+                .nfd()
+                .filter(|c| !is_combining_mark(*c))
+                .collect(),
+            Transliteration::Ascii => deunicode::deunicode_char(c).unwrap_or("?").to_string(),
+        }
+    }
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = text_tools, no_json)]
+pub struct SetTransliteration(Transliteration);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    transliteration: Transliteration,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct TextTransliterationTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    converted: Entity<InputState>,
+    changes: Entity<InputState>,
+    transliteration: Transliteration,
+}
+
+impl TextTransliterationTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .multi_line()
+                .default_value("")
+                .placeholder("Text")
+        });
+        let converted = cx.new(|cx| {
+            InputState::new(window, cx)
+                .multi_line()
+                .default_value("")
+                .placeholder("Converted")
+        });
+        let changes = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 8,
+                    hard_tabs: true,
+                })
+                .default_value("")
+                .placeholder("Changed Characters")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            converted,
+            changes,
+            transliteration: Transliteration::Ascii,
+        }
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+
+        let mut converted = String::with_capacity(value.len());
+        let mut changes: Vec<(char, String)> = Vec::new();
+        for c in value.chars() {
+            // Whitespace and plain ASCII pass through untouched; this also
+            // keeps line breaks intact in the ASCII mode.
+            if c.is_ascii() {
+                converted.push(c);
+                continue;
+            }
+            let replacement = self.transliteration.convert_char(c);
+            converted.push_str(&replacement);
+            if replacement != c.to_string() && !changes.iter().any(|(before, _)| *before == c) {
+                changes.push((c, replacement));
+            }
+        }
+
+        let changes_value = changes
+            .iter()
+            .map(|(before, after)| format!("{}\tU+{:04X}\t→\t{}", before, *before as u32, after))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        self.converted.update(cx, |state, cx| {
+            state.set_value(SharedString::from(converted), window, cx);
+        });
+        self.changes.update(cx, |state, cx| {
+            state.set_value(SharedString::from(changes_value), window, cx);
+        });
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_converted_click(
+        &mut self,
+        _: &ClickEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.converted.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_transliteration(
+        &mut self,
+        action: &SetTransliteration,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.transliteration = action.0;
+        cx.notify();
+    }
+}
+
+impl Tool for TextTransliterationTool {
+    fn title() -> &'static str {
+        "Diacritic Remover"
+    }
+
+    fn short_title() -> &'static str {
+        "Transliterate"
+    }
+
+    fn description() -> &'static str {
+        "Strips accents from text or transliterates it to plain ASCII."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_converted_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.converted.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            transliteration: self.transliteration,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_transliteration(&SetTransliteration(preset.transliteration), window, cx);
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for TextTransliterationTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TextTransliterationTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let transliteration = self.transliteration;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_transliteration))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("convert-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("convert-button")
+                                    .label("Convert")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Mode")
+                                    .menu_with_check(
+                                        "Strip Diacritics",
+                                        transliteration == Transliteration::StripDiacritics,
+                                        Box::new(SetTransliteration(
+                                            Transliteration::StripDiacritics,
+                                        )),
+                                    )
+                                    .menu_with_check(
+                                        "Transliterate to ASCII",
+                                        transliteration == Transliteration::Ascii,
+                                        Box::new(SetTransliteration(Transliteration::Ascii)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("copy-converted-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_converted_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .h_full()
+                    .gap_2()
+                    .child(
+                        div().w_full().h_full().child(
+                            TextInput::new(&self.converted)
+                                .h_full()
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        div().w_full().h_full().child(
+                            TextInput::new(&self.changes)
+                                .h_full()
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    ),
+            )
+    }
+}
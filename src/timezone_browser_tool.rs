@@ -0,0 +1,276 @@
+use std::ops::Range;
+
+use chrono::{DateTime, Duration, FixedOffset, Offset, Utc};
+use chrono_tz::{OffsetComponents, OffsetName, TZ_VARIANTS, Tz};
+
+use gpui::{
+    App, AppContext, ClickEvent, Context, Div, Entity, FocusHandle, Focusable, ParentElement,
+    Render, SharedString, Styled, Subscription, Window, div, px, uniform_list,
+};
+
+use gpui_component::{
+    ActiveTheme, StyledExt,
+    button::Button,
+    clipboard::Clipboard,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde_json::Value;
+
+use crate::{Tool, core::timestamp::format_offset, editor_font};
+
+struct ZoneInfo {
+    id: &'static str,
+    abbreviation: String,
+    offset: String,
+    dst: bool,
+    next_transition: Option<String>,
+}
+
+impl ZoneInfo {
+    fn new(tz: Tz, now: DateTime<Utc>) -> Self {
+        let offset = now.with_timezone(&tz).offset().to_owned();
+        Self {
+            id: tz.name(),
+            abbreviation: offset.abbreviation().unwrap_or_default().to_string(),
+            offset: format_offset(offset.fix()),
+            dst: !offset.dst_offset().is_zero(),
+            next_transition: next_transition(tz, now).map(|(at, before, after)| {
+                format!(
+                    "{} → {} {}",
+                    at.with_timezone(&before).format("%Y-%m-%d %H:%M"),
+                    format_offset(after.fix()),
+                    after.abbreviation().unwrap_or_default()
+                )
+                .trim_end()
+                .to_string()
+            }),
+        }
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        self.id.to_lowercase().contains(query) || self.abbreviation.to_lowercase().contains(query)
+    }
+}
+
+pub struct TimezoneBrowserTool {
+    focus_handle: FocusHandle,
+    search: Entity<InputState>,
+    zones: Vec<ZoneInfo>,
+    filtered: Vec<usize>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TimezoneBrowserTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let search = cx
+            .new(|cx| InputState::new(window, cx).placeholder("Search, e.g. Europe/Berlin or PST"));
+        let _subscriptions = vec![cx.subscribe_in(&search, window, Self::on_search_event)];
+
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            search,
+            zones: Vec::new(),
+            filtered: Vec::new(),
+            _subscriptions,
+        };
+        this.refresh();
+        this
+    }
+
+    fn refresh(&mut self) {
+        let now = Utc::now();
+        self.zones = TZ_VARIANTS
+            .iter()
+            .map(|tz| ZoneInfo::new(*tz, now))
+            .collect();
+        self.filtered = (0..self.zones.len()).collect();
+    }
+
+    fn filter(&mut self, cx: &mut Context<Self>) {
+        let query = self.search.read(cx).value().trim().to_lowercase();
+        self.filtered = self
+            .zones
+            .iter()
+            .enumerate()
+            .filter(|(_, zone)| zone.matches(&query))
+            .map(|(i, _)| i)
+            .collect();
+        cx.notify();
+    }
+
+    fn on_search_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.filter(cx);
+        }
+    }
+
+    fn on_refresh_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.refresh();
+        self.filter(cx);
+    }
+
+    fn render_rows(
+        &mut self,
+        range: Range<usize>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Vec<Div> {
+        let border = cx.theme().border;
+        let muted = cx.theme().muted_foreground;
+        range
+            .map(|i| {
+                let zone = &self.zones[self.filtered[i]];
+                h_flex()
+                    .h(px(36.))
+                    .gap_4()
+                    .px_2()
+                    .border_b_1()
+                    .border_color(border)
+                    .child(div().flex_1().font_family(editor_font(cx)).child(zone.id))
+                    .child(div().w(px(80.)).child(zone.abbreviation.clone()))
+                    .child(
+                        div()
+                            .w(px(100.))
+                            .font_family(editor_font(cx))
+                            .child(zone.offset.clone()),
+                    )
+                    .child(div().w(px(40.)).child(if zone.dst { "DST" } else { "" }))
+                    .child(
+                        div()
+                            .w(px(320.))
+                            .text_color(muted)
+                            .child(zone.next_transition.clone().unwrap_or_default()),
+                    )
+                    .child(
+                        Clipboard::new(SharedString::from(format!("zone-clipboard-{}", zone.id)))
+                            .value(zone.id),
+                    )
+            })
+            .collect()
+    }
+}
+
+/// Finds the next change of offset or abbreviation within two years. The
+/// zone is sampled weekly, then the change is narrowed down to the second.
+fn next_transition(
+    tz: Tz,
+    now: DateTime<Utc>,
+) -> Option<(DateTime<Utc>, FixedOffset, chrono_tz::TzOffset)> {
+    let key = |at: DateTime<Utc>| {
+        let offset = at.with_timezone(&tz).offset().to_owned();
+        (offset.fix(), offset.abbreviation().map(str::to_string))
+    };
+    let current = key(now);
+    let mut low = now;
+    let mut high = (1..=105)
+        .map(|week| now + Duration::weeks(week))
+        .find(|at| key(*at) != current)?;
+    while high - low > Duration::seconds(1) {
+        let mid = low + (high - low) / 2;
+        if key(mid) == current {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    let after = high.with_timezone(&tz).offset().to_owned();
+    Some((high, current.0, after))
+}
+
+impl Tool for TimezoneBrowserTool {
+    fn title() -> &'static str {
+        "Timezone Browser"
+    }
+
+    fn short_title() -> &'static str {
+        "Timezones"
+    }
+
+    fn description() -> &'static str {
+        "Lists IANA timezones with their current offset, abbreviation, and next DST transition."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_refresh_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.search.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.search.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
+}
+
+impl Focusable for TimezoneBrowserTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TimezoneBrowserTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.search)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        Button::new("refresh-button")
+                            .label("Refresh")
+                            .on_click(cx.listener(Self::on_refresh_click)),
+                    ),
+            )
+            .child(Label::new(format!(
+                "{} of {} zones (tzdb {})",
+                self.filtered.len(),
+                self.zones.len(),
+                chrono_tz::IANA_TZDB_VERSION
+            )))
+            .child(
+                uniform_list(
+                    "zones",
+                    self.filtered.len(),
+                    cx.processor(|this, range: Range<usize>, window, cx| {
+                        this.render_rows(range, window, cx)
+                    }),
+                )
+                .size_full(),
+            )
+    }
+}
@@ -0,0 +1,411 @@
+use chrono::{DateTime, Utc};
+
+use gpui::{
+    App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, InteractiveElement,
+    IntoElement, ParentElement, Render, SharedString, StatefulInteractiveElement, Styled,
+    Subscription, Window, div, px,
+};
+
+use gpui_component::{
+    ActiveTheme, IconName, IndexPath, Sizable, StyledExt,
+    button::{Button, ButtonVariants},
+    clipboard::Clipboard,
+    dropdown::{Dropdown, DropdownEvent, DropdownState, SearchableVec},
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+    v_flex,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::timestamp::{date_to_timestamp, format_offset, to_zone, zone_names},
+    editor_font,
+};
+
+/// The zones listed until others are added.
+const DEFAULT_TARGETS: [&str; 4] = ["UTC", "America/New_York", "Europe/London", "Asia/Tokyo"];
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    source: Option<SharedString>,
+    targets: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct TimezoneConverterTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    source: Entity<DropdownState<SearchableVec<SharedString>>>,
+    add_zone: Entity<DropdownState<SearchableVec<SharedString>>>,
+    targets: Vec<String>,
+    /// The point in time being converted, or `None` if the input does not
+    /// hold a date.
+    at: Option<DateTime<Utc>>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TimezoneConverterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Date and time, e.g. 2024-05-01 13:45:00, or empty for now")
+        });
+        let zones: Vec<SharedString> = zone_names().into_iter().map(SharedString::from).collect();
+        let source = cx.new(|cx| {
+            DropdownState::new(
+                SearchableVec::new(zones.clone()),
+                Some(IndexPath::default()),
+                window,
+                cx,
+            )
+        });
+        let add_zone = cx.new(|cx| DropdownState::new(SearchableVec::new(zones), None, window, cx));
+        let _subscriptions = vec![
+            cx.subscribe_in(&input, window, Self::on_input_event),
+            cx.subscribe_in(&source, window, Self::on_source_event),
+            cx.subscribe_in(&add_zone, window, Self::on_add_zone_event),
+        ];
+
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            source,
+            add_zone,
+            targets: DEFAULT_TARGETS.map(str::to_string).to_vec(),
+            at: None,
+            error: None,
+            _subscriptions,
+        };
+        this.update_converted(cx);
+        this
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_converted(cx);
+        }
+    }
+
+    fn on_source_event(
+        &mut self,
+        _: &Entity<DropdownState<SearchableVec<SharedString>>>,
+        e: &DropdownEvent<SearchableVec<SharedString>>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let DropdownEvent::Confirm(_) = e;
+        self.update_converted(cx);
+    }
+
+    fn on_add_zone_event(
+        &mut self,
+        _: &Entity<DropdownState<SearchableVec<SharedString>>>,
+        e: &DropdownEvent<SearchableVec<SharedString>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let DropdownEvent::Confirm(Some(zone)) = e else {
+            return;
+        };
+        if !self.targets.iter().any(|target| target == zone.as_str()) {
+            self.targets.push(zone.to_string());
+        }
+        self.add_zone.update(cx, |dropdown, cx| {
+            dropdown.set_selected_index(None, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn source_zone(&self, cx: &App) -> String {
+        self.source
+            .read(cx)
+            .selected_value()
+            .map_or("Local".to_string(), |zone| zone.to_string())
+    }
+
+    fn update_converted(&mut self, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        let result = match value.trim() {
+            "" => Ok(Utc::now()),
+            value => date_to_timestamp(value, &self.source_zone(cx)),
+        };
+        match result {
+            Ok(at) => {
+                self.at = Some(at);
+                self.error = None;
+            }
+            Err(err) => {
+                self.at = None;
+                self.error = Some(err.context("Invalid date"));
+            }
+        }
+        cx.notify();
+    }
+
+    fn on_convert_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.update_converted(cx);
+    }
+
+    fn on_now_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(now) = to_zone(Utc::now(), &self.source_zone(cx)) else {
+            return;
+        };
+        let value = now.date_time.format("%Y-%m-%d %H:%M:%S").to_string();
+        self.input.update(cx, |state, cx| {
+            state.set_value(value, window, cx);
+        });
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.input.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn render_target(&self, ix: usize, zone: &str, cx: &mut Context<Self>) -> impl IntoElement {
+        let source_date = self
+            .at
+            .and_then(|at| to_zone(at, &self.source_zone(cx)).ok())
+            .map(|time| time.date_time.date());
+        let time = self.at.and_then(|at| to_zone(at, zone).ok());
+        let formatted = time
+            .as_ref()
+            .map(|time| time.date_time.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_default();
+        // How many days ahead of or behind the source the zone's date is.
+        let day_shift = match (&time, source_date) {
+            (Some(time), Some(source_date)) => {
+                match (time.date_time.date() - source_date).num_days() {
+                    0 => String::new(),
+                    1 => "+1 day".to_string(),
+                    -1 => "−1 day".to_string(),
+                    days => format!("{:+} days", days).replace('-', "−"),
+                }
+            }
+            _ => String::new(),
+        };
+
+        h_flex()
+            .h(px(36.))
+            .gap_4()
+            .px_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                div()
+                    .flex_1()
+                    .font_family(editor_font(cx))
+                    .child(zone.to_string()),
+            )
+            .child(
+                div()
+                    .w(px(180.))
+                    .font_family(editor_font(cx))
+                    .child(formatted.clone()),
+            )
+            .child(
+                div()
+                    .w(px(70.))
+                    .text_color(cx.theme().muted_foreground)
+                    .child(day_shift),
+            )
+            .child(
+                div().w(px(60.)).child(
+                    time.as_ref()
+                        .and_then(|time| time.abbreviation.clone())
+                        .unwrap_or_default(),
+                ),
+            )
+            .child(
+                div().w(px(100.)).font_family(editor_font(cx)).child(
+                    time.as_ref()
+                        .map(|time| format_offset(time.offset))
+                        .unwrap_or_default(),
+                ),
+            )
+            .child(
+                div()
+                    .w(px(40.))
+                    .child(if time.is_some_and(|time| time.dst) {
+                        "DST"
+                    } else {
+                        ""
+                    }),
+            )
+            .child(Clipboard::new(("zone-time-clipboard", ix)).value(formatted))
+            .child(
+                Button::new(("remove-zone-button", ix))
+                    .icon(IconName::Close)
+                    .ghost()
+                    .small()
+                    .on_click(cx.listener(move |this, _: &ClickEvent, _, cx| {
+                        this.targets.remove(ix);
+                        cx.notify();
+                    })),
+            )
+    }
+}
+
+impl Tool for TimezoneConverterTool {
+    fn title() -> &'static str {
+        "Timezone Converter"
+    }
+
+    fn short_title() -> &'static str {
+        "Timezone Converter"
+    }
+
+    fn description() -> &'static str {
+        "Shows a date and time in a list of other timezones, with their offsets and DST status."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            source: self.source.read(cx).selected_value().cloned(),
+            targets: self.targets.clone(),
+            input: include_input.then(|| self.input.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.targets = preset.targets;
+        if let Some(source) = preset.source {
+            self.source.update(cx, |dropdown, cx| {
+                dropdown.set_selected_value(&source, window, cx);
+            });
+        }
+        if let Some(input) = preset.input {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.update_converted(cx);
+    }
+}
+
+impl Focusable for TimezoneConverterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TimezoneConverterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let rows = self
+            .targets
+            .clone()
+            .iter()
+            .enumerate()
+            .map(|(ix, zone)| self.render_target(ix, zone, cx).into_any_element())
+            .collect::<Vec<_>>();
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("convert-button")
+                            .label("Convert")
+                            .primary()
+                            .on_click(cx.listener(Self::on_convert_click)),
+                    )
+                    .child(
+                        Button::new("now-button")
+                            .label("Now")
+                            .on_click(cx.listener(Self::on_now_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.input)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        div().w(px(240.)).flex_none().child(
+                            Dropdown::new(&self.source)
+                                .placeholder("Timezone")
+                                .menu_width(px(320.)),
+                        ),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .mt_4()
+                    .child(Label::new(format!("{} Zones", self.targets.len())))
+                    .child(
+                        div().w(px(240.)).flex_none().ml_auto().child(
+                            Dropdown::new(&self.add_zone)
+                                .placeholder("Add Zone")
+                                .menu_width(px(320.)),
+                        ),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .id("zones")
+                    .flex_1()
+                    .overflow_y_scroll()
+                    .children(rows),
+            )
+    }
+}
@@ -1,27 +1,41 @@
 use std::rc::Rc;
 
 use gpui::{
-    AnyElement, App, Context, InteractiveElement as _, IntoElement, MouseButton,
-    ParentElement as _, Render, SharedString, Styled as _, Subscription, Window, div, img, px,
+    Action, AnyElement, App, Context, Corner, FocusHandle, InteractiveElement as _, IntoElement,
+    MouseButton, ParentElement as _, Render, SharedString, Styled as _, Subscription, Window, div,
+    img, px,
 };
-use gpui_component::TitleBar;
+use gpui_component::{
+    ActiveTheme as _, IconName, Sizable as _, Theme, TitleBar,
+    button::{Button, ButtonVariants as _},
+    popup_menu::PopupMenuExt as _,
+};
+use serde::Deserialize;
+
+use crate::settings::{Appearance, Settings};
+
+type ChildFn = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = title_bar, no_json)]
+struct SetAppearance(Appearance);
 
 // use crate::{SelectFont, SelectLocale, SelectRadius, SelectScrollbarShow, themes::ThemeSwitcher};
 
 pub struct AppTitleBar {
     title: SharedString,
+    focus_handle: FocusHandle,
     // locale_selector: Entity<LocaleSelector>,
     // font_size_selector: Entity<FontSizeSelector>,
-    // theme_switcher: Entity<ThemeSwitcher>,
-    child: Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>,
+    child: ChildFn,
     _subscriptions: Vec<Subscription>,
 }
 
 impl AppTitleBar {
     pub fn new(
         title: impl Into<SharedString>,
-        _window: &mut Window,
-        _cx: &mut Context<Self>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
     ) -> Self {
         // let locale_selector = cx.new(|cx| LocaleSelector::new(window, cx));
         // let font_size_selector = cx.new(|cx| FontSizeSelector::new(window, cx));
@@ -32,15 +46,21 @@ impl AppTitleBar {
         //     Theme::global_mut(cx).scrollbar_show = ScrollbarShow::Hover;
         // }
 
-        // let theme_switcher = cx.new(|cx| ThemeSwitcher::new(cx));
+        // The window knows the system appearance more reliably than the app
+        // does on Linux, so sync again once there is one.
+        Self::sync_system_appearance(window, cx);
+        let _subscriptions = vec![cx.observe_window_appearance(window, |_, window, cx| {
+            Self::sync_system_appearance(window, cx);
+            window.refresh();
+        })];
 
         Self {
             title: title.into(),
+            focus_handle: cx.focus_handle(),
             // locale_selector,
             // font_size_selector,
-            // theme_switcher,
             child: Rc::new(|_, _| div().into_any_element()),
-            _subscriptions: vec![],
+            _subscriptions,
         }
     }
 
@@ -53,18 +73,34 @@ impl AppTitleBar {
         self
     }
 
-    // fn change_color_mode(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-    //     let mode = match cx.theme().mode.is_dark() {
-    //         true => ThemeMode::Light,
-    //         false => ThemeMode::Dark,
-    //     };
+    /// Follows the system appearance, unless a light or dark one was chosen.
+    fn sync_system_appearance(window: &mut Window, cx: &mut App) {
+        let appearance = cx
+            .try_global::<Settings>()
+            .map(|settings| settings.appearance);
+        if appearance.unwrap_or_default() == Appearance::System {
+            Theme::sync_system_appearance(Some(window), cx);
+        }
+    }
 
-    //     Theme::change(mode, None, cx);
-    // }
+    fn on_action_set_appearance(
+        &mut self,
+        action: &SetAppearance,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        Settings::update(window, cx, |settings| settings.appearance = action.0);
+    }
 }
 
 impl Render for AppTitleBar {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let focus_handle = self.focus_handle.clone();
+        let appearance = cx
+            .try_global::<Settings>()
+            .map(|settings| settings.appearance)
+            .unwrap_or_default();
+
         TitleBar::new()
             // Left side
             .child(
@@ -83,29 +119,51 @@ impl Render for AppTitleBar {
                     .px_2()
                     .gap_2()
                     .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
-                    .child((self.child.clone())(window, cx)), // .child(self.theme_switcher.clone())
-                                                              // .child(
-                                                              //     Button::new("theme-mode")
-                                                              //         .map(|this| {
-                                                              //             if cx.theme().mode.is_dark() {
-                                                              //                 this.icon(IconName::Sun)
-                                                              //             } else {
-                                                              //                 this.icon(IconName::Moon)
-                                                              //             }
-                                                              //         })
-                                                              //         .small()
-                                                              //         .ghost()
-                                                              //         .on_click(cx.listener(Self::change_color_mode)),
-                                                              // )
-                                                              // .child(self.locale_selector.clone())
-                                                              // .child(self.font_size_selector.clone())
-                                                              // .child(
-                                                              //     Button::new("github")
-                                                              //         .icon(IconName::GitHub)
-                                                              //         .small()
-                                                              //         .ghost()
-                                                              //         .on_click(|_, _, cx| cx.open_url("https://github.com/hjr265/utools")),
-                                                              // ),
+                    .child((self.child.clone())(window, cx))
+                    .child(
+                        div()
+                            .id("theme-mode")
+                            .track_focus(&self.focus_handle)
+                            .on_action(cx.listener(Self::on_action_set_appearance))
+                            .child(
+                                Button::new("theme-mode-button")
+                                    .icon(if cx.theme().mode.is_dark() {
+                                        IconName::Moon
+                                    } else {
+                                        IconName::Sun
+                                    })
+                                    .small()
+                                    .ghost()
+                                    .popup_menu(move |menu, _, _| {
+                                        [
+                                            ("System", Appearance::System),
+                                            ("Light", Appearance::Light),
+                                            ("Dark", Appearance::Dark),
+                                        ]
+                                        .into_iter()
+                                        .fold(
+                                            menu.action_context(focus_handle.clone()),
+                                            |menu, (label, value)| {
+                                                menu.menu_with_check(
+                                                    label,
+                                                    appearance == value,
+                                                    Box::new(SetAppearance(value)),
+                                                )
+                                            },
+                                        )
+                                    })
+                                    .anchor(Corner::TopRight),
+                            ),
+                    ),
+                // .child(self.locale_selector.clone())
+                // .child(self.font_size_selector.clone())
+                // .child(
+                //     Button::new("github")
+                //         .icon(IconName::GitHub)
+                //         .small()
+                //         .ghost()
+                //         .on_click(|_, _, cx| cx.open_url("https://github.com/hjr265/utools")),
+                // ),
             )
     }
 }
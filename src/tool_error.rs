@@ -0,0 +1,129 @@
+use std::{fmt, num::ParseIntError, str::Utf8Error, string::FromUtf8Error};
+
+/// Where in a tool's input an error was found.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorPosition {
+    LineColumn { line: usize, column: usize },
+    Offset(usize),
+}
+
+/// An error from parsing or decoding a tool's input. Tools hold on to their
+/// last error and return it from `Tool::error`, and the tool container shows
+/// it in a banner above the tool.
+#[derive(Clone, Debug)]
+pub struct ToolError {
+    message: String,
+    position: Option<ErrorPosition>,
+}
+
+impl ToolError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            position: None,
+        }
+    }
+
+    pub fn at_line_column(mut self, line: usize, column: usize) -> Self {
+        self.position = Some(ErrorPosition::LineColumn { line, column });
+        self
+    }
+
+    pub fn at_offset(mut self, offset: usize) -> Self {
+        self.position = Some(ErrorPosition::Offset(offset));
+        self
+    }
+
+    /// Prefixes the message with what was being done, e.g. "Invalid JSON".
+    pub fn context(mut self, context: &str) -> Self {
+        self.message = format!("{}: {}", context, self.message);
+        self
+    }
+}
+
+impl fmt::Display for ToolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(ErrorPosition::LineColumn { line, column }) => {
+                write!(f, "{} (line {}, column {})", self.message, line, column)
+            }
+            Some(ErrorPosition::Offset(offset)) => {
+                write!(f, "{} (at character {})", self.message, offset + 1)
+            }
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ToolError {}
+
+impl From<serde_json::Error> for ToolError {
+    fn from(err: serde_json::Error) -> Self {
+        // serde_json appends the position to its messages, so it is trimmed
+        // off here and kept separately.
+        let (line, column) = (err.line(), err.column());
+        let message = err.to_string();
+        let message = message
+            .strip_suffix(&format!(" at line {} column {}", line, column))
+            .unwrap_or(&message);
+        let error = Self::new(message);
+        if line > 0 {
+            error.at_line_column(line, column)
+        } else {
+            error
+        }
+    }
+}
+
+impl From<base64::DecodeError> for ToolError {
+    fn from(err: base64::DecodeError) -> Self {
+        use base64::DecodeError::*;
+
+        match err {
+            InvalidByte(offset, byte) | InvalidLastSymbol(offset, byte) => {
+                Self::new(format!("unexpected {:?}", byte as char)).at_offset(offset)
+            }
+            InvalidLength(length) => Self::new(format!("invalid length {}", length)),
+            InvalidPadding => Self::new("invalid padding"),
+        }
+    }
+}
+
+impl From<FromUtf8Error> for ToolError {
+    fn from(err: FromUtf8Error) -> Self {
+        err.utf8_error().into()
+    }
+}
+
+impl From<Utf8Error> for ToolError {
+    fn from(err: Utf8Error) -> Self {
+        Self::new(format!(
+            "decoded bytes are not valid UTF-8 after byte {}",
+            err.valid_up_to()
+        ))
+    }
+}
+
+impl From<ParseIntError> for ToolError {
+    fn from(err: ParseIntError) -> Self {
+        Self::new(err.to_string())
+    }
+}
+
+impl From<roxmltree::Error> for ToolError {
+    fn from(err: roxmltree::Error) -> Self {
+        // Like serde_json, roxmltree ends its messages with the position.
+        let pos = err.pos();
+        let message = err.to_string();
+        let message = message
+            .strip_suffix(&format!(" at {}", pos))
+            .unwrap_or(&message);
+        Self::new(message).at_line_column(pos.row as usize, pos.col as usize)
+    }
+}
+
+impl From<url::ParseError> for ToolError {
+    fn from(err: url::ParseError) -> Self {
+        Self::new(err.to_string())
+    }
+}
@@ -0,0 +1,359 @@
+use std::ops::Range;
+
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Div, Entity, FocusHandle, Focusable,
+    ParentElement, Render, Styled, Subscription, Window, div, prelude::FluentBuilder, px,
+    uniform_list,
+};
+
+use gpui_component::{
+    ActiveTheme, StyledExt,
+    button::{Button, ButtonVariants},
+    checkbox::Checkbox,
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool,
+    core::unicode::{self, CharInfo, Flag},
+    editor_font,
+};
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    #[serde(default)]
+    flagged_only: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+const COLUMNS: [(&str, f32); 8] = [
+    ("Char", 48.),
+    ("Code Point", 96.),
+    ("UTF-8", 112.),
+    ("UTF-16", 96.),
+    ("Name", 0.),
+    ("Category", 200.),
+    ("Block", 200.),
+    ("Flag", 96.),
+];
+
+pub struct UnicodeInspectorTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    chars: Vec<CharInfo>,
+    /// Indices into `chars` of the rows shown.
+    filtered: Vec<usize>,
+    flagged_only: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl UnicodeInspectorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Text")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            chars: Vec::new(),
+            filtered: Vec::new(),
+            flagged_only: false,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_chars(cx);
+        }
+    }
+
+    fn update_chars(&mut self, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        self.chars = unicode::inspect(&value);
+        self.update_filtered(cx);
+    }
+
+    fn update_filtered(&mut self, cx: &mut Context<Self>) {
+        self.filtered = self
+            .chars
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| !self.flagged_only || info.flag.is_some())
+            .map(|(i, _)| i)
+            .collect();
+        cx.notify();
+    }
+
+    fn on_inspect_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.update_chars(cx);
+    }
+
+    fn on_flagged_only_click(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.flagged_only = *checked;
+        self.update_filtered(cx);
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            });
+            self.update_chars(cx);
+        }
+    }
+
+    /// Copies the rows shown as tab-separated values.
+    fn on_copy_table_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let mut value = COLUMNS
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join("\t");
+        for &i in &self.filtered {
+            value.push('\n');
+            value.push_str(&cells(&self.chars[i]).join("\t"));
+        }
+        cx.write_to_clipboard(ClipboardItem::new_string(value));
+    }
+
+    fn render_rows(
+        &mut self,
+        range: Range<usize>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Vec<Div> {
+        let theme = cx.theme();
+        range
+            .map(|i| {
+                let info = &self.chars[self.filtered[i]];
+                let colour = match info.flag {
+                    Some(Flag::BidiControl) => Some(theme.red),
+                    Some(_) => Some(theme.yellow),
+                    None => None,
+                };
+                h_flex()
+                    .h(px(22.))
+                    .gap_4()
+                    .font_family(editor_font(cx))
+                    .text_size(px(13.))
+                    .whitespace_nowrap()
+                    .when_some(colour, |this, colour| this.bg(colour.opacity(0.12)))
+                    .children(cells(info).into_iter().zip(COLUMNS).enumerate().map(
+                        |(i, (cell, (_, width)))| {
+                            let is_flag = i == COLUMNS.len() - 1;
+                            column(width)
+                                .child(cell)
+                                .when_some(colour.filter(|_| is_flag), |this, colour| {
+                                    this.text_color(colour)
+                                })
+                        },
+                    ))
+            })
+            .collect()
+    }
+}
+
+fn cells(info: &CharInfo) -> [String; 8] {
+    [
+        display(info),
+        info.code_point(),
+        info.utf8(),
+        info.utf16(),
+        info.name.clone().unwrap_or_default(),
+        info.category.to_string(),
+        info.block.to_string(),
+        info.flag
+            .map(|flag| flag.name())
+            .unwrap_or_default()
+            .to_string(),
+    ]
+}
+
+fn column(width: f32) -> Div {
+    let column = div().overflow_hidden();
+    if width > 0. {
+        column.w(px(width)).flex_none()
+    } else {
+        column.flex_1().min_w_0()
+    }
+}
+
+/// Shows the character in a way that can be seen: controls as their control
+/// pictures, combining marks on a dotted circle, and other hidden characters
+/// as nothing at all.
+fn display(info: &CharInfo) -> String {
+    match (info.char, info.flag) {
+        (c @ '\0'..='\x1f', _) => char::from_u32(0x2400 + c as u32)
+            .unwrap_or_default()
+            .to_string(),
+        ('\x7f', _) => "␡".to_string(),
+        (_, Some(_)) => String::new(),
+        (c, None) if info.category.starts_with("Nonspacing") => format!("◌{}", c),
+        (c, None) => c.to_string(),
+    }
+}
+
+impl Tool for UnicodeInspectorTool {
+    fn title() -> &'static str {
+        "Unicode Inspector"
+    }
+
+    fn short_title() -> &'static str {
+        "Unicode"
+    }
+
+    fn description() -> &'static str {
+        "Lists the code point, bytes, name, category, and block of each character and flags hidden ones."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_inspect_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_table_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            flagged_only: self.flagged_only,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.flagged_only = preset.flagged_only;
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        self.update_chars(cx);
+    }
+}
+
+impl Focusable for UnicodeInspectorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for UnicodeInspectorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let flagged = self.chars.iter().filter(|info| info.flag.is_some()).count();
+        let status = format!(
+            "{} characters, {} bytes, {} flagged",
+            self.chars.len(),
+            self.editor.read(cx).value().len(),
+            flagged
+        );
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("inspect-button")
+                            .label("Inspect")
+                            .primary()
+                            .on_click(cx.listener(Self::on_inspect_click)),
+                    )
+                    .child(
+                        Checkbox::new("flagged-only-checkbox")
+                            .label("Flagged Only")
+                            .checked(self.flagged_only)
+                            .on_click(cx.listener(Self::on_flagged_only_click)),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h(px(160.))
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex().gap_2().child(Label::new(status)).child(
+                    Button::new("copy-table-button")
+                        .label("Copy")
+                        .on_click(cx.listener(Self::on_copy_table_click))
+                        .ml_auto(),
+                ),
+            )
+            .child(
+                h_flex()
+                    .gap_4()
+                    .text_size(px(13.))
+                    .text_color(cx.theme().muted_foreground)
+                    .children(
+                        COLUMNS
+                            .iter()
+                            .map(|(name, width)| column(*width).child(*name)),
+                    ),
+            )
+            .child(
+                uniform_list(
+                    "unicode-chars",
+                    self.filtered.len(),
+                    cx.processor(|this, range: Range<usize>, window, cx| {
+                        this.render_rows(range, window, cx)
+                    }),
+                )
+                .size_full(),
+            )
+    }
+}
@@ -1,20 +1,50 @@
-use chrono::{Datelike, Duration, Local, TimeZone, Utc};
+use chrono::{Datelike, Local, NaiveTime, TimeZone, Utc};
 
 use gpui::{
-    App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable, ParentElement, Render,
-    Styled, Window, div, px,
+    Action, App, AppContext, ClickEvent, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
 };
 
 use gpui_component::{
-    Disableable, StyledExt,
-    button::{Button, ButtonVariants},
+    Disableable, IndexPath, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    calendar::Date,
     clipboard::Clipboard,
+    date_picker::{DatePicker, DatePickerEvent, DatePickerState},
+    dropdown::{Dropdown, DropdownEvent, DropdownState, SearchableVec},
     h_flex,
-    input::{InputState, TextInput},
+    input::{InputEvent, InputState, TextInput},
     label::Label,
 };
 
-use crate::Tool;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    LiveUpdate, Tool, ToolError,
+    core::timestamp::{
+        Unit, date_to_timestamp, format_relative_time, parse_date_time, parse_timestamp, zone_names,
+    },
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = date_time_tools, no_json)]
+pub struct SetUnit(Unit);
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    timestamp: String,
+    #[serde(default = "default_unit")]
+    unit: Unit,
+    #[serde(default)]
+    date: String,
+    #[serde(default)]
+    zone: Option<SharedString>,
+}
+
+fn default_unit() -> Unit {
+    Unit::Auto
+}
 
 pub struct UnixTimestampConverterTool {
     focus_handle: FocusHandle,
@@ -25,6 +55,16 @@ pub struct UnixTimestampConverterTool {
     days_since_epoch: Entity<InputState>,
     months_since_epoch: Entity<InputState>,
     day_of_year: Entity<InputState>,
+    date_input: Entity<InputState>,
+    date_picker: Entity<DatePickerState>,
+    zone: Entity<DropdownState<SearchableVec<SharedString>>>,
+    timestamp_seconds: Entity<InputState>,
+    timestamp_milliseconds: Entity<InputState>,
+    unit: Unit,
+    live: bool,
+    live_update: LiveUpdate,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl UnixTimestampConverterTool {
@@ -40,6 +80,27 @@ impl UnixTimestampConverterTool {
         let days_since_epoch = cx.new(|cx| InputState::new(window, cx));
         let months_since_epoch = cx.new(|cx| InputState::new(window, cx));
         let day_of_year = cx.new(|cx| InputState::new(window, cx));
+        let date_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("Date and time, e.g. 2024-05-01 13:45:00")
+        });
+        let date_picker = cx.new(|cx| DatePickerState::new(window, cx));
+        let zones: Vec<SharedString> = zone_names().into_iter().map(SharedString::from).collect();
+        let zone = cx.new(|cx| {
+            DropdownState::new(
+                SearchableVec::new(zones),
+                Some(IndexPath::default()),
+                window,
+                cx,
+            )
+        });
+        let timestamp_seconds = cx.new(|cx| InputState::new(window, cx));
+        let timestamp_milliseconds = cx.new(|cx| InputState::new(window, cx));
+        let _subscriptions = vec![
+            cx.subscribe_in(&input, window, Self::on_input_event),
+            cx.subscribe_in(&date_input, window, Self::on_date_input_event),
+            cx.subscribe_in(&date_picker, window, Self::on_date_picker_event),
+            cx.subscribe_in(&zone, window, Self::on_zone_event),
+        ];
 
         Self {
             focus_handle: cx.focus_handle(),
@@ -50,12 +111,99 @@ impl UnixTimestampConverterTool {
             days_since_epoch,
             months_since_epoch,
             day_of_year,
+            date_input,
+            date_picker,
+            zone,
+            timestamp_seconds,
+            timestamp_milliseconds,
+            unit: Unit::Auto,
+            live: false,
+            live_update: LiveUpdate::default(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e
+            && self.live
+        {
+            self.live_update
+                .schedule(Self::update_converted, window, cx);
         }
     }
 
+    fn on_date_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_timestamp(window, cx);
+        }
+    }
+
+    /// Puts the picked date into the date input, keeping the time already
+    /// typed there.
+    fn on_date_picker_event(
+        &mut self,
+        _: &Entity<DatePickerState>,
+        e: &DatePickerEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let DatePickerEvent::Change(Date::Single(Some(date))) = e else {
+            return;
+        };
+        let value = self.date_input.read(cx).value().clone();
+        let time = parse_date_time(&value)
+            .map(|date_time| date_time.time())
+            .unwrap_or(NaiveTime::MIN);
+        let value = date.and_time(time).format("%Y-%m-%d %H:%M:%S").to_string();
+        self.date_input.update(cx, |state, cx| {
+            state.set_value(value, window, cx);
+        });
+    }
+
+    fn on_zone_event(
+        &mut self,
+        _: &Entity<DropdownState<SearchableVec<SharedString>>>,
+        e: &DropdownEvent<SearchableVec<SharedString>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let DropdownEvent::Confirm(_) = e;
+        self.update_timestamp(window, cx);
+    }
+
     fn on_convert_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
-        let value: i64 = self.input.read(cx).value().clone().parse().unwrap();
-        let converted_utc = Utc.timestamp_opt(value, 0).unwrap();
+        self.update_converted(window, cx);
+    }
+
+    fn update_converted(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        if value.trim().is_empty() {
+            self.error = None;
+            cx.notify();
+            return;
+        }
+        let converted_utc = match parse_timestamp(&value, self.unit) {
+            Ok(converted_utc) => converted_utc,
+            Err(err) => {
+                self.error = Some(err);
+                cx.notify();
+                return;
+            }
+        };
+        self.error = None;
         let converted_local = converted_utc.with_timezone(&Local);
         let now = Utc::now();
         let since_relative = format_relative_time(now.signed_duration_since(converted_utc));
@@ -85,6 +233,52 @@ impl UnixTimestampConverterTool {
         });
     }
 
+    fn update_timestamp(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.date_input.read(cx).value().clone();
+        let timestamp = if value.trim().is_empty() {
+            None
+        } else {
+            let zone = self.zone.read(cx).selected_value().cloned();
+            match date_to_timestamp(&value, zone.as_ref().map_or("Local", |zone| zone.as_str())) {
+                Ok(timestamp) => Some(timestamp),
+                Err(err) => {
+                    self.error = Some(err.context("Invalid date"));
+                    cx.notify();
+                    return;
+                }
+            }
+        };
+        self.error = None;
+        let (seconds, milliseconds) = timestamp
+            .map(|timestamp| {
+                (
+                    timestamp.timestamp().to_string(),
+                    timestamp.timestamp_millis().to_string(),
+                )
+            })
+            .unwrap_or_default();
+        self.timestamp_seconds.update(cx, |state, cx| {
+            state.set_value(seconds, window, cx);
+        });
+        self.timestamp_milliseconds.update(cx, |state, cx| {
+            state.set_value(milliseconds, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_action_set_unit(
+        &mut self,
+        action: &SetUnit,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.unit = action.0;
+        if self.live {
+            self.update_converted(window, cx);
+        }
+        cx.notify();
+    }
+
     fn on_now_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
         let value = Utc::now().timestamp();
         self.input.update(cx, |state, cx| {
@@ -112,12 +306,73 @@ impl Tool for UnixTimestampConverterTool {
     }
 
     fn description() -> &'static str {
-        "Transforms Unix timestamps into human-readable date and time formats."
+        "Converts Unix timestamps to human-readable dates, and dates in any timezone back to timestamps."
     }
 
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_convert_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn supports_live() -> bool {
+        true
+    }
+
+    fn set_live(&mut self, live: bool, window: &mut Window, cx: &mut Context<Self>) {
+        self.live = live;
+        if live {
+            self.update_converted(window, cx);
+        }
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        let state = State {
+            timestamp: self.input.read(cx).value().to_string(),
+            unit: self.unit,
+            date: self.date_input.read(cx).value().to_string(),
+            zone: self.zone.read(cx).selected_value().cloned(),
+        };
+        serde_json::to_value(state).ok()
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        // Older versions saved only the timestamp, as a plain string.
+        let state = match state {
+            Value::String(timestamp) => State {
+                timestamp: timestamp.clone(),
+                unit: Unit::Auto,
+                date: String::new(),
+                zone: None,
+            },
+            state => match State::deserialize(state) {
+                Ok(state) => state,
+                Err(_) => return,
+            },
+        };
+        self.unit = state.unit;
+        if let Some(zone) = state.zone {
+            self.zone.update(cx, |dropdown, cx| {
+                dropdown.set_selected_value(&zone, window, cx);
+            });
+        }
+        self.input.update(cx, |input, cx| {
+            input.set_value(state.timestamp, window, cx);
+        });
+        self.date_input.update(cx, |input, cx| {
+            input.set_value(state.date, window, cx);
+        });
+    }
 }
 
 impl Focusable for UnixTimestampConverterTool {
@@ -139,8 +394,19 @@ impl Render for UnixTimestampConverterTool {
         let days_since_epoch = self.days_since_epoch.read(cx).value().clone();
         let months_since_epoch = self.months_since_epoch.read(cx).value().clone();
         let day_of_year = self.day_of_year.read(cx).value().clone();
+        let timestamp_seconds = self.timestamp_seconds.read(cx).value().clone();
+        let timestamp_milliseconds = self.timestamp_milliseconds.read(cx).value().clone();
+        let unit = self.unit;
+        let convert_label = match (unit, value.trim().parse::<i64>()) {
+            (Unit::Auto, Ok(timestamp)) => {
+                format!("Convert ({}, Auto)", Unit::detect(timestamp).abbreviation())
+            }
+            (Unit::Auto, Err(_)) => "Convert".to_string(),
+            (unit, _) => format!("Convert ({})", unit.abbreviation()),
+        };
 
         div()
+            .on_action(cx.listener(Self::on_action_set_unit))
             .v_flex()
             .size_full()
             .gap_2()
@@ -148,11 +414,43 @@ impl Render for UnixTimestampConverterTool {
                 h_flex()
                     .gap_2()
                     .child(
-                        Button::new("convert-button")
-                            .label("Convert")
+                        DropdownButton::new("convert-dropdown-button")
                             .primary()
-                            .disabled(value.is_empty())
-                            .on_click(cx.listener(Self::on_convert_click)),
+                            .button(
+                                Button::new("convert-button")
+                                    .label(convert_label)
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_convert_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Unit")
+                                    .menu_with_check(
+                                        "Auto-Detect",
+                                        unit == Unit::Auto,
+                                        Box::new(SetUnit(Unit::Auto)),
+                                    )
+                                    .menu_with_check(
+                                        "Seconds",
+                                        unit == Unit::Seconds,
+                                        Box::new(SetUnit(Unit::Seconds)),
+                                    )
+                                    .menu_with_check(
+                                        "Milliseconds",
+                                        unit == Unit::Milliseconds,
+                                        Box::new(SetUnit(Unit::Milliseconds)),
+                                    )
+                                    .menu_with_check(
+                                        "Microseconds",
+                                        unit == Unit::Microseconds,
+                                        Box::new(SetUnit(Unit::Microseconds)),
+                                    )
+                                    .menu_with_check(
+                                        "Nanoseconds",
+                                        unit == Unit::Nanoseconds,
+                                        Box::new(SetUnit(Unit::Nanoseconds)),
+                                    )
+                            }),
                     )
                     .child(
                         Button::new("now-button")
@@ -269,27 +567,69 @@ impl Render for UnixTimestampConverterTool {
                             ),
                     ),
             )
-    }
-}
-
-fn format_relative_time(duration: Duration) -> String {
-    if duration.num_seconds() < 0 {
-        let future_seconds = -duration.num_seconds();
-        return format!("in {} seconds", future_seconds);
-    }
-
-    let seconds = duration.num_seconds();
-    let minutes = duration.num_minutes();
-    let hours = duration.num_hours();
-    let days = duration.num_days();
-
-    if days > 0 {
-        return format!("{} days ago", days);
-    } else if hours > 0 {
-        return format!("{} hours ago", hours);
-    } else if minutes > 0 {
-        return format!("{} minutes ago", minutes);
-    } else {
-        return format!("{} seconds ago", seconds);
+            .child(Label::new("Date to Timestamp").mt_4())
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.date_input)
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .child(
+                        div()
+                            .w(px(160.))
+                            .flex_none()
+                            .child(DatePicker::new(&self.date_picker).placeholder("Pick Date")),
+                    )
+                    .child(
+                        div().w(px(240.)).flex_none().child(
+                            Dropdown::new(&self.zone)
+                                .placeholder("Timezone")
+                                .menu_width(px(320.)),
+                        ),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .items_start()
+                    .gap_2()
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("Seconds"))
+                            .child(
+                                TextInput::new(&self.timestamp_seconds)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("timestamp-seconds-clipboard")
+                                            .value_fn(move |_, _| timestamp_seconds.clone()),
+                                    ),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .v_flex()
+                            .w_full()
+                            .gap_2()
+                            .child(Label::new("Milliseconds"))
+                            .child(
+                                TextInput::new(&self.timestamp_milliseconds)
+                                    .text_size(px(15.))
+                                    .focus_bordered(false)
+                                    .suffix(
+                                        Clipboard::new("timestamp-milliseconds-clipboard")
+                                            .value_fn(move |_, _| timestamp_milliseconds.clone()),
+                                    ),
+                            ),
+                    ),
+            )
     }
 }
@@ -1,8 +1,6 @@
-use percent_encoding::percent_decode_str;
-
 use gpui::{
     App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
-    ParentElement, Render, SharedString, Styled, Window, div, px,
+    ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
 };
 
 use gpui_component::{
@@ -10,15 +8,21 @@ use gpui_component::{
     button::{Button, ButtonVariants},
     h_flex,
     highlighter::Language,
-    input::{InputState, TabSize, TextInput},
+    input::{InputEvent, InputState, TabSize, TextInput},
 };
 
-use crate::Tool;
+use serde_json::Value;
+
+use crate::{LiveUpdate, Tool, ToolError, core::url, editor_font};
 
 pub struct URLDecoderTool {
     focus_handle: FocusHandle,
     editor: Entity<InputState>,
     decoded: Entity<InputState>,
+    error: Option<ToolError>,
+    live: bool,
+    live_update: LiveUpdate,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl URLDecoderTool {
@@ -49,23 +53,49 @@ impl URLDecoderTool {
                 .default_value("")
                 .placeholder("Decoded text")
         });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
 
         Self {
             focus_handle: cx.focus_handle(),
             editor,
             decoded,
+            error: None,
+            live: false,
+            live_update: LiveUpdate::default(),
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e
+            && self.live
+        {
+            self.live_update.schedule(Self::update_decoded, window, cx);
         }
     }
 
     fn on_decode_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_decoded(window, cx);
+    }
+
+    fn update_decoded(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         let value = self.editor.read(cx).value().clone();
-        self.decoded.update(cx, |state, cx| {
-            let decoded_value = percent_decode_str(&value)
-                .decode_utf8()
-                .map(|s| s.to_string())
-                .unwrap_or_default();
-            state.set_value(SharedString::from(decoded_value), window, cx);
-        })
+        match url::decode(&value) {
+            Ok(decoded_value) => {
+                self.error = None;
+                self.decoded.update(cx, |state, cx| {
+                    state.set_value(SharedString::from(decoded_value), window, cx);
+                });
+            }
+            Err(err) => self.error = Some(err),
+        }
+        cx.notify();
     }
 
     fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
@@ -123,6 +153,49 @@ impl Tool for URLDecoderTool {
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
         Self::view(window, cx)
     }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_decode_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_decoded_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.decoded.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn supports_live() -> bool {
+        true
+    }
+
+    fn set_live(&mut self, live: bool, window: &mut Window, cx: &mut Context<Self>) {
+        self.live = live;
+        if live {
+            self.update_decoded(window, cx);
+        }
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.editor.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
 }
 
 impl Focusable for URLDecoderTool {
@@ -168,7 +241,7 @@ impl Render for URLDecoderTool {
             .child(
                 TextInput::new(&self.editor)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
@@ -190,7 +263,7 @@ impl Render for URLDecoderTool {
             .child(
                 TextInput::new(&self.decoded)
                     .h_full()
-                    .font_family("Space Mono")
+                    .font_family(editor_font(cx))
                     .text_size(px(15.))
                     .focus_bordered(false),
             )
@@ -0,0 +1,412 @@
+use gpui::{
+    App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, StatefulInteractiveElement, Styled, Subscription,
+    Window, div, px,
+};
+
+use gpui_component::{
+    StyledExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+};
+
+use serde_json::Value;
+use url::{Url, form_urlencoded};
+
+use crate::{Tool, ToolError, editor_font};
+
+struct Param {
+    key: Entity<InputState>,
+    value: Entity<InputState>,
+}
+
+pub struct URLParserTool {
+    focus_handle: FocusHandle,
+    input: Entity<InputState>,
+    scheme: Entity<InputState>,
+    host: Entity<InputState>,
+    port: Entity<InputState>,
+    path: Entity<InputState>,
+    fragment: Entity<InputState>,
+    params: Vec<Param>,
+    /// The user name and password of the last parsed URL, which are kept
+    /// when the URL is built again.
+    userinfo: String,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl URLParserTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("URL, e.g. https://example.com/search?q=rust")
+        });
+        let scheme = cx.new(|cx| InputState::new(window, cx).placeholder("Scheme"));
+        let host = cx.new(|cx| InputState::new(window, cx).placeholder("Host"));
+        let port = cx.new(|cx| InputState::new(window, cx).placeholder("Port"));
+        let path = cx.new(|cx| InputState::new(window, cx).placeholder("Path"));
+        let fragment = cx.new(|cx| InputState::new(window, cx).placeholder("Fragment"));
+        let _subscriptions = vec![cx.subscribe_in(&input, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            input,
+            scheme,
+            host,
+            port,
+            path,
+            fragment,
+            params: Vec::new(),
+            userinfo: String::new(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn new_param(key: &str, value: &str, window: &mut Window, cx: &mut Context<Self>) -> Param {
+        let key = key.to_string();
+        let value = value.to_string();
+        Param {
+            key: cx.new(|cx| {
+                InputState::new(window, cx)
+                    .default_value(key)
+                    .placeholder("Key")
+            }),
+            value: cx.new(|cx| {
+                InputState::new(window, cx)
+                    .default_value(value)
+                    .placeholder("Value")
+            }),
+        }
+    }
+
+    /// Splits the URL into its parts, with the query parameters and fragment
+    /// percent-decoded.
+    fn update_parts(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        if value.trim().is_empty() {
+            self.error = None;
+            cx.notify();
+            return;
+        }
+        let url = match Url::parse(value.trim()) {
+            Ok(url) => url,
+            Err(err) => {
+                self.error = Some(ToolError::from(err).context("Invalid URL"));
+                cx.notify();
+                return;
+            }
+        };
+        self.error = None;
+
+        self.userinfo = match (url.username(), url.password()) {
+            ("", None) => String::new(),
+            (username, None) => format!("{}@", username),
+            (username, Some(password)) => format!("{}:{}@", username, password),
+        };
+        let fragment = url.fragment().map(|fragment| {
+            percent_encoding::percent_decode_str(fragment)
+                .decode_utf8_lossy()
+                .to_string()
+        });
+        let fields = [
+            (&self.scheme, Some(url.scheme().to_string())),
+            (&self.host, url.host_str().map(str::to_string)),
+            (
+                &self.port,
+                url.port_or_known_default().map(|port| port.to_string()),
+            ),
+            (&self.path, Some(url.path().to_string())),
+            (&self.fragment, fragment),
+        ];
+        for (state, value) in fields {
+            state.update(cx, |state, cx| {
+                state.set_value(value.unwrap_or_default(), window, cx);
+            });
+        }
+        self.params = url
+            .query_pairs()
+            .map(|(key, value)| Self::new_param(&key, &value, window, cx))
+            .collect();
+        cx.notify();
+    }
+
+    /// Puts the URL back together from its parts. Default ports are dropped,
+    /// and the query is encoded as a form would encode it.
+    fn build(&self, cx: &App) -> Result<Url, ToolError> {
+        let read = |state: &Entity<InputState>| state.read(cx).value().trim().to_string();
+        let (scheme, host, port, path, fragment) = (
+            read(&self.scheme),
+            read(&self.host),
+            read(&self.port),
+            read(&self.path),
+            read(&self.fragment),
+        );
+        if scheme.is_empty() {
+            return Err(ToolError::new("the scheme is missing"));
+        }
+
+        let mut url = scheme.clone() + ":";
+        if !host.is_empty() {
+            url += &format!("//{}{}", self.userinfo, host);
+            if !port.is_empty() {
+                let port: u16 = port
+                    .parse()
+                    .map_err(|_| ToolError::new(format!("{:?} is not a valid port", port)))?;
+                url += &format!(":{}", port);
+            }
+            if !path.is_empty() && !path.starts_with('/') {
+                url.push('/');
+            }
+        }
+        url += &path;
+
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        for param in &self.params {
+            let key = param.key.read(cx).value();
+            let value = param.value.read(cx).value();
+            if !key.is_empty() || !value.is_empty() {
+                query.append_pair(&key, &value);
+            }
+        }
+        let query = query.finish();
+        if !query.is_empty() {
+            url += &format!("?{}", query);
+        }
+
+        let mut url = Url::parse(&url)?;
+        if !fragment.is_empty() {
+            url.set_fragment(Some(&fragment));
+        }
+        Ok(url)
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e {
+            self.update_parts(window, cx);
+        }
+    }
+
+    fn on_build_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        match self.build(cx) {
+            Ok(url) => {
+                self.input.update(cx, |state, cx| {
+                    state.set_value(url.to_string(), window, cx);
+                });
+            }
+            Err(err) => {
+                self.error = Some(err.context("Cannot build URL"));
+                cx.notify();
+            }
+        }
+    }
+
+    fn on_add_param_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let param = Self::new_param("", "", window, cx);
+        self.params.push(param);
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.input.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.input.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+}
+
+impl Tool for URLParserTool {
+    fn title() -> &'static str {
+        "URL Parser"
+    }
+
+    fn short_title() -> &'static str {
+        "Parser"
+    }
+
+    fn description() -> &'static str {
+        "Splits a URL into its parts and query parameters, and builds it again after editing."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_build_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.input.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn state(&self, cx: &App) -> Option<Value> {
+        Some(Value::from(self.input.read(cx).value().to_string()))
+    }
+
+    fn restore_state(&mut self, state: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(input) = state.as_str() {
+            self.input.update(cx, |state, cx| {
+                state.set_value(input.to_string(), window, cx);
+            });
+        }
+    }
+}
+
+impl Focusable for URLParserTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for URLParserTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let field = |label: &'static str, state: &Entity<InputState>| {
+            div()
+                .v_flex()
+                .w_full()
+                .gap_2()
+                .child(Label::new(label))
+                .child(
+                    TextInput::new(state)
+                        .font_family(editor_font(cx))
+                        .text_size(px(15.))
+                        .focus_bordered(false),
+                )
+        };
+
+        div()
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("build-button")
+                            .label("Build URL")
+                            .primary()
+                            .on_click(cx.listener(Self::on_build_click)),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.input)
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .child(field("Scheme", &self.scheme))
+                    .child(field("Host", &self.host))
+                    .child(
+                        div()
+                            .w(px(120.))
+                            .flex_none()
+                            .child(field("Port", &self.port)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .child(field("Path", &self.path))
+                    .child(field("Fragment", &self.fragment)),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Label::new("Query Parameters"))
+                    .child(
+                        Button::new("add-param-button")
+                            .label("Add Parameter")
+                            .on_click(cx.listener(Self::on_add_param_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                div()
+                    .id("params")
+                    .v_flex()
+                    .flex_1()
+                    .gap_2()
+                    .overflow_y_scroll()
+                    .children(self.params.iter().enumerate().map(|(ix, param)| {
+                        h_flex()
+                            .gap_2()
+                            .child(
+                                div().flex_1().child(
+                                    TextInput::new(&param.key)
+                                        .font_family(editor_font(cx))
+                                        .text_size(px(15.))
+                                        .focus_bordered(false),
+                                ),
+                            )
+                            .child(
+                                div().flex_1().child(
+                                    TextInput::new(&param.value)
+                                        .font_family(editor_font(cx))
+                                        .text_size(px(15.))
+                                        .focus_bordered(false),
+                                ),
+                            )
+                            .child(
+                                Button::new(("remove-param-button", ix))
+                                    .label("Remove")
+                                    .on_click(cx.listener(move |this, _, _, cx| {
+                                        this.params.remove(ix);
+                                        cx.notify();
+                                    })),
+                            )
+                    })),
+            )
+    }
+}
@@ -0,0 +1,336 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Window, div, px,
+};
+
+use gpui_component::{
+    Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{Tool, ToolError, editor_font};
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum Version {
+    V4,
+    V7,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = generator_tools, no_json)]
+pub struct SetVersion(Version);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = generator_tools, no_json)]
+pub struct SetHyphenated(bool);
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = generator_tools, no_json)]
+pub struct SetUppercase(bool);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    version: Version,
+    hyphenated: bool,
+    uppercase: bool,
+    count: String,
+}
+
+pub struct UUIDGeneratorTool {
+    focus_handle: FocusHandle,
+    count: Entity<InputState>,
+    generated: Entity<InputState>,
+    version: Version,
+    hyphenated: bool,
+    uppercase: bool,
+    error: Option<ToolError>,
+}
+
+impl UUIDGeneratorTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let count = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value("10")
+                .placeholder("Count")
+        });
+        let generated = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("Generated UUIDs")
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            count,
+            generated,
+            version: Version::V4,
+            hyphenated: true,
+            uppercase: false,
+            error: None,
+        }
+    }
+
+    fn count(&self, cx: &App) -> Result<usize, ToolError> {
+        match self.count.read(cx).value().trim().parse::<usize>() {
+            Ok(count) if count > 0 => Ok(count),
+            _ => Err(ToolError::new("Count must be a positive number")),
+        }
+    }
+
+    fn format(&self, uuid: Uuid) -> String {
+        let formatted = if self.hyphenated {
+            uuid.hyphenated().to_string()
+        } else {
+            uuid.simple().to_string()
+        };
+        if self.uppercase {
+            formatted.to_uppercase()
+        } else {
+            formatted
+        }
+    }
+
+    fn on_generate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let generated_value = match self.count(cx) {
+            Ok(count) => {
+                self.error = None;
+                (0..count)
+                    .map(|_| match self.version {
+                        Version::V4 => Uuid::new_v4(),
+                        Version::V7 => Uuid::now_v7(),
+                    })
+                    .map(|uuid| self.format(uuid))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            Err(err) => {
+                self.error = Some(err);
+                String::new()
+            }
+        };
+        self.generated.update(cx, |state, cx| {
+            state.set_value(SharedString::from(generated_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.generated.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    /// Reformats UUIDs that were already generated, so that the toggles apply
+    /// without drawing new ones.
+    fn update_formatted(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.generated.read(cx).value().clone();
+        let uuids: Option<Vec<Uuid>> = value
+            .lines()
+            .map(|line| Uuid::parse_str(line).ok())
+            .collect();
+        if let Some(uuids) = uuids.filter(|uuids| !uuids.is_empty()) {
+            let formatted_value = uuids
+                .into_iter()
+                .map(|uuid| self.format(uuid))
+                .collect::<Vec<_>>()
+                .join("\n");
+            self.generated.update(cx, |state, cx| {
+                state.set_value(SharedString::from(formatted_value), window, cx);
+            });
+        }
+        cx.notify();
+    }
+
+    fn on_action_set_version(
+        &mut self,
+        action: &SetVersion,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.version = action.0;
+        cx.notify();
+    }
+
+    fn on_action_set_hyphenated(
+        &mut self,
+        action: &SetHyphenated,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.hyphenated = action.0;
+        self.update_formatted(window, cx);
+    }
+
+    fn on_action_set_uppercase(
+        &mut self,
+        action: &SetUppercase,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.uppercase = action.0;
+        self.update_formatted(window, cx);
+    }
+}
+
+impl Tool for UUIDGeneratorTool {
+    fn title() -> &'static str {
+        "UUID Generator"
+    }
+
+    fn short_title() -> &'static str {
+        "UUID"
+    }
+
+    fn description() -> &'static str {
+        "Generates v4 and v7 UUIDs in bulk."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_generate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.generated.clone())
+    }
+
+    fn preset(&self, _: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            version: self.version,
+            hyphenated: self.hyphenated,
+            uppercase: self.uppercase,
+            count: self.count.read(cx).value().to_string(),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.version = preset.version;
+        self.hyphenated = preset.hyphenated;
+        self.uppercase = preset.uppercase;
+        self.count.update(cx, |state, cx| {
+            state.set_value(preset.count, window, cx);
+        });
+        self.update_formatted(window, cx);
+    }
+}
+
+impl Focusable for UUIDGeneratorTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for UUIDGeneratorTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let count = self.count(cx);
+        let version = self.version;
+        let hyphenated = self.hyphenated;
+        let uppercase = self.uppercase;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_version))
+            .on_action(cx.listener(Self::on_action_set_hyphenated))
+            .on_action(cx.listener(Self::on_action_set_uppercase))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("generate-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("generate-button")
+                                    .label(match version {
+                                        Version::V4 => "Generate v4",
+                                        Version::V7 => "Generate v7",
+                                    })
+                                    .primary()
+                                    .disabled(count.is_err())
+                                    .on_click(cx.listener(Self::on_generate_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Version")
+                                    .menu_with_check(
+                                        "v4 (Random)",
+                                        version == Version::V4,
+                                        Box::new(SetVersion(Version::V4)),
+                                    )
+                                    .menu_with_check(
+                                        "v7 (Time-Ordered)",
+                                        version == Version::V7,
+                                        Box::new(SetVersion(Version::V7)),
+                                    )
+                                    .separator()
+                                    .label("Format")
+                                    .menu_with_check(
+                                        "Hyphens",
+                                        hyphenated,
+                                        Box::new(SetHyphenated(!hyphenated)),
+                                    )
+                                    .menu_with_check(
+                                        "Uppercase",
+                                        uppercase,
+                                        Box::new(SetUppercase(!uppercase)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy All")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(Label::new("Count"))
+            .child(
+                TextInput::new(&self.count)
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+            .child(
+                TextInput::new(&self.generated)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
@@ -0,0 +1,301 @@
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    InteractiveElement, ParentElement, Render, SharedString, Styled, Subscription, Window, div,
+    prelude::FluentBuilder, px,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Tool, ToolError,
+    core::xml::{compact, format, validate},
+    default_indentation, editor_font,
+};
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = xml_tools, no_json)]
+pub struct SetIndentationSize(usize);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    indentation_size: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+pub struct XMLFormatterTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    indentation_size: usize,
+    well_formed: bool,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl XMLFormatterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Html.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("XML Source")
+        });
+        let _subscriptions = vec![cx.subscribe_in(&editor, window, Self::on_input_event)];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            indentation_size: default_indentation(cx),
+            well_formed: false,
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<InputState>,
+        e: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = e
+            && self.well_formed
+        {
+            self.well_formed = false;
+            cx.notify();
+        }
+    }
+
+    fn on_format_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let indentation_size = self.indentation_size;
+        self.reformat(|value| format(value, indentation_size), window, cx);
+    }
+
+    fn on_compact_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.reformat(compact, window, cx);
+    }
+
+    fn on_validate_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        self.set_validated(validate(&value), cx);
+    }
+
+    /// Checks that the editor's XML is well-formed and replaces it with the
+    /// output of `format`, or leaves it untouched and reports where it broke.
+    fn reformat(
+        &mut self,
+        format: impl FnOnce(&str) -> Result<String, ToolError>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self.editor.read(cx).value().clone();
+        let formatted = format(&value);
+        if let Ok(formatted) = &formatted {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(SharedString::from(formatted.clone()), window, cx);
+            });
+        }
+        self.set_validated(formatted.map(|_| ()), cx);
+    }
+
+    fn set_validated(&mut self, validated: Result<(), ToolError>, cx: &mut Context<Self>) {
+        self.well_formed = validated.is_ok();
+        self.error = validated.err();
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_action_set_indentation_size(
+        &mut self,
+        action: &SetIndentationSize,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.indentation_size = action.0;
+        cx.notify();
+    }
+}
+
+impl Tool for XMLFormatterTool {
+    fn title() -> &'static str {
+        "XML Formatter"
+    }
+
+    fn short_title() -> &'static str {
+        "Formatter"
+    }
+
+    fn description() -> &'static str {
+        "Formats or compacts XML and checks that it is well-formed."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_format_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.editor.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<Value> {
+        let preset = Preset {
+            indentation_size: self.indentation_size,
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(&mut self, preset: &Value, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_indentation_size(
+            &SetIndentationSize(preset.indentation_size),
+            window,
+            cx,
+        );
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for XMLFormatterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for XMLFormatterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let indentation_size = self.indentation_size;
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_indentation_size))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("format-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("format-button")
+                                    .label("Format")
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_format_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Indentation Size")
+                                    .menu_with_check(
+                                        "2",
+                                        indentation_size == 2,
+                                        Box::new(SetIndentationSize(2)),
+                                    )
+                                    .menu_with_check(
+                                        "3",
+                                        indentation_size == 3,
+                                        Box::new(SetIndentationSize(3)),
+                                    )
+                                    .menu_with_check(
+                                        "4",
+                                        indentation_size == 4,
+                                        Box::new(SetIndentationSize(4)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        Button::new("compact-button")
+                            .label("Compact")
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_compact_click)),
+                    )
+                    .child(
+                        Button::new("validate-button")
+                            .label("Validate")
+                            .disabled(value.is_empty())
+                            .on_click(cx.listener(Self::on_validate_click)),
+                    )
+                    .when(self.well_formed, |this| {
+                        this.child(Label::new("Well-formed").text_color(cx.theme().green))
+                    })
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click))
+                            .ml_auto(),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.editor)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}
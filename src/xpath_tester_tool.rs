@@ -0,0 +1,636 @@
+use std::ops::Range;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, ClipboardItem, Context, Entity, FocusHandle, Focusable,
+    HighlightStyle, InteractiveElement, ParentElement, Render, SharedString,
+    StatefulInteractiveElement, Styled, StyledText, Subscription, Window, div,
+    prelude::FluentBuilder, px, rems,
+};
+
+use gpui_component::{
+    ActiveTheme, Disableable, Size, StyleSized, StyledExt,
+    button::{Button, ButtonVariants, DropdownButton},
+    h_flex,
+    highlighter::Language,
+    input::{InputEvent, InputState, TabSize, TextInput},
+    label::Label,
+};
+
+use html_escape::{encode_double_quoted_attribute, encode_text};
+use serde::{Deserialize, Serialize};
+use sxd_document::{Package, QName, dom};
+use sxd_xpath::{Context as XPathContext, Factory, Value, nodeset::Node};
+
+use crate::{Tool, ToolError, editor_font};
+
+/// The prefix the document's default namespace is bound to, since XPath 1.0
+/// has no way to name elements in it without one.
+const DEFAULT_PREFIX: &str = "default";
+
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum DocumentKind {
+    Xml,
+    Html,
+}
+
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = xml_tools, no_json)]
+pub struct SetDocumentKind(DocumentKind);
+
+#[derive(Serialize, Deserialize)]
+struct Preset {
+    document_kind: DocumentKind,
+    expression: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+}
+
+struct XPathMatch {
+    description: String,
+    value: String,
+    range: Option<Range<usize>>,
+}
+
+struct Evaluation {
+    /// The text the match ranges point into: the source itself for XML, and
+    /// the document as parsed and serialized again for HTML.
+    source: String,
+    matches: Vec<XPathMatch>,
+    /// Whether the root element has a default namespace.
+    default_namespace: bool,
+}
+
+pub struct XPathTesterTool {
+    focus_handle: FocusHandle,
+    editor: Entity<InputState>,
+    expression: Entity<InputState>,
+    results: Entity<InputState>,
+    document_kind: DocumentKind,
+    match_count: Option<usize>,
+    highlighted_text: String,
+    highlighted_ranges: Vec<Range<usize>>,
+    error: Option<ToolError>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl XPathTesterTool {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let editor = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Html.name().to_string())
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+                .default_value("")
+                .placeholder("XML Source")
+        });
+        let expression = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(format!(
+                "XPath Expression (name elements in a default namespace with \"{}:\")",
+                DEFAULT_PREFIX
+            ))
+        });
+        let results = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(Language::Plain.name().to_string())
+                .line_number(false)
+                .default_value("")
+                .placeholder("Matches")
+        });
+        let _subscriptions =
+            vec![
+                cx.subscribe_in(&expression, window, |this, _, e, window, cx| {
+                    if let InputEvent::PressEnter { .. } = e {
+                        this.evaluate(window, cx);
+                    }
+                }),
+            ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor,
+            expression,
+            results,
+            document_kind: DocumentKind::Xml,
+            match_count: None,
+            highlighted_text: String::new(),
+            highlighted_ranges: Vec::new(),
+            error: None,
+            _subscriptions,
+        }
+    }
+
+    fn evaluate(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let source = self.editor.read(cx).value().to_string();
+        let expression = self.expression.read(cx).value().to_string();
+        if source.is_empty() || expression.trim().is_empty() {
+            self.error = None;
+            return;
+        }
+
+        let results_value = match evaluate_xpath(&source, &expression, self.document_kind) {
+            Ok(Evaluation {
+                source,
+                matches,
+                default_namespace,
+            }) => {
+                self.error = None;
+                self.match_count = Some(matches.len());
+                self.highlighted_ranges =
+                    merge_ranges(matches.iter().filter_map(|m| m.range.clone()).collect());
+                self.highlighted_text = if self.highlighted_ranges.is_empty() {
+                    String::new()
+                } else {
+                    source
+                };
+                if matches.is_empty() && default_namespace {
+                    format!(
+                        "The document has a default namespace, so its elements only match with \
+                         the \"{0}:\" prefix, as in //{0}:item.",
+                        DEFAULT_PREFIX
+                    )
+                } else {
+                    matches
+                        .iter()
+                        .enumerate()
+                        .map(|(i, m)| format!("{}. {}\n{}", i + 1, m.description, m.value))
+                        .collect::<Vec<_>>()
+                        .join("\n\n")
+                }
+            }
+            Err(err) => {
+                self.match_count = None;
+                self.highlighted_text = String::new();
+                self.highlighted_ranges = Vec::new();
+                self.error = Some(err);
+                String::new()
+            }
+        };
+
+        self.results.update(cx, |state, cx| {
+            state.set_value(SharedString::from(results_value), window, cx);
+        });
+        cx.notify();
+    }
+
+    fn on_evaluate_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.evaluate(window, cx);
+    }
+
+    fn on_edit_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.highlighted_text = String::new();
+        self.highlighted_ranges = Vec::new();
+        cx.notify();
+    }
+
+    fn on_copy_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.editor.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_paste_click(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(clipboard) = cx.read_from_clipboard() {
+            let value = clipboard.text().unwrap_or_default();
+            self.highlighted_text = String::new();
+            self.highlighted_ranges = Vec::new();
+            self.editor.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+            })
+        }
+    }
+
+    fn on_copy_results_click(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let value = self.results.read(cx).value().clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    }
+
+    fn on_action_set_document_kind(
+        &mut self,
+        action: &SetDocumentKind,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.document_kind = action.0;
+        cx.notify();
+    }
+}
+
+fn evaluate_xpath(
+    source: &str,
+    expression: &str,
+    document_kind: DocumentKind,
+) -> Result<Evaluation, ToolError> {
+    let package = Package::new();
+    let document = package.as_document();
+    let mut context = XPathContext::new();
+    let mut ranges = Vec::new();
+    let mut default_namespace = false;
+
+    let source = match document_kind {
+        DocumentKind::Xml => {
+            let parsed = roxmltree::Document::parse(source)
+                .map_err(|err| ToolError::new(err.to_string()).context("Invalid XML"))?;
+            let root = parsed.root_element();
+            for namespace in root.namespaces() {
+                default_namespace |= namespace.name().is_none();
+                context.set_namespace(namespace.name().unwrap_or(DEFAULT_PREFIX), namespace.uri());
+            }
+            let element = build_xml_element(document, root, &mut ranges);
+            document.root().append_child(element);
+            source.to_string()
+        }
+        DocumentKind::Html => {
+            let parsed = scraper::Html::parse_document(source);
+            let mut out = String::new();
+            for child in parsed.tree.root().children() {
+                if let scraper::Node::Doctype(doctype) = child.value() {
+                    out.push_str(&format!("<!DOCTYPE {}>\n", doctype.name()));
+                }
+            }
+            let element =
+                build_html_element(document, parsed.root_element(), &mut out, &mut ranges);
+            document.root().append_child(element);
+            out
+        }
+    };
+
+    let xpath = Factory::new()
+        .build(expression)
+        .map_err(|err| ToolError::new(err.to_string()).context("Invalid XPath"))?
+        .ok_or_else(|| ToolError::new("XPath was empty"))?;
+    let value = xpath
+        .evaluate(&context, document.root())
+        .map_err(|err| ToolError::new(err.to_string()).context("Evaluation failed"))?;
+
+    let matches = match value {
+        Value::Nodeset(nodeset) => nodeset
+            .document_order()
+            .into_iter()
+            .map(|node| XPathMatch {
+                description: describe_node(&node),
+                value: node.string_value(),
+                range: ranges
+                    .iter()
+                    .find(|(candidate, _)| *candidate == node)
+                    .map(|(_, range)| range.clone()),
+            })
+            .collect(),
+        Value::Boolean(b) => vec![XPathMatch {
+            description: "boolean".to_string(),
+            value: b.to_string(),
+            range: None,
+        }],
+        Value::Number(n) => vec![XPathMatch {
+            description: "number".to_string(),
+            value: n.to_string(),
+            range: None,
+        }],
+        Value::String(s) => vec![XPathMatch {
+            description: "string".to_string(),
+            value: s,
+            range: None,
+        }],
+    };
+    Ok(Evaluation {
+        source,
+        matches,
+        default_namespace,
+    })
+}
+
+fn build_xml_element<'d>(
+    document: dom::Document<'d>,
+    node: roxmltree::Node,
+    ranges: &mut Vec<(Node<'d>, Range<usize>)>,
+) -> dom::Element<'d> {
+    let tag_name = node.tag_name();
+    let element = document.create_element(QName::with_namespace_uri(
+        tag_name.namespace(),
+        tag_name.name(),
+    ));
+    ranges.push((Node::Element(element), node.range()));
+    for attribute in node.attributes() {
+        let name = QName::with_namespace_uri(attribute.namespace(), attribute.name());
+        let created = element.set_attribute_value(name, attribute.value());
+        ranges.push((Node::Attribute(created), attribute.range()));
+    }
+    for child in node.children() {
+        if child.is_element() {
+            element.append_child(build_xml_element(document, child, ranges));
+        } else if child.is_text() {
+            let text = document.create_text(child.text().unwrap_or_default());
+            ranges.push((Node::Text(text), child.range()));
+            element.append_child(text);
+        } else if child.is_comment() {
+            let comment = document.create_comment(child.text().unwrap_or_default());
+            ranges.push((Node::Comment(comment), child.range()));
+            element.append_child(comment);
+        }
+    }
+    element
+}
+
+/// Copies an HTML element into `document`, serializing it to `out` along
+/// the way so that its nodes have ranges to highlight.
+fn build_html_element<'d>(
+    document: dom::Document<'d>,
+    node: scraper::ElementRef,
+    out: &mut String,
+    ranges: &mut Vec<(Node<'d>, Range<usize>)>,
+) -> dom::Element<'d> {
+    let start = out.len();
+    let name = node.value().name();
+    let element = document.create_element(name);
+    out.push('<');
+    out.push_str(name);
+    for (attribute, value) in node.value().attrs() {
+        out.push(' ');
+        let start = out.len();
+        out.push_str(&format!(
+            "{}=\"{}\"",
+            attribute,
+            encode_double_quoted_attribute(value)
+        ));
+        let created = element.set_attribute_value(attribute, value);
+        ranges.push((Node::Attribute(created), start..out.len()));
+    }
+    out.push('>');
+    if !VOID_ELEMENTS.contains(&name) {
+        let raw_text = matches!(name, "script" | "style");
+        for child in node.children() {
+            let start = out.len();
+            match child.value() {
+                scraper::Node::Element(_) => {
+                    if let Some(child) = scraper::ElementRef::wrap(child) {
+                        element.append_child(build_html_element(document, child, out, ranges));
+                    }
+                }
+                scraper::Node::Text(text) => {
+                    if raw_text {
+                        out.push_str(text);
+                    } else {
+                        out.push_str(&encode_text(&**text));
+                    }
+                    let text = document.create_text(text);
+                    ranges.push((Node::Text(text), start..out.len()));
+                    element.append_child(text);
+                }
+                scraper::Node::Comment(comment) => {
+                    out.push_str(&format!("<!--{}-->", &**comment));
+                    let comment = document.create_comment(comment);
+                    ranges.push((Node::Comment(comment), start..out.len()));
+                    element.append_child(comment);
+                }
+                _ => {}
+            }
+        }
+        out.push_str(&format!("</{}>", name));
+    }
+    ranges.push((Node::Element(element), start..out.len()));
+    element
+}
+
+fn describe_node(node: &Node) -> String {
+    match node {
+        Node::Element(_) => format!("<{}>", node.prefixed_name().unwrap_or_default()),
+        Node::Attribute(_) => format!("@{}", node.prefixed_name().unwrap_or_default()),
+        Node::Text(_) => "text()".to_string(),
+        Node::Comment(_) => "comment()".to_string(),
+        Node::Root(_) => "/".to_string(),
+        Node::Namespace(_) => format!("namespace {}", node.prefixed_name().unwrap_or_default()),
+        Node::ProcessingInstruction(_) => {
+            format!("<?{}?>", node.prefixed_name().unwrap_or_default())
+        }
+    }
+}
+
+fn merge_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+impl Tool for XPathTesterTool {
+    fn title() -> &'static str {
+        "XPath Tester"
+    }
+
+    fn short_title() -> &'static str {
+        "XPath Tester"
+    }
+
+    fn description() -> &'static str {
+        "Evaluates XPath expressions against XML or HTML and lists the matching nodes."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
+        Self::view(window, cx)
+    }
+
+    fn error(&self) -> Option<ToolError> {
+        self.error.clone()
+    }
+
+    fn primary_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_evaluate_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn copy_output(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_copy_results_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn output(&self) -> Option<Entity<InputState>> {
+        Some(self.results.clone())
+    }
+
+    fn paste_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_paste_click(&ClickEvent::default(), window, cx);
+    }
+
+    fn preset(&self, include_input: bool, cx: &App) -> Option<serde_json::Value> {
+        let preset = Preset {
+            document_kind: self.document_kind,
+            expression: self.expression.read(cx).value().to_string(),
+            input: include_input.then(|| self.editor.read(cx).value().to_string()),
+        };
+        serde_json::to_value(preset).ok()
+    }
+
+    fn apply_preset(
+        &mut self,
+        preset: &serde_json::Value,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Ok(preset) = Preset::deserialize(preset) else {
+            return;
+        };
+        self.on_action_set_document_kind(&SetDocumentKind(preset.document_kind), window, cx);
+        self.expression.update(cx, |state, cx| {
+            state.set_value(preset.expression, window, cx);
+        });
+        if let Some(input) = preset.input {
+            self.editor.update(cx, |state, cx| {
+                state.set_value(input, window, cx);
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for XPathTesterTool {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for XPathTesterTool {
+    fn render(
+        &mut self,
+        _: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let value = self.editor.read(cx).value();
+        let document_kind = self.document_kind;
+        let match_count = self.match_count;
+        let highlight = HighlightStyle {
+            background_color: Some(cx.theme().yellow.opacity(0.35)),
+            ..Default::default()
+        };
+        let highlights: Vec<_> = self
+            .highlighted_ranges
+            .iter()
+            .map(|range| (range.clone(), highlight))
+            .collect();
+
+        div()
+            .on_action(cx.listener(Self::on_action_set_document_kind))
+            .v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        DropdownButton::new("evaluate-dropdown-button")
+                            .primary()
+                            .button(
+                                Button::new("evaluate-button")
+                                    .label("Evaluate")
+                                    .primary()
+                                    .disabled(value.is_empty())
+                                    .on_click(cx.listener(Self::on_evaluate_click)),
+                            )
+                            .popup_menu(move |this, _, _| {
+                                this.label("Document")
+                                    .menu_with_check(
+                                        "XML",
+                                        document_kind == DocumentKind::Xml,
+                                        Box::new(SetDocumentKind(DocumentKind::Xml)),
+                                    )
+                                    .menu_with_check(
+                                        "HTML",
+                                        document_kind == DocumentKind::Html,
+                                        Box::new(SetDocumentKind(DocumentKind::Html)),
+                                    )
+                            }),
+                    )
+                    .child(
+                        div().flex_1().child(
+                            TextInput::new(&self.expression)
+                                .font_family(editor_font(cx))
+                                .text_size(px(15.))
+                                .focus_bordered(false),
+                        ),
+                    )
+                    .when(!self.highlighted_text.is_empty(), |this| {
+                        this.child(
+                            Button::new("edit-button")
+                                .label("Edit")
+                                .on_click(cx.listener(Self::on_edit_click)),
+                        )
+                    })
+                    .child(
+                        Button::new("copy-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_click)),
+                    )
+                    .child(
+                        Button::new("paste-button")
+                            .label("Paste")
+                            .on_click(cx.listener(Self::on_paste_click)),
+                    ),
+            )
+            .when_else(
+                self.highlighted_text.is_empty(),
+                |this| {
+                    this.child(
+                        TextInput::new(&self.editor)
+                            .h_full()
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .focus_bordered(false),
+                    )
+                },
+                |this| {
+                    this.child(
+                        div()
+                            .id("highlighted-source")
+                            .size_full()
+                            .overflow_y_scroll()
+                            .font_family(editor_font(cx))
+                            .text_size(px(15.))
+                            .line_height(rems(1.25))
+                            .bg(cx.theme().background)
+                            .text_color(cx.theme().foreground)
+                            .rounded(cx.theme().radius)
+                            .border_color(cx.theme().input)
+                            .border_1()
+                            .input_px(Size::default())
+                            .input_py(Size::default())
+                            .child(
+                                StyledText::new(self.highlighted_text.clone())
+                                    .with_highlights(highlights),
+                            ),
+                    )
+                },
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .when_some(match_count, |this, match_count| {
+                        this.child(Label::new(format!("{} matches", match_count)))
+                    })
+                    .child(
+                        Button::new("copy-results-button")
+                            .label("Copy")
+                            .on_click(cx.listener(Self::on_copy_results_click))
+                            .ml_auto(),
+                    ),
+            )
+            .child(
+                TextInput::new(&self.results)
+                    .h_full()
+                    .font_family(editor_font(cx))
+                    .text_size(px(15.))
+                    .focus_bordered(false),
+            )
+    }
+}